@@ -1228,6 +1228,10 @@ mod tests {
             common: ir::control::ObjectCommon::default(),
             chart_id: "chart_data_123".to_string(),
             chart_type: ir::control::ChartType::Pie,
+            title: None,
+            axes: Vec::new(),
+            categories: Vec::new(),
+            series: Vec::new(),
         };
 
         let doc_chart = convert_chart(&ir_chart);
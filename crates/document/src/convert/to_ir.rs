@@ -867,6 +867,10 @@ fn convert_chart_to_ir(chart: &crate::control::Chart) -> ir::control::Chart {
             crate::control::ChartType::Scatter => ir::control::ChartType::Scatter,
             crate::control::ChartType::Radar => ir::control::ChartType::Radar,
         },
+        title: None,
+        axes: Vec::new(),
+        categories: Vec::new(),
+        series: Vec::new(),
     }
 }
 
@@ -1461,7 +1465,7 @@ mod tests {
         {
             if let ir::control::Control::TextBox(tb) = ctrl.as_ref() {
                 assert_eq!(tb.paragraphs[0].to_plain_text(), "TextBox content");
-                assert_eq!(tb.editable, true);
+                assert!(tb.editable);
             } else {
                 panic!("Expected TextBox control");
             }
@@ -314,9 +314,11 @@ mod tests {
 
     #[test]
     fn test_file_header_flags() {
-        let mut flags = FileHeaderFlags::default();
-        flags.encrypted = true;
-        flags.compressed = true;
+        let flags = FileHeaderFlags {
+            encrypted: true,
+            compressed: true,
+            ..Default::default()
+        };
 
         let ext = HwpExtension {
             file_header: Some(FileHeader {
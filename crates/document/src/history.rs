@@ -234,7 +234,7 @@ mod tests {
         let mut history = CommandHistory::with_max_size(3);
 
         for i in 0..5 {
-            let cmd = InsertText::new(Position::new(0, 0, 0, 5 + i), &i.to_string());
+            let cmd = InsertText::new(Position::new(0, 0, 0, 5 + i), i.to_string());
             history.execute(Box::new(cmd), &mut doc).unwrap();
         }
 
@@ -38,7 +38,7 @@ mod tests {
         // Copy, Clone 확인
         let id = SectionId::default();
         let id2 = id;
-        let id3 = id.clone();
+        let id3 = id;
         assert_eq!(id, id2);
         assert_eq!(id, id3);
     }
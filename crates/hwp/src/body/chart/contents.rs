@@ -0,0 +1,132 @@
+//! Chart object (`ChartObj`) framing of the `Contents` stream.
+//!
+//! Each chart object starts with its id and stored type id. The first object
+//! of a type also carries the type's stored name and version; later objects
+//! of the same type omit them. The root object is always `VtChart`.
+
+use std::collections::HashMap;
+
+use crate::error::{Error, Result};
+use crate::util::ByteReader;
+
+/// Stored name of the root chart object.
+const ROOT_NAME: &str = "VtChart";
+
+/// Header of a chart object.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChartObjectHeader {
+    /// Object id.
+    pub id: i32,
+    /// Stored type id.
+    pub type_id: i32,
+    /// Stored type name (e.g. `VtChart`, `Legend`).
+    pub name: String,
+    /// Stored type version.
+    pub version: i32,
+}
+
+/// Reads chart object headers, remembering the variable data of each type.
+#[derive(Debug, Default)]
+struct ChartObjectReader {
+    /// Stored type id → (stored name, stored version)
+    types: HashMap<i32, (String, i32)>,
+}
+
+impl ChartObjectReader {
+    /// Reads the header of the next chart object.
+    ///
+    /// Format:
+    /// - INT32: id
+    /// - INT32: StoredTypeID
+    /// - WCHAR array: StoredName (first object of the type only)
+    /// - INT32: StoredVersion (first object of the type only)
+    fn read_header(&mut self, reader: &mut ByteReader) -> Result<ChartObjectHeader> {
+        let id = reader.read_i32()?;
+        let type_id = reader.read_i32()?;
+        let (name, version) = match self.types.get(&type_id) {
+            Some(stored) => stored.clone(),
+            None => {
+                let stored = (reader.read_utf16_string()?, reader.read_i32()?);
+                self.types.insert(type_id, stored.clone());
+                stored
+            }
+        };
+        Ok(ChartObjectHeader {
+            id,
+            type_id,
+            name,
+            version,
+        })
+    }
+}
+
+/// Reads the root object header of a `Contents` stream.
+pub(super) fn read_root(data: &[u8]) -> Result<ChartObjectHeader> {
+    let mut reader = ByteReader::new(data);
+    let header = ChartObjectReader::default().read_header(&mut reader)?;
+    if header.name != ROOT_NAME {
+        return Err(Error::InvalidChartData {
+            description: format!("root object is {:?}, expected {}", header.name, ROOT_NAME),
+        });
+    }
+    Ok(header)
+}
+
+#[cfg(test)]
+pub(super) mod tests {
+    use super::*;
+
+    fn write_header(data: &mut Vec<u8>, id: i32, type_id: i32, stored: Option<(&str, i32)>) {
+        data.extend_from_slice(&id.to_le_bytes());
+        data.extend_from_slice(&type_id.to_le_bytes());
+        if let Some((name, version)) = stored {
+            data.extend_from_slice(&(name.encode_utf16().count() as u16).to_le_bytes());
+            for unit in name.encode_utf16() {
+                data.extend_from_slice(&unit.to_le_bytes());
+            }
+            data.extend_from_slice(&version.to_le_bytes());
+        }
+    }
+
+    /// `Contents` stream starting with a `VtChart` root object.
+    pub(in super::super) fn vt_chart() -> Vec<u8> {
+        let mut data = Vec::new();
+        write_header(&mut data, 1, 100, Some(("VtChart", 5)));
+        data
+    }
+
+    #[test]
+    fn test_read_root() {
+        let header = read_root(&vt_chart()).unwrap();
+        assert_eq!(header.id, 1);
+        assert_eq!(header.type_id, 100);
+        assert_eq!(header.name, "VtChart");
+        assert_eq!(header.version, 5);
+    }
+
+    #[test]
+    fn test_repeated_type_omits_variable_data() {
+        let mut data = Vec::new();
+        write_header(&mut data, 2, 7, Some(("VtFont", 1)));
+        write_header(&mut data, 3, 7, None);
+
+        let mut reader = ByteReader::new(&data);
+        let mut objects = ChartObjectReader::default();
+        let first = objects.read_header(&mut reader).unwrap();
+        let second = objects.read_header(&mut reader).unwrap();
+
+        assert_eq!(second.id, 3);
+        assert_eq!((second.name, second.version), (first.name, first.version));
+        assert!(reader.is_empty());
+    }
+
+    #[test]
+    fn test_read_root_rejects_other_objects() {
+        let mut data = Vec::new();
+        write_header(&mut data, 1, 7, Some(("Legend", 1)));
+        assert!(matches!(
+            read_root(&data),
+            Err(Error::InvalidChartData { .. })
+        ));
+    }
+}
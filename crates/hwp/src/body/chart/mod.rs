@@ -0,0 +1,333 @@
+//! Chart data parsing.
+//!
+//! Charts display data in various visual formats like bar charts,
+//! line charts, pie charts, etc.
+//!
+//! Charts in HWP are stored as OLE objects. HWPTAG_CHART_DATA only contains
+//! a 2-byte header; the actual chart content is in the OLE compound file
+//! referenced by the shape's OLE record. That storage holds a `Contents`
+//! stream with the chart objects described in the HWP chart specification
+//! and, in documents saved by newer versions, an `OOXMLChartContents` stream
+//! with the same chart as a DrawingML chart part.
+
+mod contents;
+mod ooxml;
+
+pub use contents::ChartObjectHeader;
+
+use primitive::Limits;
+
+use crate::error::{Error, Result};
+use crate::ole::OleStorage;
+use crate::util::ByteReader;
+
+/// Stream holding the chart objects (`ChartObj` sequence).
+const CONTENTS_STREAM: &str = "/Contents";
+
+/// Stream holding the chart as an OOXML chart part.
+const OOXML_STREAM: &str = "/OOXMLChartContents";
+
+/// Chart type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum ChartType {
+    /// Unknown or unsupported chart type.
+    #[default]
+    Unknown,
+    /// Bar chart.
+    Bar,
+    /// Line chart.
+    Line,
+    /// Pie chart.
+    Pie,
+    /// Area chart.
+    Area,
+    /// Scatter chart.
+    Scatter,
+    /// Combined chart.
+    Combined,
+    /// Radar chart.
+    Radar,
+    /// Column (vertical bar) chart.
+    Column,
+    /// Doughnut chart.
+    Doughnut,
+    /// Bubble chart.
+    Bubble,
+    /// Stock (high-low) chart.
+    Stock,
+    /// Surface chart.
+    Surface,
+}
+
+impl ChartType {
+    /// Creates from raw value.
+    pub const fn from_raw(value: u8) -> Self {
+        match value {
+            0 => Self::Bar,
+            1 => Self::Line,
+            2 => Self::Pie,
+            3 => Self::Area,
+            4 => Self::Scatter,
+            5 => Self::Combined,
+            6 => Self::Radar,
+            _ => Self::Unknown,
+        }
+    }
+}
+
+/// Chart axis kind.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ChartAxisKind {
+    /// Category axis.
+    #[default]
+    Category,
+    /// Value axis.
+    Value,
+    /// Date axis.
+    Date,
+    /// Series (depth) axis of 3D charts.
+    Series,
+}
+
+/// Chart axis.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ChartAxis {
+    /// Axis kind.
+    pub kind: ChartAxisKind,
+    /// Axis title (empty if none).
+    pub title: String,
+}
+
+/// Chart data series.
+#[derive(Debug, Clone, Default)]
+pub struct ChartSeries {
+    /// Series name.
+    pub name: String,
+    /// Data values (NaN for missing points).
+    pub values: Vec<f64>,
+}
+
+/// Stream of the chart storage that [`ChartData::read_storage`] decoded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChartSource {
+    /// The OOXML chart part; titles, axes, categories and values are filled.
+    Ooxml,
+    /// The chart object stream; only its root object header was read.
+    Contents(ChartObjectHeader),
+}
+
+/// Chart data in the document.
+#[derive(Debug, Clone, Default)]
+pub struct ChartData {
+    /// Chart type.
+    pub chart_type: ChartType,
+    /// Chart title.
+    pub title: String,
+    /// Chart axes.
+    pub axes: Vec<ChartAxis>,
+    /// Category labels (X-axis).
+    pub categories: Vec<String>,
+    /// Data series.
+    pub series: Vec<ChartSeries>,
+    /// Binary data ID of the chart's OLE storage, from the shape's OLE record.
+    pub binary_data_id: Option<u16>,
+}
+
+impl ChartData {
+    /// Parses chart data record from reader.
+    ///
+    /// Format (per HWP spec - HWPTAG_CHART_DATA is just 2 bytes):
+    /// - UINT16: chart data flags/type
+    ///
+    /// Note: The actual chart content is stored in the OLE object's
+    /// "Contents" or "OOXMLChartContents" stream, not in this record.
+    /// See [`ChartData::read_storage`].
+    pub fn from_reader(reader: &mut ByteReader) -> Result<Self> {
+        // HWPTAG_CHART_DATA only has 2 bytes per spec
+        let chart_flags = if reader.remaining() >= 2 {
+            reader.read_u16()?
+        } else {
+            0
+        };
+
+        // Extract chart type from flags (if encoded)
+        let chart_type = ChartType::from_raw((chart_flags & 0xFF) as u8);
+
+        Ok(Self {
+            chart_type,
+            ..Default::default()
+        })
+    }
+
+    /// Fills the chart from its OLE storage (the BinData item of the chart).
+    ///
+    /// The OOXML chart part is preferred because it carries the titles, axes,
+    /// categories and series values. The chart specification only defines the
+    /// framing of the objects in the `Contents` stream, not the layout of
+    /// their properties, so when the OOXML part is missing only the structure
+    /// of `Contents` is checked and the chart keeps its record data.
+    pub fn read_storage(&mut self, storage: &[u8]) -> Result<ChartSource> {
+        self.read_storage_with_limits(storage, &Limits::default())
+    }
+
+    /// Fills the chart from its OLE storage, checking stream sizes against `limits`.
+    ///
+    /// See [`ChartData::read_storage`] and [`OleStorage::from_bytes_with_limits`].
+    pub fn read_storage_with_limits(
+        &mut self,
+        storage: &[u8],
+        limits: &Limits,
+    ) -> Result<ChartSource> {
+        let storage = OleStorage::from_bytes_with_limits(storage, limits)?;
+
+        if let Some(data) = storage.stream(OOXML_STREAM) {
+            let chart = ooxml::parse(data)?;
+            *self = Self {
+                binary_data_id: self.binary_data_id,
+                ..chart
+            };
+            return Ok(ChartSource::Ooxml);
+        }
+
+        let data = storage
+            .stream(CONTENTS_STREAM)
+            .ok_or_else(|| Error::MissingStream {
+                name: CONTENTS_STREAM.to_string(),
+            })?;
+        let root = contents::read_root(data)?;
+        Ok(ChartSource::Contents(root))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Cursor, Write};
+
+    use cfb::CompoundFile;
+
+    use super::*;
+
+    const OOXML: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<c:chartSpace xmlns:c="http://schemas.openxmlformats.org/drawingml/2006/chart" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main">
+  <c:chart>
+    <c:title><c:tx><c:rich><a:p><a:r><a:t>Sales</a:t></a:r></a:p></c:rich></c:tx></c:title>
+    <c:plotArea>
+      <c:barChart>
+        <c:barDir val="col"/>
+        <c:ser>
+          <c:tx><c:strRef><c:strCache><c:ptCount val="1"/><c:pt idx="0"><c:v>2024</c:v></c:pt></c:strCache></c:strRef></c:tx>
+          <c:cat><c:strRef><c:strCache><c:ptCount val="2"/><c:pt idx="0"><c:v>Jan</c:v></c:pt><c:pt idx="1"><c:v>Feb</c:v></c:pt></c:strCache></c:strRef></c:cat>
+          <c:val><c:numRef><c:numCache><c:ptCount val="2"/><c:pt idx="0"><c:v>10</c:v></c:pt><c:pt idx="1"><c:v>20.5</c:v></c:pt></c:numCache></c:numRef></c:val>
+        </c:ser>
+      </c:barChart>
+      <c:catAx><c:axId val="1"/></c:catAx>
+      <c:valAx><c:axId val="2"/></c:valAx>
+    </c:plotArea>
+  </c:chart>
+</c:chartSpace>"#;
+
+    fn storage(streams: &[(&str, &[u8])]) -> Vec<u8> {
+        let mut file = CompoundFile::create(Cursor::new(Vec::new())).unwrap();
+        for (name, data) in streams {
+            file.create_stream(name).unwrap().write_all(data).unwrap();
+        }
+        file.into_inner().into_inner()
+    }
+
+    #[test]
+    fn test_chart_type_from_raw() {
+        assert_eq!(ChartType::from_raw(0), ChartType::Bar);
+        assert_eq!(ChartType::from_raw(1), ChartType::Line);
+        assert_eq!(ChartType::from_raw(2), ChartType::Pie);
+        assert_eq!(ChartType::from_raw(255), ChartType::Unknown);
+    }
+
+    #[test]
+    fn test_chart_series_default() {
+        let series = ChartSeries::default();
+        assert_eq!(series.name, "");
+        assert!(series.values.is_empty());
+    }
+
+    #[test]
+    fn test_chart_series_values() {
+        let mut series = ChartSeries::default();
+        series.values.push(10.0);
+        series.values.push(20.0);
+        series.values.push(30.0);
+        assert_eq!(series.values, &[10.0, 20.0, 30.0]);
+    }
+
+    #[test]
+    fn test_chart_data_default() {
+        let chart = ChartData::default();
+        assert_eq!(chart.chart_type, ChartType::Unknown);
+        assert_eq!(chart.title, "");
+        assert!(chart.axes.is_empty());
+        assert!(chart.categories.is_empty());
+        assert!(chart.series.is_empty());
+        assert_eq!(chart.binary_data_id, None);
+    }
+
+    #[test]
+    fn test_chart_data_full() {
+        let mut chart = ChartData {
+            chart_type: ChartType::Bar,
+            title: "Monthly Sales".to_string(),
+            categories: vec!["Jan".to_string(), "Feb".to_string(), "Mar".to_string()],
+            ..Default::default()
+        };
+
+        let series = ChartSeries {
+            name: "2024".to_string(),
+            values: vec![100.0, 150.0, 200.0],
+        };
+        chart.series.push(series);
+
+        assert_eq!(chart.title, "Monthly Sales");
+        assert_eq!(chart.categories.len(), 3);
+        assert_eq!(chart.series.len(), 1);
+    }
+
+    #[test]
+    fn test_read_storage_prefers_ooxml() {
+        let contents = contents::tests::vt_chart();
+        let storage = storage(&[
+            ("/Contents", &contents),
+            ("/OOXMLChartContents", OOXML.as_bytes()),
+        ]);
+        let mut chart = ChartData {
+            binary_data_id: Some(3),
+            ..Default::default()
+        };
+
+        assert_eq!(chart.read_storage(&storage).unwrap(), ChartSource::Ooxml);
+        assert_eq!(chart.chart_type, ChartType::Column);
+        assert_eq!(chart.title, "Sales");
+        assert_eq!(chart.categories, ["Jan", "Feb"]);
+        assert_eq!(chart.series[0].name, "2024");
+        assert_eq!(chart.series[0].values, [10.0, 20.5]);
+        assert_eq!(chart.axes.len(), 2);
+        assert_eq!(chart.binary_data_id, Some(3));
+    }
+
+    #[test]
+    fn test_read_storage_with_size_prefix() {
+        let storage = storage(&[("/Contents", &contents::tests::vt_chart())]);
+        let mut data = (storage.len() as u32).to_le_bytes().to_vec();
+        data.extend_from_slice(&storage);
+        let mut chart = ChartData::from_reader(&mut ByteReader::new(&[1, 0])).unwrap();
+
+        let source = chart.read_storage(&data).unwrap();
+        assert!(matches!(source, ChartSource::Contents(root) if root.name == "VtChart"));
+        assert_eq!(chart.chart_type, ChartType::Line);
+    }
+
+    #[test]
+    fn test_read_storage_without_chart_streams() {
+        let storage = storage(&[("/Other", b"data")]);
+        let error = ChartData::default().read_storage(&storage).unwrap_err();
+        assert!(matches!(error, Error::MissingStream { .. }));
+    }
+}
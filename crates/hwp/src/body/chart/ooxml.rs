@@ -0,0 +1,388 @@
+//! OOXML chart part (`OOXMLChartContents`) parsing.
+//!
+//! The stream holds a DrawingML `c:chartSpace` document. Only the parts that
+//! map onto [`ChartData`] are read: the chart title, the chart groups of the
+//! plot area (chart type and series), and the axes.
+
+use quick_xml::Reader;
+use quick_xml::events::{BytesStart, Event};
+
+use super::{ChartAxis, ChartAxisKind, ChartData, ChartSeries, ChartType};
+use crate::error::{Error, Result};
+
+/// Largest point count taken from `c:ptCount` (the row count of a worksheet).
+const MAX_POINT_COUNT: usize = 1 << 20;
+
+/// Parsed XML element (namespace prefixes removed).
+#[derive(Debug, Default)]
+struct Element {
+    name: String,
+    attributes: Vec<(String, String)>,
+    children: Vec<Element>,
+    text: String,
+}
+
+impl Element {
+    fn child(&self, name: &str) -> Option<&Element> {
+        self.children.iter().find(|child| child.name == name)
+    }
+
+    fn children_named<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a Element> {
+        self.children.iter().filter(move |child| child.name == name)
+    }
+
+    fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// `val` attribute of the named child element.
+    fn child_value(&self, name: &str) -> Option<&str> {
+        self.child(name).and_then(|child| child.attribute("val"))
+    }
+
+    /// First descendant with the given name (depth-first).
+    fn descendant(&self, name: &str) -> Option<&Element> {
+        self.children.iter().find_map(|child| {
+            (child.name == name)
+                .then_some(child)
+                .or_else(|| child.descendant(name))
+        })
+    }
+
+    /// Concatenated text of all descendants with the given name.
+    fn collect_text(&self, name: &str, text: &mut String) {
+        for child in &self.children {
+            if child.name == name {
+                text.push_str(&child.text);
+            } else {
+                child.collect_text(name, text);
+            }
+        }
+    }
+}
+
+/// Parses an OOXML chart part.
+pub(super) fn parse(data: &[u8]) -> Result<ChartData> {
+    let xml = std::str::from_utf8(data).map_err(|err| invalid(err.to_string()))?;
+    let root = parse_tree(xml)?;
+    if root.name != "chartSpace" {
+        return Err(invalid(format!(
+            "root element is {}, expected chartSpace",
+            root.name
+        )));
+    }
+    let chart = root
+        .child("chart")
+        .ok_or_else(|| invalid("missing chart element"))?;
+
+    let mut data = ChartData {
+        title: chart.child("title").map(title_text).unwrap_or_default(),
+        ..Default::default()
+    };
+
+    let Some(plot_area) = chart.child("plotArea") else {
+        return Ok(data);
+    };
+
+    let mut types = Vec::new();
+    for group in &plot_area.children {
+        let Some(chart_type) = group_type(group) else {
+            continue;
+        };
+        types.push(chart_type);
+        for series in group.children_named("ser") {
+            let categories = series.child("cat").or_else(|| series.child("xVal"));
+            if let Some(categories) = categories.filter(|_| data.categories.is_empty()) {
+                data.categories = points(categories);
+            }
+            let values = series.child("val").or_else(|| series.child("yVal"));
+            data.series.push(ChartSeries {
+                name: series.child("tx").map(series_name).unwrap_or_default(),
+                values: values
+                    .map(points)
+                    .unwrap_or_default()
+                    .iter()
+                    .map(|point| point.trim().parse().unwrap_or(f64::NAN))
+                    .collect(),
+            });
+        }
+    }
+    types.dedup();
+    data.chart_type = match types.as_slice() {
+        [] => ChartType::Unknown,
+        [chart_type] => *chart_type,
+        _ => ChartType::Combined,
+    };
+
+    for axis in &plot_area.children {
+        let kind = match axis.name.as_str() {
+            "catAx" => ChartAxisKind::Category,
+            "valAx" => ChartAxisKind::Value,
+            "dateAx" => ChartAxisKind::Date,
+            "serAx" => ChartAxisKind::Series,
+            _ => continue,
+        };
+        data.axes.push(ChartAxis {
+            kind,
+            title: axis.child("title").map(title_text).unwrap_or_default(),
+        });
+    }
+
+    Ok(data)
+}
+
+/// Chart type of a chart group element of the plot area.
+fn group_type(group: &Element) -> Option<ChartType> {
+    let chart_type = match group.name.as_str() {
+        // barDir defaults to col
+        "barChart" | "bar3DChart" => match group.child_value("barDir") {
+            Some("bar") => ChartType::Bar,
+            _ => ChartType::Column,
+        },
+        "lineChart" | "line3DChart" => ChartType::Line,
+        "pieChart" | "pie3DChart" | "ofPieChart" => ChartType::Pie,
+        "doughnutChart" => ChartType::Doughnut,
+        "areaChart" | "area3DChart" => ChartType::Area,
+        "scatterChart" => ChartType::Scatter,
+        "bubbleChart" => ChartType::Bubble,
+        "radarChart" => ChartType::Radar,
+        "stockChart" => ChartType::Stock,
+        "surfaceChart" | "surface3DChart" => ChartType::Surface,
+        _ => return None,
+    };
+    Some(chart_type)
+}
+
+/// Text of a `c:title`: rich text paragraphs, or the cached string of a
+/// referenced cell.
+fn title_text(title: &Element) -> String {
+    let Some(tx) = title.child("tx") else {
+        return String::new();
+    };
+    match tx.child("rich") {
+        Some(rich) => rich
+            .children_named("p")
+            .map(|paragraph| {
+                let mut text = String::new();
+                paragraph.collect_text("t", &mut text);
+                text
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+        None => series_name(tx),
+    }
+}
+
+/// Text of a series `c:tx`: the cached string of a referenced cell, or a
+/// literal value.
+fn series_name(tx: &Element) -> String {
+    match tx.child("v") {
+        Some(value) => value.text.clone(),
+        None => points(tx).join(" "),
+    }
+}
+
+/// Cached points of a data source (`c:cat`, `c:val`, `c:tx` and the like).
+///
+/// Points missing from the cache are empty strings. For multi-level
+/// categories the innermost level is used.
+fn points(source: &Element) -> Vec<String> {
+    let Some(cache) = [
+        "strCache",
+        "numCache",
+        "strLit",
+        "numLit",
+        "multiLvlStrCache",
+    ]
+    .iter()
+    .find_map(|name| source.descendant(name)) else {
+        return Vec::new();
+    };
+    let cache = cache.child("lvl").unwrap_or(cache);
+
+    let count = cache
+        .child_value("ptCount")
+        .and_then(|count| count.parse::<usize>().ok())
+        .unwrap_or(0)
+        .min(MAX_POINT_COUNT);
+    let points: Vec<_> = cache.children_named("pt").collect();
+    let mut values = vec![String::new(); count.max(points.len())];
+    for (position, point) in points.into_iter().enumerate() {
+        let index = point
+            .attribute("idx")
+            .and_then(|idx| idx.parse::<usize>().ok())
+            .unwrap_or(position);
+        // Indices beyond the point count are ignored
+        if let Some(value) = values.get_mut(index) {
+            *value = point.child("v").map(|v| v.text.clone()).unwrap_or_default();
+        }
+    }
+    values
+}
+
+fn invalid(description: impl Into<String>) -> Error {
+    Error::InvalidChartData {
+        description: description.into(),
+    }
+}
+
+fn parse_tree(xml: &str) -> Result<Element> {
+    let mut reader = Reader::from_str(xml.strip_prefix('\u{FEFF}').unwrap_or(xml));
+    let mut stack = vec![Element::default()];
+
+    loop {
+        let event = reader
+            .read_event()
+            .map_err(|err| invalid(format!("{} at byte {}", err, reader.error_position())))?;
+        match event {
+            Event::Start(start) => stack.push(element(&start)?),
+            Event::Empty(start) => {
+                let element = element(&start)?;
+                stack.last_mut().unwrap().children.push(element);
+            }
+            Event::End(_) => {
+                let element = stack.pop().unwrap();
+                stack
+                    .last_mut()
+                    .ok_or_else(|| invalid("unbalanced end tag"))?
+                    .children
+                    .push(element);
+            }
+            Event::Text(text) => {
+                let text = text.decode().map_err(|err| invalid(err.to_string()))?;
+                let text =
+                    quick_xml::escape::unescape(&text).map_err(|err| invalid(err.to_string()))?;
+                stack.last_mut().unwrap().text.push_str(&text);
+            }
+            Event::CData(data) => {
+                let data = data.decode().map_err(|err| invalid(err.to_string()))?;
+                stack.last_mut().unwrap().text.push_str(&data);
+            }
+            Event::GeneralRef(reference) => {
+                let text = match reference
+                    .resolve_char_ref()
+                    .map_err(|err| invalid(err.to_string()))?
+                {
+                    Some(ch) => ch.to_string(),
+                    None => {
+                        let name = reference.decode().map_err(|err| invalid(err.to_string()))?;
+                        quick_xml::escape::resolve_predefined_entity(&name)
+                            .ok_or_else(|| invalid(format!("unknown entity &{};", name)))?
+                            .to_string()
+                    }
+                };
+                stack.last_mut().unwrap().text.push_str(&text);
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+    if stack.len() != 1 {
+        return Err(invalid("unclosed element"));
+    }
+    stack
+        .pop()
+        .and_then(|document| document.children.into_iter().next())
+        .ok_or_else(|| invalid("missing root element"))
+}
+
+fn element(start: &BytesStart<'_>) -> Result<Element> {
+    let mut attributes = Vec::new();
+    for attribute in start.attributes() {
+        let attribute = attribute.map_err(|err| invalid(err.to_string()))?;
+        let key = String::from_utf8_lossy(attribute.key.local_name().as_ref()).into_owned();
+        let value = attribute
+            .unescape_value()
+            .map_err(|err| invalid(err.to_string()))?;
+        attributes.push((key, value.into_owned()));
+    }
+    Ok(Element {
+        name: String::from_utf8_lossy(start.local_name().as_ref()).into_owned(),
+        attributes,
+        ..Default::default()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chart_space(plot_area: &str) -> String {
+        format!(
+            r#"<c:chartSpace xmlns:c="c" xmlns:a="a"><c:chart>{}</c:chart></c:chartSpace>"#,
+            plot_area
+        )
+    }
+
+    #[test]
+    fn test_parse_line_chart_with_gaps() {
+        let xml = chart_space(
+            r#"<c:title><c:tx><c:rich><a:p><a:r><a:t>Q</a:t></a:r><a:r><a:t>1</a:t></a:r></a:p><a:p><a:r><a:t>2024</a:t></a:r></a:p></c:rich></c:tx></c:title>
+            <c:plotArea><c:lineChart><c:ser>
+              <c:tx><c:v>Revenue</c:v></c:tx>
+              <c:val><c:numRef><c:numCache><c:ptCount val="3"/><c:pt idx="0"><c:v>1.5</c:v></c:pt><c:pt idx="2"><c:v>3</c:v></c:pt></c:numCache></c:numRef></c:val>
+            </c:ser></c:lineChart>
+            <c:valAx><c:title><c:tx><c:rich><a:p><a:r><a:t>Won &amp; USD</a:t></a:r></a:p></c:rich></c:tx></c:title></c:valAx>
+            </c:plotArea>"#,
+        );
+        let chart = parse(xml.as_bytes()).unwrap();
+
+        assert_eq!(chart.chart_type, ChartType::Line);
+        assert_eq!(chart.title, "Q1\n2024");
+        assert_eq!(chart.series[0].name, "Revenue");
+        let values = &chart.series[0].values;
+        assert_eq!((values.len(), values[0], values[2]), (3, 1.5, 3.0));
+        assert!(values[1].is_nan());
+        assert_eq!(
+            chart.axes,
+            [ChartAxis {
+                kind: ChartAxisKind::Value,
+                title: "Won & USD".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_parse_combined_chart() {
+        let xml = chart_space(
+            r#"<c:plotArea>
+            <c:barChart><c:barDir val="bar"/><c:ser><c:cat><c:multiLvlStrRef><c:multiLvlStrCache><c:ptCount val="1"/><c:lvl><c:pt idx="0"><c:v>A</c:v></c:pt></c:lvl></c:multiLvlStrCache></c:multiLvlStrRef></c:cat></c:ser></c:barChart>
+            <c:lineChart><c:ser/></c:lineChart>
+            </c:plotArea>"#,
+        );
+        let chart = parse(xml.as_bytes()).unwrap();
+
+        assert_eq!(chart.chart_type, ChartType::Combined);
+        assert_eq!(chart.categories, ["A"]);
+        assert_eq!(chart.series.len(), 2);
+    }
+
+    #[test]
+    fn test_huge_point_count() {
+        let xml = chart_space(
+            r#"<c:plotArea><c:lineChart><c:ser>
+              <c:val><c:numRef><c:numCache><c:ptCount val="18446744073709551615"/><c:pt idx="0"><c:v>1</c:v></c:pt></c:numCache></c:numRef></c:val>
+            </c:ser></c:lineChart></c:plotArea>"#,
+        );
+        let chart = parse(xml.as_bytes()).unwrap();
+        assert_eq!(chart.series[0].values.len(), MAX_POINT_COUNT);
+        assert_eq!(chart.series[0].values[0], 1.0);
+    }
+
+    #[test]
+    fn test_group_type_bar_direction() {
+        let xml = chart_space(r#"<c:plotArea><c:bar3DChart/></c:plotArea>"#);
+        assert_eq!(parse(xml.as_bytes()).unwrap().chart_type, ChartType::Column);
+    }
+
+    #[test]
+    fn test_parse_rejects_other_parts() {
+        assert!(matches!(
+            parse(b"<worksheet/>"),
+            Err(Error::InvalidChartData { .. })
+        ));
+    }
+}
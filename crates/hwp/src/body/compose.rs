@@ -0,0 +1,154 @@
+//! Character overlap (글자 겹침) control parsing.
+//!
+//! The `tcps` control draws several characters on top of each other,
+//! optionally inside a border shape such as a circle or a rectangle.
+
+use crate::error::Result;
+use crate::util::ByteReader;
+
+/// Border drawn around overlapped characters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ComposeBorderType {
+    /// No border (characters only).
+    #[default]
+    None,
+    /// Circle.
+    Circle,
+    /// Reversed circle.
+    ReversalCircle,
+    /// Rectangle.
+    Rectangle,
+    /// Reversed rectangle.
+    ReversalRectangle,
+    /// Triangle.
+    Triangle,
+    /// Reversed triangle.
+    ReversalTriangle,
+    /// Light bulb.
+    Light,
+    /// Rhombus.
+    Rhombus,
+    /// Reversed rhombus.
+    ReversalRhombus,
+    /// Rounded rectangle.
+    RoundedRectangle,
+    /// Empty circulating triangle.
+    EmptyCirculateTriangle,
+    /// Thin circulating triangle.
+    ThinCirculateTriangle,
+    /// Thick circulating triangle.
+    ThickCirculateTriangle,
+}
+
+impl ComposeBorderType {
+    /// Creates from raw value.
+    pub const fn from_raw(value: u8) -> Self {
+        match value {
+            1 => Self::Circle,
+            2 => Self::ReversalCircle,
+            3 => Self::Rectangle,
+            4 => Self::ReversalRectangle,
+            5 => Self::Triangle,
+            6 => Self::ReversalTriangle,
+            7 => Self::Light,
+            8 => Self::Rhombus,
+            9 => Self::ReversalRhombus,
+            10 => Self::RoundedRectangle,
+            11 => Self::EmptyCirculateTriangle,
+            12 => Self::ThinCirculateTriangle,
+            13 => Self::ThickCirculateTriangle,
+            _ => Self::None,
+        }
+    }
+
+    /// Returns the raw value.
+    pub const fn to_raw(self) -> u8 {
+        self as u8
+    }
+}
+
+/// Character overlap control (`tcps`).
+#[derive(Debug, Clone, Default)]
+pub struct Compose {
+    /// Characters drawn on top of each other.
+    pub text: String,
+    /// Border drawn around the characters.
+    pub border_type: ComposeBorderType,
+    /// Size of the characters inside the border.
+    pub inner_char_size: i8,
+    /// Whether the characters inside the border are spread apart.
+    pub spread_inner_chars: bool,
+    /// Character shape IDs of the overlapped characters.
+    pub character_shape_ids: Vec<u32>,
+}
+
+impl Compose {
+    /// Parses the control header data following the control ID.
+    ///
+    /// Format (per HWP spec - 글자 겹침):
+    /// - WORD: text length, WCHAR array: overlapped characters
+    /// - UINT8: border type
+    /// - INT8: inner character size
+    /// - UINT8: spread inner characters
+    /// - UINT8: character shape count
+    /// - UINT32 array: character shape IDs
+    pub fn from_reader(reader: &mut ByteReader) -> Result<Self> {
+        let text = reader.read_utf16_string()?;
+        let border_type = ComposeBorderType::from_raw(reader.read_u8()?);
+        let inner_char_size = reader.read_i8()?;
+        let spread_inner_chars = reader.read_u8()? != 0;
+        let count = reader.read_u8()? as usize;
+        let character_shape_ids = (0..count)
+            .map(|_| reader.read_u32())
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self {
+            text,
+            border_type,
+            inner_char_size,
+            spread_inner_chars,
+            character_shape_ids,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compose_border_type_from_raw() {
+        assert_eq!(ComposeBorderType::from_raw(0), ComposeBorderType::None);
+        assert_eq!(ComposeBorderType::from_raw(1), ComposeBorderType::Circle);
+        assert_eq!(
+            ComposeBorderType::from_raw(13),
+            ComposeBorderType::ThickCirculateTriangle
+        );
+        assert_eq!(ComposeBorderType::from_raw(200), ComposeBorderType::None);
+        assert_eq!(ComposeBorderType::RoundedRectangle.to_raw(), 10);
+    }
+
+    #[test]
+    fn test_compose_from_reader() {
+        let mut data = Vec::new();
+        data.extend_from_slice(&2u16.to_le_bytes());
+        for ch in "가1".encode_utf16() {
+            data.extend_from_slice(&ch.to_le_bytes());
+        }
+        data.push(1); // circle
+        data.push((-3i8) as u8);
+        data.push(1);
+        data.push(2);
+        data.extend_from_slice(&7u32.to_le_bytes());
+        data.extend_from_slice(&u32::MAX.to_le_bytes());
+
+        let mut reader = ByteReader::new(&data);
+        let compose = Compose::from_reader(&mut reader).unwrap();
+
+        assert_eq!(compose.text, "가1");
+        assert_eq!(compose.border_type, ComposeBorderType::Circle);
+        assert_eq!(compose.inner_char_size, -3);
+        assert!(compose.spread_inner_chars);
+        assert_eq!(compose.character_shape_ids, [7, u32::MAX]);
+    }
+}
@@ -71,7 +71,7 @@ mod tests {
     #[test]
     fn test_shape_container_default() {
         let container = ShapeContainer::default();
-        assert!(container.children.len() == 0);
+        assert!(container.children.is_empty());
         assert_eq!(container.children.len(), 0);
     }
 
@@ -85,12 +85,12 @@ mod tests {
     fn test_shape_container_validate_child_count() {
         // With expected_child_count = 0, validation always passes
         let container = ShapeContainer::default();
-        assert!(container.children.len() == 0);
+        assert!(container.children.is_empty());
 
         // With expected_child_count > 0, validation checks actual count
         let data = [0x02, 0x00]; // expected_child_count = 2
         let mut reader = crate::util::ByteReader::new(&data);
         let container = ShapeContainer::from_reader(&mut reader).unwrap();
-        assert!(container.children.len() == 0);
+        assert!(container.children.is_empty());
     }
 }
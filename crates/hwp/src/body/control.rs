@@ -7,7 +7,10 @@ use crate::error::Result;
 use crate::util::ByteReader;
 
 use super::chart::ChartData;
+use super::compose::Compose;
 use super::container::ShapeContainer;
+use super::control_data::ControlData;
+use super::dutmal::Dutmal;
 use super::equation::Equation;
 use super::field::Field;
 use super::footnote::{Endnote, Footnote};
@@ -70,6 +73,10 @@ pub enum ControlContent {
     SectionDefinition(SectionDefinition),
     /// 단 정의.
     ColumnDefinition(ColumnDefinition),
+    /// 글자 겹침.
+    Compose(Compose),
+    /// 덧말.
+    Dutmal(Dutmal),
 }
 
 /// Control character types in HWP documents.
@@ -217,6 +224,10 @@ pub enum ControlType {
     FormObject,
     /// Field (date, time, etc.).
     Field,
+    /// Character overlap.
+    Compose,
+    /// Ruby text.
+    Dutmal,
     /// Unknown control.
     Unknown,
 }
@@ -252,9 +263,9 @@ impl ControlId {
     /// Page number position.
     pub const PAGE_NUMBER_POSITION: Self = Self::from_chars(b"pgnp");
     /// Page hide.
-    pub const PAGE_HIDE: Self = Self::from_chars(b"pgct");
+    pub const PAGE_HIDE: Self = Self::from_chars(b"pghd");
     /// Page odd/even adjust.
-    pub const PAGE_ODD_EVEN: Self = Self::from_chars(b"pght");
+    pub const PAGE_ODD_EVEN: Self = Self::from_chars(b"pgct");
     /// Bookmark.
     pub const BOOKMARK: Self = Self::from_chars(b"bokm");
     /// Index mark.
@@ -265,6 +276,30 @@ impl ControlId {
     pub const TEXT_ART: Self = Self::from_chars(b"dso ");
     /// Form object.
     pub const FORM_OBJECT: Self = Self::from_chars(b"form");
+    /// Character overlap.
+    pub const COMPOSE: Self = Self::from_chars(b"tcps");
+    /// Ruby text.
+    pub const DUTMAL: Self = Self::from_chars(b"tdut");
+
+    // Shape element IDs (개체 요소)
+    /// Line element.
+    pub const SHAPE_LINE: Self = Self::from_chars(b"$lin");
+    /// Rectangle element.
+    pub const SHAPE_RECTANGLE: Self = Self::from_chars(b"$rec");
+    /// Ellipse element.
+    pub const SHAPE_ELLIPSE: Self = Self::from_chars(b"$ell");
+    /// Arc element.
+    pub const SHAPE_ARC: Self = Self::from_chars(b"$arc");
+    /// Polygon element.
+    pub const SHAPE_POLYGON: Self = Self::from_chars(b"$pol");
+    /// Curve element.
+    pub const SHAPE_CURVE: Self = Self::from_chars(b"$cur");
+    /// Picture element.
+    pub const SHAPE_PICTURE: Self = Self::from_chars(b"$pic");
+    /// OLE element.
+    pub const SHAPE_OLE: Self = Self::from_chars(b"$ole");
+    /// Group (container) element.
+    pub const SHAPE_GROUP: Self = Self::from_chars(b"$con");
 
     // Field control IDs
     /// Date field.
@@ -294,9 +329,9 @@ impl ControlId {
     /// Meta tag field.
     pub const FIELD_META_TAG: Self = Self::from_chars(b"%mtg");
 
-    /// Creates from 4-character code.
+    /// Creates from 4-character code (`MAKE_4CHID`: first character in the high byte).
     pub const fn from_chars(chars: &[u8; 4]) -> Self {
-        Self(u32::from_le_bytes(*chars))
+        Self(u32::from_be_bytes(*chars))
     }
 
     /// Creates from raw u32 value.
@@ -316,7 +351,7 @@ impl ControlId {
 
     /// Returns as 4-character string.
     pub fn as_str(&self) -> String {
-        let bytes = self.0.to_le_bytes();
+        let bytes = self.0.to_be_bytes();
         String::from_utf8_lossy(&bytes).to_string()
     }
 
@@ -342,6 +377,8 @@ impl ControlId {
             Self::HIDDEN_COMMENT => ControlType::HiddenComment,
             Self::TEXT_ART => ControlType::TextArt,
             Self::FORM_OBJECT => ControlType::FormObject,
+            Self::COMPOSE => ControlType::Compose,
+            Self::DUTMAL => ControlType::Dutmal,
             _ if self.is_field() => ControlType::Field,
             _ => ControlType::Unknown,
         }
@@ -349,7 +386,7 @@ impl ControlId {
 
     /// Returns true if this is a field control.
     pub const fn is_field(&self) -> bool {
-        let bytes = self.0.to_le_bytes();
+        let bytes = self.0.to_be_bytes();
         bytes[0] == b'%'
     }
 }
@@ -371,6 +408,14 @@ pub struct Control {
     content: Option<ControlContent>,
     /// Child paragraphs (for controls that contain text).
     children: Vec<super::paragraph::Paragraph>,
+    /// Caption attached to the object (tables, pictures, shapes, equations).
+    caption: Option<Caption>,
+    /// Parameter sets of the control's data record (field parameters, bookmark name).
+    control_data: Option<ControlData>,
+    /// Original records of this control, kept in retention mode.
+    raw_records: Option<Vec<u8>>,
+    /// Whether the control was changed after its records were retained.
+    modified: bool,
 }
 
 impl Control {
@@ -381,6 +426,10 @@ impl Control {
             data: Vec::new(),
             content: None,
             children: Vec::new(),
+            caption: None,
+            control_data: None,
+            raw_records: None,
+            modified: false,
         }
     }
 
@@ -391,6 +440,10 @@ impl Control {
             data,
             content: None,
             children: Vec::new(),
+            caption: None,
+            control_data: None,
+            raw_records: None,
+            modified: false,
         }
     }
 
@@ -416,12 +469,14 @@ impl Control {
 
     /// Returns a mutable reference to the parsed content.
     pub fn content_mut(&mut self) -> Option<&mut ControlContent> {
+        self.modified = true;
         self.content.as_mut()
     }
 
     /// Sets the parsed content.
     pub fn set_content(&mut self, content: ControlContent) {
         self.content = Some(content);
+        self.modified = true;
     }
 
     /// Returns child paragraphs.
@@ -432,6 +487,43 @@ impl Control {
     /// Adds a child paragraph.
     pub fn add_child(&mut self, paragraph: super::paragraph::Paragraph) {
         self.children.push(paragraph);
+        self.modified = true;
+    }
+
+    /// Returns the caption attached to this object.
+    pub const fn caption(&self) -> Option<&Caption> {
+        self.caption.as_ref()
+    }
+
+    /// Sets the caption attached to this object.
+    pub fn set_caption(&mut self, caption: Caption) {
+        self.caption = Some(caption);
+        self.modified = true;
+    }
+
+    /// Returns the control data record (HWPTAG_CTRL_DATA) of this control.
+    pub const fn control_data(&self) -> Option<&ControlData> {
+        self.control_data.as_ref()
+    }
+
+    /// Sets the control data record of this control.
+    pub fn set_control_data(&mut self, control_data: ControlData) {
+        self.control_data = Some(control_data);
+        self.modified = true;
+    }
+
+    /// Returns the original records of this control, starting with its control header.
+    ///
+    /// Only available when the document was read with record retention and the
+    /// control has not been changed since. Mutable accessors count as changes.
+    pub fn raw_records(&self) -> Option<&[u8]> {
+        self.raw_records.as_deref().filter(|_| !self.modified)
+    }
+
+    /// Attaches the original records of this control.
+    pub(crate) fn retain_records(&mut self, data: Vec<u8>) {
+        self.raw_records = Some(data);
+        self.modified = false;
     }
 
     // === Convenience accessors for specific content types ===
@@ -446,6 +538,7 @@ impl Control {
 
     /// Returns a mutable reference to the table content.
     pub const fn as_table_mut(&mut self) -> Option<&mut Table> {
+        self.modified = true;
         match &mut self.content {
             Some(ControlContent::Table(table)) => Some(table),
             _ => None,
@@ -462,6 +555,7 @@ impl Control {
 
     /// Returns a mutable reference to the shape content.
     pub const fn as_shape_mut(&mut self) -> Option<&mut Shape> {
+        self.modified = true;
         match &mut self.content {
             Some(ControlContent::Shape(shape)) => Some(shape),
             _ => None,
@@ -502,6 +596,7 @@ impl Control {
 
     /// Returns a mutable reference to the header content.
     pub const fn as_header_mut(&mut self) -> Option<&mut Header> {
+        self.modified = true;
         match &mut self.content {
             Some(ControlContent::Header(header)) => Some(header),
             _ => None,
@@ -518,6 +613,7 @@ impl Control {
 
     /// Returns a mutable reference to the footer content.
     pub const fn as_footer_mut(&mut self) -> Option<&mut Footer> {
+        self.modified = true;
         match &mut self.content {
             Some(ControlContent::Footer(footer)) => Some(footer),
             _ => None,
@@ -534,6 +630,7 @@ impl Control {
 
     /// Returns a mutable reference to the footnote content.
     pub const fn as_footnote_mut(&mut self) -> Option<&mut Footnote> {
+        self.modified = true;
         match &mut self.content {
             Some(ControlContent::Footnote(footnote)) => Some(footnote),
             _ => None,
@@ -550,6 +647,7 @@ impl Control {
 
     /// Returns a mutable reference to the endnote content.
     pub const fn as_endnote_mut(&mut self) -> Option<&mut Endnote> {
+        self.modified = true;
         match &mut self.content {
             Some(ControlContent::Endnote(endnote)) => Some(endnote),
             _ => None,
@@ -566,6 +664,7 @@ impl Control {
 
     /// Returns a mutable reference to the hyperlink content.
     pub const fn as_hyperlink_mut(&mut self) -> Option<&mut Hyperlink> {
+        self.modified = true;
         match &mut self.content {
             Some(ControlContent::Hyperlink(hyperlink)) => Some(hyperlink),
             _ => None,
@@ -582,6 +681,7 @@ impl Control {
 
     /// Returns a mutable reference to the field content.
     pub const fn as_field_mut(&mut self) -> Option<&mut Field> {
+        self.modified = true;
         match &mut self.content {
             Some(ControlContent::Field(field)) => Some(field),
             _ => None,
@@ -598,6 +698,7 @@ impl Control {
 
     /// Returns a mutable reference to the text box content.
     pub const fn as_text_box_mut(&mut self) -> Option<&mut TextBox> {
+        self.modified = true;
         match &mut self.content {
             Some(ControlContent::TextBox(text_box)) => Some(text_box),
             _ => None,
@@ -614,6 +715,7 @@ impl Control {
 
     /// Returns a mutable reference to the caption content.
     pub const fn as_caption_mut(&mut self) -> Option<&mut Caption> {
+        self.modified = true;
         match &mut self.content {
             Some(ControlContent::Caption(caption)) => Some(caption),
             _ => None,
@@ -630,6 +732,7 @@ impl Control {
 
     /// Returns a mutable reference to the memo content.
     pub const fn as_memo_mut(&mut self) -> Option<&mut Memo> {
+        self.modified = true;
         match &mut self.content {
             Some(ControlContent::Memo(memo)) => Some(memo),
             _ => None,
@@ -646,6 +749,7 @@ impl Control {
 
     /// Returns a mutable reference to the form object content.
     pub const fn as_form_object_mut(&mut self) -> Option<&mut FormObject> {
+        self.modified = true;
         match &mut self.content {
             Some(ControlContent::FormObject(form)) => Some(form),
             _ => None,
@@ -662,6 +766,7 @@ impl Control {
 
     /// Returns a mutable reference to the text art content.
     pub const fn as_text_art_mut(&mut self) -> Option<&mut TextArt> {
+        self.modified = true;
         match &mut self.content {
             Some(ControlContent::TextArt(art)) => Some(art),
             _ => None,
@@ -678,6 +783,7 @@ impl Control {
 
     /// Returns a mutable reference to the chart content.
     pub const fn as_chart_mut(&mut self) -> Option<&mut ChartData> {
+        self.modified = true;
         match &mut self.content {
             Some(ControlContent::Chart(chart)) => Some(chart),
             _ => None,
@@ -694,6 +800,7 @@ impl Control {
 
     /// Returns a mutable reference to the video content.
     pub const fn as_video_mut(&mut self) -> Option<&mut VideoData> {
+        self.modified = true;
         match &mut self.content {
             Some(ControlContent::Video(video)) => Some(video),
             _ => None,
@@ -710,6 +817,7 @@ impl Control {
 
     /// Returns a mutable reference to the container content.
     pub const fn as_container_mut(&mut self) -> Option<&mut ShapeContainer> {
+        self.modified = true;
         match &mut self.content {
             Some(ControlContent::Container(container)) => Some(container),
             _ => None,
@@ -726,6 +834,7 @@ impl Control {
 
     /// Returns a mutable reference to the section definition content.
     pub const fn as_section_definition_mut(&mut self) -> Option<&mut SectionDefinition> {
+        self.modified = true;
         match &mut self.content {
             Some(ControlContent::SectionDefinition(def)) => Some(def),
             _ => None,
@@ -742,12 +851,47 @@ impl Control {
 
     /// Returns a mutable reference to the column definition content.
     pub const fn as_column_definition_mut(&mut self) -> Option<&mut ColumnDefinition> {
+        self.modified = true;
         match &mut self.content {
             Some(ControlContent::ColumnDefinition(def)) => Some(def),
             _ => None,
         }
     }
 
+    /// Returns the character overlap content if this control is a character overlap.
+    pub const fn as_compose(&self) -> Option<&Compose> {
+        match &self.content {
+            Some(ControlContent::Compose(compose)) => Some(compose),
+            _ => None,
+        }
+    }
+
+    /// Returns a mutable reference to the character overlap content.
+    pub const fn as_compose_mut(&mut self) -> Option<&mut Compose> {
+        self.modified = true;
+        match &mut self.content {
+            Some(ControlContent::Compose(compose)) => Some(compose),
+            _ => None,
+        }
+    }
+
+    /// Returns the ruby text content if this control is a ruby text.
+    pub const fn as_dutmal(&self) -> Option<&Dutmal> {
+        match &self.content {
+            Some(ControlContent::Dutmal(dutmal)) => Some(dutmal),
+            _ => None,
+        }
+    }
+
+    /// Returns a mutable reference to the ruby text content.
+    pub const fn as_dutmal_mut(&mut self) -> Option<&mut Dutmal> {
+        self.modified = true;
+        match &mut self.content {
+            Some(ControlContent::Dutmal(dutmal)) => Some(dutmal),
+            _ => None,
+        }
+    }
+
     /// Extracts plain text from the control content.
     pub fn plain_text(&self) -> String {
         match &self.content {
@@ -761,6 +905,8 @@ impl Control {
             Some(ControlContent::TextArt(art)) => art.text().to_string(),
             Some(ControlContent::Table(table)) => table.plain_text(),
             Some(ControlContent::Container(c)) => c.plain_text(),
+            Some(ControlContent::Compose(compose)) => compose.text.clone(),
+            Some(ControlContent::Dutmal(dutmal)) => dutmal.main_text.clone(),
             _ => String::new(),
         }
     }
@@ -787,6 +933,8 @@ mod tests {
         );
         assert_eq!(ControlId::HEADER.control_type(), ControlType::Header);
         assert_eq!(ControlId::FOOTNOTE.control_type(), ControlType::Footnote);
+        assert_eq!(ControlId::COMPOSE.control_type(), ControlType::Compose);
+        assert_eq!(ControlId::DUTMAL.control_type(), ControlType::Dutmal);
     }
 
     #[test]
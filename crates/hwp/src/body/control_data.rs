@@ -17,7 +17,7 @@
 
 use crate::error::Result;
 use crate::util::ByteReader;
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 
 /// Parameter item types (표 52).
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -126,7 +126,7 @@ pub struct ParameterSet {
     /// Set ID.
     pub id: u16,
     /// Items in this set, keyed by item ID.
-    items: HashMap<u16, ParameterItem>,
+    items: BTreeMap<u16, ParameterItem>,
 }
 
 impl ParameterSet {
@@ -134,7 +134,7 @@ impl ParameterSet {
     pub fn new(id: u16) -> Self {
         Self {
             id,
-            items: HashMap::new(),
+            items: BTreeMap::new(),
         }
     }
 
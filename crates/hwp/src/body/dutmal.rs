@@ -0,0 +1,156 @@
+//! Ruby text (덧말) control parsing.
+//!
+//! The `tdut` control places a smaller annotation text above or below
+//! a run of main text.
+
+use crate::error::Result;
+use crate::util::ByteReader;
+
+/// Position of the ruby text relative to the main text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DutmalPosition {
+    /// Above the main text.
+    #[default]
+    Top,
+    /// Below the main text.
+    Bottom,
+}
+
+impl DutmalPosition {
+    /// Creates from raw value.
+    pub const fn from_raw(value: u32) -> Self {
+        if value == 1 { Self::Bottom } else { Self::Top }
+    }
+
+    /// Returns the raw value.
+    pub const fn to_raw(self) -> u32 {
+        self as u32
+    }
+}
+
+/// Alignment of the ruby text over the main text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DutmalAlignment {
+    /// Justified.
+    Justify,
+    /// Left aligned.
+    Left,
+    /// Right aligned.
+    Right,
+    /// Centered.
+    #[default]
+    Center,
+    /// Distributed.
+    Distribute,
+    /// Distributed including spaces.
+    DistributeSpace,
+}
+
+impl DutmalAlignment {
+    /// Creates from raw value.
+    pub const fn from_raw(value: u32) -> Self {
+        match value {
+            0 => Self::Justify,
+            1 => Self::Left,
+            2 => Self::Right,
+            4 => Self::Distribute,
+            5 => Self::DistributeSpace,
+            _ => Self::Center,
+        }
+    }
+
+    /// Returns the raw value.
+    pub const fn to_raw(self) -> u32 {
+        self as u32
+    }
+}
+
+/// Ruby text control (`tdut`).
+#[derive(Debug, Clone, Default)]
+pub struct Dutmal {
+    /// Main text.
+    pub main_text: String,
+    /// Ruby text.
+    pub sub_text: String,
+    /// Position of the ruby text.
+    pub position: DutmalPosition,
+    /// Font size ratio of the ruby text.
+    pub size_ratio: u32,
+    /// Options.
+    pub option: u32,
+    /// Style ID of the ruby text.
+    pub style_id: u32,
+    /// Alignment of the ruby text.
+    pub alignment: DutmalAlignment,
+}
+
+impl Dutmal {
+    /// Parses the control header data following the control ID.
+    ///
+    /// Format (per HWP spec - 덧말):
+    /// - WORD: length, WCHAR array: main text
+    /// - WORD: length, WCHAR array: ruby text
+    /// - UINT32: position (0 = top, 1 = bottom)
+    /// - UINT32: font size ratio
+    /// - UINT32: options
+    /// - UINT32: style ID
+    /// - UINT32: alignment
+    pub fn from_reader(reader: &mut ByteReader) -> Result<Self> {
+        Ok(Self {
+            main_text: reader.read_utf16_string()?,
+            sub_text: reader.read_utf16_string()?,
+            position: DutmalPosition::from_raw(reader.read_u32()?),
+            size_ratio: reader.read_u32()?,
+            option: reader.read_u32()?,
+            style_id: reader.read_u32()?,
+            alignment: DutmalAlignment::from_raw(reader.read_u32()?),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_string(data: &mut Vec<u8>, text: &str) {
+        let units: Vec<u16> = text.encode_utf16().collect();
+        data.extend_from_slice(&(units.len() as u16).to_le_bytes());
+        for unit in units {
+            data.extend_from_slice(&unit.to_le_bytes());
+        }
+    }
+
+    #[test]
+    fn test_dutmal_alignment_from_raw() {
+        assert_eq!(DutmalAlignment::from_raw(0), DutmalAlignment::Justify);
+        assert_eq!(DutmalAlignment::from_raw(3), DutmalAlignment::Center);
+        assert_eq!(
+            DutmalAlignment::from_raw(5),
+            DutmalAlignment::DistributeSpace
+        );
+        assert_eq!(DutmalAlignment::Distribute.to_raw(), 4);
+    }
+
+    #[test]
+    fn test_dutmal_from_reader() {
+        let mut data = Vec::new();
+        write_string(&mut data, "漢字");
+        write_string(&mut data, "한자");
+        data.extend_from_slice(&1u32.to_le_bytes());
+        data.extend_from_slice(&50u32.to_le_bytes());
+        data.extend_from_slice(&4u32.to_le_bytes());
+        data.extend_from_slice(&0u32.to_le_bytes());
+        data.extend_from_slice(&3u32.to_le_bytes());
+
+        let mut reader = ByteReader::new(&data);
+        let dutmal = Dutmal::from_reader(&mut reader).unwrap();
+
+        assert_eq!(dutmal.main_text, "漢字");
+        assert_eq!(dutmal.sub_text, "한자");
+        assert_eq!(dutmal.position, DutmalPosition::Bottom);
+        assert_eq!(dutmal.size_ratio, 50);
+        assert_eq!(dutmal.option, 4);
+        assert_eq!(dutmal.style_id, 0);
+        assert_eq!(dutmal.alignment, DutmalAlignment::Center);
+    }
+}
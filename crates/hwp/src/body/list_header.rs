@@ -53,6 +53,24 @@ impl ListHeader {
         })
     }
 
+    /// Parses the common head of a LIST_HEADER record.
+    ///
+    /// Stored files put two padding bytes between the paragraph count and
+    /// the properties. The reader is left at the start of the
+    /// control-specific data (cell, caption, header/footer, text box).
+    pub fn from_record(reader: &mut ByteReader) -> Result<Self> {
+        let paragraph_count = reader.read_u16()?;
+        reader.skip(2)?;
+        let properties = reader.read_u32()?;
+
+        Ok(Self {
+            paragraph_count,
+            properties,
+            text_width: HwpUnit::new(0),
+            text_height: HwpUnit::new(0),
+        })
+    }
+
     /// Returns the number of paragraphs in this list.
     pub const fn paragraph_count(&self) -> u16 {
         self.paragraph_count
@@ -68,6 +86,11 @@ impl ListHeader {
         TextDirection::from_raw((self.properties & 0x07) as u8)
     }
 
+    /// Returns the raw vertical alignment (bit 5~6: 0 top, 1 center, 2 bottom).
+    pub const fn vertical_alignment_raw(&self) -> u8 {
+        ((self.properties >> 5) & 0x03) as u8
+    }
+
     /// Returns true if using auto height.
     pub const fn is_auto_height(&self) -> bool {
         (self.properties & (1 << 3)) != 0
@@ -4,9 +4,11 @@
 //! with the document's actual content including paragraphs, tables, and shapes.
 
 mod chart;
+mod compose;
 mod container;
 mod control;
 pub(crate) mod control_data;
+mod dutmal;
 mod equation;
 mod field;
 mod footnote;
@@ -28,9 +30,13 @@ mod video;
 
 // 공개 API - HWP 스펙에 정의된 타입들
 pub use crate::primitive::CellPadding;
-pub use chart::{ChartData, ChartSeries, ChartType};
+pub use chart::{
+    ChartAxis, ChartAxisKind, ChartData, ChartObjectHeader, ChartSeries, ChartSource, ChartType,
+};
+pub use compose::{Compose, ComposeBorderType};
 pub use container::ShapeContainer;
 pub use control::{Control, ControlCharacter, ControlContent, ControlId, ControlType};
+pub use dutmal::{Dutmal, DutmalAlignment, DutmalPosition};
 pub use equation::{Equation, EquationLineMode, EquationProperties};
 pub use field::{Field, FieldType};
 pub use footnote::{
@@ -45,7 +51,9 @@ pub use page::{
     GutterPosition, PageBorderFill, PageBorderFillPosition, PageDefinition, PageMargins,
     PageOrientation,
 };
-pub use paragraph::{BreakType, CharacterShapeReference, LineSegment, Paragraph, RangeTag};
+pub use paragraph::{
+    BreakType, CharacterShapeReference, LineSegment, Paragraph, ParagraphText, RangeTag, TextUnit,
+};
 pub use picture::{
     ImageCrop, ImageFlip, InnerMargin, OleObject, Picture, PictureEffect, PictureEffectType,
     PictureFill, PictureProperties,
@@ -55,26 +63,9 @@ pub use section_definition::{ColumnDefinition, ColumnDirection};
 pub use shape::{
     ArcShape, ArcType, ArrowSize, ArrowType, CurveSegmentType, CurveShape, EllipseShape,
     LineEndCap, LineShape, Point, PolygonShape, RectangleShape, Shape, ShapeBorderLine,
-    ShapeElementProperties, ShapeType,
+    ShapeElementProperties, ShapeShadow, ShapeType,
 };
 pub use table::{Table, TableCell, TableProperties};
 pub use text_art::{TextArt, TextArtAlignment, TextArtShape};
 pub use text_box::{Caption, CaptionDirection, TextBox, VerticalAlignment};
 pub use video::{VideoData, VideoType};
-
-use crate::error::Result;
-use crate::primitive::RecordHeader;
-use crate::util::ByteReader;
-
-/// Parses a record header from the reader.
-pub fn parse_record_header(reader: &mut ByteReader) -> Result<RecordHeader> {
-    let raw = reader.read_u32()?;
-    let header = RecordHeader::new(raw);
-
-    if header.has_extended_size() {
-        let extended_size = reader.read_u32()?;
-        Ok(RecordHeader::with_extended_size(raw, extended_size))
-    } else {
-        Ok(header)
-    }
-}
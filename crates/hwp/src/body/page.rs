@@ -201,11 +201,11 @@ impl PageBorderFill {
     ///
     /// Format (per HWP spec - HWPTAG_PAGE_BORDER_FILL):
     /// - UINT32: Properties
+    /// - HWPUNIT16: Left offset
+    /// - HWPUNIT16: Right offset
+    /// - HWPUNIT16: Top offset
+    /// - HWPUNIT16: Bottom offset
     /// - UINT16: BorderFill ID
-    /// - HWPUNIT: Left offset
-    /// - HWPUNIT: Right offset
-    /// - HWPUNIT: Top offset
-    /// - HWPUNIT: Bottom offset
     pub fn from_reader(reader: &mut ByteReader) -> Result<Self> {
         let properties = reader.read_u32()?;
         let position = PageBorderFillPosition::from_raw((properties & 0x01) as u8);
@@ -213,31 +213,14 @@ impl PageBorderFill {
         let include_footer = (properties & 0x04) != 0;
         let fill_behind = (properties & 0x08) != 0;
 
-        let border_fill_id = reader.read_u16()?;
-
-        let offset_left = if reader.remaining() >= 4 {
-            reader.read_hwp_unit()?
-        } else {
-            HwpUnit::default()
-        };
+        let mut read_offset =
+            || -> Result<HwpUnit> { Ok(HwpUnit::new(reader.read_hwp_unit16()?.value() as i32)) };
+        let offset_left = read_offset()?;
+        let offset_right = read_offset()?;
+        let offset_top = read_offset()?;
+        let offset_bottom = read_offset()?;
 
-        let offset_right = if reader.remaining() >= 4 {
-            reader.read_hwp_unit()?
-        } else {
-            HwpUnit::default()
-        };
-
-        let offset_top = if reader.remaining() >= 4 {
-            reader.read_hwp_unit()?
-        } else {
-            HwpUnit::default()
-        };
-
-        let offset_bottom = if reader.remaining() >= 4 {
-            reader.read_hwp_unit()?
-        } else {
-            HwpUnit::default()
-        };
+        let border_fill_id = reader.read_u16()?;
 
         Ok(Self {
             border_fill_id,
@@ -378,13 +361,13 @@ mod tests {
         let mut data = Vec::new();
         // Properties: position=Body (1), include_header (2), include_footer (4)
         data.extend_from_slice(&0x07u32.to_le_bytes());
+        // Offsets: left, right, top, bottom
+        data.extend_from_slice(&100u16.to_le_bytes());
+        data.extend_from_slice(&100u16.to_le_bytes());
+        data.extend_from_slice(&50u16.to_le_bytes());
+        data.extend_from_slice(&50u16.to_le_bytes());
         // BorderFill ID: 1
         data.extend_from_slice(&1u16.to_le_bytes());
-        // Offsets: left, right, top, bottom
-        data.extend_from_slice(&100i32.to_le_bytes());
-        data.extend_from_slice(&100i32.to_le_bytes());
-        data.extend_from_slice(&50i32.to_le_bytes());
-        data.extend_from_slice(&50i32.to_le_bytes());
 
         let mut reader = ByteReader::new(&data);
         let border_fill = PageBorderFill::from_reader(&mut reader).unwrap();
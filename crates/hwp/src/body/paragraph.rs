@@ -8,12 +8,19 @@
 //! - Range tags (bookmarks, hyperlinks, etc.)
 //! - Controls (tables, shapes, etc.)
 
-use crate::error::Result;
+use crate::error::{Error, Result};
+use crate::primitive::RecordTagId;
+use crate::record::RecordTree;
 use crate::util::ByteReader;
+use crate::writer::ByteWriter;
 use primitive::HwpUnit;
 
 use super::control::Control;
 
+/// Flag in the character count of the paragraph header marking the last
+/// paragraph of a list.
+const LAST_IN_LIST: u32 = 0x8000_0000;
+
 /// Break type flags.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub struct BreakType(u8);
@@ -33,6 +40,11 @@ impl BreakType {
         Self(value)
     }
 
+    /// Returns the raw value.
+    pub const fn raw(&self) -> u8 {
+        self.0
+    }
+
     /// Returns true if section break.
     pub const fn is_section_break(&self) -> bool {
         (self.0 & Self::SECTION) != 0
@@ -54,6 +66,50 @@ impl BreakType {
     }
 }
 
+/// Returns the number of UTF-16 code units a character occupies in paragraph text.
+///
+/// Inline and extended control characters carry 12 bytes of data and are
+/// stored as 8 code units, repeating the control code at the end. All other
+/// characters, control characters included, take a single code unit.
+pub const fn text_unit_size(ch: u16) -> usize {
+    match ch {
+        1..=9 | 11 | 12 | 14..=23 => 8,
+        _ => 1,
+    }
+}
+
+/// Returns true if `ch` is an extended control character, which refers to a
+/// control header record of the paragraph.
+pub const fn is_extended_control(ch: u16) -> bool {
+    matches!(ch, 1..=3 | 11 | 12 | 14..=18 | 21..=23)
+}
+
+/// A decoded element of paragraph text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextUnit {
+    /// Regular character.
+    Char(char),
+    /// Control character stored as a single code unit, such as a line break
+    /// (10), paragraph end (13), hyphen (24) or fixed-width space (31).
+    Control(u16),
+    /// Inline control character with its data, such as a tab (9) or a field
+    /// end (4).
+    Inline {
+        /// Control code.
+        code: u16,
+        /// The six code units between the control codes.
+        data: [u16; 6],
+    },
+    /// Extended control character, matched in order with the control headers
+    /// of the paragraph.
+    Extended {
+        /// Control code.
+        code: u16,
+        /// Control ID stored in the character data.
+        control_id: u32,
+    },
+}
+
 /// Paragraph text content.
 ///
 /// Contains the text as UTF-16LE characters. Control characters
@@ -65,37 +121,19 @@ pub struct ParagraphText {
 }
 
 impl ParagraphText {
+    /// Creates paragraph text from raw UTF-16 code units.
+    pub const fn from_raw(raw_chars: Vec<u16>) -> Self {
+        Self { raw_chars }
+    }
+
     /// Parses paragraph text from reader.
+    ///
+    /// `char_count` is the length in code units, control character data included.
     pub fn from_reader(reader: &mut ByteReader, char_count: u32) -> Result<Self> {
-        let mut raw_chars = Vec::with_capacity(char_count as usize);
-
-        // Read char_count UTF-16 characters
-        let mut remaining = char_count;
-        while remaining > 0 {
-            let ch = reader.read_u16()?;
-            raw_chars.push(ch);
-            remaining -= 1;
-
-            // Extended control characters take additional space
-            // inline controls: 8 extra WCHARs (char codes 4, 5, 6, 7, 8, 9, 10, 11)
-            // extended controls: 12 extra WCHARs (char codes 1, 2, 3, 11, 12, 14, 15, 16, 17, 18, 21, 22, 23)
-            if ch <= 31 {
-                let extra = match ch {
-                    // inline controls (4-10)
-                    4..=10 => 7, // 8 total
-                    // extended controls
-                    1..=3 | 11 | 12 | 14..=18 | 21..=23 => 11, // 12 total
-                    _ => 0,
-                };
-                for _ in 0..extra {
-                    if remaining > 0 {
-                        raw_chars.push(reader.read_u16()?);
-                        remaining -= 1;
-                    }
-                }
-            }
+        let mut raw_chars = Vec::with_capacity(reader.capacity_for(char_count as usize, 2));
+        for _ in 0..char_count {
+            raw_chars.push(reader.read_u16()?);
         }
-
         Ok(Self { raw_chars })
     }
 
@@ -104,50 +142,76 @@ impl ParagraphText {
         &self.raw_chars
     }
 
-    /// Converts to a plain text string, skipping control characters.
-    pub fn to_plain_text(&self) -> String {
-        let mut result = String::new();
+    /// Decodes the text into units, each paired with its position in code units.
+    ///
+    /// Positions match those used by character shape references and range tags.
+    pub fn units(&self) -> Vec<(u32, TextUnit)> {
+        let chars = &self.raw_chars;
+        let mut units = Vec::new();
         let mut i = 0;
 
-        while i < self.raw_chars.len() {
-            let ch = self.raw_chars[i];
+        while i < chars.len() {
+            let ch = chars[i];
+            let position = i as u32;
 
             if ch <= 31 {
-                // Skip control characters and their extra data
-                let extra = match ch {
-                    4..=10 => 7,
-                    1..=3 | 11 | 12 | 14..=18 | 21..=23 => 11,
-                    _ => 0,
-                };
-                i += 1 + extra;
-
-                // Add appropriate replacements
-                match ch {
-                    10 => result.push('\t'), // Tab
-                    13 => result.push('\n'), // Paragraph break
-                    24 => result.push(' '),  // Non-breaking space
-                    30 => result.push('-'),  // Non-breaking hyphen
-                    31 => result.push(' '),  // Fixed-width space
-                    _ => {}
-                }
-            } else {
-                // Regular character - decode UTF-16
-                if let Some(c) = char::from_u32(ch as u32) {
-                    result.push(c);
-                } else if (0xD800..=0xDBFF).contains(&ch) && i + 1 < self.raw_chars.len() {
-                    // High surrogate - combine with low surrogate
-                    let high = ch;
-                    let low = self.raw_chars[i + 1];
-                    if (0xDC00..=0xDFFF).contains(&low) {
-                        let code_point =
-                            0x10000 + ((high as u32 - 0xD800) << 10) + (low as u32 - 0xDC00);
-                        if let Some(c) = char::from_u32(code_point) {
-                            result.push(c);
-                        }
-                        i += 1;
+                let size = text_unit_size(ch);
+                if size == 1 {
+                    units.push((position, TextUnit::Control(ch)));
+                } else {
+                    let mut data = [0u16; 6];
+                    for (k, slot) in data.iter_mut().enumerate() {
+                        *slot = chars.get(i + 1 + k).copied().unwrap_or(0);
                     }
+                    let unit = if is_extended_control(ch) {
+                        TextUnit::Extended {
+                            code: ch,
+                            control_id: u32::from(data[0]) | (u32::from(data[1]) << 16),
+                        }
+                    } else {
+                        TextUnit::Inline { code: ch, data }
+                    };
+                    units.push((position, unit));
+                }
+                i += size;
+                continue;
+            }
+
+            if (0xD800..=0xDBFF).contains(&ch)
+                && let Some(&low) = chars.get(i + 1)
+                && (0xDC00..=0xDFFF).contains(&low)
+            {
+                let code_point = 0x10000 + ((ch as u32 - 0xD800) << 10) + (low as u32 - 0xDC00);
+                if let Some(c) = char::from_u32(code_point) {
+                    units.push((position, TextUnit::Char(c)));
                 }
-                i += 1;
+                i += 2;
+                continue;
+            }
+
+            if let Some(c) = char::from_u32(ch as u32) {
+                units.push((position, TextUnit::Char(c)));
+            }
+            i += 1;
+        }
+
+        units
+    }
+
+    /// Converts to a plain text string, skipping control characters.
+    pub fn to_plain_text(&self) -> String {
+        let mut result = String::new();
+
+        for (_, unit) in self.units() {
+            match unit {
+                TextUnit::Char(c) => result.push(c),
+                TextUnit::Inline { code: 9, .. } => result.push('\t'),
+                TextUnit::Control(10) => result.push('\n'), // Line break
+                TextUnit::Control(13) => result.push('\n'), // Paragraph break
+                TextUnit::Control(24) => result.push('-'),  // Hyphen
+                TextUnit::Control(30) => result.push(' '),  // Keep-word space
+                TextUnit::Control(31) => result.push(' '),  // Fixed-width space
+                _ => {}
             }
         }
 
@@ -279,6 +343,12 @@ pub struct Paragraph {
     range_tags: Vec<RangeTag>,
     /// Controls in this paragraph.
     controls: Vec<Control>,
+    /// Original records of this paragraph, kept in retention mode.
+    raw_records: Option<Vec<u8>>,
+    /// Whether the paragraph was changed after its records were retained.
+    modified: bool,
+    /// Number of controls the paragraph had when its records were retained.
+    retained_controls: usize,
 }
 
 impl Paragraph {
@@ -305,6 +375,9 @@ impl Paragraph {
             line_segments: Vec::new(),
             range_tags: Vec::new(),
             controls: Vec::new(),
+            raw_records: None,
+            modified: false,
+            retained_controls: 0,
         }
     }
 
@@ -312,7 +385,7 @@ impl Paragraph {
     pub fn from_reader(reader: &mut ByteReader, data_size: u32) -> Result<Self> {
         // Character count (with possible flag in high bit)
         let raw_char_count = reader.read_u32()?;
-        let character_count = raw_char_count & 0x7FFFFFFF;
+        let character_count = raw_char_count & !LAST_IN_LIST;
 
         let control_mask = reader.read_u32()?;
         let paragraph_shape_id = reader.read_u16()?;
@@ -386,6 +459,7 @@ impl Paragraph {
     /// Sets the text content.
     pub fn set_text(&mut self, text: ParagraphText) {
         self.text = Some(text);
+        self.modified = true;
     }
 
     /// Returns character shape references.
@@ -396,6 +470,7 @@ impl Paragraph {
     /// Adds a character shape reference.
     pub fn add_character_shape_reference(&mut self, reference: CharacterShapeReference) {
         self.character_shape_references.push(reference);
+        self.modified = true;
     }
 
     /// Returns line segments.
@@ -406,6 +481,7 @@ impl Paragraph {
     /// Adds a line segment.
     pub fn add_line_segment(&mut self, segment: LineSegment) {
         self.line_segments.push(segment);
+        self.modified = true;
     }
 
     /// Returns range tags.
@@ -416,6 +492,7 @@ impl Paragraph {
     /// Adds a range tag.
     pub fn add_range_tag(&mut self, tag: RangeTag) {
         self.range_tags.push(tag);
+        self.modified = true;
     }
 
     /// Returns controls.
@@ -426,6 +503,7 @@ impl Paragraph {
     /// Adds a control.
     pub fn add_control(&mut self, control: Control) {
         self.controls.push(control);
+        self.modified = true;
     }
 
     /// Extracts plain text from this paragraph.
@@ -435,4 +513,224 @@ impl Paragraph {
             .map(|t| t.to_plain_text())
             .unwrap_or_default()
     }
+
+    /// Returns the original records of this paragraph, starting with its header.
+    ///
+    /// Only available when the document was read with record retention and the
+    /// paragraph has not been changed since.
+    pub fn raw_records(&self) -> Option<&[u8]> {
+        self.raw_records.as_deref().filter(|_| !self.modified)
+    }
+
+    /// Attaches the original records of this paragraph and of its controls.
+    ///
+    /// Controls only get their records when the control headers line up with
+    /// the parsed controls one to one.
+    pub(crate) fn retain_records(&mut self, data: Vec<u8>) {
+        if let Ok(tree) = RecordTree::parse(&data) {
+            let control_spans: Vec<_> = tree
+                .roots
+                .first()
+                .into_iter()
+                .flat_map(|root| &root.children)
+                .filter(|child| child.record.tag_id() == Some(RecordTagId::ControlHeader))
+                .map(|child| child.span())
+                .collect();
+            if control_spans.len() == self.controls.len() {
+                for (control, span) in self.controls.iter_mut().zip(control_spans) {
+                    control.retain_records(data[span].to_vec());
+                }
+            }
+        }
+        self.raw_records = Some(data);
+        self.modified = false;
+        self.retained_controls = self.controls.len();
+    }
+
+    /// Writes this paragraph as top-level records.
+    ///
+    /// `last_in_list` sets the flag that marks the last paragraph of the list.
+    /// Untouched paragraphs are copied verbatim apart from that flag. Changed
+    /// paragraphs get freshly encoded header, text, shape, line and range
+    /// records; other original child records are copied as they were. Controls
+    /// are written from their retained records in the order their extended
+    /// control characters appear in the text.
+    pub(crate) fn write_records(&self, writer: &mut ByteWriter, last_in_list: bool) -> Result<()> {
+        if let Some(data) = self.raw_records() {
+            let mut data = data.to_vec();
+            set_last_in_list(&mut data, last_in_list);
+            writer.write_bytes(&data);
+            return Ok(());
+        }
+
+        let original = match &self.raw_records {
+            Some(data) => RecordTree::parse(data)?,
+            None => RecordTree::default(),
+        };
+        let original_root = original.roots.first();
+        let original_header = original_root
+            .map(|root| root.record.data())
+            .unwrap_or_default();
+
+        writer.write_record(
+            RecordTagId::ParagraphHeader,
+            0,
+            &self.header_bytes(original_header, last_in_list),
+        );
+
+        if let Some(text) = &self.text {
+            let mut data = ByteWriter::new();
+            for &ch in text.raw_chars() {
+                data.write_u16(ch);
+            }
+            writer.write_record(RecordTagId::ParagraphText, 1, &data.into_bytes());
+        }
+        if !self.character_shape_references.is_empty() {
+            let mut data = ByteWriter::new();
+            for reference in &self.character_shape_references {
+                data.write_u32(reference.position);
+                data.write_u32(reference.character_shape_id);
+            }
+            writer.write_record(RecordTagId::ParagraphCharacterShape, 1, &data.into_bytes());
+        }
+        if !self.line_segments.is_empty() {
+            let mut data = ByteWriter::new();
+            for segment in &self.line_segments {
+                data.write_u32(segment.text_start_position);
+                data.write_i32(segment.vertical_position.value());
+                data.write_i32(segment.line_height.value());
+                data.write_i32(segment.text_height.value());
+                data.write_i32(segment.baseline_distance.value());
+                data.write_i32(segment.line_spacing.value());
+                data.write_i32(segment.column_start_position.value());
+                data.write_i32(segment.segment_width.value());
+                data.write_u32(segment.tag);
+            }
+            writer.write_record(RecordTagId::ParagraphLineSegment, 1, &data.into_bytes());
+        }
+        if !self.range_tags.is_empty() {
+            let mut data = ByteWriter::new();
+            for tag in &self.range_tags {
+                data.write_u32(tag.start_position);
+                data.write_u32(tag.end_position);
+                data.write_bytes(&tag.tag);
+                data.write_u8(0);
+            }
+            writer.write_record(RecordTagId::ParagraphRangeTag, 1, &data.into_bytes());
+        }
+
+        // Records the model does not cover, such as newer record types
+        let mut original_controls = Vec::new();
+        if let (Some(root), Some(data)) = (original_root, &self.raw_records) {
+            for child in &root.children {
+                match child.record.tag_id() {
+                    Some(RecordTagId::ControlHeader) => original_controls.push(&data[child.span()]),
+                    Some(
+                        RecordTagId::ParagraphText
+                        | RecordTagId::ParagraphCharacterShape
+                        | RecordTagId::ParagraphLineSegment
+                        | RecordTagId::ParagraphRangeTag,
+                    ) => {}
+                    _ => writer.write_bytes(&data[child.span()]),
+                }
+            }
+        }
+
+        // Original control records stand in for retained controls changed since
+        let aligned = original_controls.len() == self.retained_controls;
+        if !aligned {
+            for data in &original_controls {
+                writer.write_bytes(data);
+            }
+        }
+        for index in self.control_order() {
+            if !aligned && index < self.retained_controls {
+                continue;
+            }
+            let control = &self.controls[index];
+            let data = control
+                .raw_records()
+                .or_else(|| original_controls.get(index).copied().filter(|_| aligned))
+                .ok_or_else(|| Error::RecordsNotRetained {
+                    description: format!("control {}", control.id().as_str()),
+                })?;
+            writer.write_bytes(data);
+        }
+
+        Ok(())
+    }
+
+    /// Returns control indices in the order of their extended control characters.
+    ///
+    /// Each character takes the first unused control with the same id; controls
+    /// without a matching character follow in their own order.
+    fn control_order(&self) -> Vec<usize> {
+        let mut order = Vec::with_capacity(self.controls.len());
+        let mut used = vec![false; self.controls.len()];
+        let units = self
+            .text
+            .as_ref()
+            .map(ParagraphText::units)
+            .unwrap_or_default();
+        for (_, unit) in units {
+            let TextUnit::Extended { control_id, .. } = unit else {
+                continue;
+            };
+            let next = (0..self.controls.len())
+                .find(|&index| !used[index] && self.controls[index].id().raw() == control_id);
+            if let Some(index) = next {
+                used[index] = true;
+                order.push(index);
+            }
+        }
+        order.extend((0..self.controls.len()).filter(|&index| !used[index]));
+        order
+    }
+
+    /// Encodes the paragraph header, keeping trailing bytes of `original`.
+    fn header_bytes(&self, original: &[u8], last_in_list: bool) -> Vec<u8> {
+        let character_count = self
+            .text
+            .as_ref()
+            .map_or(self.character_count, |text| text.raw_chars().len() as u32);
+        let last_in_list = if last_in_list { LAST_IN_LIST } else { 0 };
+
+        let mut data = ByteWriter::new();
+        data.write_u32(character_count | last_in_list);
+        data.write_u32(self.control_mask);
+        data.write_u16(self.paragraph_shape_id);
+        data.write_u8(self.style_id);
+        data.write_u8(self.break_type.raw());
+        data.write_u16(self.character_shape_references.len() as u16);
+        data.write_u16(self.range_tags.len() as u16);
+        data.write_u16(self.line_segments.len() as u16);
+        data.write_u32(self.instance_id);
+        if let Some(merge) = self.track_change_merge {
+            data.write_u16(merge);
+        }
+
+        let mut bytes = data.into_bytes();
+        if let Some(trailing) = original.get(bytes.len()..) {
+            bytes.extend_from_slice(trailing);
+        }
+        bytes
+    }
+}
+
+/// Sets or clears the last-in-list flag in the paragraph header record at the
+/// start of `records`.
+fn set_last_in_list(records: &mut [u8], last_in_list: bool) {
+    let Some(header) = records.get(..4) else {
+        return;
+    };
+    let header = u32::from_le_bytes([header[0], header[1], header[2], header[3]]);
+    let offset = if header >> 20 == 0xFFF { 8 } else { 4 };
+    // The flag is the top bit of the first DWORD of the record data
+    if let Some(flags) = records.get_mut(offset + 3) {
+        if last_in_list {
+            *flags |= 0x80;
+        } else {
+            *flags &= !0x80;
+        }
+    }
 }
@@ -46,7 +46,7 @@ pub struct PictureEffect {
     pub contrast: i8,
     /// Effect type.
     pub effect_type: PictureEffectType,
-    /// Binary picture reference (BinData ID) for pattern effect.
+    /// Binary picture reference (BinData ID).
     pub binary_pattern: u16,
 }
 
@@ -171,7 +171,9 @@ pub struct PictureProperties {
     pub border_transparency: u8,
     /// Instance ID.
     pub instance_id: u32,
-    /// Image dimension in pixels.
+    /// Picture effect flags (shadow, glow, soft edge, reflection).
+    pub effect_flags: u32,
+    /// Original image dimension in HWPUNIT.
     pub image_dimension: (u32, u32),
     /// Transparent color (COLORREF, version 5.0.3.0+).
     pub transparent_color: Option<u32>,
@@ -192,7 +194,7 @@ impl PictureProperties {
         let crop = ImageCrop::from_reader(reader)?;
         let inner_margin = InnerMargin::from_reader(reader)?;
         let effect = PictureEffect::from_reader(reader)?;
-        let binary_data_id = reader.read_u16()?;
+        let binary_data_id = effect.binary_pattern;
         let border_transparency = reader.read_u8()?;
 
         // Version 5.0.2.4+ fields
@@ -202,8 +204,15 @@ impl PictureProperties {
             0
         };
 
+        // 그림 효과 정보 (표 108): 효과 데이터가 있으면 이후 필드의 위치를 알 수 없음
+        let effect_flags = if reader.remaining() >= 4 {
+            reader.read_u32()?
+        } else {
+            0
+        };
+
         // Version 5.0.2.7+ fields
-        let image_dimension = if reader.remaining() >= 8 {
+        let image_dimension = if effect_flags == 0 && reader.remaining() >= 8 {
             let width = reader.read_u32()?;
             let height = reader.read_u32()?;
             (width, height)
@@ -212,7 +221,7 @@ impl PictureProperties {
         };
 
         // Version 5.0.3.0+ fields
-        let transparent_color = if reader.remaining() >= 4 {
+        let transparent_color = if effect_flags == 0 && reader.remaining() >= 4 {
             Some(reader.read_u32()?)
         } else {
             None
@@ -229,6 +238,7 @@ impl PictureProperties {
             binary_data_id,
             border_transparency,
             instance_id,
+            effect_flags,
             image_dimension,
             transparent_color,
         })
@@ -289,17 +299,17 @@ impl Picture {
         self.properties.binary_data_id
     }
 
-    /// Returns the image dimensions in pixels.
+    /// Returns the original image dimensions in HWPUNIT.
     pub const fn dimensions(&self) -> (u32, u32) {
         self.properties.image_dimension
     }
 
-    /// Returns the image width in pixels.
+    /// Returns the original image width in HWPUNIT.
     pub const fn width(&self) -> u32 {
         self.properties.image_dimension.0
     }
 
-    /// Returns the image height in pixels.
+    /// Returns the original image height in HWPUNIT.
     pub const fn height(&self) -> u32 {
         self.properties.image_dimension.1
     }
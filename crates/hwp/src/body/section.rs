@@ -5,9 +5,11 @@
 #![allow(clippy::collapsible_if)]
 
 use super::chart::ChartData;
+use super::compose::Compose;
 use super::container::ShapeContainer;
 use super::control::{Control, ControlContent, ControlId, ControlType};
 use super::control_data::ControlData;
+use super::dutmal::Dutmal;
 use super::equation::Equation;
 use super::field::{Field, FieldType};
 use super::footnote::{Endnote, EndnoteShape, Footnote, FootnoteShape};
@@ -18,19 +20,23 @@ use super::list_header::ListHeader;
 use super::memo::{Memo, MemoShape};
 use super::page::{PageBorderFill, PageDefinition};
 use super::paragraph::{CharacterShapeReference, LineSegment, Paragraph, ParagraphText, RangeTag};
-use super::parse_record_header;
 use super::picture::{ImageFlip, OleObject, Picture, PictureProperties};
 use super::section_definition::{ColumnDefinition, SectionDefinition};
 use super::shape::{
     ArcShape, CurveShape, DrawingObjectCommon, EllipseShape, LineShape, PolygonShape,
-    RectangleShape, Shape, ShapeElementProperties, ShapeType,
+    RectangleShape, Shape, ShapeElementProperties, ShapeTextBox, ShapeType,
 };
 use super::table::{Table, TableCell};
 use super::text_art::TextArt;
+use super::text_box::Caption;
 use super::video::VideoData;
+use crate::diagnostic::{ParseDiagnostic, ParseOptions, parse_records};
 use crate::error::Result;
-use crate::primitive::RecordTagId;
+use crate::primitive::{RecordHeader, RecordTagId};
+use crate::record::RecordTree;
 use crate::util::ByteReader;
+use crate::writer::ByteWriter;
+use primitive::{LimitKind, Limits};
 
 /// Parsing context for nested content.
 ///
@@ -62,6 +68,13 @@ enum ParsingContext {
         /// Expected number of paragraphs.
         paragraph_count: u16,
     },
+    /// Inside the caption of a table, picture, shape or equation.
+    Caption {
+        /// Expected number of paragraphs.
+        paragraph_count: u16,
+        /// Caption properties parsed from ListHeader.
+        caption: Caption,
+    },
     /// Inside a shape (for shapes with text).
     Shape {
         /// Expected number of paragraphs.
@@ -80,751 +93,871 @@ impl ParsingContext {
             Self::HeaderFooter { paragraph_count } => Some(*paragraph_count),
             Self::FootnoteEndnote { paragraph_count } => Some(*paragraph_count),
             Self::TextBox { paragraph_count } => Some(*paragraph_count),
+            Self::Caption {
+                paragraph_count, ..
+            } => Some(*paragraph_count),
             Self::Shape { paragraph_count } => Some(*paragraph_count),
         }
     }
 }
 
-/// A section in the document.
+/// A drawing object whose records are still being read.
+#[derive(Debug)]
+struct ShapeObject {
+    /// Element properties, line, fill, text and geometry of the object.
+    shape: Shape,
+    /// Non-geometric content (picture, OLE object, chart, video, text art).
+    content: Option<ControlContent>,
+    /// Members of a group object.
+    children: Vec<Control>,
+}
+
+impl ShapeObject {
+    /// Returns the content of the finished object.
+    fn into_content(self) -> ControlContent {
+        let element_type = ControlId::from_raw(self.shape.element_properties.element_type);
+        if element_type == ControlId::SHAPE_GROUP
+            || matches!(self.shape.shape_type, ShapeType::Container(_))
+        {
+            let mut container = ShapeContainer::default();
+            for child in self.children {
+                container.add_child(child);
+            }
+            return ControlContent::Container(container);
+        }
+        match self.content {
+            Some(content) => content,
+            None => ControlContent::Shape(self.shape),
+        }
+    }
+}
+
+/// An open record scope of a section stream.
 ///
-/// Sections are the top-level containers in the body text.
-/// Each section can have different page settings.
-#[derive(Debug, Clone, Default)]
-pub struct Section {
-    /// Paragraphs in this section.
-    paragraphs: Vec<Paragraph>,
-    /// Page definition (size, margins, orientation).
-    page_definition: Option<PageDefinition>,
-    /// Footnote shape settings.
-    footnote_shape: Option<FootnoteShape>,
-    /// Endnote shape settings.
-    endnote_shape: Option<EndnoteShape>,
-    /// Page border and fill settings.
-    page_border_fill: Option<PageBorderFill>,
-    /// Memos (annotations) in this section.
-    memos: Vec<Memo>,
+/// Records form a tree through their levels: a record belongs to the
+/// closest preceding record with a lower level. List headers are the
+/// exception — the paragraphs of a list are siblings of its header.
+#[derive(Debug)]
+enum Frame {
+    /// A list of paragraphs (the section itself, a cell, a caption, ...).
+    List {
+        level: u16,
+        context: ParsingContext,
+        paragraphs: Vec<Paragraph>,
+    },
+    /// A paragraph and its text, shape references and line segments.
+    Paragraph { level: u16, paragraph: Paragraph },
+    /// A control of a paragraph.
+    Control { level: u16, control: Box<Control> },
+    /// A shape component of a drawing object control.
+    Shape {
+        level: u16,
+        object: Box<ShapeObject>,
+    },
 }
 
-impl Section {
-    /// Creates a new empty section.
-    pub fn new() -> Self {
-        Self::default()
+impl Frame {
+    /// Returns true if a record with `tag` at `level` ends this scope.
+    fn is_closed_by(&self, tag: Option<RecordTagId>, level: u16) -> bool {
+        match self {
+            Self::List { level: own, .. } => {
+                level < *own || (level == *own && tag != Some(RecordTagId::ParagraphHeader))
+            }
+            Self::Paragraph { level: own, .. }
+            | Self::Control { level: own, .. }
+            | Self::Shape { level: own, .. } => level <= *own,
+        }
     }
+}
 
-    /// Parses a section from bytes.
-    pub fn from_bytes(data: &[u8]) -> Result<Self> {
-        let mut reader = ByteReader::new(data);
-        let mut section = Section::new();
+/// Parsing state for a section stream.
+struct SectionParser {
+    section: Section,
+    /// Open scopes; the bottom frame holds the section's own paragraphs.
+    frames: Vec<Frame>,
+    current_memo_shape: Option<MemoShape>,
+    /// Resource limits (nesting depth of lists)
+    limits: Limits,
+}
 
-        // Parsing state
-        let mut current_paragraph: Option<Paragraph> = None;
-        let mut current_control: Option<Control> = None;
-        let mut current_memo_shape: Option<MemoShape> = None;
-
-        // Stack for nested content parsing
-        let mut context_stack: Vec<ParsingContext> = vec![ParsingContext::Section];
-        let mut nested_paragraphs: Vec<Vec<Paragraph>> = vec![Vec::new()];
-        let mut cell_paragraph_counts: Vec<u16> = vec![0];
-
-        while !reader.is_empty() {
-            // Try to parse record header - if not enough data, stop parsing
-            let header = match parse_record_header(&mut reader) {
-                Ok(h) => h,
-                Err(_) => break, // Stop if we can't read a valid record header
-            };
+impl SectionParser {
+    fn new(limits: &Limits) -> Self {
+        Self {
+            section: Section::new(),
+            frames: vec![Frame::List {
+                level: 0,
+                context: ParsingContext::Section,
+                paragraphs: Vec::new(),
+            }],
+            current_memo_shape: None,
+            limits: *limits,
+        }
+    }
 
-            // Check if we have enough data for the record - if not, stop gracefully
-            let data_size = header.data_size() as usize;
-            if reader.remaining() < data_size {
-                // Not enough data remaining - stop parsing this section gracefully
-                // This can happen with truncated streams or very large documents
+    /// Closes the scopes that end before a record with `tag` at `level`.
+    fn close_frames(&mut self, tag: Option<RecordTagId>, level: u16) {
+        while self.frames.len() > 1 {
+            let Some(frame) = self.frames.last() else {
+                break;
+            };
+            if !frame.is_closed_by(tag, level) {
                 break;
             }
+            if let Some(frame) = self.frames.pop() {
+                self.attach(frame);
+            }
+        }
+    }
 
-            let record_data = reader.read_bytes(data_size)?;
-            let mut record_reader = ByteReader::new(record_data);
-
-            match header.tag_id() {
-                Some(RecordTagId::ParagraphHeader) => {
-                    // Save previous paragraph
-                    if let Some(para) = current_paragraph.take() {
-                        Self::save_paragraph(
-                            &mut section,
-                            &mut context_stack,
-                            &mut nested_paragraphs,
-                            &mut cell_paragraph_counts,
-                            &mut current_control,
-                            para,
-                        );
-                    }
-
-                    current_paragraph = Some(Paragraph::from_reader(
-                        &mut record_reader,
-                        header.data_size(),
-                    )?);
+    /// Hands a finished scope to its parent.
+    fn attach(&mut self, frame: Frame) {
+        let parent = self.frames.last_mut();
+        match (frame, parent) {
+            (Frame::Paragraph { paragraph, .. }, Some(Frame::List { paragraphs, .. })) => {
+                paragraphs.push(paragraph);
+            }
+            (Frame::Paragraph { paragraph, .. }, Some(Frame::Control { control, .. })) => {
+                control.add_child(paragraph);
+            }
+            (Frame::Paragraph { paragraph, .. }, Some(Frame::Shape { object, .. })) => {
+                object.shape.add_paragraph(paragraph);
+            }
+            (Frame::Control { control, .. }, Some(Frame::Paragraph { paragraph, .. })) => {
+                paragraph.add_control(*control);
+            }
+            (
+                Frame::List {
+                    context,
+                    paragraphs,
+                    ..
+                },
+                Some(Frame::Control { control, .. }),
+            ) => Self::attach_list(control, context, paragraphs),
+            (
+                Frame::List {
+                    context,
+                    paragraphs,
+                    ..
+                },
+                Some(Frame::Shape { object, .. }),
+            ) => {
+                if let Some(count) = context.paragraph_count() {
+                    object.shape.set_expected_paragraph_count(count);
                 }
-
-                Some(RecordTagId::ParagraphText) => {
-                    if let Some(ref mut para) = current_paragraph {
-                        let text =
-                            ParagraphText::from_reader(&mut record_reader, para.character_count())?;
-                        para.set_text(text);
-                    }
+                for paragraph in paragraphs {
+                    object.shape.add_paragraph(paragraph);
                 }
+            }
+            (Frame::Shape { object, .. }, Some(Frame::Control { control, .. })) => {
+                control.set_content(object.into_content());
+            }
+            (Frame::Shape { object, .. }, Some(Frame::Shape { object: group, .. })) => {
+                let element_type = object.shape.element_properties.element_type;
+                let mut child = Control::new(ControlId::from_raw(element_type));
+                child.set_content(object.into_content());
+                group.children.push(child);
+            }
+            // 부모가 없는 레코드는 버림
+            _ => {}
+        }
+    }
 
-                Some(RecordTagId::ParagraphCharacterShape) => {
-                    if let Some(ref mut para) = current_paragraph {
-                        let count = header.data_size() / 8;
-                        for _ in 0..count {
-                            let reference =
-                                CharacterShapeReference::from_reader(&mut record_reader)?;
-                            para.add_character_shape_reference(reference);
-                        }
-                    }
+    /// Stores the paragraphs of a finished list in its control.
+    fn attach_list(control: &mut Control, context: ParsingContext, paragraphs: Vec<Paragraph>) {
+        match context {
+            ParsingContext::TableCell { mut cell, .. } => {
+                cell.paragraphs = paragraphs;
+                if let Some(table) = control.as_table_mut() {
+                    table.add_cell(cell);
                 }
-
-                Some(RecordTagId::ParagraphLineSegment) => {
-                    if let Some(ref mut para) = current_paragraph {
-                        let count = header.data_size() as usize / LineSegment::SIZE;
-                        for _ in 0..count {
-                            let segment = LineSegment::from_reader(&mut record_reader)?;
-                            para.add_line_segment(segment);
-                        }
+            }
+            ParsingContext::Caption { mut caption, .. } => {
+                caption.set_paragraphs(paragraphs);
+                control.set_caption(caption);
+            }
+            ParsingContext::HeaderFooter { .. } => {
+                if let Some(header) = control.as_header_mut() {
+                    header.set_paragraphs(paragraphs);
+                } else if let Some(footer) = control.as_footer_mut() {
+                    footer.set_paragraphs(paragraphs);
+                } else {
+                    for para in paragraphs {
+                        control.add_child(para);
                     }
                 }
-
-                Some(RecordTagId::ParagraphRangeTag) => {
-                    if let Some(ref mut para) = current_paragraph {
-                        let count = header.data_size() as usize / RangeTag::SIZE;
-                        for _ in 0..count {
-                            let tag = RangeTag::from_reader(&mut record_reader)?;
-                            para.add_range_tag(tag);
-                        }
+            }
+            ParsingContext::FootnoteEndnote { .. } => {
+                if let Some(footnote) = control.as_footnote_mut() {
+                    footnote.set_paragraphs(paragraphs);
+                } else if let Some(endnote) = control.as_endnote_mut() {
+                    endnote.set_paragraphs(paragraphs);
+                } else {
+                    for para in paragraphs {
+                        control.add_child(para);
                     }
                 }
+            }
+            ParsingContext::TextBox { .. }
+            | ParsingContext::Shape { .. }
+            | ParsingContext::Section => {
+                for para in paragraphs {
+                    control.add_child(para);
+                }
+            }
+        }
+    }
 
-                Some(RecordTagId::ControlHeader) => {
-                    // Save previous control if exists
-                    if let Some(ctrl) = current_control.take() {
-                        if let Some(ref mut para) = current_paragraph {
-                            para.add_control(ctrl);
-                        }
-                    }
+    /// Returns the innermost open paragraph.
+    fn paragraph_mut(&mut self) -> Option<&mut Paragraph> {
+        match self.frames.last_mut() {
+            Some(Frame::Paragraph { paragraph, .. }) => Some(paragraph),
+            _ => None,
+        }
+    }
 
-                    let control_id = ControlId::from_reader(&mut record_reader)?;
-                    let remaining = record_data.len().saturating_sub(4);
-                    let control_data = if remaining > 0 {
-                        record_reader.read_bytes(remaining)?.to_vec()
-                    } else {
-                        Vec::new()
-                    };
+    /// Returns the innermost open control.
+    fn control_mut(&mut self) -> Option<&mut Control> {
+        match self.frames.last_mut() {
+            Some(Frame::Control { control, .. }) => Some(control.as_mut()),
+            _ => None,
+        }
+    }
 
-                    let mut ctrl = Control::with_data(control_id, control_data.clone());
-
-                    // Initialize content for Header/Footer controls
-                    match ctrl.control_type() {
-                        ControlType::Header => {
-                            // Parse target from control data (first byte)
-                            let target = if !control_data.is_empty() {
-                                HeaderFooterTarget::from_raw(control_data[0])
-                            } else {
-                                HeaderFooterTarget::BothPages
-                            };
-                            ctrl.set_content(ControlContent::Header(Header::new(target)));
-                        }
-                        ControlType::Footer => {
-                            let target = if !control_data.is_empty() {
-                                HeaderFooterTarget::from_raw(control_data[0])
-                            } else {
-                                HeaderFooterTarget::BothPages
-                            };
-                            ctrl.set_content(ControlContent::Footer(Footer::new(target)));
-                        }
-                        ControlType::Footnote => {
-                            // Parse note number from control data (bytes 4-5, u16)
-                            let number = if control_data.len() >= 6 {
-                                u16::from_le_bytes([control_data[4], control_data[5]])
-                            } else {
-                                0
-                            };
-                            ctrl.set_content(ControlContent::Footnote(Footnote::new(number)));
-                        }
-                        ControlType::Endnote => {
-                            let number = if control_data.len() >= 6 {
-                                u16::from_le_bytes([control_data[4], control_data[5]])
-                            } else {
-                                0
-                            };
-                            ctrl.set_content(ControlContent::Endnote(Endnote::new(number)));
-                        }
-                        ControlType::SectionDefinition => {
-                            // Parse section definition from control data
-                            if !control_data.is_empty() {
-                                let mut data_reader = ByteReader::new(&control_data);
-                                if let Ok(secd) = SectionDefinition::from_reader(&mut data_reader) {
-                                    ctrl.set_content(ControlContent::SectionDefinition(secd));
-                                }
-                            }
-                        }
-                        ControlType::ColumnDefinition => {
-                            // Parse column definition from control data
-                            if !control_data.is_empty() {
-                                let mut data_reader = ByteReader::new(&control_data);
-                                if let Ok(cold) = ColumnDefinition::from_reader(&mut data_reader) {
-                                    ctrl.set_content(ControlContent::ColumnDefinition(cold));
-                                }
-                            }
-                        }
-                        _ => {}
-                    }
+    /// Returns the innermost open shape component.
+    fn shape_mut(&mut self) -> Option<&mut ShapeObject> {
+        match self.frames.last_mut() {
+            Some(Frame::Shape { object, .. }) => Some(object),
+            _ => None,
+        }
+    }
 
-                    current_control = Some(ctrl);
-                }
+    /// Stores object content in the open shape component or control.
+    fn set_object_content(&mut self, content: ControlContent) {
+        match self.frames.last_mut() {
+            Some(Frame::Shape { object, .. }) => object.content = Some(content),
+            Some(Frame::Control { control, .. }) => control.set_content(content),
+            _ => {}
+        }
+    }
 
-                Some(RecordTagId::ListHeader) => {
-                    // ListHeader marks the beginning of nested content
-                    if let Ok(list_header) = ListHeader::from_reader(&mut record_reader) {
-                        let para_count = list_header.paragraph_count();
-
-                        // Determine context type based on current control
-                        if let Some(ref mut ctrl) = current_control {
-                            match ctrl.control_type() {
-                                ControlType::Table => {
-                                    // This is a table cell - read cell properties after ListHeader
-                                    // Cell properties follow ListHeader in the record
-                                    let cell = if record_reader.remaining() >= TableCell::SIZE {
-                                        TableCell::from_reader(&mut record_reader)
-                                            .unwrap_or_default()
-                                    } else {
-                                        TableCell::default()
-                                    };
-
-                                    context_stack.push(ParsingContext::TableCell {
-                                        paragraph_count: para_count,
-                                        cell,
-                                    });
-                                    nested_paragraphs.push(Vec::new());
-                                    cell_paragraph_counts.push(0);
-                                }
-                                ControlType::Header | ControlType::Footer => {
-                                    context_stack.push(ParsingContext::HeaderFooter {
-                                        paragraph_count: para_count,
-                                    });
-                                    nested_paragraphs.push(Vec::new());
-                                    cell_paragraph_counts.push(0);
-                                }
-                                ControlType::Footnote | ControlType::Endnote => {
-                                    context_stack.push(ParsingContext::FootnoteEndnote {
-                                        paragraph_count: para_count,
-                                    });
-                                    nested_paragraphs.push(Vec::new());
-                                    cell_paragraph_counts.push(0);
-                                }
-                                ControlType::DrawingObject => {
-                                    // Shape with text content
-                                    if let Some(shape) = ctrl.as_shape_mut() {
-                                        shape.set_expected_paragraph_count(para_count);
-                                    }
-                                    context_stack.push(ParsingContext::Shape {
-                                        paragraph_count: para_count,
-                                    });
-                                    nested_paragraphs.push(Vec::new());
-                                    cell_paragraph_counts.push(0);
-                                }
-                                _ => {
-                                    // Generic nested content (text box, caption, etc.)
-                                    context_stack.push(ParsingContext::TextBox {
-                                        paragraph_count: para_count,
-                                    });
-                                    nested_paragraphs.push(Vec::new());
-                                    cell_paragraph_counts.push(0);
-                                }
-                            }
-                        }
-                    }
-                }
+    /// Returns the object content of the open shape component or control.
+    fn object_content_mut(&mut self) -> Option<&mut ControlContent> {
+        match self.frames.last_mut() {
+            Some(Frame::Shape { object, .. }) => object.content.as_mut(),
+            Some(Frame::Control { control, .. }) => control.content_mut(),
+            _ => None,
+        }
+    }
 
-                Some(RecordTagId::PageDefinition) => {
-                    if let Ok(page_def) = PageDefinition::from_reader(&mut record_reader) {
-                        section.page_definition = Some(page_def);
-                    }
+    /// Parses a single record.
+    fn parse_record(&mut self, header: RecordHeader, record_data: &[u8]) -> Result<()> {
+        let mut record_reader = ByteReader::new(record_data);
+        let tag = header.tag_id();
+        let level = header.level();
+
+        // 구역 레벨 레코드는 열린 범위와 무관하게 처리
+        match tag {
+            Some(RecordTagId::PageDefinition) => {
+                let page_def = PageDefinition::from_reader(&mut record_reader)?;
+                self.section.page_definition = Some(page_def);
+                return Ok(());
+            }
+            Some(RecordTagId::FootnoteShape) => {
+                // HWP 스펙: 각주 모양과 미주 모양이 같은 태그를 사용하며 연속으로 나옴
+                // 첫 번째는 각주, 두 번째는 미주
+                if self.section.footnote_shape.is_none() {
+                    let footnote_shape = FootnoteShape::from_reader(&mut record_reader)?;
+                    self.section.footnote_shape = Some(footnote_shape);
+                } else if self.section.endnote_shape.is_none() {
+                    let endnote_shape = EndnoteShape::from_reader(&mut record_reader)?;
+                    self.section.endnote_shape = Some(endnote_shape);
                 }
-
-                Some(RecordTagId::FootnoteShape) => {
-                    // HWP 스펙: 각주 모양과 미주 모양이 같은 태그를 사용하며 연속으로 나옴
-                    // 첫 번째는 각주, 두 번째는 미주
-                    if section.footnote_shape.is_none() {
-                        if let Ok(footnote_shape) = FootnoteShape::from_reader(&mut record_reader) {
-                            section.footnote_shape = Some(footnote_shape);
-                        }
-                    } else if section.endnote_shape.is_none() {
-                        if let Ok(endnote_shape) = EndnoteShape::from_reader(&mut record_reader) {
-                            section.endnote_shape = Some(endnote_shape);
-                        }
-                    }
+                return Ok(());
+            }
+            Some(RecordTagId::PageBorderFill) => {
+                // 양쪽/짝수/홀수 쪽 순서로 나오며 첫 번째를 사용
+                if self.section.page_border_fill.is_none() {
+                    let border_fill = PageBorderFill::from_reader(&mut record_reader)?;
+                    self.section.page_border_fill = Some(border_fill);
                 }
-
-                Some(RecordTagId::PageBorderFill) => {
-                    if let Ok(border_fill) = PageBorderFill::from_reader(&mut record_reader) {
-                        section.page_border_fill = Some(border_fill);
+                return Ok(());
+            }
+            Some(RecordTagId::MemoShape) => {
+                // MemoShape defines the visual properties of a memo
+                // Store it for association with the following MemoList
+                let memo_shape = MemoShape::from_reader(&mut record_reader)?;
+                self.current_memo_shape = Some(memo_shape);
+                return Ok(());
+            }
+            Some(RecordTagId::MemoList) => {
+                // MemoList is a 4-byte record header for memo content
+                // Format: UINT32 (unknown content, possibly flags)
+                // The memo shape was already stored via MemoShape record
+                if record_reader.remaining() >= 4 {
+                    let _flags = record_reader.read_u32().unwrap_or(0);
+                    // Store the memo with its shape if available
+                    if let Some(shape) = self.current_memo_shape.take() {
+                        self.section.memos.push(Memo::with_shape(shape));
                     }
                 }
+                return Ok(());
+            }
+            _ => {}
+        }
 
-                Some(RecordTagId::Table) => {
-                    if let Some(ref mut ctrl) = current_control {
-                        if let Ok(table) = Table::from_reader(&mut record_reader) {
-                            ctrl.set_content(ControlContent::Table(table));
-                        }
-                    }
-                }
+        self.close_frames(tag, level);
 
-                Some(RecordTagId::ShapeComponent) => {
-                    if let Some(ref mut ctrl) = current_control {
-                        if let Ok(props) = ShapeElementProperties::from_reader(&mut record_reader) {
-                            let shape = Shape::new(props);
-                            ctrl.set_content(ControlContent::Shape(shape));
-                        }
-                    }
-                }
+        match tag {
+            Some(RecordTagId::ParagraphHeader) => {
+                let paragraph = Paragraph::from_reader(&mut record_reader, header.data_size())?;
+                self.frames.push(Frame::Paragraph { level, paragraph });
+            }
 
-                Some(RecordTagId::ShapeComponentLine) => {
-                    if let Some(ref mut ctrl) = current_control {
-                        // 그리기 개체 공통 속성 파싱 (표 81: 개체요소속성 + 테두리선 + 채우기 + 텍스트박스)
-                        if let Ok(common) = DrawingObjectCommon::from_reader(&mut record_reader) {
-                            if let Some(shape) = ctrl.as_shape_mut() {
-                                shape.element_properties = common.element_properties;
-                                shape.border_line = common.border_line;
-                                shape.fill = common.fill;
-                                shape.text_box = common.text_box;
-                            }
-                        }
-                        // 선 개체 속성 (표 92)
-                        if let Ok(line) = LineShape::from_reader(&mut record_reader) {
-                            if let Some(shape) = ctrl.as_shape_mut() {
-                                shape.shape_type = ShapeType::Line(line);
-                            }
-                        }
-                    }
+            Some(RecordTagId::ParagraphText) => {
+                if let Some(para) = self.paragraph_mut() {
+                    let text =
+                        ParagraphText::from_reader(&mut record_reader, para.character_count())?;
+                    para.set_text(text);
                 }
+            }
 
-                Some(RecordTagId::ShapeComponentRectangle) => {
-                    if let Some(ref mut ctrl) = current_control {
-                        // 그리기 개체 공통 속성 파싱
-                        if let Ok(common) = DrawingObjectCommon::from_reader(&mut record_reader) {
-                            if let Some(shape) = ctrl.as_shape_mut() {
-                                shape.element_properties = common.element_properties;
-                                shape.border_line = common.border_line;
-                                shape.fill = common.fill;
-                                shape.text_box = common.text_box;
-                            }
-                        }
-                        // 사각형 개체 속성 (표 94)
-                        if let Ok(rect) = RectangleShape::from_reader(&mut record_reader) {
-                            if let Some(shape) = ctrl.as_shape_mut() {
-                                shape.shape_type = ShapeType::Rectangle(rect);
-                            }
-                        }
+            Some(RecordTagId::ParagraphCharacterShape) => {
+                if let Some(para) = self.paragraph_mut() {
+                    let count = header.data_size() / 8;
+                    for _ in 0..count {
+                        let reference = CharacterShapeReference::from_reader(&mut record_reader)?;
+                        para.add_character_shape_reference(reference);
                     }
                 }
+            }
 
-                Some(RecordTagId::ShapeComponentEllipse) => {
-                    if let Some(ref mut ctrl) = current_control {
-                        // 그리기 개체 공통 속성 파싱
-                        if let Ok(common) = DrawingObjectCommon::from_reader(&mut record_reader) {
-                            if let Some(shape) = ctrl.as_shape_mut() {
-                                shape.element_properties = common.element_properties;
-                                shape.border_line = common.border_line;
-                                shape.fill = common.fill;
-                                shape.text_box = common.text_box;
-                            }
-                        }
-                        // 타원 개체 속성 (표 96)
-                        if let Ok(ellipse) = EllipseShape::from_reader(&mut record_reader) {
-                            if let Some(shape) = ctrl.as_shape_mut() {
-                                shape.shape_type = ShapeType::Ellipse(ellipse);
-                            }
-                        }
+            Some(RecordTagId::ParagraphLineSegment) => {
+                if let Some(para) = self.paragraph_mut() {
+                    let count = header.data_size() as usize / LineSegment::SIZE;
+                    for _ in 0..count {
+                        let segment = LineSegment::from_reader(&mut record_reader)?;
+                        para.add_line_segment(segment);
                     }
                 }
+            }
 
-                Some(RecordTagId::ShapeComponentArc) => {
-                    if let Some(ref mut ctrl) = current_control {
-                        // 그리기 개체 공통 속성 파싱
-                        if let Ok(common) = DrawingObjectCommon::from_reader(&mut record_reader) {
-                            if let Some(shape) = ctrl.as_shape_mut() {
-                                shape.element_properties = common.element_properties;
-                                shape.border_line = common.border_line;
-                                shape.fill = common.fill;
-                                shape.text_box = common.text_box;
-                            }
-                        }
-                        // 호 개체 속성 (표 101)
-                        if let Ok(arc) = ArcShape::from_reader(&mut record_reader) {
-                            if let Some(shape) = ctrl.as_shape_mut() {
-                                shape.shape_type = ShapeType::Arc(arc);
-                            }
-                        }
+            Some(RecordTagId::ParagraphRangeTag) => {
+                if let Some(para) = self.paragraph_mut() {
+                    let count = header.data_size() as usize / RangeTag::SIZE;
+                    for _ in 0..count {
+                        let tag = RangeTag::from_reader(&mut record_reader)?;
+                        para.add_range_tag(tag);
                     }
                 }
+            }
+
+            Some(RecordTagId::ControlHeader) => {
+                let control_id = ControlId::from_reader(&mut record_reader)?;
+                let control_data = record_reader.read_remaining().to_vec();
+                let control = parse_control(control_id, control_data);
+                self.frames.push(Frame::Control {
+                    level,
+                    control: Box::new(control),
+                });
+            }
 
-                Some(RecordTagId::ShapeComponentPolygon) => {
-                    if let Some(ref mut ctrl) = current_control {
-                        // 그리기 개체 공통 속성 파싱
-                        if let Ok(common) = DrawingObjectCommon::from_reader(&mut record_reader) {
-                            if let Some(shape) = ctrl.as_shape_mut() {
-                                shape.element_properties = common.element_properties;
-                                shape.border_line = common.border_line;
-                                shape.fill = common.fill;
-                                shape.text_box = common.text_box;
+            Some(RecordTagId::ListHeader) => {
+                // ListHeader marks the beginning of nested content
+                let list_header = ListHeader::from_record(&mut record_reader)?;
+                let paragraph_count = list_header.paragraph_count();
+
+                let context = match self.frames.last() {
+                    Some(Frame::Control { control, .. }) => {
+                        match control.control_type() {
+                            // 개체 본문보다 먼저 나오는 목록은 캡션
+                            ControlType::Table
+                            | ControlType::DrawingObject
+                            | ControlType::Equation
+                                if control.content().is_none() =>
+                            {
+                                let caption =
+                                    Caption::from_list_data(&mut record_reader).unwrap_or_default();
+                                ParsingContext::Caption {
+                                    paragraph_count,
+                                    caption,
+                                }
                             }
-                        }
-                        // 다각형 개체 속성 (표 99)
-                        if let Ok(polygon) = PolygonShape::from_reader(&mut record_reader) {
-                            if let Some(shape) = ctrl.as_shape_mut() {
-                                shape.shape_type = ShapeType::Polygon(polygon);
+                            ControlType::Table => {
+                                // Cell properties follow the list header in the record
+                                let mut cell = if record_reader.remaining() >= TableCell::SIZE {
+                                    TableCell::from_reader(&mut record_reader).unwrap_or_default()
+                                } else {
+                                    TableCell::default()
+                                };
+                                cell.list_properties = list_header.properties();
+                                ParsingContext::TableCell {
+                                    paragraph_count,
+                                    cell,
+                                }
                             }
-                        }
-                    }
-                }
-
-                Some(RecordTagId::ShapeComponentCurve) => {
-                    if let Some(ref mut ctrl) = current_control {
-                        // 그리기 개체 공통 속성 파싱
-                        if let Ok(common) = DrawingObjectCommon::from_reader(&mut record_reader) {
-                            if let Some(shape) = ctrl.as_shape_mut() {
-                                shape.element_properties = common.element_properties;
-                                shape.border_line = common.border_line;
-                                shape.fill = common.fill;
-                                shape.text_box = common.text_box;
+                            ControlType::Header | ControlType::Footer => {
+                                ParsingContext::HeaderFooter { paragraph_count }
                             }
-                        }
-                        // 곡선 개체 속성 (표 103)
-                        if let Ok(curve) = CurveShape::from_reader(&mut record_reader) {
-                            if let Some(shape) = ctrl.as_shape_mut() {
-                                shape.shape_type = ShapeType::Curve(curve);
+                            ControlType::Footnote | ControlType::Endnote => {
+                                ParsingContext::FootnoteEndnote { paragraph_count }
                             }
+                            _ => ParsingContext::TextBox { paragraph_count },
                         }
                     }
-                }
-
-                Some(RecordTagId::ShapeComponentPicture) => {
-                    if let Some(ref mut ctrl) = current_control {
-                        // 개체 요소 공통 속성(표 83)에서 flip/rotation 파싱
-                        let (flip, rotation) = if let Ok(elem_props) =
-                            ShapeElementProperties::from_reader(&mut record_reader)
-                        {
-                            let flip = match (
-                                elem_props.is_flipped_horizontal(),
-                                elem_props.is_flipped_vertical(),
-                            ) {
-                                (false, false) => ImageFlip::None,
-                                (true, false) => ImageFlip::Horizontal,
-                                (false, true) => ImageFlip::Vertical,
-                                (true, true) => ImageFlip::Both,
-                            };
-                            (flip, elem_props.rotation)
+                    Some(Frame::Shape { .. }) => {
+                        // 그리기 개체의 글상자 (표 90)
+                        let mut text_box = if record_reader.remaining() >= ShapeTextBox::SIZE {
+                            ShapeTextBox::from_reader(&mut record_reader).unwrap_or_default()
                         } else {
-                            (ImageFlip::None, 0)
+                            ShapeTextBox::default()
                         };
-
-                        // 그림 개체 속성(표 107) 파싱
-                        if let Ok(properties) = PictureProperties::from_reader(&mut record_reader) {
-                            let picture = Picture::with_flip(properties, flip, rotation);
-                            ctrl.set_content(ControlContent::Picture(picture));
+                        text_box.list_properties = list_header.properties();
+                        if let Some(object) = self.shape_mut() {
+                            object.shape.text_box = Some(text_box);
                         }
+                        ParsingContext::Shape { paragraph_count }
                     }
+                    _ => ParsingContext::TextBox { paragraph_count },
+                };
+
+                // The section itself is the bottom list
+                let depth = self
+                    .frames
+                    .iter()
+                    .filter(|frame| matches!(frame, Frame::List { .. }))
+                    .count() as u64;
+                self.limits.check(LimitKind::NestingDepth, depth)?;
+
+                self.frames.push(Frame::List {
+                    level,
+                    context,
+                    paragraphs: Vec::new(),
+                });
+            }
+
+            Some(RecordTagId::Table) => {
+                if let Some(ctrl) = self.control_mut() {
+                    let table = Table::from_reader(&mut record_reader)?;
+                    ctrl.set_content(ControlContent::Table(table));
                 }
+            }
 
-                Some(RecordTagId::ShapeComponentOle) => {
-                    if let Some(ref mut ctrl) = current_control {
-                        if let Ok(ole) = OleObject::from_reader(&mut record_reader) {
-                            ctrl.set_content(ControlContent::OleObject(ole));
-                        }
-                    }
+            Some(RecordTagId::ShapeComponent) => {
+                let top_level = match self.frames.last() {
+                    Some(Frame::Control { .. }) => true,
+                    Some(Frame::Shape { .. }) => false,
+                    _ => return Ok(()),
+                };
+                let shape = parse_shape_component(&mut record_reader, top_level)?;
+                self.frames.push(Frame::Shape {
+                    level,
+                    object: Box::new(ShapeObject {
+                        shape,
+                        content: None,
+                        children: Vec::new(),
+                    }),
+                });
+            }
+
+            Some(RecordTagId::ShapeComponentLine) => {
+                // 선 개체 속성 (표 92)
+                let line = LineShape::from_reader(&mut record_reader)?;
+                if let Some(object) = self.shape_mut() {
+                    object.shape.shape_type = ShapeType::Line(line);
                 }
+            }
 
-                Some(RecordTagId::Equation) => {
-                    if let Some(ref mut ctrl) = current_control {
-                        if let Ok(equation) = Equation::from_reader(&mut record_reader) {
-                            ctrl.set_content(ControlContent::Equation(equation));
-                        }
-                    }
+            Some(RecordTagId::ShapeComponentRectangle) => {
+                // 사각형 개체 속성 (표 94)
+                let rect = RectangleShape::from_reader(&mut record_reader)?;
+                if let Some(object) = self.shape_mut() {
+                    object.shape.shape_type = ShapeType::Rectangle(rect);
                 }
+            }
 
-                Some(RecordTagId::ChartData) => {
-                    if let Some(ref mut ctrl) = current_control {
-                        if let Ok(chart) = ChartData::from_reader(&mut record_reader) {
-                            ctrl.set_content(ControlContent::Chart(chart));
-                        }
-                    }
+            Some(RecordTagId::ShapeComponentEllipse) => {
+                // 타원 개체 속성 (표 96)
+                let ellipse = EllipseShape::from_reader(&mut record_reader)?;
+                if let Some(object) = self.shape_mut() {
+                    object.shape.shape_type = ShapeType::Ellipse(ellipse);
                 }
+            }
 
-                Some(RecordTagId::VideoData) => {
-                    if let Some(ref mut ctrl) = current_control {
-                        if let Ok(video) = VideoData::from_reader(&mut record_reader) {
-                            ctrl.set_content(ControlContent::Video(video));
-                        }
-                    }
+            Some(RecordTagId::ShapeComponentArc) => {
+                // 호 개체 속성 (표 101)
+                let arc = ArcShape::from_reader(&mut record_reader)?;
+                if let Some(object) = self.shape_mut() {
+                    object.shape.shape_type = ShapeType::Arc(arc);
                 }
+            }
 
-                Some(RecordTagId::ShapeComponentTextArt) => {
-                    if let Some(ref mut ctrl) = current_control {
-                        if let Ok(text_art) = TextArt::from_reader(&mut record_reader) {
-                            ctrl.set_content(ControlContent::TextArt(text_art));
-                        }
-                    }
+            Some(RecordTagId::ShapeComponentPolygon) => {
+                // 다각형 개체 속성 (표 99)
+                let polygon = PolygonShape::from_reader(&mut record_reader)?;
+                if let Some(object) = self.shape_mut() {
+                    object.shape.shape_type = ShapeType::Polygon(polygon);
                 }
+            }
 
-                Some(RecordTagId::FormObject) => {
-                    if let Some(ref mut ctrl) = current_control {
-                        if let Ok(form) = FormObject::from_reader(&mut record_reader) {
-                            ctrl.set_content(ControlContent::FormObject(form));
-                        }
-                    }
+            Some(RecordTagId::ShapeComponentCurve) => {
+                // 곡선 개체 속성 (표 103)
+                let curve = CurveShape::from_reader(&mut record_reader)?;
+                if let Some(object) = self.shape_mut() {
+                    object.shape.shape_type = ShapeType::Curve(curve);
                 }
+            }
 
-                Some(RecordTagId::MemoShape) => {
-                    // MemoShape defines the visual properties of a memo
-                    // Store it for association with the following MemoList
-                    if let Ok(memo_shape) = MemoShape::from_reader(&mut record_reader) {
-                        current_memo_shape = Some(memo_shape);
-                    }
+            Some(RecordTagId::ShapeComponentPicture) => {
+                // 그림 개체 속성(표 107) 파싱, flip/rotation은 개체 요소 속성(표 83)에서 가져옴
+                let properties = PictureProperties::from_reader(&mut record_reader)?;
+                if let Some(object) = self.shape_mut() {
+                    let element = &object.shape.element_properties;
+                    let flip = match (
+                        element.is_flipped_horizontal(),
+                        element.is_flipped_vertical(),
+                    ) {
+                        (false, false) => ImageFlip::None,
+                        (true, false) => ImageFlip::Horizontal,
+                        (false, true) => ImageFlip::Vertical,
+                        (true, true) => ImageFlip::Both,
+                    };
+                    let picture = Picture::with_flip(properties, flip, element.rotation);
+                    object.content = Some(ControlContent::Picture(picture));
                 }
+            }
 
-                Some(RecordTagId::MemoList) => {
-                    // MemoList is a 4-byte record header for memo content
-                    // Format: UINT32 (unknown content, possibly flags)
-                    // The memo shape was already stored via MemoShape record
-                    // For now, just acknowledge the record - memo content parsing
-                    // requires further investigation of HWP spec
-                    if record_reader.remaining() >= 4 {
-                        let _flags = record_reader.read_u32().unwrap_or(0);
-                        // Store the memo with its shape if available
-                        if let Some(shape) = current_memo_shape.take() {
-                            section.memos.push(Memo::with_shape(shape));
-                        }
-                    }
+            Some(RecordTagId::ShapeComponentOle) => {
+                let ole = OleObject::from_reader(&mut record_reader)?;
+                // 차트는 OLE 개체 레코드가 차트 저장소(BinData)를 가리킴
+                if let Some(ControlContent::Chart(chart)) = self.object_content_mut() {
+                    chart.binary_data_id = Some(ole.binary_data_id);
+                } else {
+                    self.set_object_content(ControlContent::OleObject(ole));
                 }
+            }
 
-                Some(RecordTagId::ShapeComponentContainer) => {
-                    // Container for grouped shapes - parse container info
-                    // Children are parsed as subsequent ShapeComponent records
-                    if let Some(ref mut ctrl) = current_control {
-                        if let Ok(container) = ShapeContainer::from_reader(&mut record_reader) {
-                            if let Some(shape) = ctrl.as_shape_mut() {
-                                // Initialize as Container type with empty children
-                                // Children will be populated from subsequent records
-                                shape.shape_type = ShapeType::Container(Vec::new());
-                            }
-                            // Store container metadata if needed in the future
-                            let _ = container;
-                        }
-                    }
+            Some(RecordTagId::Equation) => {
+                let equation = Equation::from_reader(&mut record_reader)?;
+                self.set_object_content(ControlContent::Equation(equation));
+            }
+
+            Some(RecordTagId::ChartData) => {
+                let mut chart = ChartData::from_reader(&mut record_reader)?;
+                if let Some(ControlContent::OleObject(ole)) = self.object_content_mut() {
+                    chart.binary_data_id = Some(ole.binary_data_id);
+                }
+                self.set_object_content(ControlContent::Chart(chart));
+            }
+
+            Some(RecordTagId::VideoData) => {
+                let video = VideoData::from_reader(&mut record_reader)?;
+                self.set_object_content(ControlContent::Video(video));
+            }
+
+            Some(RecordTagId::ShapeComponentTextArt) => {
+                let text_art = TextArt::from_reader(&mut record_reader)?;
+                self.set_object_content(ControlContent::TextArt(text_art));
+            }
+
+            Some(RecordTagId::FormObject) => {
+                let form = FormObject::from_reader(&mut record_reader)?;
+                self.set_object_content(ControlContent::FormObject(form));
+            }
+
+            Some(RecordTagId::ShapeComponentContainer) => {
+                // Container for grouped shapes - children are the nested
+                // ShapeComponent records
+                if let Some(object) = self.shape_mut() {
+                    object.shape.shape_type = ShapeType::Container(Vec::new());
                 }
+            }
 
-                Some(RecordTagId::ControlData) => {
-                    // Control arbitrary data (field names, hyperlink info)
-                    // Parse ControlData and update the current control
-                    if let Some(ref mut ctrl) = current_control {
-                        if let Ok(ctrl_data) = ControlData::from_reader(&mut record_reader) {
-                            // Update control based on type
-                            let control_id_bytes = ctrl.id().raw().to_le_bytes();
-                            match ctrl.control_type() {
-                                ControlType::Field => {
-                                    // Parse field control with control data
-                                    let field = Field::from_control_id_and_data(
-                                        &control_id_bytes,
-                                        Some(&ctrl_data),
-                                    );
-                                    // Special case: hyperlink field (%hlk)
-                                    if field.field_type() == FieldType::Hyperlink {
-                                        let hyperlink = Hyperlink::from_control_data(&ctrl_data);
-                                        ctrl.set_content(ControlContent::Hyperlink(hyperlink));
-                                    } else {
-                                        ctrl.set_content(ControlContent::Field(field));
-                                    }
+            Some(RecordTagId::ControlData) => {
+                // Control arbitrary data (field names, hyperlink info)
+                // Parse ControlData and update the current control
+                if let Some(ctrl) = self.control_mut() {
+                    if let Ok(ctrl_data) = ControlData::from_reader(&mut record_reader) {
+                        if ctrl.control_type() == ControlType::Field {
+                            match ctrl.content_mut() {
+                                Some(ControlContent::Hyperlink(hyperlink)) => {
+                                    hyperlink.update_from_control_data(&ctrl_data);
                                 }
-                                _ => {
-                                    // Other control types may use control data differently
+                                Some(ControlContent::Field(field)) => {
+                                    field.update_from_control_data(&ctrl_data);
                                 }
+                                _ => {}
                             }
                         }
+                        ctrl.set_control_data(ctrl_data);
                     }
                 }
+            }
 
-                Some(RecordTagId::ShapeComponentUnknown) => {
-                    // Unknown shape component - skip
-                }
-
-                _ => {
-                    // Skip unknown or unhandled records
-                }
+            _ => {
+                // Skip unknown or unhandled records
             }
         }
 
-        // Save final paragraph
-        if let Some(ctrl) = current_control.take() {
-            if let Some(ref mut para) = current_paragraph {
-                para.add_control(ctrl);
-            }
+        Ok(())
+    }
+
+    /// Closes all open scopes and returns the section.
+    fn finish(mut self) -> Section {
+        self.close_frames(None, 0);
+        if let Some(Frame::List { paragraphs, .. }) = self.frames.pop() {
+            self.section.paragraphs = paragraphs;
+        }
+        self.section
+    }
+}
+
+/// Creates a control from its control header.
+fn parse_control(control_id: ControlId, control_data: Vec<u8>) -> Control {
+    let mut data_reader = ByteReader::new(&control_data);
+    let content = match control_id.control_type() {
+        ControlType::Header => {
+            // Parse target from control data (first byte)
+            let target = control_data
+                .first()
+                .map_or(HeaderFooterTarget::BothPages, |&raw| {
+                    HeaderFooterTarget::from_raw(raw)
+                });
+            Some(ControlContent::Header(Header::new(target)))
         }
-        if let Some(para) = current_paragraph.take() {
-            Self::save_paragraph(
-                &mut section,
-                &mut context_stack,
-                &mut nested_paragraphs,
-                &mut cell_paragraph_counts,
-                &mut None,
-                para,
+        ControlType::Footer => {
+            let target = control_data
+                .first()
+                .map_or(HeaderFooterTarget::BothPages, |&raw| {
+                    HeaderFooterTarget::from_raw(raw)
+                });
+            Some(ControlContent::Footer(Footer::new(target)))
+        }
+        ControlType::Footnote => {
+            // Parse note number from control data (bytes 4-5, u16)
+            data_reader.skip(4).ok();
+            let number = data_reader.read_u16().unwrap_or(0);
+            Some(ControlContent::Footnote(Footnote::new(number)))
+        }
+        ControlType::Endnote => {
+            data_reader.skip(4).ok();
+            let number = data_reader.read_u16().unwrap_or(0);
+            Some(ControlContent::Endnote(Endnote::new(number)))
+        }
+        ControlType::SectionDefinition => SectionDefinition::from_reader(&mut data_reader)
+            .ok()
+            .map(ControlContent::SectionDefinition),
+        ControlType::ColumnDefinition => ColumnDefinition::from_reader(&mut data_reader)
+            .ok()
+            .map(ControlContent::ColumnDefinition),
+        ControlType::Compose => Compose::from_reader(&mut data_reader)
+            .ok()
+            .map(ControlContent::Compose),
+        ControlType::Dutmal => Dutmal::from_reader(&mut data_reader)
+            .ok()
+            .map(ControlContent::Dutmal),
+        ControlType::Field => {
+            // 필드 속성(4) + 기타 속성(1) + 명령(가변) + 문서 내 고유 아이디(4)
+            let command = data_reader
+                .skip(5)
+                .and_then(|()| data_reader.read_utf16_string())
+                .unwrap_or_default();
+            let field = Field::with_instruction(
+                FieldType::from_control_id(&control_id.raw().to_be_bytes()),
+                command,
             );
+            // Special case: hyperlink field (%hlk)
+            if field.field_type() == FieldType::Hyperlink {
+                let target = hyperlink_target(field.instruction());
+                Some(ControlContent::Hyperlink(Hyperlink::new(target)))
+            } else {
+                Some(ControlContent::Field(field))
+            }
         }
+        _ => None,
+    };
 
-        Ok(section)
+    let mut control = Control::with_data(control_id, control_data);
+    if let Some(content) = content {
+        control.set_content(content);
     }
+    control
+}
 
-    /// Saves a paragraph to the appropriate container based on the current context.
-    fn save_paragraph(
-        section: &mut Section,
-        context_stack: &mut Vec<ParsingContext>,
-        nested_paragraphs: &mut Vec<Vec<Paragraph>>,
-        cell_paragraph_counts: &mut Vec<u16>,
-        current_control: &mut Option<Control>,
-        paragraph: Paragraph,
-    ) {
-        // Get the current context
-        let context_idx = context_stack.len().saturating_sub(1);
-
-        match context_stack.get(context_idx) {
-            Some(ParsingContext::Section) | None => {
-                // Top-level paragraph
-                section.paragraphs.push(paragraph);
-            }
-            Some(ParsingContext::TableCell { .. })
-            | Some(ParsingContext::HeaderFooter { .. })
-            | Some(ParsingContext::FootnoteEndnote { .. })
-            | Some(ParsingContext::TextBox { .. })
-            | Some(ParsingContext::Shape { .. }) => {
-                // Nested paragraph - store in the nested container
-                if let Some(paragraphs) = nested_paragraphs.get_mut(context_idx) {
-                    paragraphs.push(paragraph);
-                }
-
-                // Increment the paragraph count for this context
-                if let Some(count) = cell_paragraph_counts.get_mut(context_idx) {
-                    *count += 1;
+/// Extracts the link target from a hyperlink field command.
+///
+/// The command holds `;`-separated items with `:` escaped as `\:`;
+/// the first item is the target.
+fn hyperlink_target(command: &str) -> String {
+    let mut target = String::new();
+    let mut chars = command.chars();
+    while let Some(ch) = chars.next() {
+        match ch {
+            '\\' => {
+                if let Some(escaped) = chars.next() {
+                    target.push(escaped);
                 }
-
-                // Check if we've collected all paragraphs for this context
-                Self::try_complete_context(
-                    context_stack,
-                    nested_paragraphs,
-                    cell_paragraph_counts,
-                    current_control,
-                );
             }
+            ';' => break,
+            _ => target.push(ch),
         }
     }
+    target
+}
 
-    /// Tries to complete the current context if all paragraphs have been collected.
-    fn try_complete_context(
-        context_stack: &mut Vec<ParsingContext>,
-        nested_paragraphs: &mut Vec<Vec<Paragraph>>,
-        cell_paragraph_counts: &mut Vec<u16>,
-        current_control: &mut Option<Control>,
-    ) {
-        let context_idx = context_stack.len().saturating_sub(1);
+/// Parses a shape component record (표 82, 표 83).
+///
+/// Drawing objects continue with their border line, fill and shadow
+/// (표 81); pictures, OLE objects and groups only store the element
+/// properties here.
+fn parse_shape_component(reader: &mut ByteReader, top_level: bool) -> Result<Shape> {
+    let start = reader.position();
+    let element_type = ControlId::from_reader(reader)?;
+    reader.seek(start)?;
+
+    let is_drawing_object = !matches!(
+        element_type,
+        ControlId::SHAPE_PICTURE | ControlId::SHAPE_OLE | ControlId::SHAPE_GROUP
+    );
+    if !is_drawing_object {
+        let element_properties = ShapeElementProperties::from_reader(reader, top_level)?;
+        return Ok(Shape::new(element_properties));
+    }
+
+    let common = DrawingObjectCommon::from_reader(reader, top_level)?;
+    let mut shape = Shape::new(common.element_properties);
+    shape.border_line = common.border_line;
+    shape.fill = common.fill;
+    shape.shadow = common.shadow;
+    Ok(shape)
+}
 
-        // Don't pop Section context
-        if context_idx == 0 {
+/// A section in the document.
+///
+/// Sections are the top-level containers in the body text.
+/// Each section can have different page settings.
+#[derive(Debug, Clone, Default)]
+pub struct Section {
+    /// Paragraphs in this section.
+    paragraphs: Vec<Paragraph>,
+    /// Page definition (size, margins, orientation).
+    page_definition: Option<PageDefinition>,
+    /// Footnote shape settings.
+    footnote_shape: Option<FootnoteShape>,
+    /// Endnote shape settings.
+    endnote_shape: Option<EndnoteShape>,
+    /// Page border and fill settings.
+    page_border_fill: Option<PageBorderFill>,
+    /// Memos (annotations) in this section.
+    memos: Vec<Memo>,
+    /// Retained top-level records that are not paragraphs, keyed by the index
+    /// of the paragraph they precede.
+    loose_records: Vec<(usize, Vec<u8>)>,
+}
+
+impl Section {
+    /// Creates a new empty section.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parses a section from bytes.
+    ///
+    /// Fails on the first record that cannot be parsed. Use
+    /// [`HwpDocument::open_with_options`] with lenient parsing to skip bad
+    /// records and see what was skipped.
+    ///
+    /// [`HwpDocument::open_with_options`]: crate::HwpDocument::open_with_options
+    pub fn from_bytes(data: &[u8]) -> Result<Self> {
+        Self::parse(
+            data,
+            "Section",
+            &ParseOptions::default(),
+            &Limits::default(),
+            &mut Vec::new(),
+        )
+    }
+
+    /// Parses a section, recovering from bad records as `options` allow.
+    pub(crate) fn parse(
+        data: &[u8],
+        stream: &str,
+        options: &ParseOptions,
+        limits: &Limits,
+        diagnostics: &mut Vec<ParseDiagnostic>,
+    ) -> Result<Self> {
+        let mut parser = SectionParser::new(limits);
+        parse_records(
+            data,
+            stream,
+            options,
+            limits,
+            diagnostics,
+            |header, record_data| parser.parse_record(header, record_data),
+        )?;
+        Ok(parser.finish())
+    }
+
+    /// Parses a section and keeps the original records of its top-level
+    /// paragraphs, so that [`Section::to_bytes`] can write them back verbatim.
+    ///
+    /// Records are only retained when the stream is well formed and every
+    /// top-level paragraph header matches a parsed paragraph.
+    pub fn from_bytes_retained(data: &[u8]) -> Result<Self> {
+        let mut section = Self::from_bytes(data)?;
+        section.retain_records(data);
+        Ok(section)
+    }
+
+    /// Attaches the original records of `data`, the stream this section was parsed from.
+    pub(crate) fn retain_records(&mut self, data: &[u8]) {
+        let Ok(tree) = RecordTree::parse(data) else {
+            return;
+        };
+
+        let paragraph_count = tree
+            .roots
+            .iter()
+            .filter(|root| root.record.tag_id() == Some(RecordTagId::ParagraphHeader))
+            .count();
+        if paragraph_count != self.paragraphs.len() {
             return;
         }
 
-        let should_pop = if let Some(ctx) = context_stack.get(context_idx) {
-            if let Some(expected) = ctx.paragraph_count() {
-                if let Some(&current) = cell_paragraph_counts.get(context_idx) {
-                    current >= expected
-                } else {
-                    false
-                }
+        let mut index = 0;
+        for root in &tree.roots {
+            let bytes = data[root.span()].to_vec();
+            if root.record.tag_id() == Some(RecordTagId::ParagraphHeader) {
+                self.paragraphs[index].retain_records(bytes);
+                index += 1;
             } else {
-                false
+                self.loose_records.push((index, bytes));
             }
-        } else {
-            false
-        };
+        }
+    }
 
-        if should_pop {
-            // Pop the context
-            if let Some(ctx) = context_stack.pop() {
-                let paragraphs = nested_paragraphs.pop().unwrap_or_default();
-                cell_paragraph_counts.pop();
-
-                // Add paragraphs to the appropriate target
-                match ctx {
-                    ParsingContext::TableCell { mut cell, .. } => {
-                        // Store paragraphs in the cell
-                        cell.paragraphs = paragraphs;
-                        // Add cell to the table in current control
-                        if let Some(ctrl) = current_control {
-                            if let Some(table) = ctrl.as_table_mut() {
-                                table.add_cell(cell);
-                            }
-                        }
-                    }
-                    ParsingContext::HeaderFooter { .. } => {
-                        // Store paragraphs in the Header/Footer content
-                        if let Some(ctrl) = current_control {
-                            if let Some(header) = ctrl.as_header_mut() {
-                                header.set_paragraphs(paragraphs);
-                            } else if let Some(footer) = ctrl.as_footer_mut() {
-                                footer.set_paragraphs(paragraphs);
-                            } else {
-                                // Fallback: add as children
-                                for para in paragraphs {
-                                    ctrl.add_child(para);
-                                }
-                            }
-                        }
-                    }
-                    ParsingContext::FootnoteEndnote { .. } => {
-                        // Store paragraphs in the Footnote/Endnote content
-                        if let Some(ctrl) = current_control {
-                            if let Some(footnote) = ctrl.as_footnote_mut() {
-                                footnote.set_paragraphs(paragraphs);
-                            } else if let Some(endnote) = ctrl.as_endnote_mut() {
-                                endnote.set_paragraphs(paragraphs);
-                            } else {
-                                // Fallback: add as children
-                                for para in paragraphs {
-                                    ctrl.add_child(para);
-                                }
-                            }
-                        }
-                    }
-                    ParsingContext::TextBox { .. } => {
-                        // Add as children to the current control
-                        if let Some(ctrl) = current_control {
-                            for para in paragraphs {
-                                ctrl.add_child(para);
-                            }
-                        }
-                    }
-                    ParsingContext::Shape { .. } => {
-                        // Store paragraphs in the Shape
-                        if let Some(ctrl) = current_control {
-                            if let Some(shape) = ctrl.as_shape_mut() {
-                                for para in paragraphs {
-                                    shape.add_paragraph(para);
-                                }
-                            }
-                        }
-                    }
-                    ParsingContext::Section => {
-                        // Should not happen
-                    }
-                }
+    /// Encodes the section stream (before compression).
+    ///
+    /// Paragraphs read with [`Section::from_bytes_retained`] that have not been
+    /// changed are written verbatim, together with the other retained top-level
+    /// records. Changed and new paragraphs are encoded from the model.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::RecordsNotRetained`](crate::Error::RecordsNotRetained)
+    /// if a paragraph to encode holds a control without retained records.
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        let mut writer = ByteWriter::new();
+        let mut loose = self.loose_records.iter().peekable();
+        for (index, paragraph) in self.paragraphs.iter().enumerate() {
+            while let Some((_, bytes)) = loose.next_if(|(position, _)| *position <= index) {
+                writer.write_bytes(bytes);
             }
+            paragraph.write_records(&mut writer, index + 1 == self.paragraphs.len())?;
+        }
+        for (_, bytes) in loose {
+            writer.write_bytes(bytes);
         }
+        Ok(writer.into_bytes())
     }
 
     /// Returns the paragraphs in this section.
@@ -838,10 +971,20 @@ impl Section {
     }
 
     /// Extracts all plain text from this section.
+    ///
+    /// Text of controls (table cells, text boxes, notes, ...) follows the
+    /// paragraph that holds them.
     pub fn plain_text(&self) -> String {
         self.paragraphs
             .iter()
-            .map(|p| p.plain_text())
+            .flat_map(|p| {
+                std::iter::once(p.plain_text()).chain(
+                    p.controls()
+                        .iter()
+                        .map(|c| c.plain_text())
+                        .filter(|text| !text.is_empty()),
+                )
+            })
             .collect::<Vec<_>>()
             .join("\n")
     }
@@ -931,4 +1074,151 @@ mod tests {
         section.paragraphs_mut().push(para);
         assert_eq!(section.paragraph_count(), 1);
     }
+
+    fn record(tag: u16, level: u16, data: &[u8]) -> Vec<u8> {
+        let raw = u32::from(tag) | (u32::from(level) << 10) | ((data.len() as u32) << 20);
+        let mut bytes = raw.to_le_bytes().to_vec();
+        bytes.extend(data);
+        bytes
+    }
+
+    /// Paragraph header with no text, followed by `extra` bytes.
+    fn paragraph_header(last_in_list: bool, extra: &[u8]) -> Vec<u8> {
+        let mut data = vec![0u8; 22];
+        if last_in_list {
+            data[3] = 0x80;
+        }
+        data.extend(extra);
+        record(RecordTagId::ParagraphHeader.as_u16(), 0, &data)
+    }
+
+    /// An unknown top-level record, a paragraph header with trailing bytes, and a
+    /// paragraph with an unknown control that has an unknown child.
+    fn retained_stream() -> Vec<u8> {
+        [
+            record(0x3F0, 0, &[9]),
+            paragraph_header(false, &[0, 0, 0xAA, 0xBB]),
+            paragraph_header(true, &[]),
+            record(RecordTagId::ControlHeader.as_u16(), 1, b"abcd\x01\x02"),
+            record(0x3F1, 2, &[7, 7]),
+        ]
+        .concat()
+    }
+
+    /// Whether the paragraph header record has the last-in-list flag.
+    fn is_last_in_list(record: &crate::record::Record) -> bool {
+        record.data()[3] & 0x80 != 0
+    }
+
+    #[test]
+    fn test_section_retained_round_trip() {
+        let data = retained_stream();
+        let section = Section::from_bytes_retained(&data).unwrap();
+
+        assert_eq!(section.paragraph_count(), 2);
+        let control = &section.paragraphs()[1].controls()[0];
+        assert_eq!(control.raw_records().unwrap().len(), 10 + 6);
+        assert_eq!(section.to_bytes().unwrap(), data);
+    }
+
+    #[test]
+    fn test_section_retained_edits() {
+        let data = retained_stream();
+        let mut section = Section::from_bytes_retained(&data).unwrap();
+        for paragraph in section.paragraphs_mut() {
+            paragraph.add_range_tag(RangeTag {
+                start_position: 0,
+                end_position: 0,
+                tag: [1, 2, 3],
+            });
+        }
+        section
+            .paragraphs_mut()
+            .push(Paragraph::new(0, 0, 0, 0, BreakType::from_raw(0), 0, None));
+
+        let written = section.to_bytes().unwrap();
+        let tree = RecordTree::parse(&written).unwrap();
+        assert_eq!(tree.roots.len(), 4);
+        assert_eq!(tree.roots[0].record.data(), &[9]);
+
+        let edited = &tree.roots[1];
+        assert_eq!(&edited.record.data()[22..], &[0, 0, 0xAA, 0xBB]);
+        assert_eq!(edited.record.data()[14], 1, "range tag count");
+        assert_eq!(
+            edited.children[0].record.tag_id(),
+            Some(RecordTagId::ParagraphRangeTag)
+        );
+
+        let with_control = &tree.roots[2];
+        assert_eq!(with_control.children.len(), 2);
+        let control = &with_control.children[1];
+        assert_eq!(control.record.data(), b"abcd\x01\x02");
+        assert_eq!(control.children[0].record.data(), &[7, 7]);
+
+        assert_eq!(tree.roots[3].record.size(), 22);
+        let flags: Vec<_> = tree.roots[1..]
+            .iter()
+            .map(|root| is_last_in_list(&root.record))
+            .collect();
+        assert_eq!(flags, [false, false, true]);
+    }
+
+    #[test]
+    fn test_section_appended_paragraph_round_trip() {
+        let data = [
+            paragraph_header(true, &[]),
+            // Control ids are stored as little-endian DWORDs
+            record(RecordTagId::ControlHeader.as_u16(), 1, b"dcba"),
+            record(RecordTagId::ControlHeader.as_u16(), 1, b"zyxw"),
+        ]
+        .concat();
+        let mut section = Section::from_bytes_retained(&data).unwrap();
+        let controls = section.paragraphs()[0].controls().to_vec();
+
+        // The text refers to the second control first
+        let mut chars = Vec::new();
+        for control in controls.iter().rev() {
+            let id = control.id().raw();
+            chars.extend([11, id as u16, (id >> 16) as u16, 0, 0, 0, 0, 11]);
+        }
+        chars.push(13);
+        let mut paragraph = Paragraph::new(0, 0, 0, 0, BreakType::from_raw(0), 0, None);
+        paragraph.set_text(ParagraphText::from_raw(chars));
+        for control in controls {
+            paragraph.add_control(control);
+        }
+        section.paragraphs_mut().push(paragraph);
+
+        let written = section.to_bytes().unwrap();
+        let tree = RecordTree::parse(&written).unwrap();
+        let flags: Vec<_> = tree
+            .roots
+            .iter()
+            .map(|root| is_last_in_list(&root.record))
+            .collect();
+        assert_eq!(flags, [false, true]);
+
+        let reread = Section::from_bytes_retained(&written).unwrap();
+        assert_eq!(reread.paragraph_count(), 2);
+        let appended = &reread.paragraphs()[1];
+        assert_eq!(appended.character_count(), 17);
+        let ids: Vec<_> = appended
+            .controls()
+            .iter()
+            .map(|control| control.id().as_str())
+            .collect();
+        assert_eq!(ids, ["wxyz", "abcd"]);
+        assert_eq!(reread.to_bytes().unwrap(), written);
+    }
+
+    #[test]
+    fn test_section_to_bytes_without_records() {
+        let section = Section::from_bytes(&retained_stream()).unwrap();
+
+        assert!(section.paragraphs()[1].raw_records().is_none());
+        assert!(matches!(
+            section.to_bytes(),
+            Err(crate::Error::RecordsNotRetained { .. })
+        ));
+    }
 }
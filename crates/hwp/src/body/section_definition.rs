@@ -184,7 +184,7 @@ impl ColumnDefinition {
 
         // Read column widths if not same width
         let column_widths = if !same_width && column_count > 0 {
-            let mut widths = Vec::with_capacity(column_count);
+            let mut widths = Vec::with_capacity(reader.capacity_for(column_count, 2));
             for _ in 0..column_count {
                 if reader.remaining() >= 2 {
                     widths.push(reader.read_u16()?);
@@ -120,39 +120,32 @@ pub struct ShapeBorderLine {
 }
 
 impl ShapeBorderLine {
-    /// Size in bytes (표 86: 4 + 2 + 4 + 1 = 11 바이트, but INT16 for thickness).
-    pub const SIZE: usize = 11;
+    /// Size in bytes (표 86: 색상 4 + 굵기 4 + 속성 4 + 윤곽선 1).
+    pub const SIZE: usize = 13;
 
     /// Parses from reader (표 86: 테두리 선 정보).
     pub fn from_reader(reader: &mut ByteReader) -> Result<Self> {
-        let color = reader.read_color()?;
-        // 표 86에서는 INT16이지만 실제로는 i32로 저장되는 경우도 있음
-        // 스펙: INT16 (2바이트) 선 굵기
-        let thickness = reader.read_i16()? as i32;
-        let properties = reader.read_u32()?;
-        let outline_style = reader.read_u8()?;
-
         Ok(Self {
-            color,
-            thickness,
-            properties,
-            outline_style,
+            color: reader.read_color()?,
+            thickness: reader.read_i32()?,
+            properties: reader.read_u32()?,
+            outline_style: reader.read_u8()?,
         })
     }
 
-    /// Returns the line end cap style.
+    /// Returns the line end cap style (표 87: bit 6~9).
     pub const fn end_cap(&self) -> LineEndCap {
-        LineEndCap::from_raw((self.properties & 0xFF) as u8)
+        LineEndCap::from_raw(((self.properties >> 6) & 0x0F) as u8)
     }
 
-    /// Returns the start arrow type.
+    /// Returns the start arrow type (표 87: bit 10~15).
     pub const fn start_arrow(&self) -> ArrowType {
-        ArrowType::from_raw(((self.properties >> 8) & 0x0F) as u8)
+        ArrowType::from_raw(((self.properties >> 10) & 0x3F) as u8)
     }
 
-    /// Returns the end arrow type.
+    /// Returns the end arrow type (표 87: bit 16~21).
     pub const fn end_arrow(&self) -> ArrowType {
-        ArrowType::from_raw(((self.properties >> 12) & 0x0F) as u8)
+        ArrowType::from_raw(((self.properties >> 16) & 0x3F) as u8)
     }
 
     /// Returns the start arrow size (표 87: bit 22~25).
@@ -185,11 +178,27 @@ impl Point {
     }
 }
 
-/// Shape element common properties.
+/// Shape element common properties (표 83: 개체 요소 속성).
 #[derive(Debug, Clone, Default)]
 pub struct ShapeElementProperties {
-    /// Element type.
+    /// Element type (control ID of the shape, e.g. `$rec`).
     pub element_type: u32,
+    /// X offset within the group.
+    pub offset_x: HwpUnit,
+    /// Y offset within the group.
+    pub offset_y: HwpUnit,
+    /// Number of times the object has been grouped.
+    pub group_level: u16,
+    /// Local file version.
+    pub local_version: u16,
+    /// Width at creation time.
+    pub initial_width: HwpUnit,
+    /// Height at creation time.
+    pub initial_height: HwpUnit,
+    /// Current width.
+    pub width: HwpUnit,
+    /// Current height.
+    pub height: HwpUnit,
     /// Properties flags.
     pub properties: u32,
     /// Rotation angle in degrees.
@@ -198,31 +207,53 @@ pub struct ShapeElementProperties {
     pub center_x: HwpUnit,
     /// Center Y coordinate.
     pub center_y: HwpUnit,
-    /// Number of matrix elements.
+    /// Number of scale/rotation matrix pairs.
     pub matrix_count: u16,
-    /// Transformation matrix.
+    /// Transformation matrices: the translation matrix followed by
+    /// `matrix_count` scale and rotation matrix pairs, six elements each.
     pub matrix: Vec<f64>,
 }
 
 impl ShapeElementProperties {
     /// Parses from reader.
-    pub fn from_reader(reader: &mut ByteReader) -> Result<Self> {
+    ///
+    /// Objects placed directly in a paragraph store the control ID twice;
+    /// members of a group store it once.
+    pub fn from_reader(reader: &mut ByteReader, top_level: bool) -> Result<Self> {
         let element_type = reader.read_u32()?;
+        if top_level {
+            reader.skip(4)?;
+        }
+        let offset_x = reader.read_signed_hwp_unit()?;
+        let offset_y = reader.read_signed_hwp_unit()?;
+        let group_level = reader.read_u16()?;
+        let local_version = reader.read_u16()?;
+        let initial_width = reader.read_hwp_unit()?;
+        let initial_height = reader.read_hwp_unit()?;
+        let width = reader.read_hwp_unit()?;
+        let height = reader.read_hwp_unit()?;
         let properties = reader.read_u32()?;
         let rotation = reader.read_i16()?;
         let center_x = reader.read_signed_hwp_unit()?;
         let center_y = reader.read_signed_hwp_unit()?;
         let matrix_count = reader.read_u16()?;
 
-        let mut matrix = Vec::with_capacity(matrix_count as usize);
-        for _ in 0..matrix_count {
-            // Matrix elements are stored as HWPUNIT (i32) but represent fixed-point values
-            let value = reader.read_i32()? as f64 / 65536.0;
-            matrix.push(value);
+        let element_count = 6 + 12 * matrix_count as usize;
+        let mut matrix = Vec::with_capacity(element_count.min(reader.remaining() / 8));
+        for _ in 0..element_count {
+            matrix.push(reader.read_f64()?);
         }
 
         Ok(Self {
             element_type,
+            offset_x,
+            offset_y,
+            group_level,
+            local_version,
+            initial_width,
+            initial_height,
+            width,
+            height,
             properties,
             rotation,
             center_x,
@@ -243,6 +274,44 @@ impl ShapeElementProperties {
     }
 }
 
+/// Shadow of a drawing object.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ShapeShadow {
+    /// Shadow type (0 = none).
+    pub shadow_type: u32,
+    /// Shadow color.
+    pub color: ColorReference,
+    /// Horizontal offset.
+    pub offset_x: i32,
+    /// Vertical offset.
+    pub offset_y: i32,
+    /// Transparency (0 = opaque).
+    pub alpha: u8,
+}
+
+impl ShapeShadow {
+    /// Size in bytes, including the instance ID stored with the shadow.
+    pub const SIZE: usize = 22;
+
+    /// Parses from reader.
+    pub fn from_reader(reader: &mut ByteReader) -> Result<Self> {
+        let shadow_type = reader.read_u32()?;
+        let color = reader.read_color()?;
+        let offset_x = reader.read_i32()?;
+        let offset_y = reader.read_i32()?;
+        let _instance_id = reader.read_u32()?;
+        let _reserved = reader.read_u8()?;
+        let alpha = reader.read_u8()?;
+        Ok(Self {
+            shadow_type,
+            color,
+            offset_x,
+            offset_y,
+            alpha,
+        })
+    }
+}
+
 /// Line shape data.
 #[derive(Debug, Clone, Default)]
 pub struct LineShape {
@@ -390,7 +459,7 @@ impl PolygonShape {
     /// Parses from reader.
     pub fn from_reader(reader: &mut ByteReader) -> Result<Self> {
         let count = reader.read_u32()? as usize;
-        let mut points = Vec::with_capacity(count);
+        let mut points = Vec::with_capacity(reader.capacity_for(count, 8));
         for _ in 0..count {
             points.push(Point::from_reader(reader)?);
         }
@@ -431,12 +500,13 @@ impl CurveShape {
     /// Parses from reader.
     pub fn from_reader(reader: &mut ByteReader) -> Result<Self> {
         let count = reader.read_u32()? as usize;
-        let mut points = Vec::with_capacity(count);
+        // Each point is followed later by a one-byte segment type
+        let mut points = Vec::with_capacity(reader.capacity_for(count, 9));
         for _ in 0..count {
             points.push(Point::from_reader(reader)?);
         }
 
-        let mut segment_types = Vec::with_capacity(count);
+        let mut segment_types = Vec::with_capacity(reader.capacity_for(count, 1));
         for _ in 0..count {
             segment_types.push(CurveSegmentType::from_raw(reader.read_u8()?));
         }
@@ -473,6 +543,8 @@ pub enum ShapeType {
 /// Shape text box properties (표 90: 그리기 개체 글상자용 텍스트 속성).
 #[derive(Debug, Clone, Default)]
 pub struct ShapeTextBox {
+    /// List header properties (text direction, line wrap, vertical alignment).
+    pub list_properties: u32,
     /// Left margin.
     pub margin_left: u16,
     /// Right margin.
@@ -492,6 +564,7 @@ impl ShapeTextBox {
     /// Parses from reader.
     pub fn from_reader(reader: &mut ByteReader) -> Result<Self> {
         Ok(Self {
+            list_properties: 0,
             margin_left: reader.read_u16()?,
             margin_right: reader.read_u16()?,
             margin_top: reader.read_u16()?,
@@ -510,6 +583,8 @@ pub struct Shape {
     pub border_line: ShapeBorderLine,
     /// Fill information.
     pub fill: FillInfo,
+    /// Shadow.
+    pub shadow: ShapeShadow,
     /// Text box properties (if shape contains text).
     pub text_box: Option<ShapeTextBox>,
     /// Shape type and data.
@@ -527,6 +602,7 @@ impl Shape {
             element_properties,
             border_line: ShapeBorderLine::default(),
             fill: FillInfo::None,
+            shadow: ShapeShadow::default(),
             text_box: None,
             shape_type: ShapeType::Unknown,
             paragraphs: Vec::new(),
@@ -578,17 +654,20 @@ pub struct DrawingObjectCommon {
     pub border_line: ShapeBorderLine,
     /// Fill information.
     pub fill: FillInfo,
-    /// Text box properties (if shape contains text).
-    pub text_box: Option<ShapeTextBox>,
+    /// Shadow.
+    pub shadow: ShapeShadow,
 }
 
 impl DrawingObjectCommon {
-    /// Parses drawing object common properties from reader.
-    /// This includes: element properties + border line (11 bytes) + fill info (variable) + text box (12 bytes).
-    pub fn from_reader(reader: &mut ByteReader) -> Result<Self> {
-        let element_properties = ShapeElementProperties::from_reader(reader)?;
-
-        // 테두리 선 정보 (표 86: 11 바이트)
+    /// Parses drawing object common properties from a shape component record.
+    ///
+    /// This includes: element properties + border line (13 bytes) + fill info
+    /// (variable) + shadow (22 bytes). The text box of the object is stored in
+    /// its own list header record.
+    pub fn from_reader(reader: &mut ByteReader, top_level: bool) -> Result<Self> {
+        let element_properties = ShapeElementProperties::from_reader(reader, top_level)?;
+
+        // 테두리 선 정보 (표 86: 13 바이트)
         let border_line = if reader.remaining() >= ShapeBorderLine::SIZE {
             ShapeBorderLine::from_reader(reader)?
         } else {
@@ -598,34 +677,19 @@ impl DrawingObjectCommon {
         // 채우기 정보 (표 28: 가변)
         let fill = parse_fill_info(reader)?;
 
-        // 추가 속성 (표 28 끝부분): DWORD + 가변
-        if reader.remaining() >= 4 {
-            let size = reader.read_u32()? as usize;
-            if size > 0 && reader.remaining() >= size {
-                reader.skip(size)?;
-            }
-        }
-
-        // 추가 채우기 속성 길이
-        if reader.remaining() >= 4 {
-            let size = reader.read_u32()? as usize;
-            if size > 0 && reader.remaining() >= size {
-                reader.skip(size)?;
-            }
-        }
-
-        // 글상자 텍스트 정보 (표 89-90: 12바이트 속성 + 문단 리스트)
-        let text_box = if reader.remaining() >= ShapeTextBox::SIZE {
-            Some(ShapeTextBox::from_reader(reader)?)
+        // 채우기 투명도 등 나머지 바이트는 건너뛰고 그림자 정보를 읽음
+        let shadow = if reader.remaining() >= ShapeShadow::SIZE {
+            reader.skip(reader.remaining() - ShapeShadow::SIZE)?;
+            ShapeShadow::from_reader(reader)?
         } else {
-            None
+            ShapeShadow::default()
         };
 
         Ok(Self {
             element_properties,
             border_line,
             fill,
-            text_box,
+            shadow,
         })
     }
 }
@@ -639,6 +703,19 @@ pub fn parse_fill_info(reader: &mut ByteReader) -> Result<FillInfo> {
     let fill_type_raw = reader.read_u32()?;
     let fill_type = FillType::from_raw(fill_type_raw);
 
+    let fill = parse_fill_data(reader, fill_type)?;
+
+    // 추가 채우기 속성
+    if reader.remaining() >= 4 {
+        let size = reader.read_u32()? as usize;
+        reader.skip(size.min(reader.remaining()))?;
+    }
+
+    Ok(fill)
+}
+
+/// Parses the fill data that follows the fill type.
+fn parse_fill_data(reader: &mut ByteReader, fill_type: FillType) -> Result<FillInfo> {
     match fill_type {
         FillType::Solid => {
             if reader.remaining() < 12 {
@@ -662,14 +739,14 @@ pub fn parse_fill_info(reader: &mut ByteReader) -> Result<FillInfo> {
             let center_x = reader.read_i16()?;
             let center_y = reader.read_i16()?;
             let blur = reader.read_i16()?;
-            let color_count = reader.read_i16()? as usize;
+            let color_count = reader.read_i16()?.max(0) as usize;
 
             // Skip position data if more than 2 colors
             if color_count > 2 && reader.remaining() >= 4 * color_count {
                 reader.skip(4 * color_count)?;
             }
 
-            let mut colors = Vec::with_capacity(color_count);
+            let mut colors = Vec::with_capacity(reader.capacity_for(color_count, 4));
             for _ in 0..color_count {
                 if reader.remaining() >= 4 {
                     colors.push(reader.read_color()?);
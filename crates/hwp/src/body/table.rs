@@ -83,6 +83,8 @@ pub struct TableCell {
     pub padding: CellPadding,
     /// Border fill ID reference.
     pub border_fill_id: u16,
+    /// List header properties (text direction, line wrap, vertical alignment).
+    pub list_properties: u32,
     /// Paragraphs contained in this cell.
     pub paragraphs: Vec<Paragraph>,
 }
@@ -102,10 +104,16 @@ impl TableCell {
             height: reader.read_hwp_unit()?,
             padding: CellPadding::from_reader(reader)?,
             border_fill_id: reader.read_u16()?,
+            list_properties: 0,
             paragraphs: Vec::new(),
         })
     }
 
+    /// Returns the raw vertical alignment (0 top, 1 center, 2 bottom).
+    pub const fn vertical_alignment_raw(&self) -> u8 {
+        ((self.list_properties >> 5) & 0x03) as u8
+    }
+
     /// Adds a paragraph to this cell.
     pub fn add_paragraph(&mut self, paragraph: Paragraph) {
         self.paragraphs.push(paragraph);
@@ -153,7 +161,7 @@ impl TableProperties {
         let padding = TablePadding::from_reader(reader)?;
 
         // Read row sizes
-        let mut row_sizes = Vec::with_capacity(row_count as usize);
+        let mut row_sizes = Vec::with_capacity(reader.capacity_for(row_count as usize, 2));
         for _ in 0..row_count {
             row_sizes.push(reader.read_hwp_unit16()?);
         }
@@ -153,6 +153,10 @@ pub struct Caption {
     direction: CaptionDirection,
     /// Gap between caption and object.
     gap: i32,
+    /// Caption width (used for left/right captions).
+    width: u32,
+    /// Whether the caption width includes the object margins.
+    include_margin: bool,
     /// Paragraphs in this caption.
     paragraphs: Vec<Paragraph>,
 }
@@ -163,6 +167,8 @@ impl Caption {
         Self {
             direction: CaptionDirection::Below,
             gap: 0,
+            width: 0,
+            include_margin: false,
             paragraphs: Vec::new(),
         }
     }
@@ -172,6 +178,8 @@ impl Caption {
         Self {
             direction,
             gap,
+            width: 0,
+            include_margin: false,
             paragraphs: Vec::new(),
         }
     }
@@ -186,6 +194,16 @@ impl Caption {
         self.gap
     }
 
+    /// Returns the caption width.
+    pub const fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Returns true if the caption width includes the object margins.
+    pub const fn includes_margin(&self) -> bool {
+        self.include_margin
+    }
+
     /// Returns the paragraphs.
     pub fn paragraphs(&self) -> &[Paragraph] {
         &self.paragraphs
@@ -236,6 +254,35 @@ impl Caption {
         Ok(Self {
             direction,
             gap,
+            width: 0,
+            include_margin: false,
+            paragraphs: Vec::new(),
+        })
+    }
+
+    /// Parses the caption data that follows the list header (표 73: 캡션).
+    ///
+    /// - UINT32: properties (bit 0~1 direction: left, right, top, bottom;
+    ///   bit 2 include margin)
+    /// - HWPUNIT: caption width
+    /// - HWPUNIT16: gap between caption and object
+    /// - HWPUNIT: maximum text length
+    pub fn from_list_data(reader: &mut ByteReader) -> Result<Self> {
+        let props = reader.read_u32()?;
+        let direction = match props & 0x03 {
+            0 => CaptionDirection::Left,
+            1 => CaptionDirection::Right,
+            2 => CaptionDirection::Above,
+            _ => CaptionDirection::Below,
+        };
+        let width = reader.read_u32()?;
+        let gap = i32::from(reader.read_i16()?);
+
+        Ok(Self {
+            direction,
+            gap,
+            width,
+            include_margin: (props & (1 << 2)) != 0,
             paragraphs: Vec::new(),
         })
     }
@@ -249,6 +296,11 @@ impl Caption {
     pub fn set_gap(&mut self, gap: i32) {
         self.gap = gap;
     }
+
+    /// Sets the caption width.
+    pub fn set_width(&mut self, width: u32) {
+        self.width = width;
+    }
 }
 
 #[cfg(test)]
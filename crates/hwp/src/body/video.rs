@@ -163,9 +163,11 @@ mod tests {
 
     #[test]
     fn test_video_data_dimensions() {
-        let mut video = VideoData::default();
-        video.width = 14400;
-        video.height = 8100;
+        let video = VideoData {
+            width: 14400,
+            height: 8100,
+            ..Default::default()
+        };
         assert_eq!(video.width, 14400);
         assert_eq!(video.height, 8100);
     }
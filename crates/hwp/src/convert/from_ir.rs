@@ -7,67 +7,71 @@
 //! 2. IR → HWP 파일 바이트 변환 (to_hwp_bytes)
 
 use ir::{
-    ConversionError, ConversionResult, Document as IrDocument, StyleStore,
+    CompatibleDocument, ConversionError, ConversionResult, Document as IrDocument, StyleStore,
     border_fill::{Border as IrBorder, BorderFill as IrBorderFill},
     control::{
-        AutoNumber as IrAutoNumber, AutoNumberType as IrAutoNumberType, Bookmark as IrBookmark,
-        Caption as IrCaption, CaptionPosition as IrCaptionPosition, Chart as IrChart,
-        ChartType as IrChartType, Control as IrControl, Equation as IrEquation,
-        FormObject as IrFormObject, FormObjectType as IrFormObjectType,
-        HeaderFooterControl as IrHeaderFooter, HiddenComment as IrHiddenComment,
-        Hyperlink as IrHyperlink, NewNumber as IrNewNumber, Note as IrNote,
-        ObjectCommon as IrObjectCommon, OleObject as IrOleObject, TextArt as IrTextArt,
-        TextArtAlignment as IrTextArtAlignment, TextArtFontStyle as IrTextArtFontStyle,
-        TextArtShapeType as IrTextArtShapeType, TextBox as IrTextBox, TextWrap as IrTextWrap,
-        Video as IrVideo, VideoType as IrVideoType,
+        AutoNumber as IrAutoNumber, AutoNumberType as IrAutoNumberType, ButtonBackStyle,
+        CaptionPosition as IrCaptionPosition, ChartType as IrChartType, Control as IrControl,
+        EditScrollBars, EditTabKeyBehavior, EditTextAlignment, Equation as IrEquation,
+        EquationLineMode, FormObject as IrFormObject, FormObjectType as IrFormObjectType,
+        Hyperlink as IrHyperlink, HyperlinkTarget, Note as IrNote, ObjectCommon as IrObjectCommon,
+        OleObject as IrOleObject, TextArt as IrTextArt, TextArtAlignment as IrTextArtAlignment,
+        TextArtFontStyle as IrTextArtFontStyle, TextArtShapeType as IrTextArtShapeType,
+        TextBox as IrTextBox, Video as IrVideo, VideoType as IrVideoType,
     },
     para_shape::{LineSpacingValue, ParaShape as IrParaShape, TabDef as IrTabDef},
     paragraph::{
-        FieldStart as IrFieldStart, Paragraph as IrParagraph, Run as IrRun,
-        RunContent as IrRunContent,
+        FieldStart as IrFieldStart, Paragraph as IrParagraph, RunContent as IrRunContent, TabChar,
     },
     picture::Picture as IrPicture,
     shape::{
         ArcType as IrArcType, CurvePointType as IrCurvePointType, Shape as IrShape,
         ShapeType as IrShapeType,
     },
-    table::{Table as IrTable, TableCell as IrTableCell},
+    table::{Table as IrTable, TableCell as IrTableCell, TablePageBreak},
 };
 use primitive::{
-    Alignment, FieldType as IrFieldType, HeaderFooterApplyTo,
-    HorizontalRelativeTo as IrHorizontalRelativeTo, LineType as IrLineType, NumberFormat,
-    StyleType as IrStyleType, TabLeader as IrTabLeader, TabType as IrTabType,
-    TextWrapSide as IrTextWrapSide, TextWrapType as IrTextWrapType,
-    VerticalRelativeTo as IrVerticalRelativeTo,
+    Alignment, Arrow, ArrowSize, BreakType, EndnotePlacement, FieldType as IrFieldType,
+    HeaderFooterApplyTo, HeadingType, HeightRelativeTo, HorizontalOffsetType,
+    HorizontalRelativeTo as IrHorizontalRelativeTo, ImageEffect, ImageFlip, Insets, LanguageType,
+    LineBreakKorean, LineBreakLatin, LineCap, LineOutlineStyle, LineSpacingType, LineStyle,
+    LineType as IrLineType, NoteNumbering, NumberFormat, ObjectNumberingType, PageNumberPosition,
+    PageOrientation, PageStartsOn, StyleType as IrStyleType, TabLeader as IrTabLeader,
+    TabType as IrTabType, TextDirection, TextWrapSide as IrTextWrapSide,
+    TextWrapType as IrTextWrapType, TransformMatrix, VerticalAlignment, VerticalOffsetType,
+    VerticalRelativeTo as IrVerticalRelativeTo, WidthRelativeTo,
 };
 
+use crate::body::ControlId;
 use crate::writer::{
     BodyWriter, DocInfoWriter, HwpWriter,
     body_writer::{
-        ArcShapeData, AutoNumberData, BookmarkData, CaptionData as HwpCaptionData,
-        CaptionDirection as HwpCaptionDirection, CharShapeRef, ChartData as HwpChartData,
-        ColumnDefinitionData, ContainerShapeData, ControlData, CurveShapeData, EllipseShapeData,
-        EndnoteShapeData, EquationData, FieldData as HwpFieldData,
-        FieldTypeData as HwpFieldTypeData, FootnoteShapeData, FormObjectData as HwpFormObjectData,
-        HeaderFooterData, HiddenCommentData, HyperlinkData, LineShapeData, NewNumberData, NoteData,
-        ObjectCommonData, OleData as HwpOleData, PageBorderFillData, PageDefinitionData,
-        ParagraphData, PictureData, PolygonShapeData, RectangleShapeData, SectionData,
-        SectionDefinitionData, ShapeData, ShapeTypeData, TableCellData, TableData,
-        TextArtData as HwpTextArtData, TextBoxData, VideoData as HwpVideoData,
+        AutoNumberData, BookmarkData, BorderLineData, CaptionData, CaptionDirection, CharShapeRef,
+        ChartData, ColumnDefinitionData, ComposeData, ControlData, DutmalData, EquationData, FieldData, FieldTypeData,
+        FormObjectData, HeaderFooterData, HiddenCommentData, IndexMarkData, LineSegmentData,
+        NewNumberData, NoteData, NoteShapeData, ObjectCommonData, OleData, PageBorderFillData,
+        PageDefinitionData, PageNumberData, ParagraphData, ParameterItemData, ParameterSetData,
+        ParameterValueData, PictureData, RawControlData, SectionData, SectionDefinitionData,
+        ShadowData, ShapeControlData, ShapeData, ShapeElementData, ShapeGeometry, ShapeTextData,
+        TableCellData, TableData, TableZoneData, TextArtData, VideoData,
     },
     doc_info_writer::{
-        BinaryDataInfo, BorderFillData, BorderLine, BulletData, CharShapeData, FontCounts,
-        FontData, NumberingData, NumberingLevelData, PanoseData, ParaShapeData, StyleData,
-        TabDefinitionData, TabItem,
+        BinaryDataInfo, BorderFillData, BorderLine, BulletData, CharShapeData,
+        DocumentPropertiesData, FontCounts, FontData, NumberingData, NumberingLevelData,
+        PanoseData, ParaShapeData, StyleData, TabDefinitionData, TabItem,
     },
 };
 
 use crate::doc_info::{
-    FillInfo as HwpFillInfo, GradientFill as HwpGradientFill, GradientType as HwpGradientType,
-    ImageFill as HwpImageFill, ImageFillType as HwpImageFillType, ImageInfo as HwpImageInfo,
-    PatternFill as HwpPatternFill, PatternType as HwpPatternType,
+    BorderLineThickness, FillInfo as HwpFillInfo, GradientFill as HwpGradientFill,
+    GradientType as HwpGradientType, ImageFill as HwpImageFill, ImageFillType as HwpImageFillType,
+    ImageInfo as HwpImageInfo, PatternFill as HwpPatternFill, PatternType as HwpPatternType,
 };
+use crate::doc_options::LinkDoc;
 use crate::primitive::ColorReference;
+use crate::script::{ScriptVersion, Scripts};
+use crate::summary::SummaryInfo;
+use primitive::Version;
 
 use super::{ColorConvert, FromIrContext};
 
@@ -84,29 +88,65 @@ pub trait IrToHwp {
 
 /// HWP 파일 생성을 위한 중간 데이터
 ///
-/// HwpDocument를 직접 생성할 수 없으므로 (파싱 전용),
-/// HWP 파일 생성에 필요한 데이터를 별도 구조체로 제공합니다.
-#[derive(Debug, Clone, Default)]
+/// HwpDocument를 직접 생성할 수 없으므로 (파싱 전용), 레코드로 인코딩한
+/// DocInfo와 섹션 스트림, BinData 항목, 부가 스트림을 모아 둡니다.
+/// [`HwpBuildData::into_writer`]로 [`HwpWriter`]를 만들어 파일을 씁니다.
+#[derive(Debug, Clone)]
 pub struct HwpBuildData {
-    /// 문서 제목
-    pub title: Option<String>,
-    /// 저자
-    pub author: Option<String>,
-    /// 섹션 데이터
-    pub sections: Vec<HwpSectionData>,
-    /// 바이너리 데이터 (이미지 등)
-    pub binary_data: Vec<(String, Vec<u8>)>,
-}
-
-/// HWP 섹션 데이터
-#[derive(Debug, Clone, Default)]
-pub struct HwpSectionData {
-    /// 페이지 너비 (HwpUnit)
-    pub page_width: i32,
-    /// 페이지 높이 (HwpUnit)
-    pub page_height: i32,
-    /// 문단 텍스트 목록
-    pub paragraphs: Vec<String>,
+    /// 파일 버전
+    pub version: Version,
+    /// DocInfo 스트림 (압축 전)
+    pub doc_info: Vec<u8>,
+    /// 섹션 스트림들 (압축 전)
+    pub sections: Vec<Vec<u8>>,
+    /// BinData 항목들 (DocInfo의 BIN_DATA 레코드 순서)
+    pub binary_data: Vec<HwpBinaryItem>,
+    /// 문서 요약 정보
+    pub summary_info: SummaryInfo,
+    /// 미리보기 텍스트
+    pub preview_text: String,
+    /// 스크립트
+    pub scripts: Option<Scripts>,
+    /// 연결 문서
+    pub link_doc: Option<LinkDoc>,
+}
+
+/// BinData 항목
+#[derive(Debug, Clone)]
+pub struct HwpBinaryItem {
+    /// BinData 아이디 (1부터, DocInfo의 BIN_DATA 순서)
+    pub id: u16,
+    /// BinData 스토리지 안의 스트림 이름 (`BIN0001.png` 형식)
+    pub stream_name: String,
+    /// 압축 여부
+    pub compressed: bool,
+    /// 데이터 (압축 전)
+    pub data: Vec<u8>,
+}
+
+impl HwpBuildData {
+    /// 빌드 데이터를 담은 [`HwpWriter`]를 만듭니다.
+    ///
+    /// BinData 항목은 항목별 압축 여부를 그대로 따릅니다.
+    pub fn into_writer(self) -> HwpWriter {
+        let mut writer = HwpWriter::new().with_version(self.version);
+        writer.set_doc_info(self.doc_info);
+        for section in self.sections {
+            writer.add_section(section);
+        }
+        for item in self.binary_data {
+            writer.add_binary_data_with_compression(item.stream_name, item.data, item.compressed);
+        }
+        writer.set_summary_info(self.summary_info);
+        writer.set_preview_text(self.preview_text);
+        if let Some(scripts) = self.scripts {
+            writer.set_scripts(scripts);
+        }
+        if let Some(link_doc) = self.link_doc {
+            writer.set_link_doc(link_doc);
+        }
+        writer
+    }
 }
 
 impl IrToHwp for IrDocument {
@@ -128,31 +168,31 @@ fn convert_to_hwp_data(
     ir: &IrDocument,
     ctx: &mut FromIrContext,
 ) -> Result<HwpBuildData, ConversionError> {
-    let mut data = HwpBuildData {
-        title: ir.metadata.title.clone(),
-        author: ir.metadata.author.clone(),
-        ..Default::default()
-    };
+    let section_count = ir.sections.len().max(1) as u16;
 
-    // 섹션 변환
-    for section in &ir.sections {
-        let section_data = HwpSectionData {
-            page_width: section.page.width.value(),
-            page_height: section.page.height.value(),
-            paragraphs: section
-                .paragraphs
-                .iter()
-                .map(|p| p.to_plain_text())
-                .collect(),
-        };
-        data.sections.push(section_data);
-    }
+    // BinData 아이디를 먼저 정해야 본문과 테두리/배경이 참조할 수 있음
+    let binary_data = build_binary_items(ir, ctx);
 
-    // 바이너리 데이터 변환
-    for (id, binary) in ir.binary_data.iter() {
-        data.binary_data
-            .push((id.value().to_string(), binary.data.clone()));
-    }
+    // DocInfo 빌드
+    let doc_info = build_doc_info(ir, section_count, &binary_data, ctx)?;
+
+    // BodyText 빌드
+    let sections = build_body_sections(ir, ctx)?;
+
+    // 스크립트와 연결 문서
+    let hwp_ext = ir.extensions.hwp.as_ref();
+    let scripts = hwp_ext
+        .and_then(|ext| ext.scripts.as_ref())
+        .map(build_scripts);
+    let link_doc = hwp_ext
+        .filter(|ext| !ext.linked_documents.is_empty())
+        .map(|ext| {
+            let mut link_doc = LinkDoc::new();
+            for path in &ext.linked_documents {
+                link_doc.add_path(path.clone());
+            }
+            link_doc
+        });
 
     // HWPX 확장 데이터 경고
     if ir.extensions.hwpx.is_some() {
@@ -160,7 +200,16 @@ fn convert_to_hwp_data(
             .data_loss("HWPX 확장 데이터는 HWP로 변환 시 손실됩니다");
     }
 
-    Ok(data)
+    Ok(HwpBuildData {
+        version: build_version(ir.metadata.version.as_ref()),
+        doc_info,
+        sections,
+        binary_data,
+        summary_info: build_summary_info(&ir.metadata),
+        preview_text: ir.to_preview_text(),
+        scripts,
+        link_doc,
+    })
 }
 
 /// IR → HWP 파일 바이트 변환
@@ -168,42 +217,116 @@ fn convert_to_hwp_bytes(
     ir: &IrDocument,
     ctx: &mut FromIrContext,
 ) -> Result<Vec<u8>, ConversionError> {
-    let section_count = ir.sections.len().max(1) as u16;
+    convert_to_hwp_data(ir, ctx)?
+        .into_writer()
+        .write_to_bytes()
+        .map_err(|e| ConversionError::unsupported(e.to_string()))
+}
 
-    // DocInfo 빌드
-    let doc_info_data = build_doc_info(ir, section_count, ctx)?;
+/// 문서 버전 → HWP 파일 버전 (HWP 5.x가 아니면 기본 버전)
+fn build_version(version: Option<&ir::DocumentVersion>) -> Version {
+    match version {
+        Some(v) if v.major == 5 => Version::new(
+            v.major as u8,
+            v.minor.min(255) as u8,
+            v.patch.min(255) as u8,
+            v.build.min(255) as u8,
+        ),
+        _ => Version::V5_0_3_0,
+    }
+}
 
-    // BodyText 빌드
-    let sections_data = build_body_sections(ir, ctx)?;
+/// BinData 항목 목록 빌드
+///
+/// `BIN{16진수}` 형식의 아이디는 숫자 순서로, 나머지는 그 뒤에 이름 순서로
+/// 1부터 다시 번호를 매기고 그 대응을 변환 컨텍스트에 기록합니다.
+fn build_binary_items(ir: &IrDocument, ctx: &mut FromIrContext) -> Vec<HwpBinaryItem> {
+    let mut entries: Vec<_> = ir.binary_data.iter().collect();
+    entries.sort_by_key(|(id, _)| {
+        let numeric = id
+            .value()
+            .strip_prefix("BIN")
+            .and_then(|hex| u16::from_str_radix(hex, 16).ok());
+        (numeric.is_none(), numeric, id.value().to_string())
+    });
 
-    // HwpWriter로 파일 생성
-    let mut writer = HwpWriter::new();
-    writer.set_doc_info(doc_info_data);
+    entries
+        .into_iter()
+        .zip(1u16..)
+        .map(|((ir_id, binary), id)| {
+            ctx.binary_ids.insert(ir_id.clone(), id);
+            HwpBinaryItem {
+                id,
+                stream_name: format!("BIN{:04X}.{}", id, binary_extension(binary)),
+                compressed: binary.compressed,
+                data: binary.data.clone(),
+            }
+        })
+        .collect()
+}
 
-    for section_data in sections_data {
-        writer.add_section(section_data);
-    }
+/// BinData 스트림 확장자 (원본 파일명의 확장자, 없으면 형식에서 결정)
+fn binary_extension(binary: &ir::BinaryData) -> String {
+    binary
+        .filename
+        .as_deref()
+        .and_then(|name| name.rsplit_once('.'))
+        .map(|(_, ext)| ext.to_string())
+        .filter(|ext| !ext.is_empty() && !ext.contains(['/', '\\']))
+        .unwrap_or_else(|| match binary.format {
+            ir::BinaryFormat::Unknown => detect_extension(&binary.data).to_string(),
+            format => format.extension().to_string(),
+        })
+}
 
-    // 바이너리 데이터 추가
-    for (id, binary) in ir.binary_data.iter() {
-        let ext = detect_extension(&binary.data);
-        let name = format!("BIN{:04X}.{}", id.value().parse::<u16>().unwrap_or(0), ext);
-        writer.add_binary_data(name, binary.data.clone());
+/// 메타데이터 → 문서 요약 정보
+fn build_summary_info(metadata: &ir::Metadata) -> SummaryInfo {
+    SummaryInfo {
+        title: metadata.title.clone(),
+        subject: metadata.subject.clone(),
+        author: metadata.author.clone(),
+        keywords: (!metadata.keywords.is_empty()).then(|| metadata.keywords.join(", ")),
+        comments: metadata.description.clone(),
+        last_author: metadata.last_saved_by.clone(),
+        application_name: None,
+        creation_date: metadata.created.clone(),
+        last_saved_date: metadata.modified.clone(),
     }
+}
 
-    writer
-        .write_to_bytes()
-        .map_err(|e| ConversionError::unsupported(e.to_string()))
+/// IR 스크립트 → HWP 스크립트
+fn build_scripts(scripts: &ir::HwpScripts) -> Scripts {
+    Scripts {
+        version: ScriptVersion {
+            high: scripts.version_high,
+            low: scripts.version_low,
+        },
+        header: scripts.header.clone(),
+        source: scripts.source.clone(),
+        pre_source: scripts.pre_source.clone(),
+        post_source: scripts.post_source.clone(),
+    }
 }
 
 /// DocInfo 스트림 빌드
 fn build_doc_info(
     ir: &IrDocument,
     section_count: u16,
+    binary_data: &[HwpBinaryItem],
     ctx: &mut FromIrContext,
 ) -> Result<Vec<u8>, ConversionError> {
     let mut doc_info = DocInfoWriter::new(section_count);
 
+    // 시작 번호, 캐럿 위치, 호환 문서
+    doc_info.set_document_properties(build_document_properties(&ir.settings));
+    if let Some(compatible) = ir.settings.compatible_document {
+        doc_info.set_compatible_document(match compatible {
+            CompatibleDocument::Current | CompatibleDocument::Hwp97 => 0,
+            CompatibleDocument::Hwp2007 => 1,
+            CompatibleDocument::MsWord => 2,
+        });
+    }
+
     // 폰트 설정
     let fonts = build_fonts(&ir.styles, ctx);
     doc_info.set_fonts(fonts);
@@ -228,7 +351,7 @@ fn build_doc_info(
 
     // BorderFill 변환
     for border_fill in &ir.styles.border_fills {
-        let data = convert_border_fill_to_hwp(border_fill);
+        let data = convert_border_fill_to_hwp(border_fill, ctx);
         doc_info.add_border_fill(data);
     }
 
@@ -250,115 +373,132 @@ fn build_doc_info(
         doc_info.add_bullet(data);
     }
 
-    // 바이너리 데이터 정보 추가
-    for (id, binary) in ir.binary_data.iter() {
-        let bin_id = id.value().parse::<u16>().unwrap_or_else(|_| {
-            // "BIN0001" 형식에서 숫자 추출
-            id.value()
-                .trim_start_matches("BIN")
-                .parse::<u16>()
-                .unwrap_or(0)
-        });
-
-        let extension = binary.format.extension().to_string();
-
-        let info = BinaryDataInfo {
+    // 바이너리 데이터 정보 (압축 모드 1=항상 압축, 2=압축 안 함)
+    for item in binary_data {
+        let compression: u16 = if item.compressed { 1 } else { 2 };
+        let extension = item
+            .stream_name
+            .rsplit_once('.')
+            .map(|(_, ext)| ext.to_string())
+            .unwrap_or_default();
+        doc_info.add_binary_data_info(BinaryDataInfo {
             // 타입: 0=링크, 1=임베드, 2=스토리지
-            // IR에서 임베드된 데이터만 지원하므로 1로 설정
-            data_type: 1,
+            data_type: 1 | (compression << 4),
             abs_path: String::new(),
             rel_path: String::new(),
-            bin_data_id: bin_id,
+            bin_data_id: item.id,
             extension,
-        };
-        doc_info.add_binary_data_info(info);
+        });
     }
 
     Ok(doc_info.build())
 }
 
+/// 문서 설정 → 문서 속성 (시작 번호가 0이면 1부터)
+fn build_document_properties(settings: &ir::DocumentSettings) -> DocumentPropertiesData {
+    let start = |number: u32| number.clamp(1, u16::MAX as u32) as u16;
+    let caret = settings.caret_position.clone().unwrap_or_default();
+
+    DocumentPropertiesData {
+        page_start_number: start(settings.starting_page_number),
+        footnote_start_number: start(settings.starting_footnote_number),
+        endnote_start_number: start(settings.starting_endnote_number),
+        caret_list_id: caret.section,
+        caret_paragraph_id: caret.paragraph,
+        caret_position: caret.position,
+        ..Default::default()
+    }
+}
+
 /// 폰트 목록 빌드
+///
+/// 언어 그룹이 기록된 폰트는 그룹별로 IR 순서를 유지하므로 글자 모양의
+/// 언어별 폰트 아이디가 그대로 유효합니다. 언어 그룹이 없는 폰트는 이름으로
+/// 한글/영문 그룹을 추정합니다.
 fn build_fonts(styles: &StyleStore, _ctx: &mut FromIrContext) -> FontCounts {
-    use ir::char_shape::FontType as IrFontType;
-
     let mut fonts = FontCounts::default();
 
-    // 폰트 수집 (기본 폰트 추가)
-    let mut korean_fonts: Vec<FontData> = Vec::new();
-    let mut english_fonts: Vec<FontData> = Vec::new();
-
     for font in &styles.fonts {
-        // 한글 폰트와 영문 폰트 구분 (간단한 휴리스틱)
-        let is_korean = font
-            .name
-            .chars()
-            .any(|c| ('\u{AC00}'..='\u{D7A3}').contains(&c));
-
-        // FontData 생성
-        let font_data = FontData {
-            name: font.name.clone(),
-            alternate_type: font.alternate_name.as_ref().map(|_| {
-                // FontType을 HWP 대체 폰트 타입으로 변환
-                match font.font_type {
-                    IrFontType::TrueType => 1,
-                    IrFontType::HangeulOnly => 2,
-                    IrFontType::Representative => 0,
-                }
-            }),
-            alternate_name: font.alternate_name.clone(),
-            panose_info: font.panose.map(|p| {
-                let bytes = p.to_bytes();
-                PanoseData {
-                    family_kind: bytes[0],
-                    serif_style: bytes[1],
-                    weight: bytes[2],
-                    proportion: bytes[3],
-                    contrast: bytes[4],
-                    stroke_variation: bytes[5],
-                    arm_style: bytes[6],
-                    letterform: bytes[7],
-                    midline: bytes[8],
-                    x_height: bytes[9],
+        let font_data = convert_font_to_hwp(font);
+
+        let group = match font.language {
+            Some(LanguageType::Korean) => &mut fonts.korean,
+            Some(LanguageType::English) => &mut fonts.english,
+            Some(LanguageType::Hanja) => &mut fonts.chinese,
+            Some(LanguageType::Japanese) => &mut fonts.japanese,
+            Some(LanguageType::Other) => &mut fonts.other,
+            Some(LanguageType::Symbol) => &mut fonts.symbol,
+            Some(LanguageType::User) => &mut fonts.user,
+            None => {
+                // 한글 폰트와 영문 폰트 구분 (간단한 휴리스틱)
+                let is_korean = font
+                    .name
+                    .chars()
+                    .any(|c| ('\u{AC00}'..='\u{D7A3}').contains(&c));
+                let group = if is_korean {
+                    &mut fonts.korean
+                } else {
+                    &mut fonts.english
+                };
+                if group.iter().any(|f| f.name == font.name) {
+                    continue;
                 }
-            }),
-            default_font_name: font.default_font_name.clone(),
-        };
-
-        if is_korean {
-            if !korean_fonts.iter().any(|f| f.name == font.name) {
-                korean_fonts.push(font_data);
+                group
             }
-        } else if !english_fonts.iter().any(|f| f.name == font.name) {
-            english_fonts.push(font_data);
-        }
+        };
+        group.push(font_data);
     }
 
     // 기본 폰트 추가 (없으면)
-    if korean_fonts.is_empty() {
-        korean_fonts.push(FontData {
-            name: "함초롬돋움".to_string(),
-            alternate_type: None,
-            alternate_name: None,
-            panose_info: None,
-            default_font_name: None,
-        });
-    }
-    if english_fonts.is_empty() {
-        english_fonts.push(FontData {
-            name: "함초롬돋움".to_string(),
-            alternate_type: None,
-            alternate_name: None,
-            panose_info: None,
-            default_font_name: None,
-        });
+    for group in [&mut fonts.korean, &mut fonts.english] {
+        if group.is_empty() {
+            group.push(FontData {
+                name: "함초롬돋움".to_string(),
+                alternate_type: None,
+                alternate_name: None,
+                panose_info: None,
+                default_font_name: None,
+            });
+        }
     }
 
-    fonts.korean = korean_fonts;
-    fonts.english = english_fonts;
-
     fonts
 }
 
+/// IR Font → HWP FontData 변환
+fn convert_font_to_hwp(font: &ir::char_shape::Font) -> FontData {
+    use ir::char_shape::FontType as IrFontType;
+
+    FontData {
+        name: font.name.clone(),
+        alternate_type: font.alternate_name.as_ref().map(|_| {
+            // FontType을 HWP 대체 폰트 타입으로 변환
+            match font.font_type {
+                IrFontType::TrueType => 1,
+                IrFontType::HangeulOnly => 2,
+                IrFontType::Representative => 0,
+            }
+        }),
+        alternate_name: font.alternate_name.clone(),
+        panose_info: font.panose.map(|p| {
+            let bytes = p.to_bytes();
+            PanoseData {
+                family_kind: bytes[0],
+                serif_style: bytes[1],
+                weight: bytes[2],
+                proportion: bytes[3],
+                contrast: bytes[4],
+                stroke_variation: bytes[5],
+                arm_style: bytes[6],
+                letterform: bytes[7],
+                midline: bytes[8],
+                x_height: bytes[9],
+            }
+        }),
+        default_font_name: font.default_font_name.clone(),
+    }
+}
+
 /// IR CharShape → HWP CharShapeData 변환
 fn convert_char_shape(shape: &ir::char_shape::CharShape) -> CharShapeData {
     use super::font_helper::extract_font_field;
@@ -387,17 +527,16 @@ fn convert_char_shape(shape: &ir::char_shape::CharShape) -> CharShapeData {
         }
     };
 
-    // 밑줄 모양
+    // 밑줄 모양 (HWP 명세 표 25 선 종류)
     let underline_shape = match shape.underline.line_type {
-        primitive::UnderlineType::None => 0,
-        primitive::UnderlineType::Single => 1,
-        primitive::UnderlineType::Double => 2,
-        primitive::UnderlineType::Thick => 3,
-        primitive::UnderlineType::Dotted => 4,
-        primitive::UnderlineType::Dash => 5,
-        primitive::UnderlineType::DashDot => 6,
-        primitive::UnderlineType::DashDotDot => 7,
-        primitive::UnderlineType::Wave => 8,
+        primitive::UnderlineType::None | primitive::UnderlineType::Single => 0,
+        primitive::UnderlineType::Dash => 2,
+        primitive::UnderlineType::DashDot => 3,
+        primitive::UnderlineType::DashDotDot => 4,
+        primitive::UnderlineType::Dotted => 6,
+        primitive::UnderlineType::Double => 7,
+        primitive::UnderlineType::Thick => 8,
+        primitive::UnderlineType::Wave => 11,
     };
 
     // 외곽선 종류
@@ -422,13 +561,13 @@ fn convert_char_shape(shape: &ir::char_shape::CharShape) -> CharShapeData {
     // 강조점
     let emphasis_val = match shape.emphasis.emphasis_type {
         primitive::EmphasisType::None => 0,
-        primitive::EmphasisType::Dot => 1,
-        primitive::EmphasisType::Circle => 2,
-        primitive::EmphasisType::CircleOpen => 3,
-        primitive::EmphasisType::Comma => 4,
-        primitive::EmphasisType::Colon => 5,
-        primitive::EmphasisType::Tilde => 6,
-        primitive::EmphasisType::Caron => 7,
+        primitive::EmphasisType::Circle => 1,
+        primitive::EmphasisType::CircleOpen => 2,
+        primitive::EmphasisType::Caron => 3,
+        primitive::EmphasisType::Tilde => 4,
+        // HWP에는 쉼표 강조점이 없으므로 점으로 대체
+        primitive::EmphasisType::Dot | primitive::EmphasisType::Comma => 5,
+        primitive::EmphasisType::Colon => 6,
     };
 
     // 속성 플래그 빌더로 구성
@@ -516,6 +655,16 @@ fn convert_para_shape(shape: &IrParaShape) -> ParaShapeData {
     // properties1 구성
     let mut properties1: u32 = (alignment & 0x07) << 2;
 
+    // 줄 나눔 기준 (영어 bit 5~6, 한글 bit 7)
+    properties1 |= match shape.line_break_latin {
+        LineBreakLatin::Word => 0,
+        LineBreakLatin::Hyphenation => 1,
+        LineBreakLatin::Character => 2,
+    } << 5;
+    if shape.line_break_korean == LineBreakKorean::Character {
+        properties1 |= 1 << 7;
+    }
+
     // snap_to_grid (bit 8)
     if shape.snap_to_grid {
         properties1 |= 1 << 8;
@@ -541,11 +690,30 @@ fn convert_para_shape(shape: &IrParaShape) -> ParaShapeData {
         properties1 |= 1 << 19;
     }
 
+    // 세로 정렬 (bit 20~21)
+    properties1 |= match shape.vertical_alignment {
+        VerticalAlignment::Baseline => 0,
+        VerticalAlignment::Top => 1,
+        VerticalAlignment::Middle => 2,
+        VerticalAlignment::Bottom => 3,
+    } << 20;
+
     // auto_line_height (bit 22) - 100%가 아니면 활성화
     if shape.auto_line_height_ratio.0 != 100.0 {
         properties1 |= 1 << 22;
     }
 
+    // 문단 머리 종류 (bit 23~24), 수준 (bit 25~27)
+    if let Some(ref numbering) = shape.numbering {
+        properties1 |= match numbering.heading_type {
+            HeadingType::None => 0,
+            HeadingType::Outline => 1,
+            HeadingType::Number => 2,
+            HeadingType::Bullet => 3,
+        } << 23;
+        properties1 |= (u32::from(numbering.level.saturating_sub(1)) & 0x07) << 25;
+    }
+
     // 문단 테두리 관련 속성
     if let Some(ref border) = shape.border {
         // connect (bit 28)
@@ -565,10 +733,28 @@ fn convert_para_shape(shape: &IrParaShape) -> ParaShapeData {
     if shape.suppress_line_numbers {
         properties2 |= 1;
     }
+    // 한글과 영어/숫자 사이 자동 간격 (bit 4, 5)
+    if shape.auto_spacing_east_asian_english {
+        properties2 |= 1 << 4;
+    }
+    if shape.auto_spacing_east_asian_number {
+        properties2 |= 1 << 5;
+    }
+
+    // 줄 간격 종류 (properties3 bit 0~4, 5.0.2.5 이전 형식은 properties1 bit 0~1)
+    let line_spacing_type = match shape.line_spacing.spacing_type {
+        LineSpacingType::Percent => 0,
+        LineSpacingType::Fixed => 1,
+        LineSpacingType::FontBased => 2,
+        LineSpacingType::AtLeast => 3,
+    };
+    if line_spacing_type < 3 {
+        properties1 |= line_spacing_type;
+    }
 
-    // 줄 간격 값 추출
+    // 줄 간격 값 (퍼센트는 그대로, 나머지는 HwpUnit)
     let line_spacing_val = match &shape.line_spacing.value {
-        LineSpacingValue::Percent(p) => (p.0 * 100.0) as i32, // 160% -> 16000
+        LineSpacingValue::Percent(p) => p.0.round() as i32,
         LineSpacingValue::Fixed(u) => u.value(),
     };
 
@@ -614,7 +800,7 @@ fn convert_para_shape(shape: &IrParaShape) -> ParaShapeData {
         indent: shape.first_line_indent.value(),
         space_before: shape.space_before.value(),
         space_after: shape.space_after.value(),
-        line_spacing: (line_spacing_val / 100) as i16, // HwpUnit -> 일반값
+        line_spacing: line_spacing_val,
         tab_def_id,
         numbering_bullet_id,
         border_fill_id,
@@ -623,7 +809,7 @@ fn convert_para_shape(shape: &IrParaShape) -> ParaShapeData {
         border_space_top,
         border_space_bottom,
         properties2,
-        properties3: 0,
+        properties3: line_spacing_type,
         line_spacing2: line_spacing_val as u32,
     }
 }
@@ -646,7 +832,7 @@ fn convert_style(style: &ir::style::Style) -> StyleData {
     }
 }
 
-/// BodyText 섹션들 빌드
+/// 본문 섹션 빌드
 fn build_body_sections(
     ir: &IrDocument,
     ctx: &mut FromIrContext,
@@ -667,963 +853,1705 @@ fn build_body_sections(
 }
 
 /// 섹션 빌드
+///
+/// 구역 정의와 단 정의, 섹션 수준의 머리말/꼬리말은 첫 문단의 컨트롤로 기록합니다.
 fn build_section(
     section: &ir::Section,
     ir_doc: &IrDocument,
-    _ctx: &mut FromIrContext,
+    ctx: &mut FromIrContext,
 ) -> Result<SectionData, ConversionError> {
-    let mut data = SectionData::default();
+    ctx.open_fields.clear();
 
-    // 페이지 정의
-    let orientation_flag = match section.page.orientation {
-        primitive::PageOrientation::Portrait => 0,
-        primitive::PageOrientation::Landscape => 1,
-    };
-    let gutter_flag = section.page.gutter_position.to_raw();
-    let properties = orientation_flag | ((gutter_flag as u32) << 1);
-
-    data.page_definition = Some(PageDefinitionData {
-        paper_width: section.page.width.value() as u32,
-        paper_height: section.page.height.value() as u32,
-        margin_left: section.page.margins.left.value() as u32,
-        margin_right: section.page.margins.right.value() as u32,
-        margin_top: section.page.margins.top.value() as u32,
-        margin_bottom: section.page.margins.bottom.value() as u32,
-        margin_header: section.page.margins.header.value() as u32,
-        margin_footer: section.page.margins.footer.value() as u32,
-        margin_gutter: section.page.margins.gutter.value() as u32,
-        properties,
-    });
-
-    // 페이지 테두리/배경
-    if let Some(ref page_border) = section.page_border_fill {
-        // properties 비트 구성:
-        // - 비트 0: 위치 (0=용지, 1=본문)
-        // - 비트 1: 머리글 포함
-        // - 비트 2: 바닥글 포함
-        // - 비트 3: 텍스트 뒤에 채우기
-        let position_flag = match page_border.position {
-            ir::section::PageBorderPosition::Paper => 0,
-            ir::section::PageBorderPosition::Body => 1,
-        };
-        let header_flag = if page_border.header_inside { 0x2 } else { 0 };
-        let footer_flag = if page_border.footer_inside { 0x4 } else { 0 };
-        let fill_behind_flag = if page_border.fill_behind { 0x8 } else { 0 };
-        let properties = position_flag | header_flag | footer_flag | fill_behind_flag;
-
-        data.page_border_fill = Some(PageBorderFillData {
-            border_fill_id: page_border.border_fill_id.value() as u16,
-            properties,
-            offset_left: page_border.offsets.left.value(),
-            offset_right: page_border.offsets.right.value(),
-            offset_top: page_border.offsets.top.value(),
-            offset_bottom: page_border.offsets.bottom.value(),
-        });
+    let mut leading_controls = vec![
+        ControlData::SectionDefinition(Box::new(build_section_definition(section, ir_doc))),
+        ControlData::ColumnDefinition(build_column_definition(&section.columns)),
+    ];
+    for header in &section.headers {
+        let header = build_header_footer(header.apply_to, &header.paragraphs, ctx)?;
+        leading_controls.push(ControlData::Header(header));
+    }
+    for footer in &section.footers {
+        let footer = build_header_footer(footer.apply_to, &footer.paragraphs, ctx)?;
+        leading_controls.push(ControlData::Footer(footer));
     }
 
-    // 각주 모양
-    if let Some(ref footnote_shape) = section.footnote_shape {
-        let base = &footnote_shape.base;
-        let number_format_flag = convert_note_number_format_to_hwp(base.number_format);
-        let superscript_flag = if base.superscript { 0x100 } else { 0 };
-        let properties = number_format_flag | superscript_flag;
-
-        data.footnote_shape = Some(FootnoteShapeData {
-            properties,
-            prefix: base.prefix.clone().unwrap_or_default(),
-            suffix: base.suffix.clone().unwrap_or_default(),
-            start_number: base.start_number as u16,
-            separator_length: base.separator_length.value() as u16, // HwpUnit → u16
-            separator_position: base
-                .separator_position
-                .map(|p| p.value() as u16)
-                .unwrap_or(0),
-            space_above: base.space_above.value() as u16,
-            space_below: base.space_below.value() as u16,
-            space_between: base.space_between.value() as u16,
-            separator_line_type: match base.separator_line_type {
-                primitive::LineType::None => 0,
-                primitive::LineType::Solid => 1,
-                primitive::LineType::Dash => 2,
-                primitive::LineType::Dot => 3,
-                primitive::LineType::DashDot => 4,
-                primitive::LineType::DashDotDot => 5,
-                primitive::LineType::LongDash => 6,
-                primitive::LineType::Double => 7,
-                primitive::LineType::Triple => 8,
-                primitive::LineType::Wave => 9,
-                primitive::LineType::DoubleWave => 10,
-                primitive::LineType::ThickThinLarge => 11,
-                primitive::LineType::ThinThickLarge => 12,
-                primitive::LineType::Circle => 13,
-            },
-            separator_line_thickness: base.separator_line_width,
-            // HWP 색상은 BGR 형식 (0x00BBGGRR)
-            separator_line_color: ((base.separator_line_color.blue as u32) << 16)
-                | ((base.separator_line_color.green as u32) << 8)
-                | (base.separator_line_color.red as u32),
-        });
+    let mut data = SectionData::default();
+    let empty_paragraph = IrParagraph::new();
+    let mut paragraphs = section.paragraphs.iter();
+
+    let mut first = ParagraphData::default();
+    for control in leading_controls {
+        first.push_control(control);
     }
+    build_paragraph_into(
+        paragraphs.next().unwrap_or(&empty_paragraph),
+        &mut first,
+        ctx,
+    )?;
+    data.paragraphs.push(first);
 
-    // 미주 모양
-    if let Some(ref endnote_shape) = section.endnote_shape {
-        let base = &endnote_shape.base;
-        let number_format_flag = convert_note_number_format_to_hwp(base.number_format);
-        let superscript_flag = if base.superscript { 0x100 } else { 0 };
-        let properties = number_format_flag | superscript_flag;
-
-        data.endnote_shape = Some(EndnoteShapeData {
-            properties,
-            prefix: base.prefix.clone().unwrap_or_default(),
-            suffix: base.suffix.clone().unwrap_or_default(),
-            start_number: base.start_number as u16,
-            separator_position: base
-                .separator_position
-                .map(|p| p.value() as u16)
-                .unwrap_or(0),
-        });
+    for para in paragraphs {
+        data.paragraphs.push(build_paragraph(para, ctx)?);
     }
 
-    // 구역 정의 (시작 페이지 번호 및 가시성 설정)
-    // properties 비트 구성 (표 130):
-    // - 비트 0: 머리글 숨김
-    // - 비트 1: 바닥글 숨김
-    // - 비트 2: 마스터페이지 숨김
-    // - 비트 3: 테두리 숨김
-    // - 비트 4: 배경 숨김
-    // - 비트 5: 쪽 번호 위치 숨김
+    Ok(data)
+}
+
+/// 구역 정의 빌드
+fn build_section_definition(section: &ir::Section, ir_doc: &IrDocument) -> SectionDefinitionData {
+    // 표 130: bit 0~5 감추기 설정, bit 20~21 쪽 번호 시작 방식
     let visibility = &section.extensions.visibility;
-    let hide_header_flag = if visibility.hide_header { 1 << 0 } else { 0 };
-    let hide_footer_flag = if visibility.hide_footer { 1 << 1 } else { 0 };
-    let hide_master_page_flag = if visibility.hide_master_page {
-        1 << 2
-    } else {
-        0
-    };
-    let hide_border_flag = if visibility.hide_border { 1 << 3 } else { 0 };
-    let hide_background_flag = if visibility.hide_background {
-        1 << 4
-    } else {
-        0
-    };
-    let hide_page_number_flag = if visibility.hide_page_number {
-        1 << 5
-    } else {
-        0
+    let hidden = [
+        visibility.hide_header,
+        visibility.hide_footer,
+        visibility.hide_master_page,
+        visibility.hide_border,
+        visibility.hide_background,
+        visibility.hide_page_number,
+    ];
+    let mut properties = hidden
+        .iter()
+        .enumerate()
+        .filter(|(_, hide)| **hide)
+        .fold(0u32, |props, (bit, _)| props | (1 << bit));
+    let page_starts_on: u32 = match section.start_number.page_starts_on {
+        PageStartsOn::Both => 0,
+        PageStartsOn::Even => 1,
+        PageStartsOn::Odd => 2,
     };
-    let properties = hide_header_flag
-        | hide_footer_flag
-        | hide_master_page_flag
-        | hide_border_flag
-        | hide_background_flag
-        | hide_page_number_flag;
-
-    // 가시성 설정이 있거나 시작 페이지 번호, 그리드 설정이 있으면 섹션 정의 생성
-    let has_visibility = visibility.hide_header
-        || visibility.hide_footer
-        || visibility.hide_master_page
-        || visibility.hide_border
-        || visibility.hide_background
-        || visibility.hide_page_number;
+    properties |= page_starts_on << 20;
 
     let grid = &section.extensions.grid;
-    let has_grid = grid.line_grid > 0 || grid.character_grid > 0;
-
-    // representative_language 가져오기
-    let language = ir_doc.settings.representative_language.unwrap_or(0);
-
-    if section.start_number.page > 0 || has_visibility || has_grid || language > 0 {
-        data.section_definition = Some(SectionDefinitionData {
-            properties,
-            starting_page_number: section.start_number.page as u16,
-            vertical_grid: grid.line_grid as u16,
-            horizontal_grid: grid.character_grid as u16,
-            language,
-            ..Default::default()
-        });
-    }
+    let start_number = &section.start_number;
 
-    // 줄 번호 모양
-    // 참고: HWP 5.0 바이너리 형식에서는 줄 번호 모양을 지원하지 않으므로
-    // section.extensions.line_number_shape는 무시됩니다.
-    // 이 기능은 HWPX 전용입니다.
+    SectionDefinitionData {
+        properties,
+        column_gap: section.columns.gap.value() as u16,
+        vertical_grid: grid.line_grid as u16,
+        horizontal_grid: grid.character_grid as u16,
+        starting_page_number: start_number.page as u16,
+        starting_figure_number: start_number.picture as u16,
+        starting_table_number: start_number.table as u16,
+        starting_equation_number: start_number.equation as u16,
+        language: ir_doc.settings.representative_language.unwrap_or(0),
+        page_definition: build_page_definition(&section.page),
+        footnote_shape: section.footnote_shape.as_ref().map(build_footnote_shape),
+        endnote_shape: section.endnote_shape.as_ref().map(build_endnote_shape),
+        page_border_fill: section
+            .page_border_fill
+            .as_ref()
+            .map(build_page_border_fill),
+        ..Default::default()
+    }
+}
 
-    // 단 정의 (다단 설정)
-    if section.columns.count > 1 || !section.columns.widths.is_empty() {
-        let direction_flag: u16 = match section.columns.direction {
-            ir::section::ColumnDirection::LeftToRight => 0,
-            ir::section::ColumnDirection::RightToLeft => 1,
-            ir::section::ColumnDirection::FacingPages => 2, // 맞쪽 페이지
-        };
-        let same_width_flag: u16 = if section.columns.widths.is_empty() {
-            1 << 12
-        } else {
-            0
-        };
-        let properties1 = section.columns.count << 2 | direction_flag << 10 | same_width_flag;
+/// 용지 설정 빌드
+fn build_page_definition(page: &ir::section::PageDefinition) -> PageDefinitionData {
+    PageDefinitionData {
+        paper_width: page.width.value() as u32,
+        paper_height: page.height.value() as u32,
+        margin_left: page.margins.left.value() as u32,
+        margin_right: page.margins.right.value() as u32,
+        margin_top: page.margins.top.value() as u32,
+        margin_bottom: page.margins.bottom.value() as u32,
+        margin_header: page.margins.header.value() as u32,
+        margin_footer: page.margins.footer.value() as u32,
+        margin_gutter: page.margins.gutter.value() as u32,
+        orientation: match page.orientation {
+            PageOrientation::Portrait => 0,
+            PageOrientation::Landscape => 1,
+        },
+        gutter_position: page.gutter_position.to_raw(),
+    }
+}
 
-        let separator_style = match section.columns.separator {
-            ir::section::ColumnSeparator::None => 0,
-            ir::section::ColumnSeparator::Solid => 1,
-            ir::section::ColumnSeparator::Dash => 2,
-            ir::section::ColumnSeparator::Dot => 3,
-        };
+/// 쪽 테두리/배경 빌드
+fn build_page_border_fill(page_border: &ir::section::PageBorderFill) -> PageBorderFillData {
+    // bit 0: 위치 (0=용지, 1=본문), bit 1~2: 머리말/꼬리말 포함, bit 3: 텍스트 뒤에 채우기
+    let mut properties = match page_border.position {
+        ir::section::PageBorderPosition::Paper => 0,
+        ir::section::PageBorderPosition::Body => 1,
+    };
+    if page_border.header_inside {
+        properties |= 0x2;
+    }
+    if page_border.footer_inside {
+        properties |= 0x4;
+    }
+    if page_border.fill_behind {
+        properties |= 0x8;
+    }
 
-        data.column_definition = Some(ColumnDefinitionData {
-            properties1,
-            column_gap: section.columns.gap.value() as u16,
-            column_widths: section
-                .columns
-                .widths
-                .iter()
-                .map(|w| w.value() as u16)
-                .collect(),
-            properties2: 0,
-            separator_style,
-            separator_thickness: section.columns.separator_thickness,
-            separator_color: ColorConvert::to_bgr_u32(&section.columns.separator_color),
-        });
+    PageBorderFillData {
+        properties,
+        offset_left: page_border.offsets.left.value() as i16,
+        offset_right: page_border.offsets.right.value() as i16,
+        offset_top: page_border.offsets.top.value() as i16,
+        offset_bottom: page_border.offsets.bottom.value() as i16,
+        border_fill_id: page_border.border_fill_id.value() as u16,
     }
+}
+
+/// 각주 모양 빌드
+fn build_footnote_shape(footnote_shape: &ir::section::FootnoteShape) -> NoteShapeData {
+    let numbering = footnote_shape.base.numbering;
+    // 쪽마다 새로 매기는 번호는 쪽 아래 배치로, 구역마다는 구역 끝 배치로 표현
+    let placement = match numbering {
+        NoteNumbering::RestartSection => 2,
+        NoteNumbering::Continuous | NoteNumbering::RestartPage => 0,
+    };
+    build_note_shape(
+        &footnote_shape.base,
+        placement,
+        numbering == NoteNumbering::Continuous,
+    )
+}
 
-    // 문단들
-    for para in &section.paragraphs {
-        let para_data = build_paragraph(para)?;
-        data.paragraphs.push(para_data);
+/// 미주 모양 빌드
+fn build_endnote_shape(endnote_shape: &ir::section::EndnoteShape) -> NoteShapeData {
+    let placement = match endnote_shape.placement {
+        EndnotePlacement::EndOfSection => 2,
+        EndnotePlacement::EndOfDocument => 3,
+    };
+    build_note_shape(
+        &endnote_shape.base,
+        placement,
+        endnote_shape.base.numbering == NoteNumbering::Continuous,
+    )
+}
+
+/// 각주/미주 공통 모양 빌드
+///
+/// 속성: bit 0~7 번호 모양, bit 8~9 배치, bit 10 위 첨자, bit 12 번호 이어서
+fn build_note_shape(
+    base: &ir::section::NoteShape,
+    placement: u32,
+    continue_numbering: bool,
+) -> NoteShapeData {
+    let mut properties = convert_note_number_format_to_hwp(base.number_format) | (placement << 8);
+    if base.superscript {
+        properties |= 0x400;
+    }
+    if continue_numbering {
+        properties |= 0x1000;
     }
 
-    Ok(data)
+    NoteShapeData {
+        properties,
+        user_char: first_utf16(base.user_character.as_deref()),
+        prefix_char: first_utf16(base.prefix.as_deref()),
+        suffix_char: first_utf16(base.suffix.as_deref()),
+        start_number: base.start_number as u16,
+        separator_length: base.separator_length.value() as u16,
+        separator_position: base
+            .separator_position
+            .map_or(0, |position| position.value() as u16),
+        space_above: base.space_above.value() as u16,
+        space_below: base.space_below.value() as u16,
+        space_between: base.space_between.value() as u16,
+        separator_line_type: convert_note_line_type_to_hwp(base.separator_line_type),
+        separator_line_thickness: base.separator_line_width,
+        separator_line_color: ColorConvert::to_bgr_u32(&base.separator_line_color),
+    }
 }
 
-/// NumberFormat을 HWP 번호 형식 플래그로 변환 (각주/미주용)
-/// 각주/미주는 0-7만 지원하므로 나머지는 가장 유사한 형식으로 매핑
-fn convert_note_number_format_to_hwp(format: NumberFormat) -> u32 {
+/// NumberFormat을 HWP 각주/미주 번호 모양으로 변환
+///
+/// 각주/미주 번호 모양은 아라비아 숫자, 로마 숫자, 영문자, 원 숫자만 지원하므로
+/// 나머지 형식은 아라비아 숫자로 기록합니다.
+const fn convert_note_number_format_to_hwp(format: NumberFormat) -> u32 {
     match format {
-        NumberFormat::Digit => 0,
-        NumberFormat::CircledDigit => 1,
-        NumberFormat::RomanUpper => 2,
-        NumberFormat::RomanLower => 3,
-        NumberFormat::LatinUpper => 4,
-        NumberFormat::LatinLower => 5,
-        NumberFormat::HangulSyllable => 6,
-        NumberFormat::HangulJamo => 7,
-        // 각주/미주에서 지원하지 않는 형식들은 가장 유사한 것으로 매핑
-        NumberFormat::CircledLatinUpper => 1, // CircledDigit으로 fallback
-        NumberFormat::CircledLatinLower => 1, // CircledDigit으로 fallback
-        NumberFormat::CircledHangul => 1,     // CircledDigit으로 fallback
-        NumberFormat::CircledHangulJamo => 1, // CircledDigit으로 fallback
-        NumberFormat::HangulIdeograph => 6,   // HangulSyllable으로 fallback
-        NumberFormat::Ideograph => 0,         // Digit으로 fallback
-        NumberFormat::CircledIdeograph => 1,  // CircledDigit으로 fallback
-        NumberFormat::Ganji => 0,             // Digit으로 fallback
+        NumberFormat::RomanUpper => 1,
+        NumberFormat::RomanLower => 2,
+        NumberFormat::LatinUpper => 3,
+        NumberFormat::LatinLower => 4,
+        NumberFormat::CircledDigit => 5,
+        _ => 0,
     }
 }
 
-/// RangeTag 변환 (IR → HWP)
-///
-/// IR RangeTag를 HWP RangeTagData로 변환합니다.
-fn convert_range_tag_to_hwp(
-    range_tag: &ir::paragraph::RangeTag,
-) -> crate::writer::body_writer::RangeTagData {
-    use crate::writer::body_writer::RangeTagData;
-    use ir::paragraph::RangeTagType;
+/// IR LineType → HWP 각주 구분선 종류 변환
+const fn convert_note_line_type_to_hwp(line_type: IrLineType) -> u8 {
+    match line_type {
+        IrLineType::None => 0,
+        IrLineType::Dash => 2,
+        IrLineType::Dot => 3,
+        IrLineType::DashDot => 4,
+        IrLineType::DashDotDot => 5,
+        IrLineType::LongDash => 6,
+        _ => 1,
+    }
+}
 
-    // 태그 종류를 상위 바이트로 변환
-    let tag_type_byte = match range_tag.tag_type {
-        RangeTagType::Bookmark => 0,
-        RangeTagType::Hyperlink => 1,
-        RangeTagType::TrackChangeInsert => 2,
-        RangeTagType::TrackChangeDelete => 3,
-        RangeTagType::Highlight => 4,
-        RangeTagType::Other(byte) => byte,
-    };
+/// 문자열의 첫 글자 (없으면 0)
+fn first_utf16(text: Option<&str>) -> u16 {
+    text.and_then(|text| text.encode_utf16().next())
+        .unwrap_or(0)
+}
 
-    // 태그 데이터 추출
-    let (tag_data_low, tag_data_mid) = if let Some(track_info) = &range_tag.track_change_info {
-        // 변경 추적 ID를 하위 16비트로 저장
-        let id_bytes = (track_info.track_change_id as u16).to_le_bytes();
-        (id_bytes[0], id_bytes[1])
-    } else if let Some(data_str) = &range_tag.data {
-        // 16진수 문자열을 바이트로 파싱
-        let bytes: Vec<u8> = (0..data_str.len())
-            .step_by(2)
-            .filter_map(|i| {
-                let end = (i + 2).min(data_str.len());
-                u8::from_str_radix(&data_str[i..end], 16).ok()
-            })
-            .collect();
-        (
-            bytes.get(1).copied().unwrap_or(0),
-            bytes.first().copied().unwrap_or(0),
-        )
+/// 단 정의 빌드
+fn build_column_definition(columns: &ir::section::ColumnDefinition) -> ColumnDefinitionData {
+    let direction: u16 = match columns.direction {
+        ir::section::ColumnDirection::LeftToRight => 0,
+        ir::section::ColumnDirection::RightToLeft => 1,
+        ir::section::ColumnDirection::FacingPages => 2,
+    };
+    let same_width: u16 = if columns.widths.is_empty() {
+        1 << 12
     } else {
-        (0, 0)
+        0
     };
 
-    RangeTagData {
-        start_position: range_tag.start,
-        end_position: range_tag.end,
-        tag: [tag_data_low, tag_data_mid, tag_type_byte],
+    ColumnDefinitionData {
+        properties: ((columns.count & 0xFF) << 2) | (direction << 10) | same_width,
+        column_gap: columns.gap.value() as u16,
+        column_widths: columns.widths.iter().map(|w| w.value() as u16).collect(),
+        properties2: 0,
+        separator_style: match columns.separator {
+            ir::section::ColumnSeparator::None => 0,
+            ir::section::ColumnSeparator::Solid => 1,
+            ir::section::ColumnSeparator::Dash => 2,
+            ir::section::ColumnSeparator::Dot => 3,
+        },
+        separator_thickness: columns.separator_thickness,
+        separator_color: ColorConvert::to_bgr_u32(&columns.separator_color),
     }
 }
 
-/// 문단 빌드
-fn build_paragraph(para: &IrParagraph) -> Result<ParagraphData, ConversionError> {
-    let text = para.to_plain_text();
-    let utf16: Vec<u16> = text.encode_utf16().collect();
+/// 머리말/꼬리말 빌드
+fn build_header_footer(
+    apply_to: HeaderFooterApplyTo,
+    paragraphs: &[IrParagraph],
+    ctx: &mut FromIrContext,
+) -> Result<HeaderFooterData, ConversionError> {
+    let apply_to = match apply_to {
+        HeaderFooterApplyTo::Both => 0,
+        HeaderFooterApplyTo::Even => 1,
+        HeaderFooterApplyTo::Odd => 2,
+        HeaderFooterApplyTo::First => {
+            ctx.warnings
+                .data_loss("첫 쪽 머리말/꼬리말은 HWP에서 지원하지 않아 양쪽으로 기록됩니다");
+            0
+        }
+    };
+
+    Ok(HeaderFooterData {
+        apply_to,
+        paragraphs: build_paragraphs(paragraphs, ctx)?,
+    })
+}
+
+/// 문단 목록 빌드
+fn build_paragraphs(
+    paragraphs: &[IrParagraph],
+    ctx: &mut FromIrContext,
+) -> Result<Vec<ParagraphData>, ConversionError> {
+    paragraphs
+        .iter()
+        .map(|para| build_paragraph(para, ctx))
+        .collect()
+}
 
-    // 컨트롤 추출 (표 등)
-    let controls = extract_controls(para)?;
+/// 문단 빌드
+fn build_paragraph(
+    para: &IrParagraph,
+    ctx: &mut FromIrContext,
+) -> Result<ParagraphData, ConversionError> {
+    let mut data = ParagraphData::default();
+    build_paragraph_into(para, &mut data, ctx)?;
+    Ok(data)
+}
 
-    // RangeTag 변환 (IR → HWP)
-    let range_tags = para
+/// 문단 내용을 ParagraphData에 이어 씀
+///
+/// 런이 바뀔 때마다 글자 모양 참조를 추가하고, 런 내용은 텍스트와 제어 문자,
+/// 컨트롤로 기록합니다.
+fn build_paragraph_into(
+    para: &IrParagraph,
+    data: &mut ParagraphData,
+    ctx: &mut FromIrContext,
+) -> Result<(), ConversionError> {
+    data.para_shape_id = para.para_shape_id.map_or(0, |id| id.value() as u16);
+    data.style_id = para.style_id.map_or(0, |id| id.value() as u8);
+    data.instance_id = para.instance_id.unwrap_or(0);
+    // HWP BreakType 비트: SECTION=0x01, PAGE=0x04, COLUMN=0x08
+    data.break_type = match para.break_type {
+        BreakType::None => 0,
+        BreakType::Section => 0x01,
+        BreakType::Page => 0x04,
+        BreakType::Column => 0x08,
+    };
+    data.range_tags = para
         .range_tags
         .iter()
         .map(convert_range_tag_to_hwp)
         .collect();
-
-    // CharShapeRef 생성 (Run별 char_shape_id 추적)
-    let char_shape_refs = build_char_shape_refs(para);
-
-    Ok(ParagraphData {
-        para_shape_id: para.para_shape_id.map(|id| id.value() as u16).unwrap_or(0),
-        style_id: para.style_id.map(|id| id.value() as u8).unwrap_or(0),
-        text: utf16,
-        char_shape_refs,
-        range_tags,
-        controls,
-    })
-}
-
-/// Run을 순회하면서 CharShapeRef 목록 생성
-fn build_char_shape_refs(para: &IrParagraph) -> Vec<CharShapeRef> {
-    let mut refs = Vec::new();
-    let mut position: u32 = 0;
-    let mut current_char_shape_id: Option<u32> = None;
+    data.line_segments = para
+        .line_segments
+        .iter()
+        .flatten()
+        .map(|segment| LineSegmentData {
+            text_start: segment.text_start,
+            vertical_position: segment.vertical_position.value(),
+            line_height: segment.line_height.value(),
+            text_height: segment.text_height.value(),
+            baseline_distance: segment.baseline_distance.value(),
+            line_spacing: segment.line_spacing.value(),
+            column_start: segment.column_start.value(),
+            segment_width: segment.segment_width.value(),
+            tag: 0,
+        })
+        .collect();
 
     for run in &para.runs {
-        // char_shape_id 변경 감지
-        let run_char_shape_id = run.char_shape_id.map(|id| id.value()).unwrap_or(0);
+        // 첫 글자 모양은 항상 위치 0에서 시작
+        let position = if data.char_shape_refs.is_empty() {
+            0
+        } else {
+            data.position()
+        };
+        let char_shape_id = run.char_shape_id.map_or(0, |id| id.value());
+        push_char_shape_ref(&mut data.char_shape_refs, position, char_shape_id);
 
-        if current_char_shape_id != Some(run_char_shape_id) {
-            refs.push(CharShapeRef {
-                position,
-                char_shape_id: run_char_shape_id,
-            });
-            current_char_shape_id = Some(run_char_shape_id);
+        for content in &run.contents {
+            build_run_content(content, data, ctx)?;
         }
-
-        // Run 길이 계산 (UTF-16 코드 유닛 기준)
-        position += get_run_utf16_length(run) as u32;
     }
 
-    // 비어있으면 기본값 추가
-    if refs.is_empty() {
-        refs.push(CharShapeRef {
-            position: 0,
-            char_shape_id: 0,
-        });
+    if data.char_shape_refs.is_empty() {
+        push_char_shape_ref(&mut data.char_shape_refs, 0, 0);
     }
 
-    refs
+    Ok(())
 }
 
-/// Run 내용의 UTF-16 길이 계산
-fn get_run_utf16_length(run: &IrRun) -> usize {
-    run.contents
-        .iter()
-        .map(|content| {
-            match content {
-                IrRunContent::Text(text) => text.text.encode_utf16().count(),
-                IrRunContent::Tab(_) => 1,
-                IrRunContent::LineBreak => 1,
-                IrRunContent::Hyphen => 1,
-                IrRunContent::NonBreakingSpace => 1,
-                IrRunContent::FixedWidthSpace => 1,
-                IrRunContent::Control(_) => 1, // 컨트롤은 확장문자 1글자
-                IrRunContent::FieldStart(_) => 1,
-                IrRunContent::FieldEnd(_) => 1,
-                IrRunContent::BookmarkStart(_) => 1,
-                IrRunContent::BookmarkEnd(_) => 1,
-                IrRunContent::Compose(c) => c.compose_text.encode_utf16().count(),
-                IrRunContent::Dutmal(d) => {
-                    d.main_text.encode_utf16().count() + d.sub_text.encode_utf16().count()
-                }
-            }
-        })
-        .sum()
+/// 글자 모양 참조 추가 (같은 모양이면 건너뛰고, 같은 위치면 덮어씀)
+fn push_char_shape_ref(refs: &mut Vec<CharShapeRef>, position: u32, char_shape_id: u32) {
+    match refs.last_mut() {
+        Some(last) if last.char_shape_id == char_shape_id => {}
+        Some(last) if last.position == position => last.char_shape_id = char_shape_id,
+        _ => refs.push(CharShapeRef {
+            position,
+            char_shape_id,
+        }),
+    }
 }
 
-/// 문단에서 컨트롤 추출
-fn extract_controls(para: &IrParagraph) -> Result<Vec<ControlData>, ConversionError> {
-    let mut controls = Vec::new();
-
-    for run in &para.runs {
-        for content in &run.contents {
-            match content {
-                IrRunContent::Control(ctrl) => {
-                    if let Some(control_data) = convert_control_to_hwp(ctrl.as_ref())? {
-                        controls.push(control_data);
+/// 런 내용 빌드
+fn build_run_content(
+    content: &IrRunContent,
+    data: &mut ParagraphData,
+    ctx: &mut FromIrContext,
+) -> Result<(), ConversionError> {
+    match content {
+        IrRunContent::Text(text) => {
+            for ch in text.text.chars() {
+                match ch {
+                    '\t' => data.push_inline(9, build_tab_inline(&TabChar::default())),
+                    '\n' => data.push_char(10),
+                    ch if (ch as u32) < 32 => {}
+                    ch => {
+                        let mut buffer = [0u16; 2];
+                        for &unit in ch.encode_utf16(&mut buffer).iter() {
+                            data.push_char(unit);
+                        }
                     }
                 }
-                IrRunContent::FieldStart(field_start) => {
-                    // 필드를 ControlData::Field로 변환
-                    let field_data = convert_field_start_to_hwp(field_start);
-                    controls.push(ControlData::Field(field_data));
+            }
+        }
+        IrRunContent::Tab(tab) => data.push_inline(9, build_tab_inline(tab)),
+        IrRunContent::LineBreak => data.push_char(10),
+        IrRunContent::Hyphen => data.push_char(24),
+        IrRunContent::NonBreakingSpace => data.push_char(30),
+        IrRunContent::FixedWidthSpace => data.push_char(31),
+        IrRunContent::Control(control) => {
+            if let Some(control) = build_control(control, ctx)? {
+                // 하이퍼링크도 필드로 기록되므로 필드 끝과 짝지음
+                if let ControlData::Field(field) = &control {
+                    ctx.open_fields.push(field.ctrl_id);
                 }
-                _ => {}
+                data.push_control(control);
             }
         }
+        IrRunContent::FieldStart(field_start) => {
+            let field = build_field(field_start);
+            ctx.open_fields.push(field.ctrl_id);
+            data.push_control(ControlData::Field(field));
+        }
+        IrRunContent::FieldEnd(_) => {
+            // 필드 끝 인라인 문자는 가장 최근에 열린 필드의 컨트롤 아이디를 가짐
+            if let Some(ctrl_id) = ctx.open_fields.pop() {
+                data.push_inline(4, [ctrl_id as u16, (ctrl_id >> 16) as u16, 0, 0, 0, 0]);
+            }
+        }
+        IrRunContent::BookmarkStart(bookmark) => {
+            data.push_control(ControlData::Bookmark(BookmarkData {
+                name: bookmark.name.clone(),
+            }));
+        }
+        IrRunContent::BookmarkEnd(_) => {}
+        IrRunContent::Compose(compose) => {
+            data.push_control(ControlData::Compose(build_compose(compose)));
+        }
+        IrRunContent::Dutmal(dutmal) => {
+            data.push_control(ControlData::Dutmal(build_dutmal(dutmal)));
+        }
     }
 
-    Ok(controls)
+    Ok(())
 }
 
-/// IR FieldStart → HWP FieldData 변환
-fn convert_field_start_to_hwp(field_start: &IrFieldStart) -> HwpFieldData {
-    let field_type = match field_start.field_type {
-        IrFieldType::Date => HwpFieldTypeData::Date,
-        IrFieldType::Time => HwpFieldTypeData::Time,
-        IrFieldType::FilePath => HwpFieldTypeData::FilePath,
-        IrFieldType::FileName => HwpFieldTypeData::FilePath, // FileName은 FilePath로 매핑
-        IrFieldType::Title => HwpFieldTypeData::DocTitle,
-        IrFieldType::Author => HwpFieldTypeData::Author,
-        IrFieldType::PageNumber => HwpFieldTypeData::PageNumber,
-        IrFieldType::PageCount => HwpFieldTypeData::TotalPages,
-        IrFieldType::Summary => HwpFieldTypeData::Summary,
-        IrFieldType::CrossReference => HwpFieldTypeData::CrossReference,
-        IrFieldType::Hyperlink => HwpFieldTypeData::Hyperlink,
-        IrFieldType::ClickHere => HwpFieldTypeData::ClickHere,
-        IrFieldType::UserInfo => HwpFieldTypeData::UserInfo,
-        IrFieldType::Formula => HwpFieldTypeData::Formula,
-        IrFieldType::Memo => HwpFieldTypeData::Memo,
-        IrFieldType::PrivateInfo => HwpFieldTypeData::PrivateInfo,
-        IrFieldType::MetaTag => HwpFieldTypeData::MetaTag,
-        IrFieldType::MailMerge => HwpFieldTypeData::MailMerge,
-        IrFieldType::TableOfContents => HwpFieldTypeData::TableOfContents,
-        IrFieldType::Bookmark | IrFieldType::Unknown => HwpFieldTypeData::Unknown,
+/// 글자 겹침 컨트롤 데이터 빌드
+fn build_compose(compose: &ir::paragraph::Compose) -> ComposeData {
+    use ir::paragraph::{ComposeCircleType, ComposeType};
+
+    let border_type = match compose.circle_type {
+        ComposeCircleType::Char => 0,
+        ComposeCircleType::ShapeCircle => 1,
+        ComposeCircleType::ShapeReversalCircle => 2,
+        ComposeCircleType::ShapeRectangle => 3,
+        ComposeCircleType::ShapeReversalRectangle => 4,
+        ComposeCircleType::ShapeTriangle => 5,
+        ComposeCircleType::ShapeReversalTriangle => 6,
+        ComposeCircleType::ShapeLight => 7,
+        ComposeCircleType::ShapeRhombus => 8,
+        ComposeCircleType::ShapeReversalRhombus => 9,
+        ComposeCircleType::ShapeRoundedRectangle => 10,
+        ComposeCircleType::ShapeEmptyCirculateTriangle => 11,
+        ComposeCircleType::ShapeThinCirculateTriangle => 12,
+        ComposeCircleType::ShapeThickCirculateTriangle => 13,
     };
 
-    // Note: parameters는 write_field에서 별도로 처리되거나 FieldData 구조 확장 필요
-    HwpFieldData {
-        field_type,
-        instruction: field_start.instruction.clone().unwrap_or_default(),
+    ComposeData {
+        text: compose.compose_text.clone(),
+        border_type,
+        inner_char_size: compose
+            .char_size
+            .map_or(0, |size| size.clamp(i8::MIN as i32, i8::MAX as i32) as i8),
+        spread_inner_chars: compose.compose_type == Some(ComposeType::Spread),
+        // 글자 모양 개수는 UINT8로 기록됨
+        char_shape_ids: compose
+            .char_shape_ids
+            .iter()
+            .take(u8::MAX as usize)
+            .map(|id| id.map_or(u32::MAX, |id| id.value()))
+            .collect(),
     }
 }
 
-/// IR 컨트롤 → HWP ControlData 변환
-fn convert_control_to_hwp(ctrl: &IrControl) -> Result<Option<ControlData>, ConversionError> {
-    match ctrl {
-        IrControl::Table(table) => {
-            let table_data = convert_table_to_hwp(table)?;
-            Ok(Some(ControlData::Table(table_data)))
-        }
-        IrControl::Picture(picture) => {
-            let picture_data = convert_picture_to_hwp(picture)?;
-            Ok(Some(ControlData::Picture(picture_data)))
-        }
-        IrControl::Equation(equation) => {
-            let equation_data = convert_equation_to_hwp(equation)?;
-            Ok(Some(ControlData::Equation(equation_data)))
-        }
-        IrControl::Header(header) => {
-            let header_data = convert_header_footer_to_hwp(header)?;
-            Ok(Some(ControlData::Header(header_data)))
-        }
-        IrControl::Footer(footer) => {
-            let footer_data = convert_header_footer_to_hwp(footer)?;
-            Ok(Some(ControlData::Footer(footer_data)))
-        }
-        IrControl::Footnote(note) => {
-            let note_data = convert_note_to_hwp(note)?;
-            Ok(Some(ControlData::Footnote(note_data)))
-        }
-        IrControl::Endnote(note) => {
-            let note_data = convert_note_to_hwp(note)?;
-            Ok(Some(ControlData::Endnote(note_data)))
-        }
-        IrControl::Hyperlink(link) => {
-            let link_data = convert_hyperlink_to_hwp(link)?;
-            Ok(Some(ControlData::Hyperlink(link_data)))
-        }
-        IrControl::Bookmark(bookmark) => {
-            let bookmark_data = convert_bookmark_to_hwp(bookmark)?;
-            Ok(Some(ControlData::Bookmark(bookmark_data)))
-        }
-        IrControl::AutoNumber(auto_num) => {
-            // HWPX PageNumber (위치 정보 포함)는 PageNumberData로 변환
-            if auto_num.number_type == IrAutoNumberType::Page
-                && let Some(fmt) = auto_num.auto_number_format.as_ref()
-                && let Some(position) = fmt.position
-            {
-                // PageNumberData로 변환
-                use crate::writer::body_writer::PageNumberData;
-                use primitive::PageNumberPosition;
-
-                let position_value = match position {
-                    PageNumberPosition::None => 0,
-                    PageNumberPosition::TopLeft => 1,
-                    PageNumberPosition::TopCenter => 2,
-                    PageNumberPosition::TopRight => 3,
-                    PageNumberPosition::BottomLeft => 4,
-                    PageNumberPosition::BottomCenter => 5,
-                    PageNumberPosition::BottomRight => 6,
-                    PageNumberPosition::OutsideTop => 7,
-                    PageNumberPosition::OutsideBottom => 8,
-                    PageNumberPosition::InsideTop => 9,
-                    PageNumberPosition::InsideBottom => 10,
-                };
-
-                let format_type = fmt.format_type.as_ref().unwrap_or(&auto_num.number_format);
-                let number_format = convert_auto_number_format_to_hwp(format_type);
+/// 덧말 컨트롤 데이터 빌드
+fn build_dutmal(dutmal: &ir::paragraph::Dutmal) -> DutmalData {
+    use ir::paragraph::{DutmalAlignment, DutmalPosition};
+
+    DutmalData {
+        main_text: dutmal.main_text.clone(),
+        sub_text: dutmal.sub_text.clone(),
+        position: match dutmal.position_type {
+            DutmalPosition::Top => 0,
+            DutmalPosition::Bottom => 1,
+        },
+        size_ratio: dutmal.size_ratio.unwrap_or(0),
+        option: dutmal.option.unwrap_or(0),
+        style_id: dutmal.style_id_ref.map_or(0, |id| id.value()),
+        alignment: match dutmal.alignment {
+            DutmalAlignment::Justify => 0,
+            DutmalAlignment::Left => 1,
+            DutmalAlignment::Right => 2,
+            DutmalAlignment::Center => 3,
+            DutmalAlignment::Distribute => 4,
+            DutmalAlignment::DistributeSpace => 5,
+        },
+    }
+}
 
-                let side_character = fmt
-                    .side_character
-                    .clone()
-                    .unwrap_or_else(|| "-".to_string());
+/// 탭 인라인 문자 데이터 빌드
+///
+/// 너비(UINT32), 채움 모양(하위 바이트)과 탭 종류(상위 바이트)를 기록합니다.
+fn build_tab_inline(tab: &TabChar) -> [u16; 6] {
+    let width = tab.width.map_or(0, |width| width.value().max(0) as u32);
+    let leader: u16 = match tab.leader {
+        Some('.') => 1,
+        Some('-') => 2,
+        Some('_') => 4,
+        _ => 0,
+    };
+    let tab_type = tab
+        .tab_type
+        .map_or(0, |tab_type| u16::from(tab_type.as_raw()));
+    [
+        width as u16,
+        (width >> 16) as u16,
+        leader | (tab_type << 8),
+        0,
+        0,
+        0,
+    ]
+}
 
-                return Ok(Some(ControlData::PageNumber(PageNumberData {
-                    position: position_value,
-                    number_format,
-                    side_character,
-                })));
-            }
+/// 필드 시작 → 필드 컨트롤 빌드
+fn build_field(field_start: &IrFieldStart) -> FieldData {
+    let field_type = match field_start.field_type {
+        IrFieldType::Date => FieldTypeData::Date,
+        IrFieldType::Time => FieldTypeData::Time,
+        IrFieldType::FilePath => FieldTypeData::FilePath,
+        IrFieldType::FileName => FieldTypeData::FilePath, // FileName은 FilePath로 매핑
+        IrFieldType::Title => FieldTypeData::DocTitle,
+        IrFieldType::Author => FieldTypeData::Author,
+        IrFieldType::PageNumber => FieldTypeData::PageNumber,
+        IrFieldType::PageCount => FieldTypeData::TotalPages,
+        IrFieldType::Summary => FieldTypeData::Summary,
+        IrFieldType::CrossReference => FieldTypeData::CrossReference,
+        IrFieldType::Hyperlink => FieldTypeData::Hyperlink,
+        IrFieldType::ClickHere => FieldTypeData::ClickHere,
+        IrFieldType::UserInfo => FieldTypeData::UserInfo,
+        IrFieldType::Formula => FieldTypeData::Formula,
+        IrFieldType::Memo => FieldTypeData::Memo,
+        IrFieldType::PrivateInfo => FieldTypeData::PrivateInfo,
+        IrFieldType::MetaTag => FieldTypeData::MetaTag,
+        IrFieldType::MailMerge => FieldTypeData::MailMerge,
+        IrFieldType::TableOfContents => FieldTypeData::TableOfContents,
+        IrFieldType::Bookmark | IrFieldType::Unknown => FieldTypeData::Unknown,
+    };
 
-            // 일반 AutoNumber로 변환
-            let auto_num_data = convert_auto_number_to_hwp(auto_num)?;
-            Ok(Some(ControlData::AutoNumber(auto_num_data)))
-        }
-        IrControl::NewNumber(new_num) => {
-            let new_num_data = convert_new_number_to_hwp(new_num)?;
-            Ok(Some(ControlData::NewNumber(new_num_data)))
-        }
-        IrControl::HiddenComment(comment) => {
-            let comment_data = convert_hidden_comment_to_hwp(comment)?;
-            Ok(Some(ControlData::HiddenComment(comment_data)))
-        }
-        IrControl::Shape(shape) => {
-            let shape_data = convert_shape_to_hwp(shape)?;
-            Ok(Some(ControlData::Shape(shape_data)))
-        }
-        IrControl::TextBox(text_box) => {
-            let textbox_data = convert_textbox_to_hwp(text_box)?;
-            Ok(Some(ControlData::TextBox(textbox_data)))
-        }
-        IrControl::Video(video) => {
-            let video_data = convert_video_to_hwp(video)?;
-            Ok(Some(ControlData::Video(video_data)))
-        }
-        IrControl::Ole(ole) => {
-            let ole_data = convert_ole_to_hwp(ole)?;
-            Ok(Some(ControlData::Ole(ole_data)))
-        }
-        IrControl::Chart(chart) => {
-            let chart_data = convert_chart_to_hwp(chart)?;
-            Ok(Some(ControlData::Chart(chart_data)))
-        }
-        IrControl::FormObject(form) => {
-            let form_data = convert_form_object_to_hwp(form)?;
-            Ok(Some(ControlData::FormObject(form_data)))
-        }
-        IrControl::TextArt(text_art) => {
-            let text_art_data = convert_text_art_to_hwp(text_art)?;
-            Ok(Some(ControlData::TextArt(text_art_data)))
-        }
-        IrControl::Memo(_memo) => {
-            // Memo는 HWP에서 HiddenComment와 유사하게 처리
-            // 현재 HWP ControlData에 Memo variant가 없으므로 None 반환
-            // TODO: HWP Memo 지원 필요 시 ControlData 확장 필요
-            Ok(None)
-        }
-        IrControl::IndexMark(_index_mark) => {
-            // IndexMark는 HWP에서 직접 지원하지 않음
-            // TODO: HWP IndexMark 지원 필요 시 ControlData 확장 필요
-            Ok(None)
-        }
-        // 기타 컨트롤 타입들
-        IrControl::Unknown(_) => Ok(None),
+    FieldData {
+        ctrl_id: u32::from_be_bytes(*field_type.to_control_id()),
+        properties: 0,
+        extra_properties: 0,
+        command: field_start.instruction.clone().unwrap_or_default(),
+        instance_id: field_start.field_id.unwrap_or(0),
+        parameters: field_start.parameters.as_ref().map(build_parameter_set),
     }
 }
 
-/// IR 수식 → HWP EquationData 변환
-fn convert_equation_to_hwp(equation: &IrEquation) -> Result<EquationData, ConversionError> {
-    // 캡션 변환 (있는 경우)
-    let caption = equation
-        .common
-        .caption
-        .as_ref()
-        .and_then(|c| convert_caption_to_hwp(c).ok());
+/// 필드 매개변수 → 매개변수 집합 빌드
+///
+/// "0x4000"처럼 16진수 이름을 가진 매개변수는 그 값을 아이템 아이디로 사용합니다.
+fn build_parameter_set(parameters: &ir::paragraph::FieldParameters) -> ParameterSetData {
+    use ir::paragraph::FieldParameter;
 
-    Ok(EquationData {
-        common: convert_object_common_to_hwp(&equation.common),
-        script: equation.script.clone(),
-        base_size: equation.font_size.value() as u32,
-        caption,
-    })
+    let items = parameters
+        .items
+        .iter()
+        .enumerate()
+        .filter_map(|(index, parameter)| {
+            let (name, value) = match parameter {
+                FieldParameter::Boolean { name, value } => {
+                    (name, ParameterValueData::Boolean(*value))
+                }
+                FieldParameter::Integer { name, value } => {
+                    let value = match i32::try_from(*value) {
+                        Ok(value) => ParameterValueData::Integer(value),
+                        Err(_) => ParameterValueData::Unsigned(*value as u32),
+                    };
+                    (name, value)
+                }
+                FieldParameter::Float { name, value } => {
+                    (name, ParameterValueData::String(value.to_string()))
+                }
+                FieldParameter::String { name, value } => {
+                    (name, ParameterValueData::String(value.clone()))
+                }
+                FieldParameter::List(_) => return None,
+            };
+            let id = name
+                .as_deref()
+                .and_then(|name| name.strip_prefix("0x"))
+                .and_then(|hex| u16::from_str_radix(hex, 16).ok())
+                .unwrap_or(0x4000 + index as u16);
+            Some(ParameterItemData { id, value })
+        })
+        .collect();
+
+    ParameterSetData { set_id: 0, items }
 }
 
-/// IR 표 → HWP TableData 변환
-fn convert_table_to_hwp(table: &IrTable) -> Result<TableData, ConversionError> {
-    // 열 너비 계산
-    let column_widths: Vec<u16> = if !table.rows.is_empty() && !table.rows[0].cells.is_empty() {
-        table.rows[0]
-            .cells
-            .iter()
-            .map(|cell| cell.width.value() as u16)
-            .collect()
-    } else {
-        vec![4252; table.column_count as usize] // 기본 15mm
+/// 하이퍼링크 → 하이퍼링크 필드 빌드
+///
+/// 명령 문자열은 대상 뒤에 ";1;0;0;"이 붙고, 대상 안의 `\`, `;`, `:`는 이스케이프합니다.
+fn build_hyperlink(hyperlink: &IrHyperlink) -> FieldData {
+    let target = match &hyperlink.target {
+        HyperlinkTarget::Url(url) | HyperlinkTarget::File(url) => url.clone(),
+        HyperlinkTarget::Email(address) if address.starts_with("mailto:") => address.clone(),
+        HyperlinkTarget::Email(address) => format!("mailto:{address}"),
+        HyperlinkTarget::Bookmark(name) if name.starts_with('#') => name.clone(),
+        HyperlinkTarget::Bookmark(name) => format!("#{name}"),
     };
 
-    // 셀 변환
-    let mut cells = Vec::new();
-    for row in &table.rows {
-        for cell in &row.cells {
-            let cell_data = convert_table_cell_to_hwp(cell)?;
-            cells.push(cell_data);
+    let mut command = String::with_capacity(target.len() + 8);
+    for ch in target.chars() {
+        if matches!(ch, '\\' | ';' | ':') {
+            command.push('\\');
         }
+        command.push(ch);
     }
+    command.push_str(";1;0;0;");
 
-    // 캡션 변환 (있는 경우)
-    let caption = table
-        .common
-        .caption
-        .as_ref()
-        .and_then(|c| convert_caption_to_hwp(c).ok());
-
-    // 표 속성 비트 필드 생성
-    let mut properties = 0u32;
+    FieldData {
+        ctrl_id: u32::from_be_bytes(*FieldTypeData::Hyperlink.to_control_id()),
+        properties: 0,
+        extra_properties: 0,
+        command,
+        instance_id: 0,
+        parameters: None,
+    }
+}
 
-    // bit 0-1: 페이지 나눔 설정
-    match table.page_break {
-        ir::table::TablePageBreak::None => {
-            properties |= 0; // 0 = 나누지 않음
+/// 컨트롤 빌드
+///
+/// HWP로 기록할 수 없는 컨트롤은 경고를 남기고 None을 반환합니다.
+fn build_control(
+    control: &IrControl,
+    ctx: &mut FromIrContext,
+) -> Result<Option<ControlData>, ConversionError> {
+    let control = match control {
+        IrControl::Table(table) => ControlData::Table(Box::new(build_table(table, ctx)?)),
+        IrControl::Picture(picture) => ControlData::Picture(Box::new(build_picture(picture, ctx)?)),
+        IrControl::Shape(shape) => ControlData::Shape(Box::new(ShapeControlData {
+            common: build_object_common(&shape.common),
+            caption: build_caption(&shape.common, ctx)?,
+            shape: build_shape(shape, 0, ctx)?,
+        })),
+        IrControl::Equation(equation) => {
+            ControlData::Equation(Box::new(build_equation(equation, ctx)?))
         }
-        ir::table::TablePageBreak::Cell => {
-            properties |= 1; // 1 = 셀 단위로 나눔
+        IrControl::Ole(ole) => ControlData::Ole(Box::new(build_ole(ole, ctx)?)),
+        IrControl::TextBox(text_box) => {
+            ControlData::Shape(Box::new(build_text_box(text_box, ctx)?))
         }
-        ir::table::TablePageBreak::Table => {
-            // TablePageBreak::Table은 HWP에서 지원하지 않으므로 Cell로 대체
-            properties |= 1;
+        IrControl::Header(header) => ControlData::Header(build_header_footer(
+            header.apply_to,
+            &header.paragraphs,
+            ctx,
+        )?),
+        IrControl::Footer(footer) => ControlData::Footer(build_header_footer(
+            footer.apply_to,
+            &footer.paragraphs,
+            ctx,
+        )?),
+        IrControl::Footnote(note) => ControlData::Footnote(build_note(note, ctx)?),
+        IrControl::Endnote(note) => ControlData::Endnote(build_note(note, ctx)?),
+        IrControl::Hyperlink(hyperlink) => ControlData::Field(build_hyperlink(hyperlink)),
+        IrControl::Bookmark(bookmark) => ControlData::Bookmark(BookmarkData {
+            name: bookmark.name.clone(),
+        }),
+        IrControl::IndexMark(index_mark) => ControlData::IndexMark(IndexMarkData {
+            first_key: index_mark.first_key.clone(),
+            second_key: index_mark.second_key.clone(),
+        }),
+        IrControl::AutoNumber(auto_number) => build_auto_number(auto_number),
+        IrControl::NewNumber(new_number) => ControlData::NewNumber(NewNumberData {
+            number_type: convert_auto_number_type_to_hwp(new_number.number_type),
+            number: new_number.number as u16,
+        }),
+        IrControl::HiddenComment(comment) => ControlData::HiddenComment(HiddenCommentData {
+            paragraphs: build_paragraphs(&comment.paragraphs, ctx)?,
+        }),
+        IrControl::Chart(chart) => ControlData::Chart(Box::new(ChartData {
+            common: build_object_common(&chart.common),
+            chart_type: convert_chart_type_to_hwp(chart.chart_type),
+        })),
+        IrControl::Video(video) => ControlData::Video(Box::new(build_video(video, ctx))),
+        IrControl::FormObject(form) => ControlData::FormObject(Box::new(build_form_object(form))),
+        IrControl::TextArt(text_art) => ControlData::TextArt(Box::new(build_text_art(text_art))),
+        IrControl::Memo(_) => {
+            ctx.warnings
+                .data_loss("메모는 HWP 본문에 기록하지 않습니다");
+            return Ok(None);
         }
-    }
+        IrControl::Unknown(unknown) => ControlData::Raw(RawControlData {
+            ctrl_id: u32::from_be_bytes(unknown.ctrl_id),
+            data: unknown.data.clone(),
+        }),
+    };
 
-    // bit 2: 제목 줄 자동 반복
-    if table.repeat_header {
-        properties |= 1 << 2;
-    }
+    Ok(Some(control))
+}
 
-    Ok(TableData {
-        common: convert_object_common_to_hwp(&table.common),
-        rows: table.row_count,
-        columns: table.column_count,
-        column_widths,
-        cells,
-        border_fill_id: table
-            .border_fill_id
-            .map(|id| id.value() as u16)
-            .unwrap_or(0),
-        caption,
-        properties,
+/// 각주/미주 빌드
+fn build_note(note: &IrNote, ctx: &mut FromIrContext) -> Result<NoteData, ConversionError> {
+    Ok(NoteData {
+        number: note.number as u16,
+        paragraphs: build_paragraphs(&note.paragraphs, ctx)?,
     })
 }
 
-/// IR 표 셀 → HWP TableCellData 변환
-fn convert_table_cell_to_hwp(cell: &IrTableCell) -> Result<TableCellData, ConversionError> {
-    // 셀 내용 (문단들) 변환
-    let paragraphs: Vec<ParagraphData> = cell
-        .paragraphs
-        .iter()
-        .map(build_paragraph)
-        .collect::<Result<Vec<_>, _>>()?;
+/// 자동 번호 빌드
+///
+/// 쪽 번호 위치가 있는 쪽 번호는 쪽 번호 위치 컨트롤(pgnp)로 기록합니다.
+fn build_auto_number(auto_number: &IrAutoNumber) -> ControlData {
+    if auto_number.number_type == IrAutoNumberType::Page
+        && let Some(format) = &auto_number.auto_number_format
+        && let Some(position) = format.position
+    {
+        let number_format = format.format_type.unwrap_or(auto_number.number_format);
+        return ControlData::PageNumber(PageNumberData {
+            position: convert_page_number_position_to_hwp(position),
+            number_format: u32::from(convert_auto_number_format_to_hwp(&number_format)),
+            side_character: first_utf16(format.side_character.as_deref()),
+        });
+    }
 
-    Ok(TableCellData {
-        col: cell.column,
-        row: cell.row,
-        col_span: cell.column_span,
-        row_span: cell.row_span,
-        width: cell.width.value() as u32,
-        height: cell.height.value() as u32,
-        paragraphs,
-        border_fill_id: cell.border_fill_id.map(|id| id.value() as u16).unwrap_or(0),
+    ControlData::AutoNumber(AutoNumberData {
+        number_type: convert_auto_number_type_to_hwp(auto_number.number_type),
+        number_format: u32::from(convert_auto_number_format_to_hwp(
+            &auto_number.number_format,
+        )),
     })
 }
 
-/// 바이너리 데이터 확장자 감지
-fn detect_extension(data: &[u8]) -> &'static str {
-    if data.starts_with(&[0x89, b'P', b'N', b'G']) {
-        "png"
-    } else if data.starts_with(&[0xFF, 0xD8, 0xFF]) {
-        "jpg"
-    } else if data.starts_with(b"GIF") {
-        "gif"
-    } else if data.starts_with(b"BM") {
-        "bmp"
-    } else if data.starts_with(&[0x00, 0x00, 0x01, 0x00]) {
-        "ico"
-    } else {
-        "dat"
+/// 자동 번호 종류 변환
+const fn convert_auto_number_type_to_hwp(number_type: IrAutoNumberType) -> u32 {
+    match number_type {
+        IrAutoNumberType::Page => 0,
+        IrAutoNumberType::Footnote => 1,
+        IrAutoNumberType::Endnote => 2,
+        IrAutoNumberType::Picture => 3,
+        IrAutoNumberType::Table => 4,
+        IrAutoNumberType::Equation => 5,
+        IrAutoNumberType::TotalPages => 6,
     }
 }
 
-// ColorConvert::to_bgr_u32을 사용합니다 (색상 변환 통합)
-
-/// IR ShadowType → HWP 비트 값 (0=없음, 1=discrete, 2=continuous)
-fn convert_shadow_type_to_bits(shadow_type: &primitive::ShadowType) -> u32 {
-    match shadow_type {
-        primitive::ShadowType::None => 0,
-        // 기본 방향 (스타일 구분 없음) - discrete로 처리
-        primitive::ShadowType::TopLeft
-        | primitive::ShadowType::TopRight
-        | primitive::ShadowType::BottomLeft
-        | primitive::ShadowType::BottomRight => 1,
-        // Discrete 스타일 (모든 방향)
-        primitive::ShadowType::TopLeftDiscrete
-        | primitive::ShadowType::TopRightDiscrete
-        | primitive::ShadowType::BottomLeftDiscrete
-        | primitive::ShadowType::BottomRightDiscrete => 1,
-        // Continuous 스타일 (모든 방향)
-        primitive::ShadowType::TopLeftContinuous
-        | primitive::ShadowType::TopRightContinuous
-        | primitive::ShadowType::BottomLeftContinuous
-        | primitive::ShadowType::BottomRightContinuous => 2,
+/// 쪽 번호 위치 변환 (표 150: bit 0~3)
+const fn convert_page_number_position_to_hwp(position: PageNumberPosition) -> u32 {
+    match position {
+        PageNumberPosition::None => 0,
+        PageNumberPosition::TopLeft => 1,
+        PageNumberPosition::TopCenter => 2,
+        PageNumberPosition::TopRight => 3,
+        PageNumberPosition::BottomLeft => 4,
+        PageNumberPosition::BottomCenter => 5,
+        PageNumberPosition::BottomRight => 6,
+        PageNumberPosition::OutsideTop => 7,
+        PageNumberPosition::OutsideBottom => 8,
+        PageNumberPosition::InsideTop => 9,
+        PageNumberPosition::InsideBottom => 10,
     }
 }
 
-/// IR ObjectCommon → HWP ObjectCommonData 변환
-fn convert_object_common_to_hwp(common: &IrObjectCommon) -> ObjectCommonData {
-    // TextWrap을 properties 비트 필드로 변환
-    let properties = convert_text_wrap_to_properties(&common.text_wrap);
-
+/// 개체 공통 속성 빌드
+fn build_object_common(common: &IrObjectCommon) -> ObjectCommonData {
     ObjectCommonData {
-        properties,
+        properties: build_object_properties(common),
         vertical_offset: common.position.y.value(),
         horizontal_offset: common.position.x.value(),
         width: common.size.width.value(),
         height: common.size.height.value(),
         z_order: common.z_order,
-        margin_left: common.margin.left.value() as u16,
-        margin_right: common.margin.right.value() as u16,
-        margin_top: common.margin.top.value() as u16,
-        margin_bottom: common.margin.bottom.value() as u16,
+        margin_left: common.margin.left.value() as i16,
+        margin_right: common.margin.right.value() as i16,
+        margin_top: common.margin.top.value() as i16,
+        margin_bottom: common.margin.bottom.value() as i16,
+        instance_id: common.id.unwrap_or(0),
+        prevent_page_break: 0,
+        description: common.shape_comment.clone().unwrap_or_default(),
     }
 }
 
-/// IR TextWrap → HWP properties 비트 필드로 변환
-fn convert_text_wrap_to_properties(text_wrap: &IrTextWrap) -> u32 {
-    let mut props: u32 = 0;
-
-    // Bit 0: 글자처럼 취급
-    if text_wrap.treat_as_char {
-        props |= 1 << 0;
-    }
+/// 개체 공통 속성 비트 빌드 (표 70)
+fn build_object_properties(common: &IrObjectCommon) -> u32 {
+    let wrap = &common.text_wrap;
 
-    // Bits 3-4: 세로 기준
-    let vertical_rel = match text_wrap.vertical_rel {
+    let vertical_rel: u32 = match wrap.vertical_rel {
         IrVerticalRelativeTo::Paper => 0,
         IrVerticalRelativeTo::Page => 1,
         IrVerticalRelativeTo::Paragraph => 2,
     };
-    props |= (vertical_rel & 0x3) << 3;
-
-    // Bits 8-9: 가로 기준
-    let horizontal_rel = match text_wrap.horizontal_rel {
+    let vertical_offset_type: u32 = match wrap.vertical_offset_type {
+        VerticalOffsetType::Top => 0,
+        VerticalOffsetType::Center => 1,
+        VerticalOffsetType::Bottom => 2,
+        VerticalOffsetType::Inside => 3,
+        VerticalOffsetType::Outside => 4,
+    };
+    let horizontal_rel: u32 = match wrap.horizontal_rel {
         IrHorizontalRelativeTo::Paper => 0,
         IrHorizontalRelativeTo::Page => 1,
         IrHorizontalRelativeTo::Column => 2,
         IrHorizontalRelativeTo::Paragraph => 3,
     };
-    props |= (horizontal_rel & 0x3) << 8;
-
-    // Bit 14: 다른 개체 겹침 허용
-    if text_wrap.allow_overlap {
-        props |= 1 << 14;
-    }
-
-    // Bits 21-23: 배치 종류
-    let wrap_type = match text_wrap.wrap_type {
-        IrTextWrapType::Inline => 0,
-        IrTextWrapType::Square => 0,
+    let horizontal_offset_type: u32 = match wrap.horizontal_offset_type {
+        HorizontalOffsetType::Left => 0,
+        HorizontalOffsetType::Center => 1,
+        HorizontalOffsetType::Right => 2,
+        HorizontalOffsetType::Inside => 3,
+        HorizontalOffsetType::Outside => 4,
+    };
+    let width_rel: u32 = match common.width_relative_to {
+        WidthRelativeTo::Paper => 0,
+        WidthRelativeTo::Page => 1,
+        WidthRelativeTo::Column => 2,
+        WidthRelativeTo::Paragraph => 3,
+        WidthRelativeTo::Absolute => 4,
+    };
+    let height_rel: u32 = match common.height_relative_to {
+        HeightRelativeTo::Paper => 0,
+        HeightRelativeTo::Page => 1,
+        HeightRelativeTo::Absolute => 2,
+    };
+    let wrap_type: u32 = match wrap.wrap_type {
+        IrTextWrapType::Inline | IrTextWrapType::Square => 0,
         IrTextWrapType::Tight => 1,
         IrTextWrapType::Behind => 4,
         IrTextWrapType::InFront => 5,
     };
-    props |= (wrap_type & 0x7) << 21;
-
-    // Bits 24-25: 배치 방향
-    let wrap_side = match text_wrap.wrap_side {
+    let wrap_side: u32 = match wrap.wrap_side {
         IrTextWrapSide::Both => 0,
         IrTextWrapSide::Left => 1,
         IrTextWrapSide::Right => 2,
         IrTextWrapSide::Largest => 3,
     };
-    props |= (wrap_side & 0x3) << 24;
+    let numbering: u32 = match common.numbering_type {
+        None | Some(ObjectNumberingType::None) => 0,
+        Some(ObjectNumberingType::Picture) => 1,
+        Some(ObjectNumberingType::Table) => 2,
+        Some(ObjectNumberingType::Equation) => 3,
+    };
 
-    props
+    let mut properties = u32::from(wrap.treat_as_char)
+        | (vertical_rel << 3)
+        | (vertical_offset_type << 5)
+        | (horizontal_rel << 8)
+        | (horizontal_offset_type << 10)
+        | (width_rel << 15)
+        | (height_rel << 18)
+        | (wrap_type << 21)
+        | (wrap_side << 24)
+        | (numbering << 26);
+    if wrap.flow_with_text {
+        properties |= 1 << 13;
+    }
+    if wrap.allow_overlap {
+        properties |= 1 << 14;
+    }
+    properties
 }
 
-/// IR Caption → HWP CaptionData 변환
-fn convert_caption_to_hwp(caption: &IrCaption) -> Result<HwpCaptionData, ConversionError> {
-    // 캡션 방향 변환
-    let direction = match caption.position {
-        IrCaptionPosition::Bottom => HwpCaptionDirection::Below,
-        IrCaptionPosition::Top => HwpCaptionDirection::Above,
-        IrCaptionPosition::Left => HwpCaptionDirection::Left,
-        IrCaptionPosition::Right => HwpCaptionDirection::Right,
+/// 캡션 빌드
+fn build_caption(
+    common: &IrObjectCommon,
+    ctx: &mut FromIrContext,
+) -> Result<Option<CaptionData>, ConversionError> {
+    let Some(caption) = &common.caption else {
+        return Ok(None);
     };
 
-    // 캡션 문단 변환
-    let paragraphs: Vec<ParagraphData> = caption
-        .paragraphs
-        .iter()
-        .filter_map(|para| build_paragraph(para).ok())
-        .collect();
-
-    Ok(HwpCaptionData {
-        direction,
-        gap: caption.gap.value(),
-        paragraphs,
-    })
+    Ok(Some(CaptionData {
+        direction: match caption.position {
+            IrCaptionPosition::Left => CaptionDirection::Left,
+            IrCaptionPosition::Right => CaptionDirection::Right,
+            IrCaptionPosition::Top => CaptionDirection::Above,
+            IrCaptionPosition::Bottom => CaptionDirection::Below,
+        },
+        include_margin: false,
+        width: caption.width.value().max(0) as u32,
+        gap: caption.gap.value() as i16,
+        max_length: common.size.width.value().max(0) as u32,
+        paragraphs: build_paragraphs(&caption.paragraphs, ctx)?,
+    }))
 }
 
-/// IR 그림 → HWP PictureData 변환
-fn convert_picture_to_hwp(picture: &IrPicture) -> Result<PictureData, ConversionError> {
-    // 바이너리 데이터 ID 파싱
-    let binary_id_str = picture.binary_id.value();
-    let binary_data_id = binary_id_str.parse::<u16>().unwrap_or_else(|_| {
-        // BINxxxx 형식에서 숫자 추출 시도
-        binary_id_str
-            .strip_prefix("BIN")
-            .and_then(|hex_str| u16::from_str_radix(hex_str, 16).ok())
-            .unwrap_or(0)
+/// 표 빌드
+fn build_table(table: &IrTable, ctx: &mut FromIrContext) -> Result<TableData, ConversionError> {
+    // bit 0~1: 쪽 경계에서 나눔, bit 2: 제목 줄 자동 반복
+    let mut properties = match table.page_break {
+        TablePageBreak::None => 0,
+        TablePageBreak::Cell => 1,
+        TablePageBreak::Table => 2,
+    };
+    if table.repeat_header {
+        properties |= 1 << 2;
+    }
+
+    let padding = table.inside_margin.map_or([0; 4], |margin| {
+        [
+            margin.left.value() as i16,
+            margin.right.value() as i16,
+            margin.top.value() as i16,
+            margin.bottom.value() as i16,
+        ]
     });
 
-    // 캡션 변환 (있는 경우)
-    let caption = picture
-        .common
-        .caption
-        .as_ref()
-        .and_then(|c| convert_caption_to_hwp(c).ok());
+    let mut cells = Vec::new();
+    for row in &table.rows {
+        for cell in &row.cells {
+            cells.push(build_table_cell(cell, ctx)?);
+        }
+    }
 
-    Ok(PictureData {
-        common: convert_object_common_to_hwp(&picture.common),
-        binary_data_id,
-        width: picture.original_size.width.value() as u32,
-        height: picture.original_size.height.value() as u32,
-        caption,
+    Ok(TableData {
+        common: build_object_common(&table.common),
+        caption: build_caption(&table.common, ctx)?,
+        properties,
+        rows: table.row_count,
+        columns: table.column_count,
+        cell_spacing: table.cell_spacing.value() as i16,
+        padding,
+        row_heights: table
+            .rows
+            .iter()
+            .map(|row| row.height.value() as u16)
+            .collect(),
+        border_fill_id: table.border_fill_id.map_or(0, |id| id.value() as u16),
+        zones: table
+            .zones
+            .iter()
+            .map(|zone| TableZoneData {
+                start_column: zone.start_column,
+                start_row: zone.start_row,
+                end_column: zone.end_column,
+                end_row: zone.end_row,
+                border_fill_id: zone.border_fill_id.map_or(0, |id| id.value() as u16),
+            })
+            .collect(),
+        cells,
     })
 }
 
-/// IR 머리글/바닥글 → HWP HeaderFooterData 변환
-fn convert_header_footer_to_hwp(hf: &IrHeaderFooter) -> Result<HeaderFooterData, ConversionError> {
-    let apply_to = match hf.apply_to {
-        HeaderFooterApplyTo::Both => 0,
-        HeaderFooterApplyTo::Even => 1,
-        HeaderFooterApplyTo::Odd => 2,
-        HeaderFooterApplyTo::First => 0, // HWP doesn't have First, use Both
+/// 표 셀 빌드
+fn build_table_cell(
+    cell: &IrTableCell,
+    ctx: &mut FromIrContext,
+) -> Result<TableCellData, ConversionError> {
+    // 문단 리스트 속성 bit 5~6: 세로 정렬
+    let vertical_alignment: u32 = match cell.vertical_alignment {
+        VerticalAlignment::Middle => 1,
+        VerticalAlignment::Bottom => 2,
+        _ => 0,
     };
 
-    let paragraphs: Vec<ParagraphData> = hf
-        .paragraphs
-        .iter()
-        .map(build_paragraph)
-        .collect::<Result<Vec<_>, _>>()?;
+    Ok(TableCellData {
+        col: cell.column,
+        row: cell.row,
+        col_span: cell.column_span,
+        row_span: cell.row_span,
+        width: cell.width.value() as u32,
+        height: cell.height.value() as u32,
+        padding: [
+            cell.padding.left.value() as u16,
+            cell.padding.right.value() as u16,
+            cell.padding.top.value() as u16,
+            cell.padding.bottom.value() as u16,
+        ],
+        border_fill_id: cell.border_fill_id.map_or(0, |id| id.value() as u16),
+        list_properties: vertical_alignment << 5,
+        paragraphs: build_paragraphs(&cell.paragraphs, ctx)?,
+    })
+}
 
-    Ok(HeaderFooterData {
-        apply_to,
-        paragraphs,
+/// 그림 빌드
+fn build_picture(
+    picture: &IrPicture,
+    ctx: &mut FromIrContext,
+) -> Result<PictureData, ConversionError> {
+    let common = &picture.common;
+    let mut element = build_shape_element(ControlId::SHAPE_PICTURE.raw(), common, 0);
+    element.rotation = picture.rotation.round() as i16;
+    // 개체 요소 속성 bit 0: 좌우 뒤집기, bit 1: 상하 뒤집기
+    element.properties = match picture.flip {
+        ImageFlip::None => 0,
+        ImageFlip::Horizontal => 1,
+        ImageFlip::Vertical => 2,
+        ImageFlip::Both => 3,
+    };
+    let (width, height) = (element.width as i32, element.height as i32);
+
+    // 테두리 투명도: 0 = 불투명, 100 = 완전 투명
+    let (border_color, border_thickness, border_transparency) =
+        picture.border.as_ref().map_or((0, 0, 0), |border| {
+            let opacity = (u32::from(border.color.alpha) * 100).div_ceil(255);
+            (
+                ColorConvert::to_bgr_u32(&border.color),
+                border.width.value(),
+                (100 - opacity) as u8,
+            )
+        });
+
+    Ok(PictureData {
+        common: build_object_common(common),
+        caption: build_caption(common, ctx)?,
+        element,
+        border_color,
+        border_thickness,
+        border_properties: 0,
+        border_transparency,
+        corners: rectangle_corners(width, height),
+        crop: [
+            picture.crop.left.value(),
+            picture.crop.top.value(),
+            picture.crop.right.value(),
+            picture.crop.bottom.value(),
+        ],
+        inner_margin: [
+            picture.inside_margin.left.value() as u16,
+            picture.inside_margin.right.value() as u16,
+            picture.inside_margin.top.value() as u16,
+            picture.inside_margin.bottom.value() as u16,
+        ],
+        brightness: picture.brightness,
+        contrast: picture.contrast,
+        effect: match picture.effect {
+            ImageEffect::Original => 0,
+            ImageEffect::Grayscale => 1,
+            ImageEffect::BlackWhite => 2,
+            ImageEffect::Pattern => 3,
+        },
+        binary_data_id: ctx.binary_data_id(&picture.binary_id),
+        instance_id: common.id.unwrap_or(0),
+        original_size: (
+            picture.original_size.width.value().max(0) as u32,
+            picture.original_size.height.value().max(0) as u32,
+        ),
+        transparent_color: picture
+            .transparent_color
+            .as_ref()
+            .map(ColorConvert::to_bgr_u32),
     })
 }
 
-/// IR 각주/미주 → HWP NoteData 변환
-fn convert_note_to_hwp(note: &IrNote) -> Result<NoteData, ConversionError> {
-    let paragraphs: Vec<ParagraphData> = note
-        .paragraphs
-        .iter()
-        .map(build_paragraph)
-        .collect::<Result<Vec<_>, _>>()?;
+/// OLE 개체 빌드
+fn build_ole(ole: &IrOleObject, ctx: &mut FromIrContext) -> Result<OleData, ConversionError> {
+    let common = &ole.common;
+    Ok(OleData {
+        common: build_object_common(common),
+        caption: build_caption(common, ctx)?,
+        element: build_shape_element(ControlId::SHAPE_OLE.raw(), common, 0),
+        properties: 0,
+        extent_width: common.size.width.value(),
+        extent_height: common.size.height.value(),
+        bin_data_id: ctx.binary_data_id(&ole.binary_id),
+        border_color: 0,
+        border_thickness: 0,
+        border_properties: 0,
+    })
+}
 
-    Ok(NoteData {
-        number: note.number as u16,
-        paragraphs,
+/// 수식 빌드
+fn build_equation(
+    equation: &IrEquation,
+    ctx: &mut FromIrContext,
+) -> Result<EquationData, ConversionError> {
+    // 속성의 하위 바이트는 줄 단위 배치 방식
+    let line_mode = match equation.line_mode.unwrap_or_default() {
+        EquationLineMode::Baseline => 0,
+        EquationLineMode::Center => 1,
+        EquationLineMode::Bottom => 2,
+        EquationLineMode::Top => 3,
+    };
+
+    Ok(EquationData {
+        common: build_object_common(&equation.common),
+        caption: build_caption(&equation.common, ctx)?,
+        properties: (equation.properties.unwrap_or(0) & !0xFF) | line_mode,
+        script: equation.script.clone(),
+        base_size: equation.font_size.value().max(0) as u32,
+        color: equation.color.as_ref().map_or(0, ColorConvert::to_bgr_u32),
+        baseline: equation.baseline_offset.value() as i16,
+        version: equation.version.clone().unwrap_or_default(),
+        font_name: equation.font_name.clone().unwrap_or_default(),
     })
 }
 
-/// IR 하이퍼링크 → HWP HyperlinkData 변환
-fn convert_hyperlink_to_hwp(link: &IrHyperlink) -> Result<HyperlinkData, ConversionError> {
-    use ir::control::HyperlinkTarget;
+/// 비디오 빌드
+fn build_video(video: &IrVideo, ctx: &FromIrContext) -> VideoData {
+    let poster = video
+        .poster_binary_id
+        .as_ref()
+        .or(video.preview_image_id.as_ref());
+
+    VideoData {
+        common: build_object_common(&video.common),
+        video_type: match video.video_type {
+            IrVideoType::Embedded => 0,
+            IrVideoType::Linked => 1,
+            IrVideoType::YouTube | IrVideoType::Web => 2,
+        },
+        bin_data_id: video
+            .video_id
+            .as_ref()
+            .map_or(0, |id| ctx.binary_data_id(id)),
+        poster_bin_id: poster.map_or(0, |id| ctx.binary_data_id(id)),
+        source_url: video.source_url.clone().unwrap_or_default(),
+    }
+}
+
+/// 차트 종류 변환
+const fn convert_chart_type_to_hwp(chart_type: IrChartType) -> u16 {
+    match chart_type {
+        IrChartType::Bar | IrChartType::Column => 0,
+        IrChartType::Line | IrChartType::Stock => 1,
+        IrChartType::Pie | IrChartType::Doughnut => 2,
+        IrChartType::Area | IrChartType::Surface => 3,
+        IrChartType::Scatter | IrChartType::Bubble => 4,
+        IrChartType::Radar => 6,
+    }
+}
+
+/// 양식 개체 빌드
+///
+/// 속성 하위 바이트는 양식 종류이고, 나머지 비트는 종류별 설정입니다.
+fn build_form_object(form: &IrFormObject) -> FormObjectData {
+    let form_type: u32 = match form.form_type {
+        IrFormObjectType::Edit => 0,
+        IrFormObjectType::CheckBox => 1,
+        IrFormObjectType::RadioButton => 2,
+        IrFormObjectType::ComboBox => 3,
+        IrFormObjectType::ListBox => 4,
+        IrFormObjectType::Button | IrFormObjectType::Signature => 5,
+        IrFormObjectType::ScrollBar => 6,
+    };
+
+    let mut properties = form_type;
+    match form.form_type {
+        IrFormObjectType::Edit => {
+            if form.multiline {
+                properties |= 0x100;
+            }
+            if form.num_only {
+                properties |= 0x200;
+            }
+            if form.read_only {
+                properties |= 0x400;
+            }
+            let scroll_bars: u32 = match form.scroll_bars.unwrap_or_default() {
+                EditScrollBars::None => 0,
+                EditScrollBars::Vertical => 1,
+                EditScrollBars::Horizontal => 2,
+                EditScrollBars::Both => 3,
+            };
+            let tab_key: u32 = match form.tab_key_behavior.unwrap_or_default() {
+                EditTabKeyBehavior::NextObject => 0,
+                EditTabKeyBehavior::InsertTab => 1,
+            };
+            let alignment: u32 = match form.alignment.unwrap_or_default() {
+                EditTextAlignment::Left => 0,
+                EditTextAlignment::Center => 1,
+                EditTextAlignment::Right => 2,
+            };
+            properties |= (scroll_bars << 12) | (tab_key << 14) | (alignment << 16);
+        }
+        IrFormObjectType::ComboBox | IrFormObjectType::ListBox => {
+            if form.edit_enable {
+                properties |= 0x100;
+            }
+        }
+        _ => {
+            if form.tri_state {
+                properties |= 0x100;
+            }
+            if form.gradient_fill {
+                properties |= 0x200;
+            }
+            match form.back_style {
+                Some(ButtonBackStyle::Opaque) => properties |= 1 << 10,
+                Some(ButtonBackStyle::Transparent) => properties |= 2 << 10,
+                None => {}
+            }
+            if form.image_fill {
+                properties |= 0x400;
+            }
+        }
+    }
+
+    FormObjectData {
+        common: build_object_common(&form.common),
+        properties,
+        name: form.name.clone().unwrap_or_default(),
+        value: form.value.clone().unwrap_or_default(),
+        caption: form.caption.clone().unwrap_or_default(),
+        back_color: form.back_color.as_ref().map_or(0, ColorConvert::to_bgr_u32),
+        radio_group_name: form.radio_group_name.clone().unwrap_or_default(),
+        password_char: first_utf16(form.password_char.as_deref()),
+        max_length: form.max_length.unwrap_or(0),
+        selected_value: form.selected_value.clone().unwrap_or_default(),
+        list_box_rows: form.list_box_rows.unwrap_or(0),
+        list_box_width: form.list_box_width.unwrap_or(0),
+        item_height: form.item_height.unwrap_or(0),
+        top_index: form.top_index.unwrap_or(0),
+        items: form
+            .items
+            .iter()
+            .map(|item| {
+                (
+                    item.display_text.clone().unwrap_or_default(),
+                    item.value.clone().unwrap_or_default(),
+                )
+            })
+            .collect(),
+    }
+}
 
-    let url = match &link.target {
-        HyperlinkTarget::Url(url) => url.clone(),
-        HyperlinkTarget::Email(email) => format!("mailto:{}", email),
-        HyperlinkTarget::File(path) => path.clone(),
-        HyperlinkTarget::Bookmark(name) => format!("#{}", name),
+/// 글맵시 빌드
+fn build_text_art(text_art: &IrTextArt) -> TextArtData {
+    let font_style = match text_art.font_style {
+        IrTextArtFontStyle::Regular => 0,
+        IrTextArtFontStyle::Bold => 1,
+        IrTextArtFontStyle::Italic => 2,
+        IrTextArtFontStyle::BoldItalic => 3,
+    };
+    let shape_type = match text_art.shape {
+        IrTextArtShapeType::Rectangle | IrTextArtShapeType::Cylinder => 0,
+        IrTextArtShapeType::Wave => 1,
+        IrTextArtShapeType::ArchUp => 2,
+        IrTextArtShapeType::ArchDown => 3,
+        IrTextArtShapeType::Circle => 4,
+        IrTextArtShapeType::Inflate => 6,
+        IrTextArtShapeType::Deflate => 7,
+        IrTextArtShapeType::Other(value) => value,
     };
+    let text_color = match &text_art.fill {
+        ir::border_fill::Fill::Solid(solid) => ColorConvert::to_bgr_u32(&solid.color),
+        _ => 0,
+    };
+
+    TextArtData {
+        common: build_object_common(&text_art.common),
+        text: text_art.text.clone(),
+        font_name: text_art.font_name.clone().unwrap_or_default(),
+        font_style,
+        shape_type,
+        alignment: match text_art.alignment {
+            IrTextArtAlignment::Left => 0,
+            IrTextArtAlignment::Center => 1,
+            IrTextArtAlignment::Right => 2,
+            IrTextArtAlignment::Full => 3,
+        },
+        text_color,
+        outline_color: ColorConvert::to_bgr_u32(&text_art.line.color),
+        shadow_color: text_art
+            .shadow
+            .as_ref()
+            .map_or(0, |shadow| ColorConvert::to_bgr_u32(&shadow.color)),
+    }
+}
 
-    Ok(HyperlinkData {
-        url,
-        tooltip: link.tooltip.clone(),
+/// 글상자 빌드 (글상자는 텍스트를 가진 사각형 그리기 개체로 기록)
+fn build_text_box(
+    text_box: &IrTextBox,
+    ctx: &mut FromIrContext,
+) -> Result<ShapeControlData, ConversionError> {
+    let common = &text_box.common;
+    let element = build_shape_element(ControlId::SHAPE_RECTANGLE.raw(), common, 0);
+    let (width, height) = (element.width as i32, element.height as i32);
+    let text = build_shape_text(
+        &text_box.paragraphs,
+        &text_box.padding,
+        text_box.vertical_alignment,
+        text_box.text_direction,
+        element.width,
+        ctx,
+    )?;
+
+    Ok(ShapeControlData {
+        common: build_object_common(common),
+        caption: build_caption(common, ctx)?,
+        shape: ShapeData {
+            element,
+            border: BorderLineData::default(),
+            fill: HwpFillInfo::None,
+            shadow: ShadowData::default(),
+            text: Some(text),
+            geometry: ShapeGeometry::Rectangle {
+                round_ratio: 0,
+                corners: rectangle_corners(width, height),
+            },
+        },
     })
 }
 
-/// IR 책갈피 → HWP BookmarkData 변환
-fn convert_bookmark_to_hwp(bookmark: &IrBookmark) -> Result<BookmarkData, ConversionError> {
-    Ok(BookmarkData {
-        name: bookmark.name.clone(),
+/// 개체 요소 속성 빌드
+///
+/// 본문에 놓인 개체(group_level 0)는 위치를 개체 공통 속성에 기록하므로
+/// 그룹 안의 개체만 오프셋을 가집니다.
+fn build_shape_element(
+    element_type: u32,
+    common: &IrObjectCommon,
+    group_level: u16,
+) -> ShapeElementData {
+    let width = common.size.width.value().max(0) as u32;
+    let height = common.size.height.value().max(0) as u32;
+    let (offset_x, offset_y) = if group_level == 0 {
+        (0, 0)
+    } else {
+        (common.position.x.value(), common.position.y.value())
+    };
+
+    ShapeElementData {
+        element_type,
+        offset_x,
+        offset_y,
+        group_level,
+        local_version: 1,
+        initial_width: width,
+        initial_height: height,
+        width,
+        height,
+        properties: 0,
+        rotation: 0,
+        center_x: (width / 2) as i32,
+        center_y: (height / 2) as i32,
+        matrix: Vec::new(),
+    }
+}
+
+/// 사각형 모서리 좌표 (좌상, 우상, 우하, 좌하)
+const fn rectangle_corners(width: i32, height: i32) -> [(i32, i32); 4] {
+    [(0, 0), (width, 0), (width, height), (0, height)]
+}
+
+/// 도형 빌드
+fn build_shape(
+    shape: &IrShape,
+    group_level: u16,
+    ctx: &mut FromIrContext,
+) -> Result<ShapeData, ConversionError> {
+    let width = shape.common.size.width.value();
+    let height = shape.common.size.height.value();
+
+    let (element_type, geometry) = match &shape.shape_type {
+        IrShapeType::Line(line) => (
+            ControlId::SHAPE_LINE,
+            ShapeGeometry::Line {
+                start: point_to_hwp(&line.start),
+                end: point_to_hwp(&line.end),
+                reversed: false,
+            },
+        ),
+        IrShapeType::Connector(connector) => (
+            ControlId::SHAPE_LINE,
+            ShapeGeometry::Line {
+                start: point_to_hwp(&connector.start.point),
+                end: point_to_hwp(&connector.end.point),
+                reversed: false,
+            },
+        ),
+        IrShapeType::Rectangle(rectangle) => (
+            ControlId::SHAPE_RECTANGLE,
+            ShapeGeometry::Rectangle {
+                round_ratio: (rectangle.corner_radius.value() / 100).clamp(0, 255) as u8,
+                corners: rectangle_corners(width, height),
+            },
+        ),
+        IrShapeType::Ellipse(ellipse) => {
+            let (start, end) =
+                arc_end_points(width, height, ellipse.start_angle, ellipse.end_angle);
+            let center = (width / 2, height / 2);
+            let axis1 = (width, height / 2);
+            let axis2 = (width / 2, height);
+            (
+                ControlId::SHAPE_ELLIPSE,
+                ShapeGeometry::Ellipse {
+                    properties: u32::from(convert_arc_type_to_hwp(ellipse.arc_type)),
+                    points: [center, axis1, axis2, start, end, start, end],
+                },
+            )
+        }
+        IrShapeType::Arc(arc) => {
+            let (start, end) = arc_end_points(width, height, arc.start_angle, arc.end_angle);
+            (
+                ControlId::SHAPE_ARC,
+                ShapeGeometry::Arc {
+                    arc_type: convert_arc_type_to_hwp(arc.arc_type),
+                    points: [(width / 2, height / 2), start, end],
+                },
+            )
+        }
+        IrShapeType::Polygon(polygon) => (
+            ControlId::SHAPE_POLYGON,
+            ShapeGeometry::Polygon {
+                points: polygon.points.iter().map(point_to_hwp).collect(),
+            },
+        ),
+        IrShapeType::Curve(curve) => (
+            ControlId::SHAPE_CURVE,
+            ShapeGeometry::Curve {
+                points: curve
+                    .points
+                    .iter()
+                    .map(|p| point_to_hwp(&p.point))
+                    .collect(),
+                segment_types: curve
+                    .points
+                    .iter()
+                    .map(|p| match p.point_type {
+                        IrCurvePointType::Normal => 0,
+                        IrCurvePointType::Control1 | IrCurvePointType::Control2 => 1,
+                    })
+                    .collect(),
+            },
+        ),
+        IrShapeType::Group(children) => (
+            ControlId::SHAPE_GROUP,
+            ShapeGeometry::Container {
+                children: children
+                    .iter()
+                    .map(|child| build_shape(child, group_level + 1, ctx))
+                    .collect::<Result<_, _>>()?,
+            },
+        ),
+    };
+
+    let mut element = build_shape_element(element_type.raw(), &shape.common, group_level);
+    element.rotation = shape.rotation.round() as i16;
+    if !matches!(shape.shape_type, IrShapeType::Group(_)) {
+        element.matrix = build_shape_matrix(shape);
+    }
+
+    let text = shape
+        .text
+        .as_ref()
+        .map(|text| {
+            build_shape_text(
+                &text.paragraphs,
+                &text.padding,
+                text.vertical_alignment,
+                text.text_direction,
+                element.width,
+                ctx,
+            )
+        })
+        .transpose()?;
+
+    Ok(ShapeData {
+        element,
+        border: build_shape_border(shape),
+        fill: convert_fill_to_hwp(&shape.fill, ctx),
+        shadow: shape
+            .shadow
+            .as_ref()
+            .map_or_else(ShadowData::default, |shadow| ShadowData {
+                shadow_type: 1,
+                color: ColorConvert::to_bgr_u32(&shadow.color),
+                offset_x: shadow.offset_x.value(),
+                offset_y: shadow.offset_y.value(),
+                alpha: (shadow.alpha.clamp(0.0, 1.0) * 255.0).round() as u8,
+            }),
+        text,
+        geometry,
     })
 }
 
-/// IR 자동 번호 → HWP AutoNumberData/PageNumberData 변환
+/// 개체 요소 변환 행렬 빌드 (이동 행렬 + 크기/회전 행렬 쌍)
+fn build_shape_matrix(shape: &IrShape) -> Vec<f64> {
+    let values = |matrix: Option<&TransformMatrix>| {
+        let matrix = matrix.cloned().unwrap_or_else(TransformMatrix::identity);
+        [
+            matrix.e1, matrix.e2, matrix.e3, matrix.e4, matrix.e5, matrix.e6,
+        ]
+    };
+
+    let mut matrix = values(shape.translation_matrix.as_ref()).to_vec();
+    if shape.scale_matrix.is_some() || shape.rotation_matrix.is_some() {
+        matrix.extend(values(shape.scale_matrix.as_ref()));
+        matrix.extend(values(shape.rotation_matrix.as_ref()));
+    }
+    matrix
+}
+
+/// 그리기 개체 테두리 선 빌드
 ///
-/// HWPX PageNumber (위치 정보 포함)는 HWP PageNumberData로 변환하고,
-/// 나머지는 HWP AutoNumberData로 변환합니다.
-fn convert_auto_number_to_hwp(auto_num: &IrAutoNumber) -> Result<AutoNumberData, ConversionError> {
-    let number_type = match auto_num.number_type {
-        IrAutoNumberType::Page => 0,
-        IrAutoNumberType::Footnote => 1,
-        IrAutoNumberType::Endnote => 2,
-        IrAutoNumberType::Picture => 3,
-        IrAutoNumberType::Table => 4,
-        IrAutoNumberType::Equation => 5,
-        IrAutoNumberType::TotalPages => 6,
+/// 속성: bit 0~5 선 종류, bit 6~9 끝 모양, bit 10~29 화살표 모양과 크기 (선만)
+fn build_shape_border(shape: &IrShape) -> BorderLineData {
+    let line = &shape.line;
+    let line_type: u32 = match line.line_type {
+        IrLineType::None | IrLineType::Solid => 0,
+        IrLineType::LongDash => 1,
+        IrLineType::Dot => 2,
+        IrLineType::DashDot => 3,
+        IrLineType::DashDotDot => 4,
+        IrLineType::Dash => 5,
+        IrLineType::Circle => 6,
+        IrLineType::Double => 7,
+        IrLineType::Triple => 10,
+        IrLineType::Wave => 11,
+        IrLineType::DoubleWave => 12,
+        IrLineType::ThickThinLarge => 13,
+        IrLineType::ThinThickLarge => 15,
     };
+    let cap: u32 = match line.cap {
+        LineCap::Round => 0,
+        LineCap::Flat | LineCap::Square => 1,
+    };
+
+    let mut properties = line_type | (cap << 6);
+    let arrows = match &shape.shape_type {
+        IrShapeType::Line(line) => Some((&line.start_arrow, &line.end_arrow)),
+        IrShapeType::Connector(connector) => Some((&connector.start_arrow, &connector.end_arrow)),
+        _ => None,
+    };
+    if let Some((start, end)) = arrows {
+        let arrow_type = |arrow: &Arrow| {
+            u32::from(convert_arrow_type_with_filled(
+                &arrow.arrow_type,
+                arrow.filled,
+            ))
+        };
+        let arrow_size = |arrow: &Arrow| match arrow.size {
+            ArrowSize::Small => 1,
+            ArrowSize::Medium => 2,
+            ArrowSize::Large => 3,
+        };
+        properties |= (arrow_type(start) << 10)
+            | (arrow_type(end) << 16)
+            | (arrow_size(start) << 22)
+            | (arrow_size(end) << 26);
+    }
 
-    let number_format = convert_auto_number_format_to_hwp(&auto_num.number_format);
+    BorderLineData {
+        color: ColorConvert::to_bgr_u32(&line.color),
+        thickness: line.width.value(),
+        properties,
+        outline_style: match line.outline_style {
+            LineOutlineStyle::Normal => 0,
+            LineOutlineStyle::Outer => 1,
+            LineOutlineStyle::Inner => 2,
+        },
+    }
+}
+
+/// 도형 글상자 빌드
+///
+/// 문단 리스트 속성: bit 0~2 글자 방향, bit 5~6 세로 정렬
+fn build_shape_text(
+    paragraphs: &[IrParagraph],
+    padding: &Insets,
+    vertical_alignment: VerticalAlignment,
+    text_direction: TextDirection,
+    max_width: u32,
+    ctx: &mut FromIrContext,
+) -> Result<ShapeTextData, ConversionError> {
+    let direction: u32 = match text_direction {
+        TextDirection::Horizontal => 0,
+        _ => 1,
+    };
+    let vertical_alignment: u32 = match vertical_alignment {
+        VerticalAlignment::Middle => 1,
+        VerticalAlignment::Bottom => 2,
+        _ => 0,
+    };
 
-    Ok(AutoNumberData {
-        number_type,
-        number_format,
+    Ok(ShapeTextData {
+        list_properties: direction | (vertical_alignment << 5),
+        margins: [
+            padding.left.value() as u16,
+            padding.right.value() as u16,
+            padding.top.value() as u16,
+            padding.bottom.value() as u16,
+        ],
+        max_width,
+        paragraphs: build_paragraphs(paragraphs, ctx)?,
     })
 }
 
-/// IR 새 번호 → HWP NewNumberData 변환
-fn convert_new_number_to_hwp(new_num: &IrNewNumber) -> Result<NewNumberData, ConversionError> {
-    let number_type = match new_num.number_type {
-        IrAutoNumberType::Page => 0,
-        IrAutoNumberType::Footnote => 1,
-        IrAutoNumberType::Endnote => 2,
-        IrAutoNumberType::Picture => 3,
-        IrAutoNumberType::Table => 4,
-        IrAutoNumberType::Equation => 5,
-        IrAutoNumberType::TotalPages => 6,
+/// 호 종류 변환 (전체 타원은 호로 기록)
+const fn convert_arc_type_to_hwp(arc_type: IrArcType) -> u8 {
+    match arc_type {
+        IrArcType::Full | IrArcType::Arc => 0,
+        IrArcType::Pie => 1,
+        IrArcType::Chord => 2,
+    }
+}
+
+/// 호의 시작점/끝점 계산
+///
+/// 도형 크기에 내접하는 타원 위에서 주어진 각도(도 단위)에 해당하는 점을 구합니다.
+/// 시작점과 끝점이 같으면 완전한 타원으로 읽히므로 360도 범위는 같은 점으로 기록합니다.
+fn arc_end_points(
+    width: i32,
+    height: i32,
+    start_angle: f64,
+    end_angle: f64,
+) -> ((i32, i32), (i32, i32)) {
+    let point_at = |angle: f64| {
+        let (a, b) = (f64::from(width) / 2.0, f64::from(height) / 2.0);
+        let (sin, cos) = angle.to_radians().sin_cos();
+        let denominator = ((b * cos).powi(2) + (a * sin).powi(2)).sqrt();
+        let radius = if denominator > 0.0 {
+            a * b / denominator
+        } else {
+            0.0
+        };
+        (
+            (a + radius * cos).round() as i32,
+            (b + radius * sin).round() as i32,
+        )
+    };
+
+    let start = point_at(start_angle);
+    if (end_angle - start_angle).abs() >= 360.0 {
+        return (start, start);
+    }
+    (start, point_at(end_angle))
+}
+
+/// IR 점 → HWP 좌표
+const fn point_to_hwp(point: &primitive::Point) -> (i32, i32) {
+    (point.x.value(), point.y.value())
+}
+
+/// RangeTag 변환 (IR → HWP)
+///
+/// IR RangeTag를 HWP RangeTagData로 변환합니다.
+fn convert_range_tag_to_hwp(
+    range_tag: &ir::paragraph::RangeTag,
+) -> crate::writer::body_writer::RangeTagData {
+    use crate::writer::body_writer::RangeTagData;
+    use ir::paragraph::RangeTagType;
+
+    // 태그 종류를 상위 바이트로 변환
+    let tag_type_byte = match range_tag.tag_type {
+        RangeTagType::Bookmark => 0,
+        RangeTagType::Hyperlink => 1,
+        RangeTagType::TrackChangeInsert => 2,
+        RangeTagType::TrackChangeDelete => 3,
+        RangeTagType::Highlight => 4,
+        RangeTagType::Other(byte) => byte,
+    };
+
+    // 태그 데이터 추출
+    let (tag_data_low, tag_data_mid) = if let Some(track_info) = &range_tag.track_change_info {
+        // 변경 추적 ID를 하위 16비트로 저장
+        let id_bytes = (track_info.track_change_id as u16).to_le_bytes();
+        (id_bytes[0], id_bytes[1])
+    } else if let Some(data_str) = &range_tag.data {
+        // 16진수 문자열을 바이트로 파싱
+        let bytes: Vec<u8> = (0..data_str.len())
+            .step_by(2)
+            .filter_map(|i| {
+                let end = (i + 2).min(data_str.len());
+                u8::from_str_radix(&data_str[i..end], 16).ok()
+            })
+            .collect();
+        (
+            bytes.get(1).copied().unwrap_or(0),
+            bytes.first().copied().unwrap_or(0),
+        )
+    } else {
+        (0, 0)
     };
 
-    Ok(NewNumberData {
-        number_type,
-        number: new_num.number as u16,
-    })
+    RangeTagData {
+        start_position: range_tag.start,
+        end_position: range_tag.end,
+        tag: [tag_data_low, tag_data_mid, tag_type_byte],
+    }
 }
 
-/// IR 숨은 설명 → HWP HiddenCommentData 변환
-fn convert_hidden_comment_to_hwp(
-    comment: &IrHiddenComment,
-) -> Result<HiddenCommentData, ConversionError> {
-    let paragraphs: Vec<ParagraphData> = comment
-        .paragraphs
-        .iter()
-        .map(build_paragraph)
-        .collect::<Result<Vec<_>, _>>()?;
-
-    Ok(HiddenCommentData { paragraphs })
+/// 바이너리 데이터 확장자 감지
+fn detect_extension(data: &[u8]) -> &'static str {
+    if data.starts_with(&[0x89, b'P', b'N', b'G']) {
+        "png"
+    } else if data.starts_with(&[0xFF, 0xD8, 0xFF]) {
+        "jpg"
+    } else if data.starts_with(b"GIF") {
+        "gif"
+    } else if data.starts_with(b"BM") {
+        "bmp"
+    } else if data.starts_with(&[0x00, 0x00, 0x01, 0x00]) {
+        "ico"
+    } else {
+        "dat"
+    }
 }
 
 /// IR 번호 형식 → HWP 번호 형식 변환 (자동 번호용)
@@ -1649,62 +2577,8 @@ fn convert_auto_number_format_to_hwp(format: &NumberFormat) -> u16 {
     }
 }
 
-/// IR 도형 → HWP ShapeData 변환
-fn convert_shape_to_hwp(shape: &IrShape) -> Result<ShapeData, ConversionError> {
-    // 기본 도형 크기 및 위치
-    let width = shape.common.size.width.value() as u32;
-    let height = shape.common.size.height.value() as u32;
-    let offset_x = shape.common.position.x.value();
-    let offset_y = shape.common.position.y.value();
-
-    // 선 색상 및 두께
-    let line_color = ColorConvert::to_bgr_u32(&shape.line.color);
-    let line_thickness = shape.line.width.value() as u16;
-
-    // 채우기 정보 변환
-    let fill = convert_fill_to_hwp(&shape.fill);
-
-    // 도형 타입 변환
-    let shape_type = convert_shape_type_to_hwp(&shape.shape_type)?;
-
-    // 캡션 변환 (있는 경우)
-    let caption = shape
-        .common
-        .caption
-        .as_ref()
-        .and_then(|c| convert_caption_to_hwp(c).ok());
-
-    // 변환 행렬 변환 (IR TransformMatrix → HWP 행렬)
-    let translation_matrix = shape
-        .translation_matrix
-        .map(|m| [m.e1, m.e2, m.e3, m.e4, m.e5, m.e6]);
-    let scale_matrix = shape
-        .scale_matrix
-        .map(|m| [m.e1, m.e2, m.e3, m.e4, m.e5, m.e6]);
-    let rotation_matrix = shape
-        .rotation_matrix
-        .map(|m| [m.e1, m.e2, m.e3, m.e4, m.e5, m.e6]);
-
-    Ok(ShapeData {
-        common: convert_object_common_to_hwp(&shape.common),
-        shape_type,
-        width,
-        height,
-        offset_x,
-        offset_y,
-        line_color,
-        line_thickness,
-        fill,
-        caption,
-        translation_matrix,
-        scale_matrix,
-        rotation_matrix,
-        rotation: shape.rotation,
-    })
-}
-
 /// IR Fill → HWP FillInfo 변환
-fn convert_fill_to_hwp(fill: &ir::border_fill::Fill) -> HwpFillInfo {
+fn convert_fill_to_hwp(fill: &ir::border_fill::Fill, ctx: &FromIrContext) -> HwpFillInfo {
     match fill {
         ir::border_fill::Fill::None => HwpFillInfo::None,
         ir::border_fill::Fill::Solid(solid) => {
@@ -1797,7 +2671,7 @@ fn convert_fill_to_hwp(fill: &ir::border_fill::Fill) -> HwpFillInfo {
                     brightness: image.brightness,
                     contrast: image.contrast,
                     effect,
-                    binary_data_id: image.binary_id.value().parse::<u16>().unwrap_or(0),
+                    binary_data_id: ctx.binary_data_id(&image.binary_id),
                 },
             })
         }
@@ -1806,148 +2680,6 @@ fn convert_fill_to_hwp(fill: &ir::border_fill::Fill) -> HwpFillInfo {
 
 // ColorConvert::from_ir을 사용합니다 (색상 변환 통합)
 
-/// IR Fill → HWP (fill_type, background_color) 변환 (BorderFillData용)
-fn convert_fill_to_hwp_simple(fill: &ir::border_fill::Fill) -> (u32, u32) {
-    match fill {
-        ir::border_fill::Fill::None => (0, 0xFFFFFF),
-        ir::border_fill::Fill::Solid(solid) => {
-            // fill_type bit 0 = 단색 채우기
-            // HWP는 0x00RRGGBB 형식 (알파 없음)
-            (1, solid.color.to_rgb_u32())
-        }
-        ir::border_fill::Fill::Gradient(gradient) => {
-            // fill_type bit 2 = 그라데이션
-            // HWP는 0x00RRGGBB 형식 (알파 없음)
-            let bg = gradient
-                .stops
-                .first()
-                .map(|s| s.color.to_rgb_u32())
-                .unwrap_or(0xFFFFFF);
-            (4, bg)
-        }
-        ir::border_fill::Fill::Image(_) => {
-            // fill_type bit 1 = 이미지 채우기
-            (2, 0xFFFFFF)
-        }
-        ir::border_fill::Fill::Pattern(pattern) => {
-            // fill_type bit 0 = 단색 (패턴은 HWP에서 별도 처리)
-            // HWP는 0x00RRGGBB 형식 (알파 없음)
-            (1, pattern.background.to_rgb_u32())
-        }
-    }
-}
-
-/// IR 도형 타입 → HWP ShapeTypeData 변환
-fn convert_shape_type_to_hwp(shape_type: &IrShapeType) -> Result<ShapeTypeData, ConversionError> {
-    match shape_type {
-        IrShapeType::Line(line) => Ok(ShapeTypeData::Line(LineShapeData {
-            start_x: line.start.x.value(),
-            start_y: line.start.y.value(),
-            end_x: line.end.x.value(),
-            end_y: line.end.y.value(),
-            start_arrow_type: convert_arrow_type_with_filled(
-                &line.start_arrow.arrow_type,
-                line.start_arrow.filled,
-            ),
-            start_arrow_size: convert_arrow_size(&line.start_arrow.size),
-            end_arrow_type: convert_arrow_type_with_filled(
-                &line.end_arrow.arrow_type,
-                line.end_arrow.filled,
-            ),
-            end_arrow_size: convert_arrow_size(&line.end_arrow.size),
-        })),
-        IrShapeType::Rectangle(rect) => Ok(ShapeTypeData::Rectangle(RectangleShapeData {
-            corner_radius: rect.corner_radius.value() as u32,
-        })),
-        IrShapeType::Ellipse(ellipse) => Ok(ShapeTypeData::Ellipse(EllipseShapeData {
-            arc_type: convert_arc_type(&ellipse.arc_type),
-            start_angle: (ellipse.start_angle * 100.0) as i32,
-            end_angle: (ellipse.end_angle * 100.0) as i32,
-        })),
-        IrShapeType::Arc(arc) => Ok(ShapeTypeData::Arc(ArcShapeData {
-            arc_type: convert_arc_type(&arc.arc_type),
-            start_angle: (arc.start_angle * 100.0) as i32,
-            end_angle: (arc.end_angle * 100.0) as i32,
-        })),
-        IrShapeType::Polygon(poly) => {
-            let points = poly
-                .points
-                .iter()
-                .map(|p| (p.x.value(), p.y.value()))
-                .collect();
-            Ok(ShapeTypeData::Polygon(PolygonShapeData { points }))
-        }
-        IrShapeType::Curve(curve) => {
-            let points = curve
-                .points
-                .iter()
-                .map(|p| {
-                    (
-                        p.point.x.value(),
-                        p.point.y.value(),
-                        convert_curve_point_type(&p.point_type),
-                    )
-                })
-                .collect();
-            Ok(ShapeTypeData::Curve(CurveShapeData {
-                points,
-                closed: curve.closed,
-            }))
-        }
-        IrShapeType::Connector(connector) => {
-            use crate::writer::body_writer::ConnectorShapeData;
-
-            // ConnectorType을 HWP line_type으로 변환
-            let line_type = match connector.connector_type {
-                ir::shape::ConnectorType::Straight => 0, // 직선
-                ir::shape::ConnectorType::Elbow
-                | ir::shape::ConnectorType::VerticalHorizontal
-                | ir::shape::ConnectorType::HorizontalVertical => 1, // 꺾인선
-                ir::shape::ConnectorType::Curved => 2,   // 곡선
-            };
-
-            // 제어점 변환
-            let control_points: Vec<(i32, i32)> = connector
-                .control_points
-                .iter()
-                .map(|cp| (cp.point.x.value(), cp.point.y.value()))
-                .collect();
-
-            Ok(ShapeTypeData::Connector(ConnectorShapeData {
-                line_type,
-                start_x: connector.start.point.x.value(),
-                start_y: connector.start.point.y.value(),
-                start_subject_id: connector.start.subject_id_ref,
-                start_subject_index: connector.start.subject_index,
-                end_x: connector.end.point.x.value(),
-                end_y: connector.end.point.y.value(),
-                end_subject_id: connector.end.subject_id_ref,
-                end_subject_index: connector.end.subject_index,
-                control_points,
-                start_arrow_type: convert_arrow_type_with_filled(
-                    &connector.start_arrow.arrow_type,
-                    connector.start_arrow.filled,
-                ),
-                start_arrow_size: convert_arrow_size(&connector.start_arrow.size),
-                end_arrow_type: convert_arrow_type_with_filled(
-                    &connector.end_arrow.arrow_type,
-                    connector.end_arrow.filled,
-                ),
-                end_arrow_size: convert_arrow_size(&connector.end_arrow.size),
-            }))
-        }
-        IrShapeType::Group(shapes) => {
-            // 그룹 도형의 모든 자식을 재귀적으로 변환
-            let children: Vec<ShapeData> = shapes
-                .iter()
-                .filter_map(|child_shape| convert_shape_to_hwp(child_shape).ok())
-                .collect();
-
-            Ok(ShapeTypeData::Container(ContainerShapeData { children }))
-        }
-    }
-}
-
 /// IR 화살표 → HWP 화살표 타입 (filled 속성 반영)
 fn convert_arrow_type_with_filled(arrow_type: &primitive::ArrowType, filled: bool) -> u8 {
     match arrow_type {
@@ -1979,57 +2711,8 @@ fn convert_arrow_type_with_filled(arrow_type: &primitive::ArrowType, filled: boo
     }
 }
 
-/// IR 화살표 크기 → HWP 화살표 크기
-fn convert_arrow_size(size: &primitive::ArrowSize) -> u8 {
-    match size {
-        primitive::ArrowSize::Small => 0,
-        primitive::ArrowSize::Medium => 1,
-        primitive::ArrowSize::Large => 2,
-    }
-}
-
-/// IR 호 타입 → HWP 호 타입
-fn convert_arc_type(arc_type: &IrArcType) -> u8 {
-    match arc_type {
-        IrArcType::Full => 0,
-        IrArcType::Arc => 1,
-        IrArcType::Pie => 2,
-        IrArcType::Chord => 3,
-    }
-}
-
-/// IR 곡선 점 타입 → HWP 곡선 점 타입
-fn convert_curve_point_type(pt_type: &IrCurvePointType) -> u8 {
-    match pt_type {
-        IrCurvePointType::Normal => 0,
-        IrCurvePointType::Control1 => 1,
-        IrCurvePointType::Control2 => 2,
-    }
-}
-
-/// IR 텍스트 박스 → HWP TextBoxData 변환
-fn convert_textbox_to_hwp(text_box: &IrTextBox) -> Result<TextBoxData, ConversionError> {
-    // 문단들 변환
-    let paragraphs: Vec<ParagraphData> = text_box
-        .paragraphs
-        .iter()
-        .map(build_paragraph)
-        .collect::<Result<Vec<_>, _>>()?;
-
-    // 크기 추출
-    let width = text_box.common.size.width.value().max(0) as u32;
-    let height = text_box.common.size.height.value().max(0) as u32;
-
-    Ok(TextBoxData {
-        common: convert_object_common_to_hwp(&text_box.common),
-        width,
-        height,
-        paragraphs,
-    })
-}
-
 /// IR BorderFill → HWP BorderFillData 변환
-fn convert_border_fill_to_hwp(border_fill: &IrBorderFill) -> BorderFillData {
+fn convert_border_fill_to_hwp(border_fill: &IrBorderFill, ctx: &FromIrContext) -> BorderFillData {
     // 속성 계산: 3D 효과(bit 0), 그림자(bit 1)
     let mut properties: u16 = 0;
     if border_fill.is_3d {
@@ -2058,9 +2741,6 @@ fn convert_border_fill_to_hwp(border_fill: &IrBorderFill) -> BorderFillData {
         properties |= 2 << 2; // 오른쪽위→왼쪽아래
     }
 
-    // 채우기 타입 및 배경색
-    let (fill_type, background_color) = convert_fill_to_hwp_simple(&border_fill.fill);
-
     BorderFillData {
         properties,
         left_border: convert_border_to_line(&border_fill.left),
@@ -2068,8 +2748,7 @@ fn convert_border_fill_to_hwp(border_fill: &IrBorderFill) -> BorderFillData {
         top_border: convert_border_to_line(&border_fill.top),
         bottom_border: convert_border_to_line(&border_fill.bottom),
         diagonal,
-        fill_type,
-        background_color,
+        fill: convert_fill_to_hwp(&border_fill.fill, ctx),
     }
 }
 
@@ -2077,64 +2756,34 @@ fn convert_border_fill_to_hwp(border_fill: &IrBorderFill) -> BorderFillData {
 fn convert_border_to_line(border: &IrBorder) -> BorderLine {
     BorderLine {
         style: convert_line_type_to_hwp(&border.line_type),
-        thickness: convert_line_width_to_thickness(border.width.value()),
-        // HWP는 0x00RRGGBB 형식 (알파 없음)
-        color: border.color.to_rgb_u32(),
+        thickness: BorderLineThickness::from_mm(border.width.to_mm()).as_raw(),
+        color: ColorConvert::to_bgr_u32(&border.color),
     }
 }
 
-/// IR LineType → HWP 선 스타일 변환
+/// IR LineType → HWP 선 종류 변환 (HWP 명세 표 25)
 fn convert_line_type_to_hwp(line_type: &IrLineType) -> u8 {
-    match line_type {
-        IrLineType::None => 0,
-        IrLineType::Solid => 1,
-        IrLineType::Dash => 2,
-        IrLineType::Dot => 3,
-        IrLineType::DashDot => 4,
-        IrLineType::DashDotDot => 5,
-        IrLineType::LongDash => 6,
-        IrLineType::Double => 7,
-        IrLineType::Triple => 8,
-        IrLineType::Wave => 9,
-        IrLineType::DoubleWave => 10,
-        IrLineType::ThickThinLarge => 11,
-        IrLineType::ThinThickLarge => 12,
-        IrLineType::Circle => 13,
-    }
-}
-
-/// 선 두께(HwpUnit) → HWP 두께 코드 변환
-fn convert_line_width_to_thickness(width: i32) -> u8 {
-    // HWP 두께 코드: 0=0.1mm, 1=0.12mm, 2=0.15mm, 3=0.2mm, 4=0.25mm,
-    // 5=0.3mm, 6=0.4mm, 7=0.5mm, 8=0.6mm, 9=0.7mm, 10=1.0mm, 11=1.5mm, 12=2.0mm 등
-    // HwpUnit 100 = 1mm
-    match width {
-        0..=9 => 0,      // ~0.1mm
-        10..=11 => 1,    // ~0.12mm
-        12..=14 => 2,    // ~0.15mm
-        15..=22 => 3,    // ~0.2mm
-        23..=27 => 4,    // ~0.25mm
-        28..=34 => 5,    // ~0.3mm
-        35..=44 => 6,    // ~0.4mm
-        45..=54 => 7,    // ~0.5mm
-        55..=64 => 8,    // ~0.6mm
-        65..=84 => 9,    // ~0.7mm
-        85..=124 => 10,  // ~1.0mm
-        125..=174 => 11, // ~1.5mm
-        _ => 12,         // 2.0mm 이상
-    }
+    let style = match line_type {
+        IrLineType::None | IrLineType::Solid => LineStyle::Solid,
+        IrLineType::LongDash => LineStyle::LongDash,
+        IrLineType::Dash => LineStyle::Dash,
+        IrLineType::DashDot => LineStyle::DashDot,
+        IrLineType::DashDotDot => LineStyle::DashDotDot,
+        IrLineType::Dot | IrLineType::Circle => LineStyle::Circle,
+        IrLineType::Double => LineStyle::Double,
+        IrLineType::ThinThickLarge => LineStyle::ThinThick,
+        IrLineType::ThickThinLarge => LineStyle::ThickThin,
+        IrLineType::Triple => LineStyle::ThinThickThin,
+        IrLineType::Wave => LineStyle::Wave,
+        IrLineType::DoubleWave => LineStyle::DoubleWave,
+    };
+    style.as_raw()
 }
 
 /// IR TabDef → HWP TabDefinitionData 변환
 fn convert_tab_def_to_hwp(tab_def: &IrTabDef) -> TabDefinitionData {
-    // 속성: 자동 탭 간격이 있으면 bit 0 설정
-    let properties = if tab_def.auto_tab_interval.is_some() {
-        // 자동 탭 간격 활성화
-        0
-    } else {
-        // 자동 탭 비활성화
-        1
-    };
+    // 속성: 자동 탭 간격이 있으면 문단 왼쪽 끝 자동 탭 (bit 0) 설정
+    let properties = u32::from(tab_def.auto_tab_interval.is_some());
 
     let items: Vec<TabItem> = tab_def
         .tabs
@@ -2149,26 +2798,24 @@ fn convert_tab_def_to_hwp(tab_def: &IrTabDef) -> TabDefinitionData {
     TabDefinitionData { properties, items }
 }
 
-/// IR TabType → HWP 탭 종류 변환
+/// IR TabType → HWP 탭 종류 변환 (0: 왼쪽, 1: 오른쪽, 2: 가운데, 3: 소수점)
 fn convert_tab_type_to_hwp(tab_type: &IrTabType) -> u8 {
     match tab_type {
         IrTabType::Left => 0,
-        IrTabType::Center => 1,
-        IrTabType::Right => 2,
+        IrTabType::Right => 1,
+        IrTabType::Center => 2,
         IrTabType::Decimal => 3,
     }
 }
 
-/// IR TabLeader → HWP 채움 종류 변환
-/// HWP 명세: 채움 종류는 테두리선 종류(표 25)를 참조
-/// 0: 실선, 1: 긴 점선, 2: 점선, 3: -.-.-., 4: -..-..-, 5: Dash보다 긴 선분, 6: Dot보다 큰 동그라미
+/// IR TabLeader → HWP 채움 종류 변환 (읽기 쪽 변환의 역)
 fn convert_tab_leader_to_fill_char(leader: &IrTabLeader) -> u16 {
     match leader {
-        IrTabLeader::None => 0,       // 없음 (실선으로 표시되지만 실제로는 채움 없음)
-        IrTabLeader::Dot => 2,        // 점선
-        IrTabLeader::LongDash => 1,   // 긴 점선
-        IrTabLeader::Dash => 3,       // -.-.-. (파선)
-        IrTabLeader::Underscore => 0, // 밑줄 (실선)
+        IrTabLeader::None => 0,
+        IrTabLeader::Dot => 1,
+        IrTabLeader::LongDash => 2,
+        IrTabLeader::Dash => 3,
+        IrTabLeader::Underscore => 4,
     }
 }
 
@@ -2230,270 +2877,31 @@ fn convert_numbering_to_hwp(numbering: &ir::style::Numbering) -> NumberingData {
 
 /// IR Bullet → HWP BulletData 변환
 fn convert_bullet_to_hwp(bullet: &ir::style::Bullet) -> BulletData {
-    let para_head_info = bullet.char_shape_id.map(|id| id.value()).unwrap_or(0);
-
     BulletData {
-        para_head_info,
+        char_shape_id: bullet.char_shape_id.map(|id| id.value()).unwrap_or(0),
         bullet_char: bullet.char,
+        check_bullet_char: if bullet.is_checkbox { '\u{2611}' } else { '\0' },
     }
 }
 
-/// BinaryDataId에서 숫자 ID 추출 (BIN{XXXX} 형식에서 숫자 추출)
-fn extract_bin_data_id(id: &primitive::BinaryDataId) -> u16 {
-    let value = id.value();
-    // "BIN{XXXX}" 형식에서 숫자 부분 추출
-    if let Some(hex_part) = value.strip_prefix("BIN") {
-        u16::from_str_radix(hex_part, 16).unwrap_or(0)
-    } else {
-        // 단순 숫자인 경우
-        value.parse::<u16>().unwrap_or(0)
-    }
-}
-
-/// IR Video → HWP VideoData 변환
-fn convert_video_to_hwp(video: &IrVideo) -> Result<HwpVideoData, ConversionError> {
-    // 비디오 종류 변환
-    let video_type = match video.video_type {
-        IrVideoType::Embedded => 0,
-        IrVideoType::Linked => 1,
-        IrVideoType::YouTube | IrVideoType::Web => 2,
-    };
-
-    // 바이너리 데이터 ID (임베디드인 경우)
-    let bin_data_id = video
-        .video_id
-        .as_ref()
-        .map(extract_bin_data_id)
-        .unwrap_or(0);
-
-    // 포스터 바이너리 데이터 ID (poster_binary_id 우선, 없으면 preview_image_id 사용)
-    let poster_bin_id = video
-        .poster_binary_id
-        .as_ref()
-        .map(extract_bin_data_id)
-        .or_else(|| video.preview_image_id.as_ref().map(extract_bin_data_id))
-        .unwrap_or(0);
-
-    // 소스 URL (링크인 경우)
-    let source_url = video.source_url.clone().unwrap_or_default();
-
-    // 비디오 너비와 높이
-    let width = video.width.map(|w| w.value() as u32).unwrap_or(0);
-    let height = video.height.map(|h| h.value() as u32).unwrap_or(0);
-
-    Ok(HwpVideoData {
-        common: convert_object_common_to_hwp(&video.common),
-        video_type,
-        bin_data_id,
-        poster_bin_id,
-        source_url,
-        width,
-        height,
-    })
-}
-
-/// IR OleObject → HWP OleData 변환
-fn convert_ole_to_hwp(ole: &IrOleObject) -> Result<HwpOleData, ConversionError> {
-    let bin_data_id = extract_bin_data_id(&ole.binary_id);
-
-    Ok(HwpOleData {
-        common: convert_object_common_to_hwp(&ole.common),
-        properties: 0,
-        extent_width: ole.common.size.width.value(),
-        extent_height: ole.common.size.height.value(),
-        bin_data_id,
-        border_color: 0,
-        border_thickness: 0,
-    })
-}
-
-/// IR Chart → HWP ChartData 변환
-fn convert_chart_to_hwp(chart: &IrChart) -> Result<HwpChartData, ConversionError> {
-    let chart_type = match chart.chart_type {
-        IrChartType::Bar | IrChartType::Column => 0,
-        IrChartType::Line | IrChartType::Stock => 1,
-        IrChartType::Pie | IrChartType::Doughnut => 2,
-        IrChartType::Area | IrChartType::Surface => 3,
-        IrChartType::Scatter | IrChartType::Bubble => 4,
-        IrChartType::Radar => 6,
-    };
-
-    Ok(HwpChartData {
-        common: convert_object_common_to_hwp(&chart.common),
-        chart_type,
-    })
-}
-
-/// IR FormObject → HWP FormObjectData 변환
-/// IR FormObject → HWP FormObjectData 변환
-fn convert_form_object_to_hwp(form: &IrFormObject) -> Result<HwpFormObjectData, ConversionError> {
-    let form_type = match form.form_type {
-        IrFormObjectType::Button | IrFormObjectType::Signature => 5,
-        IrFormObjectType::CheckBox => 1,
-        IrFormObjectType::RadioButton => 2,
-        IrFormObjectType::ComboBox => 3,
-        IrFormObjectType::ListBox => 4,
-        IrFormObjectType::Edit => 0,
-        IrFormObjectType::ScrollBar => 6,
-    };
-
-    // Build properties flags
-    let mut properties = form_type as u32;
-
-    // Button properties
-    if form.tri_state {
-        properties |= 0x0100;
-    }
-    if form.gradient_fill {
-        properties |= 0x0200;
-    }
-    if form.image_fill {
-        properties |= 0x0400;
-    }
-
-    // Back style (bits 10-11)
-    if let Some(back_style) = &form.back_style {
-        let style_val = match back_style {
-            ir::control::ButtonBackStyle::Transparent => 0,
-            ir::control::ButtonBackStyle::Opaque => 1,
-        };
-        properties |= (style_val as u32) << 10;
-    }
-
-    // Edit properties
-    if form.multiline {
-        properties |= 0x0100;
-    }
-    if form.num_only {
-        properties |= 0x0200;
-    }
-    if form.read_only {
-        properties |= 0x0400;
-    }
-
-    // Scroll bars (bits 12-13)
-    if let Some(scroll_bars) = &form.scroll_bars {
-        let scroll_val = match scroll_bars {
-            ir::control::EditScrollBars::None => 0,
-            ir::control::EditScrollBars::Vertical => 1,
-            ir::control::EditScrollBars::Horizontal => 2,
-            ir::control::EditScrollBars::Both => 3,
-        };
-        properties |= (scroll_val as u32) << 12;
-    }
-
-    // Tab key behavior (bit 14)
-    if let Some(tab_key) = &form.tab_key_behavior {
-        let tab_val = match tab_key {
-            ir::control::EditTabKeyBehavior::NextObject => 0,
-            ir::control::EditTabKeyBehavior::InsertTab => 1,
-        };
-        properties |= (tab_val as u32) << 14;
-    }
-
-    // Alignment (bits 16-17)
-    if let Some(alignment) = &form.alignment {
-        let align_val = match alignment {
-            ir::control::EditTextAlignment::Left => 0,
-            ir::control::EditTextAlignment::Center => 1,
-            ir::control::EditTextAlignment::Right => 2,
-        };
-        properties |= (align_val as u32) << 16;
-    }
-
-    // ComboBox/ListBox properties
-    if form.edit_enable {
-        properties |= 0x0100;
+/// IR ShadowType → HWP 비트 값 (0=없음, 1=discrete, 2=continuous)
+fn convert_shadow_type_to_bits(shadow_type: &primitive::ShadowType) -> u32 {
+    match shadow_type {
+        primitive::ShadowType::None => 0,
+        // 기본 방향 (스타일 구분 없음) - discrete로 처리
+        primitive::ShadowType::TopLeft
+        | primitive::ShadowType::TopRight
+        | primitive::ShadowType::BottomLeft
+        | primitive::ShadowType::BottomRight => 1,
+        // Discrete 스타일 (모든 방향)
+        primitive::ShadowType::TopLeftDiscrete
+        | primitive::ShadowType::TopRightDiscrete
+        | primitive::ShadowType::BottomLeftDiscrete
+        | primitive::ShadowType::BottomRightDiscrete => 1,
+        // Continuous 스타일 (모든 방향)
+        primitive::ShadowType::TopLeftContinuous
+        | primitive::ShadowType::TopRightContinuous
+        | primitive::ShadowType::BottomLeftContinuous
+        | primitive::ShadowType::BottomRightContinuous => 2,
     }
-
-    // Convert back_color from IR Color to HWP BGR format
-    let back_color = form.back_color.as_ref().map(|color| {
-        ((color.blue as u32) << 16) | ((color.green as u32) << 8) | (color.red as u32)
-    });
-
-    // Convert password_char
-    let password_char = form
-        .password_char
-        .as_ref()
-        .and_then(|s| s.chars().next().map(|c| c as u16));
-
-    // Build items lists
-    let (items_text, items_value): (Vec<String>, Vec<String>) = form
-        .items
-        .iter()
-        .map(|item| {
-            let text = item.display_text.clone().unwrap_or_default();
-            let value = item.value.clone().unwrap_or_default();
-            (text, value)
-        })
-        .unzip();
-
-    Ok(HwpFormObjectData {
-        common: convert_object_common_to_hwp(&form.common),
-        form_type,
-        name: form.name.clone().unwrap_or_default(),
-        value: form.value.clone().unwrap_or_default(),
-        properties,
-        caption: form.caption.clone(),
-        radio_group_name: form.radio_group_name.clone(),
-        back_color,
-        password_char,
-        max_length: form.max_length,
-        selected_value: form.selected_value.clone(),
-        list_box_rows: form.list_box_rows,
-        list_box_width: form.list_box_width,
-        item_height: form.item_height,
-        top_index: form.top_index,
-        items_text,
-        items_value,
-        min: form.min,
-        max: form.max,
-        scroll_value: form.scroll_value,
-        small_change: form.small_change,
-        large_change: form.large_change,
-        page: form.page,
-        delay: form.delay,
-    })
-}
-
-/// IR TextArt → HWP TextArtData 변환
-fn convert_text_art_to_hwp(text_art: &IrTextArt) -> Result<HwpTextArtData, ConversionError> {
-    let font_style = match text_art.font_style {
-        IrTextArtFontStyle::Regular => 0,
-        IrTextArtFontStyle::Bold => 1,
-        IrTextArtFontStyle::Italic => 2,
-        IrTextArtFontStyle::BoldItalic => 3,
-    };
-
-    let shape_type = match text_art.shape {
-        IrTextArtShapeType::Rectangle => 0,
-        IrTextArtShapeType::Circle => 1,
-        IrTextArtShapeType::ArchUp => 2,
-        IrTextArtShapeType::ArchDown => 3,
-        IrTextArtShapeType::Wave => 4,
-        IrTextArtShapeType::Cylinder => 5,
-        IrTextArtShapeType::Inflate => 6,
-        IrTextArtShapeType::Deflate => 7,
-        IrTextArtShapeType::Other(n) => n as u8,
-    };
-
-    let alignment = match text_art.alignment {
-        IrTextArtAlignment::Left => 0,
-        IrTextArtAlignment::Center => 1,
-        IrTextArtAlignment::Right => 2,
-        IrTextArtAlignment::Full => 3,
-    };
-
-    Ok(HwpTextArtData {
-        common: convert_object_common_to_hwp(&text_art.common),
-        text: text_art.text.clone(),
-        font_name: text_art.font_name.clone(),
-        font_style,
-        shape_type,
-        line_spacing: text_art.line_spacing,
-        char_spacing: text_art.char_spacing,
-        alignment,
-        width: text_art.common.size.width.value() as u32,
-        height: text_art.common.size.height.value() as u32,
-    })
 }
@@ -9,9 +9,12 @@ mod to_ir;
 
 pub use color::ColorConvert;
 
-pub use from_ir::IrToHwp;
+pub use from_ir::{HwpBinaryItem, HwpBuildData, IrToHwp};
+
+use std::collections::HashMap;
 
 use ir::WarningCollector;
+use primitive::BinaryDataId;
 
 /// HWP → IR 변환 컨텍스트
 pub struct ToIrContext {
@@ -38,6 +41,10 @@ impl Default for ToIrContext {
 pub struct FromIrContext {
     /// 경고 수집기
     pub warnings: WarningCollector,
+    /// IR 바이너리 데이터 아이디 → HWP BinData 아이디 (1부터)
+    binary_ids: HashMap<BinaryDataId, u16>,
+    /// 열려 있는 필드의 컨트롤 아이디 (필드 끝 인라인 문자 기록용)
+    open_fields: Vec<u32>,
 }
 
 impl FromIrContext {
@@ -45,8 +52,15 @@ impl FromIrContext {
     pub fn new() -> Self {
         Self {
             warnings: WarningCollector::new(),
+            binary_ids: HashMap::new(),
+            open_fields: Vec::new(),
         }
     }
+
+    /// IR 바이너리 데이터 아이디에 해당하는 HWP BinData 아이디 (없으면 0)
+    fn binary_data_id(&self, id: &BinaryDataId) -> u16 {
+        self.binary_ids.get(id).copied().unwrap_or(0)
+    }
 }
 
 impl Default for FromIrContext {
@@ -5,21 +5,25 @@
 //! 스타일 정보(폰트, 글자 모양, 문단 모양, 테두리/채우기, 스타일)를
 //! 모두 IR로 변환하여 완전한 문서 표현을 제공합니다.
 
+use std::cell::{Cell, RefCell};
+
 use super::{ColorConvert, ToIrContext};
 use crate::HwpDocument;
 use crate::body::control_data::ControlData;
 use crate::body::{
     ArcType as HwpArcType, ArrowSize as HwpArrowSize, ArrowType as HwpArrowType,
     Caption as HwpCaption, CaptionDirection as HwpCaptionDirection, CellPadding,
-    ChartData as HwpChartData, Control, ControlContent, ControlType,
-    CurveSegmentType as HwpCurveSegmentType, Endnote as HwpEndnote, Equation as HwpEquation,
-    Field as HwpField, FieldType as HwpFieldType, Footer as HwpFooter, Footnote as HwpFootnote,
-    FormObject as HwpFormObject, FormObjectType as HwpFormObjectType, Header as HwpHeader,
-    Hyperlink as HwpHyperlink, HyperlinkType as HwpHyperlinkType, ImageFlip as HwpImageFlip,
-    NoteNumberingType, OleObject as HwpOleObject, Picture as HwpPicture, PictureEffectType,
-    Point as HwpPoint, Shape as HwpShape, ShapeBorderLine, ShapeContainer as HwpShapeContainer,
+    ChartData as HwpChartData, Compose as HwpCompose, ComposeBorderType, Control, ControlContent,
+    ControlType, CurveSegmentType as HwpCurveSegmentType, Dutmal as HwpDutmal,
+    DutmalAlignment as HwpDutmalAlignment, DutmalPosition as HwpDutmalPosition,
+    Endnote as HwpEndnote, Equation as HwpEquation, Field as HwpField, FieldType as HwpFieldType,
+    Footer as HwpFooter, Footnote as HwpFootnote, FormObject as HwpFormObject,
+    FormObjectType as HwpFormObjectType, Header as HwpHeader, Hyperlink as HwpHyperlink,
+    HyperlinkType as HwpHyperlinkType, ImageFlip as HwpImageFlip, NoteNumberingType,
+    OleObject as HwpOleObject, Picture as HwpPicture, PictureEffectType, Point as HwpPoint,
+    Shape as HwpShape, ShapeBorderLine, ShapeContainer as HwpShapeContainer,
     ShapeType as HwpShapeType, Table as HwpTable, TableCell as HwpTableCell, TextArt as HwpTextArt,
-    TextArtAlignment as HwpTextArtAlignment, TextArtShape as HwpTextArtShape,
+    TextArtAlignment as HwpTextArtAlignment, TextArtShape as HwpTextArtShape, TextUnit,
     VideoData as HwpVideoData,
 };
 use crate::doc_info::{
@@ -28,9 +32,10 @@ use crate::doc_info::{
     Style as HwpStyle, VerticalAlignment as HwpVerticalAlignment,
 };
 use ir::{
-    BinaryData, BinaryDataId, BinaryDataStore, BinaryFormat, ConversionError, ConversionResult,
-    Document as IrDocument, Extensions, HwpExtensions, Metadata, Paragraph as IrParagraph,
-    Run as IrRun, Section as IrSection,
+    BinaryData, BinaryDataId, BinaryDataStore, BinaryFormat, CaretPosition, CompatibleDocument,
+    ConversionError, ConversionResult, Document as IrDocument, DocumentSettings, Extensions,
+    HwpExtensions, HwpScripts, Metadata, Paragraph as IrParagraph, Run as IrRun,
+    Section as IrSection,
     char_shape::{
         CharShape, EmphasisStyle, Font, FontFamily, FontRef, FontSet, ShadowStyle, UnderlineStyle,
     },
@@ -68,9 +73,9 @@ use primitive::{
     Alignment, ArrowSize as IrArrowSize, ArrowType as IrArrowType, BorderFillId, CharShapeId,
     Color, EmphasisType, FieldType as IrFieldType, FontId, HeaderFooterApplyTo,
     HeadingType as IrHeadingType, HorizontalRelativeTo as IrHorizontalRelativeTo, HwpUnit,
-    ImageEffect, Insets, LineBreakKorean, LineBreakLatin, LineCap as IrLineCap,
-    LineType as IrLineType, NumberFormat, OutlineType, ParaShapeId, Percent, Point as IrPoint,
-    ShadowType, Size, StrikethroughType, StyleId, StyleType as IrStyleType,
+    ImageEffect, Insets, LanguageType, LineBreakKorean, LineBreakLatin, LineCap as IrLineCap,
+    LineType as IrLineType, NumberFormat, OutlineType, PageNumberPosition, ParaShapeId, Percent,
+    Point as IrPoint, ShadowType, Size, StrikethroughType, StyleId, StyleType as IrStyleType,
     TabLeader as IrTabLeader, TabType as IrTabType, TextWrapSide as IrTextWrapSide,
     TextWrapType as IrTextWrapType, UnderlinePosition, UnderlineType, VerticalAlignment,
     VerticalRelativeTo as IrVerticalRelativeTo,
@@ -94,12 +99,15 @@ fn convert_document(
     // 메타데이터 변환
     doc.metadata = convert_metadata(hwp);
 
+    // 문서 설정 변환 (대표 언어는 구역 정의에서 채움)
+    doc.settings = convert_settings(hwp);
+
     // 스타일 변환 (완전한 변환)
     doc.styles = convert_styles(hwp, ctx)?;
 
     // 섹션 변환
     for section in hwp.sections() {
-        let section = convert_section(section, &mut doc)?;
+        let section = convert_section(section, hwp, &mut doc, ctx)?;
         doc.sections.push(section);
     }
 
@@ -112,6 +120,35 @@ fn convert_document(
     Ok(doc)
 }
 
+/// 문서 설정 변환 (시작 번호, 캐럿 위치, 호환 문서)
+fn convert_settings(hwp: &HwpDocument) -> DocumentSettings {
+    use crate::doc_info::TargetProgram;
+
+    let mut settings = DocumentSettings::default();
+    let doc_info = hwp.doc_info();
+
+    if let Some(props) = &doc_info.document_properties {
+        settings.starting_page_number = props.page_start_number() as u32;
+        settings.starting_footnote_number = props.footnote_start_number() as u32;
+        settings.starting_endnote_number = props.endnote_start_number() as u32;
+        settings.caret_position = Some(CaretPosition {
+            section: props.caret_list_id(),
+            paragraph: props.caret_paragraph_id(),
+            position: props.caret_position_in_paragraph(),
+        });
+    }
+
+    if let Some(compatible) = &doc_info.compatible_document {
+        settings.compatible_document = Some(match compatible.target_program() {
+            TargetProgram::HwpCurrent => CompatibleDocument::Current,
+            TargetProgram::Hwp2007 => CompatibleDocument::Hwp2007,
+            TargetProgram::MsWord => CompatibleDocument::MsWord,
+        });
+    }
+
+    settings
+}
+
 /// 메타데이터 변환
 fn convert_metadata(hwp: &HwpDocument) -> Metadata {
     let mut metadata = Metadata::new();
@@ -129,6 +166,12 @@ fn convert_metadata(hwp: &HwpDocument) -> Metadata {
     if let Some(keywords) = hwp.keywords() {
         metadata.keywords = keywords.split(',').map(|s| s.trim().to_owned()).collect();
     }
+    if let Some(summary) = hwp.summary_info() {
+        metadata.description = summary.comments.clone();
+        metadata.last_saved_by = summary.last_author.clone();
+        metadata.created = summary.creation_date.clone();
+        metadata.modified = summary.last_saved_date.clone();
+    }
 
     // 버전 정보
     let version = hwp.header().version();
@@ -149,9 +192,12 @@ fn convert_styles(
 ) -> Result<StyleStore, ConversionError> {
     let mut store = StyleStore::new();
 
-    // 폰트 변환
-    for face in hwp.font_faces() {
-        store.fonts.push(convert_font_face(face));
+    // 폰트 변환 (ID_MAPPINGS의 언어별 개수로 언어 그룹 기록)
+    let languages = font_languages(hwp);
+    for (index, face) in hwp.font_faces().iter().enumerate() {
+        let mut font = convert_font_face(face);
+        font.language = languages.get(index).copied();
+        store.fonts.push(font);
     }
 
     // 글자 모양 변환
@@ -192,6 +238,26 @@ fn convert_styles(
     Ok(store)
 }
 
+/// FACE_NAME 레코드 순서대로 각 폰트의 언어 그룹 목록
+fn font_languages(hwp: &HwpDocument) -> Vec<LanguageType> {
+    let Some(id_mappings) = &hwp.doc_info().id_mappings else {
+        return Vec::new();
+    };
+    let counts = [
+        (LanguageType::Korean, id_mappings.korean_font_count()),
+        (LanguageType::English, id_mappings.english_font_count()),
+        (LanguageType::Hanja, id_mappings.chinese_font_count()),
+        (LanguageType::Japanese, id_mappings.japanese_font_count()),
+        (LanguageType::Other, id_mappings.other_font_count()),
+        (LanguageType::Symbol, id_mappings.symbol_font_count()),
+        (LanguageType::User, id_mappings.user_font_count()),
+    ];
+    counts
+        .into_iter()
+        .flat_map(|(language, count)| std::iter::repeat_n(language, count.max(0) as usize))
+        .collect()
+}
+
 /// 폰트 변환
 fn convert_font_face(face: &FaceName) -> Font {
     use crate::doc_info::AlternateFontType;
@@ -744,7 +810,7 @@ fn convert_fill_info(fill_info: &crate::doc_info::FillInfo) -> ir::border_fill::
             };
 
             Fill::Image(ImageFill {
-                binary_id: BinaryDataId::new(image.image_info.binary_data_id.to_string()),
+                binary_id: BinaryDataId::from_numeric(image.image_info.binary_data_id),
                 mode,
                 brightness: image.image_info.brightness,
                 contrast: image.image_info.contrast,
@@ -870,16 +936,51 @@ fn convert_style(style: &HwpStyle) -> Style {
     }
 }
 
-/// 섹션 변환 컨텍스트 (각주/미주 모양 정보)
+/// 섹션 변환 컨텍스트 (각주/미주 모양 정보, 열린 필드)
 struct SectionContext<'a> {
+    /// 차트 저장소 등 바이너리 데이터 조회용 원본 문서
+    document: &'a HwpDocument,
     footnote_shape: Option<&'a crate::body::FootnoteShape>,
     endnote_shape: Option<&'a crate::body::EndnoteShape>,
+    /// 다음 필드 ID
+    next_field_id: Cell<u32>,
+    /// 아직 닫히지 않은 필드 ID (필드 끝 문자와 짝지음)
+    open_fields: RefCell<Vec<u32>>,
+    /// 내용(제목, 축, 계열 값)을 읽지 못한 차트 수
+    undecoded_charts: Cell<usize>,
+}
+
+impl<'a> SectionContext<'a> {
+    fn new(
+        document: &'a HwpDocument,
+        footnote_shape: Option<&'a crate::body::FootnoteShape>,
+        endnote_shape: Option<&'a crate::body::EndnoteShape>,
+    ) -> Self {
+        Self {
+            document,
+            footnote_shape,
+            endnote_shape,
+            next_field_id: Cell::new(0),
+            open_fields: RefCell::new(Vec::new()),
+            undecoded_charts: Cell::new(0),
+        }
+    }
+
+    /// 새 필드 ID를 할당하고 열린 필드로 기록
+    fn open_field(&self) -> u32 {
+        let id = self.next_field_id.get();
+        self.next_field_id.set(id + 1);
+        self.open_fields.borrow_mut().push(id);
+        id
+    }
 }
 
 /// 섹션 변환
 fn convert_section(
     section: &crate::Section,
+    hwp: &HwpDocument,
     ir_doc: &mut IrDocument,
+    ctx: &mut ToIrContext,
 ) -> Result<IrSection, ConversionError> {
     let mut ir_section = IrSection::default();
 
@@ -952,10 +1053,8 @@ fn convert_section(
     }
 
     // 섹션 컨텍스트 생성 (각주/미주 모양 정보 포함)
-    let section_context = SectionContext {
-        footnote_shape: section.footnote_shape(),
-        endnote_shape: section.endnote_shape(),
-    };
+    let section_context =
+        SectionContext::new(hwp, section.footnote_shape(), section.endnote_shape());
 
     // 문단 변환
     for para in section.paragraphs() {
@@ -963,6 +1062,14 @@ fn convert_section(
         ir_section.paragraphs.push(ir_para);
     }
 
+    let undecoded_charts = section_context.undecoded_charts.get();
+    if undecoded_charts > 0 {
+        ctx.warnings.data_loss(format!(
+            "차트 {}개의 제목, 축, 항목과 계열 값을 읽지 못했습니다 (OOXMLChartContents 없음, Contents는 VtChart 헤더만 확인)",
+            undecoded_charts
+        ));
+    }
+
     Ok(ir_section)
 }
 
@@ -1231,10 +1338,15 @@ fn convert_range_tag(range_tag: &crate::body::RangeTag) -> ir::paragraph::RangeT
 }
 
 /// 문단 변환 (컨텍스트 포함)
+///
+/// 문단 텍스트를 글자 모양 참조 위치에 따라 런으로 나누고, 확장 제어 문자는
+/// 문단의 컨트롤과 순서대로 짝지어 변환합니다.
 fn convert_paragraph_with_context(
     para: &crate::Paragraph,
     ctx: &SectionContext,
 ) -> Result<IrParagraph, ConversionError> {
+    use ir::paragraph::{RunContent, TabChar, Text};
+
     let mut ir_para = IrParagraph::new();
 
     // 문단 모양 ID
@@ -1254,112 +1366,153 @@ fn convert_paragraph_with_context(
         ir_para.range_tags.push(convert_range_tag(range_tag));
     }
 
-    // 텍스트 변환 - plain_text() 메서드 사용
-    let plain_text = para.plain_text();
-    if !plain_text.is_empty() {
-        ir_para.runs.push(IrRun::text(plain_text));
-    }
-
-    // 컨트롤 (표, 그림 등) 변환 및 Caption 연결
-    // Caption은 이전 Table/Picture/Shape 등에 연결해야 함
-    let mut pending_caption: Option<IrCaption> = None;
-    let mut field_id_counter: u32 = 0;
-
-    for control in para.controls() {
-        // Caption 컨트롤 먼저 체크
-        if let Some(ControlContent::Caption(caption)) = control.content() {
-            // Caption을 IR Caption으로 변환하고 보류
-            if let Ok(ir_caption) = convert_caption(caption) {
-                pending_caption = Some(ir_caption);
-            }
-            continue;
+    let shape_refs = para.character_shape_references();
+    let shape_at = |position: u32| {
+        shape_refs
+            .iter()
+            .take_while(|shape_ref| shape_ref.position <= position)
+            .last()
+            .or(shape_refs.first())
+            .map(|shape_ref| CharShapeId::new(shape_ref.character_shape_id))
+    };
+
+    let mut controls = para.controls().iter();
+    let mut runs: Vec<IrRun> = Vec::new();
+    let mut push = |position: u32, content: RunContent| {
+        let char_shape_id = shape_at(position);
+        match runs.last_mut() {
+            Some(run) if run.char_shape_id == char_shape_id => run.contents.push(content),
+            _ => runs.push(IrRun {
+                char_shape_id,
+                contents: vec![content],
+            }),
         }
+    };
 
-        // Field 컨트롤 특별 처리 (Hyperlink 제외 - FieldStart/FieldEnd로 변환)
-        if let Some(ControlContent::Field(field)) = control.content()
-            && field.field_type() != HwpFieldType::Hyperlink
-        {
-            // 비-하이퍼링크 필드는 FieldStart/FieldEnd로 변환
-            let field_id = field_id_counter;
-            field_id_counter += 1;
-
-            let field_start = convert_field_to_field_start(field, field_id, control.data());
-            let field_end = IrFieldEnd { id: field_id };
-
-            // 필드 값(표시 텍스트)도 포함
-            let mut run = IrRun::new();
-            run.contents
-                .push(ir::paragraph::RunContent::FieldStart(field_start));
-
-            // 필드의 표시 텍스트 추가 (있는 경우)
-            let display_text = field.display_text();
-            if !display_text.is_empty() {
-                run.contents
-                    .push(ir::paragraph::RunContent::Text(ir::paragraph::Text::new(
-                        display_text,
-                    )));
+    let units = para.text().map(|text| text.units()).unwrap_or_default();
+    for (position, unit) in units {
+        match unit {
+            TextUnit::Char(c) => push(position, RunContent::Text(Text::new(c.to_string()))),
+            TextUnit::Control(10) => push(position, RunContent::LineBreak),
+            TextUnit::Control(24) => push(position, RunContent::Hyphen),
+            TextUnit::Control(30) => push(position, RunContent::NonBreakingSpace),
+            TextUnit::Control(31) => push(position, RunContent::FixedWidthSpace),
+            TextUnit::Control(_) => {}
+            TextUnit::Inline { code: 9, data } => {
+                let width = u32::from(data[0]) | (u32::from(data[1]) << 16);
+                let leader = match data[2] & 0xFF {
+                    1 => Some('.'),
+                    2 | 3 => Some('-'),
+                    4 => Some('_'),
+                    _ => None,
+                };
+                let tab = TabChar {
+                    width: Some(HwpUnit::new(width as i32)),
+                    leader,
+                    tab_type: Some(IrTabType::from_raw((data[2] >> 8) as u8)),
+                };
+                push(position, RunContent::Tab(tab));
             }
-
-            run.contents
-                .push(ir::paragraph::RunContent::FieldEnd(field_end));
-            ir_para.runs.push(run);
-            continue;
-            // Hyperlink 필드는 아래 일반 컨트롤 변환으로 처리
-        }
-
-        // 일반 컨트롤 변환 (섹션 컨텍스트 전달)
-        if let Some(mut ir_control) = convert_control_with_context(control, ctx)? {
-            // 보류된 Caption이 있으면 해당 컨트롤에 연결
-            if let Some(caption) = pending_caption.take() {
-                match &mut ir_control {
-                    IrControl::Table(table) => table.common.caption = Some(caption),
-                    IrControl::Picture(picture) => picture.common.caption = Some(caption),
-                    IrControl::Equation(equation) => equation.common.caption = Some(caption),
-                    IrControl::Shape(shape) => shape.common.caption = Some(caption),
-                    // 다른 컨트롤 타입에는 Caption을 연결할 수 없음
-                    _ => {}
+            TextUnit::Inline { code: 4, .. } => {
+                // 필드 끝: 가장 최근에 열린 필드를 닫음
+                if let Some(id) = ctx.open_fields.borrow_mut().pop() {
+                    push(position, RunContent::FieldEnd(IrFieldEnd { id }));
+                }
+            }
+            TextUnit::Inline { .. } => {}
+            TextUnit::Extended { .. } => {
+                if let Some(control) = controls.next()
+                    && let Some(content) = convert_run_control(control, ctx)?
+                {
+                    push(position, content);
                 }
             }
-            ir_para.runs.push(IrRun::control(ir_control));
         }
     }
 
-    // 남은 Caption이 있고, runs에 캡션을 연결할 수 있는 컨트롤이 있으면 연결
-    if let Some(caption) = pending_caption {
-        // 뒤에서부터 검색하여 가장 최근의 Table/Picture 등에 연결
-        'outer: for run in ir_para.runs.iter_mut().rev() {
-            for content in run.contents.iter_mut().rev() {
-                if let ir::paragraph::RunContent::Control(ctrl) = content {
-                    let connected = match ctrl.as_mut() {
-                        IrControl::Table(table) => {
-                            table.common.caption = Some(caption.clone());
-                            true
-                        }
-                        IrControl::Picture(picture) => {
-                            picture.common.caption = Some(caption.clone());
-                            true
-                        }
-                        IrControl::Equation(equation) => {
-                            equation.common.caption = Some(caption.clone());
-                            true
-                        }
-                        IrControl::Shape(shape) => {
-                            shape.common.caption = Some(caption.clone());
-                            true
-                        }
-                        _ => false,
-                    };
-                    if connected {
-                        break 'outer;
-                    }
+    // 텍스트에 대응하는 확장 문자가 없는 컨트롤은 문단 끝에 추가
+    let end = para.character_count() & 0x7FFF_FFFF;
+    for control in controls {
+        if let Some(content) = convert_run_control(control, ctx)? {
+            push(end, content);
+        }
+    }
+
+    // 연속된 텍스트 병합
+    for run in &mut runs {
+        let mut contents: Vec<RunContent> = Vec::with_capacity(run.contents.len());
+        for content in run.contents.drain(..) {
+            match (contents.last_mut(), content) {
+                (Some(RunContent::Text(last)), RunContent::Text(text)) => {
+                    last.text.push_str(&text.text);
                 }
+                (_, content) => contents.push(content),
             }
         }
+        run.contents = contents;
+    }
+
+    if runs.is_empty() {
+        runs.push(IrRun {
+            char_shape_id: shape_at(0),
+            contents: Vec::new(),
+        });
     }
+    ir_para.runs = runs;
 
     Ok(ir_para)
 }
 
+/// 문단 텍스트의 확장 제어 문자에 대응하는 컨트롤을 런 내용으로 변환
+///
+/// 구역/단 정의는 섹션 수준에서 처리하므로 건너뜁니다. 글자 겹침과 덧말은 런 내용
+/// 그대로, 하이퍼링크가 아닌 필드는 FieldStart로, 하이퍼링크는 컨트롤로 변환하며
+/// 필드와 하이퍼링크는 필드 끝과 짝지어지도록 열린 필드로 기록합니다.
+fn convert_run_control(
+    control: &Control,
+    ctx: &SectionContext,
+) -> Result<Option<ir::paragraph::RunContent>, ConversionError> {
+    use ir::paragraph::RunContent;
+
+    if matches!(
+        control.content(),
+        Some(ControlContent::SectionDefinition(_) | ControlContent::ColumnDefinition(_))
+    ) {
+        return Ok(None);
+    }
+
+    match control.content() {
+        Some(ControlContent::Compose(compose)) => {
+            return Ok(Some(RunContent::Compose(convert_compose(compose))));
+        }
+        Some(ControlContent::Dutmal(dutmal)) => {
+            return Ok(Some(RunContent::Dutmal(convert_dutmal(dutmal))));
+        }
+        _ => {}
+    }
+
+    if let Some(ControlContent::Field(field)) = control.content()
+        && field.field_type() != HwpFieldType::Hyperlink
+    {
+        let field_id = ctx.open_field();
+        let field_start = convert_field_to_field_start(field, field_id, control.control_data());
+        return Ok(Some(RunContent::FieldStart(field_start)));
+    }
+
+    let ir_control = match convert_control_with_context(control, ctx)? {
+        Some(ir_control) => ir_control,
+        None => IrControl::Unknown(Box::new(ir::control::UnknownControl {
+            ctrl_id: control.id().raw().to_be_bytes(),
+            data: control.data().to_vec(),
+        })),
+    };
+    if matches!(ir_control, IrControl::Hyperlink(_)) {
+        ctx.open_field();
+    }
+
+    Ok(Some(RunContent::Control(Box::new(ir_control))))
+}
+
 /// 컨트롤 변환 (섹션 컨텍스트 포함)
 fn convert_control_with_context(
     control: &Control,
@@ -1367,13 +1520,18 @@ fn convert_control_with_context(
 ) -> Result<Option<IrControl>, ConversionError> {
     // 개체 공통 속성이 있는 컨트롤에서 ObjectCommon 파싱
     let object_common = parse_object_common(control.data());
+    let caption = control
+        .caption()
+        .map(|caption| convert_caption(caption, ctx))
+        .transpose()?;
 
     match control.content() {
         Some(ControlContent::Table(table)) => {
-            let mut ir_table = convert_table(table)?;
+            let mut ir_table = convert_table(table, ctx)?;
             if let Some(common) = object_common {
                 ir_table.common = common;
             }
+            ir_table.common.caption = caption;
             Ok(Some(IrControl::Table(Box::new(ir_table))))
         }
         Some(ControlContent::Picture(picture)) => {
@@ -1381,6 +1539,7 @@ fn convert_control_with_context(
             if let Some(common) = object_common {
                 ir_picture.common = common;
             }
+            ir_picture.common.caption = caption;
             Ok(Some(IrControl::Picture(Box::new(ir_picture))))
         }
         Some(ControlContent::Equation(equation)) => {
@@ -1388,24 +1547,25 @@ fn convert_control_with_context(
             if let Some(common) = object_common {
                 ir_equation.common = common;
             }
+            ir_equation.common.caption = caption;
             Ok(Some(IrControl::Equation(Box::new(ir_equation))))
         }
         Some(ControlContent::Header(header)) => {
-            let ir_header = convert_header(header)?;
+            let ir_header = convert_header(header, ctx)?;
             Ok(Some(IrControl::Header(Box::new(ir_header))))
         }
         Some(ControlContent::Footer(footer)) => {
-            let ir_footer = convert_footer(footer)?;
+            let ir_footer = convert_footer(footer, ctx)?;
             Ok(Some(IrControl::Footer(Box::new(ir_footer))))
         }
         Some(ControlContent::Footnote(footnote)) => {
             // 섹션 컨텍스트에서 각주 모양 정보 사용
-            let ir_footnote = convert_footnote_with_shape(footnote, ctx.footnote_shape)?;
+            let ir_footnote = convert_footnote_with_shape(footnote, ctx)?;
             Ok(Some(IrControl::Footnote(Box::new(ir_footnote))))
         }
         Some(ControlContent::Endnote(endnote)) => {
             // 섹션 컨텍스트에서 미주 모양 정보 사용
-            let ir_endnote = convert_endnote_with_shape(endnote, ctx.endnote_shape)?;
+            let ir_endnote = convert_endnote_with_shape(endnote, ctx)?;
             Ok(Some(IrControl::Endnote(Box::new(ir_endnote))))
         }
         Some(ControlContent::Hyperlink(hyperlink)) => {
@@ -1417,13 +1577,15 @@ fn convert_control_with_context(
             if let Some(common) = object_common {
                 ir_shape.common = common;
             }
+            ir_shape.common.caption = caption;
             Ok(Some(IrControl::Shape(Box::new(ir_shape))))
         }
         Some(ControlContent::TextBox(text_box)) => {
-            let mut ir_text_box = convert_text_box(text_box, control)?;
+            let mut ir_text_box = convert_text_box(text_box, control, ctx)?;
             if let Some(common) = object_common {
                 ir_text_box.common = common;
             }
+            ir_text_box.common.caption = caption;
             Ok(Some(IrControl::TextBox(Box::new(ir_text_box))))
         }
         Some(ControlContent::Video(video)) => {
@@ -1431,20 +1593,23 @@ fn convert_control_with_context(
             if let Some(common) = object_common {
                 ir_video.common = common;
             }
+            ir_video.common.caption = caption;
             Ok(Some(IrControl::Video(Box::new(ir_video))))
         }
         Some(ControlContent::OleObject(ole)) => {
-            let mut ir_ole = convert_ole(ole, control)?;
+            let mut ir_ole = convert_ole(ole, control, ctx)?;
             if let Some(common) = object_common {
                 ir_ole.common = common;
             }
+            ir_ole.common.caption = caption;
             Ok(Some(IrControl::Ole(Box::new(ir_ole))))
         }
         Some(ControlContent::Chart(chart)) => {
-            let mut ir_chart = convert_chart(chart, control)?;
+            let mut ir_chart = convert_chart(chart, control, ctx)?;
             if let Some(common) = object_common {
                 ir_chart.common = common;
             }
+            ir_chart.common.caption = caption;
             Ok(Some(IrControl::Chart(Box::new(ir_chart))))
         }
         Some(ControlContent::FormObject(form)) => {
@@ -1452,6 +1617,7 @@ fn convert_control_with_context(
             if let Some(common) = object_common {
                 ir_form.common = common;
             }
+            ir_form.common.caption = caption;
             Ok(Some(IrControl::FormObject(Box::new(ir_form))))
         }
         Some(ControlContent::TextArt(text_art)) => {
@@ -1459,6 +1625,7 @@ fn convert_control_with_context(
             if let Some(common) = object_common {
                 ir_text_art.common = common;
             }
+            ir_text_art.common.caption = caption;
             Ok(Some(IrControl::TextArt(Box::new(ir_text_art))))
         }
         Some(ControlContent::Field(field)) => {
@@ -1483,19 +1650,15 @@ fn convert_control_with_context(
             if let Some(common) = object_common {
                 ir_shape.common = common;
             }
+            ir_shape.common.caption = caption;
             Ok(Some(IrControl::Shape(Box::new(ir_shape))))
         }
         Some(ControlContent::Memo(memo)) => {
             // 메모를 IR Memo로 변환
-            // 메모 내부에서는 각주/미주가 없으므로 기본 컨텍스트 사용
-            let empty_ctx = SectionContext {
-                footnote_shape: None,
-                endnote_shape: None,
-            };
             let ir_paragraphs: Vec<IrParagraph> = memo
                 .paragraphs()
                 .iter()
-                .map(|p| convert_paragraph_with_context(p, &empty_ctx))
+                .map(|p| convert_paragraph_with_context(p, ctx))
                 .collect::<Result<Vec<_>, _>>()?;
 
             let ir_memo = ir::control::Memo {
@@ -1520,6 +1683,10 @@ fn convert_control_with_context(
             // 단 정의는 Section 레벨에서 columns로 처리되므로 컨트롤로는 변환하지 않음
             Ok(None)
         }
+        Some(ControlContent::Compose(_) | ControlContent::Dutmal(_)) => {
+            // 글자 겹침과 덧말은 컨트롤이 아닌 런 내용으로 변환됨 (convert_run_control)
+            Ok(None)
+        }
         // 컨트롤 타입으로 처리하는 경우 (ControlContent가 없는 컨트롤들)
         None => {
             match control.control_type() {
@@ -1536,7 +1703,7 @@ fn convert_control_with_context(
                     Ok(Some(IrControl::NewNumber(Box::new(ir_new_number))))
                 }
                 ControlType::HiddenComment => {
-                    let ir_hidden_comment = convert_hidden_comment(control)?;
+                    let ir_hidden_comment = convert_hidden_comment(control, ctx)?;
                     Ok(Some(IrControl::HiddenComment(Box::new(ir_hidden_comment))))
                 }
                 ControlType::PageNumberPosition => {
@@ -1555,7 +1722,10 @@ fn convert_control_with_context(
 }
 
 /// 캡션 변환
-fn convert_caption(caption: &HwpCaption) -> Result<IrCaption, ConversionError> {
+fn convert_caption(
+    caption: &HwpCaption,
+    ctx: &SectionContext,
+) -> Result<IrCaption, ConversionError> {
     // 캡션 위치 변환
     let position = match caption.direction() {
         HwpCaptionDirection::Below => IrCaptionPosition::Bottom,
@@ -1564,27 +1734,23 @@ fn convert_caption(caption: &HwpCaption) -> Result<IrCaption, ConversionError> {
         HwpCaptionDirection::Right => IrCaptionPosition::Right,
     };
 
-    // 캡션 문단 변환 (캡션 내부에서는 각주/미주가 없으므로 빈 컨텍스트 사용)
-    let empty_ctx = SectionContext {
-        footnote_shape: None,
-        endnote_shape: None,
-    };
+    // 캡션 문단 변환
     let paragraphs: Vec<IrParagraph> = caption
         .paragraphs()
         .iter()
-        .filter_map(|para| convert_paragraph_with_context(para, &empty_ctx).ok())
+        .filter_map(|para| convert_paragraph_with_context(para, ctx).ok())
         .collect();
 
     Ok(IrCaption {
         position,
-        width: HwpUnit::default(), // 캡션 너비는 HWP에서 따로 지정하지 않음
+        width: HwpUnit::new(caption.width() as i32),
         gap: HwpUnit::new(caption.gap()),
         paragraphs,
     })
 }
 
 /// 표 변환
-fn convert_table(table: &HwpTable) -> Result<IrTable, ConversionError> {
+fn convert_table(table: &HwpTable, ctx: &SectionContext) -> Result<IrTable, ConversionError> {
     let props = &table.properties;
 
     let mut ir_table = IrTable::new(props.row_count, props.column_count);
@@ -1605,7 +1771,7 @@ fn convert_table(table: &HwpTable) -> Result<IrTable, ConversionError> {
     ir_table.page_break = match props.page_border_split() {
         crate::body::table::PageBorderSplit::NoSplit => ir::table::TablePageBreak::None,
         crate::body::table::PageBorderSplit::SplitByCell => ir::table::TablePageBreak::Cell,
-        crate::body::table::PageBorderSplit::NoSplitAlt => ir::table::TablePageBreak::None,
+        crate::body::table::PageBorderSplit::NoSplitAlt => ir::table::TablePageBreak::Table,
     };
 
     // 표 안쪽 여백 변환
@@ -1642,7 +1808,7 @@ fn convert_table(table: &HwpTable) -> Result<IrTable, ConversionError> {
 
         // 해당 행의 셀들 변환
         for cell in table.cells_in_row(row_idx) {
-            ir_row.cells.push(convert_table_cell(cell)?);
+            ir_row.cells.push(convert_table_cell(cell, ctx)?);
         }
 
         ir_table.rows.push(ir_row);
@@ -1652,7 +1818,10 @@ fn convert_table(table: &HwpTable) -> Result<IrTable, ConversionError> {
 }
 
 /// 표 셀 변환
-fn convert_table_cell(cell: &HwpTableCell) -> Result<IrTableCell, ConversionError> {
+fn convert_table_cell(
+    cell: &HwpTableCell,
+    ctx: &SectionContext,
+) -> Result<IrTableCell, ConversionError> {
     let mut ir_cell = IrTableCell::new(cell.row, cell.column);
 
     ir_cell.column_span = cell.column_span;
@@ -1660,6 +1829,11 @@ fn convert_table_cell(cell: &HwpTableCell) -> Result<IrTableCell, ConversionErro
     ir_cell.width = HwpUnit::new(cell.width.value());
     ir_cell.height = HwpUnit::new(cell.height.value());
     ir_cell.padding = convert_cell_padding(&cell.padding);
+    ir_cell.vertical_alignment = match cell.vertical_alignment_raw() {
+        1 => VerticalAlignment::Middle,
+        2 => VerticalAlignment::Bottom,
+        _ => VerticalAlignment::Top,
+    };
 
     ir_cell.border_fill_id = if cell.border_fill_id > 0 {
         Some(BorderFillId::new(cell.border_fill_id as u32))
@@ -1667,15 +1841,11 @@ fn convert_table_cell(cell: &HwpTableCell) -> Result<IrTableCell, ConversionErro
         None
     };
 
-    // 셀 내용 (문단들) 변환 (표 셀 내부에서는 각주/미주가 없으므로 빈 컨텍스트 사용)
-    let empty_ctx = SectionContext {
-        footnote_shape: None,
-        endnote_shape: None,
-    };
+    // 셀 내용 (문단들) 변환
     for para in &cell.paragraphs {
         ir_cell
             .paragraphs
-            .push(convert_paragraph_with_context(para, &empty_ctx)?);
+            .push(convert_paragraph_with_context(para, ctx)?);
     }
 
     Ok(ir_cell)
@@ -1695,10 +1865,18 @@ fn convert_cell_padding(padding: &CellPadding) -> Insets {
 fn convert_binary_data(hwp: &HwpDocument) -> Result<BinaryDataStore, ConversionError> {
     let mut store = BinaryDataStore::new();
 
+    let storage_compressed = hwp.header().properties().is_compressed();
     for id in hwp.binary_data_ids() {
         if let Some(data) = hwp.get_binary_data(id) {
             let format = detect_binary_format(data);
-            let ir_data = BinaryData::new(format, data.to_vec());
+            let mut ir_data = BinaryData::new(format, data.to_vec());
+            // 스트림 이름과 항목별 압축 여부 보존
+            if let Some(info) = hwp.doc_info().binary_data.get(id as usize - 1) {
+                if let Some(name) = info.stream_name() {
+                    ir_data = ir_data.with_filename(name);
+                }
+                ir_data.compressed = info.compression().is_compressed(storage_compressed);
+            }
             let ir_id = BinaryDataId::from_numeric(id);
             store.add(ir_id, ir_data);
         }
@@ -1737,7 +1915,7 @@ fn convert_extensions(hwp: &HwpDocument, ctx: &mut ToIrContext) -> Extensions {
     let mut ext = Extensions::new();
 
     // HWP 고유 데이터
-    let hwp_ext = HwpExtensions::default();
+    let mut hwp_ext = HwpExtensions::default();
 
     // 배포용 문서 확인
     if hwp.is_distribution_document() {
@@ -1745,10 +1923,23 @@ fn convert_extensions(hwp: &HwpDocument, ctx: &mut ToIrContext) -> Extensions {
             .data_loss("배포용 문서 데이터는 HWPX로 변환 시 손실됩니다");
     }
 
-    // 스크립트 확인
-    if hwp.has_scripts() {
+    // 스크립트
+    if let Some(scripts) = hwp.scripts().filter(|s| !s.is_empty()) {
         ctx.warnings
             .data_loss("스크립트는 HWPX로 변환 시 손실됩니다");
+        hwp_ext.scripts = Some(HwpScripts {
+            version_high: scripts.version.high,
+            version_low: scripts.version.low,
+            header: scripts.header.clone(),
+            source: scripts.source.clone(),
+            pre_source: scripts.pre_source.clone(),
+            post_source: scripts.post_source.clone(),
+        });
+    }
+
+    // 연결 문서
+    if let Some(link_doc) = hwp.doc_options().and_then(|o| o.link_doc.as_ref()) {
+        hwp_ext.linked_documents = link_doc.paths().to_vec();
     }
 
     ext.hwp = Some(hwp_ext);
@@ -1894,15 +2085,12 @@ fn convert_picture(picture: &HwpPicture) -> Result<IrPicture, ConversionError> {
 
     let mut ir_picture = IrPicture::new(binary_id);
 
-    // 원본 크기 (픽셀 → HwpUnit 변환, 대략적 계산: 96 DPI 가정)
+    // 원본 크기 (HwpUnit)
     let (width, height) = props.image_dimension;
-    if width > 0 && height > 0 {
-        // 픽셀을 HwpUnit으로 변환 (1 inch = 96 pixels = 7200 HwpUnits)
-        ir_picture.original_size = Size {
-            width: HwpUnit::new((width as i32 * 7200) / 96),
-            height: HwpUnit::new((height as i32 * 7200) / 96),
-        };
-    }
+    ir_picture.original_size = Size {
+        width: HwpUnit::new(width as i32),
+        height: HwpUnit::new(height as i32),
+    };
 
     // 자르기 변환
     ir_picture.crop = IrImageCrop {
@@ -2030,18 +2218,15 @@ fn convert_equation(equation: &HwpEquation) -> Result<IrEquation, ConversionErro
 }
 
 /// 머리글 변환
-fn convert_header(header: &HwpHeader) -> Result<HeaderFooterControl, ConversionError> {
+fn convert_header(
+    header: &HwpHeader,
+    ctx: &SectionContext,
+) -> Result<HeaderFooterControl, ConversionError> {
     let apply_to = convert_header_footer_target(header.target());
 
-    // 머리글 내부에서는 각주/미주가 없으므로 빈 컨텍스트 사용
-    let empty_ctx = SectionContext {
-        footnote_shape: None,
-        endnote_shape: None,
-    };
-
     let mut paragraphs = Vec::new();
     for para in header.paragraphs() {
-        paragraphs.push(convert_paragraph_with_context(para, &empty_ctx)?);
+        paragraphs.push(convert_paragraph_with_context(para, ctx)?);
     }
 
     Ok(HeaderFooterControl {
@@ -2051,18 +2236,15 @@ fn convert_header(header: &HwpHeader) -> Result<HeaderFooterControl, ConversionE
 }
 
 /// 바닥글 변환
-fn convert_footer(footer: &HwpFooter) -> Result<HeaderFooterControl, ConversionError> {
+fn convert_footer(
+    footer: &HwpFooter,
+    ctx: &SectionContext,
+) -> Result<HeaderFooterControl, ConversionError> {
     let apply_to = convert_header_footer_target(footer.target());
 
-    // 바닥글 내부에서는 각주/미주가 없으므로 빈 컨텍스트 사용
-    let empty_ctx = SectionContext {
-        footnote_shape: None,
-        endnote_shape: None,
-    };
-
     let mut paragraphs = Vec::new();
     for para in footer.paragraphs() {
-        paragraphs.push(convert_paragraph_with_context(para, &empty_ctx)?);
+        paragraphs.push(convert_paragraph_with_context(para, ctx)?);
     }
 
     Ok(HeaderFooterControl {
@@ -2085,21 +2267,15 @@ const fn convert_header_footer_target(
 /// 각주 변환 (섹션의 각주 모양 정보 활용)
 fn convert_footnote_with_shape(
     footnote: &HwpFootnote,
-    footnote_shape: Option<&crate::body::FootnoteShape>,
+    ctx: &SectionContext,
 ) -> Result<IrNote, ConversionError> {
-    // 각주 내부 문단에서는 각주/미주가 없으므로 빈 컨텍스트 사용
-    let empty_ctx = SectionContext {
-        footnote_shape: None,
-        endnote_shape: None,
-    };
-
     let mut paragraphs = Vec::new();
     for para in footnote.paragraphs() {
-        paragraphs.push(convert_paragraph_with_context(para, &empty_ctx)?);
+        paragraphs.push(convert_paragraph_with_context(para, ctx)?);
     }
 
     // 섹션의 각주 모양에서 번호 형식과 위치 정보 가져오기
-    let (number_format, number_position) = if let Some(shape) = footnote_shape {
+    let (number_format, number_position) = if let Some(shape) = ctx.footnote_shape {
         let format = convert_note_numbering_type_to_format(shape.numbering_type);
         let position = if shape.superscript {
             primitive::NoteNumberPosition::Superscript
@@ -2126,21 +2302,15 @@ fn convert_footnote_with_shape(
 /// 미주 변환 (섹션의 미주 모양 정보 활용)
 fn convert_endnote_with_shape(
     endnote: &HwpEndnote,
-    endnote_shape: Option<&crate::body::EndnoteShape>,
+    ctx: &SectionContext,
 ) -> Result<IrNote, ConversionError> {
-    // 미주 내부 문단에서는 각주/미주가 없으므로 빈 컨텍스트 사용
-    let empty_ctx = SectionContext {
-        footnote_shape: None,
-        endnote_shape: None,
-    };
-
     let mut paragraphs = Vec::new();
     for para in endnote.paragraphs() {
-        paragraphs.push(convert_paragraph_with_context(para, &empty_ctx)?);
+        paragraphs.push(convert_paragraph_with_context(para, ctx)?);
     }
 
     // 섹션의 미주 모양에서 번호 형식과 위치 정보 가져오기
-    let (number_format, number_position) = if let Some(shape) = endnote_shape {
+    let (number_format, number_position) = if let Some(shape) = ctx.endnote_shape {
         let format = convert_note_numbering_type_to_format(shape.numbering_type);
         let position = if shape.superscript {
             primitive::NoteNumberPosition::Superscript
@@ -2194,19 +2364,19 @@ fn convert_shape(shape: &HwpShape, ctx: &SectionContext) -> Result<IrShape, Conv
     // 회전 각도 변환 (i16 → f64)
     let rotation = shape.element_properties.rotation as f64;
 
-    // 도형 크기 계산
-    let size = calculate_shape_size(&shape.shape_type);
-
-    // 공통 속성 설정 - 중심점 정보 활용
-    let center_x = shape.element_properties.center_x.value();
-    let center_y = shape.element_properties.center_y.value();
+    // 공통 속성 설정 - 그룹 안에서의 위치와 현재 크기 사용
+    // (본문에 직접 놓인 개체는 호출 측에서 컨트롤 헤더의 값으로 덮어씀)
+    let element = &shape.element_properties;
     let common = ObjectCommon {
         id: None,
         position: IrPoint {
-            x: HwpUnit::new(center_x),
-            y: HwpUnit::new(center_y),
+            x: HwpUnit::new(element.offset_x.value()),
+            y: HwpUnit::new(element.offset_y.value()),
+        },
+        size: Size {
+            width: HwpUnit::new(element.width.value()),
+            height: HwpUnit::new(element.height.value()),
         },
-        size,
         z_order: 0,
         text_wrap: ir::control::TextWrap::default(),
         caption: None,
@@ -2236,6 +2406,7 @@ fn convert_shape(shape: &HwpShape, ctx: &SectionContext) -> Result<IrShape, Conv
             .collect();
 
         // 텍스트박스 속성이 있으면 사용, 없으면 기본값
+        // 문단 리스트 속성: bit 0~2 글자 방향, bit 5~6 세로 정렬
         let (padding, vertical_alignment, text_direction) = if let Some(tb) = &shape.text_box {
             (
                 Insets {
@@ -2244,8 +2415,15 @@ fn convert_shape(shape: &HwpShape, ctx: &SectionContext) -> Result<IrShape, Conv
                     top: HwpUnit::new(tb.margin_top as i32),
                     bottom: HwpUnit::new(tb.margin_bottom as i32),
                 },
-                VerticalAlignment::Top,
-                TextDirection::Horizontal,
+                match (tb.list_properties >> 5) & 0x03 {
+                    1 => VerticalAlignment::Middle,
+                    2 => VerticalAlignment::Bottom,
+                    _ => VerticalAlignment::Top,
+                },
+                match tb.list_properties & 0x07 {
+                    0 => TextDirection::Horizontal,
+                    _ => TextDirection::Vertical,
+                },
             )
         } else {
             (
@@ -2300,7 +2478,7 @@ fn convert_shape(shape: &HwpShape, ctx: &SectionContext) -> Result<IrShape, Conv
         shape_type,
         line,
         fill,
-        shadow: None,
+        shadow: convert_shape_shadow(&shape.shadow),
         rotation,
         text,
         translation_matrix,
@@ -2309,116 +2487,24 @@ fn convert_shape(shape: &HwpShape, ctx: &SectionContext) -> Result<IrShape, Conv
     })
 }
 
-/// 도형 타입에서 크기 계산
-fn calculate_shape_size(shape_type: &HwpShapeType) -> Size {
-    match shape_type {
-        HwpShapeType::Line(line) => {
-            // 선의 경우 시작점과 끝점의 바운딩 박스
-            let width = (line.end.x.value() - line.start.x.value()).abs();
-            let height = (line.end.y.value() - line.start.y.value()).abs();
-            Size {
-                width: HwpUnit::new(width.max(1)), // 최소 1
-                height: HwpUnit::new(height.max(1)),
-            }
-        }
-        HwpShapeType::Rectangle(rect) => {
-            // 4개 꼭짓점에서 바운딩 박스 계산
-            let xs: Vec<i32> = rect.corners.iter().map(|p| p.x.value()).collect();
-            let ys: Vec<i32> = rect.corners.iter().map(|p| p.y.value()).collect();
-            let min_x = xs.iter().copied().min().unwrap_or(0);
-            let max_x = xs.iter().copied().max().unwrap_or(0);
-            let min_y = ys.iter().copied().min().unwrap_or(0);
-            let max_y = ys.iter().copied().max().unwrap_or(0);
-            Size {
-                width: HwpUnit::new((max_x - min_x).max(1)),
-                height: HwpUnit::new((max_y - min_y).max(1)),
-            }
-        }
-        HwpShapeType::Ellipse(ellipse) => {
-            // axis1, axis2에서 크기 계산 (중심에서 축까지의 거리 * 2)
-            let dx1 = (ellipse.axis1.x.value() - ellipse.center.x.value()).abs();
-            let dy1 = (ellipse.axis1.y.value() - ellipse.center.y.value()).abs();
-            let dx2 = (ellipse.axis2.x.value() - ellipse.center.x.value()).abs();
-            let dy2 = (ellipse.axis2.y.value() - ellipse.center.y.value()).abs();
-            // 두 축 중 더 큰 것 선택
-            let width = (dx1.max(dx2) * 2).max(1);
-            let height = (dy1.max(dy2) * 2).max(1);
-            Size {
-                width: HwpUnit::new(width),
-                height: HwpUnit::new(height),
-            }
-        }
-        HwpShapeType::Arc(arc) => {
-            // Arc도 Ellipse와 유사
-            let dx1 = (arc.axis1.x.value() - arc.center.x.value()).abs();
-            let dy1 = (arc.axis1.y.value() - arc.center.y.value()).abs();
-            let dx2 = (arc.axis2.x.value() - arc.center.x.value()).abs();
-            let dy2 = (arc.axis2.y.value() - arc.center.y.value()).abs();
-            let width = (dx1.max(dx2) * 2).max(1);
-            let height = (dy1.max(dy2) * 2).max(1);
-            Size {
-                width: HwpUnit::new(width),
-                height: HwpUnit::new(height),
-            }
-        }
-        HwpShapeType::Polygon(polygon) => {
-            // 모든 점의 바운딩 박스
-            if polygon.points.is_empty() {
-                return Size::default();
-            }
-            let xs: Vec<i32> = polygon.points.iter().map(|p| p.x.value()).collect();
-            let ys: Vec<i32> = polygon.points.iter().map(|p| p.y.value()).collect();
-            let min_x = xs.iter().copied().min().unwrap_or(0);
-            let max_x = xs.iter().copied().max().unwrap_or(0);
-            let min_y = ys.iter().copied().min().unwrap_or(0);
-            let max_y = ys.iter().copied().max().unwrap_or(0);
-            Size {
-                width: HwpUnit::new((max_x - min_x).max(1)),
-                height: HwpUnit::new((max_y - min_y).max(1)),
-            }
-        }
-        HwpShapeType::Curve(curve) => {
-            // 모든 점의 바운딩 박스
-            if curve.points.is_empty() {
-                return Size::default();
-            }
-            let xs: Vec<i32> = curve.points.iter().map(|p| p.x.value()).collect();
-            let ys: Vec<i32> = curve.points.iter().map(|p| p.y.value()).collect();
-            let min_x = xs.iter().copied().min().unwrap_or(0);
-            let max_x = xs.iter().copied().max().unwrap_or(0);
-            let min_y = ys.iter().copied().min().unwrap_or(0);
-            let max_y = ys.iter().copied().max().unwrap_or(0);
-            Size {
-                width: HwpUnit::new((max_x - min_x).max(1)),
-                height: HwpUnit::new((max_y - min_y).max(1)),
-            }
-        }
-        HwpShapeType::Container(shapes) => {
-            // 그룹의 경우 모든 자식 도형의 바운딩 박스
-            if shapes.is_empty() {
-                return Size::default();
-            }
-            let child_sizes: Vec<Size> = shapes
-                .iter()
-                .map(|s| calculate_shape_size(&s.shape_type))
-                .collect();
-            let max_width = child_sizes
-                .iter()
-                .map(|s| s.width.value())
-                .max()
-                .unwrap_or(0);
-            let max_height = child_sizes
-                .iter()
-                .map(|s| s.height.value())
-                .max()
-                .unwrap_or(0);
-            Size {
-                width: HwpUnit::new(max_width),
-                height: HwpUnit::new(max_height),
-            }
-        }
-        HwpShapeType::Unknown => Size::default(),
+/// 그리기 개체 그림자 변환 (그림자 종류 0은 그림자 없음)
+fn convert_shape_shadow(shadow: &crate::ShapeShadow) -> Option<IrShapeShadow> {
+    if shadow.shadow_type == 0 {
+        return None;
     }
+    Some(IrShapeShadow {
+        color: Color::rgb(
+            shadow.color.red(),
+            shadow.color.green(),
+            shadow.color.blue(),
+        ),
+        offset_x: HwpUnit::new(shadow.offset_x),
+        offset_y: HwpUnit::new(shadow.offset_y),
+        alpha: f64::from(shadow.alpha) / 255.0,
+        blur: None,
+        direction: None,
+        distance: None,
+    })
 }
 
 /// HWP 도형 타입 → IR 도형 타입 변환
@@ -2658,15 +2744,12 @@ const fn convert_hwp_arrow_size(hwp_size: HwpArrowSize) -> IrArrowSize {
 
 /// 책갈피 변환
 fn convert_bookmark(control: &Control) -> Result<IrBookmark, ConversionError> {
-    // 책갈피 이름은 ControlData에서 가져옴
-    let name = if let Ok(ctrl_data) = ControlData::from_bytes(control.data()) {
-        ctrl_data
-            .get_string(0x4000) // NAME item
-            .map(|s| s.to_string())
-            .unwrap_or_default()
-    } else {
-        String::new()
-    };
+    // 책갈피 이름은 컨트롤 데이터(HWPTAG_CTRL_DATA)의 매개변수 집합에 있음
+    let name = control
+        .control_data()
+        .and_then(|ctrl_data| ctrl_data.get_string(0x4000)) // NAME item
+        .map(|s| s.to_string())
+        .unwrap_or_default();
 
     Ok(IrBookmark { name })
 }
@@ -2689,7 +2772,7 @@ fn convert_auto_number(control: &Control) -> Result<IrAutoNumber, ConversionErro
 
     // 번호 모양 추출 (bit 4~11)
     let number_shape = ((properties >> 4) & 0xFF) as u8;
-    let number_format = convert_number_format(number_shape);
+    let number_format = convert_hwp_number_format(number_shape);
 
     Ok(IrAutoNumber {
         number_type,
@@ -2742,27 +2825,53 @@ fn convert_page_number_to_auto_number(control: &Control) -> Result<IrAutoNumber,
 
     // 번호 모양 추출 (bit 4~11)
     let number_shape = ((properties >> 4) & 0xFF) as u8;
-    let number_format = convert_number_format(number_shape);
+    let number_format = convert_hwp_number_format(number_shape);
+
+    // 위치 추출 (bit 0~3)
+    let position = match properties & 0x0F {
+        1 => PageNumberPosition::TopLeft,
+        2 => PageNumberPosition::TopCenter,
+        3 => PageNumberPosition::TopRight,
+        4 => PageNumberPosition::BottomLeft,
+        5 => PageNumberPosition::BottomCenter,
+        6 => PageNumberPosition::BottomRight,
+        7 => PageNumberPosition::OutsideTop,
+        8 => PageNumberPosition::OutsideBottom,
+        9 => PageNumberPosition::InsideTop,
+        10 => PageNumberPosition::InsideBottom,
+        _ => PageNumberPosition::None,
+    };
+
+    // 사용자 기호, 앞/뒤 장식 문자 다음에 줄표 문자가 온다
+    let side_character = data
+        .get(10..12)
+        .map(|bytes| u16::from_le_bytes([bytes[0], bytes[1]]))
+        .filter(|&code| code != 0)
+        .and_then(|code| char::from_u32(code as u32))
+        .map(|ch| ch.to_string());
 
     Ok(IrAutoNumber {
         number_type: IrAutoNumberType::Page,
         number_format,
-        auto_number_format: None,
+        auto_number_format: Some(primitive::AutoNumberFormat {
+            position: Some(position),
+            format_type: Some(number_format),
+            side_character,
+            ..Default::default()
+        }),
     })
 }
 
 /// 숨은 설명 변환
-fn convert_hidden_comment(control: &Control) -> Result<IrHiddenComment, ConversionError> {
+fn convert_hidden_comment(
+    control: &Control,
+    ctx: &SectionContext,
+) -> Result<IrHiddenComment, ConversionError> {
     // 숨은 설명은 문단 리스트를 포함 - children에서 가져옴
-    // 숨은 설명 내부에서는 각주/미주가 없으므로 빈 컨텍스트 사용
-    let empty_ctx = SectionContext {
-        footnote_shape: None,
-        endnote_shape: None,
-    };
     let paragraphs = control
         .children()
         .iter()
-        .filter_map(|p| convert_paragraph_with_context(p, &empty_ctx).ok())
+        .filter_map(|p| convert_paragraph_with_context(p, ctx).ok())
         .collect();
 
     Ok(IrHiddenComment { paragraphs })
@@ -2770,27 +2879,12 @@ fn convert_hidden_comment(control: &Control) -> Result<IrHiddenComment, Conversi
 
 /// 색인 표시 변환
 fn convert_index_mark(control: &Control) -> Result<ir::control::IndexMark, ConversionError> {
-    use crate::body::control_data::ControlData;
-
-    // 색인 표시 데이터는 ControlData에서 가져옴
-    // HWP 스펙 표 146: 색인 표시 (idxm)
-    // - 첫 번째 키 (item 0x4000)
-    // - 두 번째 키 (item 0x4001)
-    let (first_key, second_key) = if let Ok(ctrl_data) = ControlData::from_bytes(control.data()) {
-        let first = ctrl_data
-            .get_string(0x4000)
-            .map(|s| s.to_string())
-            .unwrap_or_default();
-
-        let second = ctrl_data
-            .get_string(0x4001)
-            .map(|s| s.to_string())
-            .unwrap_or_default();
-
-        (first, second)
-    } else {
-        (String::new(), String::new())
-    };
+    // HWP 스펙 표 146: 찾아보기 표식 (idxm)
+    // - WORD: 키워드 1 길이, WCHAR array: 키워드 1
+    // - WORD: 키워드 2 길이, WCHAR array: 키워드 2
+    let mut reader = crate::util::ByteReader::new(control.data());
+    let first_key = reader.read_utf16_string().unwrap_or_default();
+    let second_key = reader.read_utf16_string().unwrap_or_default();
 
     Ok(ir::control::IndexMark {
         first_key,
@@ -2798,25 +2892,85 @@ fn convert_index_mark(control: &Control) -> Result<ir::control::IndexMark, Conve
     })
 }
 
+/// 글자 겹침 변환
+fn convert_compose(compose: &HwpCompose) -> ir::paragraph::Compose {
+    use ir::paragraph::{ComposeCircleType, ComposeType};
+
+    let circle_type = match compose.border_type {
+        ComposeBorderType::None => ComposeCircleType::Char,
+        ComposeBorderType::Circle => ComposeCircleType::ShapeCircle,
+        ComposeBorderType::ReversalCircle => ComposeCircleType::ShapeReversalCircle,
+        ComposeBorderType::Rectangle => ComposeCircleType::ShapeRectangle,
+        ComposeBorderType::ReversalRectangle => ComposeCircleType::ShapeReversalRectangle,
+        ComposeBorderType::Triangle => ComposeCircleType::ShapeTriangle,
+        ComposeBorderType::ReversalTriangle => ComposeCircleType::ShapeReversalTriangle,
+        ComposeBorderType::Light => ComposeCircleType::ShapeLight,
+        ComposeBorderType::Rhombus => ComposeCircleType::ShapeRhombus,
+        ComposeBorderType::ReversalRhombus => ComposeCircleType::ShapeReversalRhombus,
+        ComposeBorderType::RoundedRectangle => ComposeCircleType::ShapeRoundedRectangle,
+        ComposeBorderType::EmptyCirculateTriangle => ComposeCircleType::ShapeEmptyCirculateTriangle,
+        ComposeBorderType::ThinCirculateTriangle => ComposeCircleType::ShapeThinCirculateTriangle,
+        ComposeBorderType::ThickCirculateTriangle => ComposeCircleType::ShapeThickCirculateTriangle,
+    };
+
+    ir::paragraph::Compose {
+        compose_text: compose.text.clone(),
+        compose_type: Some(if compose.spread_inner_chars {
+            ComposeType::Spread
+        } else {
+            ComposeType::Overlap
+        }),
+        circle_type,
+        char_size: Some(compose.inner_char_size as i32),
+        // 0xFFFFFFFF는 글자 모양을 지정하지 않은 글자
+        char_shape_ids: compose
+            .character_shape_ids
+            .iter()
+            .map(|&id| (id != u32::MAX).then(|| CharShapeId::new(id)))
+            .collect(),
+    }
+}
+
+/// 덧말 변환
+fn convert_dutmal(dutmal: &HwpDutmal) -> ir::paragraph::Dutmal {
+    use ir::paragraph::{DutmalAlignment, DutmalPosition};
+
+    ir::paragraph::Dutmal {
+        main_text: dutmal.main_text.clone(),
+        sub_text: dutmal.sub_text.clone(),
+        position_type: match dutmal.position {
+            HwpDutmalPosition::Top => DutmalPosition::Top,
+            HwpDutmalPosition::Bottom => DutmalPosition::Bottom,
+        },
+        size_ratio: Some(dutmal.size_ratio),
+        option: Some(dutmal.option),
+        style_id_ref: Some(StyleId::new(dutmal.style_id)),
+        alignment: match dutmal.alignment {
+            HwpDutmalAlignment::Justify => DutmalAlignment::Justify,
+            HwpDutmalAlignment::Left => DutmalAlignment::Left,
+            HwpDutmalAlignment::Right => DutmalAlignment::Right,
+            HwpDutmalAlignment::Center => DutmalAlignment::Center,
+            HwpDutmalAlignment::Distribute => DutmalAlignment::Distribute,
+            HwpDutmalAlignment::DistributeSpace => DutmalAlignment::DistributeSpace,
+        },
+    }
+}
+
 /// 텍스트 박스 변환
 fn convert_text_box(
     text_box: &crate::body::TextBox,
     control: &Control,
+    ctx: &SectionContext,
 ) -> Result<ir::control::TextBox, ConversionError> {
     use crate::body::VerticalAlignment as HwpVertAlign;
     use ir::control::TextBox as IrTextBox;
     use primitive::VerticalAlignment as IrVertAlign;
 
     // 문단 변환 - children에서 가져옴
-    // 텍스트 박스 내부에서는 각주/미주가 없으므로 빈 컨텍스트 사용
-    let empty_ctx = SectionContext {
-        footnote_shape: None,
-        endnote_shape: None,
-    };
     let paragraphs = control
         .children()
         .iter()
-        .filter_map(|p| convert_paragraph_with_context(p, &empty_ctx).ok())
+        .filter_map(|p| convert_paragraph_with_context(p, ctx).ok())
         .collect();
 
     // 세로 정렬 변환
@@ -2858,21 +3012,6 @@ const fn convert_auto_number_type(kind: u8) -> IrAutoNumberType {
     }
 }
 
-/// 번호 모양 변환 (표 134 참조)
-const fn convert_number_format(shape: u8) -> NumberFormat {
-    match shape {
-        0 => NumberFormat::Digit,         // 1, 2, 3
-        1 => NumberFormat::CircledDigit,  // ①, ②, ③
-        2 => NumberFormat::RomanUpper,    // I, II, III
-        3 => NumberFormat::RomanLower,    // i, ii, iii
-        4 => NumberFormat::LatinUpper,    // A, B, C
-        5 => NumberFormat::LatinLower,    // a, b, c
-        6 => NumberFormat::CircledHangul, // ㉮, ㉯, ㉰
-        7 => NumberFormat::Ideograph,     // 一, 二, 三
-        _ => NumberFormat::Digit,
-    }
-}
-
 /// 비디오 변환
 fn convert_video(video: &HwpVideoData, control: &Control) -> Result<IrVideo, ConversionError> {
     use crate::VideoType;
@@ -2935,23 +3074,53 @@ fn convert_video(video: &HwpVideoData, control: &Control) -> Result<IrVideo, Con
 }
 
 /// OLE 객체 변환
-fn convert_ole(ole: &HwpOleObject, control: &Control) -> Result<IrOleObject, ConversionError> {
+fn convert_ole(
+    ole: &HwpOleObject,
+    control: &Control,
+    ctx: &SectionContext,
+) -> Result<IrOleObject, ConversionError> {
     // 공통 속성 파싱 (Control 데이터에서 추출)
     let common = parse_object_common(control.data()).unwrap_or_default();
 
+    // 클래스 ID는 OLE 저장소(BinData)의 루트 항목에 있음
+    let class_id = ctx
+        .document
+        .open_ole_object(ole)
+        .ok()
+        .and_then(|storage| storage.class_id().map(str::to_string));
+
     Ok(IrOleObject {
         common,
         binary_id: BinaryDataId::from_numeric(ole.binary_data_id),
-        class_id: None,         // HWP에서는 class_id 정보가 별도로 없음
+        class_id,
         preview_image_id: None, // 미리보기 이미지는 별도 처리 필요
     })
 }
 
 /// 차트 변환
-fn convert_chart(chart: &HwpChartData, control: &Control) -> Result<IrChart, ConversionError> {
+///
+/// 차트 레코드에는 종류만 있으므로 OLE 저장소(BinData)에서 제목, 축, 항목과 계열 값을 읽음
+fn convert_chart(
+    chart: &HwpChartData,
+    control: &Control,
+    ctx: &SectionContext,
+) -> Result<IrChart, ConversionError> {
+    use crate::body::ChartSource;
+    use ir::control::{ChartAxis as IrChartAxis, ChartSeries as IrChartSeries};
+
     // 공통 속성 파싱 (Control 데이터에서 추출)
     let common = parse_object_common(control.data()).unwrap_or_default();
 
+    let mut chart = chart.clone();
+    let decoded = ctx.document.get_chart_storage(&chart).and_then(|storage| {
+        chart
+            .read_storage_with_limits(storage, ctx.document.limits())
+            .ok()
+    });
+    if !matches!(decoded, Some(ChartSource::Ooxml)) {
+        ctx.undecoded_charts.set(ctx.undecoded_charts.get() + 1);
+    }
+
     // 차트 타입 변환
     let chart_type = convert_chart_type(&chart.chart_type);
 
@@ -2959,9 +3128,40 @@ fn convert_chart(chart: &HwpChartData, control: &Control) -> Result<IrChart, Con
         common,
         chart_id: String::new(), // HWP에서는 차트 ID가 별도로 없음
         chart_type,
+        title: (!chart.title.is_empty()).then_some(chart.title),
+        axes: chart
+            .axes
+            .into_iter()
+            .map(|axis| IrChartAxis {
+                axis_type: convert_chart_axis_kind(axis.kind),
+                title: (!axis.title.is_empty()).then_some(axis.title),
+            })
+            .collect(),
+        categories: chart.categories,
+        series: chart
+            .series
+            .into_iter()
+            .map(|series| IrChartSeries {
+                name: (!series.name.is_empty()).then_some(series.name),
+                values: series.values,
+            })
+            .collect(),
     })
 }
 
+/// HWP 차트 축 종류 → IR 차트 축 종류 변환
+const fn convert_chart_axis_kind(kind: crate::body::ChartAxisKind) -> ir::control::ChartAxisType {
+    use crate::body::ChartAxisKind;
+    use ir::control::ChartAxisType;
+
+    match kind {
+        ChartAxisKind::Category => ChartAxisType::Category,
+        ChartAxisKind::Value => ChartAxisType::Value,
+        ChartAxisKind::Date => ChartAxisType::Date,
+        ChartAxisKind::Series => ChartAxisType::Series,
+    }
+}
+
 /// HWP 차트 타입 → IR 차트 타입 변환
 const fn convert_chart_type(hwp_type: &crate::body::ChartType) -> IrChartType {
     match hwp_type {
@@ -2971,6 +3171,11 @@ const fn convert_chart_type(hwp_type: &crate::body::ChartType) -> IrChartType {
         crate::body::ChartType::Area => IrChartType::Area,
         crate::body::ChartType::Scatter => IrChartType::Scatter,
         crate::body::ChartType::Radar => IrChartType::Radar,
+        crate::body::ChartType::Column => IrChartType::Column,
+        crate::body::ChartType::Doughnut => IrChartType::Doughnut,
+        crate::body::ChartType::Bubble => IrChartType::Bubble,
+        crate::body::ChartType::Stock => IrChartType::Stock,
+        crate::body::ChartType::Surface => IrChartType::Surface,
         _ => IrChartType::Bar, // Unknown, Combined 등은 Bar로 기본값
     }
 }
@@ -3301,17 +3506,10 @@ const fn convert_field_type(hwp_type: HwpFieldType) -> IrFieldType {
 fn convert_field_to_field_start(
     field: &HwpField,
     field_id: u32,
-    control_data: &[u8],
+    control_data: Option<&ControlData>,
 ) -> IrFieldStart {
     // ControlData에서 ParameterSet 추출
-    let parameters = if !control_data.is_empty() {
-        match ControlData::from_bytes(control_data) {
-            Ok(ctrl_data) => convert_hwp_parameters_to_ir(&ctrl_data),
-            Err(_) => None,
-        }
-    } else {
-        None
-    };
+    let parameters = control_data.and_then(convert_hwp_parameters_to_ir);
 
     IrFieldStart {
         id: field_id,
@@ -3419,7 +3617,7 @@ fn convert_container(
             }
             Some(ControlContent::TextBox(text_box)) => {
                 // 그룹 내 텍스트 박스를 사각형 + 텍스트로 변환
-                if let Ok(mut ir_text_box) = convert_text_box(text_box, child_control) {
+                if let Ok(mut ir_text_box) = convert_text_box(text_box, child_control, ctx) {
                     // 자식의 ObjectCommon 적용
                     if let Some(common) = child_common {
                         ir_text_box.common = common.clone();
@@ -3473,8 +3671,7 @@ fn convert_container(
 
 /// HWP Control 데이터에서 개체 공통 속성(ObjectCommon) 파싱
 ///
-/// HWP 스펙 표 69에 따른 구조:
-/// - UINT32: ctrl ID (4 bytes)
+/// HWP 스펙 표 69에 따른 구조 (ctrl ID 이후):
 /// - UINT32: 속성 (4 bytes)
 /// - HWPUNIT: 세로 오프셋 (4 bytes)
 /// - HWPUNIT: 가로 오프셋 (4 bytes)
@@ -3486,35 +3683,58 @@ fn convert_container(
 /// - INT32: 쪽나눔 방지 (4 bytes)
 /// - WORD: 개체 설명문 길이 (2 bytes)
 /// - WCHAR array: 개체 설명문
-const fn parse_object_common(data: &[u8]) -> Option<ObjectCommon> {
-    // 최소 크기 확인 (46 bytes 기본)
-    if data.len() < 46 {
+fn parse_object_common(data: &[u8]) -> Option<ObjectCommon> {
+    // 최소 크기 확인 (42 bytes 기본)
+    if data.len() < 42 {
         return None;
     }
 
-    // ctrl ID skip (4 bytes)
-    let properties = u32::from_le_bytes([data[4], data[5], data[6], data[7]]);
-    let offset_y = i32::from_le_bytes([data[8], data[9], data[10], data[11]]);
-    let offset_x = i32::from_le_bytes([data[12], data[13], data[14], data[15]]);
-    let width = i32::from_le_bytes([data[16], data[17], data[18], data[19]]);
-    let height = i32::from_le_bytes([data[20], data[21], data[22], data[23]]);
-    let z_order = i32::from_le_bytes([data[24], data[25], data[26], data[27]]);
-    // 바깥 여백 4개 (HWPUNIT16 x 4 = 8 bytes: 28-35)
-    // 순서: left, right, top, bottom
-    let margin_left = i16::from_le_bytes([data[28], data[29]]);
-    let margin_right = i16::from_le_bytes([data[30], data[31]]);
-    let margin_top = i16::from_le_bytes([data[32], data[33]]);
-    let margin_bottom = i16::from_le_bytes([data[34], data[35]]);
-    // 평균을 사용하거나 가장 큰 값을 사용 (IR은 단일 margin만 지원)
+    let mut reader = crate::util::ByteReader::new(data);
+    let properties = reader.read_u32().ok()?;
+    let offset_y = reader.read_i32().ok()?;
+    let offset_x = reader.read_i32().ok()?;
+    let width = reader.read_i32().ok()?;
+    let height = reader.read_i32().ok()?;
+    let z_order = reader.read_i32().ok()?;
+    // 바깥 여백 4개 (HWPUNIT16 x 4), 순서: left, right, top, bottom
+    let margin_left = reader.read_i16().ok()?;
+    let margin_right = reader.read_i16().ok()?;
+    let margin_top = reader.read_i16().ok()?;
+    let margin_bottom = reader.read_i16().ok()?;
+    // 평균을 사용 (TextWrap은 단일 margin만 지원)
     let avg_margin =
         (margin_left as i32 + margin_right as i32 + margin_top as i32 + margin_bottom as i32) / 4;
-    let instance_id = u32::from_le_bytes([data[36], data[37], data[38], data[39]]);
-    // 쪽나눔 방지 skip (4 bytes: 40-43)
-    // 개체 설명문 길이/내용 skip
+    let instance_id = reader.read_u32().ok()?;
+    let _prevent_page_break = reader.read_i32().ok()?;
+    let description = reader.read_utf16_string().unwrap_or_default();
 
     // 속성 비트 파싱 (표 70 참조)
     let text_wrap = parse_text_wrap_from_properties(properties, avg_margin);
 
+    // bit 15~17: 오브젝트 폭의 기준
+    let width_relative_to = match (properties >> 15) & 0x07 {
+        0 => primitive::WidthRelativeTo::Paper,
+        1 => primitive::WidthRelativeTo::Page,
+        2 => primitive::WidthRelativeTo::Column,
+        3 => primitive::WidthRelativeTo::Paragraph,
+        _ => primitive::WidthRelativeTo::Absolute,
+    };
+
+    // bit 18~19: 오브젝트 높이의 기준
+    let height_relative_to = match (properties >> 18) & 0x03 {
+        0 => primitive::HeightRelativeTo::Paper,
+        1 => primitive::HeightRelativeTo::Page,
+        _ => primitive::HeightRelativeTo::Absolute,
+    };
+
+    // bit 26~28: 개체가 속하는 번호 범주
+    let numbering_type = match (properties >> 26) & 0x07 {
+        1 => Some(primitive::ObjectNumberingType::Picture),
+        2 => Some(primitive::ObjectNumberingType::Table),
+        3 => Some(primitive::ObjectNumberingType::Equation),
+        _ => None,
+    };
+
     Some(ObjectCommon {
         id: if instance_id > 0 {
             Some(instance_id)
@@ -3531,13 +3751,17 @@ const fn parse_object_common(data: &[u8]) -> Option<ObjectCommon> {
         },
         z_order,
         text_wrap,
-        caption: None, // Caption은 별도 처리 (Phase 2)
-        numbering_type: None,
-        shape_comment: None,
+        caption: None, // Caption은 컨트롤에 연결된 목록에서 변환
+        numbering_type,
+        shape_comment: if description.is_empty() {
+            None
+        } else {
+            Some(description)
+        },
         meta_tag: None,
         dirty: false,
-        width_relative_to: primitive::WidthRelativeTo::Absolute,
-        height_relative_to: primitive::HeightRelativeTo::Absolute,
+        width_relative_to,
+        height_relative_to,
         margin: ir::control::ObjectMargin {
             left: HwpUnit::new(margin_left as i32),
             right: HwpUnit::new(margin_right as i32),
@@ -3561,6 +3785,15 @@ const fn parse_text_wrap_from_properties(properties: u32, margin: i32) -> IrText
         _ => IrVerticalRelativeTo::Paper,
     };
 
+    // bit 5~7: 세로 위치의 기준에 대한 상대적인 배열 방식
+    let vertical_offset_type = match (properties >> 5) & 0x07 {
+        1 => primitive::VerticalOffsetType::Center,
+        2 => primitive::VerticalOffsetType::Bottom,
+        3 => primitive::VerticalOffsetType::Inside,
+        4 => primitive::VerticalOffsetType::Outside,
+        _ => primitive::VerticalOffsetType::Top,
+    };
+
     // bit 8~9: 가로 위치의 기준 (HorzRelTo)
     let horz_rel_to = (properties >> 8) & 0x03;
     let horizontal_rel = match horz_rel_to {
@@ -3571,6 +3804,18 @@ const fn parse_text_wrap_from_properties(properties: u32, margin: i32) -> IrText
         _ => IrHorizontalRelativeTo::Paper,
     };
 
+    // bit 10~12: 가로 위치의 기준에 대한 상대적인 배열 방식
+    let horizontal_offset_type = match (properties >> 10) & 0x07 {
+        1 => primitive::HorizontalOffsetType::Center,
+        2 => primitive::HorizontalOffsetType::Right,
+        3 => primitive::HorizontalOffsetType::Inside,
+        4 => primitive::HorizontalOffsetType::Outside,
+        _ => primitive::HorizontalOffsetType::Left,
+    };
+
+    // bit 13: 세로 위치를 본문 영역으로 제한할지 여부
+    let flow_with_text = (properties >> 13) & 0x01 != 0;
+
     // bit 14: 다른 오브젝트와 겹치는 것을 허용할지 여부
     let allow_overlap = (properties >> 14) & 0x01 != 0;
 
@@ -3609,10 +3854,10 @@ const fn parse_text_wrap_from_properties(properties: u32, margin: i32) -> IrText
         margin: HwpUnit::new(margin),
         vertical_rel,
         horizontal_rel,
-        vertical_offset_type: primitive::VerticalOffsetType::Top,
-        horizontal_offset_type: primitive::HorizontalOffsetType::Left,
+        vertical_offset_type,
+        horizontal_offset_type,
         treat_as_char,
-        flow_with_text: false, // HWP 5.0 스펙에서 명시적으로 정의되지 않음
+        flow_with_text,
         allow_overlap,
     }
 }
@@ -1,26 +1,45 @@
-//! Distribution document decryption.
+//! Distribution document encryption and decryption.
 //!
 //! Distribution documents are special HWP documents designed for read-only distribution.
-//! They use a different encryption scheme with optional copy/print restrictions.
+//! Each encrypted stream starts with an HWPTAG_DISTRIBUTE_DOC_DATA record holding
+//! 256 bytes of scrambled key data, followed by the stream content encrypted with
+//! AES-128 ECB. The key data also carries the copy/print restriction flags.
 
+use aes::Aes128;
+use aes::cipher::generic_array::GenericArray;
+use aes::cipher::{BlockDecrypt, BlockEncrypt, KeyInit};
+
+use super::password::sha1_hash;
 use crate::error::{Error, Result};
+use crate::primitive::{RecordHeader, RecordTagId};
+
+/// AES block size in bytes.
+const BLOCK_SIZE: usize = 16;
+
+/// Size of the record header in front of the distribution data.
+const RECORD_HEADER_SIZE: usize = 4;
 
 /// Distribution document decryptor.
 ///
 /// Handles decryption of distribution documents which use ViewText instead of BodyText.
 #[derive(Debug, Clone)]
 pub struct DistributionDecryptor {
-    /// The decryption key (first 16 bytes of SHA-1 hash).
+    /// The decryption key (first 16 bytes of the hash code).
     key: [u8; 16],
+    /// Copy/print restriction flags.
+    options: u16,
 }
 
 impl DistributionDecryptor {
     /// Size of the distribution document data record.
     pub const DISTRIBUTION_DATA_SIZE: usize = 256;
 
+    /// Size of the hash code (SHA-1 as WCHAR[40]).
+    const HASH_SIZE: usize = 80;
+
     /// Creates a new DistributionDecryptor from distribution data.
     ///
-    /// The distribution data is 256 bytes that contains the encrypted
+    /// The distribution data is 256 bytes that contains the scrambled
     /// hash code and options.
     ///
     /// # Arguments
@@ -38,7 +57,7 @@ impl DistributionDecryptor {
             });
         }
 
-        // Extract seed (first 4 bytes)
+        // Seed is the first 4 bytes, before unscrambling
         let seed = u32::from_le_bytes([
             distribution_data[0],
             distribution_data[1],
@@ -46,20 +65,28 @@ impl DistributionDecryptor {
             distribution_data[3],
         ]);
 
-        // Generate random array using MSVC-compatible random
+        // XOR the distribution data with the random array
         let random_array = generate_random_array(seed);
+        let decoded: Vec<u8> = distribution_data[..Self::DISTRIBUTION_DATA_SIZE]
+            .iter()
+            .zip(random_array)
+            .map(|(byte, random)| byte ^ random)
+            .collect();
 
-        // XOR the distribution data with the random array to get hash code
-        let mut decoded = [0u8; 82];
-        for i in 0..82 {
-            decoded[i] = distribution_data[i] ^ random_array[i];
-        }
-
-        // First 80 bytes are the SHA-1 hash (as WCHAR[40]), take first 16 bytes as key
+        // The hash code starts at the seed-dependent offset; its first 16 bytes
+        // are the key, and the option flags follow it
+        let offset = hash_offset(seed);
         let mut key = [0u8; 16];
-        key.copy_from_slice(&decoded[0..16]);
+        key.copy_from_slice(&decoded[offset..offset + 16]);
+        let options_offset = offset + Self::HASH_SIZE;
+        let options = u16::from_le_bytes([decoded[options_offset], decoded[options_offset + 1]]);
+
+        Ok(Self { key, options })
+    }
 
-        Ok(Self { key })
+    /// Returns the copy/print restriction flags.
+    pub const fn options(&self) -> u16 {
+        self.options
     }
 
     /// Decrypts a distribution document stream.
@@ -71,52 +98,195 @@ impl DistributionDecryptor {
     /// # Returns
     ///
     /// The decrypted data.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidDistributionData`] if the data is not a whole
+    /// number of AES blocks.
     pub fn decrypt(&self, data: &[u8]) -> Result<Vec<u8>> {
-        if data.is_empty() {
-            return Ok(Vec::new());
+        if !data.len().is_multiple_of(BLOCK_SIZE) {
+            return Err(Error::InvalidDistributionData);
         }
 
-        // Distribution documents use a simple XOR cipher with the key
-        let mut result = Vec::with_capacity(data.len());
-        for (i, &byte) in data.iter().enumerate() {
-            result.push(byte ^ self.key[i % 16]);
+        let cipher = Aes128::new(GenericArray::from_slice(&self.key));
+        let mut result = data.to_vec();
+        for block in result.chunks_exact_mut(BLOCK_SIZE) {
+            cipher.decrypt_block(GenericArray::from_mut_slice(block));
         }
 
         Ok(result)
     }
+
+    /// Encrypts data so that [`decrypt`](Self::decrypt) restores it.
+    ///
+    /// The data is padded with zeros to a whole number of AES blocks, and the
+    /// padding is not recorded, so decrypting returns it after the data.
+    /// Readers are unaffected: a compressed stream ends at its deflate end
+    /// block, and in an uncompressed record stream the padding reads as empty
+    /// records with tag 0, which the parsers skip.
+    pub fn encrypt(&self, data: &[u8]) -> Vec<u8> {
+        let cipher = Aes128::new(GenericArray::from_slice(&self.key));
+        let mut result = data.to_vec();
+        result.resize(data.len().next_multiple_of(BLOCK_SIZE), 0);
+        for block in result.chunks_exact_mut(BLOCK_SIZE) {
+            cipher.encrypt_block(GenericArray::from_mut_slice(block));
+        }
+
+        result
+    }
+}
+
+/// Offset of the hash code within the unscrambled distribution data.
+const fn hash_offset(seed: u32) -> usize {
+    (seed & 0x0F) as usize + size_of::<u32>()
 }
 
-/// Generates a random array using MSVC-compatible random number generator.
+/// MSVC-compatible `srand()`/`rand()`.
+struct MsvcRandom {
+    state: u32,
+}
+
+impl MsvcRandom {
+    const fn new(seed: u32) -> Self {
+        Self { state: seed }
+    }
+
+    fn next(&mut self) -> u32 {
+        self.state = self.state.wrapping_mul(214013).wrapping_add(2531011);
+        (self.state >> 16) & 0x7FFF
+    }
+}
+
+/// Generates the random array used to scramble distribution data.
 ///
-/// This replicates the behavior of MSVC's srand()/rand() functions
-/// which are used in the HWP distribution document encryption.
+/// Each odd `rand()` call gives a byte value and the following even call how
+/// many times to repeat it, `(rand() & 0x0F) + 1`, until 256 bytes are filled.
 fn generate_random_array(seed: u32) -> [u8; 256] {
     let mut result = [0u8; 256];
-    let mut state = seed;
+    let mut random = MsvcRandom::new(seed);
 
-    for byte in result.iter_mut() {
-        // MSVC rand() implementation: state = state * 214013 + 2531011
-        state = state.wrapping_mul(214013).wrapping_add(2531011);
-        // Extract bits 16-22 (7 bits) for the random value
-        *byte = ((state >> 16) & 0xFF) as u8;
+    let mut index = 0;
+    while index < result.len() {
+        let value = (random.next() & 0xFF) as u8;
+        let count = (random.next() & 0x0F) as usize + 1;
+        let end = (index + count).min(result.len());
+        result[index..end].fill(value);
+        index = end;
     }
 
     result
 }
 
+/// Builds the 256-byte distribution document data for a password.
+///
+/// `seed` picks the scrambling pattern and `filler` supplies the bytes around
+/// the hash code; both should be unpredictable.
+fn build_distribution_data(
+    password: &str,
+    options: u16,
+    seed: u32,
+    filler: &[u8; 256],
+) -> [u8; 256] {
+    let random_array = generate_random_array(seed);
+
+    // Hash code: SHA-1 of the password as an uppercase hex WCHAR[40]
+    let hash: String = sha1_hash(password.as_bytes())
+        .iter()
+        .map(|byte| format!("{:02X}", byte))
+        .collect();
+    let hash: Vec<u8> = hash.encode_utf16().flat_map(u16::to_le_bytes).collect();
+
+    let mut decoded = *filler;
+    // The stored seed must read back unchanged after scrambling
+    for (index, byte) in seed.to_le_bytes().into_iter().enumerate() {
+        decoded[index] = byte ^ random_array[index];
+    }
+    let offset = hash_offset(seed);
+    decoded[offset..offset + DistributionDecryptor::HASH_SIZE].copy_from_slice(&hash);
+    let options_offset = offset + DistributionDecryptor::HASH_SIZE;
+    decoded[options_offset..options_offset + 2].copy_from_slice(&options.to_le_bytes());
+
+    let mut data = [0u8; 256];
+    for (index, byte) in data.iter_mut().enumerate() {
+        *byte = decoded[index] ^ random_array[index];
+    }
+    data
+}
+
+/// Builds distribution document data with a fresh seed and filler.
+///
+/// # Errors
+///
+/// Returns [`Error::Io`] if the operating system's random source fails.
+pub(crate) fn new_distribution_data(password: &str, options: u16) -> Result<[u8; 256]> {
+    let mut filler = [0u8; 256];
+    let mut seed = [0u8; 4];
+    getrandom::fill(&mut filler).map_err(|err| Error::Io(std::io::Error::other(err)))?;
+    getrandom::fill(&mut seed).map_err(|err| Error::Io(std::io::Error::other(err)))?;
+    Ok(build_distribution_data(
+        password,
+        options,
+        u32::from_le_bytes(seed),
+        &filler,
+    ))
+}
+
 /// Convenience function to decrypt a distribution document stream.
 ///
+/// The stream must start with the HWPTAG_DISTRIBUTE_DOC_DATA record; the rest
+/// is decrypted with the key it carries.
+///
 /// # Arguments
 ///
 /// * `data` - The encrypted stream data
-/// * `distribution_data` - The 256-byte distribution document data
 ///
 /// # Returns
 ///
 /// The decrypted data.
-pub fn decrypt_distribution_stream(data: &[u8], distribution_data: &[u8]) -> Result<Vec<u8>> {
+pub fn decrypt_distribution_stream(data: &[u8]) -> Result<Vec<u8>> {
+    let (distribution_data, encrypted) = split_distribution_record(data)?;
     let decryptor = DistributionDecryptor::from_distribution_data(distribution_data)?;
-    decryptor.decrypt(data)
+    decryptor.decrypt(encrypted)
+}
+
+/// Encrypts a stream for a distribution document.
+///
+/// The output is the HWPTAG_DISTRIBUTE_DOC_DATA record followed by the
+/// encrypted data, as [`decrypt_distribution_stream`] expects.
+pub(crate) fn encrypt_distribution_stream(data: &[u8], distribution_data: &[u8; 256]) -> Vec<u8> {
+    let decryptor = DistributionDecryptor::from_distribution_data(distribution_data)
+        .expect("distribution data has the full size");
+
+    let header = u32::from(RecordTagId::DistributeDocumentData.as_u16())
+        | ((DistributionDecryptor::DISTRIBUTION_DATA_SIZE as u32) << 20);
+    let mut result = header.to_le_bytes().to_vec();
+    result.extend_from_slice(distribution_data);
+    result.extend(decryptor.encrypt(data));
+    result
+}
+
+/// Splits a stream into the distribution data and the encrypted remainder.
+fn split_distribution_record(data: &[u8]) -> Result<(&[u8], &[u8])> {
+    let Some(header) = data.get(..RECORD_HEADER_SIZE) else {
+        return Err(Error::InvalidDistributionData);
+    };
+    let header = RecordHeader::new(u32::from_le_bytes([
+        header[0], header[1], header[2], header[3],
+    ]));
+    if header.tag_id() != Some(RecordTagId::DistributeDocumentData)
+        || header.data_size() as usize != DistributionDecryptor::DISTRIBUTION_DATA_SIZE
+    {
+        return Err(Error::InvalidDistributionData);
+    }
+
+    let end = RECORD_HEADER_SIZE + DistributionDecryptor::DISTRIBUTION_DATA_SIZE;
+    let Some(distribution_data) = data.get(RECORD_HEADER_SIZE..end) else {
+        return Err(Error::UnexpectedEndOfData {
+            expected: end,
+            actual: data.len(),
+        });
+    };
+    Ok((distribution_data, &data[end..]))
 }
 
 #[cfg(test)]
@@ -133,6 +303,14 @@ mod tests {
         assert_ne!(arr1, arr3);
     }
 
+    #[test]
+    fn test_random_array_runs() {
+        // srand(0): rand() yields 38, 7719, 21238, 2437, ...
+        let arr = generate_random_array(0);
+        // 38 repeated (7719 & 0x0F) + 1 = 8 times, then 21238 & 0xFF
+        assert_eq!(arr[..9], [38, 38, 38, 38, 38, 38, 38, 38, 0xF6]);
+    }
+
     #[test]
     fn test_decrypt_empty() {
         let distribution_data = [0u8; 256];
@@ -140,4 +318,57 @@ mod tests {
         let result = decryptor.decrypt(&[]).unwrap();
         assert!(result.is_empty());
     }
+
+    #[test]
+    fn test_distribution_data_round_trip() {
+        let filler = [0x5Au8; 256];
+        let data = build_distribution_data("배포", 0x03, 0xDEAD_BEEF, &filler);
+        assert_eq!(
+            u32::from_le_bytes([data[0], data[1], data[2], data[3]]),
+            0xDEAD_BEEF
+        );
+
+        let decryptor = DistributionDecryptor::from_distribution_data(&data).unwrap();
+        assert_eq!(decryptor.options(), 0x03);
+
+        let plain = b"distribution document stream";
+        let stream = encrypt_distribution_stream(plain, &data);
+        assert_eq!(stream.len(), 4 + 256 + 32);
+        let decrypted = decrypt_distribution_stream(&stream).unwrap();
+        assert_eq!(&decrypted[..plain.len()], plain);
+        assert!(decrypted[plain.len()..].iter().all(|&byte| byte == 0));
+    }
+
+    #[test]
+    fn test_encrypt_pads_with_zeros() {
+        let decryptor = DistributionDecryptor::from_distribution_data(&[0x11; 256]).unwrap();
+        for (len, encrypted_len) in [(0, 0), (1, 16), (16, 16), (17, 32)] {
+            let plain = vec![0xA5; len];
+            let encrypted = decryptor.encrypt(&plain);
+            assert_eq!(encrypted.len(), encrypted_len);
+
+            let decrypted = decryptor.decrypt(&encrypted).unwrap();
+            assert_eq!(&decrypted[..len], plain.as_slice());
+            assert!(decrypted[len..].iter().all(|&byte| byte == 0));
+        }
+    }
+
+    #[test]
+    fn test_new_distribution_data() {
+        let first = new_distribution_data("배포", 0x01).unwrap();
+        let second = new_distribution_data("배포", 0x01).unwrap();
+        assert_ne!(first, second);
+        for data in [first, second] {
+            let decryptor = DistributionDecryptor::from_distribution_data(&data).unwrap();
+            assert_eq!(decryptor.options(), 0x01);
+        }
+    }
+
+    #[test]
+    fn test_missing_distribution_record() {
+        assert!(matches!(
+            decrypt_distribution_stream(&[0u8; 300]),
+            Err(Error::InvalidDistributionData)
+        ));
+    }
 }
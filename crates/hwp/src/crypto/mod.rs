@@ -1,11 +1,14 @@
 //! Cryptographic utilities for HWP documents.
 //!
-//! This module handles decryption for:
-//! - Password-encrypted documents
-//! - Distribution documents
+//! This module handles:
+//! - Password-encrypted documents (decryption)
+//! - Distribution documents (decryption and encryption)
 
 mod distribution;
 mod password;
 
-pub(crate) use distribution::decrypt_distribution_stream;
+pub(crate) use distribution::{
+    DistributionDecryptor, decrypt_distribution_stream, encrypt_distribution_stream,
+    new_distribution_data,
+};
 pub(crate) use password::decrypt_password_stream;
@@ -76,7 +76,6 @@ impl PasswordDecryptor {
             _ => Err(Error::UnsupportedEncryptionVersion(self.version.as_raw())),
         }
     }
-
 }
 
 /// Derives decryption key for modern encryption (HWP 7.0+).
@@ -115,7 +114,7 @@ fn decrypt_modern(data: &[u8], key: &[u8]) -> Result<Vec<u8>> {
 ///
 /// This is a minimal implementation for key derivation.
 /// For production use, consider using a dedicated crypto library.
-fn sha1_hash(data: &[u8]) -> [u8; 20] {
+pub(crate) fn sha1_hash(data: &[u8]) -> [u8; 20] {
     // SHA-1 initial hash values
     let mut h0: u32 = 0x67452301;
     let mut h1: u32 = 0xEFCDAB89;
@@ -0,0 +1,273 @@
+//! Parse options and diagnostics.
+//!
+//! By default the first record or stream that fails to parse is returned as an
+//! error. With [`Recovery::Lenient`] such a record is skipped together with the
+//! records nested under it, and parsing resumes at the next record on the same
+//! or a higher level. Each skipped part is reported as a [`ParseDiagnostic`], so
+//! callers can tell users exactly what was lost.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use hwp::{HwpDocument, ParseOptions, ReadOptions};
+//!
+//! let options = ReadOptions::new().with_parse_options(ParseOptions::lenient());
+//! let doc = HwpDocument::from_bytes_with_options(&bytes, &options)?;
+//! for diagnostic in doc.diagnostics() {
+//!     eprintln!("{}", diagnostic);
+//! }
+//! ```
+
+use std::fmt;
+
+use primitive::{LimitKind, Limits};
+
+use crate::error::{Error, Result};
+use crate::record::{RecordHeader, RecordIter};
+
+/// How to handle malformed records and streams.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Recovery {
+    /// Fail on the first record or stream that cannot be parsed.
+    #[default]
+    Strict,
+    /// Skip what cannot be parsed and report it as a diagnostic.
+    Lenient,
+}
+
+/// Options for parsing DocInfo, section and optional streams.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ParseOptions {
+    /// Recovery mode.
+    pub recovery: Recovery,
+}
+
+impl ParseOptions {
+    /// Creates lenient parse options.
+    pub const fn lenient() -> Self {
+        Self {
+            recovery: Recovery::Lenient,
+        }
+    }
+
+    /// Creates strict parse options.
+    pub const fn strict() -> Self {
+        Self {
+            recovery: Recovery::Strict,
+        }
+    }
+
+    /// Returns `error` in strict mode, or records it in lenient mode.
+    ///
+    /// [`Error::LimitExceeded`] is returned in either mode.
+    pub(crate) fn recover(
+        &self,
+        diagnostics: &mut Vec<ParseDiagnostic>,
+        diagnostic: ParseDiagnostic,
+    ) -> Result<()> {
+        match self.recovery {
+            _ if matches!(diagnostic.error, Error::LimitExceeded { .. }) => Err(diagnostic.error),
+            Recovery::Strict => Err(diagnostic.error),
+            Recovery::Lenient => {
+                diagnostics.push(diagnostic);
+                Ok(())
+            }
+        }
+    }
+}
+
+/// A part of a document that was skipped during lenient parsing.
+#[derive(Debug)]
+pub struct ParseDiagnostic {
+    /// Stream path within the compound file (e.g. `/BodyText/Section0`).
+    pub stream: String,
+    /// Byte offset of the skipped record within the decoded stream.
+    ///
+    /// Zero when the whole stream was skipped.
+    pub offset: usize,
+    /// Raw tag ID of the skipped record, if a record header could be read.
+    pub tag: Option<u16>,
+    /// Why the record or stream was skipped.
+    pub error: Error,
+}
+
+impl ParseDiagnostic {
+    /// Creates a diagnostic for a stream that was skipped as a whole.
+    pub(crate) fn stream(stream: impl Into<String>, error: Error) -> Self {
+        Self {
+            stream: stream.into(),
+            offset: 0,
+            tag: None,
+            error,
+        }
+    }
+}
+
+impl fmt::Display for ParseDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at 0x{:X}", self.stream, self.offset)?;
+        if let Some(tag) = self.tag {
+            write!(f, " (tag 0x{:03X})", tag)?;
+        }
+        write!(f, ": {}", self.error)
+    }
+}
+
+/// Calls `parse` for each record of a decoded stream.
+///
+/// When `parse` fails in lenient mode, the records nested under the failed
+/// record are skipped. A truncated record ends the stream, and a record larger
+/// than [`Limits::max_record_size`] fails it.
+pub(crate) fn parse_records(
+    data: &[u8],
+    stream: &str,
+    options: &ParseOptions,
+    limits: &Limits,
+    diagnostics: &mut Vec<ParseDiagnostic>,
+    mut parse: impl FnMut(RecordHeader, &[u8]) -> Result<()>,
+) -> Result<()> {
+    let mut skip_level = None;
+    let mut end = 0;
+
+    for record in RecordIter::new(data) {
+        let record = match record {
+            Ok(record) => record,
+            Err(error) => {
+                let diagnostic = ParseDiagnostic {
+                    stream: stream.to_string(),
+                    offset: end,
+                    tag: None,
+                    error,
+                };
+                return options.recover(diagnostics, diagnostic);
+            }
+        };
+        end = record.end();
+        limits.check(LimitKind::RecordSize, record.size() as u64)?;
+
+        if let Some(level) = skip_level {
+            if record.level() > level {
+                continue;
+            }
+            skip_level = None;
+        }
+
+        if let Err(error) = parse(record.header(), record.data()) {
+            let diagnostic = ParseDiagnostic {
+                stream: stream.to_string(),
+                offset: record.offset(),
+                tag: Some(record.tag_id_raw()),
+                error,
+            };
+            options.recover(diagnostics, diagnostic)?;
+            skip_level = Some(record.level());
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(tag: u16, level: u16, data: &[u8]) -> Vec<u8> {
+        let raw = u32::from(tag) | (u32::from(level) << 10) | ((data.len() as u32) << 20);
+        let mut bytes = raw.to_le_bytes().to_vec();
+        bytes.extend(data);
+        bytes
+    }
+
+    /// Fails on records with an empty payload and collects the others.
+    fn collect_with_limits(
+        data: &[u8],
+        options: &ParseOptions,
+        limits: &Limits,
+    ) -> Result<(Vec<u8>, Vec<ParseDiagnostic>)> {
+        let mut seen = Vec::new();
+        let mut diagnostics = Vec::new();
+        parse_records(
+            data,
+            "Test",
+            options,
+            limits,
+            &mut diagnostics,
+            |_, data| {
+                let first = *data.first().ok_or(Error::UnexpectedEndOfData {
+                    expected: 1,
+                    actual: 0,
+                })?;
+                seen.push(first);
+                Ok(())
+            },
+        )?;
+        Ok((seen, diagnostics))
+    }
+
+    fn collect(data: &[u8], options: &ParseOptions) -> Result<(Vec<u8>, Vec<ParseDiagnostic>)> {
+        collect_with_limits(data, options, &Limits::default())
+    }
+
+    fn stream() -> Vec<u8> {
+        [
+            record(0x042, 0, &[1]),
+            record(0x042, 0, &[]),
+            record(0x043, 1, &[2]),
+            record(0x047, 2, &[3]),
+            record(0x042, 0, &[4]),
+        ]
+        .concat()
+    }
+
+    #[test]
+    fn test_lenient_skips_nested_records() {
+        let (seen, diagnostics) = collect(&stream(), &ParseOptions::lenient()).unwrap();
+
+        assert_eq!(seen, [1, 4]);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].offset, 5);
+        assert_eq!(diagnostics[0].tag, Some(0x042));
+        assert_eq!(
+            diagnostics[0].to_string(),
+            "Test at 0x5 (tag 0x042): Unexpected end of data: expected 1 bytes, got 0"
+        );
+    }
+
+    #[test]
+    fn test_strict_fails() {
+        assert!(matches!(
+            collect(&stream(), &ParseOptions::strict()),
+            Err(Error::UnexpectedEndOfData { .. })
+        ));
+    }
+
+    #[test]
+    fn test_truncated_stream() {
+        let mut data = stream();
+        data.extend(record(0x042, 0, &[5, 5, 5]));
+        data.truncate(data.len() - 1);
+        let truncated_at = stream().len();
+
+        let (seen, diagnostics) = collect(&data, &ParseOptions::lenient()).unwrap();
+        assert_eq!(seen, [1, 4]);
+        assert_eq!(diagnostics.len(), 2);
+        assert_eq!(diagnostics[1].offset, truncated_at);
+        assert_eq!(diagnostics[1].tag, None);
+
+        assert!(collect(&data, &ParseOptions::strict()).is_err());
+    }
+
+    #[test]
+    fn test_record_size_limit() {
+        let limits = Limits::new().with_max_record_size(2);
+        let data = [record(0x042, 0, &[1, 1]), record(0x042, 0, &[2, 2, 2])].concat();
+
+        assert!(matches!(
+            collect_with_limits(&data, &ParseOptions::lenient(), &limits),
+            Err(Error::LimitExceeded {
+                kind: LimitKind::RecordSize,
+                limit: 2
+            })
+        ));
+    }
+}
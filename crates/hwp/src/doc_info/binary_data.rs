@@ -49,6 +49,15 @@ impl CompressionMode {
             _ => Self::Default,
         }
     }
+
+    /// Returns whether the stream is compressed given the storage default.
+    pub const fn is_compressed(self, storage_compressed: bool) -> bool {
+        match self {
+            Self::Default => storage_compressed,
+            Self::Compress => true,
+            Self::NoCompress => false,
+        }
+    }
 }
 
 /// Access state of binary data.
@@ -210,9 +219,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_compression_mode_is_compressed() {
+        assert!(CompressionMode::Default.is_compressed(true));
+        assert!(!CompressionMode::Default.is_compressed(false));
+        assert!(CompressionMode::Compress.is_compressed(false));
+        assert!(!CompressionMode::NoCompress.is_compressed(true));
+    }
+
     #[test]
     fn test_binary_data_state() {
-        assert_eq!(BinaryDataState::from_raw(0x0000), BinaryDataState::NotAccessed);
+        assert_eq!(
+            BinaryDataState::from_raw(0x0000),
+            BinaryDataState::NotAccessed
+        );
         assert_eq!(
             BinaryDataState::from_raw(0x0100),
             BinaryDataState::AccessSuccess
@@ -22,6 +22,24 @@ impl BorderLineThickness {
         Self(value)
     }
 
+    /// Creates the thickness closest to the given millimeters.
+    pub fn from_mm(mm: f64) -> Self {
+        let hundredths = mm * 100.0;
+        let index = (0..Self::VALUES.len())
+            .min_by(|&a, &b| {
+                let da = (Self::VALUES[a] as f64 - hundredths).abs();
+                let db = (Self::VALUES[b] as f64 - hundredths).abs();
+                da.total_cmp(&db)
+            })
+            .unwrap_or(0);
+        Self(index as u8)
+    }
+
+    /// Returns the raw value.
+    pub const fn as_raw(&self) -> u8 {
+        self.0
+    }
+
     /// Returns the thickness in 0.01mm units.
     pub const fn value_hundredths_mm(&self) -> u16 {
         if (self.0 as usize) < Self::VALUES.len() {
@@ -95,18 +113,31 @@ pub enum PatternType {
 }
 
 impl PatternType {
+    /// Creates from raw value (-1 means no pattern).
     pub const fn from_raw(value: i32) -> Self {
         match value {
-            0 => Self::None,
-            1 => Self::Horizontal,
-            2 => Self::Vertical,
-            3 => Self::BackSlash,
-            4 => Self::Slash,
-            5 => Self::Cross,
-            6 => Self::CrossDiagonal,
+            0 => Self::Horizontal,
+            1 => Self::Vertical,
+            2 => Self::BackSlash,
+            3 => Self::Slash,
+            4 => Self::Cross,
+            5 => Self::CrossDiagonal,
             _ => Self::None,
         }
     }
+
+    /// Returns the raw value.
+    pub const fn as_raw(self) -> i32 {
+        match self {
+            Self::None => -1,
+            Self::Horizontal => 0,
+            Self::Vertical => 1,
+            Self::BackSlash => 2,
+            Self::Slash => 3,
+            Self::Cross => 4,
+            Self::CrossDiagonal => 5,
+        }
+    }
 }
 
 /// Gradient type.
@@ -129,6 +160,16 @@ impl GradientType {
             _ => Self::Linear,
         }
     }
+
+    /// Returns the raw value.
+    pub const fn as_raw(self) -> i16 {
+        match self {
+            Self::Linear => 1,
+            Self::Radial => 2,
+            Self::Conical => 3,
+            Self::Rectangular => 4,
+        }
+    }
 }
 
 /// Image fill type.
@@ -175,6 +216,11 @@ impl ImageFillType {
             _ => Self::TileAll,
         }
     }
+
+    /// Returns the raw value.
+    pub const fn as_raw(self) -> u8 {
+        self as u8
+    }
 }
 
 /// Pattern fill info.
@@ -251,22 +297,15 @@ impl BorderFill {
     pub fn from_reader(reader: &mut ByteReader) -> Result<Self> {
         let properties = reader.read_u16()?;
 
-        // Read 4 border styles
+        // Read 4 borders (left, right, top, bottom), each stored as
+        // line style, thickness and color in sequence
         let mut border_styles = [BorderLineStyle::default(); 4];
-        for style in &mut border_styles {
-            *style = BorderLineStyle::from_raw(reader.read_u8()?);
-        }
-
-        // Read 4 border thicknesses
         let mut border_thicknesses = [BorderLineThickness::default(); 4];
-        for thickness in &mut border_thicknesses {
-            *thickness = BorderLineThickness::from_raw(reader.read_u8()?);
-        }
-
-        // Read 4 border colors
         let mut border_colors = [ColorReference::default(); 4];
-        for color in &mut border_colors {
-            *color = reader.read_color()?;
+        for index in 0..4 {
+            border_styles[index] = BorderLineStyle::from_raw(reader.read_u8()?);
+            border_thicknesses[index] = BorderLineThickness::from_raw(reader.read_u8()?);
+            border_colors[index] = reader.read_color()?;
         }
 
         // Read diagonal
@@ -295,14 +334,14 @@ impl BorderFill {
                 let center_x = reader.read_i16()?;
                 let center_y = reader.read_i16()?;
                 let blur = reader.read_i16()?;
-                let color_count = reader.read_i16()? as usize;
+                let color_count = reader.read_i16()?.max(0) as usize;
 
                 // Skip position data if more than 2 colors
                 if color_count > 2 {
                     reader.skip(4 * color_count)?;
                 }
 
-                let mut colors = Vec::with_capacity(color_count);
+                let mut colors = Vec::with_capacity(reader.capacity_for(color_count, 4));
                 for _ in 0..color_count {
                     colors.push(reader.read_color()?);
                 }
@@ -400,6 +439,21 @@ mod tests {
         assert!((t.value_mm() - 1.0).abs() < f64::EPSILON);
     }
 
+    #[test]
+    fn test_border_line_thickness_from_mm() {
+        assert_eq!(BorderLineThickness::from_mm(0.12).as_raw(), 1);
+        assert_eq!(BorderLineThickness::from_mm(0.4).as_raw(), 6);
+        assert_eq!(BorderLineThickness::from_mm(9.0).as_raw(), 15);
+    }
+
+    #[test]
+    fn test_pattern_type() {
+        assert_eq!(PatternType::from_raw(-1), PatternType::None);
+        assert_eq!(PatternType::from_raw(0), PatternType::Horizontal);
+        assert_eq!(PatternType::CrossDiagonal.as_raw(), 5);
+        assert_eq!(PatternType::None.as_raw(), -1);
+    }
+
     #[test]
     fn test_fill_type() {
         assert_eq!(FillType::from_raw(0), FillType::None);
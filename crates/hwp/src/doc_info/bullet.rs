@@ -34,8 +34,12 @@ impl Bullet {
             reader.skip(4)?;
         }
 
-        let check_bullet_char_code = reader.read_u16()?;
-        let check_bullet_char = char::from_u32(check_bullet_char_code as u32).unwrap_or('\u{2611}');
+        // Older documents end the record before the check bullet character
+        let check_bullet_char = if reader.remaining() >= 2 {
+            char::from_u32(reader.read_u16()? as u32).unwrap_or('\u{2611}')
+        } else {
+            '\u{2611}'
+        };
 
         Ok(Self {
             head_info,
@@ -71,3 +75,35 @@ impl Bullet {
         self.check_bullet_char
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bullet_data(check_bullet_char: &[u8]) -> Vec<u8> {
+        let mut data = vec![0; 12]; // ParagraphHeadInfo
+        data.extend_from_slice(&0x25CF_u16.to_le_bytes());
+        data.extend_from_slice(&0_i32.to_le_bytes());
+        data.extend_from_slice(check_bullet_char);
+        data
+    }
+
+    #[test]
+    fn test_bullet() {
+        let data = bullet_data(&0x2713_u16.to_le_bytes());
+        let bullet = Bullet::from_reader(&mut ByteReader::new(&data)).unwrap();
+        assert_eq!(bullet.bullet_char(), '\u{25CF}');
+        assert!(!bullet.use_image());
+        assert_eq!(bullet.check_bullet_char(), '\u{2713}');
+    }
+
+    #[test]
+    fn test_bullet_without_check_bullet_char() {
+        for tail in [&[][..], &[0x00]] {
+            let data = bullet_data(tail);
+            let bullet = Bullet::from_reader(&mut ByteReader::new(&data)).unwrap();
+            assert_eq!(bullet.bullet_char(), '\u{25CF}');
+            assert_eq!(bullet.check_bullet_char(), '\u{2611}');
+        }
+    }
+}
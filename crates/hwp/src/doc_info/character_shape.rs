@@ -513,24 +513,16 @@ mod tests {
         }
 
         // width_ratios: 7 x u8 (100% = 기본값)
-        for _ in 0..7 {
-            data.push(100);
-        }
+        data.extend(std::iter::repeat_n(100, 7));
 
         // spacings: 7 x i8 (0 = 기본값)
-        for _ in 0..7 {
-            data.push(0);
-        }
+        data.extend(std::iter::repeat_n(0, 7));
 
         // relative_sizes: 7 x u8 (100% = 기본값)
-        for _ in 0..7 {
-            data.push(100);
-        }
+        data.extend(std::iter::repeat_n(100, 7));
 
         // positions: 7 x i8 (0 = 기본값)
-        for _ in 0..7 {
-            data.push(0);
-        }
+        data.extend(std::iter::repeat_n(0, 7));
 
         // base_size: i32 (1000 = 10pt)
         data.extend_from_slice(&1000i32.to_le_bytes());
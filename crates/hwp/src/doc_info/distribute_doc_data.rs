@@ -3,12 +3,26 @@
 //! Distribution documents have special encryption applied to all streams.
 //! This 256-byte record appears in all streams of a distribution document.
 
+use bitflags::bitflags;
+
+use crate::crypto::DistributionDecryptor;
 use crate::error::Result;
 use crate::util::ByteReader;
 
 /// Distribution document data size in bytes.
 pub const DISTRIBUTE_DOC_DATA_SIZE: usize = 256;
 
+bitflags! {
+    /// Restrictions stored in distribution document data.
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    pub struct DistributionRestrictions: u16 {
+        /// Bit 0: Copying is disabled
+        const COPY = 0x01;
+        /// Bit 1: Printing is disabled
+        const PRINT = 0x02;
+    }
+}
+
 /// Distribution document data.
 ///
 /// This 256-byte record is present in distribution documents and contains
@@ -42,6 +56,13 @@ impl DistributeDocData {
         &self.data
     }
 
+    /// Decodes the copy/print restrictions.
+    pub fn restrictions(&self) -> DistributionRestrictions {
+        DistributionDecryptor::from_distribution_data(&self.data)
+            .map(|decryptor| DistributionRestrictions::from_bits_retain(decryptor.options()))
+            .unwrap_or_default()
+    }
+
     /// Parses distribution document data from reader.
     ///
     /// Format (per HWP spec - HWPTAG_DISTRIBUTE_DOC_DATA):
@@ -65,6 +86,12 @@ impl DistributeDocData {
     }
 }
 
+impl Default for DistributeDocData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -211,7 +211,8 @@ impl ParameterItem {
                 } else {
                     0
                 };
-                let mut array = Vec::with_capacity(count);
+                // A parameter set is at least 4 bytes
+                let mut array = Vec::with_capacity(reader.capacity_for(count, 4));
                 for _ in 0..count {
                     array.push(ParameterSet::from_reader(reader)?);
                 }
@@ -262,7 +263,8 @@ impl ParameterSet {
         let set_id = reader.read_u16()?;
         let item_count = reader.read_u16()? as usize;
 
-        let mut items = Vec::with_capacity(item_count);
+        // A parameter item is at least 4 bytes
+        let mut items = Vec::with_capacity(reader.capacity_for(item_count, 4));
         for _ in 0..item_count {
             if reader.is_empty() {
                 break;
@@ -28,7 +28,6 @@ mod tab_definition;
 mod track_change;
 
 // 공개 API - HWP 스펙에 정의된 타입들
-pub use primitive::{BorderLineStyle, UnderlineShape};
 pub use binary_data::BinaryData;
 pub use border_fill::{
     BorderFill, BorderLineThickness, DiagonalType, FillInfo, FillType, GradientFill, GradientType,
@@ -40,8 +39,8 @@ pub use character_shape::{
     CharacterShape, EmphasisType, LanguageType, OutlineType, ShadowType, StrikethroughShape,
     UnderlinePosition,
 };
-pub use compatible_document::CompatibleDocument;
-pub use distribute_doc_data::DistributeDocData;
+pub use compatible_document::{CompatibleDocument, TargetProgram};
+pub use distribute_doc_data::{DistributeDocData, DistributionRestrictions};
 pub use document_data::DocumentData;
 pub use document_properties::DocumentProperties;
 pub use face_name::{AlternateFontType, FaceName};
@@ -54,28 +53,16 @@ pub use paragraph_shape::{
     VerticalAlignment,
 };
 pub use primitive::StyleType;
+pub use primitive::{BorderLineStyle, UnderlineShape};
 pub use style::Style;
 pub use tab_definition::TabDefinition;
 pub use track_change::{TrackChangeAuthor, TrackChangeContent, TrackChangeInfo};
 
+use crate::diagnostic::{ParseDiagnostic, ParseOptions, parse_records};
 use crate::error::Result;
 use crate::primitive::{RecordHeader, RecordTagId};
 use crate::util::ByteReader;
-
-/// 레코드 헤더를 파싱한다.
-///
-/// 크기 필드가 0xFFF인 경우 확장 크기를 처리한다.
-fn parse_record_header(reader: &mut ByteReader) -> Result<RecordHeader> {
-    let raw = reader.read_u32()?;
-    let header = RecordHeader::new(raw);
-
-    if header.has_extended_size() {
-        let extended_size = reader.read_u32()?;
-        Ok(RecordHeader::with_extended_size(raw, extended_size))
-    } else {
-        Ok(header)
-    }
-}
+use primitive::Limits;
 
 /// Parsed DocInfo containing all document-level information.
 #[derive(Debug, Clone, Default)]
@@ -118,11 +105,25 @@ pub struct DocInfo {
     pub forbidden_char: Option<ForbiddenChar>,
     /// Distribution document data (HWPTAG_DISTRIBUTE_DOC_DATA 0x01C).
     pub distribute_doc_data: Option<DistributeDocData>,
+    /// The decoded DocInfo stream, kept when reading with record retention.
+    ///
+    /// DocInfo entries are not editable through [`HwpDocument`](crate::HwpDocument),
+    /// so the whole stream is written back as is.
+    pub raw_records: Option<Vec<u8>>,
 }
 
 impl DocInfo {
     /// Parses DocInfo from bytes.
     ///
+    /// Fails on the first record that cannot be parsed. Use
+    /// [`HwpDocument::open_with_options`] with lenient parsing to skip bad
+    /// records and see what was skipped.
+    ///
+    /// Note that DocInfo entries are referenced by position, so an entry skipped
+    /// in lenient mode shifts the IDs of the entries after it.
+    ///
+    /// [`HwpDocument::open_with_options`]: crate::HwpDocument::open_with_options
+    ///
     /// # Arguments
     ///
     /// * `data` - The decompressed DocInfo stream data
@@ -131,117 +132,115 @@ impl DocInfo {
     ///
     /// The parsed DocInfo.
     pub fn from_bytes(data: &[u8]) -> Result<Self> {
-        let mut reader = ByteReader::new(data);
+        Self::parse(
+            data,
+            "DocInfo",
+            &ParseOptions::default(),
+            &Limits::default(),
+            &mut Vec::new(),
+        )
+    }
+
+    /// Parses DocInfo, recovering from bad records as `options` allow.
+    pub(crate) fn parse(
+        data: &[u8],
+        stream: &str,
+        options: &ParseOptions,
+        limits: &Limits,
+        diagnostics: &mut Vec<ParseDiagnostic>,
+    ) -> Result<Self> {
         let mut doc_info = DocInfo::default();
+        parse_records(
+            data,
+            stream,
+            options,
+            limits,
+            diagnostics,
+            |header, record_data| doc_info.parse_record(header, record_data),
+        )?;
+        Ok(doc_info)
+    }
 
-        while !reader.is_empty() {
-            // Try to parse record header - if not enough data, stop parsing
-            let header = match parse_record_header(&mut reader) {
-                Ok(h) => h,
-                Err(_) => break, // Stop if we can't read a valid record header
-            };
+    /// Parses a single record into this DocInfo.
+    fn parse_record(&mut self, header: RecordHeader, record_data: &[u8]) -> Result<()> {
+        let mut record_reader = ByteReader::new(record_data);
 
-            // Check if we have enough data for the record - if not, stop gracefully
-            let data_size = header.data_size() as usize;
-            if reader.remaining() < data_size {
-                // Not enough data remaining - stop parsing gracefully
-                break;
+        match header.tag_id() {
+            Some(RecordTagId::DocumentProperties) => {
+                self.document_properties =
+                    Some(DocumentProperties::from_reader(&mut record_reader)?);
             }
-
-            let record_data = reader.read_bytes(data_size)?;
-            let mut record_reader = ByteReader::new(record_data);
-
-            match header.tag_id() {
-                Some(RecordTagId::DocumentProperties) => {
-                    doc_info.document_properties =
-                        Some(DocumentProperties::from_reader(&mut record_reader)?);
-                }
-                Some(RecordTagId::IdMappings) => {
-                    doc_info.id_mappings = Some(IdMappings::from_reader(&mut record_reader)?);
-                }
-                Some(RecordTagId::BinaryData) => {
-                    doc_info
-                        .binary_data
-                        .push(BinaryData::from_reader(&mut record_reader)?);
-                }
-                Some(RecordTagId::FaceName) => {
-                    doc_info
-                        .face_names
-                        .push(FaceName::from_reader(&mut record_reader)?);
-                }
-                Some(RecordTagId::BorderFill) => {
-                    doc_info
-                        .border_fills
-                        .push(BorderFill::from_reader(&mut record_reader)?);
-                }
-                Some(RecordTagId::CharacterShape) => {
-                    doc_info
-                        .character_shapes
-                        .push(CharacterShape::from_reader(&mut record_reader)?);
-                }
-                Some(RecordTagId::TabDefinition) => {
-                    doc_info
-                        .tab_definitions
-                        .push(TabDefinition::from_reader(&mut record_reader)?);
-                }
-                Some(RecordTagId::Numbering) => {
-                    doc_info
-                        .numberings
-                        .push(Numbering::from_reader(&mut record_reader)?);
-                }
-                Some(RecordTagId::Bullet) => {
-                    doc_info
-                        .bullets
-                        .push(Bullet::from_reader(&mut record_reader)?);
-                }
-                Some(RecordTagId::ParagraphShape) => {
-                    doc_info
-                        .paragraph_shapes
-                        .push(ParagraphShape::from_reader(&mut record_reader)?);
-                }
-                Some(RecordTagId::Style) => {
-                    doc_info
-                        .styles
-                        .push(Style::from_reader(&mut record_reader)?);
-                }
-                Some(RecordTagId::CompatibleDocument) => {
-                    doc_info.compatible_document =
-                        Some(CompatibleDocument::from_reader(&mut record_reader)?);
-                }
-                Some(RecordTagId::LayoutCompatibility) => {
-                    doc_info.layout_compatibility =
-                        Some(LayoutCompatibility::from_reader(&mut record_reader)?);
-                }
-                Some(RecordTagId::TrackChange) => {
-                    doc_info.track_change_info =
-                        Some(TrackChangeInfo::from_reader(&mut record_reader)?);
-                }
-                Some(RecordTagId::TrackChangeAuthor) => {
-                    doc_info
-                        .track_change_authors
-                        .push(TrackChangeAuthor::from_reader(&mut record_reader)?);
-                }
-                Some(RecordTagId::TrackChangeContent) => {
-                    doc_info
-                        .track_change_contents
-                        .push(TrackChangeContent::from_reader(&mut record_reader)?);
-                }
-                Some(RecordTagId::DocumentData) => {
-                    doc_info.document_data = Some(DocumentData::from_reader(&mut record_reader)?);
-                }
-                Some(RecordTagId::ForbiddenCharacter) => {
-                    doc_info.forbidden_char = Some(ForbiddenChar::from_reader(&mut record_reader)?);
-                }
-                Some(RecordTagId::DistributeDocumentData) => {
-                    doc_info.distribute_doc_data =
-                        Some(DistributeDocData::from_reader(&mut record_reader)?);
-                }
-                _ => {
-                    // Skip unknown records
-                }
+            Some(RecordTagId::IdMappings) => {
+                self.id_mappings = Some(IdMappings::from_reader(&mut record_reader)?);
+            }
+            Some(RecordTagId::BinaryData) => {
+                self.binary_data
+                    .push(BinaryData::from_reader(&mut record_reader)?);
+            }
+            Some(RecordTagId::FaceName) => {
+                self.face_names
+                    .push(FaceName::from_reader(&mut record_reader)?);
+            }
+            Some(RecordTagId::BorderFill) => {
+                self.border_fills
+                    .push(BorderFill::from_reader(&mut record_reader)?);
+            }
+            Some(RecordTagId::CharacterShape) => {
+                self.character_shapes
+                    .push(CharacterShape::from_reader(&mut record_reader)?);
+            }
+            Some(RecordTagId::TabDefinition) => {
+                self.tab_definitions
+                    .push(TabDefinition::from_reader(&mut record_reader)?);
+            }
+            Some(RecordTagId::Numbering) => {
+                self.numberings
+                    .push(Numbering::from_reader(&mut record_reader)?);
+            }
+            Some(RecordTagId::Bullet) => {
+                self.bullets.push(Bullet::from_reader(&mut record_reader)?);
+            }
+            Some(RecordTagId::ParagraphShape) => {
+                self.paragraph_shapes
+                    .push(ParagraphShape::from_reader(&mut record_reader)?);
+            }
+            Some(RecordTagId::Style) => {
+                self.styles.push(Style::from_reader(&mut record_reader)?);
+            }
+            Some(RecordTagId::CompatibleDocument) => {
+                self.compatible_document =
+                    Some(CompatibleDocument::from_reader(&mut record_reader)?);
+            }
+            Some(RecordTagId::LayoutCompatibility) => {
+                self.layout_compatibility =
+                    Some(LayoutCompatibility::from_reader(&mut record_reader)?);
+            }
+            Some(RecordTagId::TrackChange) => {
+                self.track_change_info = Some(TrackChangeInfo::from_reader(&mut record_reader)?);
+            }
+            Some(RecordTagId::TrackChangeAuthor) => {
+                self.track_change_authors
+                    .push(TrackChangeAuthor::from_reader(&mut record_reader)?);
+            }
+            Some(RecordTagId::TrackChangeContent) => {
+                self.track_change_contents
+                    .push(TrackChangeContent::from_reader(&mut record_reader)?);
+            }
+            Some(RecordTagId::DocumentData) => {
+                self.document_data = Some(DocumentData::from_reader(&mut record_reader)?);
+            }
+            Some(RecordTagId::ForbiddenCharacter) => {
+                self.forbidden_char = Some(ForbiddenChar::from_reader(&mut record_reader)?);
+            }
+            Some(RecordTagId::DistributeDocumentData) => {
+                self.distribute_doc_data =
+                    Some(DistributeDocData::from_reader(&mut record_reader)?);
+            }
+            _ => {
+                // Skip unknown records
             }
         }
 
-        Ok(doc_info)
+        Ok(())
     }
 }
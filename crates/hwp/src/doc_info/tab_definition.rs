@@ -13,6 +13,9 @@ pub struct TabInfo {
 }
 
 impl TabInfo {
+    /// Size of one tab stop in bytes.
+    pub const SIZE: usize = 8;
+
     pub fn from_reader(reader: &mut ByteReader) -> Result<Self> {
         let position = reader.read_hwp_unit()?;
         let tab_type = TabType::from_raw(reader.read_u8()?);
@@ -36,9 +39,10 @@ pub struct TabDefinition {
 impl TabDefinition {
     pub fn from_reader(reader: &mut ByteReader) -> Result<Self> {
         let properties = reader.read_u32()?;
-        let count = reader.read_i16()? as usize;
+        // Stored as a 4-byte count in practice (the spec table lists INT16)
+        let count = reader.read_i32()?.max(0) as usize;
 
-        let mut tabs = Vec::with_capacity(count);
+        let mut tabs = Vec::with_capacity(reader.capacity_for(count, TabInfo::SIZE));
         for _ in 0..count {
             tabs.push(TabInfo::from_reader(reader)?);
         }
@@ -61,3 +65,63 @@ impl TabDefinition {
         &self.tabs
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::diagnostic::ParseOptions;
+    use crate::doc_info::DocInfo;
+    use crate::error::Error;
+    use crate::record::RecordTagId;
+    use crate::writer::byte_writer::ByteWriter;
+    use primitive::Limits;
+
+    /// A TabDef record claiming `count` tab stops but holding only one.
+    fn tab_definition_data(count: i32) -> Vec<u8> {
+        let mut data = 1u32.to_le_bytes().to_vec();
+        data.extend(count.to_le_bytes());
+        data.extend(1000u32.to_le_bytes());
+        data.extend([1, 0, 0, 0]);
+        data
+    }
+
+    #[test]
+    fn test_tab_definition() {
+        let data = tab_definition_data(1);
+        let tab_definition = TabDefinition::from_reader(&mut ByteReader::new(&data)).unwrap();
+        assert!(tab_definition.has_left_auto_tab());
+        assert_eq!(tab_definition.tabs().len(), 1);
+        assert_eq!(tab_definition.tabs()[0].position, HwpUnit::new(1000));
+
+        let data = tab_definition_data(-1);
+        let tab_definition = TabDefinition::from_reader(&mut ByteReader::new(&data)).unwrap();
+        assert!(tab_definition.tabs().is_empty());
+    }
+
+    #[test]
+    fn test_huge_tab_count() {
+        let data = tab_definition_data(i32::MAX);
+        assert!(matches!(
+            TabDefinition::from_reader(&mut ByteReader::new(&data)),
+            Err(Error::UnexpectedEndOfData { .. })
+        ));
+
+        // In lenient mode a corrupt TabDef record is skipped instead of aborting
+        // DocInfo parsing
+        let mut stream = ByteWriter::new();
+        stream.write_record(RecordTagId::TabDefinition, 0, &data);
+        let stream = stream.into_bytes();
+        assert!(DocInfo::from_bytes(&stream).is_err());
+        let mut diagnostics = Vec::new();
+        let doc_info = DocInfo::parse(
+            &stream,
+            "DocInfo",
+            &ParseOptions::lenient(),
+            &Limits::default(),
+            &mut diagnostics,
+        )
+        .unwrap();
+        assert!(doc_info.tab_definitions.is_empty());
+        assert_eq!(diagnostics.len(), 1);
+    }
+}
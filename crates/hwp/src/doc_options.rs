@@ -73,6 +73,17 @@ impl LinkDoc {
 
         Ok(link_doc)
     }
+
+    /// Encodes the linked document paths as stored in the _LinkDoc stream.
+    ///
+    /// Each path is written as UTF-16LE followed by a null character.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.paths
+            .iter()
+            .flat_map(|path| path.encode_utf16().chain([0]))
+            .flat_map(u16::to_le_bytes)
+            .collect()
+    }
 }
 
 /// DRM license information.
@@ -201,6 +212,16 @@ mod tests {
         assert_eq!(link.paths()[0], "test.hwp");
     }
 
+    #[test]
+    fn test_link_doc_round_trip() {
+        let mut link = LinkDoc::new();
+        link.add_path("C:\\문서\\원본.hwp".to_string());
+        link.add_path("test.hwp".to_string());
+        let data = link.to_bytes();
+        assert_eq!(&data[data.len() - 2..], &[0, 0]);
+        assert_eq!(LinkDoc::from_bytes(&data).unwrap().paths(), link.paths());
+    }
+
     #[test]
     fn test_drm_license_from_bytes() {
         let data = [0x01, 0x00, 0x00, 0x00, 0xAB, 0xCD];
@@ -3,21 +3,67 @@
 //! This module provides the main `HwpDocument` type for reading HWP files.
 
 use std::collections::HashMap;
-use std::io::{Read, Seek};
+use std::io::{Cursor, Read, Seek};
 
-use cfb::CompoundFile;
-
-use crate::body::{Picture, Section};
-use crate::crypto::{decrypt_distribution_stream, decrypt_password_stream};
+use crate::body::{ChartData, OleObject, Picture, Section};
+use crate::diagnostic::{ParseDiagnostic, ParseOptions};
 use crate::doc_info::DocInfo;
-use crate::doc_options::{DocOptions, DrmLicense, LinkDoc};
+use crate::doc_options::DocOptions;
 use crate::error::{Error, Result};
 use crate::header::FileHeader;
+use crate::ole::OleStorage;
 use crate::preview::{PreviewImage, PreviewText};
-use crate::script::{ScriptHeader, ScriptSource, ScriptVersion, Scripts};
+use crate::reader::HwpReader;
+use crate::script::Scripts;
 use crate::summary::SummaryInfo;
-use crate::util::decompress_stream;
-use primitive::Version;
+use crate::writer::HwpWriter;
+use primitive::{Limits, Version};
+
+/// Options for reading an HWP document.
+#[derive(Debug, Clone, Default)]
+pub struct ReadOptions {
+    /// Password for encrypted documents.
+    pub password: Option<String>,
+    /// Whether to keep the original record bytes of DocInfo, paragraphs and
+    /// controls so that [`HwpDocument::to_bytes`] can write them back verbatim.
+    pub retain_records: bool,
+    /// How to handle malformed records and streams.
+    pub parse: ParseOptions,
+    /// Resource limits for untrusted documents.
+    pub limits: Limits,
+}
+
+impl ReadOptions {
+    /// Creates options with no password, no record retention, strict parsing
+    /// and the default [`Limits`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the password.
+    pub fn with_password(mut self, password: impl Into<String>) -> Self {
+        self.password = Some(password.into());
+        self
+    }
+
+    /// Sets whether to retain the original records.
+    pub const fn with_retain_records(mut self, retain_records: bool) -> Self {
+        self.retain_records = retain_records;
+        self
+    }
+
+    /// Sets the parse options.
+    pub const fn with_parse_options(mut self, parse: ParseOptions) -> Self {
+        self.parse = parse;
+        self
+    }
+
+    /// Sets the resource limits.
+    pub const fn with_limits(mut self, limits: Limits) -> Self {
+        self.limits = limits;
+        self
+    }
+}
 
 /// An HWP 5.0 document.
 ///
@@ -58,6 +104,10 @@ pub struct HwpDocument {
     scripts: Option<Scripts>,
     /// Document options (from DocOptions storage).
     doc_options: Option<DocOptions>,
+    /// Parts skipped during lenient parsing.
+    diagnostics: Vec<ParseDiagnostic>,
+    /// Resource limits the document was read with.
+    limits: Limits,
 }
 
 impl HwpDocument {
@@ -74,7 +124,7 @@ impl HwpDocument {
     /// - The document is encrypted (use `from_bytes_with_password` instead)
     /// - Any parsing error occurs
     pub fn from_bytes(data: &[u8]) -> Result<Self> {
-        Self::from_bytes_internal(data, None)
+        Self::from_bytes_with_options(data, &ReadOptions::default())
     }
 
     /// Parses an encrypted HWP document with a password.
@@ -84,67 +134,79 @@ impl HwpDocument {
     /// * `data` - The raw bytes of the HWP file
     /// * `password` - The document password
     pub fn from_bytes_with_password(data: &[u8], password: &str) -> Result<Self> {
-        Self::from_bytes_internal(data, Some(password))
+        Self::from_bytes_with_options(data, &ReadOptions::new().with_password(password))
     }
 
-    fn from_bytes_internal(data: &[u8], password: Option<&str>) -> Result<Self> {
-        // Open as compound file
-        let cursor = std::io::Cursor::new(data);
-        let mut cfb = CompoundFile::open(cursor)?;
-
-        // Read and parse file header
-        let header = Self::read_file_header(&mut cfb)?;
-
-        // Check for encryption
-        if header.properties().is_encrypted() && password.is_none() {
-            return Err(Error::EncryptedDocument);
-        }
+    /// Parses an HWP document from bytes with the given options.
+    ///
+    /// Set [`ReadOptions::retain_records`] to make the document writable with
+    /// [`HwpDocument::to_bytes`].
+    pub fn from_bytes_with_options(data: &[u8], options: &ReadOptions) -> Result<Self> {
+        HwpReader::new(Cursor::new(data), options)?.into_document()
+    }
 
-        // Check for distribution document
-        let distribution_data = if header.is_distribution() {
-            Some(Self::read_distribution_data(&mut cfb)?)
-        } else {
-            None
-        };
+    /// Opens an HWP document for lazy reading.
+    ///
+    /// Only the file header and DocInfo are parsed up front. Sections and
+    /// binary data are read on demand through the returned [`HwpReader`],
+    /// which keeps `reader` open until it is dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file is not a valid HWP 5.0 document, the
+    /// document is encrypted, or DocInfo cannot be parsed.
+    pub fn open<R: Read + Seek>(reader: R) -> Result<HwpReader<R>> {
+        HwpReader::new(reader, &ReadOptions::default())
+    }
 
-        // Read and parse DocInfo
-        let doc_info =
-            Self::read_doc_info(&mut cfb, &header, password, distribution_data.as_deref())?;
+    /// Opens an encrypted HWP document with a password for lazy reading.
+    pub fn open_with_password<R: Read + Seek>(reader: R, password: &str) -> Result<HwpReader<R>> {
+        HwpReader::new(reader, &ReadOptions::new().with_password(password))
+    }
 
-        // Read sections
-        let section_count = doc_info
-            .document_properties
-            .as_ref()
-            .map(|p| p.section_count() as usize)
-            .unwrap_or(1);
+    /// Opens an HWP document for lazy reading with the given options.
+    pub fn open_with_options<R: Read + Seek>(
+        reader: R,
+        options: &ReadOptions,
+    ) -> Result<HwpReader<R>> {
+        HwpReader::new(reader, options)
+    }
 
+    /// Reads every remaining stream of an open document.
+    ///
+    /// In lenient mode a section or optional stream that cannot be read is
+    /// skipped and reported in [`HwpDocument::diagnostics`].
+    pub(crate) fn load<R: Read + Seek>(mut reader: HwpReader<R>) -> Result<Self> {
+        let section_count = reader.section_count();
         let mut sections = Vec::with_capacity(section_count);
         for i in 0..section_count {
-            match Self::read_section(&mut cfb, &header, i, password, distribution_data.as_deref()) {
+            match reader.read_section(i) {
                 Ok(section) => sections.push(section),
-                // Some documents may have fewer sections than declared
-                Err(_) => break,
+                // A missing section stream is reported like any unreadable one so
+                // that the sections after it are still read in lenient mode
+                Err(error) => {
+                    let stream = reader.section_stream_name(i);
+                    reader.recover(ParseDiagnostic::stream(stream, error))?;
+                }
             }
         }
 
         // Read binary data (images, etc.)
-        let binary_data = Self::read_binary_data(&mut cfb, &doc_info, &header)?;
-
-        // Read preview text (optional)
-        let preview_text = Self::read_preview_text(&mut cfb).ok();
-
-        // Read preview image (optional)
-        let preview_image = Self::read_preview_image(&mut cfb).ok();
-
-        // Read summary information (optional)
-        let summary_info = Self::read_summary_info(&mut cfb).ok();
-
-        // Read scripts (optional)
-        let scripts = Self::read_scripts(&mut cfb).ok();
-
-        // Read document options (optional)
-        let doc_options = Self::read_doc_options(&mut cfb).ok();
-
+        let binary_data = reader.binary_data().collect::<Result<_>>()?;
+
+        // Optional streams
+        let result = reader.try_read_preview_text();
+        let preview_text = recover_optional(&mut reader, "/PrvText", result)?.flatten();
+        let preview_image = reader.read_preview_image();
+        let result = reader.try_read_summary_info();
+        let summary_info =
+            recover_optional(&mut reader, "/\x05HwpSummaryInformation", result)?.flatten();
+        let result = reader.try_read_scripts();
+        let scripts = recover_optional(&mut reader, "/Scripts", result)?;
+        let result = reader.try_read_doc_options();
+        let doc_options = recover_optional(&mut reader, "/DocOptions", result)?;
+
+        let (header, doc_info, diagnostics, limits) = reader.into_parts();
         Ok(Self {
             header,
             doc_info,
@@ -155,256 +217,103 @@ impl HwpDocument {
             summary_info,
             scripts,
             doc_options,
+            diagnostics,
+            limits,
         })
     }
 
-    fn read_file_header<R: Read + Seek>(cfb: &mut CompoundFile<R>) -> Result<FileHeader> {
-        let mut stream = cfb.open_stream("/FileHeader")?;
-        let mut data = vec![0u8; FileHeader::SIZE];
-        stream.read_exact(&mut data)?;
-        FileHeader::from_bytes(&data)
-    }
-
-    fn read_distribution_data<R: Read + Seek>(cfb: &mut CompoundFile<R>) -> Result<Vec<u8>> {
-        // Distribution data is stored in DocInfo stream as HWPTAG_DISTRIBUTE_DOC_DATA
-        // For now, we'll read it from DocInfo and extract it
-        // This is a simplified implementation
-        let mut stream = cfb.open_stream("/DocInfo")?;
-        let mut data = Vec::new();
-        stream.read_to_end(&mut data)?;
-        Ok(data)
-    }
-
-    fn read_doc_info<R: Read + Seek>(
-        cfb: &mut CompoundFile<R>,
-        header: &FileHeader,
-        password: Option<&str>,
-        distribution_data: Option<&[u8]>,
-    ) -> Result<DocInfo> {
-        let mut stream = cfb.open_stream("/DocInfo")?;
-        let mut data = Vec::new();
-        stream.read_to_end(&mut data)?;
-
-        // Decompress if needed
-        let decompressed = if header.properties().is_compressed() {
-            decompress_stream(&data)?
-        } else {
-            data
-        };
-
-        // Decrypt if encrypted
-        let decrypted = if header.is_encrypted() {
-            if let Some(pwd) = password {
-                let version = header.encryption_version();
-                decrypt_password_stream(&decompressed, version, pwd)?
-            } else {
-                return Err(Error::EncryptedDocument);
-            }
-        } else if let Some(dist_data) = distribution_data {
-            // Distribution document decryption
-            decrypt_distribution_stream(&decompressed, dist_data)?
-        } else {
-            decompressed
-        };
-
-        DocInfo::from_bytes(&decrypted)
+    /// Returns the file header.
+    pub const fn header(&self) -> &FileHeader {
+        &self.header
     }
 
-    fn read_section<R: Read + Seek>(
-        cfb: &mut CompoundFile<R>,
-        header: &FileHeader,
-        index: usize,
-        password: Option<&str>,
-        distribution_data: Option<&[u8]>,
-    ) -> Result<Section> {
-        let stream_name = if header.is_distribution() {
-            format!("/ViewText/Section{}", index)
-        } else {
-            format!("/BodyText/Section{}", index)
-        };
-
-        let mut stream = cfb
-            .open_stream(&stream_name)
-            .map_err(|_| Error::MissingStream {
-                name: stream_name.clone(),
-            })?;
-        let mut data = Vec::new();
-        stream.read_to_end(&mut data)?;
-
-        // Decompress if needed
-        let decompressed = if header.properties().is_compressed() {
-            decompress_stream(&data)?
-        } else {
-            data
-        };
-
-        // Decrypt if encrypted
-        let decrypted = if header.is_encrypted() {
-            if let Some(pwd) = password {
-                let version = header.encryption_version();
-                decrypt_password_stream(&decompressed, version, pwd)?
-            } else {
-                return Err(Error::EncryptedDocument);
-            }
-        } else if let Some(dist_data) = distribution_data {
-            decrypt_distribution_stream(&decompressed, dist_data)?
-        } else {
-            decompressed
-        };
-
-        Section::from_bytes(&decrypted)
+    /// Returns the document information.
+    pub const fn doc_info(&self) -> &DocInfo {
+        &self.doc_info
     }
 
-    /// Reads preview text from PrvText stream.
-    fn read_preview_text<R: Read + Seek>(cfb: &mut CompoundFile<R>) -> Result<PreviewText> {
-        let mut stream = cfb.open_stream("/PrvText")?;
-        let mut data = Vec::new();
-        stream.read_to_end(&mut data)?;
-        PreviewText::from_bytes(&data)
+    /// Returns the records, sections and streams skipped while parsing.
+    ///
+    /// Always empty for documents read in strict mode.
+    pub fn diagnostics(&self) -> &[ParseDiagnostic] {
+        &self.diagnostics
     }
 
-    /// Reads preview image from PrvImage stream.
-    fn read_preview_image<R: Read + Seek>(cfb: &mut CompoundFile<R>) -> Result<PreviewImage> {
-        let mut stream = cfb.open_stream("/PrvImage")?;
-        let mut data = Vec::new();
-        stream.read_to_end(&mut data)?;
-        Ok(PreviewImage::from_bytes(data))
+    /// Returns the resource limits the document was read with.
+    ///
+    /// They also apply to OLE storages opened from its binary data.
+    pub const fn limits(&self) -> &Limits {
+        &self.limits
     }
 
-    /// Reads summary information from \005HwpSummaryInformation stream.
-    fn read_summary_info<R: Read + Seek>(cfb: &mut CompoundFile<R>) -> Result<SummaryInfo> {
-        // OLE property stream name starts with \005
-        let mut stream = cfb.open_stream("/\x05HwpSummaryInformation")?;
-        let mut data = Vec::new();
-        stream.read_to_end(&mut data)?;
-        SummaryInfo::from_bytes(&data)
+    /// Returns the sections.
+    pub fn sections(&self) -> &[Section] {
+        &self.sections
     }
 
-    /// Reads scripts from Scripts storage.
-    fn read_scripts<R: Read + Seek>(cfb: &mut CompoundFile<R>) -> Result<Scripts> {
-        let mut scripts = Scripts::default();
-
-        // Read JScriptVersion
-        if let Ok(mut stream) = cfb.open_stream("/Scripts/JScriptVersion") {
-            let mut data = Vec::new();
-            stream.read_to_end(&mut data)?;
-            scripts.version = ScriptVersion::from_bytes(&data)?;
-        }
+    /// Returns a mutable reference to the sections.
+    pub fn sections_mut(&mut self) -> &mut [Section] {
+        &mut self.sections
+    }
 
-        // Read DefaultJScript
-        if let Ok(mut stream) = cfb.open_stream("/Scripts/DefaultJScript") {
-            let mut data = Vec::new();
-            stream.read_to_end(&mut data)?;
-            scripts.header = ScriptHeader::from_bytes(&data)?;
+    /// Writes the document as an HWP 5.0 file.
+    ///
+    /// The document must have been read with [`ReadOptions::retain_records`].
+    /// DocInfo and unchanged paragraphs are written from their original records,
+    /// so an open/save cycle keeps records this crate does not understand.
+    /// Changed paragraphs are re-encoded as described in [`Section::to_bytes`].
+    ///
+    /// The output keeps the version and compression of the original but is never
+    /// encrypted or distribution-protected. Preview, summary and script
+    /// streams are written as read, as are the linked document paths; DRM
+    /// streams of DocOptions are not written.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::RecordsNotRetained`] if the records needed to write the
+    /// document were not retained.
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        let doc_info =
+            self.doc_info
+                .raw_records
+                .clone()
+                .ok_or_else(|| Error::RecordsNotRetained {
+                    description: "DocInfo".to_string(),
+                })?;
+
+        let mut writer = HwpWriter::new()
+            .with_version(self.header.version())
+            .with_compression(self.header.properties().is_compressed());
+        writer.set_doc_info(doc_info);
+        for section in &self.sections {
+            writer.add_section(section.to_bytes()?);
         }
-
-        // Read script sources (JScript0, JScript1, etc.)
-        let max_scripts = scripts.header.script_count.min(100);
-        for i in 0..max_scripts {
-            let stream_name = format!("/Scripts/JScript{}", i);
-            let Ok(mut stream) = cfb.open_stream(&stream_name) else {
-                continue;
-            };
-
-            let mut data = Vec::new();
-            if stream.read_to_end(&mut data).is_ok() && !data.is_empty() {
-                if let Ok(source) = ScriptSource::from_bytes(&format!("JScript{}", i), &data) {
-                    scripts.sources.push(source);
-                }
+        let storage_compressed = self.header.properties().is_compressed();
+        for (&id, data) in &self.binary_data {
+            let info = (id as usize)
+                .checked_sub(1)
+                .and_then(|index| self.doc_info.binary_data.get(index));
+            if let Some((info, name)) = info.and_then(|info| Some((info, info.stream_name()?))) {
+                let compress = info.compression().is_compressed(storage_compressed);
+                writer.add_binary_data_with_compression(name, data.clone(), compress);
             }
         }
-
-        Ok(scripts)
-    }
-
-    /// Reads document options from DocOptions storage.
-    fn read_doc_options<R: Read + Seek>(cfb: &mut CompoundFile<R>) -> Result<DocOptions> {
-        let mut doc_options = DocOptions::new();
-
-        // Read _LinkDoc
-        if let Ok(mut stream) = cfb.open_stream("/DocOptions/_LinkDoc") {
-            let mut data = Vec::new();
-            stream.read_to_end(&mut data)?;
-            doc_options.set_link_doc(LinkDoc::from_bytes(&data)?);
+        if let Some(summary_info) = &self.summary_info {
+            writer.set_summary_info(summary_info.clone());
         }
-
-        // Read DrmLicense
-        if let Ok(mut stream) = cfb.open_stream("/DocOptions/DrmLicense") {
-            let mut data = Vec::new();
-            stream.read_to_end(&mut data)?;
-            doc_options.set_drm_license(DrmLicense::from_bytes(&data)?);
+        if let Some(preview_text) = &self.preview_text {
+            writer.set_preview_text(preview_text.text.clone());
         }
-
-        // Read DrmRootSect
-        if let Ok(mut stream) = cfb.open_stream("/DocOptions/DrmRootSect") {
-            let mut data = Vec::new();
-            stream.read_to_end(&mut data)?;
-            doc_options.set_drm_root_sect(data);
+        if let Some(preview_image) = &self.preview_image {
+            writer.set_preview_image(preview_image.data.clone());
         }
-
-        // Read CertDrmHeader
-        if let Ok(mut stream) = cfb.open_stream("/DocOptions/CertDrmHeader") {
-            let mut data = Vec::new();
-            stream.read_to_end(&mut data)?;
-            doc_options.set_cert_drm_header(data);
+        if let Some(scripts) = self.scripts.as_ref().filter(|s| !s.is_empty()) {
+            writer.set_scripts(scripts.clone());
         }
-
-        Ok(doc_options)
-    }
-
-    /// Reads binary data from the BinData storage.
-    fn read_binary_data<R: Read + Seek>(
-        cfb: &mut CompoundFile<R>,
-        doc_info: &DocInfo,
-        header: &FileHeader,
-    ) -> Result<HashMap<u16, Vec<u8>>> {
-        let mut result = HashMap::new();
-
-        // Get binary data info from doc_info
-        let binary_items = &doc_info.binary_data;
-
-        for (index, bin_info) in binary_items.iter().enumerate() {
-            // BinData IDs are 1-based in the storage
-            let bin_id = (index + 1) as u16;
-
-            // Get the stream name from BinaryData
-            if let Some(stream_name) = bin_info.stream_name() {
-                let full_path = format!("/BinData/{}", stream_name);
-
-                // Try to read the stream
-                if let Ok(mut stream) = cfb.open_stream(&full_path) {
-                    let mut data = Vec::new();
-                    stream.read_to_end(&mut data)?;
-                    if !data.is_empty() {
-                        // Decompress if needed (BinData follows storage compression setting)
-                        let final_data = if header.properties().is_compressed() {
-                            // Try decompression, fall back to raw data if it fails
-                            decompress_stream(&data).unwrap_or(data)
-                        } else {
-                            data
-                        };
-                        result.insert(bin_id, final_data);
-                    }
-                }
-            }
+        if let Some(link_doc) = self.doc_options.as_ref().and_then(|o| o.link_doc.as_ref()) {
+            writer.set_link_doc(link_doc.clone());
         }
-
-        Ok(result)
-    }
-
-    /// Returns the file header.
-    pub const fn header(&self) -> &FileHeader {
-        &self.header
-    }
-
-    /// Returns the document information.
-    pub const fn doc_info(&self) -> &DocInfo {
-        &self.doc_info
-    }
-
-    /// Returns the sections.
-    pub fn sections(&self) -> &[Section] {
-        &self.sections
+        writer.write_to_bytes()
     }
 
     /// Returns the HWP version.
@@ -471,6 +380,34 @@ impl HwpDocument {
         self.get_binary_data(picture.binary_data_id())
     }
 
+    /// Opens the BinData item of an OLE object as a compound file.
+    ///
+    /// The returned [`OleStorage`] lists the object's streams and storages,
+    /// identifies its class, and gives access to the native payload (such as
+    /// an embedded `.xlsx` or `.hwp`) and the cached presentation.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::MissingStream`] if the document has no BinData item
+    /// for the object, an error if the item is not a compound file, or
+    /// [`Error::LimitExceeded`] if its streams exceed the document's [`Limits`].
+    pub fn open_ole_object(&self, ole: &OleObject) -> Result<OleStorage> {
+        let data = self
+            .get_binary_data(ole.binary_data_id)
+            .ok_or_else(|| Error::MissingStream {
+                name: format!("/BinData/BIN{:04X}.OLE", ole.binary_data_id),
+            })?;
+        OleStorage::from_bytes_with_limits(data, &self.limits)
+    }
+
+    /// Returns the OLE storage holding the contents of a chart control.
+    ///
+    /// Pass the result to [`ChartData::read_storage`] to decode the chart's
+    /// titles, axes, categories and series.
+    pub fn get_chart_storage(&self, chart: &ChartData) -> Option<&[u8]> {
+        chart.binary_data_id.and_then(|id| self.get_binary_data(id))
+    }
+
     // === Preview API ===
 
     /// Returns the preview text if available.
@@ -606,3 +543,585 @@ impl HwpDocument {
         &self.doc_info.bullets
     }
 }
+
+/// Reports an optional stream that failed to parse, skipping it in lenient mode.
+fn recover_optional<R: Read + Seek, T>(
+    reader: &mut HwpReader<R>,
+    stream: &str,
+    result: Result<T>,
+) -> Result<Option<T>> {
+    match result {
+        Ok(value) => Ok(Some(value)),
+        Err(error) => {
+            reader.recover(ParseDiagnostic::stream(stream, error))?;
+            Ok(None)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use ir::{
+        BinaryData, BinaryDataId, BinaryFormat, Document as IrDocument, Paragraph,
+        Section as IrSection,
+    };
+
+    use super::*;
+    use crate::body::LineSegment;
+    use crate::convert::IrToHwp;
+    use crate::doc_info::DistributionRestrictions;
+    use crate::error::Error;
+    use crate::record::{RecordIter, RecordTagId, RecordTree};
+    use primitive::{HwpUnit, LimitKind};
+
+    const PNG: &[u8] = &[0x89, 0x50, 0x4E, 0x47, 0x0D, 0x0A, 0x1A, 0x0A, 0x00, 0x01];
+    /// A tag ID this crate does not know.
+    const UNKNOWN_TAG: u16 = 0x3F0;
+
+    fn record(tag: u16, level: u16, data: &[u8]) -> Vec<u8> {
+        let raw = u32::from(tag) | (u32::from(level) << 10) | ((data.len() as u32) << 20);
+        let mut bytes = raw.to_le_bytes().to_vec();
+        bytes.extend(data);
+        bytes
+    }
+
+    fn retained(bytes: &[u8]) -> HwpDocument {
+        let options = ReadOptions::new().with_retain_records(true);
+        HwpDocument::from_bytes_with_options(bytes, &options).unwrap()
+    }
+
+    fn streams(bytes: &[u8]) -> (Vec<u8>, Vec<u8>) {
+        let mut reader = HwpDocument::open(Cursor::new(bytes)).unwrap();
+        (
+            reader.read_doc_info_stream().unwrap(),
+            reader.read_section_stream(0).unwrap(),
+        )
+    }
+
+    /// A document with two paragraphs and one image.
+    fn sample() -> Vec<u8> {
+        let mut ir = IrDocument::new();
+        let mut section = IrSection::default();
+        section.paragraphs.push(Paragraph::with_text("first"));
+        section.paragraphs.push(Paragraph::with_text("second"));
+        ir.add_section(section);
+        ir.binary_data.add(
+            BinaryDataId::new("1"),
+            BinaryData::new(BinaryFormat::Png, PNG.to_vec()),
+        );
+        ir.to_hwp_bytes().unwrap().value
+    }
+
+    /// Returns the end offset of the first paragraph in a section stream.
+    fn first_paragraph_end(section: &[u8]) -> usize {
+        let tree = RecordTree::parse(section).unwrap();
+        let paragraph = tree
+            .roots
+            .iter()
+            .find(|node| node.record.tag_id() == Some(RecordTagId::ParagraphHeader))
+            .unwrap();
+        paragraph.span().end
+    }
+
+    /// Rewrites `bytes` with a different section stream.
+    fn with_section(bytes: &[u8], section: Vec<u8>) -> Vec<u8> {
+        let (doc_info, _) = streams(bytes);
+        let mut writer = HwpWriter::new();
+        writer.set_doc_info(doc_info);
+        writer.add_section(section);
+        let document = HwpDocument::from_bytes(bytes).unwrap();
+        let info = &document.doc_info().binary_data[0];
+        writer.add_binary_data_with_compression(
+            info.stream_name().unwrap(),
+            PNG.to_vec(),
+            info.compression().is_compressed(true),
+        );
+        writer.write_to_bytes().unwrap()
+    }
+
+    /// A document whose section has records the parser skips: an unknown child
+    /// record in the first paragraph and an unknown top-level record at the end.
+    fn sample_with_unknown_records() -> Vec<u8> {
+        let bytes = sample();
+        let (_, section) = streams(&bytes);
+        let end = first_paragraph_end(&section);
+
+        let mut injected = section[..end].to_vec();
+        injected.extend(record(UNKNOWN_TAG, 1, &[1, 2, 3]));
+        injected.extend(&section[end..]);
+        injected.extend(record(UNKNOWN_TAG, 0, &[4, 5]));
+        with_section(&bytes, injected)
+    }
+
+    /// A document whose first paragraph has a truncated page definition with a
+    /// nested record under it. Returns the document and the offset of the bad record.
+    fn sample_with_bad_record() -> (Vec<u8>, usize) {
+        let bytes = sample();
+        let (_, section) = streams(&bytes);
+        let end = first_paragraph_end(&section);
+
+        let mut injected = section[..end].to_vec();
+        injected.extend(record(RecordTagId::PageDefinition as u16, 1, &[0; 2]));
+        injected.extend(record(UNKNOWN_TAG, 2, &[1]));
+        injected.extend(&section[end..]);
+        (with_section(&bytes, injected), end)
+    }
+
+    fn unknown_records(section: &[u8]) -> Vec<(usize, Vec<u8>)> {
+        RecordTree::parse(section)
+            .unwrap()
+            .walk()
+            .into_iter()
+            .filter(|(_, record)| record.tag_id_raw() == UNKNOWN_TAG)
+            .map(|(depth, record)| (depth, record.data().to_vec()))
+            .collect()
+    }
+
+    #[test]
+    fn test_round_trip_retained_records() {
+        let bytes = sample_with_unknown_records();
+        let document = retained(&bytes);
+
+        let written = document.to_bytes().unwrap();
+        assert_eq!(streams(&written), streams(&bytes));
+
+        let reread = HwpDocument::from_bytes(&written).unwrap();
+        assert_eq!(reread.extract_text(), document.extract_text());
+        assert_eq!(reread.get_binary_data(1), Some(PNG));
+    }
+
+    #[test]
+    fn test_edited_paragraph_keeps_unknown_records() {
+        let bytes = sample_with_unknown_records();
+        let mut document = retained(&bytes);
+
+        let paragraph = &mut document.sections_mut()[0].paragraphs_mut()[0];
+        let segments = paragraph.line_segments().len();
+        assert!(paragraph.raw_records().is_some());
+        paragraph.add_line_segment(LineSegment {
+            text_start_position: 0,
+            vertical_position: HwpUnit::new(0),
+            line_height: HwpUnit::new(1000),
+            text_height: HwpUnit::new(1000),
+            baseline_distance: HwpUnit::new(850),
+            line_spacing: HwpUnit::new(600),
+            column_start_position: HwpUnit::new(0),
+            segment_width: HwpUnit::new(42520),
+            tag: 0,
+        });
+        assert!(paragraph.raw_records().is_none());
+
+        let written = document.to_bytes().unwrap();
+        let (doc_info, section) = streams(&written);
+        assert_eq!(doc_info, streams(&bytes).0);
+        assert_eq!(
+            unknown_records(&section),
+            [(1, vec![1, 2, 3]), (0, vec![4, 5])]
+        );
+
+        let reread = HwpDocument::from_bytes(&written).unwrap();
+        assert_eq!(reread.extract_text(), document.extract_text());
+        let paragraph = &reread.sections()[0].paragraphs()[0];
+        assert_eq!(paragraph.line_segments().len(), segments + 1);
+        assert_eq!(
+            paragraph.line_segments()[segments].segment_width.value(),
+            42520
+        );
+    }
+
+    #[test]
+    fn test_summary_and_preview_streams() {
+        let mut ir = IrDocument::new();
+        ir.metadata.title = Some("제목".to_string());
+        ir.metadata.author = Some("작성자".to_string());
+        ir.metadata.keywords = vec!["a".to_string(), "b".to_string()];
+        ir.metadata.created = Some("2024-01-31T09:30:00Z".to_string());
+        let mut section = IrSection::default();
+        section.paragraphs.push(Paragraph::with_text("본문"));
+        ir.add_section(section);
+        let bytes = ir.to_hwp_bytes().unwrap().value;
+
+        let document = HwpDocument::from_bytes(&bytes).unwrap();
+        assert_eq!(document.title(), Some("제목"));
+        assert_eq!(document.author(), Some("작성자"));
+        assert_eq!(document.keywords(), Some("a, b"));
+        let summary = document.summary_info().unwrap();
+        assert_eq!(
+            summary.creation_date.as_deref(),
+            Some("2024-01-31T09:30:00Z")
+        );
+        assert_eq!(document.preview_text().unwrap().text, ir.to_preview_text());
+        assert!(document.preview_image().is_none());
+
+        let mut writer = HwpWriter::new();
+        let (doc_info, section) = streams(&bytes);
+        writer.set_doc_info(doc_info);
+        writer.add_section(section);
+        writer.set_preview_image(PNG.to_vec());
+        let written = retained(&writer.write_to_bytes().unwrap())
+            .to_bytes()
+            .unwrap();
+        let reread = HwpDocument::from_bytes(&written).unwrap();
+        assert_eq!(reread.preview_image().unwrap().data, PNG);
+        assert!(reread.summary_info().is_none());
+    }
+
+    #[test]
+    fn test_distribution_document() {
+        let plain = sample();
+        let (doc_info, section) = streams(&plain);
+        let expected = HwpDocument::from_bytes(&plain).unwrap().extract_text();
+
+        for compress in [true, false] {
+            let mut writer = HwpWriter::new()
+                .with_compression(compress)
+                .with_distribution("배포 암호", DistributionRestrictions::all())
+                .unwrap();
+            writer.set_doc_info(doc_info.clone());
+            writer.add_section(section.clone());
+            let bytes = writer.write_to_bytes().unwrap();
+
+            let mut cfb = cfb::CompoundFile::open(Cursor::new(&bytes)).unwrap();
+            assert!(!cfb.exists("/BodyText"));
+            let mut raw = Vec::new();
+            cfb.open_stream("/ViewText/Section0")
+                .unwrap()
+                .read_to_end(&mut raw)
+                .unwrap();
+            let record = RecordIter::new(&raw).next().unwrap().unwrap();
+            assert_eq!(record.tag_id(), Some(RecordTagId::DistributeDocumentData));
+
+            let document = HwpDocument::from_bytes(&bytes).unwrap();
+            assert!(document.is_distribution_document());
+            assert_eq!(document.extract_text(), expected);
+            let data = document.doc_info().distribute_doc_data.as_ref().unwrap();
+            assert_eq!(data.restrictions(), DistributionRestrictions::all());
+        }
+
+        let mut writer = HwpWriter::new()
+            .with_distribution("", DistributionRestrictions::PRINT)
+            .unwrap();
+        writer.set_doc_info(doc_info);
+        writer.add_section(section);
+        let document = HwpDocument::from_bytes(&writer.write_to_bytes().unwrap()).unwrap();
+        let data = document.doc_info().distribute_doc_data.as_ref().unwrap();
+        assert_eq!(data.restrictions(), DistributionRestrictions::PRINT);
+    }
+
+    #[test]
+    fn test_scripts_and_linked_documents() {
+        let scripts = ir::HwpScripts {
+            version_high: 1,
+            version_low: 0,
+            header: "var Documents = XHwpDocuments;".to_string(),
+            source: "function OnDocument_New() {}".to_string(),
+            pre_source: String::new(),
+            post_source: "// 끝".to_string(),
+        };
+        let mut ir = IrDocument::new();
+        ir.extensions.hwp = Some(ir::HwpExtensions {
+            scripts: Some(scripts.clone()),
+            linked_documents: vec!["C:\\문서\\원본.hwp".to_string()],
+            ..Default::default()
+        });
+        let mut section = IrSection::default();
+        section.paragraphs.push(Paragraph::with_text("본문"));
+        ir.add_section(section);
+        let bytes = ir.to_hwp_bytes().unwrap().value;
+
+        let document = retained(&bytes);
+        assert!(document.header().has_script());
+        assert!(document.has_scripts());
+        assert!(document.has_linked_documents());
+        let converted = document.to_ir().unwrap().value;
+        let hwp_ext = converted.extensions.hwp.unwrap();
+        assert_eq!(hwp_ext.scripts, Some(scripts));
+        assert_eq!(hwp_ext.linked_documents, ["C:\\문서\\원본.hwp"]);
+
+        let reread = HwpDocument::from_bytes(&document.to_bytes().unwrap()).unwrap();
+        assert_eq!(reread.scripts(), document.scripts());
+        let link_doc = reread.doc_options().and_then(|o| o.link_doc.as_ref());
+        assert_eq!(link_doc.unwrap().paths(), ["C:\\문서\\원본.hwp"]);
+
+        // Script streams are encrypted in distribution documents
+        let (doc_info, section) = streams(&bytes);
+        let mut writer = HwpWriter::new()
+            .with_distribution("", DistributionRestrictions::empty())
+            .unwrap();
+        writer.set_doc_info(doc_info);
+        writer.add_section(section);
+        writer.set_scripts(document.scripts().unwrap().clone());
+        let distributed = HwpDocument::from_bytes(&writer.write_to_bytes().unwrap()).unwrap();
+        assert_eq!(distributed.scripts(), document.scripts());
+    }
+
+    #[test]
+    fn test_compose_and_dutmal_round_trip() {
+        use crate::Control;
+        use ir::paragraph::{
+            Compose, ComposeCircleType, ComposeType, Dutmal, DutmalAlignment, DutmalPosition,
+            RunContent,
+        };
+
+        let mut paragraph = Paragraph::with_text("본문");
+        paragraph.runs[0].contents.extend([
+            RunContent::Compose(Compose {
+                compose_text: "12".to_string(),
+                compose_type: Some(ComposeType::Spread),
+                circle_type: ComposeCircleType::ShapeRectangle,
+                char_size: Some(-3),
+                char_shape_ids: vec![Some(primitive::CharShapeId::new(0)), None],
+            }),
+            RunContent::Dutmal(Dutmal {
+                main_text: "漢字".to_string(),
+                sub_text: "한자".to_string(),
+                position_type: DutmalPosition::Bottom,
+                size_ratio: Some(50),
+                option: Some(4),
+                style_id_ref: Some(primitive::StyleId::new(0)),
+                alignment: DutmalAlignment::Distribute,
+            }),
+        ]);
+        let mut ir = IrDocument::new();
+        let mut section = IrSection::default();
+        section.paragraphs.push(paragraph);
+        ir.add_section(section);
+
+        let document = HwpDocument::from_bytes(&ir.to_hwp_bytes().unwrap().value).unwrap();
+        let controls = document.sections()[0].paragraphs()[0].controls();
+        let compose = controls.iter().find_map(Control::as_compose).unwrap();
+        assert_eq!(compose.text, "12");
+        assert_eq!(compose.character_shape_ids, [0, u32::MAX]);
+        let dutmal = controls.iter().find_map(Control::as_dutmal).unwrap();
+        assert_eq!(dutmal.sub_text, "한자");
+
+        let converted = document.to_ir().unwrap().value;
+        let contents = &converted.sections[0].paragraphs[0].runs[0].contents;
+        let Some(RunContent::Compose(compose)) = contents.get(1) else {
+            panic!("expected compose, got {contents:?}");
+        };
+        assert_eq!(compose.compose_type, Some(ComposeType::Spread));
+        assert_eq!(compose.circle_type, ComposeCircleType::ShapeRectangle);
+        assert_eq!(compose.char_size, Some(-3));
+        assert_eq!(compose.char_shape_ids.len(), 2);
+        assert!(compose.char_shape_ids[1].is_none());
+        let Some(RunContent::Dutmal(dutmal)) = contents.get(2) else {
+            panic!("expected dutmal, got {contents:?}");
+        };
+        assert_eq!(dutmal.main_text, "漢字");
+        assert_eq!(dutmal.position_type, DutmalPosition::Bottom);
+        assert_eq!(dutmal.size_ratio, Some(50));
+        assert_eq!(dutmal.alignment, DutmalAlignment::Distribute);
+    }
+
+    #[test]
+    fn test_to_bytes_requires_retained_records() {
+        let bytes = sample_with_unknown_records();
+        let document = HwpDocument::from_bytes(&bytes).unwrap();
+
+        assert!(document.doc_info().raw_records.is_none());
+        assert!(matches!(
+            document.to_bytes(),
+            Err(Error::RecordsNotRetained { .. })
+        ));
+    }
+
+    #[test]
+    fn test_lenient_parse_reports_bad_record() {
+        let (bytes, offset) = sample_with_bad_record();
+        let options = ReadOptions::new().with_parse_options(ParseOptions::lenient());
+        let document = HwpDocument::from_bytes_with_options(&bytes, &options).unwrap();
+
+        assert_eq!(
+            document.extract_text(),
+            HwpDocument::from_bytes(&sample()).unwrap().extract_text()
+        );
+        assert!(document.sections()[0].page_definition().is_some());
+        let diagnostics = document.diagnostics();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].stream, "/BodyText/Section0");
+        assert_eq!(diagnostics[0].offset, offset);
+        assert_eq!(diagnostics[0].tag, Some(RecordTagId::PageDefinition as u16));
+        assert!(matches!(
+            diagnostics[0].error,
+            Error::UnexpectedEndOfData { .. }
+        ));
+    }
+
+    #[test]
+    fn test_strict_parse_fails_on_bad_record() {
+        let (bytes, _) = sample_with_bad_record();
+        let options = ReadOptions::new().with_parse_options(ParseOptions::strict());
+
+        assert!(matches!(
+            HwpDocument::from_bytes_with_options(&bytes, &options),
+            Err(Error::UnexpectedEndOfData { .. })
+        ));
+        // Strict parsing is the default
+        assert_eq!(ReadOptions::new().parse, options.parse);
+        assert!(matches!(
+            HwpDocument::from_bytes(&bytes),
+            Err(Error::UnexpectedEndOfData { .. })
+        ));
+        assert!(
+            HwpDocument::from_bytes(&sample())
+                .unwrap()
+                .diagnostics()
+                .is_empty()
+        );
+    }
+
+    /// A document with three sections whose middle section stream is removed.
+    fn sample_without_middle_section() -> Vec<u8> {
+        let mut ir = IrDocument::new();
+        for text in ["first", "second", "third"] {
+            let mut section = IrSection::default();
+            section.paragraphs.push(Paragraph::with_text(text));
+            ir.add_section(section);
+        }
+        let bytes = ir.to_hwp_bytes().unwrap().value;
+
+        let mut cfb = cfb::CompoundFile::open(Cursor::new(bytes)).unwrap();
+        cfb.remove_stream("/BodyText/Section1").unwrap();
+        cfb.flush().unwrap();
+        cfb.into_inner().into_inner()
+    }
+
+    #[test]
+    fn test_missing_section_stream() {
+        let bytes = sample_without_middle_section();
+
+        let options = ReadOptions::new().with_parse_options(ParseOptions::lenient());
+        let document = HwpDocument::from_bytes_with_options(&bytes, &options).unwrap();
+        assert_eq!(document.section_count(), 2);
+        let text = document.extract_text();
+        assert!(text.contains("first") && text.contains("third"), "{}", text);
+        let diagnostics = document.diagnostics();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].stream, "/BodyText/Section1");
+        assert!(matches!(diagnostics[0].error, Error::MissingStream { .. }));
+
+        assert!(matches!(
+            HwpDocument::from_bytes(&bytes),
+            Err(Error::MissingStream { .. })
+        ));
+    }
+
+    /// A document with a one-cell table.
+    fn sample_with_table() -> Vec<u8> {
+        let mut table = ir::table::Table::new(1, 1);
+        let mut row = ir::table::TableRow::new();
+        row.cells
+            .push(ir::table::TableCell::with_text(0, 0, "cell"));
+        table.rows.push(row);
+
+        let mut paragraph = Paragraph::new();
+        paragraph.add_run(ir::paragraph::Run::control(ir::control::Control::Table(
+            Box::new(table),
+        )));
+        let mut ir = IrDocument::new();
+        let mut section = IrSection::default();
+        section.paragraphs.push(paragraph);
+        ir.add_section(section);
+        ir.to_hwp_bytes().unwrap().value
+    }
+
+    fn read_with_limits(bytes: &[u8], limits: Limits) -> Result<HwpDocument> {
+        HwpDocument::from_bytes_with_options(bytes, &ReadOptions::new().with_limits(limits))
+    }
+
+    fn exceeded(result: Result<HwpDocument>) -> Option<LimitKind> {
+        match result {
+            Err(Error::LimitExceeded { kind, .. }) => Some(kind),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_stream_limits() {
+        let bytes = sample();
+        let (doc_info, section) = streams(&bytes);
+        let largest = doc_info.len().max(section.len()) as u64;
+
+        let limits = Limits::new().with_max_stream_size(largest);
+        assert!(read_with_limits(&bytes, limits).is_ok());
+        let limits = Limits::new().with_max_stream_size(largest - 1);
+        assert_eq!(
+            exceeded(read_with_limits(&bytes, limits)),
+            Some(LimitKind::StreamSize)
+        );
+
+        let limits = Limits::new().with_max_total_size((doc_info.len() + section.len()) as u64);
+        assert_eq!(
+            exceeded(read_with_limits(&bytes, limits)),
+            Some(LimitKind::TotalSize)
+        );
+
+        let limits = Limits::new().with_max_binary_data_size(PNG.len() as u64 - 1);
+        assert_eq!(
+            exceeded(read_with_limits(&bytes, limits)),
+            Some(LimitKind::BinaryDataSize)
+        );
+
+        let limits = Limits::new().with_max_record_size(4);
+        assert_eq!(
+            exceeded(read_with_limits(&bytes, limits)),
+            Some(LimitKind::RecordSize)
+        );
+    }
+
+    #[test]
+    fn test_nesting_depth_limit() {
+        let bytes = sample_with_table();
+
+        let limits = Limits::new().with_max_nesting_depth(1);
+        let document = read_with_limits(&bytes, limits).unwrap();
+        assert!(document.extract_text().contains("cell"));
+
+        let limits = Limits::new().with_max_nesting_depth(0);
+        assert_eq!(
+            exceeded(read_with_limits(&bytes, limits)),
+            Some(LimitKind::NestingDepth)
+        );
+        let options = ReadOptions::new()
+            .with_limits(limits)
+            .with_parse_options(ParseOptions::lenient());
+        assert!(HwpDocument::from_bytes_with_options(&bytes, &options).is_err());
+    }
+
+    #[test]
+    fn test_corrupt_counts_do_not_preallocate() {
+        use crate::body::{CurveShape, ParagraphText, PolygonShape};
+        use crate::doc_info::{BorderFill, FillInfo};
+        use crate::util::ByteReader;
+
+        fn truncated<T>(result: Result<T>) -> bool {
+            matches!(result, Err(Error::UnexpectedEndOfData { .. }))
+        }
+
+        // Point counts near u32::MAX followed by a single point
+        let mut data = u32::MAX.to_le_bytes().to_vec();
+        data.extend([0; 8]);
+        let mut reader = ByteReader::new(&data);
+        assert!(truncated(PolygonShape::from_reader(&mut reader)));
+        let mut reader = ByteReader::new(&data);
+        assert!(truncated(CurveShape::from_reader(&mut reader)));
+        assert!(truncated(ParagraphText::from_reader(
+            &mut ByteReader::new(&data),
+            u32::MAX
+        )));
+
+        // Gradient fill with a negative color count
+        let mut data = vec![0; 2 + 4 * 6 + 6];
+        data.extend(4u32.to_le_bytes()); // gradient fill
+        data.extend([0; 10]);
+        data.extend((-1i16).to_le_bytes());
+        let border_fill = BorderFill::from_reader(&mut ByteReader::new(&data)).unwrap();
+        assert!(matches!(
+            border_fill.fill_info(),
+            FillInfo::Gradient(gradient) if gradient.colors.is_empty()
+        ));
+    }
+}
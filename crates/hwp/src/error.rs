@@ -2,6 +2,8 @@
 
 use std::fmt;
 
+use primitive::{LimitExceeded, LimitKind};
+
 /// Result type alias for HWP operations.
 pub type Result<T> = std::result::Result<T, Error>;
 
@@ -41,6 +43,10 @@ pub enum Error {
         name: String,
     },
 
+    /// A distribution document stream does not start with valid
+    /// distribution document data, or its encrypted part is malformed.
+    InvalidDistributionData,
+
     /// Decompression failed.
     DecompressionFailed {
         /// Description of the decompression error.
@@ -57,6 +63,26 @@ pub enum Error {
         /// Actual number of bytes available.
         actual: usize,
     },
+
+    /// Chart storage data is malformed.
+    InvalidChartData {
+        /// Description of the problem.
+        description: String,
+    },
+
+    /// Writing requires original records that were not retained when reading.
+    RecordsNotRetained {
+        /// What could not be written.
+        description: String,
+    },
+
+    /// A configured resource limit was exceeded.
+    LimitExceeded {
+        /// Which limit was exceeded.
+        kind: LimitKind,
+        /// The configured limit.
+        limit: u64,
+    },
 }
 
 impl fmt::Display for Error {
@@ -82,6 +108,7 @@ impl fmt::Display for Error {
             Error::MissingStream { name } => {
                 write!(f, "Missing stream: {}", name)
             }
+            Error::InvalidDistributionData => write!(f, "Invalid distribution document data"),
             Error::DecompressionFailed { description } => {
                 write!(f, "Decompression failed: {}", description)
             }
@@ -93,6 +120,15 @@ impl fmt::Display for Error {
                     expected, actual
                 )
             }
+            Error::InvalidChartData { description } => {
+                write!(f, "Invalid chart data: {}", description)
+            }
+            Error::RecordsNotRetained { description } => {
+                write!(f, "Original records not retained: {}", description)
+            }
+            Error::LimitExceeded { kind, limit } => {
+                write!(f, "Limit exceeded: {} over {}", kind, limit)
+            }
         }
     }
 }
@@ -111,3 +147,12 @@ impl From<std::io::Error> for Error {
         Error::Io(err)
     }
 }
+
+impl From<LimitExceeded> for Error {
+    fn from(err: LimitExceeded) -> Self {
+        Error::LimitExceeded {
+            kind: err.kind,
+            limit: err.limit,
+        }
+    }
+}
@@ -33,10 +33,15 @@
 //! ## 주요 타입
 //!
 //! - [`HwpDocument`]: 문서 전체를 나타내는 최상위 타입
+//! - [`HwpReader`]: 섹션과 바이너리 데이터를 필요할 때 읽는 지연 로딩 리더 ([`HwpDocument::open`])
+//! - [`ReadOptions`]: 읽기 설정 (암호, [`HwpDocument::to_bytes`]로 다시 저장하기 위한 원본 레코드 보존, [`ParseOptions`])
+//! - [`ParseDiagnostic`]: 관대한 파싱([`Recovery::Lenient`])에서 건너뛴 레코드/스트림 정보
+//! - [`Limits`]: 신뢰할 수 없는 문서를 읽을 때의 자원 제한 (넘으면 [`Error::LimitExceeded`])
 //! - [`FileHeader`]: 파일 헤더 정보 (버전, 암호화 여부 등)
 //! - [`DocInfo`]: 문서 정보 (폰트, 스타일, 문단 모양 등)
 //! - [`Section`] - 섹션 (본문 내용)
 //! - [`Paragraph`] - 문단
+//! - [`record::RecordTree`]: 복호화/압축 해제한 스트림의 레코드 트리 (레코드 단위 검사와 비교용)
 //!
 //! ## HWP 5.0 형식 구조
 //!
@@ -44,7 +49,7 @@
 //!
 //! - `FileHeader`: 문서 식별 및 속성 정보
 //! - `DocInfo`: 문서 수준 정보 (폰트, 스타일, 번호 매김 등)
-//! - `BodyText/SectionN`: 본문 내용 (섹션별로 분리)
+//! - `BodyText/SectionN`: 본문 내용 (섹션별로 분리, 배포용 문서는 암호화된 `ViewText/SectionN`)
 //! - `BinData`: 바이너리 데이터 (이미지 등)
 //! - `PrvText`, `PrvImage`: 미리보기 텍스트 및 이미지
 
@@ -52,26 +57,32 @@
 
 pub mod convert;
 pub mod error;
+pub mod record;
 pub mod writer;
 
 mod body;
 mod crypto;
+mod diagnostic;
 mod doc_info;
 mod doc_options;
 mod document;
 mod header;
+mod ole;
 mod preview;
 mod primitive;
+mod reader;
 mod script;
 mod summary;
 mod util;
 
 // 핵심 API 타입 재export
-pub use document::HwpDocument;
+pub use diagnostic::{ParseDiagnostic, ParseOptions, Recovery};
+pub use document::{HwpDocument, ReadOptions};
 pub use error::{Error, Result};
+pub use reader::{BinaryDataIter, HwpReader, Sections};
 
 // 주요 타입 재export
-pub use ::primitive::Version;
+pub use ::primitive::{LimitKind, Limits, Version};
 pub use body::Section;
 pub use doc_info::DocInfo;
 pub use header::FileHeader;
@@ -90,9 +101,16 @@ pub use body::{
     CaptionDirection,
     CharacterShapeReference,
     // 추가 컨트롤 타입
+    ChartAxis,
+    ChartAxisKind,
     ChartData,
+    ChartObjectHeader,
     ChartSeries,
+    ChartSource,
     ChartType,
+    // 글자 겹침
+    Compose,
+    ComposeBorderType,
     // 컨트롤 타입 (표, 그림 등 특수 개체)
     Control,
     ControlCharacter,
@@ -101,6 +119,10 @@ pub use body::{
     ControlType,
     CurveSegmentType,
     CurveShape,
+    // 덧말
+    Dutmal,
+    DutmalAlignment,
+    DutmalPosition,
     EllipseShape,
     // 각주/미주
     Endnote,
@@ -145,6 +167,7 @@ pub use body::{
     PageMargins,
     PageOrientation,
     Paragraph,
+    ParagraphText,
     Picture,
     PictureEffect,
     PictureFill,
@@ -157,6 +180,7 @@ pub use body::{
     ShapeBorderLine,
     ShapeContainer,
     ShapeElementProperties,
+    ShapeShadow,
     ShapeType,
     // 표 타입
     Table,
@@ -167,6 +191,7 @@ pub use body::{
     TextArtShape,
     TextBox,
     TextDirection,
+    TextUnit,
     VerticalAlignment,
     VideoData,
     VideoType,
@@ -223,8 +248,12 @@ pub use doc_info::{
 };
 
 // 미리보기 및 요약 정보 타입
+pub use ole::{
+    OleEntry, OleEntryKind, OlePayload, OlePayloadKind, OlePresentation, OlePresentationFormat,
+    OleStorage,
+};
 pub use preview::{PreviewImage, PreviewText};
-pub use script::{ScriptHeader, ScriptSource, ScriptVersion, Scripts};
+pub use script::{ScriptVersion, Scripts};
 pub use summary::SummaryInfo;
 
 // 문서 옵션 타입
@@ -232,6 +261,6 @@ pub use doc_options::{DocOptions, DrmLicense, LinkDoc};
 
 // 추가 DocInfo 타입 (변경 추적, 배포용 문서 등)
 pub use doc_info::{
-    DistributeDocData, DocumentData, ForbiddenChar, TrackChangeAuthor, TrackChangeContent,
-    TrackChangeInfo,
+    DistributeDocData, DistributionRestrictions, DocumentData, ForbiddenChar, TrackChangeAuthor,
+    TrackChangeContent, TrackChangeInfo,
 };
@@ -0,0 +1,581 @@
+//! Embedded OLE object storage.
+//!
+//! An OLE object's BinData item is a compound file (CFB) written by the
+//! server application of the object. Besides the object's own streams it
+//! usually holds:
+//!
+//! - `\x01CompObj`: user type and ProgID of the object
+//! - `\x01Ole10Native`: native data of OLE 1.0 objects (e.g. packages)
+//! - `Package`: an OOXML package (`.xlsx`, `.docx`, `.pptx`)
+//! - `\x02OlePres000`…: cached presentations (WMF, DIB) for display
+//! - `\x03EPRINT`: cached EMF presentation
+//!
+//! Objects such as embedded HWP or legacy Office documents have no separate
+//! payload stream; the compound file itself is the native document.
+
+use std::collections::BTreeMap;
+use std::io::{Cursor, Read};
+
+use cfb::CompoundFile;
+use primitive::{LimitKind, Limits};
+
+use crate::error::Result;
+use crate::util::ByteReader;
+
+/// Compound file signature.
+const CFB_SIGNATURE: [u8; 8] = [0xD0, 0xCF, 0x11, 0xE0, 0xA1, 0xB1, 0x1A, 0xE1];
+
+/// Stream holding the user type and ProgID.
+const COMP_OBJ_STREAM: &str = "/\u{1}CompObj";
+
+/// Stream holding OLE 1.0 native data.
+const OLE10_NATIVE_STREAM: &str = "/\u{1}Ole10Native";
+
+/// Stream holding an OOXML package.
+const PACKAGE_STREAM: &str = "/Package";
+
+/// Stream holding the cached EMF presentation.
+const EPRINT_STREAM: &str = "/\u{3}EPRINT";
+
+/// Prefix of presentation streams (`\x02OlePres000` to `\x02OlePres999`).
+const OLE_PRES_PREFIX: &str = "/\u{2}OlePres";
+
+/// Clipboard format of a Windows metafile picture.
+const CF_METAFILEPICT: u32 = 3;
+
+/// Clipboard format of a device-independent bitmap.
+const CF_DIB: u32 = 8;
+
+/// Clipboard format of an enhanced metafile.
+const CF_ENHMETAFILE: u32 = 14;
+
+/// Well-known class IDs and their ProgIDs.
+const KNOWN_CLASSES: [(&str, &str); 9] = [
+    ("00020820-0000-0000-C000-000000000046", "Excel.Sheet.8"),
+    ("00020821-0000-0000-C000-000000000046", "Excel.Chart.8"),
+    ("00020830-0000-0000-C000-000000000046", "Excel.Sheet.12"),
+    ("00020906-0000-0000-C000-000000000046", "Word.Document.8"),
+    ("F4754C9B-64F5-4B40-8AF4-679732AC0607", "Word.Document.12"),
+    ("64818D10-4F9B-11CF-86EA-00AA00B929E8", "PowerPoint.Show.8"),
+    ("CF4F55F4-8F87-4D47-80BB-5808164BB3F8", "PowerPoint.Show.12"),
+    ("0003000C-0000-0000-C000-000000000046", "Package"),
+    ("0002CE02-0000-0000-C000-000000000046", "Equation.3"),
+];
+
+/// Kind of an entry in an OLE storage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OleEntryKind {
+    /// Storage (directory).
+    Storage,
+    /// Stream (file).
+    Stream,
+}
+
+/// Entry of an OLE storage.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OleEntry {
+    /// Absolute path (e.g. `/\x01CompObj`, `/ObjectPool/_1234`).
+    pub path: String,
+    /// Entry kind.
+    pub kind: OleEntryKind,
+    /// Stream size in bytes (0 for storages).
+    pub size: u64,
+}
+
+/// Kind of the native payload of an OLE object.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OlePayloadKind {
+    /// OOXML package from the `Package` stream.
+    Package,
+    /// Native data from the `\x01Ole10Native` stream.
+    Ole10Native,
+    /// The compound file itself (e.g. an embedded HWP or `.xls` document).
+    CompoundFile,
+}
+
+/// Native payload of an OLE object.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OlePayload<'a> {
+    /// Payload kind.
+    pub kind: OlePayloadKind,
+    /// Payload bytes.
+    pub data: &'a [u8],
+}
+
+impl OlePayload<'_> {
+    /// Returns the usual file extension of the payload, if it is recognized.
+    pub fn extension(&self, prog_id: Option<&str>) -> Option<&'static str> {
+        let prog_id = prog_id.unwrap_or_default();
+        match self.kind {
+            OlePayloadKind::Package if prog_id.starts_with("Excel.") => Some("xlsx"),
+            OlePayloadKind::Package if prog_id.starts_with("Word.") => Some("docx"),
+            OlePayloadKind::Package if prog_id.starts_with("PowerPoint.") => Some("pptx"),
+            OlePayloadKind::Package => Some("zip"),
+            OlePayloadKind::CompoundFile => {
+                let storage = OleStorage::from_bytes(self.data).ok()?;
+                [
+                    ("/FileHeader", "hwp"),
+                    ("/Workbook", "xls"),
+                    ("/Book", "xls"),
+                    ("/WordDocument", "doc"),
+                    ("/PowerPoint Document", "ppt"),
+                ]
+                .into_iter()
+                .find(|(stream, _)| storage.stream(stream).is_some())
+                .map(|(_, extension)| extension)
+            }
+            OlePayloadKind::Ole10Native => match self.data {
+                [b'%', b'P', b'D', b'F', ..] => Some("pdf"),
+                _ => None,
+            },
+        }
+    }
+}
+
+/// Format of a cached presentation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OlePresentationFormat {
+    /// Windows metafile (without the placeable header).
+    Wmf,
+    /// Enhanced metafile.
+    Emf,
+    /// Device-independent bitmap (without the file header).
+    Dib,
+}
+
+/// Cached presentation of an OLE object.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OlePresentation<'a> {
+    /// Presentation format.
+    pub format: OlePresentationFormat,
+    /// Width in HIMETRIC (0.01 mm), or 0 if unknown.
+    pub width: u32,
+    /// Height in HIMETRIC (0.01 mm), or 0 if unknown.
+    pub height: u32,
+    /// Presentation data.
+    pub data: &'a [u8],
+}
+
+/// An OLE object's storage opened as a compound file.
+#[derive(Debug, Clone, Default)]
+pub struct OleStorage {
+    /// Compound file bytes (without the BinData size prefix).
+    data: Vec<u8>,
+    /// Entries in depth-first order, without the root.
+    entries: Vec<OleEntry>,
+    /// Stream path → contents.
+    streams: BTreeMap<String, Vec<u8>>,
+    /// Class ID of the root storage.
+    class_id: Option<String>,
+}
+
+impl OleStorage {
+    /// Opens an OLE object's BinData item with the default [`Limits`].
+    ///
+    /// BinData of OLE objects may start with a 4-byte storage size; it is
+    /// skipped when present.
+    pub fn from_bytes(data: &[u8]) -> Result<Self> {
+        Self::from_bytes_with_limits(data, &Limits::default())
+    }
+
+    /// Opens an OLE object's BinData item, checking stream sizes against `limits`.
+    ///
+    /// Every stream is copied, so a stream larger than
+    /// [`Limits::max_stream_size`] or streams adding up to more than
+    /// [`Limits::max_total_size`] fail with
+    /// [`Error::LimitExceeded`](crate::Error::LimitExceeded) before they are read.
+    pub fn from_bytes_with_limits(data: &[u8], limits: &Limits) -> Result<Self> {
+        let data = match data.get(4..12) {
+            Some(signature) if signature == CFB_SIGNATURE => &data[4..],
+            _ => data,
+        };
+        let mut file = CompoundFile::open(Cursor::new(data))?;
+
+        let root = file.root_entry();
+        let class_id = (!root.clsid().is_nil())
+            .then(|| format!("{{{}}}", root.clsid().to_string().to_uppercase()));
+
+        let mut entries = Vec::new();
+        for entry in file.walk().filter(|entry| !entry.is_root()) {
+            let (kind, size) = if entry.is_stream() {
+                (OleEntryKind::Stream, entry.len())
+            } else {
+                (OleEntryKind::Storage, 0)
+            };
+            entries.push(OleEntry {
+                path: entry.path().to_string_lossy().replace('\\', "/"),
+                kind,
+                size,
+            });
+        }
+
+        let mut streams = BTreeMap::new();
+        let mut total_size = 0u64;
+        for entry in entries.iter().filter(|e| e.kind == OleEntryKind::Stream) {
+            limits.check(LimitKind::StreamSize, entry.size)?;
+            total_size = total_size.saturating_add(entry.size);
+            limits.check(LimitKind::TotalSize, total_size)?;
+
+            let mut stream = Vec::new();
+            file.open_stream(&entry.path)?
+                .take(entry.size)
+                .read_to_end(&mut stream)?;
+            streams.insert(entry.path.clone(), stream);
+        }
+
+        Ok(Self {
+            data: data.to_vec(),
+            entries,
+            streams,
+            class_id,
+        })
+    }
+
+    /// Returns the compound file bytes.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Returns the storages and streams in depth-first order.
+    pub fn entries(&self) -> &[OleEntry] {
+        &self.entries
+    }
+
+    /// Returns the contents of a stream.
+    ///
+    /// The path is absolute; a missing leading `/` is added.
+    pub fn stream(&self, path: &str) -> Option<&[u8]> {
+        match path.strip_prefix('/') {
+            Some(_) => self.streams.get(path),
+            None => self.streams.get(&format!("/{}", path)),
+        }
+        .map(Vec::as_slice)
+    }
+
+    /// Returns the class ID of the root storage (e.g.
+    /// `{00020830-0000-0000-C000-000000000046}`).
+    pub fn class_id(&self) -> Option<&str> {
+        self.class_id.as_deref()
+    }
+
+    /// Returns the ProgID of the object (e.g. `Excel.Sheet.12`).
+    ///
+    /// Read from `\x01CompObj`, or looked up from the class ID for
+    /// well-known classes.
+    pub fn prog_id(&self) -> Option<String> {
+        self.comp_obj()
+            .and_then(|comp_obj| comp_obj.prog_id)
+            .or_else(|| {
+                let class_id = self.class_id()?.trim_matches(['{', '}']);
+                KNOWN_CLASSES
+                    .iter()
+                    .find(|(known, _)| known.eq_ignore_ascii_case(class_id))
+                    .map(|(_, prog_id)| prog_id.to_string())
+            })
+    }
+
+    /// Returns the user-visible type name from `\x01CompObj`
+    /// (e.g. `Microsoft Excel Worksheet`).
+    pub fn user_type(&self) -> Option<String> {
+        self.comp_obj().and_then(|comp_obj| comp_obj.user_type)
+    }
+
+    /// Returns the native payload of the object.
+    ///
+    /// The `Package` stream is preferred, then `\x01Ole10Native`. Otherwise
+    /// the compound file itself is the payload, unless it holds nothing but
+    /// OLE bookkeeping streams.
+    pub fn native_payload(&self) -> Option<OlePayload<'_>> {
+        if let Some(data) = self.stream(PACKAGE_STREAM) {
+            return Some(OlePayload {
+                kind: OlePayloadKind::Package,
+                data,
+            });
+        }
+        if let Some(native) = self.stream(OLE10_NATIVE_STREAM) {
+            // NativeDataSize (UINT32) + NativeData
+            let size = native
+                .get(..4)
+                .map(|size| u32::from_le_bytes([size[0], size[1], size[2], size[3]]) as usize)?;
+            let data = native.get(4..)?;
+            return Some(OlePayload {
+                kind: OlePayloadKind::Ole10Native,
+                data: &data[..size.min(data.len())],
+            });
+        }
+        let has_content = self.streams.keys().any(|path| !is_ole_stream(path));
+        has_content.then_some(OlePayload {
+            kind: OlePayloadKind::CompoundFile,
+            data: &self.data,
+        })
+    }
+
+    /// Returns the cached presentations for display.
+    ///
+    /// `\x02OlePres` streams holding a WMF, EMF or DIB come first in stream
+    /// order, followed by the EMF of `\x03EPRINT`.
+    pub fn presentations(&self) -> Vec<OlePresentation<'_>> {
+        let mut presentations: Vec<_> = self
+            .streams
+            .iter()
+            .filter(|(path, _)| path.starts_with(OLE_PRES_PREFIX))
+            .filter_map(|(_, data)| read_presentation(data).ok().flatten())
+            .collect();
+        if let Some(data) = self.stream(EPRINT_STREAM) {
+            // An EMF starts with an EMR_HEADER record
+            if data.starts_with(&[1, 0, 0, 0]) {
+                presentations.push(OlePresentation {
+                    format: OlePresentationFormat::Emf,
+                    width: 0,
+                    height: 0,
+                    data,
+                });
+            }
+        }
+        presentations
+    }
+
+    fn comp_obj(&self) -> Option<CompObj> {
+        self.stream(COMP_OBJ_STREAM)
+            .and_then(|data| CompObj::from_bytes(data).ok())
+    }
+}
+
+/// Returns true for streams used by OLE itself rather than the object.
+fn is_ole_stream(path: &str) -> bool {
+    let name = path.rsplit('/').next().unwrap_or(path);
+    name.starts_with(['\u{1}', '\u{2}', '\u{3}', '\u{5}'])
+}
+
+/// `\x01CompObj` stream (user type, clipboard format and ProgID).
+struct CompObj {
+    user_type: Option<String>,
+    prog_id: Option<String>,
+}
+
+impl CompObj {
+    /// Format:
+    /// - 28 bytes: header
+    /// - LengthPrefixedAnsiString: AnsiUserType
+    /// - ClipboardFormatOrAnsiString: AnsiClipboardFormat
+    /// - LengthPrefixedAnsiString: Reserved1 (ProgID)
+    fn from_bytes(data: &[u8]) -> Result<Self> {
+        let mut reader = ByteReader::new(data);
+        reader.skip(28)?;
+        let user_type = read_ansi_string(&mut reader)?;
+        read_clipboard_format(&mut reader)?;
+        // The ProgID is at most 40 characters; some writers leave it out
+        let prog_id = read_ansi_string(&mut reader)
+            .ok()
+            .flatten()
+            .filter(|prog_id| prog_id.len() <= 40);
+        Ok(Self { user_type, prog_id })
+    }
+}
+
+/// Reads a LengthPrefixedAnsiString (UINT32 length including the NUL).
+fn read_ansi_string(reader: &mut ByteReader) -> Result<Option<String>> {
+    let length = reader.read_u32()? as usize;
+    let bytes = reader.read_bytes(length)?;
+    let bytes = bytes.split(|&b| b == 0).next().unwrap_or_default();
+    Ok((!bytes.is_empty()).then(|| String::from_utf8_lossy(bytes).into_owned()))
+}
+
+/// Reads a ClipboardFormatOrAnsiString and returns the standard clipboard
+/// format, if any.
+fn read_clipboard_format(reader: &mut ByteReader) -> Result<Option<u32>> {
+    match reader.read_u32()? {
+        0 => Ok(None),
+        0xFFFF_FFFF | 0xFFFF_FFFE => Ok(Some(reader.read_u32()?)),
+        length => {
+            reader.skip(length as usize)?;
+            Ok(None)
+        }
+    }
+}
+
+/// Reads an OLEPresentationStream.
+///
+/// Format:
+/// - ClipboardFormatOrAnsiString: AnsiClipboardFormat
+/// - UINT32: TargetDeviceSize (4 if there is no target device)
+/// - TargetDeviceSize - 4 bytes: TargetDevice
+/// - UINT32: Aspect, Lindex, Advf, Reserved1
+/// - UINT32: Width, Height (HIMETRIC)
+/// - UINT32: Size, then Size bytes of data
+fn read_presentation(data: &[u8]) -> Result<Option<OlePresentation<'_>>> {
+    let mut reader = ByteReader::new(data);
+    let format = match read_clipboard_format(&mut reader)? {
+        Some(CF_METAFILEPICT) => OlePresentationFormat::Wmf,
+        Some(CF_ENHMETAFILE) => OlePresentationFormat::Emf,
+        Some(CF_DIB) => OlePresentationFormat::Dib,
+        _ => return Ok(None),
+    };
+    let target_device_size = reader.read_u32()? as usize;
+    reader.skip(target_device_size.saturating_sub(4))?;
+    reader.skip(16)?;
+    let width = reader.read_u32()?;
+    let height = reader.read_u32()?;
+    let size = reader.read_u32()? as usize;
+    let data = reader.read_bytes(size)?;
+    Ok(Some(OlePresentation {
+        format,
+        width,
+        height,
+        data,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use super::*;
+
+    fn storage(streams: &[(&str, &[u8])]) -> Vec<u8> {
+        let mut file = CompoundFile::create(Cursor::new(Vec::new())).unwrap();
+        for (path, data) in streams {
+            if let Some((parent, _)) = path.rsplit_once('/').filter(|(p, _)| !p.is_empty()) {
+                file.create_storage_all(parent).unwrap();
+            }
+            file.create_stream(path).unwrap().write_all(data).unwrap();
+        }
+        file.into_inner().into_inner()
+    }
+
+    fn comp_obj(user_type: &str, prog_id: &str) -> Vec<u8> {
+        let mut data = vec![0; 28];
+        for text in [user_type, "", prog_id] {
+            if text.is_empty() {
+                data.extend_from_slice(&0u32.to_le_bytes());
+                continue;
+            }
+            data.extend_from_slice(&(text.len() as u32 + 1).to_le_bytes());
+            data.extend_from_slice(text.as_bytes());
+            data.push(0);
+        }
+        data
+    }
+
+    fn ole_pres(format: u32, data: &[u8]) -> Vec<u8> {
+        let mut pres = Vec::new();
+        for value in [0xFFFF_FFFF, format, 4, 1, 0xFFFF_FFFF, 2, 0, 2540, 1270] {
+            pres.extend_from_slice(&u32::to_le_bytes(value));
+        }
+        pres.extend_from_slice(&(data.len() as u32).to_le_bytes());
+        pres.extend_from_slice(data);
+        pres
+    }
+
+    #[test]
+    fn test_embedded_workbook() {
+        let data = storage(&[
+            (
+                "/\u{1}CompObj",
+                &comp_obj("Microsoft Excel Worksheet", "Excel.Sheet.12"),
+            ),
+            ("/Package", b"PK\x03\x04xlsx"),
+            ("/\u{2}OlePres000", &ole_pres(CF_METAFILEPICT, b"wmf")),
+            ("/\u{3}EPRINT", &[1, 0, 0, 0, 9]),
+        ]);
+        let ole = OleStorage::from_bytes(&data).unwrap();
+
+        assert_eq!(
+            ole.user_type().as_deref(),
+            Some("Microsoft Excel Worksheet")
+        );
+        assert_eq!(ole.prog_id().as_deref(), Some("Excel.Sheet.12"));
+
+        let payload = ole.native_payload().unwrap();
+        assert_eq!(payload.kind, OlePayloadKind::Package);
+        assert_eq!(payload.data, b"PK\x03\x04xlsx");
+        assert_eq!(payload.extension(ole.prog_id().as_deref()), Some("xlsx"));
+
+        let presentations = ole.presentations();
+        assert_eq!(presentations.len(), 2);
+        assert_eq!(presentations[0].format, OlePresentationFormat::Wmf);
+        assert_eq!(
+            (presentations[0].width, presentations[0].height),
+            (2540, 1270)
+        );
+        assert_eq!(presentations[0].data, b"wmf");
+        assert_eq!(presentations[1].format, OlePresentationFormat::Emf);
+    }
+
+    #[test]
+    fn test_entries_and_size_prefix() {
+        let inner = storage(&[("/Sub/Data", b"abc"), ("/Top", b"")]);
+        let mut data = (inner.len() as u32).to_le_bytes().to_vec();
+        data.extend_from_slice(&inner);
+        let ole = OleStorage::from_bytes(&data).unwrap();
+
+        assert_eq!(ole.as_bytes(), inner.as_slice());
+        assert!(ole.entries().contains(&OleEntry {
+            path: "/Sub".to_string(),
+            kind: OleEntryKind::Storage,
+            size: 0,
+        }));
+        assert!(ole.entries().contains(&OleEntry {
+            path: "/Sub/Data".to_string(),
+            kind: OleEntryKind::Stream,
+            size: 3,
+        }));
+        assert_eq!(ole.stream("Sub/Data"), Some(&b"abc"[..]));
+        assert_eq!(ole.class_id(), None);
+    }
+
+    #[test]
+    fn test_stream_limits() {
+        let data = storage(&[("/First", &[1; 600]), ("/Second", &[2; 600])]);
+        let exceeded = |limits: Limits| match OleStorage::from_bytes_with_limits(&data, &limits) {
+            Err(crate::Error::LimitExceeded { kind, .. }) => Some(kind),
+            _ => None,
+        };
+
+        assert_eq!(
+            exceeded(Limits::new().with_max_stream_size(599)),
+            Some(LimitKind::StreamSize)
+        );
+        assert_eq!(
+            exceeded(Limits::new().with_max_total_size(1199)),
+            Some(LimitKind::TotalSize)
+        );
+        let limits = Limits::new()
+            .with_max_stream_size(600)
+            .with_max_total_size(1200);
+        let ole = OleStorage::from_bytes_with_limits(&data, &limits).unwrap();
+        assert_eq!(ole.stream("/Second").map(<[u8]>::len), Some(600));
+    }
+
+    #[test]
+    fn test_embedded_hwp_document() {
+        let data = storage(&[("/FileHeader", b"HWP Document File"), ("/DocInfo", b"")]);
+        let ole = OleStorage::from_bytes(&data).unwrap();
+
+        let payload = ole.native_payload().unwrap();
+        assert_eq!(payload.kind, OlePayloadKind::CompoundFile);
+        assert_eq!(payload.extension(None), Some("hwp"));
+    }
+
+    #[test]
+    fn test_ole10_native_payload() {
+        let mut native = 4u32.to_le_bytes().to_vec();
+        native.extend_from_slice(b"%PDFtrailing");
+        let data = storage(&[("/\u{1}Ole10Native", &native)]);
+        let ole = OleStorage::from_bytes(&data).unwrap();
+
+        let payload = ole.native_payload().unwrap();
+        assert_eq!(payload.kind, OlePayloadKind::Ole10Native);
+        assert_eq!(payload.data, b"%PDF");
+        assert_eq!(payload.extension(None), Some("pdf"));
+    }
+
+    #[test]
+    fn test_only_ole_streams_have_no_payload() {
+        let data = storage(&[("/\u{1}CompObj", &comp_obj("Object", ""))]);
+        let ole = OleStorage::from_bytes(&data).unwrap();
+
+        assert!(ole.native_payload().is_none());
+        assert_eq!(ole.prog_id(), None);
+    }
+}
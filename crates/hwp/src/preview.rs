@@ -75,6 +75,15 @@ impl PreviewText {
 
         Ok(Self { text })
     }
+
+    /// Encodes the text as null-terminated UTF-16LE bytes.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.text
+            .encode_utf16()
+            .chain(std::iter::once(0))
+            .flat_map(u16::to_le_bytes)
+            .collect()
+    }
 }
 
 #[cfg(test)]
@@ -88,7 +97,7 @@ mod tests {
             PreviewFormat::Png
         );
         assert_eq!(
-            PreviewFormat::detect(&[b'G', b'I', b'F', b'8', b'9', b'a']),
+            PreviewFormat::detect(b"GIF89a"),
             PreviewFormat::Gif
         );
         assert_eq!(
@@ -115,4 +124,14 @@ mod tests {
         let preview = PreviewText::from_bytes(&data).unwrap();
         assert_eq!(preview.text, "Hello");
     }
+
+    #[test]
+    fn test_preview_text_round_trip() {
+        let preview = PreviewText {
+            text: "미리보기\r\n".to_string(),
+        };
+        let data = preview.to_bytes();
+        assert_eq!(&data[data.len() - 2..], &[0, 0]);
+        assert_eq!(PreviewText::from_bytes(&data).unwrap().text, preview.text);
+    }
 }
@@ -0,0 +1,655 @@
+//! Lazy HWP document reader.
+//!
+//! [`HwpReader`] keeps the compound file open and parses only the file header
+//! and DocInfo up front. Sections and binary streams are read on demand, so a
+//! document full of large images never has to sit in memory all at once.
+
+use std::io::{Read, Seek};
+
+use cfb::CompoundFile;
+
+use crate::body::Section;
+use crate::crypto::{decrypt_distribution_stream, decrypt_password_stream};
+use crate::diagnostic::{ParseDiagnostic, ParseOptions};
+use crate::doc_info::DocInfo;
+use crate::doc_options::{DocOptions, DrmLicense, LinkDoc};
+use crate::document::{HwpDocument, ReadOptions};
+use crate::error::{Error, Result};
+use crate::header::FileHeader;
+use crate::preview::{PreviewImage, PreviewText};
+use crate::script::{ScriptVersion, Scripts};
+use crate::summary::SummaryInfo;
+use crate::util::decompress_stream;
+use primitive::{LimitKind, Limits, Version};
+
+/// An open HWP 5.0 document whose streams are read on demand.
+///
+/// Created by [`HwpDocument::open`], [`HwpDocument::open_with_password`] or
+/// [`HwpDocument::open_with_options`].
+///
+/// # Example
+///
+/// ```ignore
+/// use hwp::HwpDocument;
+///
+/// let file = std::fs::File::open("document.hwp")?;
+/// let mut reader = HwpDocument::open(std::io::BufReader::new(file))?;
+///
+/// for section in reader.sections() {
+///     println!("{}", section?.plain_text());
+/// }
+/// for id in reader.binary_data_ids() {
+///     if let Some(data) = reader.read_binary_data(id)? {
+///         std::fs::write(format!("image_{}.bin", id), data)?;
+///     }
+/// }
+/// ```
+#[derive(Debug)]
+pub struct HwpReader<R> {
+    cfb: CompoundFile<R>,
+    /// File header.
+    header: FileHeader,
+    /// Document information.
+    doc_info: DocInfo,
+    /// Password for encrypted documents.
+    password: Option<String>,
+    /// Whether to keep the original records of parsed streams.
+    retain_records: bool,
+    /// How to handle malformed records and streams.
+    parse_options: ParseOptions,
+    /// Parts skipped so far in lenient mode.
+    diagnostics: Vec<ParseDiagnostic>,
+    /// Resource limits.
+    limits: Limits,
+    /// Bytes of decoded stream data read so far.
+    total_size: u64,
+}
+
+impl<R: Read + Seek> HwpReader<R> {
+    /// Opens the compound file and parses the file header and DocInfo.
+    pub(crate) fn new(reader: R, options: &ReadOptions) -> Result<Self> {
+        let password = options.password.as_deref();
+        let mut cfb = CompoundFile::open(reader)?;
+
+        let header = Self::read_file_header(&mut cfb)?;
+
+        // Check for encryption
+        if header.properties().is_encrypted() && password.is_none() {
+            return Err(Error::EncryptedDocument);
+        }
+
+        let mut reader = Self {
+            cfb,
+            header,
+            doc_info: DocInfo::default(),
+            password: password.map(str::to_string),
+            retain_records: options.retain_records,
+            parse_options: options.parse,
+            diagnostics: Vec::new(),
+            limits: options.limits,
+            total_size: 0,
+        };
+        let data = reader.read_doc_info_stream()?;
+        reader.doc_info = DocInfo::parse(
+            &data,
+            "/DocInfo",
+            &reader.parse_options,
+            &reader.limits,
+            &mut reader.diagnostics,
+        )?;
+        if reader.retain_records {
+            reader.doc_info.raw_records = Some(data);
+        }
+        Ok(reader)
+    }
+
+    fn read_file_header(cfb: &mut CompoundFile<R>) -> Result<FileHeader> {
+        let mut stream = cfb.open_stream("/FileHeader")?;
+        let mut data = vec![0u8; FileHeader::SIZE];
+        stream.read_exact(&mut data)?;
+        FileHeader::from_bytes(&data)
+    }
+
+    /// Reads a whole stream, failing if it is larger than the `kind` limit.
+    fn read_stream(&mut self, name: &str, kind: LimitKind) -> Result<Vec<u8>> {
+        let mut stream = self.cfb.open_stream(name)?;
+        self.limits.check(kind, stream.len())?;
+        let mut data = Vec::new();
+        stream.read_to_end(&mut data)?;
+        Ok(data)
+    }
+
+    /// Reads a whole stream, returning `None` if it does not exist.
+    fn read_optional_stream(&mut self, name: &str, kind: LimitKind) -> Result<Option<Vec<u8>>> {
+        if !self.cfb.is_stream(name) {
+            return Ok(None);
+        }
+        self.read_stream(name, kind).map(Some)
+    }
+
+    /// Adds `len` decoded bytes to the running total.
+    fn add_total_size(&mut self, len: usize) -> Result<()> {
+        self.total_size = self.total_size.saturating_add(len as u64);
+        self.limits.check(LimitKind::TotalSize, self.total_size)?;
+        Ok(())
+    }
+
+    /// Decompresses and decrypts a DocInfo or section stream.
+    ///
+    /// `view_text` marks the ViewText sections of a distribution document,
+    /// which are encrypted after compression.
+    fn decode_stream(&mut self, data: Vec<u8>, view_text: bool) -> Result<Vec<u8>> {
+        let data = if view_text {
+            decrypt_distribution_stream(&data)?
+        } else {
+            data
+        };
+
+        // Decompress if needed
+        let decompressed = if self.header.properties().is_compressed() {
+            decompress_stream(&data, self.limits.max_stream_size, LimitKind::StreamSize)?
+        } else {
+            data
+        };
+        self.add_total_size(decompressed.len())?;
+
+        // Decrypt if encrypted
+        if self.header.is_encrypted() {
+            let Some(password) = self.password.as_deref() else {
+                return Err(Error::EncryptedDocument);
+            };
+            let version = self.header.encryption_version();
+            decrypt_password_stream(&decompressed, version, password)
+        } else {
+            Ok(decompressed)
+        }
+    }
+
+    /// Returns the file header.
+    pub const fn header(&self) -> &FileHeader {
+        &self.header
+    }
+
+    /// Returns the document information.
+    pub const fn doc_info(&self) -> &DocInfo {
+        &self.doc_info
+    }
+
+    /// Returns the HWP version.
+    pub const fn version(&self) -> Version {
+        self.header.version()
+    }
+
+    /// Returns true if this is a distribution document.
+    pub const fn is_distribution_document(&self) -> bool {
+        self.header.is_distribution()
+    }
+
+    /// Returns true if this is an encrypted document.
+    pub const fn is_encrypted(&self) -> bool {
+        self.header.is_encrypted()
+    }
+
+    /// Consumes the reader, returning the underlying reader.
+    pub fn into_inner(self) -> R {
+        self.cfb.into_inner()
+    }
+
+    /// Returns the parts skipped so far in lenient mode.
+    ///
+    /// Grows as sections and optional streams are read.
+    pub fn diagnostics(&self) -> &[ParseDiagnostic] {
+        &self.diagnostics
+    }
+
+    /// Records a skipped part, or returns its error in strict mode.
+    pub(crate) fn recover(&mut self, diagnostic: ParseDiagnostic) -> Result<()> {
+        self.parse_options
+            .recover(&mut self.diagnostics, diagnostic)
+    }
+
+    /// Consumes the reader, returning the parsed file header, DocInfo and diagnostics.
+    pub(crate) fn into_parts(self) -> (FileHeader, DocInfo, Vec<ParseDiagnostic>, Limits) {
+        (self.header, self.doc_info, self.diagnostics, self.limits)
+    }
+
+    /// Reads every stream and returns the fully loaded document.
+    pub fn into_document(self) -> Result<HwpDocument> {
+        HwpDocument::load(self)
+    }
+
+    // === Sections ===
+
+    /// Returns the number of sections declared in DocInfo.
+    pub fn section_count(&self) -> usize {
+        self.doc_info
+            .document_properties
+            .as_ref()
+            .map(|p| p.section_count() as usize)
+            .unwrap_or(1)
+    }
+
+    /// Reads and parses a single section.
+    ///
+    /// With [`ReadOptions::retain_records`] the section keeps its original records.
+    /// Records skipped in lenient mode are added to [`HwpReader::diagnostics`].
+    ///
+    /// Returns [`Error::MissingStream`] if the section stream does not exist.
+    pub fn read_section(&mut self, index: usize) -> Result<Section> {
+        let data = self.read_section_stream(index)?;
+        let mut section = Section::parse(
+            &data,
+            &self.section_stream_name(index),
+            &self.parse_options,
+            &self.limits,
+            &mut self.diagnostics,
+        )?;
+        if self.retain_records {
+            section.retain_records(&data);
+        }
+        Ok(section)
+    }
+
+    /// Returns an iterator that reads the sections one at a time.
+    ///
+    /// Iteration stops early if the document has fewer section streams than declared.
+    pub fn sections(&mut self) -> Sections<'_, R> {
+        Sections {
+            count: self.section_count(),
+            reader: self,
+            index: 0,
+        }
+    }
+
+    // === Raw Streams ===
+
+    /// Reads the DocInfo stream, decompressed and decrypted.
+    ///
+    /// The result can be walked with [`RecordIter`](crate::record::RecordIter) or
+    /// [`RecordTree`](crate::record::RecordTree).
+    pub fn read_doc_info_stream(&mut self) -> Result<Vec<u8>> {
+        let data = self.read_stream("/DocInfo", LimitKind::StreamSize)?;
+        self.decode_stream(data, false)
+    }
+
+    /// Reads a section stream, decompressed and decrypted.
+    ///
+    /// Returns [`Error::MissingStream`] if the section stream does not exist.
+    pub fn read_section_stream(&mut self, index: usize) -> Result<Vec<u8>> {
+        let stream_name = self.section_stream_name(index);
+
+        let Some(data) = self.read_optional_stream(&stream_name, LimitKind::StreamSize)? else {
+            return Err(Error::MissingStream { name: stream_name });
+        };
+        let view_text = self.header.is_distribution();
+        self.decode_stream(data, view_text)
+    }
+
+    /// Returns the path of a section stream.
+    pub(crate) fn section_stream_name(&self, index: usize) -> String {
+        if self.header.is_distribution() {
+            format!("/ViewText/Section{}", index)
+        } else {
+            format!("/BodyText/Section{}", index)
+        }
+    }
+
+    // === Binary Data ===
+
+    /// Returns the IDs of binary data items that are stored in the BinData storage.
+    ///
+    /// Binary data IDs are 1-based and correspond to items in the DocInfo BinaryData list.
+    pub fn binary_data_ids(&self) -> Vec<u16> {
+        self.doc_info
+            .binary_data
+            .iter()
+            .enumerate()
+            .filter(|(_, info)| info.stream_name().is_some())
+            .map(|(index, _)| (index + 1) as u16)
+            .collect()
+    }
+
+    /// Reads a binary data stream by its ID.
+    ///
+    /// Returns `None` if the item has no stream or the stream is missing or empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::LimitExceeded`] if the stream is larger than
+    /// [`Limits::max_binary_data_size`] or the total size limit is reached.
+    pub fn read_binary_data(&mut self, id: u16) -> Result<Option<Vec<u8>>> {
+        let Some(info) = (id as usize)
+            .checked_sub(1)
+            .and_then(|index| self.doc_info.binary_data.get(index))
+        else {
+            return Ok(None);
+        };
+        let Some(stream_name) = info.stream_name() else {
+            return Ok(None);
+        };
+        let compressed = info
+            .compression()
+            .is_compressed(self.header.properties().is_compressed());
+
+        let name = format!("/BinData/{}", stream_name);
+        let Some(data) = self.read_optional_stream(&name, LimitKind::BinaryDataSize)? else {
+            return Ok(None);
+        };
+        if data.is_empty() {
+            return Ok(None);
+        }
+        // Decompress if needed (per-item mode, defaulting to the storage setting)
+        let data = if compressed {
+            let limit = self.limits.max_binary_data_size;
+            match decompress_stream(&data, limit, LimitKind::BinaryDataSize) {
+                Ok(decompressed) => decompressed,
+                Err(err @ Error::LimitExceeded { .. }) => return Err(err),
+                // Fall back to raw data if decompression fails
+                Err(_) => data,
+            }
+        } else {
+            data
+        };
+        self.add_total_size(data.len())?;
+        Ok(Some(data))
+    }
+
+    /// Returns an iterator that reads the binary data streams one at a time.
+    ///
+    /// Items without a readable stream are skipped.
+    pub fn binary_data(&mut self) -> BinaryDataIter<'_, R> {
+        BinaryDataIter {
+            ids: self.binary_data_ids().into_iter(),
+            reader: self,
+        }
+    }
+
+    // === Optional Streams ===
+
+    /// Reads an optional stream that is stored as is, counting it toward the total size.
+    fn read_counted_stream(&mut self, name: &str) -> Result<Option<Vec<u8>>> {
+        let data = self.read_optional_stream(name, LimitKind::StreamSize)?;
+        if let Some(data) = &data {
+            self.add_total_size(data.len())?;
+        }
+        Ok(data)
+    }
+
+    /// Reads the preview text from the PrvText stream.
+    pub fn read_preview_text(&mut self) -> Option<PreviewText> {
+        self.try_read_preview_text().ok().flatten()
+    }
+
+    /// Reads the preview text, failing if the stream exists but cannot be parsed.
+    pub(crate) fn try_read_preview_text(&mut self) -> Result<Option<PreviewText>> {
+        let Some(data) = self.read_counted_stream("/PrvText")? else {
+            return Ok(None);
+        };
+        PreviewText::from_bytes(&data).map(Some)
+    }
+
+    /// Reads the preview image from the PrvImage stream.
+    pub fn read_preview_image(&mut self) -> Option<PreviewImage> {
+        let data = self.read_counted_stream("/PrvImage").ok().flatten()?;
+        Some(PreviewImage::from_bytes(data))
+    }
+
+    /// Reads summary information from the \005HwpSummaryInformation stream.
+    pub fn read_summary_info(&mut self) -> Option<SummaryInfo> {
+        self.try_read_summary_info().ok().flatten()
+    }
+
+    /// Reads summary information, failing if the stream exists but cannot be parsed.
+    pub(crate) fn try_read_summary_info(&mut self) -> Result<Option<SummaryInfo>> {
+        // OLE property stream name starts with \005
+        let Some(data) = self.read_counted_stream("/\x05HwpSummaryInformation")? else {
+            return Ok(None);
+        };
+        SummaryInfo::from_bytes(&data).map(Some)
+    }
+
+    /// Reads scripts from the Scripts storage.
+    pub fn read_scripts(&mut self) -> Option<Scripts> {
+        self.try_read_scripts().ok()
+    }
+
+    /// Reads scripts, failing if a script stream exists but cannot be parsed.
+    pub(crate) fn try_read_scripts(&mut self) -> Result<Scripts> {
+        let mut scripts = Scripts::default();
+
+        // Read JScriptVersion
+        if let Some(data) = self.read_script_stream("/Scripts/JScriptVersion")? {
+            scripts.version = ScriptVersion::from_bytes(&data)?;
+        }
+
+        // Read DefaultJScript
+        if let Some(data) = self.read_script_stream("/Scripts/DefaultJScript")? {
+            scripts = Scripts::from_bytes(scripts.version, &data)?;
+        }
+
+        Ok(scripts)
+    }
+
+    /// Reads a stream of the Scripts storage.
+    ///
+    /// Script streams follow the storage compression setting and are
+    /// encrypted in distribution documents.
+    fn read_script_stream(&mut self, name: &str) -> Result<Option<Vec<u8>>> {
+        let Some(data) = self.read_optional_stream(name, LimitKind::StreamSize)? else {
+            return Ok(None);
+        };
+        let data = if self.header.is_distribution() {
+            decrypt_distribution_stream(&data)?
+        } else {
+            data
+        };
+        let data = if self.header.properties().is_compressed() {
+            let limit = self.limits.max_stream_size;
+            match decompress_stream(&data, limit, LimitKind::StreamSize) {
+                Ok(decompressed) => decompressed,
+                Err(err @ Error::LimitExceeded { .. }) => return Err(err),
+                // Some writers store scripts uncompressed
+                Err(_) => data,
+            }
+        } else {
+            data
+        };
+        self.add_total_size(data.len())?;
+        Ok(Some(data))
+    }
+
+    /// Reads document options from the DocOptions storage.
+    pub fn read_doc_options(&mut self) -> Option<DocOptions> {
+        self.try_read_doc_options().ok()
+    }
+
+    /// Reads document options, failing if a stream exists but cannot be parsed.
+    pub(crate) fn try_read_doc_options(&mut self) -> Result<DocOptions> {
+        let mut doc_options = DocOptions::new();
+
+        // Read _LinkDoc
+        if let Some(data) = self.read_counted_stream("/DocOptions/_LinkDoc")? {
+            doc_options.set_link_doc(LinkDoc::from_bytes(&data)?);
+        }
+
+        // Read DrmLicense
+        if let Some(data) = self.read_counted_stream("/DocOptions/DrmLicense")? {
+            doc_options.set_drm_license(DrmLicense::from_bytes(&data)?);
+        }
+
+        // Read DrmRootSect
+        if let Some(data) = self.read_counted_stream("/DocOptions/DrmRootSect")? {
+            doc_options.set_drm_root_sect(data);
+        }
+
+        // Read CertDrmHeader
+        if let Some(data) = self.read_counted_stream("/DocOptions/CertDrmHeader")? {
+            doc_options.set_cert_drm_header(data);
+        }
+
+        Ok(doc_options)
+    }
+}
+
+/// Iterator over the sections of an [`HwpReader`].
+///
+/// Created by [`HwpReader::sections`].
+#[derive(Debug)]
+pub struct Sections<'a, R> {
+    reader: &'a mut HwpReader<R>,
+    index: usize,
+    count: usize,
+}
+
+impl<R: Read + Seek> Iterator for Sections<'_, R> {
+    type Item = Result<Section>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.count {
+            return None;
+        }
+        let result = self.reader.read_section(self.index);
+        self.index += 1;
+        match result {
+            // Some documents may have fewer sections than declared
+            Err(Error::MissingStream { .. }) => {
+                self.index = self.count;
+                None
+            }
+            result => Some(result),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.count - self.index))
+    }
+}
+
+/// Iterator over the binary data streams of an [`HwpReader`].
+///
+/// Created by [`HwpReader::binary_data`]. Yields `(id, data)` pairs.
+#[derive(Debug)]
+pub struct BinaryDataIter<'a, R> {
+    reader: &'a mut HwpReader<R>,
+    ids: std::vec::IntoIter<u16>,
+}
+
+impl<R: Read + Seek> Iterator for BinaryDataIter<'_, R> {
+    type Item = Result<(u16, Vec<u8>)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for id in self.ids.by_ref() {
+            match self.reader.read_binary_data(id) {
+                Ok(Some(data)) => return Some(Ok((id, data))),
+                Ok(None) => continue,
+                Err(err) => return Some(Err(err)),
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use ir::{
+        BinaryData, BinaryDataId, BinaryFormat, Document as IrDocument, Paragraph,
+        Section as IrSection,
+    };
+
+    use super::*;
+    use crate::convert::IrToHwp;
+    use crate::record::{RecordTagId, RecordTree};
+
+    const PNG: &[u8] = &[0x89, 0x50, 0x4E, 0x47, 0x0D, 0x0A, 0x1A, 0x0A, 0x00, 0x01];
+
+    fn sample_bytes() -> Vec<u8> {
+        let mut ir = IrDocument::new();
+        for text in ["first", "second"] {
+            let mut section = IrSection::default();
+            section.paragraphs.push(Paragraph::with_text(text));
+            ir.add_section(section);
+        }
+        ir.binary_data.add(
+            BinaryDataId::new("1"),
+            BinaryData::new(BinaryFormat::Png, PNG.to_vec()),
+        );
+        ir.to_hwp_bytes().unwrap().value
+    }
+
+    #[test]
+    fn test_sections_on_demand() {
+        let bytes = sample_bytes();
+        let mut reader = HwpDocument::open(Cursor::new(&bytes)).unwrap();
+        assert_eq!(reader.section_count(), 2);
+
+        let second = reader.read_section(1).unwrap();
+        assert_eq!(second.plain_text().trim_end(), "second");
+
+        let texts: Vec<_> = reader
+            .sections()
+            .map(|section| section.unwrap().plain_text().trim_end().to_string())
+            .collect();
+        assert_eq!(texts, ["first", "second"]);
+        assert!(matches!(
+            reader.read_section(2),
+            Err(Error::MissingStream { .. })
+        ));
+    }
+
+    #[test]
+    fn test_binary_data_on_demand() {
+        let bytes = sample_bytes();
+        let mut reader = HwpDocument::open(Cursor::new(&bytes)).unwrap();
+
+        assert_eq!(reader.binary_data_ids(), [1]);
+        assert_eq!(reader.read_binary_data(1).unwrap().as_deref(), Some(PNG));
+        assert_eq!(reader.read_binary_data(0).unwrap(), None);
+        assert_eq!(reader.read_binary_data(2).unwrap(), None);
+
+        let items: Vec<_> = reader.binary_data().map(Result::unwrap).collect();
+        assert_eq!(items, [(1, PNG.to_vec())]);
+    }
+
+    #[test]
+    fn test_raw_streams() {
+        let bytes = sample_bytes();
+        let mut reader = HwpDocument::open(Cursor::new(&bytes)).unwrap();
+
+        let doc_info = reader.read_doc_info_stream().unwrap();
+        let tree = RecordTree::parse(&doc_info).unwrap();
+        assert_eq!(
+            tree.roots[0].record.tag_id(),
+            Some(RecordTagId::DocumentProperties)
+        );
+
+        let section = reader.read_section_stream(0).unwrap();
+        let tree = RecordTree::parse(&section).unwrap();
+        let paragraph = tree
+            .roots
+            .iter()
+            .find(|node| node.record.tag_id() == Some(RecordTagId::ParagraphHeader))
+            .unwrap();
+        assert!(
+            paragraph
+                .children
+                .iter()
+                .any(|child| child.record.tag_id() == Some(RecordTagId::ParagraphText))
+        );
+    }
+
+    #[test]
+    fn test_into_document_matches_from_bytes() {
+        let bytes = sample_bytes();
+        let eager = HwpDocument::from_bytes(&bytes).unwrap();
+        let lazy = HwpDocument::open(Cursor::new(&bytes))
+            .unwrap()
+            .into_document()
+            .unwrap();
+
+        assert_eq!(lazy.extract_text(), eager.extract_text());
+        assert_eq!(lazy.section_count(), 2);
+        assert_eq!(lazy.get_binary_data(1), Some(PNG));
+    }
+}
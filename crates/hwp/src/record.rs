@@ -0,0 +1,366 @@
+//! Raw record access.
+//!
+//! DocInfo and BodyText streams are sequences of tagged records. The parsers in
+//! this crate only keep what they understand, so this module exposes the raw
+//! records of any decompressed and decrypted stream for inspection and diffing.
+//!
+//! Use [`HwpReader::read_doc_info_stream`](crate::HwpReader::read_doc_info_stream) or
+//! [`HwpReader::read_section_stream`](crate::HwpReader::read_section_stream) to get
+//! the decoded stream bytes.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use hwp::HwpDocument;
+//! use hwp::record::RecordTree;
+//!
+//! let mut reader = HwpDocument::open(std::fs::File::open("document.hwp")?)?;
+//! let data = reader.read_section_stream(0)?;
+//! print!("{}", RecordTree::parse(&data)?);
+//! ```
+
+use std::fmt;
+use std::ops::Range;
+
+use crate::error::{Error, Result};
+pub use crate::primitive::{RecordHeader, RecordTagId};
+
+/// A single record in a stream.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Record<'a> {
+    header: RecordHeader,
+    offset: usize,
+    data: &'a [u8],
+}
+
+impl<'a> Record<'a> {
+    /// Returns the record header.
+    #[inline]
+    pub const fn header(&self) -> RecordHeader {
+        self.header
+    }
+
+    /// Returns the raw tag ID (0-1023).
+    #[inline]
+    pub const fn tag_id_raw(&self) -> u16 {
+        self.header.tag_id_raw()
+    }
+
+    /// Returns the tag ID as enum, if recognized.
+    #[inline]
+    pub const fn tag_id(&self) -> Option<RecordTagId> {
+        self.header.tag_id()
+    }
+
+    /// Returns the hierarchy level.
+    #[inline]
+    pub const fn level(&self) -> u16 {
+        self.header.level()
+    }
+
+    /// Returns the payload size in bytes.
+    #[inline]
+    pub const fn size(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns the byte offset of the record header within the stream.
+    #[inline]
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the payload bytes.
+    #[inline]
+    pub const fn data(&self) -> &'a [u8] {
+        self.data
+    }
+
+    /// Returns the byte offset just past the payload.
+    #[inline]
+    pub const fn end(&self) -> usize {
+        let header_size = if self.header.has_extended_size() {
+            8
+        } else {
+            4
+        };
+        self.offset + header_size + self.data.len()
+    }
+}
+
+impl fmt::Display for Record<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.tag_id() {
+            Some(tag) => write!(f, "{} (0x{:03X})", tag, self.tag_id_raw())?,
+            None => write!(f, "Unknown (0x{:03X})", self.tag_id_raw())?,
+        }
+        write!(
+            f,
+            " level {}, {} bytes at 0x{:X}",
+            self.level(),
+            self.size(),
+            self.offset
+        )
+    }
+}
+
+/// Iterator over the records of a decoded stream, in stream order.
+///
+/// Yields an error and stops if a record header or payload is truncated.
+#[derive(Debug, Clone)]
+pub struct RecordIter<'a> {
+    data: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> RecordIter<'a> {
+    /// Creates an iterator over the records in `data`.
+    pub const fn new(data: &'a [u8]) -> Self {
+        Self { data, offset: 0 }
+    }
+
+    fn read_u32(&self, at: usize) -> Result<u32> {
+        let bytes = self
+            .data
+            .get(at..at + 4)
+            .ok_or(Error::UnexpectedEndOfData {
+                expected: 4,
+                actual: self.data.len().saturating_sub(at),
+            })?;
+        Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    fn read_record(&self) -> Result<(Record<'a>, usize)> {
+        let raw = self.read_u32(self.offset)?;
+        let mut header = RecordHeader::new(raw);
+        let mut start = self.offset + 4;
+        if header.has_extended_size() {
+            header = RecordHeader::with_extended_size(raw, self.read_u32(start)?);
+            start += 4;
+        }
+
+        let size = header.data_size() as usize;
+        let data = self
+            .data
+            .get(start..start + size)
+            .ok_or(Error::UnexpectedEndOfData {
+                expected: size,
+                actual: self.data.len() - start,
+            })?;
+        let record = Record {
+            header,
+            offset: self.offset,
+            data,
+        };
+        Ok((record, start + size))
+    }
+}
+
+impl<'a> Iterator for RecordIter<'a> {
+    type Item = Result<Record<'a>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.offset >= self.data.len() {
+            return None;
+        }
+        match self.read_record() {
+            Ok((record, next)) => {
+                self.offset = next;
+                Some(Ok(record))
+            }
+            Err(err) => {
+                self.offset = self.data.len();
+                Some(Err(err))
+            }
+        }
+    }
+}
+
+/// A record together with the records nested under it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordNode<'a> {
+    /// The record itself.
+    pub record: Record<'a>,
+    /// Records at a deeper level that follow this record.
+    pub children: Vec<RecordNode<'a>>,
+}
+
+impl RecordNode<'_> {
+    /// Returns the byte range covered by this record and all of its descendants.
+    pub fn span(&self) -> Range<usize> {
+        let mut last = self;
+        while let Some(child) = last.children.last() {
+            last = child;
+        }
+        self.record.offset()..last.record.end()
+    }
+}
+
+/// Records of a stream arranged by their level field.
+///
+/// A record becomes a child of the closest preceding record with a lower level.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RecordTree<'a> {
+    /// Top-level records.
+    pub roots: Vec<RecordNode<'a>>,
+}
+
+impl<'a> RecordTree<'a> {
+    /// Parses a decoded stream into a record tree.
+    pub fn parse(data: &'a [u8]) -> Result<Self> {
+        let mut tree = Self::default();
+        let mut stack: Vec<RecordNode<'a>> = Vec::new();
+
+        for record in RecordIter::new(data) {
+            let record = record?;
+            while stack
+                .last()
+                .is_some_and(|node| node.record.level() >= record.level())
+            {
+                let node = stack.pop().unwrap();
+                tree.attach(&mut stack, node);
+            }
+            stack.push(RecordNode {
+                record,
+                children: Vec::new(),
+            });
+        }
+        while let Some(node) = stack.pop() {
+            tree.attach(&mut stack, node);
+        }
+
+        Ok(tree)
+    }
+
+    fn attach(&mut self, stack: &mut [RecordNode<'a>], node: RecordNode<'a>) {
+        match stack.last_mut() {
+            Some(parent) => parent.children.push(node),
+            None => self.roots.push(node),
+        }
+    }
+
+    /// Returns all records in stream order with their depth in the tree.
+    pub fn walk(&self) -> Vec<(usize, &Record<'a>)> {
+        fn visit<'t, 'a>(
+            nodes: &'t [RecordNode<'a>],
+            depth: usize,
+            out: &mut Vec<(usize, &'t Record<'a>)>,
+        ) {
+            for node in nodes {
+                out.push((depth, &node.record));
+                visit(&node.children, depth + 1, out);
+            }
+        }
+
+        let mut out = Vec::new();
+        visit(&self.roots, 0, &mut out);
+        out
+    }
+}
+
+impl fmt::Display for RecordTree<'_> {
+    /// Writes one line per record, indented by depth.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (depth, record) in self.walk() {
+            writeln!(f, "{:indent$}{}", "", record, indent = depth * 2)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Encodes a record header and payload.
+    fn record(tag: u16, level: u16, data: &[u8]) -> Vec<u8> {
+        let size = data.len() as u32;
+        let mut bytes = Vec::new();
+        let raw = u32::from(tag) | (u32::from(level) << 10);
+        if size >= RecordHeader::EXTENDED_SIZE_MARKER {
+            bytes.extend((raw | (RecordHeader::EXTENDED_SIZE_MARKER << 20)).to_le_bytes());
+            bytes.extend(size.to_le_bytes());
+        } else {
+            bytes.extend((raw | (size << 20)).to_le_bytes());
+        }
+        bytes.extend(data);
+        bytes
+    }
+
+    #[test]
+    fn test_record_iter() {
+        let large = vec![7u8; 5000];
+        let data = [
+            record(0x042, 0, &[1, 2]),
+            record(0x3FF, 1, &[]),
+            record(0x043, 1, &large),
+        ]
+        .concat();
+
+        let records: Vec<_> = RecordIter::new(&data).map(Result::unwrap).collect();
+        assert_eq!(records.len(), 3);
+        assert_eq!(records[0].tag_id(), Some(RecordTagId::ParagraphHeader));
+        assert_eq!(records[0].data(), &[1, 2]);
+        assert_eq!(records[1].tag_id_raw(), 0x3FF);
+        assert_eq!(records[1].tag_id(), None);
+        assert_eq!(records[1].offset(), 6);
+        assert_eq!(records[2].offset(), 10);
+        assert!(records[2].header().has_extended_size());
+        assert_eq!(records[2].size(), 5000);
+        assert_eq!(records[0].end(), 6);
+        assert_eq!(records[2].end(), data.len());
+    }
+
+    #[test]
+    fn test_record_iter_truncated() {
+        let mut data = record(0x042, 0, &[1, 2, 3, 4]);
+        data.truncate(6);
+
+        let mut iter = RecordIter::new(&data);
+        assert!(matches!(
+            iter.next(),
+            Some(Err(Error::UnexpectedEndOfData {
+                expected: 4,
+                actual: 2
+            }))
+        ));
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn test_record_tree() {
+        let data = [
+            record(0x042, 0, &[]),
+            record(0x043, 1, &[]),
+            record(0x047, 1, &[]),
+            record(0x048, 2, &[]),
+            record(0x042, 3, &[]),
+            record(0x042, 0, &[]),
+        ]
+        .concat();
+
+        let tree = RecordTree::parse(&data).unwrap();
+        assert_eq!(tree.roots.len(), 2);
+        assert_eq!(tree.roots[0].children.len(), 2);
+        assert_eq!(tree.roots[0].children[1].children[0].children.len(), 1);
+        assert!(tree.roots[1].children.is_empty());
+
+        let depths: Vec<_> = tree.walk().iter().map(|(depth, _)| *depth).collect();
+        assert_eq!(depths, [0, 1, 1, 2, 3, 0]);
+
+        assert_eq!(tree.roots[0].span(), 0..20);
+        assert_eq!(tree.roots[0].children[1].span(), 8..20);
+        assert_eq!(tree.roots[1].span(), 20..24);
+    }
+
+    #[test]
+    fn test_record_tree_display() {
+        let data = [record(0x042, 0, &[0; 3]), record(0x200, 1, &[])].concat();
+        let tree = RecordTree::parse(&data).unwrap();
+
+        assert_eq!(
+            tree.to_string(),
+            "ParagraphHeader (0x042) level 0, 3 bytes at 0x0\n  Unknown (0x200) level 1, 0 bytes at 0x7\n"
+        );
+    }
+}
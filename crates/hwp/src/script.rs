@@ -1,131 +1,125 @@
 //! Script parsing.
 //!
 //! HWP documents can contain JavaScript code for forms and automation.
-//! Scripts are stored in the Scripts storage within the compound file.
+//! Scripts are stored in the Scripts storage within the compound file:
+//!
+//! - `JScriptVersion`: script version (two DWORDs)
+//! - `DefaultJScript`: script header, source, pre-source and post-source
+//!
+//! Both streams follow the document's compression setting and are encrypted in
+//! distribution documents.
 
-use crate::error::Result;
+use crate::error::{Error, Result};
+use crate::util::ByteReader;
 
-/// Script version information.
-#[derive(Debug, Clone, Default)]
+/// Script version information (JScriptVersion stream).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
 pub struct ScriptVersion {
-    /// Default JavaScript version.
-    pub default_js_version: String,
-    /// JavaScript version for each file.
-    pub js_versions: Vec<String>,
+    /// High part of the script version.
+    pub high: u32,
+    /// Low part of the script version.
+    pub low: u32,
 }
 
 impl ScriptVersion {
-    /// Parses script version from bytes.
-    pub fn from_bytes(data: &[u8]) -> Result<Self> {
-        let text = decode_utf16_le(data);
-        let mut lines: Vec<&str> = text.lines().collect();
-
-        let default_js_version = if !lines.is_empty() {
-            lines.remove(0).to_string()
-        } else {
-            String::new()
-        };
-
-        let js_versions = lines.iter().map(|s| s.to_string()).collect();
-
-        Ok(Self {
-            default_js_version,
-            js_versions,
-        })
-    }
-}
-
-/// Script header information.
-#[derive(Debug, Clone, Default)]
-pub struct ScriptHeader {
-    /// Number of script files.
-    pub script_count: u32,
-}
+    /// Size of the JScriptVersion stream in bytes.
+    pub const SIZE: usize = 8;
 
-impl ScriptHeader {
-    /// Parses script header from bytes.
+    /// Parses script version from bytes.
     pub fn from_bytes(data: &[u8]) -> Result<Self> {
-        if data.len() < 4 {
-            return Ok(Self::default());
-        }
-
-        let script_count = u32::from_le_bytes([data[0], data[1], data[2], data[3]]);
-
-        Ok(Self { script_count })
-    }
-}
-
-/// A JavaScript source file.
-#[derive(Debug, Clone, Default)]
-pub struct ScriptSource {
-    /// Script file name.
-    pub name: String,
-    /// Script source code.
-    pub code: String,
-}
-
-impl ScriptSource {
-    /// Creates a new script source.
-    pub fn new(name: String, code: String) -> Self {
-        Self { name, code }
-    }
-
-    /// Parses script source from UTF-16 bytes.
-    pub fn from_bytes(name: &str, data: &[u8]) -> Result<Self> {
-        let code = decode_utf16_le(data);
+        let mut reader = ByteReader::new(data);
         Ok(Self {
-            name: name.to_string(),
-            code,
+            high: reader.read_u32()?,
+            low: reader.read_u32()?,
         })
     }
 
-    /// Returns true if the script is empty.
-    pub fn is_empty(&self) -> bool {
-        self.code.is_empty()
+    /// Encodes the script version as stored in the JScriptVersion stream.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::SIZE);
+        data.extend_from_slice(&self.high.to_le_bytes());
+        data.extend_from_slice(&self.low.to_le_bytes());
+        data
     }
 }
 
 /// Collection of scripts in the document.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub struct Scripts {
     /// Script version information.
     pub version: ScriptVersion,
     /// Script header.
-    pub header: ScriptHeader,
-    /// Script source files.
-    pub sources: Vec<ScriptSource>,
+    pub header: String,
+    /// Script source code.
+    pub source: String,
+    /// Source run before the main script.
+    pub pre_source: String,
+    /// Source run after the main script.
+    pub post_source: String,
 }
 
 impl Scripts {
-    /// Returns true if there are no scripts.
-    pub fn is_empty(&self) -> bool {
-        self.sources.is_empty()
+    /// End flag written after the post-source.
+    const END_FLAG: u32 = 0xFFFF_FFFF;
+
+    /// Parses the DefaultJScript stream.
+    pub fn from_bytes(version: ScriptVersion, data: &[u8]) -> Result<Self> {
+        let mut reader = ByteReader::new(data);
+        let header = read_script_string(&mut reader)?;
+        let source = read_script_string(&mut reader)?;
+        let pre_source = read_script_string(&mut reader)?;
+        let post_source = read_script_string(&mut reader)?;
+
+        Ok(Self {
+            version,
+            header,
+            source,
+            pre_source,
+            post_source,
+        })
     }
 
-    /// Returns the number of scripts.
-    pub fn len(&self) -> usize {
-        self.sources.len()
+    /// Encodes the DefaultJScript stream.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut data = Vec::new();
+        for text in [
+            &self.header,
+            &self.source,
+            &self.pre_source,
+            &self.post_source,
+        ] {
+            let units: Vec<u16> = text.encode_utf16().collect();
+            data.extend_from_slice(&(units.len() as u32).to_le_bytes());
+            data.extend(units.iter().flat_map(|unit| unit.to_le_bytes()));
+        }
+        data.extend_from_slice(&Self::END_FLAG.to_le_bytes());
+        data
     }
 
-    /// Returns an iterator over script sources.
-    pub fn iter(&self) -> impl Iterator<Item = &ScriptSource> {
-        self.sources.iter()
+    /// Returns true if there are no scripts.
+    pub fn is_empty(&self) -> bool {
+        self.header.is_empty()
+            && self.source.is_empty()
+            && self.pre_source.is_empty()
+            && self.post_source.is_empty()
     }
 }
 
-/// Decodes UTF-16 little-endian bytes to a String.
-fn decode_utf16_le(bytes: &[u8]) -> String {
-    let mut chars = Vec::with_capacity(bytes.len() / 2);
-    for chunk in bytes.chunks(2) {
-        if chunk.len() == 2 {
-            let code_unit = u16::from_le_bytes([chunk[0], chunk[1]]);
-            if code_unit == 0 {
-                break;
-            }
-            chars.push(code_unit);
-        }
-    }
-    String::from_utf16_lossy(&chars)
+/// Reads a DWORD length followed by that many UTF-16LE code units.
+fn read_script_string(reader: &mut ByteReader) -> Result<String> {
+    let len = reader.read_u32()? as usize;
+    let Some(byte_len) = len.checked_mul(2).filter(|&n| n <= reader.remaining()) else {
+        return Err(Error::UnexpectedEndOfData {
+            expected: len.saturating_mul(2),
+            actual: reader.remaining(),
+        });
+    };
+    let units: Vec<u16> = reader
+        .read_bytes(byte_len)?
+        .chunks_exact(2)
+        .map(|chunk| u16::from_le_bytes([chunk[0], chunk[1]]))
+        .collect();
+    Ok(String::from_utf16_lossy(&units))
 }
 
 #[cfg(test)]
@@ -133,21 +127,34 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_script_header() {
-        let data = [0x02, 0x00, 0x00, 0x00]; // 2 scripts
-        let header = ScriptHeader::from_bytes(&data).unwrap();
-        assert_eq!(header.script_count, 2);
+    fn test_script_version() {
+        let data = [0x01, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00];
+        let version = ScriptVersion::from_bytes(&data).unwrap();
+        assert_eq!(version, ScriptVersion { high: 1, low: 2 });
+        assert_eq!(version.to_bytes(), data);
+    }
+
+    #[test]
+    fn test_scripts_round_trip() {
+        let scripts = Scripts {
+            version: ScriptVersion { high: 1, low: 0 },
+            header: "var Documents = XHwpDocuments;".to_string(),
+            source: "function OnDocument_New() { alert('새 문서'); }".to_string(),
+            pre_source: String::new(),
+            post_source: "// end".to_string(),
+        };
+
+        let data = scripts.to_bytes();
+        assert_eq!(&data[data.len() - 4..], &[0xFF; 4]);
+        let parsed = Scripts::from_bytes(scripts.version, &data).unwrap();
+        assert_eq!(parsed, scripts);
+        assert!(!parsed.is_empty());
     }
 
     #[test]
-    fn test_script_source() {
-        // "alert(1)" in UTF-16LE
-        let data = [
-            0x61, 0x00, 0x6C, 0x00, 0x65, 0x00, 0x72, 0x00, 0x74, 0x00, 0x28, 0x00, 0x31, 0x00,
-            0x29, 0x00,
-        ];
-        let source = ScriptSource::from_bytes("test.js", &data).unwrap();
-        assert_eq!(source.name, "test.js");
-        assert_eq!(source.code, "alert(1)");
+    fn test_scripts_truncated() {
+        // Header length says 4 code units but only 2 bytes follow
+        let data = [0x04, 0x00, 0x00, 0x00, 0x61, 0x00];
+        assert!(Scripts::from_bytes(ScriptVersion::default(), &data).is_err());
     }
 }
@@ -2,6 +2,10 @@
 //!
 //! The Summary information is stored in OLE Document Summary Information
 //! property sets as defined by Microsoft's Compound File format.
+//!
+//! Strings are read as `VT_LPSTR` or `VT_LPWSTR` and written as `VT_LPWSTR`.
+//! Dates are `VT_FILETIME` values, exposed as ISO 8601 UTC strings
+//! (`2024-01-31T09:30:00Z`).
 
 use crate::error::Result;
 
@@ -24,22 +28,40 @@ pub struct SummaryInfo {
     pub last_author: Option<String>,
     /// Application name.
     pub application_name: Option<String>,
-    /// Creation date.
+    /// Creation date (ISO 8601, UTC).
     pub creation_date: Option<String>,
-    /// Last saved date.
+    /// Last saved date (ISO 8601, UTC).
     pub last_saved_date: Option<String>,
 }
 
 impl SummaryInfo {
+    /// FMTID of the HWP summary property set, {9FA2B660-1061-11D4-B4C6-006097C09D8C}.
+    const FMTID: [u8; 16] = [
+        0x60, 0xB6, 0xA2, 0x9F, 0x61, 0x10, 0xD4, 0x11, 0xB4, 0xC6, 0x00, 0x60, 0x97, 0xC0, 0x9D,
+        0x8C,
+    ];
+
     /// OLE property IDs for DocumentSummaryInformation.
+    const PID_CODEPAGE: u32 = 0x01;
     const PIDSI_TITLE: u32 = 0x02;
     const PIDSI_SUBJECT: u32 = 0x03;
     const PIDSI_AUTHOR: u32 = 0x04;
     const PIDSI_KEYWORDS: u32 = 0x05;
     const PIDSI_COMMENTS: u32 = 0x06;
     const PIDSI_LAST_AUTHOR: u32 = 0x08;
+    const PIDSI_CREATE_DTM: u32 = 0x0C;
+    const PIDSI_LASTSAVE_DTM: u32 = 0x0D;
     const PIDSI_APPNAME: u32 = 0x12;
 
+    /// Property types.
+    const VT_I2: u32 = 2;
+    const VT_LPSTR: u32 = 30;
+    const VT_LPWSTR: u32 = 31;
+    const VT_FILETIME: u32 = 64;
+
+    /// UTF-16LE code page.
+    const CODEPAGE_UTF16: u16 = 1200;
+
     /// Parses summary information from bytes.
     ///
     /// The format follows Microsoft's Property Set format:
@@ -124,6 +146,8 @@ impl SummaryInfo {
 
             if let Some(value) = Self::read_property_value(&data[value_offset..]) {
                 match property_id {
+                    Self::PIDSI_CREATE_DTM => info.creation_date = Some(value),
+                    Self::PIDSI_LASTSAVE_DTM => info.last_saved_date = Some(value),
                     Self::PIDSI_TITLE => info.title = Some(value),
                     Self::PIDSI_SUBJECT => info.subject = Some(value),
                     Self::PIDSI_AUTHOR => info.author = Some(value),
@@ -147,11 +171,10 @@ impl SummaryInfo {
         let property_type = u32::from_le_bytes([data[0], data[1], data[2], data[3]]);
 
         match property_type {
-            // VT_LPSTR (30)
-            30 => {
+            Self::VT_LPSTR => {
                 let string_len =
                     u32::from_le_bytes([data[4], data[5], data[6], data[7]]) as usize;
-                if data.len() >= 8 + string_len && string_len > 0 {
+                if data.len() - 8 >= string_len && string_len > 0 {
                     // Try to decode as UTF-8, falling back to latin1
                     let bytes = &data[8..8 + string_len - 1]; // Exclude null terminator
                     Some(String::from_utf8_lossy(bytes).into_owned())
@@ -159,12 +182,12 @@ impl SummaryInfo {
                     None
                 }
             }
-            // VT_LPWSTR (31)
-            31 => {
+            Self::VT_LPWSTR => {
                 let char_count =
                     u32::from_le_bytes([data[4], data[5], data[6], data[7]]) as usize;
-                let byte_len = char_count * 2;
-                if data.len() >= 8 + byte_len && char_count > 0 {
+                // The length check bounds the allocation below by the property data
+                let byte_len = char_count.saturating_mul(2);
+                if data.len() - 8 >= byte_len && char_count > 0 {
                     let mut chars = Vec::with_capacity(char_count);
                     for i in 0..(char_count - 1) {
                         // Exclude null terminator
@@ -177,6 +200,12 @@ impl SummaryInfo {
                     None
                 }
             }
+            Self::VT_FILETIME => {
+                let bytes = data.get(4..12)?;
+                let filetime = u64::from_le_bytes(bytes.try_into().ok()?);
+                // Zero means "not set"
+                (filetime != 0).then(|| filetime_to_iso8601(filetime))
+            }
             _ => None,
         }
     }
@@ -190,9 +219,192 @@ impl SummaryInfo {
             && self.comments.is_none()
             && self.last_author.is_none()
             && self.application_name.is_none()
+            && self.creation_date.is_none()
+            && self.last_saved_date.is_none()
+    }
+
+    /// Encodes the summary information as an OLE property set stream.
+    ///
+    /// The output has a single section with a UTF-16 code page property
+    /// followed by every field that is set. Dates that are not valid ISO 8601
+    /// date-times are left out.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let strings = [
+            (Self::PIDSI_TITLE, &self.title),
+            (Self::PIDSI_SUBJECT, &self.subject),
+            (Self::PIDSI_AUTHOR, &self.author),
+            (Self::PIDSI_KEYWORDS, &self.keywords),
+            (Self::PIDSI_COMMENTS, &self.comments),
+            (Self::PIDSI_LAST_AUTHOR, &self.last_author),
+            (Self::PIDSI_APPNAME, &self.application_name),
+        ];
+        let dates = [
+            (Self::PIDSI_CREATE_DTM, &self.creation_date),
+            (Self::PIDSI_LASTSAVE_DTM, &self.last_saved_date),
+        ];
+
+        let mut properties = vec![(Self::PID_CODEPAGE, {
+            let mut value = Vec::new();
+            value.extend(Self::VT_I2.to_le_bytes());
+            value.extend(Self::CODEPAGE_UTF16.to_le_bytes());
+            value
+        })];
+        for (id, text) in strings {
+            let Some(text) = text else { continue };
+            let mut chars: Vec<u16> = text.encode_utf16().collect();
+            chars.push(0);
+            let mut value = Vec::new();
+            value.extend(Self::VT_LPWSTR.to_le_bytes());
+            value.extend((chars.len() as u32).to_le_bytes());
+            value.extend(chars.iter().flat_map(|c| c.to_le_bytes()));
+            properties.push((id, value));
+        }
+        for (id, date) in dates {
+            let Some(filetime) = date.as_deref().and_then(iso8601_to_filetime) else {
+                continue;
+            };
+            let mut value = Vec::new();
+            value.extend(Self::VT_FILETIME.to_le_bytes());
+            value.extend(filetime.to_le_bytes());
+            properties.push((id, value));
+        }
+        properties.sort_by_key(|(id, _)| *id);
+
+        // Section: size, count, (id, offset) pairs, then 4-byte aligned values
+        let mut values = Vec::new();
+        let mut pairs = Vec::new();
+        let values_start = 8 + properties.len() * 8;
+        for (id, value) in &properties {
+            pairs.push((*id, (values_start + values.len()) as u32));
+            values.extend(value);
+            values.resize(values.len().next_multiple_of(4), 0);
+        }
+        let section_size = (values_start + values.len()) as u32;
+
+        let mut data = Vec::with_capacity(48 + section_size as usize);
+        // Header: byte order, version, system identifier, CLSID, section count
+        data.extend(0xFFFEu16.to_le_bytes());
+        data.extend(0u16.to_le_bytes());
+        data.extend(0x0002_0005u32.to_le_bytes());
+        data.extend([0u8; 16]);
+        data.extend(1u32.to_le_bytes());
+        // Section list: FMTID and offset
+        data.extend(Self::FMTID);
+        data.extend(48u32.to_le_bytes());
+
+        data.extend(section_size.to_le_bytes());
+        data.extend((properties.len() as u32).to_le_bytes());
+        for (id, offset) in pairs {
+            data.extend(id.to_le_bytes());
+            data.extend(offset.to_le_bytes());
+        }
+        data.extend(values);
+        data
     }
 }
 
+/// 100-nanosecond intervals between 1601-01-01 and 1970-01-01.
+const FILETIME_UNIX_EPOCH: u64 = 116_444_736_000_000_000;
+const FILETIME_PER_SECOND: u64 = 10_000_000;
+
+/// Formats a FILETIME as an ISO 8601 UTC date-time.
+fn filetime_to_iso8601(filetime: u64) -> String {
+    let seconds = (filetime / FILETIME_PER_SECOND) as i64
+        - (FILETIME_UNIX_EPOCH / FILETIME_PER_SECOND) as i64;
+    let days = seconds.div_euclid(86_400);
+    let time = seconds.rem_euclid(86_400);
+    let (year, month, day) = civil_from_days(days);
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        year,
+        month,
+        day,
+        time / 3600,
+        time / 60 % 60,
+        time % 60
+    )
+}
+
+/// Parses an ISO 8601 date-time (`YYYY-MM-DDTHH:MM:SS`, optionally followed by
+/// fractional seconds and a `Z` or `±HH:MM` zone designator) into a FILETIME.
+///
+/// Date-times without a zone designator are taken as UTC.
+fn iso8601_to_filetime(text: &str) -> Option<u64> {
+    let bytes = text.as_bytes();
+    if bytes.len() < 19
+        || bytes[4] != b'-'
+        || bytes[7] != b'-'
+        || bytes[13] != b':'
+        || bytes[16] != b':'
+    {
+        return None;
+    }
+    if !matches!(bytes[10], b'T' | b' ') {
+        return None;
+    }
+    let field = |range: std::ops::Range<usize>| text.get(range)?.parse::<i64>().ok();
+    let (year, month, day) = (field(0..4)?, field(5..7)?, field(8..10)?);
+    let (hour, minute, second) = (field(11..13)?, field(14..16)?, field(17..19)?);
+    if !(1..=12).contains(&month)
+        || !(1..=31).contains(&day)
+        || hour > 23
+        || minute > 59
+        || second > 60
+    {
+        return None;
+    }
+
+    // Fractional seconds are dropped
+    let rest = text[19..].trim_start_matches(|c: char| c == '.' || c.is_ascii_digit());
+    let offset = match rest.as_bytes() {
+        [] | [b'Z'] => 0,
+        [sign @ (b'+' | b'-'), ..] if rest.len() == 6 && rest.as_bytes()[3] == b':' => {
+            let minutes = rest[1..3].parse::<i64>().ok()? * 60 + rest[4..6].parse::<i64>().ok()?;
+            if *sign == b'+' {
+                minutes * 60
+            } else {
+                -minutes * 60
+            }
+        }
+        _ => return None,
+    };
+
+    let seconds =
+        days_from_civil(year, month, day) * 86_400 + hour * 3600 + minute * 60 + second - offset;
+    let seconds =
+        u64::try_from(seconds + (FILETIME_UNIX_EPOCH / FILETIME_PER_SECOND) as i64).ok()?;
+    Some(seconds * FILETIME_PER_SECOND)
+}
+
+/// Days since 1970-01-01 for a proleptic Gregorian date.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Proleptic Gregorian date for days since 1970-01-01.
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -203,4 +415,56 @@ mod tests {
         let info = SummaryInfo::from_bytes(&data).unwrap();
         assert!(info.is_empty());
     }
+
+    #[test]
+    fn test_summary_round_trip() {
+        let info = SummaryInfo {
+            title: Some("제목".to_string()),
+            subject: Some("Subject".to_string()),
+            author: Some("작성자".to_string()),
+            keywords: Some("a, b".to_string()),
+            comments: None,
+            last_author: Some("editor".to_string()),
+            application_name: None,
+            creation_date: Some("2024-02-29T09:30:05Z".to_string()),
+            last_saved_date: Some("1999-12-31T23:59:59+09:00".to_string()),
+        };
+
+        let data = info.to_bytes();
+        assert_eq!(&data[28..44], &SummaryInfo::FMTID);
+        let read = SummaryInfo::from_bytes(&data).unwrap();
+        assert_eq!(read.title, info.title);
+        assert_eq!(read.subject, info.subject);
+        assert_eq!(read.author, info.author);
+        assert_eq!(read.keywords, info.keywords);
+        assert_eq!(read.comments, None);
+        assert_eq!(read.last_author, info.last_author);
+        assert_eq!(read.creation_date.as_deref(), Some("2024-02-29T09:30:05Z"));
+        // Zone offsets are converted to UTC
+        assert_eq!(
+            read.last_saved_date.as_deref(),
+            Some("1999-12-31T14:59:59Z")
+        );
+    }
+
+    #[test]
+    fn test_filetime_conversion() {
+        assert_eq!(iso8601_to_filetime("1601-01-01T00:00:00Z"), Some(0));
+        assert_eq!(
+            iso8601_to_filetime("1970-01-01T00:00:00"),
+            Some(FILETIME_UNIX_EPOCH)
+        );
+        assert_eq!(
+            filetime_to_iso8601(FILETIME_UNIX_EPOCH),
+            "1970-01-01T00:00:00Z"
+        );
+        assert_eq!(
+            iso8601_to_filetime("1970-01-01T09:00:00.5+09:00"),
+            Some(FILETIME_UNIX_EPOCH)
+        );
+        assert_eq!(iso8601_to_filetime("2024-13-01T00:00:00Z"), None);
+        assert_eq!(iso8601_to_filetime("2024-01-01T00:00:00 UTC"), None);
+        assert_eq!(iso8601_to_filetime("yesterday"), None);
+        assert!(SummaryInfo::default().to_bytes().len() > 48);
+    }
 }
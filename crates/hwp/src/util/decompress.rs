@@ -4,35 +4,46 @@
 //! BodyText, and DocHistory streams.
 
 use crate::error::{Error, Result};
-use miniz_oxide::inflate::{decompress_to_vec, decompress_to_vec_zlib};
+use miniz_oxide::inflate::{
+    DecompressError, TINFLStatus, decompress_to_vec_with_limit, decompress_to_vec_zlib_with_limit,
+};
+use primitive::LimitKind;
 
-/// Decompresses a compressed stream.
+/// Decompresses a compressed stream, producing at most `limit` bytes.
 ///
 /// HWP streams use raw deflate compression without a zlib header.
 /// This function first tries raw deflate, and falls back to zlib
 /// if that fails (for compatibility with older documents).
 ///
-/// # Arguments
-///
-/// * `data` - The compressed data
-///
-/// # Returns
-///
-/// The decompressed data as a Vec<u8>.
+/// Decompression stops as soon as the output would grow past `limit`, so a
+/// deflate bomb never gets fully inflated.
 ///
 /// # Errors
 ///
-/// Returns an error if decompression fails.
-pub fn decompress_stream(data: &[u8]) -> Result<Vec<u8>> {
+/// Returns [`Error::LimitExceeded`] with `kind` if the output is larger than
+/// `limit`, or an error if decompression fails.
+pub fn decompress_stream(data: &[u8], limit: u64, kind: LimitKind) -> Result<Vec<u8>> {
+    let max_size = usize::try_from(limit).unwrap_or(usize::MAX);
+    let exceeded = |e: &DecompressError| e.status == TINFLStatus::HasMoreOutput;
+
     // HWP uses raw deflate (no zlib header)
-    decompress_to_vec(data)
-        .or_else(|_| {
+    let result = decompress_to_vec_with_limit(data, max_size).or_else(|e| {
+        if exceeded(&e) {
+            Err(e)
+        } else {
             // Fall back to zlib for compatibility
-            decompress_to_vec_zlib(data)
-        })
-        .map_err(|e| Error::DecompressionFailed {
-            description: format!("{:?}", e),
-        })
+            decompress_to_vec_zlib_with_limit(data, max_size)
+        }
+    });
+    result.map_err(|e| {
+        if exceeded(&e) {
+            Error::LimitExceeded { kind, limit }
+        } else {
+            Error::DecompressionFailed {
+                description: format!("{:?}", e),
+            }
+        }
+    })
 }
 
 #[cfg(test)]
@@ -44,7 +55,7 @@ mod tests {
     fn test_decompress_roundtrip() {
         let original = b"Hello, HWP World! This is a test of zlib compression.";
         let compressed = compress_to_vec_zlib(original, 6);
-        let decompressed = decompress_stream(&compressed).unwrap();
+        let decompressed = decompress_stream(&compressed, u64::MAX, LimitKind::StreamSize).unwrap();
         assert_eq!(&decompressed, original);
     }
 
@@ -52,13 +63,31 @@ mod tests {
     fn test_decompress_empty() {
         // Empty zlib stream (just header and checksum)
         let compressed = compress_to_vec_zlib(&[], 6);
-        let decompressed = decompress_stream(&compressed).unwrap();
+        let decompressed = decompress_stream(&compressed, u64::MAX, LimitKind::StreamSize).unwrap();
         assert!(decompressed.is_empty());
     }
 
     #[test]
     fn test_decompress_invalid_data() {
         let invalid_data = [0x00, 0x01, 0x02, 0x03];
-        assert!(decompress_stream(&invalid_data).is_err());
+        assert!(decompress_stream(&invalid_data, u64::MAX, LimitKind::StreamSize).is_err());
+    }
+
+    #[test]
+    fn test_decompress_limit() {
+        let original = vec![0u8; 100_000];
+        let compressed = compress_to_vec_zlib(&original, 6);
+
+        assert_eq!(
+            decompress_stream(&compressed, 100_000, LimitKind::StreamSize).unwrap(),
+            original
+        );
+        assert!(matches!(
+            decompress_stream(&compressed, 1000, LimitKind::BinaryDataSize),
+            Err(Error::LimitExceeded {
+                kind: LimitKind::BinaryDataSize,
+                limit: 1000
+            })
+        ));
     }
 }
@@ -43,6 +43,15 @@ impl<'a> ByteReader<'a> {
         self.data.len().saturating_sub(self.position)
     }
 
+    /// Returns a capacity for `count` elements of at least `element_size` bytes each.
+    ///
+    /// Counts come from the data itself, so the capacity is bounded by the
+    /// remaining bytes to keep a corrupt count from requesting a huge allocation.
+    #[inline]
+    pub fn capacity_for(&self, count: usize, element_size: usize) -> usize {
+        count.min(self.remaining() / element_size.max(1))
+    }
+
     /// Returns true if there are no more bytes to read.
     #[inline]
     pub const fn is_empty(&self) -> bool {
@@ -223,7 +232,7 @@ impl<'a> ByteReader<'a> {
 
     /// Reads a UTF-16LE string with a fixed character count.
     pub fn read_utf16_string_fixed(&mut self, char_count: usize) -> Result<String> {
-        let byte_count = char_count * 2;
+        let byte_count = char_count.saturating_mul(2);
         self.ensure_available(byte_count)?;
 
         let mut chars = Vec::with_capacity(char_count);
@@ -286,6 +295,17 @@ mod tests {
         assert_eq!(reader.read_u32().unwrap(), 0x78563412);
     }
 
+    #[test]
+    fn test_capacity_for() {
+        let data = [0u8; 10];
+        let mut reader = ByteReader::new(&data);
+        assert_eq!(reader.capacity_for(3, 2), 3);
+        assert_eq!(reader.capacity_for(usize::MAX, 4), 2);
+        assert_eq!(reader.capacity_for(usize::MAX, 0), 10);
+        reader.skip(9).unwrap();
+        assert_eq!(reader.capacity_for(100, 2), 0);
+    }
+
     #[test]
     fn test_read_utf16_string() {
         // "Hello" in UTF-16LE with length prefix
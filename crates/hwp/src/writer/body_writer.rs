@@ -20,6 +20,16 @@ use super::ByteWriter;
 use crate::doc_info::FillInfo;
 use crate::primitive::RecordTagId;
 
+/// 8 유닛을 차지하는 확장/인라인 컨트롤 문자 코드인지 확인합니다.
+const fn is_extended_char(code: u16) -> bool {
+    matches!(code, 1..=9 | 11 | 12 | 14..=23)
+}
+
+/// 4바이트 컨트롤 ID를 u32로 변환합니다 (빅엔디언 순서).
+const fn make_ctrl_id(id: &[u8; 4]) -> u32 {
+    u32::from_be_bytes(*id)
+}
+
 /// BodyText (Section) 스트림 생성기
 pub struct BodyWriter {
     /// 섹션 데이터들
@@ -27,20 +37,10 @@ pub struct BodyWriter {
 }
 
 /// 섹션 데이터
+///
+/// 구역 정의(`secd`)와 단 정의(`cold`)는 첫 문단의 컨트롤로 들어갑니다.
 #[derive(Debug, Clone, Default)]
 pub struct SectionData {
-    /// 페이지 정의
-    pub page_definition: Option<PageDefinitionData>,
-    /// 페이지 테두리/배경
-    pub page_border_fill: Option<PageBorderFillData>,
-    /// 각주 모양
-    pub footnote_shape: Option<FootnoteShapeData>,
-    /// 미주 모양
-    pub endnote_shape: Option<EndnoteShapeData>,
-    /// 구역 정의
-    pub section_definition: Option<SectionDefinitionData>,
-    /// 단 정의
-    pub column_definition: Option<ColumnDefinitionData>,
     /// 문단들
     pub paragraphs: Vec<ParagraphData>,
 }
@@ -66,87 +66,76 @@ pub struct PageDefinitionData {
     pub margin_footer: u32,
     /// 제본 여백
     pub margin_gutter: u32,
-    /// 속성 (하위 1비트: 용지 방향, 2-3비트: 제본 여백 위치)
-    pub properties: u32,
+    /// 용지 방향 (0: 세로, 1: 가로)
+    pub orientation: u8,
+    /// 제본 위치
+    pub gutter_position: u8,
 }
 
-/// 페이지 테두리/배경 데이터
-#[derive(Debug, Clone)]
+/// 쪽 테두리/배경 데이터
+#[derive(Debug, Clone, Default)]
 pub struct PageBorderFillData {
-    /// 테두리/채우기 ID
-    pub border_fill_id: u16,
-    /// 속성 (하위 1비트: 위치(0=용지, 1=본문), 2비트: 머리글 포함, 4비트: 바닥글 포함)
+    /// 속성 (bit 0: 본문 기준, bit 1: 머리말 포함, bit 2: 꼬리말 포함, bit 3: 채울 영역)
     pub properties: u32,
-    /// 왼쪽 오프셋
-    pub offset_left: i32,
-    /// 오른쪽 오프셋
-    pub offset_right: i32,
-    /// 위쪽 오프셋
-    pub offset_top: i32,
-    /// 아래쪽 오프셋
-    pub offset_bottom: i32,
+    /// 왼쪽 간격
+    pub offset_left: i16,
+    /// 오른쪽 간격
+    pub offset_right: i16,
+    /// 위쪽 간격
+    pub offset_top: i16,
+    /// 아래쪽 간격
+    pub offset_bottom: i16,
+    /// 테두리/배경 ID
+    pub border_fill_id: u16,
 }
 
-/// 각주 모양 데이터
-#[derive(Debug, Clone)]
-pub struct FootnoteShapeData {
-    /// 속성 (번호 형식, 위첨자 등)
+/// 각주/미주 모양 데이터
+#[derive(Debug, Clone, Default)]
+pub struct NoteShapeData {
+    /// 속성 (번호 모양, 배치, 위첨자, 번호 매기기)
     pub properties: u32,
-    /// 접두 문자열
-    pub prefix: String,
-    /// 접미 문자열
-    pub suffix: String,
+    /// 사용자 기호 (0: 없음)
+    pub user_char: u16,
+    /// 앞 장식 문자 (0: 없음)
+    pub prefix_char: u16,
+    /// 뒤 장식 문자 (0: 없음)
+    pub suffix_char: u16,
     /// 시작 번호
     pub start_number: u16,
-    /// 구분선 길이 (0-100%)
+    /// 구분선 길이
     pub separator_length: u16,
-    /// 구분선 위치 (여백으로부터의 거리)
+    /// 구분선 위 여백
     pub separator_position: u16,
-    /// 구분선 위쪽 여백
+    /// 구분선 아래 여백
     pub space_above: u16,
-    /// 구분선 아래쪽 여백
+    /// 주석 사이 여백
     pub space_below: u16,
-    /// 각주 간 간격
+    /// 주석 사이 간격
     pub space_between: u16,
     /// 구분선 종류
     pub separator_line_type: u8,
-    /// 구분선 두께 (0.1mm 단위)
+    /// 구분선 굵기
     pub separator_line_thickness: u8,
-    /// 구분선 색상 (RGB)
+    /// 구분선 색상 (BGR)
     pub separator_line_color: u32,
 }
 
-/// 미주 모양 데이터
-#[derive(Debug, Clone)]
-pub struct EndnoteShapeData {
-    /// 속성
-    pub properties: u32,
-    /// 접두 문자열
-    pub prefix: String,
-    /// 접미 문자열
-    pub suffix: String,
-    /// 시작 번호
-    pub start_number: u16,
-    /// 구분선 위치 (여백으로부터의 거리)
-    pub separator_position: u16,
-}
-
-/// 구역 정의 데이터 (HWP 표 129)
+/// 구역 정의 데이터
 #[derive(Debug, Clone)]
 pub struct SectionDefinitionData {
-    /// 속성 (표 130)
+    /// 속성 (감추기 플래그, 쪽 시작 위치 등)
     pub properties: u32,
-    /// 단 간격
+    /// 단 사이 간격
     pub column_gap: u16,
-    /// 세로 격자선 간격
+    /// 세로 줄맞춤 간격
     pub vertical_grid: u16,
-    /// 가로 격자선 간격
+    /// 가로 줄맞춤 간격
     pub horizontal_grid: u16,
     /// 기본 탭 간격
     pub default_tab_interval: u32,
     /// 번호 문단 모양 ID
     pub numbering_shape_id: u16,
-    /// 시작 페이지 번호 (0이면 이전 섹션에서 이어감)
+    /// 쪽 시작 번호
     pub starting_page_number: u16,
     /// 그림/표/수식 시작 번호
     pub starting_figure_number: u16,
@@ -154,6 +143,14 @@ pub struct SectionDefinitionData {
     pub starting_equation_number: u16,
     /// 대표 언어
     pub language: u16,
+    /// 페이지 정의
+    pub page_definition: PageDefinitionData,
+    /// 각주 모양
+    pub footnote_shape: Option<NoteShapeData>,
+    /// 미주 모양 (각주 모양 다음에 기록됨)
+    pub endnote_shape: Option<NoteShapeData>,
+    /// 쪽 테두리/배경
+    pub page_border_fill: Option<PageBorderFillData>,
 }
 
 impl Default for SectionDefinitionData {
@@ -163,40 +160,45 @@ impl Default for SectionDefinitionData {
             column_gap: 0,
             vertical_grid: 0,
             horizontal_grid: 0,
-            default_tab_interval: 800, // 기본 탭 간격 (약 8mm)
+            default_tab_interval: 800,
             numbering_shape_id: 0,
             starting_page_number: 0,
-            starting_figure_number: 1,
-            starting_table_number: 1,
-            starting_equation_number: 1,
-            language: 0, // 기본값 0 (시스템 기본 언어)
+            starting_figure_number: 0,
+            starting_table_number: 0,
+            starting_equation_number: 0,
+            language: 0,
+            page_definition: PageDefinitionData::default(),
+            footnote_shape: None,
+            endnote_shape: None,
+            page_border_fill: None,
         }
     }
 }
 
-/// 단 정의 데이터 (HWP 표 138)
+/// 단 정의 데이터
 #[derive(Debug, Clone)]
 pub struct ColumnDefinitionData {
-    /// 속성1 (표 139) - 단 종류, 단 수, 단 방향, 같은 너비
-    pub properties1: u16,
-    /// 단 간격
+    /// 속성 (단 종류, 단 개수, 방향, 너비 동일 여부)
+    pub properties: u16,
+    /// 단 사이 간격
     pub column_gap: u16,
-    /// 개별 단 너비 (같은 너비가 아닐 때만)
+    /// 단 너비들 (너비가 동일하지 않을 때만 기록)
     pub column_widths: Vec<u16>,
-    /// 속성2
+    /// 추가 속성
     pub properties2: u16,
     /// 구분선 종류
     pub separator_style: u8,
-    /// 구분선 두께
+    /// 구분선 굵기
     pub separator_thickness: u8,
-    /// 구분선 색상 (COLORREF)
+    /// 구분선 색상 (BGR)
     pub separator_color: u32,
 }
 
 impl Default for ColumnDefinitionData {
     fn default() -> Self {
         Self {
-            properties1: 1 << 2 | 1 << 12, // 단 수 1, 같은 너비
+            // 1단, 너비 동일
+            properties: (1 << 2) | (1 << 12),
             column_gap: 0,
             column_widths: Vec::new(),
             properties2: 0,
@@ -208,316 +210,507 @@ impl Default for ColumnDefinitionData {
 }
 
 /// 문단 데이터
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct ParagraphData {
     /// 문단 모양 ID
     pub para_shape_id: u16,
     /// 스타일 ID
     pub style_id: u8,
-    /// 문단 텍스트 (UTF-16)
+    /// 나누기 종류 (0x01: 구역, 0x04: 쪽, 0x08: 단)
+    pub break_type: u8,
+    /// 인스턴스 ID
+    pub instance_id: u32,
+    /// 텍스트 (UTF-16, 문단 끝 문자 제외)
     pub text: Vec<u16>,
     /// 글자 모양 참조들
     pub char_shape_refs: Vec<CharShapeRef>,
-    /// 범위 태그들 (변경 추적, 형광펜 등)
+    /// 줄 세그먼트들
+    pub line_segments: Vec<LineSegmentData>,
+    /// 영역 태그들
     pub range_tags: Vec<RangeTagData>,
-    /// 컨트롤들
+    /// 확장 컨트롤 문자 순서대로 나열된 컨트롤들
     pub controls: Vec<ControlData>,
 }
 
 /// 글자 모양 참조
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct CharShapeRef {
-    /// 위치
+    /// 시작 위치
     pub position: u32,
     /// 글자 모양 ID
     pub char_shape_id: u32,
 }
 
-/// 범위 태그 데이터
-#[derive(Debug, Clone, Copy)]
+/// 줄 세그먼트 데이터
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LineSegmentData {
+    /// 텍스트 시작 위치
+    pub text_start: u32,
+    /// 줄의 세로 위치
+    pub vertical_position: i32,
+    /// 줄 높이
+    pub line_height: i32,
+    /// 텍스트 높이
+    pub text_height: i32,
+    /// 베이스라인까지 거리
+    pub baseline_distance: i32,
+    /// 줄 간격
+    pub line_spacing: i32,
+    /// 컬럼 시작 위치
+    pub column_start: i32,
+    /// 세그먼트 너비
+    pub segment_width: i32,
+    /// 태그 플래그
+    pub tag: u32,
+}
+
+/// 영역 태그 데이터
+#[derive(Debug, Clone)]
 pub struct RangeTagData {
     /// 시작 위치
     pub start_position: u32,
     /// 끝 위치
     pub end_position: u32,
-    /// 태그 (3바이트)
+    /// 태그 데이터
     pub tag: [u8; 3],
 }
 
 /// 컨트롤 데이터
 #[derive(Debug, Clone)]
 pub enum ControlData {
+    /// 구역 정의
+    SectionDefinition(Box<SectionDefinitionData>),
+    /// 단 정의
+    ColumnDefinition(ColumnDefinitionData),
     /// 표
-    Table(TableData),
+    Table(Box<TableData>),
     /// 그림
-    Picture(PictureData),
-    /// 텍스트 박스
-    TextBox(TextBoxData),
+    Picture(Box<PictureData>),
+    /// OLE 객체
+    Ole(Box<OleData>),
     /// 수식
-    Equation(EquationData),
-    /// 도형
-    Shape(ShapeData),
-    /// 머리글
+    Equation(Box<EquationData>),
+    /// 그리기 개체 (선, 사각형, 타원, 호, 다각형, 곡선, 묶음)
+    Shape(Box<ShapeControlData>),
+    /// 머리말
     Header(HeaderFooterData),
-    /// 바닥글
+    /// 꼬리말
     Footer(HeaderFooterData),
     /// 각주
     Footnote(NoteData),
     /// 미주
     Endnote(NoteData),
-    /// 하이퍼링크
-    Hyperlink(HyperlinkData),
+    /// 필드 시작 (하이퍼링크, 상호 참조, 목차 등)
+    Field(FieldData),
     /// 책갈피
     Bookmark(BookmarkData),
     /// 자동 번호
     AutoNumber(AutoNumberData),
     /// 새 번호
     NewNumber(NewNumberData),
-    /// 페이지 번호
+    /// 쪽 번호 위치
     PageNumber(PageNumberData),
+    /// 찾아보기 표식
+    IndexMark(IndexMarkData),
     /// 숨은 설명
     HiddenComment(HiddenCommentData),
+    /// 글자 겹침
+    Compose(ComposeData),
+    /// 덧말
+    Dutmal(DutmalData),
     /// 비디오
-    Video(VideoData),
-    /// OLE 객체
-    Ole(OleData),
+    Video(Box<VideoData>),
     /// 차트
-    Chart(ChartData),
+    Chart(Box<ChartData>),
     /// 양식 객체
-    FormObject(FormObjectData),
+    FormObject(Box<FormObjectData>),
     /// 글맵시
-    TextArt(TextArtData),
-    /// 필드 (날짜, 시간, 페이지 번호 등)
-    Field(FieldData),
+    TextArt(Box<TextArtData>),
+    /// 해석하지 않고 그대로 기록하는 컨트롤
+    Raw(RawControlData),
+}
+
+impl ControlData {
+    /// 컨트롤 ID를 반환합니다.
+    pub fn ctrl_id(&self) -> u32 {
+        match self {
+            Self::SectionDefinition(_) => make_ctrl_id(b"secd"),
+            Self::ColumnDefinition(_) => make_ctrl_id(b"cold"),
+            Self::Table(_) => make_ctrl_id(b"tbl "),
+            Self::Picture(_) | Self::Ole(_) | Self::Shape(_) | Self::Video(_) | Self::Chart(_) => {
+                make_ctrl_id(b"gso ")
+            }
+            Self::Equation(_) => make_ctrl_id(b"eqed"),
+            Self::Header(_) => make_ctrl_id(b"head"),
+            Self::Footer(_) => make_ctrl_id(b"foot"),
+            Self::Footnote(_) => make_ctrl_id(b"fn  "),
+            Self::Endnote(_) => make_ctrl_id(b"en  "),
+            Self::Field(field) => field.ctrl_id,
+            Self::Bookmark(_) => make_ctrl_id(b"bokm"),
+            Self::AutoNumber(_) => make_ctrl_id(b"atno"),
+            Self::NewNumber(_) => make_ctrl_id(b"nwno"),
+            Self::PageNumber(_) => make_ctrl_id(b"pgnp"),
+            Self::IndexMark(_) => make_ctrl_id(b"idxm"),
+            Self::HiddenComment(_) => make_ctrl_id(b"tcmt"),
+            Self::Compose(_) => make_ctrl_id(b"tcps"),
+            Self::Dutmal(_) => make_ctrl_id(b"tdut"),
+            Self::FormObject(_) => make_ctrl_id(b"form"),
+            Self::TextArt(_) => make_ctrl_id(b"dso "),
+            Self::Raw(raw) => raw.ctrl_id,
+        }
+    }
+
+    /// 문단 텍스트에 들어가는 컨트롤 문자 코드를 반환합니다.
+    pub fn char_code(&self) -> u16 {
+        char_code_for_ctrl_id(self.ctrl_id())
+    }
+}
+
+/// 컨트롤 ID에 대응하는 확장 컨트롤 문자 코드
+fn char_code_for_ctrl_id(ctrl_id: u32) -> u16 {
+    match &ctrl_id.to_be_bytes() {
+        b"secd" | b"cold" => 2,
+        b"tbl " | b"gso " | b"eqed" | b"dso " | b"form" => 11,
+        b"tcmt" => 15,
+        b"head" | b"foot" => 16,
+        b"fn  " | b"en  " => 17,
+        b"atno" => 18,
+        b"nwno" | b"pgnp" | b"pghd" | b"pgct" => 21,
+        b"bokm" | b"idxm" => 22,
+        b"tdut" | b"tcps" => 23,
+        [b'%', ..] => 3,
+        _ => 11,
+    }
 }
 
 /// 개체 공통 속성 데이터
 #[derive(Debug, Clone, Default)]
 pub struct ObjectCommonData {
-    /// 위치 속성 (TextWrap 등)
+    /// 위치 속성 (글자처럼 취급, 기준, 배치 등)
     pub properties: u32,
     /// 세로 오프셋 (HwpUnit)
     pub vertical_offset: i32,
     /// 가로 오프셋 (HwpUnit)
     pub horizontal_offset: i32,
-    /// 너비 (HwpUnit)
+    /// 너비
     pub width: i32,
-    /// 높이 (HwpUnit)
+    /// 높이
     pub height: i32,
     /// Z 순서
     pub z_order: i32,
-    /// 왼쪽 여백
-    pub margin_left: u16,
-    /// 오른쪽 여백
-    pub margin_right: u16,
-    /// 위쪽 여백
-    pub margin_top: u16,
-    /// 아래쪽 여백
-    pub margin_bottom: u16,
+    /// 바깥 여백 (왼쪽, 오른쪽, 위, 아래)
+    pub margin_left: i16,
+    pub margin_right: i16,
+    pub margin_top: i16,
+    pub margin_bottom: i16,
+    /// 인스턴스 ID
+    pub instance_id: u32,
+    /// 쪽 나눔 방지
+    pub prevent_page_break: i32,
+    /// 개체 설명문
+    pub description: String,
 }
 
 /// 캡션 방향
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
 pub enum CaptionDirection {
-    /// 아래
-    #[default]
-    Below,
-    /// 위
-    Above,
     /// 왼쪽
     Left,
     /// 오른쪽
     Right,
+    /// 위
+    Above,
+    /// 아래
+    #[default]
+    Below,
 }
 
 /// 캡션 데이터
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct CaptionData {
     /// 캡션 방향
     pub direction: CaptionDirection,
-    /// 캡션 간격
-    pub gap: i32,
-    /// 캡션 내용 (문단들)
+    /// 여백 포함 여부
+    pub include_margin: bool,
+    /// 캡션 폭
+    pub width: u32,
+    /// 캡션과 개체 사이 간격
+    pub gap: i16,
+    /// 텍스트 최대 길이
+    pub max_length: u32,
+    /// 캡션 문단들
     pub paragraphs: Vec<ParagraphData>,
 }
 
 /// 표 데이터
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct TableData {
     /// 공통 속성
     pub common: ObjectCommonData,
+    /// 캡션 (있는 경우)
+    pub caption: Option<CaptionData>,
+    /// 속성 (쪽 나눔, 제목 줄 반복)
+    pub properties: u32,
     /// 행 수
     pub rows: u16,
     /// 열 수
     pub columns: u16,
-    /// 열 너비들
-    pub column_widths: Vec<u16>,
-    /// 셀들
+    /// 셀 간격
+    pub cell_spacing: i16,
+    /// 안쪽 여백 (왼쪽, 오른쪽, 위, 아래)
+    pub padding: [i16; 4],
+    /// 행 높이들
+    pub row_heights: Vec<u16>,
+    /// 테두리/배경 ID
+    pub border_fill_id: u16,
+    /// 영역 속성들
+    pub zones: Vec<TableZoneData>,
+    /// 셀들 (행 순서)
     pub cells: Vec<TableCellData>,
-    /// 테두리/채우기 ID
+}
+
+/// 표 영역 속성 데이터
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TableZoneData {
+    /// 시작 열
+    pub start_column: u16,
+    /// 시작 행
+    pub start_row: u16,
+    /// 끝 열
+    pub end_column: u16,
+    /// 끝 행
+    pub end_row: u16,
+    /// 테두리/배경 ID
     pub border_fill_id: u16,
-    /// 캡션 (있는 경우)
-    pub caption: Option<CaptionData>,
-    /// 표 속성 (bit 0-1: 페이지 나눔, bit 2: 제목 줄 자동 반복)
-    pub properties: u32,
 }
 
 /// 표 셀 데이터
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct TableCellData {
     /// 열 주소
     pub col: u16,
     /// 행 주소
     pub row: u16,
-    /// 열 병합 수
+    /// 열 병합 개수
     pub col_span: u16,
-    /// 행 병합 수
+    /// 행 병합 개수
     pub row_span: u16,
-    /// 너비
+    /// 셀 너비
     pub width: u32,
-    /// 높이
+    /// 셀 높이
     pub height: u32,
-    /// 셀 내 문단들
-    pub paragraphs: Vec<ParagraphData>,
-    /// 테두리/채우기 ID
+    /// 안쪽 여백 (왼쪽, 오른쪽, 위, 아래)
+    pub padding: [u16; 4],
+    /// 테두리/배경 ID
     pub border_fill_id: u16,
+    /// 문단 목록 속성 (세로 정렬 등)
+    pub list_properties: u32,
+    /// 셀 문단들
+    pub paragraphs: Vec<ParagraphData>,
 }
 
-/// 그림 데이터
-#[derive(Debug, Clone)]
-pub struct PictureData {
-    /// 공통 속성
-    pub common: ObjectCommonData,
-    /// 바이너리 데이터 ID
-    pub binary_data_id: u16,
-    /// 너비
-    pub width: u32,
-    /// 높이
-    pub height: u32,
-    /// 캡션 (있는 경우)
-    pub caption: Option<CaptionData>,
-}
-
-/// 텍스트 박스 데이터
-#[derive(Debug, Clone)]
-pub struct TextBoxData {
-    /// 공통 속성
-    pub common: ObjectCommonData,
-    /// 너비
+/// 그리기 개체 요소 속성 데이터
+#[derive(Debug, Clone, Default)]
+pub struct ShapeElementData {
+    /// 개체 종류 ID (`$rec`, `$pic` 등)
+    pub element_type: u32,
+    /// 그룹 내 X 오프셋
+    pub offset_x: i32,
+    /// 그룹 내 Y 오프셋
+    pub offset_y: i32,
+    /// 그룹 수준
+    pub group_level: u16,
+    /// 로컬 파일 버전
+    pub local_version: u16,
+    /// 생성 시 너비
+    pub initial_width: u32,
+    /// 생성 시 높이
+    pub initial_height: u32,
+    /// 현재 너비
     pub width: u32,
-    /// 높이
+    /// 현재 높이
     pub height: u32,
-    /// 내부 문단들
-    pub paragraphs: Vec<ParagraphData>,
+    /// 속성 (bit 0: 좌우 뒤집기, bit 1: 상하 뒤집기)
+    pub properties: u32,
+    /// 회전각
+    pub rotation: i16,
+    /// 회전 중심 X
+    pub center_x: i32,
+    /// 회전 중심 Y
+    pub center_y: i32,
+    /// 이동 행렬 6개 다음에 크기/회전 행렬 쌍이 이어지는 변환 행렬 값들
+    pub matrix: Vec<f64>,
 }
 
-/// 수식 데이터
-#[derive(Debug, Clone)]
-pub struct EquationData {
-    /// 공통 속성
-    pub common: ObjectCommonData,
-    /// 수식 스크립트
-    pub script: String,
-    /// 크기
-    pub base_size: u32,
-    /// 캡션 (있는 경우)
-    pub caption: Option<CaptionData>,
+/// 테두리 선 데이터
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BorderLineData {
+    /// 선 색상 (BGR)
+    pub color: u32,
+    /// 선 굵기
+    pub thickness: i32,
+    /// 속성 (선 종류, 끝 모양, 화살표)
+    pub properties: u32,
+    /// 외곽선 스타일
+    pub outline_style: u8,
 }
 
-/// 머리글/바닥글 데이터
-#[derive(Debug, Clone)]
-pub struct HeaderFooterData {
-    /// 적용 대상 (0: 양쪽, 1: 짝수, 2: 홀수)
-    pub apply_to: u8,
-    /// 내용 문단들
-    pub paragraphs: Vec<ParagraphData>,
+/// 그림자 데이터
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ShadowData {
+    /// 그림자 종류 (0: 없음)
+    pub shadow_type: u32,
+    /// 그림자 색상 (BGR)
+    pub color: u32,
+    /// X 오프셋
+    pub offset_x: i32,
+    /// Y 오프셋
+    pub offset_y: i32,
+    /// 투명도
+    pub alpha: u8,
 }
 
-/// 각주/미주 데이터
-#[derive(Debug, Clone)]
-pub struct NoteData {
-    /// 번호
-    pub number: u16,
-    /// 내용 문단들
+/// 도형 안 글상자 데이터
+#[derive(Debug, Clone, Default)]
+pub struct ShapeTextData {
+    /// 문단 목록 속성 (텍스트 방향, 세로 정렬)
+    pub list_properties: u32,
+    /// 안쪽 여백 (왼쪽, 오른쪽, 위, 아래)
+    pub margins: [u16; 4],
+    /// 텍스트 최대 폭
+    pub max_width: u32,
+    /// 문단들
     pub paragraphs: Vec<ParagraphData>,
 }
 
-/// 하이퍼링크 데이터
-#[derive(Debug, Clone)]
-pub struct HyperlinkData {
-    /// URL
-    pub url: String,
-    /// 툴팁
-    pub tooltip: Option<String>,
-}
-
-/// 책갈피 데이터
-#[derive(Debug, Clone)]
-pub struct BookmarkData {
-    /// 이름
-    pub name: String,
-}
-
-/// 자동 번호 데이터
-#[derive(Debug, Clone)]
-pub struct AutoNumberData {
-    /// 번호 타입 (0: 페이지, 1: 각주, 2: 미주, 3: 그림, 4: 표, 5: 수식)
-    pub number_type: u16,
-    /// 번호 형식 (0: 숫자, 1: 원숫자, 2: 로마 대문자, 등)
-    pub number_format: u16,
-}
-
-/// 새 번호 데이터
+/// 그리기 개체 컨트롤 데이터
 #[derive(Debug, Clone)]
-pub struct NewNumberData {
-    /// 번호 타입
-    pub number_type: u16,
-    /// 새 번호 값
-    pub number: u16,
+pub struct ShapeControlData {
+    /// 공통 속성
+    pub common: ObjectCommonData,
+    /// 캡션 (있는 경우)
+    pub caption: Option<CaptionData>,
+    /// 도형
+    pub shape: ShapeData,
 }
 
-/// 페이지 번호 데이터
+/// 그리기 개체 도형 데이터 (SHAPE_COMPONENT 레코드)
 #[derive(Debug, Clone)]
-pub struct PageNumberData {
-    /// 위치 (0: 없음, 1: 위왼쪽, 2: 위가운데, ... 10: 안쪽 아래)
-    pub position: u16,
-    /// 번호 형식
-    pub number_format: u16,
-    /// 줄표 문자
-    pub side_character: String,
+pub struct ShapeData {
+    /// 요소 속성
+    pub element: ShapeElementData,
+    /// 테두리 선
+    pub border: BorderLineData,
+    /// 채우기 정보
+    pub fill: FillInfo,
+    /// 그림자
+    pub shadow: ShadowData,
+    /// 도형 안 글상자
+    pub text: Option<ShapeTextData>,
+    /// 도형별 기하 데이터
+    pub geometry: ShapeGeometry,
 }
 
-/// 숨은 설명 데이터
+/// 도형별 기하 데이터
 #[derive(Debug, Clone)]
-pub struct HiddenCommentData {
-    /// 내용 문단들
-    pub paragraphs: Vec<ParagraphData>,
+pub enum ShapeGeometry {
+    /// 선
+    Line {
+        /// 시작점
+        start: (i32, i32),
+        /// 끝점
+        end: (i32, i32),
+        /// 처음 생성 시 오른쪽 아래에서 시작했는지 여부
+        reversed: bool,
+    },
+    /// 사각형
+    Rectangle {
+        /// 모서리 곡률 (%)
+        round_ratio: u8,
+        /// 네 꼭짓점
+        corners: [(i32, i32); 4],
+    },
+    /// 타원
+    Ellipse {
+        /// 속성 (호 종류 등)
+        properties: u32,
+        /// 중심점, 제1축, 제2축, 시작점1, 끝점1, 시작점2, 끝점2
+        points: [(i32, i32); 7],
+    },
+    /// 호
+    Arc {
+        /// 호 종류 (0: 호, 1: 부채꼴, 2: 활꼴)
+        arc_type: u8,
+        /// 중심점, 제1축, 제2축
+        points: [(i32, i32); 3],
+    },
+    /// 다각형
+    Polygon {
+        /// 꼭짓점들
+        points: Vec<(i32, i32)>,
+    },
+    /// 곡선
+    Curve {
+        /// 점들
+        points: Vec<(i32, i32)>,
+        /// 구간 종류들 (0: 직선, 1: 곡선)
+        segment_types: Vec<u8>,
+    },
+    /// 묶음 개체
+    Container {
+        /// 자식 도형들
+        children: Vec<ShapeData>,
+    },
 }
 
-/// 비디오 데이터
-#[derive(Debug, Clone)]
-pub struct VideoData {
+/// 그림 데이터
+#[derive(Debug, Clone, Default)]
+pub struct PictureData {
     /// 공통 속성
     pub common: ObjectCommonData,
-    /// 비디오 타입 (0: 임베디드, 1: 링크)
-    pub video_type: u8,
-    /// 바이너리 데이터 ID (임베디드용)
-    pub bin_data_id: u16,
-    /// 포스터 바이너리 데이터 ID
-    pub poster_bin_id: u16,
-    /// 소스 URL (링크용)
-    pub source_url: String,
-    /// 비디오 너비 (HWP 단위)
-    pub width: u32,
-    /// 비디오 높이 (HWP 단위)
-    pub height: u32,
+    /// 캡션 (있는 경우)
+    pub caption: Option<CaptionData>,
+    /// 요소 속성
+    pub element: ShapeElementData,
+    /// 테두리 색상 (BGR)
+    pub border_color: u32,
+    /// 테두리 두께 (0: 테두리 없음)
+    pub border_thickness: i32,
+    /// 테두리 속성
+    pub border_properties: u32,
+    /// 테두리 투명도
+    pub border_transparency: u8,
+    /// 이미지 네 꼭짓점
+    pub corners: [(i32, i32); 4],
+    /// 자르기 (왼쪽, 위, 오른쪽, 아래)
+    pub crop: [i32; 4],
+    /// 안쪽 여백 (왼쪽, 오른쪽, 위, 아래)
+    pub inner_margin: [u16; 4],
+    /// 밝기
+    pub brightness: i8,
+    /// 명암
+    pub contrast: i8,
+    /// 효과 (0: 원본, 1: 그레이스케일, 2: 흑백, 3: 패턴)
+    pub effect: u8,
+    /// 바이너리 데이터 ID
+    pub binary_data_id: u16,
+    /// 인스턴스 ID
+    pub instance_id: u32,
+    /// 원본 이미지 크기
+    pub original_size: (u32, u32),
+    /// 투명 처리할 색상
+    pub transparent_color: Option<u32>,
 }
 
 /// OLE 데이터
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct OleData {
     /// 공통 속성
     pub common: ObjectCommonData,
+    /// 캡션 (있는 경우)
+    pub caption: Option<CaptionData>,
+    /// 요소 속성
+    pub element: ShapeElementData,
     /// 속성 플래그
     pub properties: u32,
     /// 너비
@@ -530,102 +723,66 @@ pub struct OleData {
     pub border_color: u32,
     /// 테두리 두께
     pub border_thickness: i32,
+    /// 테두리 속성
+    pub border_properties: u32,
 }
 
-/// 차트 데이터
-#[derive(Debug, Clone)]
-pub struct ChartData {
+/// 수식 데이터
+#[derive(Debug, Clone, Default)]
+pub struct EquationData {
     /// 공통 속성
     pub common: ObjectCommonData,
-    /// 차트 타입
-    pub chart_type: u8,
+    /// 캡션 (있는 경우)
+    pub caption: Option<CaptionData>,
+    /// 속성
+    pub properties: u32,
+    /// 수식 스크립트
+    pub script: String,
+    /// 글자 크기
+    pub base_size: u32,
+    /// 글자 색상 (BGR)
+    pub color: u32,
+    /// 베이스라인
+    pub baseline: i16,
+    /// 버전 정보
+    pub version: String,
+    /// 글꼴 이름
+    pub font_name: String,
 }
 
-/// 양식 객체 데이터
+/// 머리말/꼬리말 데이터
 #[derive(Debug, Clone, Default)]
-pub struct FormObjectData {
-    /// 공통 속성
-    pub common: ObjectCommonData,
-    /// 양식 타입
-    pub form_type: u8,
-    /// 이름
-    pub name: String,
-    /// 값
-    pub value: String,
-    /// 속성 플래그
-    pub properties: u32,
-    /// 버튼 캡션
-    pub caption: Option<String>,
-    /// 라디오 그룹 이름
-    pub radio_group_name: Option<String>,
-    /// 배경 색상 (BGR format)
-    pub back_color: Option<u32>,
-    /// 비밀번호 문자 (UTF-16 코드)
-    pub password_char: Option<u16>,
-    /// 최대 길이
-    pub max_length: Option<u32>,
-    /// 선택된 값
-    pub selected_value: Option<String>,
-    /// 목록 박스 행 수
-    pub list_box_rows: Option<i32>,
-    /// 목록 박스 폭
-    pub list_box_width: Option<i32>,
-    /// 항목 높이
-    pub item_height: Option<i32>,
-    /// 최상단 인덱스
-    pub top_index: Option<u32>,
-    /// 목록 항목 (표시 텍스트)
-    pub items_text: Vec<String>,
-    /// 목록 항목 (값)
-    pub items_value: Vec<String>,
-    /// 최소값 (ScrollBar)
-    pub min: Option<i32>,
-    /// 최대값 (ScrollBar)
-    pub max: Option<i32>,
-    /// 현재 값 (ScrollBar)
-    pub scroll_value: Option<i32>,
-    /// 작은 증감값
-    pub small_change: Option<u32>,
-    /// 큰 증감값
-    pub large_change: Option<u32>,
-    /// 페이지 단위
-    pub page: Option<i32>,
-    /// 반복 지연
-    pub delay: Option<u32>,
+pub struct HeaderFooterData {
+    /// 적용 페이지 (0: 양쪽, 1: 짝수, 2: 홀수)
+    pub apply_to: u32,
+    /// 내용 문단들
+    pub paragraphs: Vec<ParagraphData>,
 }
 
-/// 글맵시 데이터
-#[derive(Debug, Clone)]
-pub struct TextArtData {
-    /// 공통 속성
-    pub common: ObjectCommonData,
-    /// 텍스트 내용
-    pub text: String,
-    /// 글꼴 이름
-    pub font_name: Option<String>,
-    /// 글꼴 스타일 (0: Regular, 1: Bold, 2: Italic, 3: BoldItalic)
-    pub font_style: u8,
-    /// 모양 (0: 사각형, 1: 원형, 2: 아치 위, 3: 아치 아래, 등)
-    pub shape_type: u8,
-    /// 줄 간격
-    pub line_spacing: u32,
-    /// 자간
-    pub char_spacing: u32,
-    /// 정렬 (0: 왼쪽, 1: 가운데, 2: 오른쪽, 3: 양쪽)
-    pub alignment: u8,
-    /// 너비
-    pub width: u32,
-    /// 높이
-    pub height: u32,
-}
-
-/// 필드 데이터
+/// 각주/미주 데이터
+#[derive(Debug, Clone, Default)]
+pub struct NoteData {
+    /// 번호
+    pub number: u16,
+    /// 내용 문단들
+    pub paragraphs: Vec<ParagraphData>,
+}
+
+/// 필드 데이터
 #[derive(Debug, Clone)]
 pub struct FieldData {
-    /// 필드 타입
-    pub field_type: FieldTypeData,
-    /// 명령/형식 문자열
-    pub instruction: String,
+    /// 필드 컨트롤 ID (`%hlk`, `%xrf` 등)
+    pub ctrl_id: u32,
+    /// 속성
+    pub properties: u32,
+    /// 기타 속성
+    pub extra_properties: u8,
+    /// 명령 문자열
+    pub command: String,
+    /// 필드 ID
+    pub instance_id: u32,
+    /// 추가 매개변수 (CTRL_DATA)
+    pub parameters: Option<ParameterSetData>,
 }
 
 /// 필드 타입 데이터
@@ -698,164 +855,212 @@ impl FieldTypeData {
     }
 }
 
-/// 도형 데이터
-#[derive(Debug, Clone)]
-pub struct ShapeData {
-    /// 공통 속성
-    pub common: ObjectCommonData,
-    /// 도형 타입
-    pub shape_type: ShapeTypeData,
-    /// 너비
-    pub width: u32,
-    /// 높이
-    pub height: u32,
-    /// X 오프셋
-    pub offset_x: i32,
-    /// Y 오프셋
-    pub offset_y: i32,
-    /// 선 색상
-    pub line_color: u32,
-    /// 선 두께
-    pub line_thickness: u16,
-    /// 채우기 정보 (그라데이션, 패턴, 이미지 포함)
-    pub fill: FillInfo,
-    /// 캡션 (있는 경우)
-    pub caption: Option<CaptionData>,
-    /// 변환 행렬 (translation matrix)
-    pub translation_matrix: Option<[f64; 6]>,
-    /// 크기 조정 행렬 (scale matrix)
-    pub scale_matrix: Option<[f64; 6]>,
-    /// 회전 행렬 (rotation matrix)
-    pub rotation_matrix: Option<[f64; 6]>,
-    /// 회전 각도 (도)
-    pub rotation: f64,
+/// 매개변수 집합 데이터 (CTRL_DATA)
+#[derive(Debug, Clone, Default)]
+pub struct ParameterSetData {
+    /// 집합 ID
+    pub set_id: u16,
+    /// 항목들
+    pub items: Vec<ParameterItemData>,
 }
 
-/// 도형 타입 데이터
+/// 매개변수 항목 데이터
 #[derive(Debug, Clone)]
-pub enum ShapeTypeData {
-    /// 선
-    Line(LineShapeData),
-    /// 사각형
-    Rectangle(RectangleShapeData),
-    /// 타원
-    Ellipse(EllipseShapeData),
-    /// 호
-    Arc(ArcShapeData),
-    /// 다각형
-    Polygon(PolygonShapeData),
-    /// 곡선
-    Curve(CurveShapeData),
-    /// 그룹 (컨테이너)
-    Container(ContainerShapeData),
-    /// 연결선
-    Connector(ConnectorShapeData),
+pub struct ParameterItemData {
+    /// 항목 ID
+    pub id: u16,
+    /// 값
+    pub value: ParameterValueData,
 }
 
-/// 연결선 도형 데이터
+/// 매개변수 값
 #[derive(Debug, Clone)]
-pub struct ConnectorShapeData {
-    /// 연결선 스타일 (0~8)
-    pub line_type: u8,
-    /// 시작점 X
-    pub start_x: i32,
-    /// 시작점 Y
-    pub start_y: i32,
-    /// 시작점 연결 개체 ID
-    pub start_subject_id: Option<u32>,
-    /// 시작점 연결 인덱스
-    pub start_subject_index: Option<u32>,
-    /// 끝점 X
-    pub end_x: i32,
-    /// 끝점 Y
-    pub end_y: i32,
-    /// 끝점 연결 개체 ID
-    pub end_subject_id: Option<u32>,
-    /// 끝점 연결 인덱스
-    pub end_subject_index: Option<u32>,
-    /// 제어점 목록
-    pub control_points: Vec<(i32, i32)>,
-    /// 시작 화살표 타입
-    pub start_arrow_type: u8,
-    /// 시작 화살표 크기
-    pub start_arrow_size: u8,
-    /// 끝 화살표 타입
-    pub end_arrow_type: u8,
-    /// 끝 화살표 크기
-    pub end_arrow_size: u8,
+pub enum ParameterValueData {
+    /// 문자열
+    String(String),
+    /// 부호 있는 정수
+    Integer(i32),
+    /// 부호 없는 정수
+    Unsigned(u32),
+    /// 참/거짓
+    Boolean(bool),
 }
 
-/// 그룹 도형 데이터 (컨테이너)
+/// 책갈피 데이터
 #[derive(Debug, Clone)]
-pub struct ContainerShapeData {
-    /// 자식 도형들
-    pub children: Vec<ShapeData>,
+pub struct BookmarkData {
+    /// 이름
+    pub name: String,
 }
 
-/// 선 도형 데이터
-#[derive(Debug, Clone)]
-pub struct LineShapeData {
-    /// 시작점 X
-    pub start_x: i32,
-    /// 시작점 Y
-    pub start_y: i32,
-    /// 끝점 X
-    pub end_x: i32,
-    /// 끝점 Y
-    pub end_y: i32,
-    /// 시작 화살표 타입
-    pub start_arrow_type: u8,
-    /// 시작 화살표 크기
-    pub start_arrow_size: u8,
-    /// 끝 화살표 타입
-    pub end_arrow_type: u8,
-    /// 끝 화살표 크기
-    pub end_arrow_size: u8,
+/// 자동 번호 데이터
+#[derive(Debug, Clone, Copy)]
+pub struct AutoNumberData {
+    /// 번호 종류 (0: 쪽, 1: 각주, 2: 미주, 3: 그림, 4: 표, 5: 수식)
+    pub number_type: u32,
+    /// 번호 모양
+    pub number_format: u32,
 }
 
-/// 사각형 도형 데이터
-#[derive(Debug, Clone)]
-pub struct RectangleShapeData {
-    /// 모서리 반지름
-    pub corner_radius: u32,
+/// 새 번호 데이터
+#[derive(Debug, Clone, Copy)]
+pub struct NewNumberData {
+    /// 번호 종류
+    pub number_type: u32,
+    /// 새 번호
+    pub number: u16,
 }
 
-/// 타원 도형 데이터
-#[derive(Debug, Clone)]
-pub struct EllipseShapeData {
-    /// 호 타입 (0: 전체, 1: 호, 2: 부채꼴, 3: 활꼴)
-    pub arc_type: u8,
-    /// 시작 각도 (degree * 100)
-    pub start_angle: i32,
-    /// 끝 각도 (degree * 100)
-    pub end_angle: i32,
+/// 쪽 번호 위치 데이터
+#[derive(Debug, Clone, Copy)]
+pub struct PageNumberData {
+    /// 위치 (0: 없음, 1: 위왼쪽, 2: 위가운데, ... 10: 안쪽 아래)
+    pub position: u32,
+    /// 번호 모양
+    pub number_format: u32,
+    /// 줄표 문자
+    pub side_character: u16,
 }
 
-/// 호 도형 데이터
-#[derive(Debug, Clone)]
-pub struct ArcShapeData {
-    /// 호 타입
-    pub arc_type: u8,
-    /// 시작 각도
-    pub start_angle: i32,
-    /// 끝 각도
-    pub end_angle: i32,
+/// 찾아보기 표식 데이터
+#[derive(Debug, Clone, Default)]
+pub struct IndexMarkData {
+    /// 첫 번째 키워드
+    pub first_key: String,
+    /// 두 번째 키워드
+    pub second_key: String,
 }
 
-/// 다각형 도형 데이터
-#[derive(Debug, Clone)]
-pub struct PolygonShapeData {
-    /// 꼭짓점 좌표들 (x, y 쌍)
-    pub points: Vec<(i32, i32)>,
+/// 숨은 설명 데이터
+#[derive(Debug, Clone, Default)]
+pub struct HiddenCommentData {
+    /// 내용 문단들
+    pub paragraphs: Vec<ParagraphData>,
+}
+
+/// 글자 겹침 데이터
+#[derive(Debug, Clone, Default)]
+pub struct ComposeData {
+    /// 겹칠 글자
+    pub text: String,
+    /// 테두리 타입 (0: 없음, 1: 원, 2: 반전 원, 3: 사각형, ... 13: 굵은 순환 삼각형)
+    pub border_type: u8,
+    /// 테두리 내부 글자 크기
+    pub inner_char_size: i8,
+    /// 테두리 내부 글자 펼침
+    pub spread_inner_chars: bool,
+    /// 글자 모양 아이디 목록 (0xFFFFFFFF: 지정 안 함)
+    pub char_shape_ids: Vec<u32>,
+}
+
+/// 덧말 데이터
+#[derive(Debug, Clone, Default)]
+pub struct DutmalData {
+    /// 본말
+    pub main_text: String,
+    /// 덧말
+    pub sub_text: String,
+    /// 위치 (0: 위, 1: 아래)
+    pub position: u32,
+    /// 글자 크기 비율
+    pub size_ratio: u32,
+    /// 옵션
+    pub option: u32,
+    /// 스타일 아이디
+    pub style_id: u32,
+    /// 정렬 (0: 양쪽, 1: 왼쪽, 2: 오른쪽, 3: 가운데, 4: 배분, 5: 나눔)
+    pub alignment: u32,
+}
+
+/// 비디오 데이터
+#[derive(Debug, Clone, Default)]
+pub struct VideoData {
+    /// 공통 속성
+    pub common: ObjectCommonData,
+    /// 비디오 타입 (0: 임베디드, 1: 링크)
+    pub video_type: i32,
+    /// 바이너리 데이터 ID (임베디드용)
+    pub bin_data_id: u16,
+    /// 포스터 바이너리 데이터 ID
+    pub poster_bin_id: u16,
+    /// 소스 URL (링크용)
+    pub source_url: String,
+}
+
+/// 차트 데이터
+#[derive(Debug, Clone, Default)]
+pub struct ChartData {
+    /// 공통 속성
+    pub common: ObjectCommonData,
+    /// 차트 타입
+    pub chart_type: u16,
+}
+
+/// 양식 객체 데이터
+#[derive(Debug, Clone, Default)]
+pub struct FormObjectData {
+    /// 공통 속성
+    pub common: ObjectCommonData,
+    /// 속성 (하위 바이트: 양식 종류, 나머지: 종류별 플래그)
+    pub properties: u32,
+    /// 이름
+    pub name: String,
+    /// 값
+    pub value: String,
+    /// 버튼 캡션
+    pub caption: String,
+    /// 배경 색상 (BGR)
+    pub back_color: u32,
+    /// 라디오 그룹 이름
+    pub radio_group_name: String,
+    /// 비밀번호 문자 (UTF-16 코드, 0: 없음)
+    pub password_char: u16,
+    /// 최대 길이 (0: 제한 없음)
+    pub max_length: u32,
+    /// 선택된 값
+    pub selected_value: String,
+    /// 목록 박스 행 수
+    pub list_box_rows: i32,
+    /// 목록 박스 폭
+    pub list_box_width: i32,
+    /// 항목 높이
+    pub item_height: i32,
+    /// 최상단 인덱스
+    pub top_index: u32,
+    /// 목록 항목들 (표시 텍스트, 값)
+    pub items: Vec<(String, String)>,
+}
+
+/// 글맵시 데이터
+#[derive(Debug, Clone, Default)]
+pub struct TextArtData {
+    /// 공통 속성
+    pub common: ObjectCommonData,
+    /// 텍스트 내용
+    pub text: String,
+    /// 글꼴 이름
+    pub font_name: String,
+    /// 글꼴 스타일 (bit 0: 굵게, bit 1: 기울임)
+    pub font_style: u32,
+    /// 모양 (0: 평면, 1: 물결, 2: 위로 아치, ...)
+    pub shape_type: u32,
+    /// 정렬 (0: 왼쪽, 1: 가운데, 2: 오른쪽, 3: 양쪽)
+    pub alignment: u32,
+    /// 글자 색상 (BGR)
+    pub text_color: u32,
+    /// 외곽선 색상 (BGR)
+    pub outline_color: u32,
+    /// 그림자 색상 (BGR)
+    pub shadow_color: u32,
 }
 
-/// 곡선 도형 데이터
+/// 해석하지 않은 컨트롤 데이터
 #[derive(Debug, Clone)]
-pub struct CurveShapeData {
-    /// 제어점 좌표들 (x, y, type 튜플)
-    pub points: Vec<(i32, i32, u8)>,
-    /// 닫힌 곡선 여부
-    pub closed: bool,
+pub struct RawControlData {
+    /// 컨트롤 ID
+    pub ctrl_id: u32,
+    /// CTRL_HEADER 레코드에서 컨트롤 ID 뒤의 데이터
+    pub data: Vec<u8>,
 }
 
 impl BodyWriter {
@@ -881,957 +1086,822 @@ impl BodyWriter {
 
     fn build_section(&self, section: &SectionData) -> Vec<u8> {
         let mut writer = ByteWriter::new();
-
-        // 페이지 정의 (있으면)
-        if let Some(ref page_def) = section.page_definition {
-            self.write_page_definition(&mut writer, page_def);
-        }
-
-        // 각주 모양 (있으면)
-        if let Some(ref footnote_shape) = section.footnote_shape {
-            self.write_footnote_shape(&mut writer, footnote_shape);
-        }
-
-        // 미주 모양 (있으면) - 같은 태그 사용, 다른 속성
-        if let Some(ref endnote_shape) = section.endnote_shape {
-            self.write_endnote_shape(&mut writer, endnote_shape);
-        }
-
-        // 페이지 테두리/배경 (있으면)
-        if let Some(ref page_border_fill) = section.page_border_fill {
-            self.write_page_border_fill(&mut writer, page_border_fill);
-        }
-
-        // 문단들
-        for (i, para) in section.paragraphs.iter().enumerate() {
-            self.write_paragraph(&mut writer, para, i as u32);
-        }
-
+        self.write_paragraphs(&mut writer, &section.paragraphs, 0);
         writer.into_bytes()
     }
 
-    fn write_page_definition(&self, writer: &mut ByteWriter, page_def: &PageDefinitionData) {
-        let mut data = ByteWriter::new();
-
-        data.write_u32(page_def.paper_width);
-        data.write_u32(page_def.paper_height);
-        data.write_u32(page_def.margin_left);
-        data.write_u32(page_def.margin_right);
-        data.write_u32(page_def.margin_top);
-        data.write_u32(page_def.margin_bottom);
-        data.write_u32(page_def.margin_header);
-        data.write_u32(page_def.margin_footer);
-        data.write_u32(page_def.margin_gutter);
-        data.write_u32(page_def.properties);
-
-        let data_bytes = data.into_bytes();
-        writer.write_record(RecordTagId::PageDefinition, 0, &data_bytes);
-    }
-
-    fn write_footnote_shape(&self, writer: &mut ByteWriter, footnote: &FootnoteShapeData) {
-        let mut data = ByteWriter::new();
-
-        data.write_u32(footnote.properties);
-        data.write_hwp_string(&footnote.prefix);
-        data.write_hwp_string(&footnote.suffix);
-        data.write_u16(footnote.start_number);
-        data.write_u16(footnote.separator_length);
-        data.write_u16(footnote.separator_position);
-        data.write_u16(footnote.space_above);
-        data.write_u16(footnote.space_below);
-        data.write_u16(footnote.space_between);
-        data.write_u8(footnote.separator_line_type);
-        data.write_u8(footnote.separator_line_thickness);
-        data.write_u32(footnote.separator_line_color);
-
-        let data_bytes = data.into_bytes();
-        writer.write_record(RecordTagId::FootnoteShape, 0, &data_bytes);
-    }
-
-    fn write_endnote_shape(&self, writer: &mut ByteWriter, endnote: &EndnoteShapeData) {
-        let mut data = ByteWriter::new();
-
-        data.write_u32(endnote.properties);
-        data.write_hwp_string(&endnote.prefix);
-        data.write_hwp_string(&endnote.suffix);
-        data.write_u16(endnote.start_number);
-        // 미주는 구분선 관련 필드가 각주와 다름 - 위치만 있음
-        data.write_u16(endnote.separator_position);
+    /// 문단 목록을 기록합니다. 빈 목록에는 빈 문단 하나를 넣습니다.
+    fn write_paragraphs(&self, writer: &mut ByteWriter, paragraphs: &[ParagraphData], level: u16) {
+        if paragraphs.is_empty() {
+            self.write_paragraph(writer, &ParagraphData::default(), level, true);
+            return;
+        }
 
-        let data_bytes = data.into_bytes();
-        // 미주 모양도 FootnoteShape 태그 사용 (0x04A) - 같은 포맷
-        writer.write_record(RecordTagId::FootnoteShape, 0, &data_bytes);
+        let last = paragraphs.len() - 1;
+        for (i, para) in paragraphs.iter().enumerate() {
+            self.write_paragraph(writer, para, level, i == last);
+        }
     }
 
-    fn write_page_border_fill(&self, writer: &mut ByteWriter, border_fill: &PageBorderFillData) {
+    /// LIST_HEADER 레코드와 그 문단들을 기록합니다.
+    fn write_list(
+        &self,
+        writer: &mut ByteWriter,
+        level: u16,
+        properties: u32,
+        extra: &[u8],
+        paragraphs: &[ParagraphData],
+    ) {
         let mut data = ByteWriter::new();
+        data.write_u16(paragraphs.len().max(1) as u16);
+        data.write_u16(0);
+        data.write_u32(properties);
+        data.write_bytes(extra);
+        writer.write_record(RecordTagId::ListHeader, level, &data.into_bytes());
 
-        data.write_u32(border_fill.properties);
-        data.write_u16(border_fill.border_fill_id);
-        data.write_i32(border_fill.offset_left);
-        data.write_i32(border_fill.offset_right);
-        data.write_i32(border_fill.offset_top);
-        data.write_i32(border_fill.offset_bottom);
-
-        let data_bytes = data.into_bytes();
-        writer.write_record(RecordTagId::PageBorderFill, 0, &data_bytes);
+        self.write_paragraphs(writer, paragraphs, level);
     }
 
-    fn write_paragraph(&self, writer: &mut ByteWriter, para: &ParagraphData, instance_id: u32) {
+    fn write_paragraph(
+        &self,
+        writer: &mut ByteWriter,
+        para: &ParagraphData,
+        level: u16,
+        is_last: bool,
+    ) {
         // 1. PARAGRAPH_HEADER
-        self.write_paragraph_header(writer, para, instance_id);
-
-        // 2. PARAGRAPH_TEXT (있으면)
+        let mut data = ByteWriter::new();
+        let mut char_count = para.text.len() as u32 + 1;
+        if is_last {
+            // 목록의 마지막 문단 표시
+            char_count |= 0x8000_0000;
+        }
+        data.write_u32(char_count);
+        data.write_u32(para.control_mask());
+        data.write_u16(para.para_shape_id);
+        data.write_u8(para.style_id);
+        data.write_u8(para.break_type);
+        data.write_u16(para.char_shape_refs.len() as u16);
+        data.write_u16(para.range_tags.len() as u16);
+        data.write_u16(para.line_segments.len() as u16);
+        data.write_u32(para.instance_id);
+        data.write_u16(0); // 변경 추적 병합 문단 여부
+        writer.write_record(RecordTagId::ParagraphHeader, level, &data.into_bytes());
+
+        let child_level = level + 1;
+
+        // 2. PARAGRAPH_TEXT (문단 끝 문자만 있으면 생략)
         if !para.text.is_empty() {
-            self.write_paragraph_text(writer, para);
+            let mut data = ByteWriter::new();
+            for ch in &para.text {
+                data.write_u16(*ch);
+            }
+            data.write_u16(13);
+            writer.write_record(RecordTagId::ParagraphText, child_level, &data.into_bytes());
         }
 
-        // 3. PARAGRAPH_CHAR_SHAPE (있으면)
+        // 3. PARAGRAPH_CHAR_SHAPE
         if !para.char_shape_refs.is_empty() {
-            self.write_paragraph_char_shape(writer, para);
+            let mut data = ByteWriter::new();
+            for char_ref in &para.char_shape_refs {
+                data.write_u32(char_ref.position);
+                data.write_u32(char_ref.char_shape_id);
+            }
+            writer.write_record(
+                RecordTagId::ParagraphCharacterShape,
+                child_level,
+                &data.into_bytes(),
+            );
         }
 
-        // 4. 컨트롤들
-        for ctrl in &para.controls {
-            self.write_control(writer, ctrl);
+        // 4. PARAGRAPH_LINE_SEG
+        if !para.line_segments.is_empty() {
+            let mut data = ByteWriter::new();
+            for seg in &para.line_segments {
+                data.write_u32(seg.text_start);
+                data.write_i32(seg.vertical_position);
+                data.write_i32(seg.line_height);
+                data.write_i32(seg.text_height);
+                data.write_i32(seg.baseline_distance);
+                data.write_i32(seg.line_spacing);
+                data.write_i32(seg.column_start);
+                data.write_i32(seg.segment_width);
+                data.write_u32(seg.tag);
+            }
+            writer.write_record(
+                RecordTagId::ParagraphLineSegment,
+                child_level,
+                &data.into_bytes(),
+            );
         }
-    }
-
-    fn write_paragraph_header(
-        &self,
-        writer: &mut ByteWriter,
-        para: &ParagraphData,
-        instance_id: u32,
-    ) {
-        let mut data = ByteWriter::new();
-
-        // 텍스트 길이 계산
-        let char_count = self.calculate_char_count(para);
-
-        data.write_u32(char_count); // character_count
-        data.write_u32(0); // control_mask
-        data.write_u16(para.para_shape_id); // paragraph_shape_id
-        data.write_u8(para.style_id); // style_id
-        data.write_u8(0); // break_type
-        data.write_u16(para.char_shape_refs.len() as u16); // char_shape_count
-        data.write_u16(0); // range_tag_count
-        data.write_u16(0); // line_seg_count
-        data.write_u32(instance_id); // instance_id
-
-        let data_bytes = data.into_bytes();
-        writer.write_record(RecordTagId::ParagraphHeader, 0, &data_bytes);
-    }
-
-    fn write_paragraph_text(&self, writer: &mut ByteWriter, para: &ParagraphData) {
-        let mut data = ByteWriter::new();
 
-        // 텍스트 (UTF-16LE)
-        for ch in &para.text {
-            data.write_u16(*ch);
+        // 5. PARAGRAPH_RANGE_TAG
+        if !para.range_tags.is_empty() {
+            let mut data = ByteWriter::new();
+            for tag in &para.range_tags {
+                data.write_u32(tag.start_position);
+                data.write_u32(tag.end_position);
+                data.write_bytes(&tag.tag);
+                data.write_u8(0);
+            }
+            writer.write_record(
+                RecordTagId::ParagraphRangeTag,
+                child_level,
+                &data.into_bytes(),
+            );
         }
 
-        // 문단 끝 표시 (char code 13)
-        data.write_u16(13);
-
-        let data_bytes = data.into_bytes();
-        writer.write_record(RecordTagId::ParagraphText, 1, &data_bytes);
+        // 6. 컨트롤들
+        for ctrl in &para.controls {
+            self.write_control(writer, ctrl, child_level);
+        }
     }
 
-    fn write_paragraph_char_shape(&self, writer: &mut ByteWriter, para: &ParagraphData) {
+    fn write_control(&self, writer: &mut ByteWriter, ctrl: &ControlData, level: u16) {
         let mut data = ByteWriter::new();
+        data.write_u32(ctrl.ctrl_id());
 
-        for char_ref in &para.char_shape_refs {
-            data.write_u32(char_ref.position);
-            data.write_u32(char_ref.char_shape_id);
-        }
-
-        let data_bytes = data.into_bytes();
-        writer.write_record(RecordTagId::ParagraphCharacterShape, 1, &data_bytes);
-    }
+        let child_level = level + 1;
 
-    fn write_control(&self, writer: &mut ByteWriter, ctrl: &ControlData) {
         match ctrl {
-            ControlData::Table(table) => self.write_table(writer, table),
-            ControlData::Picture(picture) => self.write_picture(writer, picture),
-            ControlData::TextBox(textbox) => self.write_textbox(writer, textbox),
-            ControlData::Equation(equation) => self.write_equation(writer, equation),
-            ControlData::Shape(shape) => self.write_shape(writer, shape),
-            ControlData::Header(header) => self.write_header_footer(writer, header, true),
-            ControlData::Footer(footer) => self.write_header_footer(writer, footer, false),
-            ControlData::Footnote(note) => self.write_note(writer, note, true),
-            ControlData::Endnote(note) => self.write_note(writer, note, false),
-            ControlData::Hyperlink(link) => self.write_hyperlink(writer, link),
-            ControlData::Bookmark(bookmark) => self.write_bookmark(writer, bookmark),
-            ControlData::AutoNumber(auto_num) => self.write_auto_number(writer, auto_num),
-            ControlData::NewNumber(new_num) => self.write_new_number(writer, new_num),
-            ControlData::PageNumber(page_num) => self.write_page_number(writer, page_num),
-            ControlData::HiddenComment(comment) => self.write_hidden_comment(writer, comment),
-            ControlData::Video(video) => self.write_video(writer, video),
-            ControlData::Ole(ole) => self.write_ole(writer, ole),
-            ControlData::Chart(chart) => self.write_chart(writer, chart),
-            ControlData::FormObject(form) => self.write_form_object(writer, form),
-            ControlData::TextArt(text_art) => self.write_text_art(writer, text_art),
-            ControlData::Field(field) => self.write_field(writer, field),
+            ControlData::SectionDefinition(secd) => {
+                data.write_u32(secd.properties);
+                data.write_u16(secd.column_gap);
+                data.write_u16(secd.vertical_grid);
+                data.write_u16(secd.horizontal_grid);
+                data.write_u32(secd.default_tab_interval);
+                data.write_u16(secd.numbering_shape_id);
+                data.write_u16(secd.starting_page_number);
+                data.write_u16(secd.starting_figure_number);
+                data.write_u16(secd.starting_table_number);
+                data.write_u16(secd.starting_equation_number);
+                data.write_u16(secd.language);
+                writer.write_record(RecordTagId::ControlHeader, level, &data.into_bytes());
+
+                self.write_page_definition(writer, &secd.page_definition, child_level);
+                // 미주 모양만 있어도 각주 모양을 먼저 기록해야 순서가 유지됨
+                if secd.footnote_shape.is_some() || secd.endnote_shape.is_some() {
+                    let footnote = secd.footnote_shape.clone().unwrap_or_default();
+                    self.write_note_shape(writer, &footnote, child_level);
+                }
+                if let Some(ref endnote) = secd.endnote_shape {
+                    self.write_note_shape(writer, endnote, child_level);
+                }
+                if let Some(ref border_fill) = secd.page_border_fill {
+                    self.write_page_border_fill(writer, border_fill, child_level);
+                }
+            }
+            ControlData::ColumnDefinition(cold) => {
+                data.write_u16(cold.properties);
+                data.write_u16(cold.column_gap);
+                if cold.properties & (1 << 12) == 0 {
+                    for width in &cold.column_widths {
+                        data.write_u16(*width);
+                    }
+                }
+                data.write_u16(cold.properties2);
+                data.write_u8(cold.separator_style);
+                data.write_u8(cold.separator_thickness);
+                data.write_u32(cold.separator_color);
+                writer.write_record(RecordTagId::ControlHeader, level, &data.into_bytes());
+            }
+            ControlData::Table(table) => {
+                write_object_common(&mut data, &table.common);
+                writer.write_record(RecordTagId::ControlHeader, level, &data.into_bytes());
+                self.write_table(writer, table, child_level);
+            }
+            ControlData::Picture(picture) => {
+                write_object_common(&mut data, &picture.common);
+                writer.write_record(RecordTagId::ControlHeader, level, &data.into_bytes());
+                if let Some(ref caption) = picture.caption {
+                    self.write_caption(writer, caption, child_level);
+                }
+                self.write_picture(writer, picture, child_level);
+            }
+            ControlData::Ole(ole) => {
+                write_object_common(&mut data, &ole.common);
+                writer.write_record(RecordTagId::ControlHeader, level, &data.into_bytes());
+                if let Some(ref caption) = ole.caption {
+                    self.write_caption(writer, caption, child_level);
+                }
+                self.write_ole(writer, ole, child_level);
+            }
+            ControlData::Equation(equation) => {
+                write_object_common(&mut data, &equation.common);
+                writer.write_record(RecordTagId::ControlHeader, level, &data.into_bytes());
+                if let Some(ref caption) = equation.caption {
+                    self.write_caption(writer, caption, child_level);
+                }
+                self.write_equation(writer, equation, child_level);
+            }
+            ControlData::Shape(shape) => {
+                write_object_common(&mut data, &shape.common);
+                writer.write_record(RecordTagId::ControlHeader, level, &data.into_bytes());
+                if let Some(ref caption) = shape.caption {
+                    self.write_caption(writer, caption, child_level);
+                }
+                self.write_shape_component(writer, &shape.shape, child_level, true);
+            }
+            ControlData::Header(header_footer) | ControlData::Footer(header_footer) => {
+                data.write_u32(header_footer.apply_to);
+                writer.write_record(RecordTagId::ControlHeader, level, &data.into_bytes());
+                // 텍스트 영역 폭/높이, 텍스트/번호 참조 플래그
+                let mut extra = ByteWriter::new();
+                extra.write_u32(0);
+                extra.write_u32(0);
+                extra.write_u8(0);
+                extra.write_u8(0);
+                self.write_list(
+                    writer,
+                    child_level,
+                    0,
+                    &extra.into_bytes(),
+                    &header_footer.paragraphs,
+                );
+            }
+            ControlData::Footnote(note) | ControlData::Endnote(note) => {
+                data.write_u32(0);
+                data.write_u16(note.number);
+                writer.write_record(RecordTagId::ControlHeader, level, &data.into_bytes());
+                self.write_list(writer, child_level, 0, &[], &note.paragraphs);
+            }
+            ControlData::Field(field) => {
+                data.write_u32(field.properties);
+                data.write_u8(field.extra_properties);
+                data.write_hwp_string(&field.command);
+                data.write_u32(field.instance_id);
+                writer.write_record(RecordTagId::ControlHeader, level, &data.into_bytes());
+                if let Some(ref parameters) = field.parameters {
+                    self.write_control_data(writer, parameters, child_level);
+                }
+            }
+            ControlData::Bookmark(bookmark) => {
+                writer.write_record(RecordTagId::ControlHeader, level, &data.into_bytes());
+                let parameters = ParameterSetData {
+                    set_id: 0x021B,
+                    items: vec![ParameterItemData {
+                        id: 0x4000,
+                        value: ParameterValueData::String(bookmark.name.clone()),
+                    }],
+                };
+                self.write_control_data(writer, &parameters, child_level);
+            }
+            ControlData::AutoNumber(auto_number) => {
+                data.write_u32((auto_number.number_type & 0x0F) | (auto_number.number_format << 4));
+                data.write_u16(0); // 번호
+                data.write_u16(0); // 사용자 기호
+                data.write_u16(0); // 앞 장식 문자
+                data.write_u16(0); // 뒤 장식 문자
+                writer.write_record(RecordTagId::ControlHeader, level, &data.into_bytes());
+            }
+            ControlData::NewNumber(new_number) => {
+                data.write_u32(new_number.number_type & 0x0F);
+                data.write_u16(new_number.number);
+                writer.write_record(RecordTagId::ControlHeader, level, &data.into_bytes());
+            }
+            ControlData::PageNumber(page_number) => {
+                // 속성: bit 0~3 위치, bit 4~11 번호 모양
+                data.write_u32((page_number.position & 0x0F) | (page_number.number_format << 4));
+                data.write_u16(0); // 사용자 기호
+                data.write_u16(0); // 앞 장식 문자
+                data.write_u16(0); // 뒤 장식 문자
+                data.write_u16(page_number.side_character);
+                writer.write_record(RecordTagId::ControlHeader, level, &data.into_bytes());
+            }
+            ControlData::IndexMark(index_mark) => {
+                data.write_hwp_string(&index_mark.first_key);
+                data.write_hwp_string(&index_mark.second_key);
+                writer.write_record(RecordTagId::ControlHeader, level, &data.into_bytes());
+            }
+            ControlData::HiddenComment(comment) => {
+                writer.write_record(RecordTagId::ControlHeader, level, &data.into_bytes());
+                self.write_list(writer, child_level, 0, &[], &comment.paragraphs);
+            }
+            ControlData::Compose(compose) => {
+                data.write_hwp_string(&compose.text);
+                data.write_u8(compose.border_type);
+                data.write_i8(compose.inner_char_size);
+                data.write_u8(u8::from(compose.spread_inner_chars));
+                data.write_u8(compose.char_shape_ids.len() as u8);
+                for &id in &compose.char_shape_ids {
+                    data.write_u32(id);
+                }
+                writer.write_record(RecordTagId::ControlHeader, level, &data.into_bytes());
+            }
+            ControlData::Dutmal(dutmal) => {
+                data.write_hwp_string(&dutmal.main_text);
+                data.write_hwp_string(&dutmal.sub_text);
+                data.write_u32(dutmal.position);
+                data.write_u32(dutmal.size_ratio);
+                data.write_u32(dutmal.option);
+                data.write_u32(dutmal.style_id);
+                data.write_u32(dutmal.alignment);
+                writer.write_record(RecordTagId::ControlHeader, level, &data.into_bytes());
+            }
+            ControlData::Video(video) => {
+                write_object_common(&mut data, &video.common);
+                writer.write_record(RecordTagId::ControlHeader, level, &data.into_bytes());
+                self.write_video(writer, video, child_level);
+            }
+            ControlData::Chart(chart) => {
+                write_object_common(&mut data, &chart.common);
+                writer.write_record(RecordTagId::ControlHeader, level, &data.into_bytes());
+                let mut chart_data = ByteWriter::new();
+                chart_data.write_u16(chart.chart_type);
+                writer.write_record(
+                    RecordTagId::ChartData,
+                    child_level,
+                    &chart_data.into_bytes(),
+                );
+            }
+            ControlData::FormObject(form) => {
+                write_object_common(&mut data, &form.common);
+                writer.write_record(RecordTagId::ControlHeader, level, &data.into_bytes());
+                self.write_form_object(writer, form, child_level);
+            }
+            ControlData::TextArt(text_art) => {
+                write_object_common(&mut data, &text_art.common);
+                writer.write_record(RecordTagId::ControlHeader, level, &data.into_bytes());
+                self.write_text_art(writer, text_art, child_level);
+            }
+            ControlData::Raw(raw) => {
+                data.write_bytes(&raw.data);
+                writer.write_record(RecordTagId::ControlHeader, level, &data.into_bytes());
+            }
         }
     }
 
-    /// 공통 컨트롤 헤더 작성 헬퍼
-    fn write_ctrl_header(
+    fn write_page_definition(
         &self,
         writer: &mut ByteWriter,
-        ctrl_id: &[u8; 4],
-        common: &ObjectCommonData,
+        page_def: &PageDefinitionData,
+        level: u16,
     ) {
-        let mut ctrl_header = ByteWriter::new();
-        // Control ID
-        ctrl_header.write_bytes(ctrl_id);
-        // 공통 속성
-        ctrl_header.write_u32(common.properties);
-        ctrl_header.write_i32(common.vertical_offset);
-        ctrl_header.write_i32(common.horizontal_offset);
-        ctrl_header.write_i32(common.width);
-        ctrl_header.write_i32(common.height);
-        ctrl_header.write_i32(common.z_order);
-        ctrl_header.write_u16(common.margin_left);
-        ctrl_header.write_u16(common.margin_right);
-        ctrl_header.write_u16(common.margin_top);
-        ctrl_header.write_u16(common.margin_bottom);
-        ctrl_header.write_u32(0); // instance_id
-        ctrl_header.write_u32(0); // prevent_page_break
-        ctrl_header.write_hwp_string(""); // description
-
-        let ctrl_header_bytes = ctrl_header.into_bytes();
-        writer.write_record(RecordTagId::ControlHeader, 1, &ctrl_header_bytes);
-    }
-
-    fn write_table(&self, writer: &mut ByteWriter, table: &TableData) {
-        // CTRL_HEADER (tbl )
-        self.write_ctrl_header(writer, b" tbl", &table.common);
-
-        // TABLE record
-        let mut table_data = ByteWriter::new();
-        table_data.write_u32(table.properties); // properties (bit 0-1: page break, bit 2: auto repeat title row)
-        table_data.write_u16(table.rows);
-        table_data.write_u16(table.columns);
-        table_data.write_u16(0); // cell_spacing
-        table_data.write_u16(0); // margin_left
-        table_data.write_u16(0); // margin_right
-
-        // 열 너비들
-        for width in &table.column_widths {
-            table_data.write_u16(*width);
-        }
-
-        table_data.write_u16(table.border_fill_id);
-        table_data.write_u16(0); // zone_info_count
-
-        let table_bytes = table_data.into_bytes();
-        writer.write_record(RecordTagId::Table, 2, &table_bytes);
-
-        // 셀들
-        for cell in &table.cells {
-            self.write_table_cell(writer, cell);
-        }
-    }
+        let mut data = ByteWriter::new();
 
-    fn write_table_cell(&self, writer: &mut ByteWriter, cell: &TableCellData) {
-        // LIST_HEADER + Cell properties
-        let mut list_header = ByteWriter::new();
-        list_header.write_u16(cell.paragraphs.len() as u16); // paragraph_count
-        list_header.write_u32(0); // properties
-        list_header.write_u16(cell.col); // col
-        list_header.write_u16(cell.row); // row
-        list_header.write_u16(cell.col_span); // col_span
-        list_header.write_u16(cell.row_span); // row_span
-        list_header.write_u32(cell.width); // width
-        list_header.write_u32(cell.height); // height
-        list_header.write_u16(0); // margin_left
-        list_header.write_u16(0); // margin_right
-        list_header.write_u16(0); // margin_top
-        list_header.write_u16(0); // margin_bottom
-        list_header.write_u16(cell.border_fill_id); // border_fill_id
-        list_header.write_u32(0); // unknown
-
-        let list_header_bytes = list_header.into_bytes();
-        writer.write_record(RecordTagId::ListHeader, 2, &list_header_bytes);
-
-        // 셀 내 문단들
-        for (i, para) in cell.paragraphs.iter().enumerate() {
-            self.write_paragraph(writer, para, (i + 1000) as u32);
-        }
-    }
+        data.write_u32(page_def.paper_width);
+        data.write_u32(page_def.paper_height);
+        data.write_u32(page_def.margin_left);
+        data.write_u32(page_def.margin_right);
+        data.write_u32(page_def.margin_top);
+        data.write_u32(page_def.margin_bottom);
+        data.write_u32(page_def.margin_header);
+        data.write_u32(page_def.margin_footer);
+        data.write_u32(page_def.margin_gutter);
+        data.write_u8(page_def.orientation);
+        data.write_u8(page_def.gutter_position);
+        data.write_u16(0);
 
-    fn write_picture(&self, writer: &mut ByteWriter, picture: &PictureData) {
-        // CTRL_HEADER (gso )
-        self.write_ctrl_header(writer, b" gso", &picture.common);
-
-        // SHAPE_COMPONENT_PICTURE
-        let mut pic_data = ByteWriter::new();
-        pic_data.write_u32(0); // border_color
-        pic_data.write_u32(0); // border_thickness
-        pic_data.write_u32(0); // border_properties
-        pic_data.write_i32(0); // crop_left
-        pic_data.write_i32(0); // crop_top
-        pic_data.write_i32(0); // crop_right
-        pic_data.write_i32(0); // crop_bottom
-        pic_data.write_u16(0); // inner_margin_left
-        pic_data.write_u16(0); // inner_margin_right
-        pic_data.write_u16(0); // inner_margin_top
-        pic_data.write_u16(0); // inner_margin_bottom
-        pic_data.write_u8(100); // brightness
-        pic_data.write_u8(100); // contrast
-        pic_data.write_u8(0); // effect
-        pic_data.write_u16(picture.binary_data_id);
-        pic_data.write_u8(0); // border_transparency
-        pic_data.write_u32(0); // instance_id
-        pic_data.write_u8(0); // picture_effect_info count
-        pic_data.write_u16(0); // image_width
-        pic_data.write_u16(0); // image_height
-
-        let pic_bytes = pic_data.into_bytes();
-        writer.write_record(RecordTagId::ShapeComponentPicture, 2, &pic_bytes);
+        writer.write_record(RecordTagId::PageDefinition, level, &data.into_bytes());
     }
 
-    fn write_textbox(&self, writer: &mut ByteWriter, textbox: &TextBoxData) {
-        // CTRL_HEADER (gso )
-        self.write_ctrl_header(writer, b" gso", &textbox.common);
-
-        // LIST_HEADER
-        let mut list_header = ByteWriter::new();
-        list_header.write_u16(textbox.paragraphs.len() as u16);
-        list_header.write_u32(0);
-
-        let list_header_bytes = list_header.into_bytes();
-        writer.write_record(RecordTagId::ListHeader, 2, &list_header_bytes);
-
-        // 내부 문단들
-        for (i, para) in textbox.paragraphs.iter().enumerate() {
-            self.write_paragraph(writer, para, (i + 2000) as u32);
-        }
-    }
+    fn write_note_shape(&self, writer: &mut ByteWriter, note: &NoteShapeData, level: u16) {
+        let mut data = ByteWriter::new();
 
-    fn write_equation(&self, writer: &mut ByteWriter, equation: &EquationData) {
-        // CTRL_HEADER (eqed)
-        self.write_ctrl_header(writer, b"deqe", &equation.common);
-
-        // EQUATION record
-        let mut eq_data = ByteWriter::new();
-        eq_data.write_u32(0); // properties
-        eq_data.write_hwp_string(&equation.script);
-        eq_data.write_u32(equation.base_size);
-        eq_data.write_u32(0); // text_color
-        eq_data.write_i16(100); // base_line
-        eq_data.write_hwp_string(""); // version_info
-        eq_data.write_hwp_string(""); // font_name
-
-        let eq_bytes = eq_data.into_bytes();
-        writer.write_record(RecordTagId::Equation, 2, &eq_bytes);
+        data.write_u32(note.properties);
+        data.write_u16(note.user_char);
+        data.write_u16(note.prefix_char);
+        data.write_u16(note.suffix_char);
+        data.write_u16(note.start_number);
+        data.write_u16(note.separator_length);
+        data.write_u16(note.separator_position);
+        data.write_u16(note.space_above);
+        data.write_u16(note.space_below);
+        data.write_u16(note.space_between);
+        data.write_u8(note.separator_line_type);
+        data.write_u8(note.separator_line_thickness);
+        data.write_u32(note.separator_line_color);
+
+        writer.write_record(RecordTagId::FootnoteShape, level, &data.into_bytes());
     }
 
-    fn write_header_footer(
+    fn write_page_border_fill(
         &self,
         writer: &mut ByteWriter,
-        data: &HeaderFooterData,
-        is_header: bool,
+        border_fill: &PageBorderFillData,
+        level: u16,
     ) {
-        // CTRL_HEADER (daeh for header, toof for footer)
-        let mut ctrl_header = ByteWriter::new();
-        if is_header {
-            ctrl_header.write_bytes(b"head"); // "daeh" in LE
-        } else {
-            ctrl_header.write_bytes(b"foot"); // "toof" in LE
-        }
-        ctrl_header.write_u32(data.apply_to as u32); // apply_to
-
-        let ctrl_header_bytes = ctrl_header.into_bytes();
-        writer.write_record(RecordTagId::ControlHeader, 1, &ctrl_header_bytes);
-
-        // LIST_HEADER
-        let mut list_header = ByteWriter::new();
-        list_header.write_u16(data.paragraphs.len() as u16); // paragraph_count
-        list_header.write_u32(0); // properties
+        let mut data = ByteWriter::new();
 
-        let list_header_bytes = list_header.into_bytes();
-        writer.write_record(RecordTagId::ListHeader, 2, &list_header_bytes);
+        data.write_u32(border_fill.properties);
+        data.write_i16(border_fill.offset_left);
+        data.write_i16(border_fill.offset_right);
+        data.write_i16(border_fill.offset_top);
+        data.write_i16(border_fill.offset_bottom);
+        data.write_u16(border_fill.border_fill_id);
 
-        // 내부 문단들
-        for (i, para) in data.paragraphs.iter().enumerate() {
-            self.write_paragraph(writer, para, (i + 3000) as u32);
-        }
+        writer.write_record(RecordTagId::PageBorderFill, level, &data.into_bytes());
     }
 
-    fn write_note(&self, writer: &mut ByteWriter, data: &NoteData, is_footnote: bool) {
-        // CTRL_HEADER (nf for footnote, ne for endnote)
-        let mut ctrl_header = ByteWriter::new();
-        if is_footnote {
-            ctrl_header.write_bytes(b"  nf"); // "fn  " in LE
-        } else {
-            ctrl_header.write_bytes(b"  ne"); // "en  " in LE
-        }
-        ctrl_header.write_u16(data.number);
-        ctrl_header.write_u16(0); // before_decoration
-        ctrl_header.write_u16(0); // after_decoration
-        ctrl_header.write_u32(0); // instance_id
-
-        let ctrl_header_bytes = ctrl_header.into_bytes();
-        writer.write_record(RecordTagId::ControlHeader, 1, &ctrl_header_bytes);
-
-        // LIST_HEADER
-        let mut list_header = ByteWriter::new();
-        list_header.write_u16(data.paragraphs.len() as u16);
-        list_header.write_u32(0);
-
-        let list_header_bytes = list_header.into_bytes();
-        writer.write_record(RecordTagId::ListHeader, 2, &list_header_bytes);
+    fn write_caption(&self, writer: &mut ByteWriter, caption: &CaptionData, level: u16) {
+        let direction = match caption.direction {
+            CaptionDirection::Left => 0,
+            CaptionDirection::Right => 1,
+            CaptionDirection::Above => 2,
+            CaptionDirection::Below => 3,
+        };
+        let mut extra = ByteWriter::new();
+        extra.write_u32(direction | (u32::from(caption.include_margin) << 2));
+        extra.write_u32(caption.width);
+        extra.write_i16(caption.gap);
+        extra.write_u32(caption.max_length);
 
-        // 내부 문단들
-        for (i, para) in data.paragraphs.iter().enumerate() {
-            self.write_paragraph(writer, para, (i + 4000) as u32);
-        }
+        self.write_list(writer, level, 0, &extra.into_bytes(), &caption.paragraphs);
     }
 
-    fn write_hyperlink(&self, writer: &mut ByteWriter, data: &HyperlinkData) {
-        // CTRL_HEADER (klh )
-        let mut ctrl_header = ByteWriter::new();
-        ctrl_header.write_bytes(b" klh"); // "hlk " in LE
-        ctrl_header.write_hwp_string(&data.url);
-        if let Some(ref tooltip) = data.tooltip {
-            ctrl_header.write_hwp_string(tooltip);
-        } else {
-            ctrl_header.write_hwp_string("");
+    fn write_table(&self, writer: &mut ByteWriter, table: &TableData, level: u16) {
+        // 캡션은 표 레코드보다 먼저 나와야 캡션으로 인식됨
+        if let Some(ref caption) = table.caption {
+            self.write_caption(writer, caption, level);
         }
 
-        let ctrl_header_bytes = ctrl_header.into_bytes();
-        writer.write_record(RecordTagId::ControlHeader, 1, &ctrl_header_bytes);
-    }
-
-    fn write_bookmark(&self, writer: &mut ByteWriter, data: &BookmarkData) {
-        // CTRL_HEADER (kobm)
-        let mut ctrl_header = ByteWriter::new();
-        ctrl_header.write_bytes(b"mbok"); // "bokm" in LE
-        ctrl_header.write_u32(0); // properties
-        ctrl_header.write_hwp_string(&data.name);
-
-        let ctrl_header_bytes = ctrl_header.into_bytes();
-        writer.write_record(RecordTagId::ControlHeader, 1, &ctrl_header_bytes);
-    }
-
-    fn write_auto_number(&self, writer: &mut ByteWriter, data: &AutoNumberData) {
-        // CTRL_HEADER (onta)
-        let mut ctrl_header = ByteWriter::new();
-        ctrl_header.write_bytes(b"atno"); // "atno" in LE
-        ctrl_header.write_u16(data.number_type);
-        ctrl_header.write_u16(data.number_format);
-        ctrl_header.write_u16(0); // superscript
-
-        let ctrl_header_bytes = ctrl_header.into_bytes();
-        writer.write_record(RecordTagId::ControlHeader, 1, &ctrl_header_bytes);
-    }
-
-    fn write_new_number(&self, writer: &mut ByteWriter, data: &NewNumberData) {
-        // CTRL_HEADER (nwno)
-        let mut ctrl_header = ByteWriter::new();
-        ctrl_header.write_bytes(b"nwno"); // "nwno" in LE
-        ctrl_header.write_u16(data.number_type);
-        ctrl_header.write_u16(data.number);
-
-        let ctrl_header_bytes = ctrl_header.into_bytes();
-        writer.write_record(RecordTagId::ControlHeader, 1, &ctrl_header_bytes);
-    }
-
-    fn write_page_number(&self, writer: &mut ByteWriter, data: &PageNumberData) {
-        // CTRL_HEADER (pgnp)
-        let mut ctrl_header = ByteWriter::new();
-        ctrl_header.write_bytes(b"pgnp"); // "pgnp" in LE
-
-        // 속성: bit 0~3: 위치, bit 4~11: 번호 모양
-        let properties = (data.position as u32) | ((data.number_format as u32) << 4);
-        ctrl_header.write_u32(properties);
-
-        // 줄표 문자 (UTF-16)
-        for ch in data.side_character.encode_utf16() {
-            ctrl_header.write_u16(ch);
+        let mut data = ByteWriter::new();
+        data.write_u32(table.properties);
+        data.write_u16(table.rows);
+        data.write_u16(table.columns);
+        data.write_i16(table.cell_spacing);
+        for padding in table.padding {
+            data.write_i16(padding);
         }
-        ctrl_header.write_u16(0); // NULL terminator
-
-        let ctrl_header_bytes = ctrl_header.into_bytes();
-        writer.write_record(RecordTagId::ControlHeader, 1, &ctrl_header_bytes);
-    }
-
-    fn write_hidden_comment(&self, writer: &mut ByteWriter, data: &HiddenCommentData) {
-        // CTRL_HEADER (tcmo)
-        let mut ctrl_header = ByteWriter::new();
-        ctrl_header.write_bytes(b"omct"); // "tcmo" in LE
-
-        let ctrl_header_bytes = ctrl_header.into_bytes();
-        writer.write_record(RecordTagId::ControlHeader, 1, &ctrl_header_bytes);
-
-        // LIST_HEADER
-        let mut list_header = ByteWriter::new();
-        list_header.write_u16(data.paragraphs.len() as u16);
-        list_header.write_u32(0);
-
-        let list_header_bytes = list_header.into_bytes();
-        writer.write_record(RecordTagId::ListHeader, 2, &list_header_bytes);
-
-        // 내부 문단들
-        for (i, para) in data.paragraphs.iter().enumerate() {
-            self.write_paragraph(writer, para, (i + 5000) as u32);
+        for row in 0..usize::from(table.rows) {
+            data.write_u16(table.row_heights.get(row).copied().unwrap_or(0));
         }
-    }
-
-    fn write_video(&self, writer: &mut ByteWriter, video: &VideoData) {
-        // CTRL_HEADER (vid )
-        self.write_ctrl_header(writer, b" vid", &video.common);
-
-        // VIDEO_DATA
-        let mut video_data = ByteWriter::new();
-        video_data.write_i32(video.video_type as i32);
-
-        if video.video_type == 0 {
-            // 임베디드 비디오
-            video_data.write_u16(video.bin_data_id);
-            video_data.write_u16(video.poster_bin_id);
-        } else {
-            // 링크 비디오 - URL 문자열 작성
-            video_data.write_hwp_string(&video.source_url);
-            video_data.write_u16(video.poster_bin_id);
+        data.write_u16(table.border_fill_id);
+        data.write_u16(table.zones.len() as u16);
+        for zone in &table.zones {
+            data.write_u16(zone.start_column);
+            data.write_u16(zone.start_row);
+            data.write_u16(zone.end_column);
+            data.write_u16(zone.end_row);
+            data.write_u16(zone.border_fill_id);
         }
+        writer.write_record(RecordTagId::Table, level, &data.into_bytes());
 
-        let video_data_bytes = video_data.into_bytes();
-        writer.write_record(RecordTagId::VideoData, 1, &video_data_bytes);
-    }
-
-    fn write_ole(&self, writer: &mut ByteWriter, ole: &OleData) {
-        // CTRL_HEADER (ole )
-        self.write_ctrl_header(writer, b" ole", &ole.common);
-
-        // OLE_OBJECT
-        let mut ole_data = ByteWriter::new();
-        ole_data.write_u32(ole.properties);
-        ole_data.write_i32(ole.extent_width);
-        ole_data.write_i32(ole.extent_height);
-        ole_data.write_u16(ole.bin_data_id);
-        ole_data.write_u32(ole.border_color);
-        ole_data.write_i32(ole.border_thickness);
-        ole_data.write_u32(0); // border_properties
-
-        let ole_data_bytes = ole_data.into_bytes();
-        writer.write_record(RecordTagId::ShapeComponentOle, 1, &ole_data_bytes);
-    }
-
-    fn write_chart(&self, writer: &mut ByteWriter, chart: &ChartData) {
-        // CTRL_HEADER (cht )
-        self.write_ctrl_header(writer, b" cht", &chart.common);
-
-        // CHART_DATA (2바이트만)
-        let mut chart_data = ByteWriter::new();
-        chart_data.write_u16(chart.chart_type as u16);
-
-        let chart_data_bytes = chart_data.into_bytes();
-        writer.write_record(RecordTagId::ChartData, 1, &chart_data_bytes);
-    }
-
-    fn write_form_object(&self, writer: &mut ByteWriter, form: &FormObjectData) {
-        // CTRL_HEADER (form)
-        self.write_ctrl_header(writer, b"mrof", &form.common);
-
-        // FORM_OBJECT
-        let mut form_data = ByteWriter::new();
-        form_data.write_u32(form.form_type as u32); // properties (하위 바이트가 form type)
-        form_data.write_hwp_string(&form.name);
-        form_data.write_hwp_string(&form.value);
-
-        let form_data_bytes = form_data.into_bytes();
-        writer.write_record(RecordTagId::FormObject, 1, &form_data_bytes);
-    }
-
-    fn write_text_art(&self, writer: &mut ByteWriter, text_art: &TextArtData) {
-        // CTRL_HEADER (gso ) - TextArt is a type of general shape object
-        self.write_ctrl_header(writer, b" osg", &text_art.common);
-
-        // SHAPE_COMPONENT for TextArt
-        let mut shape_comp = ByteWriter::new();
-        shape_comp.write_bytes(b" tra"); // "art " in LE for TextArt
-        shape_comp.write_i32(0); // x_pos
-        shape_comp.write_i32(0); // y_pos
-        shape_comp.write_u16(0); // group_level
-        shape_comp.write_u16(0); // local_file_version
-        shape_comp.write_u32(text_art.width); // initial_width
-        shape_comp.write_u32(text_art.height); // initial_height
-        shape_comp.write_u32(text_art.width); // current_width
-        shape_comp.write_u32(text_art.height); // current_height
-        shape_comp.write_u32(0); // properties
-        shape_comp.write_u16(0); // rotation_angle
-        shape_comp.write_i16(0); // center_x
-        shape_comp.write_i16(0); // center_y
-        // 변환 행렬 (6x6 단위 행렬 근사)
-        for _ in 0..6 {
-            shape_comp.write_i32(1);
-            shape_comp.write_i32(0);
+        for cell in &table.cells {
+            let mut extra = ByteWriter::new();
+            extra.write_u16(cell.col);
+            extra.write_u16(cell.row);
+            extra.write_u16(cell.col_span);
+            extra.write_u16(cell.row_span);
+            extra.write_u32(cell.width);
+            extra.write_u32(cell.height);
+            for padding in cell.padding {
+                extra.write_u16(padding);
+            }
+            extra.write_u16(cell.border_fill_id);
+
+            self.write_list(
+                writer,
+                level,
+                cell.list_properties,
+                &extra.into_bytes(),
+                &cell.paragraphs,
+            );
         }
-
-        let shape_comp_bytes = shape_comp.into_bytes();
-        writer.write_record(RecordTagId::ShapeComponent, 1, &shape_comp_bytes);
-
-        // SHAPE_COMPONENT_TEXTART
-        let mut text_art_data = ByteWriter::new();
-        text_art_data.write_hwp_string(&text_art.text);
-        text_art_data.write_hwp_string(text_art.font_name.as_deref().unwrap_or("함초롬바탕"));
-        text_art_data.write_u8(text_art.font_style);
-        text_art_data.write_u8(text_art.shape_type);
-        text_art_data.write_u32(text_art.line_spacing);
-        text_art_data.write_u32(text_art.char_spacing);
-        text_art_data.write_u8(text_art.alignment);
-
-        let text_art_bytes = text_art_data.into_bytes();
-        writer.write_record(RecordTagId::ShapeComponentTextArt, 1, &text_art_bytes);
     }
 
-    fn write_field(&self, writer: &mut ByteWriter, field: &FieldData) {
-        // 필드 컨트롤 헤더 작성
-        // 필드는 ObjectCommon 없이 컨트롤 ID만 필요
-        let ctrl_id = field.field_type.to_control_id();
-
-        let mut ctrl_header = ByteWriter::new();
-        ctrl_header.write_bytes(ctrl_id);
-        // 필드 속성 (properties) - 기본값
-        ctrl_header.write_u32(0);
-
-        let header_bytes = ctrl_header.into_bytes();
-        writer.write_record(RecordTagId::ControlHeader, 1, &header_bytes);
-
-        // CTRL_DATA (필드 명령 저장)
-        // Parameter Set 형식으로 instruction 저장
-        if !field.instruction.is_empty() {
-            let mut ctrl_data = ByteWriter::new();
-            // Parameter Set format: Item ID(2) + Item Type(2) + Data
-            // Item ID 0x4000 = command/instruction
-            ctrl_data.write_u16(0x4000); // FIELD_COMMAND item ID
-            ctrl_data.write_u16(0x0001); // Item type: string (HWPSTRING)
-            ctrl_data.write_hwp_string(&field.instruction);
-
-            let data_bytes = ctrl_data.into_bytes();
-            writer.write_record(RecordTagId::ControlData, 1, &data_bytes);
-        }
-    }
+    fn write_picture(&self, writer: &mut ByteWriter, picture: &PictureData, level: u16) {
+        let mut data = ByteWriter::new();
+        write_shape_element(&mut data, &picture.element, true);
+        writer.write_record(RecordTagId::ShapeComponent, level, &data.into_bytes());
 
-    fn write_shape(&self, writer: &mut ByteWriter, shape: &ShapeData) {
-        // CTRL_HEADER (gso )
-        self.write_ctrl_header(writer, b" gso", &shape.common);
-
-        // SHAPE_COMPONENT (공통 도형 속성)
-        let mut shape_comp = ByteWriter::new();
-        // 도형 타입에 따라 컴포넌트 ID 결정
-        let component_id: [u8; 4] = match &shape.shape_type {
-            ShapeTypeData::Line(_) => [b'e', b'n', b'i', b'l'], // "line" in LE
-            ShapeTypeData::Rectangle(_) => [b't', b'c', b'e', b'r'], // "rect" in LE
-            ShapeTypeData::Ellipse(_) => [b'l', b'l', b'e', b' '], // " ell" in LE
-            ShapeTypeData::Arc(_) => [b' ', b'c', b'r', b'a'],  // "arc " in LE
-            ShapeTypeData::Polygon(_) => [b'g', b'l', b'o', b'p'], // "poly" in LE (approximation)
-            ShapeTypeData::Curve(_) => [b'v', b'r', b'u', b'c'], // "curv" in LE
-            ShapeTypeData::Container(_) => [b' ', b'n', b'o', b'c'], // "con " in LE (container)
-            ShapeTypeData::Connector(_) => [b't', b'c', b'n', b'c'], // "cnct" in LE (connector)
-        };
-        shape_comp.write_bytes(&component_id);
-        shape_comp.write_i32(0); // x_pos
-        shape_comp.write_i32(0); // y_pos
-        shape_comp.write_u16(0); // group_level
-        shape_comp.write_u16(0); // local_file_version
-        shape_comp.write_u32(shape.width); // initial_width
-        shape_comp.write_u32(shape.height); // initial_height
-        shape_comp.write_u32(shape.width); // current_width
-        shape_comp.write_u32(shape.height); // current_height
-        shape_comp.write_u32(0); // properties
-        shape_comp.write_u16((shape.rotation * 100.0) as u16); // rotation_angle (degrees * 100)
-        shape_comp.write_i32(0); // rotation_center_x
-        shape_comp.write_i32(0); // rotation_center_y
-        shape_comp.write_u16(1); // render_count (1 = 1개의 도형 요소)
-
-        // 변환 행렬 (18개 element: translation 6 + scale 6 + rotation 6)
-        // Translation matrix
-        if let Some(trans) = shape.translation_matrix {
-            for val in &trans {
-                shape_comp.write_i32((*val * 65536.0) as i32); // fixed point representation
-            }
-        } else {
-            // 단위 행렬
-            shape_comp.write_i32(65536);
-            shape_comp.write_i32(0);
-            shape_comp.write_i32(0);
-            shape_comp.write_i32(65536);
-            shape_comp.write_i32(0);
-            shape_comp.write_i32(0);
+        let mut data = ByteWriter::new();
+        data.write_u32(picture.border_color);
+        data.write_i32(picture.border_thickness);
+        data.write_u32(picture.border_properties);
+        for (x, y) in picture.corners {
+            data.write_i32(x);
+            data.write_i32(y);
         }
-
-        // Scale matrix
-        if let Some(scale) = shape.scale_matrix {
-            for val in &scale {
-                shape_comp.write_i32((*val * 65536.0) as i32);
-            }
-        } else {
-            // 단위 행렬
-            shape_comp.write_i32(65536);
-            shape_comp.write_i32(0);
-            shape_comp.write_i32(0);
-            shape_comp.write_i32(65536);
-            shape_comp.write_i32(0);
-            shape_comp.write_i32(0);
+        for crop in picture.crop {
+            data.write_i32(crop);
         }
-
-        // Rotation matrix
-        if let Some(rot) = shape.rotation_matrix {
-            for val in &rot {
-                shape_comp.write_i32((*val * 65536.0) as i32);
-            }
-        } else {
-            // 단위 행렬
-            shape_comp.write_i32(65536);
-            shape_comp.write_i32(0);
-            shape_comp.write_i32(0);
-            shape_comp.write_i32(65536);
-            shape_comp.write_i32(0);
-            shape_comp.write_i32(0);
+        for margin in picture.inner_margin {
+            data.write_u16(margin);
         }
-
-        let shape_comp_bytes = shape_comp.into_bytes();
-        writer.write_record(RecordTagId::ShapeComponent, 2, &shape_comp_bytes);
-
-        // 도형 타입별 레코드
-        match &shape.shape_type {
-            ShapeTypeData::Line(line) => self.write_shape_line(writer, line, shape),
-            ShapeTypeData::Rectangle(rect) => self.write_shape_rectangle(writer, rect, shape),
-            ShapeTypeData::Ellipse(ellipse) => self.write_shape_ellipse(writer, ellipse, shape),
-            ShapeTypeData::Arc(arc) => self.write_shape_arc(writer, arc, shape),
-            ShapeTypeData::Polygon(poly) => self.write_shape_polygon(writer, poly, shape),
-            ShapeTypeData::Curve(curve) => self.write_shape_curve(writer, curve, shape),
-            ShapeTypeData::Container(container) => self.write_shape_container(writer, container),
-            ShapeTypeData::Connector(connector) => {
-                self.write_shape_connector(writer, connector, shape)
-            }
+        data.write_i8(picture.brightness);
+        data.write_i8(picture.contrast);
+        data.write_u8(picture.effect);
+        data.write_u16(picture.binary_data_id);
+        data.write_u8(picture.border_transparency);
+        data.write_u32(picture.instance_id);
+        data.write_u32(0); // 그림 효과 없음
+        data.write_u32(picture.original_size.0);
+        data.write_u32(picture.original_size.1);
+        if let Some(color) = picture.transparent_color {
+            data.write_u32(color);
         }
+        writer.write_record(
+            RecordTagId::ShapeComponentPicture,
+            level + 1,
+            &data.into_bytes(),
+        );
     }
 
-    fn write_shape_container(&self, writer: &mut ByteWriter, container: &ContainerShapeData) {
-        // Container (그룹 도형)의 모든 자식 도형 재귀적으로 작성
-        for child in &container.children {
-            self.write_shape(writer, child);
-        }
-    }
+    fn write_ole(&self, writer: &mut ByteWriter, ole: &OleData, level: u16) {
+        let mut data = ByteWriter::new();
+        write_shape_element(&mut data, &ole.element, true);
+        writer.write_record(RecordTagId::ShapeComponent, level, &data.into_bytes());
 
-    fn write_shape_line(&self, writer: &mut ByteWriter, line: &LineShapeData, shape: &ShapeData) {
         let mut data = ByteWriter::new();
-        data.write_i32(line.start_x);
-        data.write_i32(line.start_y);
-        data.write_i32(line.end_x);
-        data.write_i32(line.end_y);
-        // 선 스타일
-        data.write_u32(shape.line_color); // color
-        data.write_u16(shape.line_thickness); // thickness
-        data.write_u8(0); // line_type (실선)
-        data.write_u8(0); // end_cap
-        data.write_u8(line.start_arrow_type);
-        data.write_u8(line.start_arrow_size);
-        data.write_u8(line.end_arrow_type);
-        data.write_u8(line.end_arrow_size);
-
-        let data_bytes = data.into_bytes();
-        writer.write_record(RecordTagId::ShapeComponentLine, 2, &data_bytes);
+        data.write_u32(ole.properties);
+        data.write_i32(ole.extent_width);
+        data.write_i32(ole.extent_height);
+        data.write_u16(ole.bin_data_id);
+        data.write_u32(ole.border_color);
+        data.write_i32(ole.border_thickness);
+        data.write_u32(ole.border_properties);
+        writer.write_record(
+            RecordTagId::ShapeComponentOle,
+            level + 1,
+            &data.into_bytes(),
+        );
     }
 
-    fn write_shape_rectangle(
-        &self,
-        writer: &mut ByteWriter,
-        rect: &RectangleShapeData,
-        shape: &ShapeData,
-    ) {
+    fn write_equation(&self, writer: &mut ByteWriter, equation: &EquationData, level: u16) {
         let mut data = ByteWriter::new();
-        data.write_u8(1); // ratio
-        data.write_u32(rect.corner_radius); // corner_radius_x
-        data.write_u32(rect.corner_radius); // corner_radius_y
-        data.write_u32(rect.corner_radius);
-        data.write_u32(rect.corner_radius);
-        data.write_u32(rect.corner_radius);
-        data.write_u32(rect.corner_radius);
-        data.write_u32(rect.corner_radius);
-        data.write_u32(rect.corner_radius);
-        // 선 스타일
-        data.write_u32(shape.line_color);
-        data.write_u16(shape.line_thickness);
-        data.write_u8(0); // line_type
-        data.write_u8(0); // end_cap
-        // 채우기 (구버전 호환: 단순 색상으로 작성)
-        data.write_u32(Self::get_fill_color(&shape.fill));
-
-        let data_bytes = data.into_bytes();
-        writer.write_record(RecordTagId::ShapeComponentRectangle, 2, &data_bytes);
+        data.write_u32(equation.properties);
+        data.write_hwp_string(&equation.script);
+        data.write_u32(equation.base_size);
+        data.write_u32(equation.color);
+        data.write_i16(equation.baseline);
+        data.write_hwp_string(&equation.version);
+        data.write_hwp_string(&equation.font_name);
+        writer.write_record(RecordTagId::Equation, level, &data.into_bytes());
     }
 
-    fn write_shape_ellipse(
+    /// SHAPE_COMPONENT 레코드와 하위 레코드들을 기록합니다.
+    ///
+    /// 컨트롤 바로 아래의 도형은 개체 종류 ID를 두 번 기록하고,
+    /// 묶음 개체의 자식은 한 번만 기록합니다.
+    fn write_shape_component(
         &self,
         writer: &mut ByteWriter,
-        ellipse: &EllipseShapeData,
         shape: &ShapeData,
+        level: u16,
+        top_level: bool,
     ) {
         let mut data = ByteWriter::new();
-        data.write_u32(0); // properties
-        data.write_i32(0); // center_x
-        data.write_i32(0); // center_y
-        data.write_i32((shape.width / 2) as i32); // axis1_x
-        data.write_i32(0); // axis1_y
-        data.write_i32(0); // axis2_x
-        data.write_i32((shape.height / 2) as i32); // axis2_y
-        data.write_i32(ellipse.start_angle); // start_angle (x100)
-        data.write_i32(0); // start1_x
-        data.write_i32(0); // start1_y
-        data.write_i32(ellipse.end_angle); // end_angle (x100)
-        data.write_i32(0); // end1_x
-        data.write_i32(0); // end1_y
-        data.write_u8(ellipse.arc_type); // arc_type
-        // 선 스타일
-        data.write_u32(shape.line_color);
-        data.write_u16(shape.line_thickness);
-        data.write_u8(0); // line_type
-        data.write_u8(0); // end_cap
-
-        let data_bytes = data.into_bytes();
-        writer.write_record(RecordTagId::ShapeComponentEllipse, 2, &data_bytes);
-    }
+        write_shape_element(&mut data, &shape.element, top_level);
+
+        // 묶음 개체는 요소 속성만 가짐
+        if !matches!(shape.geometry, ShapeGeometry::Container { .. }) {
+            data.write_u32(shape.border.color);
+            data.write_i32(shape.border.thickness);
+            data.write_u32(shape.border.properties);
+            data.write_u8(shape.border.outline_style);
+            super::doc_info_writer::write_fill_info(&mut data, &shape.fill);
+            data.write_u32(shape.shadow.shadow_type);
+            data.write_u32(shape.shadow.color);
+            data.write_i32(shape.shadow.offset_x);
+            data.write_i32(shape.shadow.offset_y);
+            data.write_u32(0); // 인스턴스 ID
+            data.write_u8(0);
+            data.write_u8(shape.shadow.alpha);
+        }
+        writer.write_record(RecordTagId::ShapeComponent, level, &data.into_bytes());
+
+        let child_level = level + 1;
+
+        if let Some(ref text) = shape.text {
+            let mut extra = ByteWriter::new();
+            for margin in text.margins {
+                extra.write_u16(margin);
+            }
+            extra.write_u32(text.max_width);
+            self.write_list(
+                writer,
+                child_level,
+                text.list_properties,
+                &extra.into_bytes(),
+                &text.paragraphs,
+            );
+        }
 
-    fn write_shape_arc(&self, writer: &mut ByteWriter, arc: &ArcShapeData, shape: &ShapeData) {
         let mut data = ByteWriter::new();
-        data.write_u8(arc.arc_type);
-        data.write_i32(0); // center_x
-        data.write_i32(0); // center_y
-        data.write_i32((shape.width / 2) as i32); // axis1_x
-        data.write_i32(0); // axis1_y
-        data.write_i32(0); // axis2_x
-        data.write_i32((shape.height / 2) as i32); // axis2_y
-        data.write_i32(arc.start_angle);
-        data.write_i32(arc.end_angle);
-        // 선 스타일
-        data.write_u32(shape.line_color);
-        data.write_u16(shape.line_thickness);
-        data.write_u8(0); // line_type
-        data.write_u8(0); // end_cap
-
-        let data_bytes = data.into_bytes();
-        writer.write_record(RecordTagId::ShapeComponentArc, 2, &data_bytes);
+        let tag = match &shape.geometry {
+            ShapeGeometry::Line {
+                start,
+                end,
+                reversed,
+            } => {
+                write_point(&mut data, *start);
+                write_point(&mut data, *end);
+                data.write_u32(u32::from(*reversed));
+                RecordTagId::ShapeComponentLine
+            }
+            ShapeGeometry::Rectangle {
+                round_ratio,
+                corners,
+            } => {
+                data.write_u8(*round_ratio);
+                for corner in corners {
+                    write_point(&mut data, *corner);
+                }
+                RecordTagId::ShapeComponentRectangle
+            }
+            ShapeGeometry::Ellipse { properties, points } => {
+                data.write_u32(*properties);
+                for point in points {
+                    write_point(&mut data, *point);
+                }
+                RecordTagId::ShapeComponentEllipse
+            }
+            ShapeGeometry::Arc { arc_type, points } => {
+                data.write_u8(*arc_type);
+                for point in points {
+                    write_point(&mut data, *point);
+                }
+                RecordTagId::ShapeComponentArc
+            }
+            ShapeGeometry::Polygon { points } => {
+                data.write_u32(points.len() as u32);
+                for point in points {
+                    write_point(&mut data, *point);
+                }
+                RecordTagId::ShapeComponentPolygon
+            }
+            ShapeGeometry::Curve {
+                points,
+                segment_types,
+            } => {
+                data.write_u32(points.len() as u32);
+                for point in points {
+                    write_point(&mut data, *point);
+                }
+                for i in 0..points.len() {
+                    data.write_u8(segment_types.get(i).copied().unwrap_or(0));
+                }
+                RecordTagId::ShapeComponentCurve
+            }
+            ShapeGeometry::Container { children } => {
+                data.write_u16(children.len() as u16);
+                for child in children {
+                    data.write_u32(child.element.element_type);
+                }
+                writer.write_record(
+                    RecordTagId::ShapeComponentContainer,
+                    child_level,
+                    &data.into_bytes(),
+                );
+                for child in children {
+                    self.write_shape_component(writer, child, child_level, false);
+                }
+                return;
+            }
+        };
+        writer.write_record(tag, child_level, &data.into_bytes());
     }
 
-    fn write_shape_polygon(
-        &self,
-        writer: &mut ByteWriter,
-        poly: &PolygonShapeData,
-        shape: &ShapeData,
-    ) {
+    fn write_video(&self, writer: &mut ByteWriter, video: &VideoData, level: u16) {
         let mut data = ByteWriter::new();
-        data.write_u16(poly.points.len() as u16); // point_count
-        for (x, y) in &poly.points {
-            data.write_i32(*x);
-            data.write_i32(*y);
+        data.write_i32(video.video_type);
+        if video.video_type == 0 {
+            data.write_u16(video.bin_data_id);
+        } else {
+            for ch in video.source_url.encode_utf16() {
+                data.write_u16(ch);
+            }
+            data.write_u16(0);
         }
-        // 선 스타일
-        data.write_u32(shape.line_color);
-        data.write_u16(shape.line_thickness);
-        data.write_u8(0); // line_type
-        data.write_u8(0); // end_cap
-        // 채우기 (구버전 호환: 단순 색상으로 작성)
-        data.write_u32(Self::get_fill_color(&shape.fill));
-
-        let data_bytes = data.into_bytes();
-        writer.write_record(RecordTagId::ShapeComponentPolygon, 2, &data_bytes);
+        data.write_u16(video.poster_bin_id);
+        writer.write_record(RecordTagId::VideoData, level, &data.into_bytes());
     }
 
-    fn write_shape_curve(
-        &self,
-        writer: &mut ByteWriter,
-        curve: &CurveShapeData,
-        shape: &ShapeData,
-    ) {
+    fn write_form_object(&self, writer: &mut ByteWriter, form: &FormObjectData, level: u16) {
         let mut data = ByteWriter::new();
-        data.write_u16(curve.points.len() as u16); // point_count
-        for (x, y, pt_type) in &curve.points {
-            data.write_i32(*x);
-            data.write_i32(*y);
-            data.write_u8(*pt_type);
+        data.write_u32(form.properties);
+        data.write_hwp_string(&form.name);
+        data.write_hwp_string(&form.value);
+
+        match form.properties & 0xFF {
+            // 명령 단추, 선택 상자, 라디오 단추
+            1 | 2 | 5 => {
+                data.write_hwp_string(&form.caption);
+                data.write_u32(form.back_color);
+                if form.properties & 0xFF == 2 {
+                    data.write_hwp_string(&form.radio_group_name);
+                }
+            }
+            // 입력 상자
+            0 => {
+                data.write_u16(form.password_char);
+                data.write_u32(form.max_length);
+            }
+            // 콤보 상자, 목록 상자
+            3 | 4 => {
+                data.write_hwp_string(&form.selected_value);
+                data.write_i32(form.list_box_rows);
+                data.write_i32(form.list_box_width);
+                data.write_i32(form.item_height);
+                data.write_u32(form.top_index);
+                data.write_u16(form.items.len() as u16);
+                for (text, value) in &form.items {
+                    data.write_hwp_string(text);
+                    data.write_hwp_string(value);
+                }
+            }
+            _ => {}
         }
-        data.write_u8(if curve.closed { 1 } else { 0 }); // closed
-        // 선 스타일
-        data.write_u32(shape.line_color);
-        data.write_u16(shape.line_thickness);
-        data.write_u8(0); // line_type
-        data.write_u8(0); // end_cap
-
-        let data_bytes = data.into_bytes();
-        writer.write_record(RecordTagId::ShapeComponentCurve, 2, &data_bytes);
+
+        writer.write_record(RecordTagId::FormObject, level, &data.into_bytes());
     }
 
-    fn write_shape_connector(
-        &self,
-        writer: &mut ByteWriter,
-        connector: &ConnectorShapeData,
-        shape: &ShapeData,
-    ) {
-        // 연결선은 특수한 선 타입으로 처리
+    fn write_text_art(&self, writer: &mut ByteWriter, text_art: &TextArtData, level: u16) {
         let mut data = ByteWriter::new();
+        data.write_hwp_string(&text_art.text);
+        data.write_hwp_string(&text_art.font_name);
+        data.write_u32(text_art.font_style);
+        data.write_u32(text_art.shape_type);
+        data.write_u32(text_art.alignment);
+        data.write_u32(text_art.text_color);
+        data.write_u32(text_art.outline_color);
+        data.write_u32(text_art.shadow_color);
+        writer.write_record(
+            RecordTagId::ShapeComponentTextArt,
+            level,
+            &data.into_bytes(),
+        );
+    }
 
-        // 연결선 타입 (0~8)
-        data.write_u8(connector.line_type);
-
-        // 시작점
-        data.write_i32(connector.start_x);
-        data.write_i32(connector.start_y);
-        data.write_u32(connector.start_subject_id.unwrap_or(0));
-        data.write_u32(connector.start_subject_index.unwrap_or(0));
-
-        // 끝점
-        data.write_i32(connector.end_x);
-        data.write_i32(connector.end_y);
-        data.write_u32(connector.end_subject_id.unwrap_or(0));
-        data.write_u32(connector.end_subject_index.unwrap_or(0));
-
-        // 제어점 개수 및 데이터
-        data.write_u16(connector.control_points.len() as u16);
-        for (x, y) in &connector.control_points {
-            data.write_i32(*x);
-            data.write_i32(*y);
+    fn write_control_data(&self, writer: &mut ByteWriter, set: &ParameterSetData, level: u16) {
+        let mut data = ByteWriter::new();
+        data.write_u16(set.set_id);
+        data.write_i16(set.items.len() as i16);
+        for item in &set.items {
+            data.write_u16(item.id);
+            match &item.value {
+                ParameterValueData::String(value) => {
+                    data.write_u16(1);
+                    data.write_hwp_string(value);
+                }
+                ParameterValueData::Integer(value) => {
+                    data.write_u16(4);
+                    data.write_i32(*value);
+                }
+                ParameterValueData::Unsigned(value) => {
+                    data.write_u16(7);
+                    data.write_u32(*value);
+                }
+                ParameterValueData::Boolean(value) => {
+                    data.write_u16(8);
+                    data.write_u32(u32::from(*value));
+                }
+            }
         }
+        writer.write_record(RecordTagId::ControlData, level, &data.into_bytes());
+    }
+}
 
-        // 선 스타일
-        data.write_u32(shape.line_color);
-        data.write_u16(shape.line_thickness);
-        data.write_u8(0); // line_style (실선)
-        data.write_u8(0); // end_cap
-
-        // 화살표 스타일
-        data.write_u8(connector.start_arrow_type);
-        data.write_u8(connector.start_arrow_size);
-        data.write_u8(connector.end_arrow_type);
-        data.write_u8(connector.end_arrow_size);
+/// 개체 공통 속성을 기록합니다 (컨트롤 ID 다음).
+fn write_object_common(data: &mut ByteWriter, common: &ObjectCommonData) {
+    data.write_u32(common.properties);
+    data.write_i32(common.vertical_offset);
+    data.write_i32(common.horizontal_offset);
+    data.write_i32(common.width);
+    data.write_i32(common.height);
+    data.write_i32(common.z_order);
+    data.write_i16(common.margin_left);
+    data.write_i16(common.margin_right);
+    data.write_i16(common.margin_top);
+    data.write_i16(common.margin_bottom);
+    data.write_u32(common.instance_id);
+    data.write_i32(common.prevent_page_break);
+    data.write_hwp_string(&common.description);
+}
 
-        let data_bytes = data.into_bytes();
-        writer.write_record(RecordTagId::ShapeComponentLine, 2, &data_bytes);
+/// 그리기 개체 요소 속성을 기록합니다.
+fn write_shape_element(data: &mut ByteWriter, element: &ShapeElementData, top_level: bool) {
+    data.write_u32(element.element_type);
+    if top_level {
+        data.write_u32(element.element_type);
     }
-
-    fn calculate_char_count(&self, para: &ParagraphData) -> u32 {
-        // 텍스트 길이 + 문단 끝 표시 (13)
-        para.text.len() as u32 + 1
+    data.write_i32(element.offset_x);
+    data.write_i32(element.offset_y);
+    data.write_u16(element.group_level);
+    data.write_u16(element.local_version);
+    data.write_u32(element.initial_width);
+    data.write_u32(element.initial_height);
+    data.write_u32(element.width);
+    data.write_u32(element.height);
+    data.write_u32(element.properties);
+    data.write_i16(element.rotation);
+    data.write_i32(element.center_x);
+    data.write_i32(element.center_y);
+
+    // 이동 행렬 1개 + (크기, 회전) 행렬 쌍
+    const IDENTITY: [f64; 6] = [1.0, 0.0, 0.0, 0.0, 1.0, 0.0];
+    let pair_count = element.matrix.len().saturating_sub(6) / 12;
+    data.write_u16(pair_count as u16);
+    for i in 0..6 + pair_count * 12 {
+        let value = element.matrix.get(i).copied().unwrap_or(IDENTITY[i % 6]);
+        data.write_bytes(&value.to_le_bytes());
     }
+}
 
-    /// FillInfo에서 단순 색상값 추출 (구버전 호환용)
-    fn get_fill_color(fill: &FillInfo) -> u32 {
-        match fill {
-            FillInfo::None => 0xFFFFFF,
-            FillInfo::Pattern(pattern) => pattern.background_color.value(),
-            FillInfo::Gradient(gradient) => gradient
-                .colors
-                .first()
-                .map(|c| c.value())
-                .unwrap_or(0xFFFFFF),
-            FillInfo::Image(_) => 0xFFFFFF,
-        }
-    }
+fn write_point(data: &mut ByteWriter, (x, y): (i32, i32)) {
+    data.write_i32(x);
+    data.write_i32(y);
 }
 
 impl Default for BodyWriter {
@@ -1842,79 +1912,82 @@ impl Default for BodyWriter {
 
 impl Default for PageDefinitionData {
     fn default() -> Self {
+        // A4 세로 (210mm x 297mm)
         Self {
-            // A4 용지 기본값 (HwpUnit: 1/7200 inch)
-            // A4: 210mm × 297mm
-            // 210mm = 8.27 inch = 59544 HwpUnit
-            // 297mm = 11.69 inch = 84168 HwpUnit
-            paper_width: 59544,
-            paper_height: 84168,
-            margin_left: 8503,   // 30mm
-            margin_right: 8503,  // 30mm
-            margin_top: 5669,    // 20mm
-            margin_bottom: 4252, // 15mm
-            margin_header: 4252, // 15mm
-            margin_footer: 4252, // 15mm
+            paper_width: 59528,
+            paper_height: 84188,
+            margin_left: 8504,
+            margin_right: 8504,
+            margin_top: 5668,
+            margin_bottom: 4252,
+            margin_header: 4252,
+            margin_footer: 4252,
             margin_gutter: 0,
-            properties: 0,
-        }
-    }
-}
-
-impl Default for ParagraphData {
-    fn default() -> Self {
-        Self {
-            para_shape_id: 0,
-            style_id: 0,
-            text: Vec::new(),
-            char_shape_refs: vec![CharShapeRef {
-                position: 0,
-                char_shape_id: 0,
-            }],
-            range_tags: Vec::new(),
-            controls: Vec::new(),
+            orientation: 0,
+            gutter_position: 0,
         }
     }
 }
 
 impl ParagraphData {
-    /// 텍스트로 문단을 생성합니다.
+    /// 텍스트로 문단 생성
     pub fn with_text(text: &str) -> Self {
-        let utf16: Vec<u16> = text.encode_utf16().collect();
-        Self {
-            text: utf16,
-            ..Default::default()
-        }
+        let mut para = Self::default();
+        para.push_text(text);
+        para
     }
-}
 
-impl Default for TableData {
-    fn default() -> Self {
-        Self {
-            common: ObjectCommonData::default(),
-            rows: 1,
-            columns: 1,
-            column_widths: vec![4252], // 15mm
-            cells: Vec::new(),
-            border_fill_id: 0,
-            caption: None,
-            properties: 0,
-        }
+    /// 현재 텍스트 위치 (UTF-16 유닛 단위)
+    pub fn position(&self) -> u32 {
+        self.text.len() as u32
     }
-}
 
-impl Default for TableCellData {
-    fn default() -> Self {
-        Self {
-            col: 0,
-            row: 0,
-            col_span: 1,
-            row_span: 1,
-            width: 4252,
-            height: 1417, // 5mm
-            paragraphs: Vec::new(),
-            border_fill_id: 0,
+    /// 일반 텍스트를 덧붙입니다.
+    pub fn push_text(&mut self, text: &str) {
+        self.text.extend(text.encode_utf16());
+    }
+
+    /// 1 유닛짜리 문자 (줄 바꿈, 묶음 빈칸 등)를 덧붙입니다.
+    pub fn push_char(&mut self, code: u16) {
+        self.text.push(code);
+    }
+
+    /// 8 유닛짜리 인라인 컨트롤 문자 (탭, 필드 끝 등)를 덧붙입니다.
+    pub fn push_inline(&mut self, code: u16, data: [u16; 6]) {
+        self.text.push(code);
+        self.text.extend_from_slice(&data);
+        self.text.push(code);
+    }
+
+    /// 확장 컨트롤 문자와 컨트롤을 함께 덧붙입니다.
+    pub fn push_control(&mut self, control: ControlData) {
+        let ctrl_id = control.ctrl_id();
+        self.push_inline(
+            control.char_code(),
+            [
+                (ctrl_id & 0xFFFF) as u16,
+                (ctrl_id >> 16) as u16,
+                0,
+                0,
+                0,
+                0,
+            ],
+        );
+        self.controls.push(control);
+    }
+
+    /// 텍스트에 포함된 컨트롤 문자들의 마스크를 계산합니다.
+    fn control_mask(&self) -> u32 {
+        let mut mask = 0u32;
+        let mut i = 0;
+        while i < self.text.len() {
+            let code = self.text[i];
+            if code < 32 {
+                mask |= 1 << code;
+            }
+            i += if is_extended_char(code) { 8 } else { 1 };
         }
+        mask
     }
 }
 
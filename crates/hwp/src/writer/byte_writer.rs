@@ -93,6 +93,12 @@ impl ByteWriter {
     }
 }
 
+impl Default for ByteWriter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
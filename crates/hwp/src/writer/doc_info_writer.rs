@@ -15,16 +15,18 @@
 //! 9. BULLET (0x018) × bullet_count
 //! 10. PARA_SHAPE (0x019) × para_shape_count
 //! 11. STYLE (0x01A) × style_count
+//! 12. COMPATIBLE_DOCUMENT (0x01E), LAYOUT_COMPATIBILITY (0x01F) (설정한 경우)
 
 use super::ByteWriter;
+use crate::doc_info::FillInfo;
 use crate::primitive::RecordTagId;
 
 /// DocInfo 스트림 생성기
 pub struct DocInfoWriter {
     /// 섹션 수
     section_count: u16,
-    /// 시작 페이지 번호
-    page_start_number: u16,
+    /// 시작 번호와 캐럿 위치
+    properties: DocumentPropertiesData,
     /// 폰트 이름들 (언어별)
     fonts: FontCounts,
     /// BorderFill 데이터들
@@ -43,6 +45,47 @@ pub struct DocInfoWriter {
     styles: Vec<StyleData>,
     /// 바이너리 데이터 정보들
     binary_data: Vec<BinaryDataInfo>,
+    /// 호환 문서 대상 (0=현재 버전, 1=한글 2007, 2=MS 워드)
+    compatible_target: Option<u32>,
+}
+
+/// 문서 속성 데이터 (섹션 수 제외)
+#[derive(Debug, Clone, Copy)]
+pub struct DocumentPropertiesData {
+    /// 시작 페이지 번호
+    pub page_start_number: u16,
+    /// 시작 각주 번호
+    pub footnote_start_number: u16,
+    /// 시작 미주 번호
+    pub endnote_start_number: u16,
+    /// 시작 그림 번호
+    pub figure_start_number: u16,
+    /// 시작 표 번호
+    pub table_start_number: u16,
+    /// 시작 수식 번호
+    pub equation_start_number: u16,
+    /// 캐럿 위치 - 리스트 ID
+    pub caret_list_id: u32,
+    /// 캐럿 위치 - 문단 ID
+    pub caret_paragraph_id: u32,
+    /// 캐럿 위치 - 문단 내 글자 위치
+    pub caret_position: u32,
+}
+
+impl Default for DocumentPropertiesData {
+    fn default() -> Self {
+        Self {
+            page_start_number: 1,
+            footnote_start_number: 1,
+            endnote_start_number: 1,
+            figure_start_number: 1,
+            table_start_number: 1,
+            equation_start_number: 1,
+            caret_list_id: 0,
+            caret_paragraph_id: 0,
+            caret_position: 0,
+        }
+    }
 }
 
 /// 언어별 폰트 수
@@ -119,10 +162,8 @@ pub struct BorderFillData {
     pub bottom_border: BorderLine,
     /// 대각선
     pub diagonal: BorderLine,
-    /// 채우기 타입
-    pub fill_type: u32,
-    /// 배경색
-    pub background_color: u32,
+    /// 채우기
+    pub fill: FillInfo,
 }
 
 /// 테두리 선
@@ -226,10 +267,12 @@ pub struct NumberingLevelData {
 /// 글머리표 데이터
 #[derive(Debug, Clone)]
 pub struct BulletData {
-    /// 문단 머리 정보
-    pub para_head_info: u32,
+    /// 문단 머리 글자 모양 ID
+    pub char_shape_id: u32,
     /// 글머리 문자
     pub bullet_char: char,
+    /// 체크 글머리 문자 (없으면 '\0')
+    pub check_bullet_char: char,
 }
 
 /// 문단 모양 데이터
@@ -248,7 +291,7 @@ pub struct ParaShapeData {
     /// 문단 아래 간격
     pub space_after: i32,
     /// 줄 간격 (old)
-    pub line_spacing: i16,
+    pub line_spacing: i32,
     /// 탭 정의 ID
     pub tab_def_id: u16,
     /// 번호 매기기/글머리표 ID
@@ -310,7 +353,7 @@ impl DocInfoWriter {
     pub fn new(section_count: u16) -> Self {
         Self {
             section_count,
-            page_start_number: 1,
+            properties: DocumentPropertiesData::default(),
             fonts: FontCounts::default(),
             border_fills: Vec::new(),
             char_shapes: Vec::new(),
@@ -320,15 +363,26 @@ impl DocInfoWriter {
             para_shapes: Vec::new(),
             styles: Vec::new(),
             binary_data: Vec::new(),
+            compatible_target: None,
         }
     }
 
     /// 시작 페이지 번호를 설정합니다.
     pub const fn with_page_start_number(mut self, num: u16) -> Self {
-        self.page_start_number = num;
+        self.properties.page_start_number = num;
         self
     }
 
+    /// 시작 번호와 캐럿 위치를 설정합니다.
+    pub const fn set_document_properties(&mut self, properties: DocumentPropertiesData) {
+        self.properties = properties;
+    }
+
+    /// 호환 문서 대상을 설정합니다 (0=현재 버전, 1=한글 2007, 2=MS 워드).
+    pub const fn set_compatible_document(&mut self, target: u32) {
+        self.compatible_target = Some(target);
+    }
+
     /// 폰트를 설정합니다.
     pub fn set_fonts(&mut self, fonts: FontCounts) {
         self.fonts = fonts;
@@ -427,22 +481,29 @@ impl DocInfoWriter {
             self.write_style(&mut writer, style);
         }
 
+        // 12. COMPATIBLE_DOCUMENT
+        if let Some(target) = self.compatible_target {
+            writer.write_record(RecordTagId::CompatibleDocument, 0, &target.to_le_bytes());
+            writer.write_record(RecordTagId::LayoutCompatibility, 1, &[0; 20]);
+        }
+
         writer.into_bytes()
     }
 
     fn write_document_properties(&self, writer: &mut ByteWriter) {
         let mut data = ByteWriter::new();
 
+        let props = &self.properties;
         data.write_u16(self.section_count);
-        data.write_u16(self.page_start_number);
-        data.write_u16(1); // footnote_start_number
-        data.write_u16(1); // endnote_start_number
-        data.write_u16(1); // figure_start_number
-        data.write_u16(1); // table_start_number
-        data.write_u16(1); // equation_start_number
-        data.write_u32(0); // caret_list_id
-        data.write_u32(0); // caret_paragraph_id
-        data.write_u32(0); // caret_position_in_paragraph
+        data.write_u16(props.page_start_number);
+        data.write_u16(props.footnote_start_number);
+        data.write_u16(props.endnote_start_number);
+        data.write_u16(props.figure_start_number);
+        data.write_u16(props.table_start_number);
+        data.write_u16(props.equation_start_number);
+        data.write_u32(props.caret_list_id);
+        data.write_u32(props.caret_paragraph_id);
+        data.write_u32(props.caret_position);
 
         let data_bytes = data.into_bytes();
         writer.write_record(RecordTagId::DocumentProperties, 0, &data_bytes);
@@ -503,7 +564,7 @@ impl DocInfoWriter {
         }
 
         let data_bytes = data.into_bytes();
-        writer.write_record(RecordTagId::BinaryData, 0, &data_bytes);
+        writer.write_record(RecordTagId::BinaryData, 1, &data_bytes);
     }
 
     fn write_face_names(&self, writer: &mut ByteWriter) {
@@ -581,7 +642,7 @@ impl DocInfoWriter {
         }
 
         let data_bytes = data.into_bytes();
-        writer.write_record(RecordTagId::FaceName, 0, &data_bytes);
+        writer.write_record(RecordTagId::FaceName, 1, &data_bytes);
     }
 
     fn write_border_fill(&self, writer: &mut ByteWriter, bf: &BorderFillData) {
@@ -597,13 +658,10 @@ impl DocInfoWriter {
         self.write_border_line(&mut data, &bf.diagonal);
 
         // 채우기
-        data.write_u32(bf.fill_type);
-        if bf.fill_type != 0 {
-            data.write_u32(bf.background_color);
-        }
+        write_fill_info(&mut data, &bf.fill);
 
         let data_bytes = data.into_bytes();
-        writer.write_record(RecordTagId::BorderFill, 0, &data_bytes);
+        writer.write_record(RecordTagId::BorderFill, 1, &data_bytes);
     }
 
     fn write_border_line(&self, data: &mut ByteWriter, line: &BorderLine) {
@@ -667,7 +725,7 @@ impl DocInfoWriter {
         }
 
         let data_bytes = data.into_bytes();
-        writer.write_record(RecordTagId::CharacterShape, 0, &data_bytes);
+        writer.write_record(RecordTagId::CharacterShape, 1, &data_bytes);
     }
 
     fn write_tab_definition(&self, writer: &mut ByteWriter, td: &TabDefinitionData) {
@@ -679,12 +737,12 @@ impl DocInfoWriter {
         for item in &td.items {
             data.write_u32(item.position);
             data.write_u8(item.tab_type);
-            data.write_u16(item.fill_char);
-            data.write_u8(0); // reserved
+            data.write_u8(item.fill_char as u8);
+            data.write_u16(0); // reserved
         }
 
         let data_bytes = data.into_bytes();
-        writer.write_record(RecordTagId::TabDefinition, 0, &data_bytes);
+        writer.write_record(RecordTagId::TabDefinition, 1, &data_bytes);
     }
 
     fn write_numbering(&self, writer: &mut ByteWriter, num: &NumberingData) {
@@ -719,17 +777,24 @@ impl DocInfoWriter {
         }
 
         let data_bytes = data.into_bytes();
-        writer.write_record(RecordTagId::Numbering, 0, &data_bytes);
+        writer.write_record(RecordTagId::Numbering, 1, &data_bytes);
     }
 
     fn write_bullet(&self, writer: &mut ByteWriter, bullet: &BulletData) {
         let mut data = ByteWriter::new();
 
-        data.write_u32(bullet.para_head_info);
+        // 문단 머리 정보 (12 bytes)
+        data.write_u32(0);
+        data.write_i16(0);
+        data.write_i16(0);
+        data.write_u32(bullet.char_shape_id);
+
         data.write_u16(bullet.bullet_char as u16);
+        data.write_i32(0); // 이미지 글머리표 사용 안 함
+        data.write_u16(bullet.check_bullet_char as u16);
 
         let data_bytes = data.into_bytes();
-        writer.write_record(RecordTagId::Bullet, 0, &data_bytes);
+        writer.write_record(RecordTagId::Bullet, 1, &data_bytes);
     }
 
     fn write_para_shape(&self, writer: &mut ByteWriter, ps: &ParaShapeData) {
@@ -741,7 +806,7 @@ impl DocInfoWriter {
         data.write_i32(ps.indent);
         data.write_i32(ps.space_before);
         data.write_i32(ps.space_after);
-        data.write_i16(ps.line_spacing);
+        data.write_i32(ps.line_spacing);
         data.write_u16(ps.tab_def_id);
         data.write_u16(ps.numbering_bullet_id);
         data.write_u16(ps.border_fill_id);
@@ -758,7 +823,7 @@ impl DocInfoWriter {
         data.write_u32(ps.line_spacing2);
 
         let data_bytes = data.into_bytes();
-        writer.write_record(RecordTagId::ParagraphShape, 0, &data_bytes);
+        writer.write_record(RecordTagId::ParagraphShape, 1, &data_bytes);
     }
 
     fn write_style(&self, writer: &mut ByteWriter, style: &StyleData) {
@@ -773,8 +838,52 @@ impl DocInfoWriter {
         data.write_u16(style.char_shape_id);
 
         let data_bytes = data.into_bytes();
-        writer.write_record(RecordTagId::Style, 0, &data_bytes);
+        writer.write_record(RecordTagId::Style, 1, &data_bytes);
+    }
+}
+
+/// 채우기 정보를 씁니다 (HWP 명세 표 28).
+///
+/// 테두리/배경과 그리기 개체가 같은 형식을 사용합니다.
+pub(crate) fn write_fill_info(data: &mut ByteWriter, fill: &FillInfo) {
+    match fill {
+        FillInfo::None => data.write_u32(0),
+        FillInfo::Pattern(pattern) => {
+            data.write_u32(0x01);
+            data.write_u32(pattern.background_color.value());
+            data.write_u32(pattern.pattern_color.value());
+            data.write_i32(pattern.pattern_type.as_raw());
+        }
+        FillInfo::Image(image) => {
+            data.write_u32(0x02);
+            data.write_u8(image.fill_type.as_raw());
+            data.write_i8(image.image_info.brightness);
+            data.write_i8(image.image_info.contrast);
+            data.write_u8(image.image_info.effect);
+            data.write_u16(image.image_info.binary_data_id);
+        }
+        FillInfo::Gradient(gradient) => {
+            data.write_u32(0x04);
+            data.write_i16(gradient.gradient_type.as_raw());
+            data.write_i16(gradient.angle);
+            data.write_i16(gradient.center_x);
+            data.write_i16(gradient.center_y);
+            data.write_i16(gradient.blur);
+            data.write_i16(gradient.colors.len() as i16);
+            // 색이 셋 이상이면 위치를 고르게 나누어 기록
+            if gradient.colors.len() > 2 {
+                let last = gradient.colors.len() - 1;
+                for index in 0..=last {
+                    data.write_i32((index * 100 / last) as i32);
+                }
+            }
+            for color in &gradient.colors {
+                data.write_u32(color.value());
+            }
+        }
     }
+    // 추가 채우기 속성 길이
+    data.write_u32(0);
 }
 
 #[cfg(test)]
@@ -130,7 +130,7 @@ impl FileHeaderWriter {
     const fn build_properties(&self) -> u32 {
         let mut props = 0;
 
-        props |= (self.compressed as u32) << 0;
+        props |= self.compressed as u32;
         props |= (self.encrypted as u32) << 1;
         props |= (self.distribution as u32) << 2;
         props |= (self.has_script as u32) << 3;
@@ -10,6 +10,16 @@
 //! - `/DocInfo`: 문서 정보 (압축됨)
 //! - `/BodyText/Section{N}`: 본문 내용 (압축됨)
 //! - `/BinData/BIN{XXXX}.{ext}`: 바이너리 데이터 (이미지 등)
+//! - `/\x05HwpSummaryInformation`: 문서 요약 정보 (OLE 속성 집합)
+//! - `/PrvText`: 미리보기 텍스트 (UTF-16LE)
+//! - `/PrvImage`: 미리보기 이미지 (PNG, GIF, BMP)
+//! - `/Scripts/JScriptVersion`, `/Scripts/DefaultJScript`: 스크립트 (압축됨)
+//! - `/DocOptions/_LinkDoc`: 연결 문서 경로 (UTF-16LE)
+//!
+//! [`HwpWriter::with_distribution`]으로 배포용 문서를 만들면 본문을 `/BodyText` 대신
+//! `/ViewText/Section{N}`에 기록합니다. 각 섹션은 배포용 문서 데이터 레코드
+//! (HWPTAG_DISTRIBUTE_DOC_DATA)로 시작하고, 나머지는 압축 후 AES-128 ECB로
+//! 암호화됩니다. 같은 레코드를 DocInfo 끝에도 추가합니다.
 
 pub mod body_writer;
 pub mod byte_writer;
@@ -27,7 +37,14 @@ use std::io::{Cursor, Write};
 use cfb::CompoundFile;
 use miniz_oxide::deflate::compress_to_vec;
 
+use crate::crypto::{encrypt_distribution_stream, new_distribution_data};
+use crate::doc_info::DistributionRestrictions;
+use crate::doc_options::LinkDoc;
 use crate::error::{Error, Result};
+use crate::preview::PreviewText;
+use crate::primitive::RecordTagId;
+use crate::script::Scripts;
+use crate::summary::SummaryInfo;
 use primitive::Version;
 
 /// HWP 파일 생성기
@@ -43,9 +60,21 @@ pub struct HwpWriter {
     /// BodyText 섹션들 (압축 전)
     sections: Vec<Vec<u8>>,
     /// 바이너리 데이터 (이미지 등)
-    binary_data: HashMap<String, Vec<u8>>,
+    binary_data: HashMap<String, (Vec<u8>, Option<bool>)>,
+    /// 문서 요약 정보
+    summary_info: Option<SummaryInfo>,
+    /// 미리보기 텍스트
+    preview_text: Option<String>,
+    /// 미리보기 이미지
+    preview_image: Option<Vec<u8>>,
+    /// 스크립트
+    scripts: Option<Scripts>,
+    /// 연결 문서 정보
+    link_doc: Option<LinkDoc>,
     /// 압축 활성화 여부
     compress: bool,
+    /// 배포용 문서 데이터
+    distribution_data: Option<[u8; 256]>,
 }
 
 impl HwpWriter {
@@ -57,7 +86,13 @@ impl HwpWriter {
             doc_info: Vec::new(),
             sections: Vec::new(),
             binary_data: HashMap::new(),
+            summary_info: None,
+            preview_text: None,
+            preview_image: None,
+            scripts: None,
+            link_doc: None,
             compress: true,
+            distribution_data: None,
         }
     }
 
@@ -73,6 +108,24 @@ impl HwpWriter {
         self
     }
 
+    /// 배포용 문서로 설정합니다.
+    ///
+    /// `password`는 한글에서 배포용 문서를 해제할 때 쓰는 암호이며,
+    /// `restrictions`로 복사와 인쇄를 막을 수 있습니다. 읽을 때는 암호가 필요하지
+    /// 않습니다.
+    ///
+    /// # Errors
+    ///
+    /// 운영체제의 난수 생성에 실패하면 [`Error::Io`]를 반환합니다.
+    pub fn with_distribution(
+        mut self,
+        password: &str,
+        restrictions: DistributionRestrictions,
+    ) -> Result<Self> {
+        self.distribution_data = Some(new_distribution_data(password, restrictions.bits())?);
+        Ok(self)
+    }
+
     /// FileHeader 데이터를 설정합니다.
     pub fn set_file_header(&mut self, data: Vec<u8>) {
         self.file_header = data;
@@ -88,9 +141,48 @@ impl HwpWriter {
         self.sections.push(data);
     }
 
-    /// 바이너리 데이터를 추가합니다.
+    /// 바이너리 데이터를 추가합니다 (문서 압축 설정을 따름).
     pub fn add_binary_data(&mut self, name: String, data: Vec<u8>) {
-        self.binary_data.insert(name, data);
+        self.binary_data.insert(name, (data, None));
+    }
+
+    /// 압축 여부를 지정해 바이너리 데이터를 추가합니다.
+    ///
+    /// DocInfo의 BIN_DATA 레코드 압축 모드와 일치해야 읽을 때 올바르게 해제됩니다.
+    pub fn add_binary_data_with_compression(
+        &mut self,
+        name: String,
+        data: Vec<u8>,
+        compress: bool,
+    ) {
+        self.binary_data.insert(name, (data, Some(compress)));
+    }
+
+    /// 문서 요약 정보를 설정합니다.
+    pub fn set_summary_info(&mut self, summary_info: SummaryInfo) {
+        self.summary_info = Some(summary_info);
+    }
+
+    /// 미리보기 텍스트를 설정합니다.
+    pub fn set_preview_text(&mut self, text: impl Into<String>) {
+        self.preview_text = Some(text.into());
+    }
+
+    /// 미리보기 이미지를 설정합니다 (PNG, GIF 또는 BMP).
+    pub fn set_preview_image(&mut self, data: Vec<u8>) {
+        self.preview_image = Some(data);
+    }
+
+    /// 스크립트를 설정합니다.
+    ///
+    /// FileHeader에 스크립트 저장 비트가 기록됩니다.
+    pub fn set_scripts(&mut self, scripts: Scripts) {
+        self.scripts = Some(scripts);
+    }
+
+    /// 연결 문서 정보를 설정합니다.
+    pub fn set_link_doc(&mut self, link_doc: LinkDoc) {
+        self.link_doc = Some(link_doc);
     }
 
     /// HWP 파일을 바이트로 생성합니다.
@@ -111,6 +203,26 @@ impl HwpWriter {
         // BinData 스트림들 생성
         self.write_bin_data(&mut cfb)?;
 
+        // 요약 정보와 미리보기 스트림 생성 (압축하지 않음)
+        if let Some(summary_info) = &self.summary_info {
+            Self::write_stream(
+                &mut cfb,
+                "/\x05HwpSummaryInformation",
+                &summary_info.to_bytes(),
+            )?;
+        }
+        if let Some(text) = &self.preview_text {
+            let preview = PreviewText { text: text.clone() };
+            Self::write_stream(&mut cfb, "/PrvText", &preview.to_bytes())?;
+        }
+        if let Some(image) = &self.preview_image {
+            Self::write_stream(&mut cfb, "/PrvImage", image)?;
+        }
+
+        // Scripts, DocOptions 스트림 생성
+        self.write_scripts(&mut cfb)?;
+        self.write_doc_options(&mut cfb)?;
+
         // CFB 파일 완성
         cfb.flush()
             .map_err(|e| Error::Io(std::io::Error::other(e)))?;
@@ -128,7 +240,10 @@ impl HwpWriter {
             .map_err(|e| Error::Io(std::io::Error::other(e)))?;
 
         let header_data = if self.file_header.is_empty() {
-            FileHeaderWriter::new(self.version, self.compress).build()
+            FileHeaderWriter::new(self.version, self.compress)
+                .with_distribution(self.distribution_data.is_some())
+                .with_script(self.scripts.is_some())
+                .build()
         } else {
             self.file_header.clone()
         };
@@ -146,12 +261,15 @@ impl HwpWriter {
             .create_stream("/DocInfo")
             .map_err(|e| Error::Io(std::io::Error::other(e)))?;
 
-        let data = if self.compress {
-            compress_to_vec(&self.doc_info, 6)
-        } else {
-            self.doc_info.clone()
+        let data = match &self.distribution_data {
+            Some(distribution_data) => {
+                let mut doc_info = ByteWriter::new();
+                doc_info.write_bytes(&self.doc_info);
+                doc_info.write_record(RecordTagId::DistributeDocumentData, 0, distribution_data);
+                self.encode_stream(&doc_info.into_bytes())
+            }
+            None => self.encode_stream(&self.doc_info),
         };
-
         stream.write_all(&data)?;
 
         Ok(())
@@ -161,22 +279,27 @@ impl HwpWriter {
         &self,
         cfb: &mut CompoundFile<W>,
     ) -> Result<()> {
+        // 배포용 문서는 ViewText에 기록
+        let storage = if self.distribution_data.is_some() {
+            "/ViewText"
+        } else {
+            "/BodyText"
+        };
+
         // BodyText 디렉토리 생성
-        cfb.create_storage("/BodyText")
+        cfb.create_storage(storage)
             .map_err(|e| Error::Io(std::io::Error::other(e)))?;
 
         for (i, section_data) in self.sections.iter().enumerate() {
-            let stream_name = format!("/BodyText/Section{}", i);
+            let stream_name = format!("{}/Section{}", storage, i);
             let mut stream = cfb
                 .create_stream(&stream_name)
                 .map_err(|e| Error::Io(std::io::Error::other(e)))?;
 
-            let data = if self.compress {
-                compress_to_vec(section_data, 6)
-            } else {
-                section_data.clone()
-            };
-
+            let mut data = self.encode_stream(section_data);
+            if let Some(distribution_data) = &self.distribution_data {
+                data = encrypt_distribution_stream(&data, distribution_data);
+            }
             stream.write_all(&data)?;
         }
 
@@ -195,14 +318,14 @@ impl HwpWriter {
         cfb.create_storage("/BinData")
             .map_err(|e| Error::Io(std::io::Error::other(e)))?;
 
-        for (name, data) in &self.binary_data {
+        for (name, (data, compress)) in &self.binary_data {
             let stream_name = format!("/BinData/{}", name);
             let mut stream = cfb
                 .create_stream(&stream_name)
                 .map_err(|e| Error::Io(std::io::Error::other(e)))?;
 
             // BinData는 압축 여부에 따라 다르게 처리
-            let final_data = if self.compress {
+            let final_data = if compress.unwrap_or(self.compress) {
                 compress_to_vec(data, 6)
             } else {
                 data.clone()
@@ -213,4 +336,76 @@ impl HwpWriter {
 
         Ok(())
     }
+
+    fn write_scripts<W: std::io::Read + std::io::Write + std::io::Seek>(
+        &self,
+        cfb: &mut CompoundFile<W>,
+    ) -> Result<()> {
+        let Some(scripts) = &self.scripts else {
+            return Ok(());
+        };
+
+        cfb.create_storage("/Scripts")
+            .map_err(|e| Error::Io(std::io::Error::other(e)))?;
+
+        // 스크립트는 압축 설정을 따르고, 배포용 문서에서는 암호화됨
+        let streams = [
+            ("/Scripts/JScriptVersion", scripts.version.to_bytes()),
+            ("/Scripts/DefaultJScript", scripts.to_bytes()),
+        ];
+        for (name, data) in streams {
+            let mut data = if self.compress {
+                compress_to_vec(&data, 6)
+            } else {
+                data
+            };
+            if let Some(distribution_data) = &self.distribution_data {
+                data = encrypt_distribution_stream(&data, distribution_data);
+            }
+            Self::write_stream(cfb, name, &data)?;
+        }
+
+        Ok(())
+    }
+
+    fn write_doc_options<W: std::io::Read + std::io::Write + std::io::Seek>(
+        &self,
+        cfb: &mut CompoundFile<W>,
+    ) -> Result<()> {
+        let Some(link_doc) = &self.link_doc else {
+            return Ok(());
+        };
+
+        cfb.create_storage("/DocOptions")
+            .map_err(|e| Error::Io(std::io::Error::other(e)))?;
+        Self::write_stream(cfb, "/DocOptions/_LinkDoc", &link_doc.to_bytes())
+    }
+
+    /// DocInfo/BodyText 스트림을 압축합니다.
+    fn encode_stream(&self, data: &[u8]) -> Vec<u8> {
+        if self.compress {
+            compress_to_vec(data, 6)
+        } else {
+            data.to_vec()
+        }
+    }
+
+    fn write_stream<W: std::io::Read + std::io::Write + std::io::Seek>(
+        cfb: &mut CompoundFile<W>,
+        name: &str,
+        data: &[u8],
+    ) -> Result<()> {
+        let mut stream = cfb
+            .create_stream(name)
+            .map_err(|e| Error::Io(std::io::Error::other(e)))?;
+        stream.write_all(data)?;
+
+        Ok(())
+    }
+}
+
+impl Default for HwpWriter {
+    fn default() -> Self {
+        Self::new()
+    }
 }
@@ -17,6 +17,7 @@ use std::collections::HashSet;
 use std::path::PathBuf;
 
 use hwp::HwpDocument;
+use hwp::convert::IrToHwp;
 
 /// Size threshold for considering a file "large" (50MB).
 const LARGE_FILE_THRESHOLD: u64 = 50 * 1024 * 1024;
@@ -70,6 +71,7 @@ enum FixtureTestResult {
 }
 
 /// Configuration for files that need special handling.
+#[derive(Default)]
 struct FixtureConfig {
     /// Files that should be skipped entirely (known broken, not HWP 5.0, etc.)
     skip_files: HashSet<String>,
@@ -77,15 +79,6 @@ struct FixtureConfig {
     expected_failures: HashSet<String>,
 }
 
-impl Default for FixtureConfig {
-    fn default() -> Self {
-        Self {
-            skip_files: HashSet::new(),
-            expected_failures: HashSet::new(),
-        }
-    }
-}
-
 /// Detailed test results for reporting.
 struct TestResults {
     success: Vec<(String, FixtureTestResult)>,
@@ -234,18 +227,18 @@ fn test_all_fixtures() {
         }
 
         // Check file size for large files
-        if let Ok(metadata) = std::fs::metadata(path) {
-            if metadata.len() > LARGE_FILE_THRESHOLD {
-                results.skipped.push((
-                    file_name.to_string(),
-                    format!(
-                        "Large file ({}MB) - run with --ignored",
-                        metadata.len() / 1024 / 1024
-                    ),
-                ));
-                eprintln!("[SKIP] {}: large file, run with --ignored", file_name);
-                continue;
-            }
+        if let Ok(metadata) = std::fs::metadata(path)
+            && metadata.len() > LARGE_FILE_THRESHOLD
+        {
+            results.skipped.push((
+                file_name.to_string(),
+                format!(
+                    "Large file ({}MB) - run with --ignored",
+                    metadata.len() / 1024 / 1024
+                ),
+            ));
+            eprintln!("[SKIP] {}: large file, run with --ignored", file_name);
+            continue;
         }
 
         // Run the test
@@ -292,6 +285,310 @@ fn test_all_fixtures() {
     }
 }
 
+/// Collects the plain text of each paragraph in a caption.
+fn caption_text(caption: Option<&ir::control::Caption>) -> Option<String> {
+    caption.map(|caption| {
+        caption
+            .paragraphs
+            .iter()
+            .map(|paragraph| paragraph.to_plain_text())
+            .collect()
+    })
+}
+
+/// Checks the IR converted from the distribution document specification
+/// against content known from the document itself.
+#[test]
+fn test_to_ir_fixture_content() {
+    use ir::control::Control;
+    use ir::paragraph::RunContent;
+
+    let Some(path) = get_fixture_path("한글문서파일형식_배포용문서_revision1.2.hwp")
+    else {
+        eprintln!("Fixture not found, skipping.");
+        return;
+    };
+    let data = std::fs::read(path).expect("fixture should be readable");
+    let document = HwpDocument::from_bytes(&data)
+        .expect("fixture should parse")
+        .to_ir()
+        .expect("fixture should convert to IR")
+        .value;
+
+    // Document settings and fonts from DocInfo
+    let settings = &document.settings;
+    assert_eq!(settings.starting_page_number, 1);
+    let caret = settings.caret_position.as_ref().expect("caret position");
+    assert_eq!((caret.section, caret.paragraph, caret.position), (0, 0, 40));
+    assert_eq!(
+        settings.compatible_document,
+        Some(ir::CompatibleDocument::Current)
+    );
+    assert_eq!(document.styles.fonts[0].name, "한컴돋움");
+    assert_eq!(
+        document.styles.fonts[0].language,
+        Some(primitive::LanguageType::Korean)
+    );
+
+    // Table of contents entry: text, tab with leader and page number in one run
+    let section = &document.sections[0];
+    let entry = &section.paragraphs[4];
+    assert_eq!(entry.runs.len(), 1);
+    let contents = &entry.runs[0].contents;
+    assert_eq!(contents.len(), 3);
+    assert!(matches!(&contents[0], RunContent::Text(text) if text.text == "저작권"));
+    assert!(matches!(&contents[1], RunContent::Tab(tab) if tab.leader == Some('-')));
+    assert!(matches!(&contents[2], RunContent::Text(text) if text.text == "4"));
+
+    // Tables and pictures with their captions
+    let controls: Vec<&Control> = section
+        .paragraphs
+        .iter()
+        .flat_map(|paragraph| &paragraph.runs)
+        .flat_map(|run| &run.contents)
+        .filter_map(|content| match content {
+            RunContent::Control(control) => Some(control.as_ref()),
+            _ => None,
+        })
+        .collect();
+    let tables: Vec<_> = controls
+        .iter()
+        .filter_map(|control| match control {
+            Control::Table(table) => Some(table),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(tables.len(), 5);
+    assert_eq!(
+        caption_text(tables[1].common.caption.as_ref()).as_deref(),
+        Some("표  배포용 문서 데이터")
+    );
+    assert_eq!((tables[1].row_count, tables[1].column_count), (3, 3));
+    assert_eq!(
+        tables[1].rows[0].cells[0].paragraphs[0].to_plain_text(),
+        "자료형"
+    );
+    let captions: Vec<_> = controls
+        .iter()
+        .filter_map(|control| match control {
+            Control::Picture(picture) => caption_text(picture.common.caption.as_ref()),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(captions, ["그림  난수 배열"]);
+}
+
+/// Returns the first line that differs between two debug dumps.
+fn first_difference(original: &str, written: &str) -> Option<(usize, String, String)> {
+    let mut original_lines = original.lines();
+    let mut written_lines = written.lines();
+    for line in 1.. {
+        match (original_lines.next(), written_lines.next()) {
+            (None, None) => return None,
+            (a, b) if a == b => {}
+            (a, b) => {
+                return Some((
+                    line,
+                    a.unwrap_or("<end>").to_string(),
+                    b.unwrap_or("<end>").to_string(),
+                ));
+            }
+        }
+    }
+    None
+}
+
+/// Converts every fixture HWP -> IR -> HWP -> IR and checks that both IR
+/// documents are the same.
+#[test]
+fn test_ir_round_trip() {
+    let fixtures = discover_fixtures();
+
+    if fixtures.is_empty() {
+        eprintln!("No fixtures found in fixtures/ directory.");
+        return;
+    }
+
+    let mut failures = Vec::new();
+
+    for path in &fixtures {
+        let file_name = path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or("unknown");
+
+        if std::fs::metadata(path).is_ok_and(|m| m.len() > LARGE_FILE_THRESHOLD) {
+            eprintln!("[SKIP] {}: large file", file_name);
+            continue;
+        }
+
+        let data = std::fs::read(path).expect("fixture should be readable");
+        let original = HwpDocument::from_bytes(&data)
+            .expect("fixture should parse")
+            .to_ir()
+            .expect("fixture should convert to IR")
+            .value;
+        let written = original
+            .to_hwp_bytes()
+            .expect("IR should convert to HWP")
+            .value;
+        let round_tripped = HwpDocument::from_bytes(&written)
+            .expect("written document should parse")
+            .to_ir()
+            .expect("written document should convert to IR")
+            .value;
+
+        let binary_data = |document: &ir::Document| {
+            let mut items: Vec<_> = document
+                .binary_data
+                .iter()
+                .map(|(id, item)| {
+                    let checksum = item
+                        .data
+                        .iter()
+                        .fold(0u32, |sum, &byte| sum.rotate_left(5) ^ u32::from(byte));
+                    format!(
+                        "{id:?} {:?} {} bytes, checksum {checksum:08x}",
+                        item.format,
+                        item.data.len()
+                    )
+                })
+                .collect();
+            items.sort();
+            items.join("\n")
+        };
+
+        let parts = [
+            (
+                "metadata",
+                format!("{:#?}", original.metadata),
+                format!("{:#?}", round_tripped.metadata),
+            ),
+            (
+                "settings",
+                format!("{:#?}", original.settings),
+                format!("{:#?}", round_tripped.settings),
+            ),
+            (
+                "styles",
+                format!("{:#?}", original.styles),
+                format!("{:#?}", round_tripped.styles),
+            ),
+            (
+                "sections",
+                format!("{:#?}", original.sections),
+                format!("{:#?}", round_tripped.sections),
+            ),
+            (
+                "extensions",
+                format!("{:#?}", original.extensions),
+                format!("{:#?}", round_tripped.extensions),
+            ),
+            (
+                "binary_data",
+                binary_data(&original),
+                binary_data(&round_tripped),
+            ),
+        ];
+
+        let mut ok = true;
+        for (part, before, after) in &parts {
+            if let Some((line, a, b)) = first_difference(before, after) {
+                eprintln!(
+                    "[FAIL] {}: {} differs at line {}\n  original: {}\n  written:  {}",
+                    file_name,
+                    part,
+                    line,
+                    a.trim(),
+                    b.trim()
+                );
+                failures.push(format!("{file_name} ({part})"));
+                ok = false;
+            }
+        }
+        if ok {
+            eprintln!("[OK]   {}: {} bytes written", file_name, written.len());
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "IR changed after writing HWP: {}",
+        failures.join(", ")
+    );
+}
+
+/// Writes the distribution document specification through IR and checks the
+/// written records against the source document's own records.
+#[test]
+fn test_to_hwp_fixture_content() {
+    let Some(path) = get_fixture_path("한글문서파일형식_배포용문서_revision1.2.hwp")
+    else {
+        eprintln!("Fixture not found, skipping.");
+        return;
+    };
+    let data = std::fs::read(path).expect("fixture should be readable");
+    let source = HwpDocument::from_bytes(&data).expect("fixture should parse");
+    let written = source
+        .to_ir()
+        .expect("fixture should convert to IR")
+        .value
+        .to_hwp_bytes()
+        .expect("IR should convert to HWP")
+        .value;
+    let written = HwpDocument::from_bytes(&written).expect("written document should parse");
+
+    // DocInfo entries
+    let font_names = |document: &HwpDocument| {
+        document
+            .font_faces()
+            .iter()
+            .map(|face| face.name().to_string())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(font_names(&written), font_names(&source));
+    assert_eq!(
+        written.character_shapes().len(),
+        source.character_shapes().len()
+    );
+    assert_eq!(
+        written.paragraph_shapes().len(),
+        source.paragraph_shapes().len()
+    );
+    assert_eq!(written.border_fills().len(), source.border_fills().len());
+    let style_names = |document: &HwpDocument| {
+        document
+            .styles()
+            .iter()
+            .map(|style| style.local_name().to_string())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(style_names(&written), style_names(&source));
+
+    // Body text and controls
+    assert_eq!(written.section_count(), source.section_count());
+    for (written, source) in written.sections().iter().zip(source.sections()) {
+        assert_eq!(written.paragraph_count(), source.paragraph_count());
+        for (written, source) in written.paragraphs().iter().zip(source.paragraphs()) {
+            assert_eq!(written.plain_text(), source.plain_text());
+            let ids = |paragraph: &hwp::Paragraph| {
+                paragraph
+                    .controls()
+                    .iter()
+                    .map(|control| control.id())
+                    .collect::<Vec<_>>()
+            };
+            assert_eq!(ids(written), ids(source));
+        }
+    }
+
+    // BinData contents
+    assert_eq!(written.binary_data_count(), source.binary_data_count());
+    for id in source.binary_data_ids() {
+        assert_eq!(written.get_binary_data(id), source.get_binary_data(id));
+    }
+}
+
 /// Tests large fixture files (run with: cargo test --test fixtures_test -- --ignored)
 #[test]
 #[ignore]
@@ -0,0 +1,567 @@
+//! 본문 엘리먼트 (`BODY`)
+//!
+//! 구역(`SECTION`), 문단(`P`), 글자 모양이 같은 텍스트 묶음(`TEXT`)과 글자(`CHAR`)를 담습니다.
+//! HWPML 명세 5장.
+//!
+//! `TEXT`의 자식 중 구역 정의(`SECDEF`), 단 정의(`COLDEF`)와 표·그림·수식([`crate::control`])을 모델로 읽고,
+//! 도형·필드 같은 나머지 컨트롤은 [`TextItem::Unknown`]에 [`RawElement`]로 보관했다가 그대로 다시 씁니다.
+//! `CHAR` 안의 문자열과 마크업은 `$value` 순서를 유지해야 하며,
+//! 공백만으로 된 문자열은 quick-xml이 건너뛰므로 읽을 때 남지 않습니다.
+
+use std::fmt;
+
+use serde::de::{EnumAccess, MapAccess, VariantAccess, Visitor};
+use serde::ser::{SerializeMap, SerializeStruct};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::control::{Equation, Picture, Table};
+use crate::types::RgbColor;
+
+/// 본문 (`BODY`)
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct Body {
+    /// 구역 (`SECTION`)
+    #[serde(rename = "SECTION", default)]
+    pub sections: Vec<Section>,
+}
+
+/// 구역 (`SECTION`)
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct Section {
+    /// 구역 아이디 (`Id`)
+    #[serde(rename = "@Id", default)]
+    pub id: u32,
+
+    /// 문단 (`P`)
+    #[serde(rename = "P", default)]
+    pub paragraphs: Vec<Paragraph>,
+}
+
+/// 문단 (`P`)
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct Paragraph {
+    /// 문단 모양 아이디 참조 (`ParaShape`)
+    #[serde(
+        rename = "@ParaShape",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub para_shape: Option<u32>,
+
+    /// 문단 스타일 아이디 참조 (`Style`)
+    #[serde(rename = "@Style", default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<u32>,
+
+    /// 개요 문단의 문서 내 유일한 아이디 (`InstId`)
+    #[serde(rename = "@InstId", default, skip_serializing_if = "Option::is_none")]
+    pub instance_id: Option<u32>,
+
+    /// 쪽 나눔 여부 (`PageBreak`)
+    #[serde(rename = "@PageBreak", default, skip_serializing_if = "is_false")]
+    pub page_break: bool,
+
+    /// 단 나눔 여부 (`ColumnBreak`)
+    #[serde(rename = "@ColumnBreak", default, skip_serializing_if = "is_false")]
+    pub column_break: bool,
+
+    /// 텍스트 (`TEXT`)
+    #[serde(rename = "TEXT", default)]
+    pub texts: Vec<Text>,
+}
+
+const fn is_false(value: &bool) -> bool {
+    !*value
+}
+
+/// 컨트롤을 포함한 텍스트 (`TEXT`)
+#[derive(Debug, Clone, PartialEq, Default, Deserialize)]
+pub struct Text {
+    /// 글자 모양 아이디 참조 (`CharShape`)
+    #[serde(rename = "@CharShape", default)]
+    pub char_shape: Option<u32>,
+
+    /// 글자와 컨트롤 (`$value` 시퀀스)
+    #[serde(rename = "$value", default)]
+    pub items: Vec<TextItem>,
+}
+
+/// 모델에 없는 컨트롤([`TextItem::Unknown`])은 엘리먼트 이름이 정해져 있지 않으므로,
+/// 구조체 대신 맵으로 써서 이름을 키로 기록합니다.
+impl Serialize for Text {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let attributes = usize::from(self.char_shape.is_some());
+        let mut state = serializer.serialize_map(Some(attributes + self.items.len()))?;
+        if let Some(char_shape) = &self.char_shape {
+            state.serialize_entry("@CharShape", char_shape)?;
+        }
+        for item in &self.items {
+            match item {
+                TextItem::Unknown(element) => state.serialize_entry(&element.name, element)?,
+                item => state.serialize_entry("$value", item)?,
+            }
+        }
+        state.end()
+    }
+}
+
+/// `TEXT`의 자식 엘리먼트
+///
+/// [`TextItem::Unknown`]은 [`Text`]가 직접 쓰므로 단독으로 직렬화하지 않습니다.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub enum TextItem {
+    /// 구역 정의 (`SECDEF`)
+    #[serde(rename = "SECDEF")]
+    SectionDef(Box<SectionDef>),
+    /// 단 정의 (`COLDEF`)
+    #[serde(rename = "COLDEF")]
+    ColumnDef(ColumnDef),
+    /// 글자 (`CHAR`)
+    #[serde(rename = "CHAR")]
+    Char(Char),
+    /// 표 (`TABLE`)
+    #[serde(rename = "TABLE")]
+    Table(Box<Table>),
+    /// 그림 (`PICTURE`)
+    #[serde(rename = "PICTURE")]
+    Picture(Box<Picture>),
+    /// 수식 (`EQUATION`)
+    #[serde(rename = "EQUATION")]
+    Equation(Box<Equation>),
+    /// 모델에 없는 컨트롤 (도형, 필드 등)
+    #[serde(skip_serializing)]
+    Unknown(RawElement),
+}
+
+/// 엘리먼트 이름으로 변형을 고르고, 모르는 이름은 [`RawElement`]로 읽습니다.
+impl<'de> Deserialize<'de> for TextItem {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct TextItemVisitor;
+
+        impl<'de> Visitor<'de> for TextItemVisitor {
+            type Value = TextItem;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("TEXT child element")
+            }
+
+            fn visit_enum<A: EnumAccess<'de>>(self, data: A) -> Result<Self::Value, A::Error> {
+                let (name, variant): (String, _) = data.variant()?;
+                Ok(match name.as_str() {
+                    "SECDEF" => TextItem::SectionDef(variant.newtype_variant()?),
+                    "COLDEF" => TextItem::ColumnDef(variant.newtype_variant()?),
+                    "CHAR" => TextItem::Char(variant.newtype_variant()?),
+                    "TABLE" => TextItem::Table(variant.newtype_variant()?),
+                    "PICTURE" => TextItem::Picture(variant.newtype_variant()?),
+                    "EQUATION" => TextItem::Equation(variant.newtype_variant()?),
+                    _ => {
+                        let RawContent {
+                            attributes,
+                            children,
+                        } = variant.newtype_variant()?;
+                        TextItem::Unknown(RawElement {
+                            name,
+                            attributes,
+                            children,
+                        })
+                    }
+                })
+            }
+        }
+
+        deserializer.deserialize_enum(
+            "TextItem",
+            &["SECDEF", "COLDEF", "CHAR", "TABLE", "PICTURE", "EQUATION"],
+            TextItemVisitor,
+        )
+    }
+}
+
+/// 모델에 없는 엘리먼트
+///
+/// 속성과 자식을 문서 순서대로 보관합니다. 공백만으로 된 문자열은 남지 않습니다.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct RawElement {
+    /// 엘리먼트 이름
+    pub name: String,
+    /// 속성 (이름, 값)
+    pub attributes: Vec<(String, String)>,
+    /// 자식 엘리먼트와 문자열
+    pub children: Vec<RawNode>,
+}
+
+/// [`RawElement`]의 자식
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RawNode {
+    /// 엘리먼트
+    Element(RawElement),
+    /// 문자열
+    Text(String),
+}
+
+impl RawElement {
+    /// 부모가 이름을 정한 `name` 엘리먼트를 읽습니다.
+    ///
+    /// 구조체 필드처럼 엘리먼트 이름이 역직렬화기에 전달되지 않는 곳에서 씁니다.
+    pub(crate) fn deserialize_named<'de, D: Deserializer<'de>>(
+        deserializer: D,
+        name: &str,
+    ) -> Result<Self, D::Error> {
+        let RawContent {
+            attributes,
+            children,
+        } = RawContent::deserialize(deserializer)?;
+        Ok(Self {
+            name: name.to_string(),
+            attributes,
+            children,
+        })
+    }
+}
+
+/// 엘리먼트 이름은 부모가 키로 쓰므로, 속성은 `@` 키로, 자식은 이름을 키로 기록합니다.
+impl Serialize for RawElement {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state =
+            serializer.serialize_map(Some(self.attributes.len() + self.children.len()))?;
+        for (name, value) in &self.attributes {
+            state.serialize_entry(&format!("@{}", name), value)?;
+        }
+        for child in &self.children {
+            match child {
+                RawNode::Element(element) => state.serialize_entry(&element.name, element)?,
+                RawNode::Text(text) => state.serialize_entry("$text", text)?,
+            }
+        }
+        state.end()
+    }
+}
+
+/// 이름을 뺀 [`RawElement`]의 내용
+///
+/// quick-xml은 엘리먼트를 맵으로 읽을 때 속성을 `@` 키로, 문자열을 `$text` 키로,
+/// 자식 엘리먼트를 이름 키로 문서 순서대로 넘겨줍니다.
+struct RawContent {
+    attributes: Vec<(String, String)>,
+    children: Vec<RawNode>,
+}
+
+impl<'de> Deserialize<'de> for RawContent {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct RawContentVisitor;
+
+        impl<'de> Visitor<'de> for RawContentVisitor {
+            type Value = RawContent;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("XML element")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                let mut content = RawContent {
+                    attributes: Vec::new(),
+                    children: Vec::new(),
+                };
+                while let Some(key) = map.next_key::<String>()? {
+                    if let Some(name) = key.strip_prefix('@') {
+                        let value = map.next_value::<String>()?;
+                        content.attributes.push((name.to_string(), value));
+                    } else if key == "$text" {
+                        content.children.push(RawNode::Text(map.next_value()?));
+                    } else {
+                        let RawContent {
+                            attributes,
+                            children,
+                        } = map.next_value()?;
+                        content.children.push(RawNode::Element(RawElement {
+                            name: key,
+                            attributes,
+                            children,
+                        }));
+                    }
+                }
+                Ok(content)
+            }
+        }
+
+        deserializer.deserialize_map(RawContentVisitor)
+    }
+}
+
+/// 글자 (`CHAR`)
+#[derive(Debug, Clone, PartialEq, Default, Deserialize)]
+pub struct Char {
+    /// 스타일 아이디 참조 (`Style`)
+    #[serde(rename = "@Style", default)]
+    pub style: Option<u32>,
+
+    /// 문자열과 글자 엘리먼트 (`$value` 시퀀스)
+    #[serde(rename = "$value", default)]
+    pub items: Vec<CharItem>,
+}
+
+/// `$value` 시퀀스의 첫 항목이 `$text`이면 quick-xml 시퀀스 직렬화가 실패하므로,
+/// 문자열은 `$text` 필드로, 글자 엘리먼트는 `$value` 필드로 하나씩 기록합니다.
+impl Serialize for Char {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let attributes = usize::from(self.style.is_some());
+        let mut state = serializer.serialize_struct("CHAR", attributes + self.items.len())?;
+        if let Some(style) = &self.style {
+            state.serialize_field("@Style", style)?;
+        }
+        for item in &self.items {
+            match item {
+                CharItem::Text(text) => state.serialize_field("$text", text)?,
+                item => state.serialize_field("$value", item)?,
+            }
+        }
+        state.end()
+    }
+}
+
+impl Char {
+    /// 문자열만 담은 글자
+    pub fn text(text: impl Into<String>) -> Self {
+        Self {
+            style: None,
+            items: vec![CharItem::Text(text.into())],
+        }
+    }
+}
+
+/// `CHAR`의 내용
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum CharItem {
+    /// 문자열
+    #[serde(rename = "$text")]
+    Text(String),
+    /// 탭 (`TAB`)
+    #[serde(rename = "TAB")]
+    Tab,
+    /// 강제 줄 나눔 (`LINEBREAK`)
+    #[serde(rename = "LINEBREAK")]
+    LineBreak,
+    /// 하이픈 (`HYPEN`, 명세 표기 그대로)
+    #[serde(rename = "HYPEN")]
+    Hyphen,
+    /// 묶음 빈 칸 (`NBSPACE`)
+    #[serde(rename = "NBSPACE")]
+    NonBreakingSpace,
+    /// 고정폭 빈 칸 (`FWSPACE`)
+    #[serde(rename = "FWSPACE")]
+    FixedWidthSpace,
+    /// 제목 차례 표시 (`TITLEMARK`)
+    #[serde(rename = "TITLEMARK")]
+    TitleMark(TitleMark),
+    /// 형광펜 시작 (`MARKPENBEGIN`)
+    #[serde(rename = "MARKPENBEGIN")]
+    MarkPenBegin(MarkPenBegin),
+    /// 형광펜 끝 (`MARKPENEND`)
+    #[serde(rename = "MARKPENEND")]
+    MarkPenEnd,
+}
+
+/// 제목 차례 표시 (`TITLEMARK`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct TitleMark {
+    /// 차례 만들기 무시 (`Ignore`)
+    #[serde(rename = "@Ignore", default)]
+    pub ignore: bool,
+}
+
+/// 형광펜 시작 (`MARKPENBEGIN`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct MarkPenBegin {
+    /// 형광펜 색 (`Color`)
+    #[serde(rename = "@Color", default)]
+    pub color: RgbColor,
+}
+
+// ---------------------------------------------------------------------------
+// 구역/단 정의
+// ---------------------------------------------------------------------------
+
+/// 구역 정의 (`SECDEF`)
+///
+/// 구역의 첫 문단 첫 `TEXT`에 들어갑니다.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct SectionDef {
+    /// 단 사이 간격 (`SpaceColumns`, hwpunit)
+    #[serde(
+        rename = "@SpaceColumns",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub space_columns: Option<i32>,
+
+    /// 기본 탭 간격 (`TabStop`, hwpunit)
+    #[serde(rename = "@TabStop", default, skip_serializing_if = "Option::is_none")]
+    pub tab_stop: Option<i32>,
+
+    /// 시작 번호 (`STARTNUMBER`)
+    #[serde(
+        rename = "STARTNUMBER",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub start_number: Option<StartNumber>,
+
+    /// 용지 설정 (`PAGEDEF`)
+    #[serde(rename = "PAGEDEF", default, skip_serializing_if = "Option::is_none")]
+    pub page_def: Option<PageDef>,
+}
+
+/// 구역 시작 쪽 종류 (`STARTNUMBER`의 `PageStartsOn`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum PageStartsOn {
+    /// 이어서
+    #[default]
+    Both,
+    /// 짝수 쪽
+    Even,
+    /// 홀수 쪽
+    Odd,
+}
+
+/// 구역 시작 번호 (`STARTNUMBER`)
+///
+/// 번호가 0이면 앞 구역에 이어서 매깁니다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct StartNumber {
+    /// 구역 시작 쪽 종류 (`PageStartsOn`)
+    #[serde(rename = "@PageStartsOn", default)]
+    pub page_starts_on: PageStartsOn,
+
+    /// 쪽 시작 번호 (`Page`)
+    #[serde(rename = "@Page", default)]
+    pub page: u32,
+
+    /// 그림 시작 번호 (`Figure`)
+    #[serde(rename = "@Figure", default)]
+    pub figure: u32,
+
+    /// 표 시작 번호 (`Table`)
+    #[serde(rename = "@Table", default)]
+    pub table: u32,
+
+    /// 수식 시작 번호 (`Equation`)
+    #[serde(rename = "@Equation", default)]
+    pub equation: u32,
+}
+
+/// 제본 방법 (`PAGEDEF`의 `GutterType`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum GutterType {
+    /// 한쪽 편집
+    #[default]
+    LeftOnly,
+    /// 맞쪽 편집
+    LeftRight,
+    /// 위로 넘기기
+    TopBottom,
+}
+
+/// 용지 설정 (`PAGEDEF`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct PageDef {
+    /// 용지 방향 (`Landscape`, 0 = 세로, 1 = 가로)
+    #[serde(rename = "@Landscape", default)]
+    pub landscape: u8,
+
+    /// 용지 가로 크기 (`Width`, hwpunit)
+    #[serde(rename = "@Width")]
+    pub width: i32,
+
+    /// 용지 세로 크기 (`Height`, hwpunit)
+    #[serde(rename = "@Height")]
+    pub height: i32,
+
+    /// 제본 방법 (`GutterType`)
+    #[serde(rename = "@GutterType", default)]
+    pub gutter_type: GutterType,
+
+    /// 용지 여백 (`PAGEMARGIN`)
+    #[serde(
+        rename = "PAGEMARGIN",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub margin: Option<PageMargin>,
+}
+
+/// 용지 여백 (`PAGEMARGIN`, hwpunit)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct PageMargin {
+    /// 왼쪽 (`Left`)
+    #[serde(rename = "@Left", default)]
+    pub left: i32,
+    /// 오른쪽 (`Right`)
+    #[serde(rename = "@Right", default)]
+    pub right: i32,
+    /// 위 (`Top`)
+    #[serde(rename = "@Top", default)]
+    pub top: i32,
+    /// 아래 (`Bottom`)
+    #[serde(rename = "@Bottom", default)]
+    pub bottom: i32,
+    /// 머리말 (`Header`)
+    #[serde(rename = "@Header", default)]
+    pub header: i32,
+    /// 꼬리말 (`Footer`)
+    #[serde(rename = "@Footer", default)]
+    pub footer: i32,
+    /// 제본 (`Gutter`)
+    #[serde(rename = "@Gutter", default)]
+    pub gutter: i32,
+}
+
+/// 단 종류 (`COLDEF`의 `Type`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ColumnType {
+    /// 일반 다단
+    #[default]
+    Newspaper,
+    /// 배분 다단
+    BalancedNewspaper,
+    /// 평행 다단
+    Parallel,
+}
+
+/// 단 방향 (`COLDEF`의 `Layout`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ColumnLayout {
+    /// 왼쪽부터
+    #[default]
+    Left,
+    /// 오른쪽부터
+    Right,
+    /// 맞쪽
+    Mirror,
+}
+
+/// 단 정의 (`COLDEF`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct ColumnDef {
+    /// 단 종류 (`Type`)
+    #[serde(rename = "@Type", default)]
+    pub column_type: ColumnType,
+
+    /// 단 개수 (`Count`)
+    #[serde(rename = "@Count", default = "one")]
+    pub count: u16,
+
+    /// 단 방향 (`Layout`)
+    #[serde(rename = "@Layout", default)]
+    pub layout: ColumnLayout,
+
+    /// 단 너비를 같게 (`SameSize`)
+    #[serde(rename = "@SameSize", default)]
+    pub same_size: bool,
+
+    /// 단 사이 간격 (`SameGap`, hwpunit)
+    #[serde(rename = "@SameGap", default)]
+    pub same_gap: i32,
+}
+
+const fn one() -> u16 {
+    1
+}
@@ -0,0 +1,696 @@
+//! 본문 컨트롤 엘리먼트 (`TABLE`, `PICTURE`, `EQUATION`)
+//!
+//! `TEXT` 안에 글자와 함께 놓이는 표, 그림, 수식입니다. 세 컨트롤이 함께 쓰는
+//! 개체 속성(`SHAPEOBJECT`)과 표 셀의 문단 목록(`PARALIST`)도 여기에 둡니다.
+//!
+//! 그림의 회전/렌더링 정보(`SHAPECOMPONENT`), 테두리(`LINESHAPE`), 이미지 영역(`IMAGERECT`,
+//! `IMAGECLIP`)과 효과(`EFFECTS`), 개체 캡션(`CAPTION`), 표의 셀 영역(`CELLZONELIST`)은
+//! [`RawElement`]로 보관했다가 그대로 다시 씁니다.
+
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::body::{Paragraph, RawElement};
+use crate::mapping_table::Image;
+use crate::types::RgbColor;
+
+// ---------------------------------------------------------------------------
+// 개체 공통
+// ---------------------------------------------------------------------------
+
+/// 번호 종류 (`SHAPEOBJECT`의 `NumberingType`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum NumberingType {
+    /// 없음
+    #[default]
+    None,
+    /// 그림
+    Figure,
+    /// 표
+    Table,
+    /// 수식
+    Equation,
+}
+
+/// 본문과의 배치 (`SHAPEOBJECT`의 `TextWrap`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum TextWrap {
+    /// 어울림
+    Square,
+    /// 빈 공간까지 어울림
+    Tight,
+    /// 투명 배경까지 어울림
+    Through,
+    /// 자리 차지
+    #[default]
+    TopAndBottom,
+    /// 글 뒤로
+    BehindText,
+    /// 글 앞으로
+    InFrontOfText,
+}
+
+/// 글이 흐르는 쪽 (`SHAPEOBJECT`의 `TextFlow`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum TextFlow {
+    /// 양쪽
+    #[default]
+    BothSides,
+    /// 왼쪽
+    LeftOnly,
+    /// 오른쪽
+    RightOnly,
+    /// 넓은 쪽
+    LargestOnly,
+}
+
+/// 개체 속성 (`SHAPEOBJECT`)
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct ShapeObject {
+    /// 개체 아이디 (`InstId`)
+    #[serde(rename = "@InstId", default, skip_serializing_if = "Option::is_none")]
+    pub instance_id: Option<u32>,
+
+    /// Z 순서 (`ZOrder`)
+    #[serde(rename = "@ZOrder", default, skip_serializing_if = "Option::is_none")]
+    pub z_order: Option<i32>,
+
+    /// 번호 종류 (`NumberingType`)
+    #[serde(
+        rename = "@NumberingType",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub numbering_type: Option<NumberingType>,
+
+    /// 본문과의 배치 (`TextWrap`)
+    #[serde(rename = "@TextWrap", default, skip_serializing_if = "Option::is_none")]
+    pub text_wrap: Option<TextWrap>,
+
+    /// 글이 흐르는 쪽 (`TextFlow`)
+    #[serde(rename = "@TextFlow", default, skip_serializing_if = "Option::is_none")]
+    pub text_flow: Option<TextFlow>,
+
+    /// 크기와 위치 고정 (`Lock`)
+    #[serde(rename = "@Lock", default, skip_serializing_if = "Option::is_none")]
+    pub lock: Option<bool>,
+
+    /// 크기 (`SIZE`)
+    #[serde(rename = "SIZE", default, skip_serializing_if = "Option::is_none")]
+    pub size: Option<ObjectSize>,
+
+    /// 위치 (`POSITION`)
+    #[serde(rename = "POSITION", default, skip_serializing_if = "Option::is_none")]
+    pub position: Option<ObjectPosition>,
+
+    /// 바깥 여백 (`OUTSIDEMARGIN`)
+    #[serde(
+        rename = "OUTSIDEMARGIN",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub outside_margin: Option<Margin>,
+
+    /// 캡션 (`CAPTION`)
+    #[serde(
+        rename = "CAPTION",
+        default,
+        deserialize_with = "caption",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub caption: Option<RawElement>,
+
+    /// 개체 설명문 (`SHAPECOMMENT`)
+    #[serde(
+        rename = "SHAPECOMMENT",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub comment: Option<String>,
+}
+
+/// 너비 기준 (`SIZE`의 `WidthRelTo`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum WidthRelTo {
+    /// 종이
+    Paper,
+    /// 쪽
+    Page,
+    /// 단
+    Column,
+    /// 문단
+    Para,
+    /// 고정 값
+    #[default]
+    Absolute,
+}
+
+/// 높이 기준 (`SIZE`의 `HeightRelTo`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum HeightRelTo {
+    /// 종이
+    Paper,
+    /// 쪽
+    Page,
+    /// 고정 값
+    #[default]
+    Absolute,
+}
+
+/// 개체 크기 (`SIZE`, hwpunit)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct ObjectSize {
+    /// 너비 (`Width`)
+    #[serde(rename = "@Width", default)]
+    pub width: i32,
+
+    /// 너비 기준 (`WidthRelTo`)
+    #[serde(
+        rename = "@WidthRelTo",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub width_rel_to: Option<WidthRelTo>,
+
+    /// 높이 (`Height`)
+    #[serde(rename = "@Height", default)]
+    pub height: i32,
+
+    /// 높이 기준 (`HeightRelTo`)
+    #[serde(
+        rename = "@HeightRelTo",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub height_rel_to: Option<HeightRelTo>,
+
+    /// 크기 보호 (`Protect`)
+    #[serde(rename = "@Protect", default, skip_serializing_if = "Option::is_none")]
+    pub protect: Option<bool>,
+}
+
+/// 세로 위치 기준 (`POSITION`의 `VertRelTo`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum VertRelTo {
+    /// 종이
+    Paper,
+    /// 쪽
+    Page,
+    /// 문단
+    #[default]
+    Para,
+}
+
+/// 가로 위치 기준 (`POSITION`의 `HorzRelTo`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum HorzRelTo {
+    /// 종이
+    Paper,
+    /// 쪽
+    Page,
+    /// 단
+    #[default]
+    Column,
+    /// 문단
+    Para,
+}
+
+/// 세로 정렬 (`POSITION`의 `VertAlign`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum VertAlign {
+    /// 위
+    #[default]
+    Top,
+    /// 가운데
+    Center,
+    /// 아래
+    Bottom,
+    /// 안쪽
+    Inside,
+    /// 바깥쪽
+    Outside,
+}
+
+/// 가로 정렬 (`POSITION`의 `HorzAlign`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum HorzAlign {
+    /// 왼쪽
+    #[default]
+    Left,
+    /// 가운데
+    Center,
+    /// 오른쪽
+    Right,
+    /// 안쪽
+    Inside,
+    /// 바깥쪽
+    Outside,
+}
+
+/// 개체 위치 (`POSITION`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct ObjectPosition {
+    /// 글자처럼 취급 (`TreatAsChar`)
+    #[serde(rename = "@TreatAsChar", default)]
+    pub treat_as_char: bool,
+
+    /// 줄 간격에 영향 (`AffectLSpacing`)
+    #[serde(
+        rename = "@AffectLSpacing",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub affect_line_spacing: Option<bool>,
+
+    /// 세로 위치 기준 (`VertRelTo`)
+    #[serde(rename = "@VertRelTo", default)]
+    pub vert_rel_to: VertRelTo,
+
+    /// 세로 정렬 (`VertAlign`)
+    #[serde(rename = "@VertAlign", default)]
+    pub vert_align: VertAlign,
+
+    /// 가로 위치 기준 (`HorzRelTo`)
+    #[serde(rename = "@HorzRelTo", default)]
+    pub horz_rel_to: HorzRelTo,
+
+    /// 가로 정렬 (`HorzAlign`)
+    #[serde(rename = "@HorzAlign", default)]
+    pub horz_align: HorzAlign,
+
+    /// 세로 오프셋 (`VertOffset`, hwpunit)
+    #[serde(rename = "@VertOffset", default)]
+    pub vert_offset: i32,
+
+    /// 가로 오프셋 (`HorzOffset`, hwpunit)
+    #[serde(rename = "@HorzOffset", default)]
+    pub horz_offset: i32,
+
+    /// 본문 영역으로 제한 (`FlowWithText`)
+    #[serde(rename = "@FlowWithText", default)]
+    pub flow_with_text: bool,
+
+    /// 다른 개체와 겹침 허용 (`AllowOverlap`)
+    #[serde(rename = "@AllowOverlap", default)]
+    pub allow_overlap: bool,
+
+    /// 개체와 조판 부호를 항상 같은 쪽에 놓음 (`HoldAnchorAndSO`)
+    #[serde(
+        rename = "@HoldAnchorAndSO",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub hold_anchor_and_shape: Option<bool>,
+}
+
+/// 여백 (`OUTSIDEMARGIN`, `INSIDEMARGIN`, `CELLMARGIN`, hwpunit)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct Margin {
+    /// 왼쪽 (`Left`)
+    #[serde(rename = "@Left", default)]
+    pub left: i32,
+    /// 오른쪽 (`Right`)
+    #[serde(rename = "@Right", default)]
+    pub right: i32,
+    /// 위 (`Top`)
+    #[serde(rename = "@Top", default)]
+    pub top: i32,
+    /// 아래 (`Bottom`)
+    #[serde(rename = "@Bottom", default)]
+    pub bottom: i32,
+}
+
+// ---------------------------------------------------------------------------
+// 표
+// ---------------------------------------------------------------------------
+
+/// 쪽 경계에서 나눔 (`TABLE`의 `PageBreak`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum TablePageBreak {
+    /// 나누지 않음
+    None,
+    /// 셀 단위로 나눔
+    #[default]
+    Cell,
+    /// 표 단위로 나눔
+    Table,
+}
+
+/// 표 (`TABLE`)
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct Table {
+    /// 쪽 경계에서 나눔 (`PageBreak`)
+    #[serde(
+        rename = "@PageBreak",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub page_break: Option<TablePageBreak>,
+
+    /// 제목 행 반복 (`RepeatHeader`)
+    #[serde(
+        rename = "@RepeatHeader",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub repeat_header: Option<bool>,
+
+    /// 행 개수 (`RowCount`)
+    #[serde(rename = "@RowCount", default)]
+    pub row_count: u16,
+
+    /// 열 개수 (`ColCount`)
+    #[serde(rename = "@ColCount", default)]
+    pub col_count: u16,
+
+    /// 셀 간격 (`CellSpacing`, hwpunit)
+    #[serde(
+        rename = "@CellSpacing",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub cell_spacing: Option<i32>,
+
+    /// 테두리/배경 아이디 참조 (`BorderFill`)
+    #[serde(
+        rename = "@BorderFill",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub border_fill: Option<u32>,
+
+    /// 개체 속성 (`SHAPEOBJECT`)
+    #[serde(
+        rename = "SHAPEOBJECT",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub shape_object: Option<ShapeObject>,
+
+    /// 안쪽 여백 (`INSIDEMARGIN`)
+    #[serde(
+        rename = "INSIDEMARGIN",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub inside_margin: Option<Margin>,
+
+    /// 셀 영역 (`CELLZONELIST`)
+    #[serde(
+        rename = "CELLZONELIST",
+        default,
+        deserialize_with = "cell_zone_list",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub cell_zone_list: Option<RawElement>,
+
+    /// 행 (`ROW`)
+    #[serde(rename = "ROW", default)]
+    pub rows: Vec<Row>,
+}
+
+/// 표의 행 (`ROW`)
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct Row {
+    /// 셀 (`CELL`)
+    #[serde(rename = "CELL", default)]
+    pub cells: Vec<Cell>,
+}
+
+/// 표의 셀 (`CELL`)
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct Cell {
+    /// 셀 필드 이름 (`Name`)
+    #[serde(rename = "@Name", default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// 열 주소 (`ColAddr`, 0부터)
+    #[serde(rename = "@ColAddr", default)]
+    pub col_addr: u16,
+
+    /// 행 주소 (`RowAddr`, 0부터)
+    #[serde(rename = "@RowAddr", default)]
+    pub row_addr: u16,
+
+    /// 병합한 열 수 (`ColSpan`)
+    #[serde(rename = "@ColSpan", default, skip_serializing_if = "Option::is_none")]
+    pub col_span: Option<u16>,
+
+    /// 병합한 행 수 (`RowSpan`)
+    #[serde(rename = "@RowSpan", default, skip_serializing_if = "Option::is_none")]
+    pub row_span: Option<u16>,
+
+    /// 셀 너비 (`Width`, hwpunit)
+    #[serde(rename = "@Width", default, skip_serializing_if = "Option::is_none")]
+    pub width: Option<i32>,
+
+    /// 셀 높이 (`Height`, hwpunit)
+    #[serde(rename = "@Height", default, skip_serializing_if = "Option::is_none")]
+    pub height: Option<i32>,
+
+    /// 제목 셀 (`Header`)
+    #[serde(rename = "@Header", default, skip_serializing_if = "Option::is_none")]
+    pub header: Option<bool>,
+
+    /// 셀 여백을 따로 지정 (`HasMargin`)
+    #[serde(
+        rename = "@HasMargin",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub has_margin: Option<bool>,
+
+    /// 셀 보호 (`Protect`)
+    #[serde(rename = "@Protect", default, skip_serializing_if = "Option::is_none")]
+    pub protect: Option<bool>,
+
+    /// 양식 모드에서 편집 가능 (`Editable`)
+    #[serde(rename = "@Editable", default, skip_serializing_if = "Option::is_none")]
+    pub editable: Option<bool>,
+
+    /// 테두리/배경 아이디 참조 (`BorderFill`)
+    #[serde(
+        rename = "@BorderFill",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub border_fill: Option<u32>,
+
+    /// 셀 여백 (`CELLMARGIN`)
+    #[serde(
+        rename = "CELLMARGIN",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub margin: Option<Margin>,
+
+    /// 셀 내용 (`PARALIST`)
+    #[serde(rename = "PARALIST", default)]
+    pub para_list: ParaList,
+}
+
+/// 줄 나눔 방식 (`PARALIST`의 `LineWrap`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum LineWrap {
+    /// 일반 줄 나눔
+    #[default]
+    Break,
+    /// 자간을 줄여 한 줄로
+    Squeeze,
+    /// 내용에 따라 폭이 늘어남
+    Keep,
+}
+
+/// 문단 목록의 세로 정렬 (`PARALIST`의 `VertAlign`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ParaListVertAlign {
+    /// 위
+    #[default]
+    Top,
+    /// 가운데
+    Center,
+    /// 아래
+    Bottom,
+}
+
+/// 문단 목록 (`PARALIST`)
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct ParaList {
+    /// 글자 방향 (`TextDirection`, 0 = 가로, 1 = 세로)
+    #[serde(
+        rename = "@TextDirection",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub text_direction: Option<u8>,
+
+    /// 줄 나눔 방식 (`LineWrap`)
+    #[serde(rename = "@LineWrap", default, skip_serializing_if = "Option::is_none")]
+    pub line_wrap: Option<LineWrap>,
+
+    /// 세로 정렬 (`VertAlign`)
+    #[serde(
+        rename = "@VertAlign",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub vert_align: Option<ParaListVertAlign>,
+
+    /// 문단 (`P`)
+    #[serde(rename = "P", default)]
+    pub paragraphs: Vec<Paragraph>,
+}
+
+// ---------------------------------------------------------------------------
+// 그림
+// ---------------------------------------------------------------------------
+
+/// 그림 (`PICTURE`)
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct Picture {
+    /// 반전 (`Reverse`)
+    #[serde(rename = "@Reverse", default, skip_serializing_if = "Option::is_none")]
+    pub reverse: Option<bool>,
+
+    /// 개체 속성 (`SHAPEOBJECT`)
+    #[serde(
+        rename = "SHAPEOBJECT",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub shape_object: Option<ShapeObject>,
+
+    /// 회전/렌더링 정보 (`SHAPECOMPONENT`)
+    #[serde(
+        rename = "SHAPECOMPONENT",
+        default,
+        deserialize_with = "shape_component",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub shape_component: Option<RawElement>,
+
+    /// 테두리 선 (`LINESHAPE`)
+    #[serde(
+        rename = "LINESHAPE",
+        default,
+        deserialize_with = "line_shape",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub line_shape: Option<RawElement>,
+
+    /// 이미지 좌표 (`IMAGERECT`)
+    #[serde(
+        rename = "IMAGERECT",
+        default,
+        deserialize_with = "image_rect",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub image_rect: Option<RawElement>,
+
+    /// 자르기 (`IMAGECLIP`)
+    #[serde(
+        rename = "IMAGECLIP",
+        default,
+        deserialize_with = "image_clip",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub image_clip: Option<RawElement>,
+
+    /// 안쪽 여백 (`INSIDEMARGIN`)
+    #[serde(
+        rename = "INSIDEMARGIN",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub inside_margin: Option<Margin>,
+
+    /// 그림 (`IMAGE`)
+    #[serde(rename = "IMAGE", default)]
+    pub image: Image,
+
+    /// 그림 효과 (`EFFECTS`)
+    #[serde(
+        rename = "EFFECTS",
+        default,
+        deserialize_with = "effects",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub effects: Option<RawElement>,
+}
+
+// ---------------------------------------------------------------------------
+// 수식
+// ---------------------------------------------------------------------------
+
+/// 수식 (`EQUATION`)
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct Equation {
+    /// 줄 단위 배치 (`LineMode`)
+    #[serde(rename = "@LineMode", default, skip_serializing_if = "Option::is_none")]
+    pub line_mode: Option<bool>,
+
+    /// 글자 크기 (`BaseUnit`, hwpunit)
+    #[serde(rename = "@BaseUnit", default, skip_serializing_if = "Option::is_none")]
+    pub base_unit: Option<i32>,
+
+    /// 글자 색 (`TextColor`)
+    #[serde(
+        rename = "@TextColor",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub text_color: Option<RgbColor>,
+
+    /// 기준선 (`BaseLine`)
+    #[serde(rename = "@BaseLine", default, skip_serializing_if = "Option::is_none")]
+    pub base_line: Option<i32>,
+
+    /// 수식 버전 (`Version`)
+    #[serde(rename = "@Version", default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+
+    /// 개체 속성 (`SHAPEOBJECT`)
+    #[serde(
+        rename = "SHAPEOBJECT",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub shape_object: Option<ShapeObject>,
+
+    /// 수식 스크립트 (`SCRIPT`)
+    #[serde(rename = "SCRIPT", default)]
+    pub script: String,
+}
+
+// 구조를 모르는 자식은 필드 이름을 엘리먼트 이름으로 기록합니다.
+
+fn caption<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<RawElement>, D::Error> {
+    RawElement::deserialize_named(deserializer, "CAPTION").map(Some)
+}
+
+fn cell_zone_list<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<RawElement>, D::Error> {
+    RawElement::deserialize_named(deserializer, "CELLZONELIST").map(Some)
+}
+
+fn shape_component<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<RawElement>, D::Error> {
+    RawElement::deserialize_named(deserializer, "SHAPECOMPONENT").map(Some)
+}
+
+fn line_shape<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<RawElement>, D::Error> {
+    RawElement::deserialize_named(deserializer, "LINESHAPE").map(Some)
+}
+
+fn image_rect<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<RawElement>, D::Error> {
+    RawElement::deserialize_named(deserializer, "IMAGERECT").map(Some)
+}
+
+fn image_clip<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<RawElement>, D::Error> {
+    RawElement::deserialize_named(deserializer, "IMAGECLIP").map(Some)
+}
+
+fn effects<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<RawElement>, D::Error> {
+    RawElement::deserialize_named(deserializer, "EFFECTS").map(Some)
+}
@@ -0,0 +1,1114 @@
+//! IR → HWPML 변환
+//!
+//! IR 문서를 HWPML 문서로 변환합니다.
+//! 글꼴은 언어별로 다시 나누고, 구역 속성은 각 구역 첫 문단의 구역 정의(`SECDEF`)와
+//! 단 정의(`COLDEF`)로 기록합니다. 바이너리 데이터는 `TAIL`에 Base64로 포함합니다.
+//! 표, 그림, 수식은 `TEXT` 안의 컨트롤로 기록하고, 나머지 컨트롤과 필드, 책갈피, 글자 겹침,
+//! 덧말은 HWPML 모델에 없으므로 경고와 함께 버립니다.
+
+use super::{FromIrContext, IMAGE_FILL_MODES};
+use crate::Document as HwpmlDocument;
+use crate::body::{
+    Body, Char, CharItem, ColumnDef, ColumnLayout, ColumnType, GutterType, PageDef, PageMargin,
+    PageStartsOn as HwpmlPageStartsOn, Paragraph as HwpmlParagraph, Section as HwpmlSection,
+    SectionDef, StartNumber, Text as HwpmlText, TextItem,
+};
+use crate::control::{
+    Cell, Equation as HwpmlEquation, HeightRelTo, HorzAlign, HorzRelTo, Margin, NumberingType,
+    ObjectPosition, ObjectSize, ParaList, ParaListVertAlign, Picture as HwpmlPicture, Row,
+    ShapeObject, Table as HwpmlTable, TablePageBreak as HwpmlTablePageBreak, TextFlow,
+    TextWrap as HwpmlTextWrap, VertAlign, VertRelTo, WidthRelTo,
+};
+use crate::head::{BeginNumber, CaretPosition, DocSetting, DocSummary, Head};
+use crate::mapping_table::{
+    BinDataList, BinItem, BinItemType, BorderFill as HwpmlBorderFill, BorderFillList, BorderLine,
+    CharShape as HwpmlCharShape, CharShapeList, FaceNameList, FillBrush, Font as HwpmlFont,
+    FontFace, Gradation, GradationColor, GradationType, Image, ImageBrush, LangValues,
+    LineSpacingType as HwpmlLineSpacingType, MappingTable, Marker, Outline, ParaBorder, ParaMargin,
+    ParaShape as HwpmlParaShape, ParaShapeList, Shadow, ShadowType as HwpmlShadowType, Strikeout,
+    StrikeoutType, Style as HwpmlStyle, StyleList, StyleType as HwpmlStyleType, SubstituteFont,
+    TypeInfo, Underline, UnderlineType as HwpmlUnderlineType, WindowBrush,
+};
+use crate::tail::{BinData, BinDataStorage, ScriptCode, Tail};
+use crate::types::{
+    Alignment as HwpmlAlignment, FontType as HwpmlFontType, HatchStyle, LangType,
+    LineType as HwpmlLineType, NO_COLOR, OutlineLineType, color_to_rgb, format_line_width,
+};
+use ir::{
+    ConversionError, ConversionResult, Document as IrDocument, Paragraph as IrParagraph,
+    RunContent as IrRunContent, Section as IrSection,
+    border_fill::{Border, BorderFill, Fill, GradientType, ImageFillMode, PatternType},
+    char_shape::{CharShape, Font, FontType},
+    control::{Control, Equation as IrEquation, ObjectCommon},
+    para_shape::{LineSpacingType, LineSpacingValue, ParaShape},
+    picture::Picture as IrPicture,
+    section::ColumnDirection,
+    style::{Style, StyleStore},
+    table::{Table as IrTable, TableCell, TablePageBreak},
+};
+use primitive::{
+    Alignment, BreakType, EmphasisType, EquationFormat, GutterPosition, HeightRelativeTo,
+    HorizontalOffsetType, HorizontalRelativeTo, ImageEffect, Insets, LanguageType, LineType,
+    ObjectNumberingType, OutlineType, PageOrientation, PageStartsOn, ShadowType, StrikethroughType,
+    StyleType, TextWrapSide, TextWrapType, UnderlinePosition, UnderlineType, VerticalAlignment,
+    VerticalOffsetType, VerticalRelativeTo, WidthRelativeTo,
+};
+
+/// 언어 그룹이 비었을 때 넣는 기본 글꼴
+const DEFAULT_FONT_NAME: &str = "함초롬돋움";
+
+/// IR → HWPML 변환 트레이트
+pub trait IrToHwpml {
+    /// HWPML 문서로 변환
+    fn to_hwpml(&self) -> Result<ConversionResult<HwpmlDocument>, ConversionError>;
+}
+
+impl IrToHwpml for IrDocument {
+    fn to_hwpml(&self) -> Result<ConversionResult<HwpmlDocument>, ConversionError> {
+        let mut ctx = FromIrContext::new();
+        let doc = convert_document(self, &mut ctx)?;
+        Ok(ctx.warnings.into_result(doc))
+    }
+}
+
+/// 문서 변환
+fn convert_document(
+    ir: &IrDocument,
+    ctx: &mut FromIrContext,
+) -> Result<HwpmlDocument, ConversionError> {
+    let mut doc = HwpmlDocument::new();
+
+    // 문서를 저장한 한글 버전
+    if let Some(version) = &ir.metadata.version {
+        doc.sub_version = Some(format!(
+            "{}.{}.{}.{}",
+            version.major, version.minor, version.patch, version.build
+        ));
+    }
+
+    // 바이너리 데이터 (그림 채우기가 참조하므로 매핑 테이블보다 먼저 번호를 매김)
+    let (bin_items, bin_data) = build_binary_data(ir, ctx);
+
+    doc.head = Head {
+        section_count: Some(ir.sections.len() as u32),
+        summary: Some(build_summary(ir)),
+        setting: Some(build_setting(ir)),
+        mapping_table: Some(build_mapping_table(&ir.styles, bin_items, ctx)),
+    };
+
+    // 본문
+    let mut lost_contents = 0;
+    doc.body = Body {
+        sections: ir
+            .sections
+            .iter()
+            .zip(0u32..)
+            .map(|(section, id)| build_section(section, id, &mut lost_contents, ctx))
+            .collect(),
+    };
+    if lost_contents > 0 {
+        ctx.warnings.data_loss(format!(
+            "HWPML로 표현할 수 없는 컨트롤/필드/책갈피 {}개는 변환되지 않습니다",
+            lost_contents
+        ));
+    }
+
+    // 부가 정보
+    doc.tail = build_tail(ir, bin_data);
+
+    Ok(doc)
+}
+
+/// 문서 요약 정보 빌드 (`DOCSUMMARY`)
+fn build_summary(ir: &IrDocument) -> DocSummary {
+    let metadata = &ir.metadata;
+    DocSummary {
+        title: metadata.title.clone(),
+        subject: metadata.subject.clone(),
+        author: metadata.author.clone(),
+        date: metadata.created.clone(),
+        keywords: (!metadata.keywords.is_empty()).then(|| metadata.keywords.join(", ")),
+        comments: metadata.description.clone(),
+        forbidden_string: None,
+    }
+}
+
+/// 문서 설정 빌드 (`DOCSETTING`)
+fn build_setting(ir: &IrDocument) -> DocSetting {
+    let settings = &ir.settings;
+    let start = |number: u32| Some(number.max(1));
+    DocSetting {
+        begin_number: Some(BeginNumber {
+            page: start(settings.starting_page_number),
+            footnote: start(settings.starting_footnote_number),
+            endnote: start(settings.starting_endnote_number),
+            picture: Some(1),
+            table: Some(1),
+            equation: Some(1),
+            total_page: None,
+        }),
+        caret_position: settings.caret_position.as_ref().map(|caret| CaretPosition {
+            list: Some(caret.section),
+            paragraph: Some(caret.paragraph),
+            position: Some(caret.position),
+        }),
+    }
+}
+
+/// 바이너리 데이터 빌드 (`BINDATALIST`, `BINDATASTORAGE`)
+///
+/// `BIN{16진수}` 형식의 아이디는 숫자 순서로, 나머지는 그 뒤에 이름 순서로
+/// 1부터 다시 번호를 매기고 그 대응을 변환 컨텍스트에 기록합니다.
+fn build_binary_data(ir: &IrDocument, ctx: &mut FromIrContext) -> (Vec<BinItem>, Vec<BinData>) {
+    let mut entries: Vec<_> = ir.binary_data.iter().collect();
+    entries.sort_by_key(|(id, _)| {
+        let numeric = id
+            .value()
+            .strip_prefix("BIN")
+            .and_then(|hex| u16::from_str_radix(hex, 16).ok());
+        (numeric.is_none(), numeric, id.value().to_string())
+    });
+
+    entries
+        .into_iter()
+        .zip(1u16..)
+        .map(|((ir_id, binary), id)| {
+            ctx.binary_ids.insert(ir_id.clone(), id);
+            let extension = binary
+                .filename
+                .as_deref()
+                .and_then(|name| name.rsplit_once('.'))
+                .map(|(_, ext)| ext.to_string())
+                .filter(|ext| !ext.is_empty())
+                .unwrap_or_else(|| binary.format.extension().to_string());
+            let item = BinItem {
+                item_type: BinItemType::Embedding,
+                absolute_path: None,
+                relative_path: None,
+                bin_data: Some(id.to_string()),
+                format: Some(extension),
+            };
+            let data = BinData::encode(id.to_string(), &binary.data, binary.compressed);
+            (item, data)
+        })
+        .unzip()
+}
+
+/// 매핑 테이블 빌드 (`MAPPINGTABLE`)
+fn build_mapping_table(
+    styles: &StyleStore,
+    bin_items: Vec<BinItem>,
+    ctx: &mut FromIrContext,
+) -> MappingTable {
+    if !styles.tab_defs.is_empty() || !styles.numberings.is_empty() || !styles.bullets.is_empty() {
+        ctx.warnings
+            .data_loss("탭 정의, 문단 번호, 글머리표는 HWPML로 변환되지 않습니다");
+    }
+
+    MappingTable {
+        bin_data_list: (!bin_items.is_empty()).then_some(BinDataList {
+            count: bin_items.len() as u32,
+            items: bin_items,
+        }),
+        face_name_list: Some(build_face_names(styles)),
+        border_fill_list: Some(BorderFillList {
+            count: styles.border_fills.len() as u32,
+            border_fills: styles
+                .border_fills
+                .iter()
+                .zip(1u32..)
+                .map(|(border_fill, id)| convert_border_fill(border_fill, id, ctx))
+                .collect(),
+        }),
+        char_shape_list: Some(CharShapeList {
+            count: styles.char_shapes.len() as u32,
+            char_shapes: styles
+                .char_shapes
+                .iter()
+                .zip(0u32..)
+                .map(|(shape, id)| convert_char_shape(shape, id))
+                .collect(),
+        }),
+        para_shape_list: Some(ParaShapeList {
+            count: styles.para_shapes.len() as u32,
+            para_shapes: styles
+                .para_shapes
+                .iter()
+                .zip(0u32..)
+                .map(|(shape, id)| convert_para_shape(shape, id))
+                .collect(),
+        }),
+        style_list: Some(StyleList {
+            count: styles.styles.len() as u32,
+            styles: styles
+                .styles
+                .iter()
+                .zip(0u32..)
+                .map(|(style, id)| convert_style(style, id))
+                .collect(),
+        }),
+        ..MappingTable::default()
+    }
+}
+
+/// 글꼴 목록 빌드 (`FACENAMELIST`)
+///
+/// 언어 그룹이 기록된 글꼴은 그룹별로 IR 순서를 유지하므로 글자 모양의
+/// 언어별 글꼴 아이디가 그대로 유효합니다. 언어 그룹이 없는 글꼴은 한글 그룹에 넣습니다.
+fn build_face_names(styles: &StyleStore) -> FaceNameList {
+    let font_faces = LangType::ALL
+        .into_iter()
+        .map(|language| {
+            let mut fonts: Vec<HwpmlFont> = styles
+                .fonts
+                .iter()
+                .filter(|font| {
+                    font.language.unwrap_or(LanguageType::Korean) == convert_language(language)
+                })
+                .map(convert_font)
+                .collect();
+            if fonts.is_empty() {
+                fonts.push(HwpmlFont {
+                    font_type: HwpmlFontType::TrueType,
+                    name: DEFAULT_FONT_NAME.to_string(),
+                    ..Default::default()
+                });
+            }
+            for (font, id) in fonts.iter_mut().zip(0u32..) {
+                font.id = id;
+            }
+            FontFace {
+                language,
+                count: fonts.len() as u32,
+                fonts,
+            }
+        })
+        .collect();
+
+    FaceNameList { font_faces }
+}
+
+/// 글꼴 변환 (`FONT`)
+fn convert_font(font: &Font) -> HwpmlFont {
+    let substitute = font
+        .substitute_font
+        .as_ref()
+        .map(|substitute| SubstituteFont {
+            font_type: convert_font_type(substitute.font_type),
+            name: substitute.face.clone(),
+        })
+        .or_else(|| {
+            font.alternate_name.as_ref().map(|name| SubstituteFont {
+                font_type: HwpmlFontType::TrueType,
+                name: name.clone(),
+            })
+        });
+
+    let type_info = font.panose.map(|panose| {
+        let [
+            family_type,
+            serif_style,
+            weight,
+            proportion,
+            contrast,
+            stroke_variation,
+            arm_style,
+            letterform,
+            midline,
+            x_height,
+        ] = panose.to_bytes();
+        TypeInfo {
+            family_type,
+            serif_style,
+            weight,
+            proportion,
+            contrast,
+            stroke_variation,
+            arm_style,
+            letterform,
+            midline,
+            x_height,
+        }
+    });
+
+    HwpmlFont {
+        id: 0,
+        font_type: convert_font_type(font.font_type),
+        name: font.name.clone(),
+        substitute,
+        type_info,
+    }
+}
+
+/// 테두리/배경 변환 (`BORDERFILL`)
+fn convert_border_fill(
+    border_fill: &BorderFill,
+    id: u32,
+    ctx: &mut FromIrContext,
+) -> HwpmlBorderFill {
+    let diagonal = border_fill
+        .diagonal_down
+        .as_ref()
+        .or(border_fill.diagonal_up.as_ref())
+        .map(convert_border);
+
+    HwpmlBorderFill {
+        id,
+        three_d: Some(border_fill.is_3d),
+        shadow: Some(border_fill.has_shadow),
+        slash: Some(u8::from(border_fill.diagonal_up.is_some())),
+        back_slash: Some(u8::from(border_fill.diagonal_down.is_some())),
+        left: Some(convert_border(&border_fill.left)),
+        right: Some(convert_border(&border_fill.right)),
+        top: Some(convert_border(&border_fill.top)),
+        bottom: Some(convert_border(&border_fill.bottom)),
+        diagonal,
+        fill_brush: convert_fill(&border_fill.fill, ctx),
+    }
+}
+
+/// 테두리 선 변환
+fn convert_border(border: &Border) -> BorderLine {
+    BorderLine {
+        line_type: convert_line_type(border.line_type),
+        width: Some(format_line_width(border.width).to_string()),
+        color: color_to_rgb(border.color),
+    }
+}
+
+/// 채우기 변환 (`FILLBRUSH`)
+fn convert_fill(fill: &Fill, ctx: &mut FromIrContext) -> Option<FillBrush> {
+    let brush = match fill {
+        Fill::None => return None,
+        Fill::Solid(solid) => FillBrush {
+            window_brush: Some(WindowBrush {
+                face_color: color_to_rgb(solid.color),
+                hatch_color: 0,
+                hatch_style: None,
+                alpha: (solid.alpha != 255).then(|| 255 - solid.alpha),
+            }),
+            ..Default::default()
+        },
+        Fill::Pattern(pattern) => FillBrush {
+            window_brush: Some(WindowBrush {
+                face_color: color_to_rgb(pattern.background),
+                hatch_color: color_to_rgb(pattern.foreground),
+                hatch_style: convert_pattern_type(pattern.pattern_type),
+                alpha: None,
+            }),
+            ..Default::default()
+        },
+        Fill::Gradient(gradient) => FillBrush {
+            gradation: Some(Gradation {
+                gradation_type: match gradient.gradient_type {
+                    GradientType::Linear => GradationType::Linear,
+                    GradientType::Radial => GradationType::Radial,
+                    GradientType::Conical => GradationType::Conical,
+                    GradientType::Square => GradationType::Square,
+                },
+                angle: Some(gradient.angle as i32),
+                center_x: Some(gradient.center_x as i32),
+                center_y: Some(gradient.center_y as i32),
+                step: Some(gradient.blur as u32),
+                color_count: Some(gradient.stops.len() as u32),
+                step_center: Some(gradient.step_center as u32),
+                alpha: None,
+                colors: gradient
+                    .stops
+                    .iter()
+                    .map(|stop| GradationColor {
+                        value: color_to_rgb(stop.color),
+                    })
+                    .collect(),
+            }),
+            ..Default::default()
+        },
+        Fill::Image(image) => FillBrush {
+            image_brush: Some(ImageBrush {
+                mode: Some(image_fill_mode_name(image.mode).to_string()),
+                image: Some(Image {
+                    bright: Some(image.brightness as i32),
+                    contrast: Some(image.contrast as i32),
+                    effect: Some(image_effect_name(image.effect).to_string()),
+                    bin_item: Some(ctx.binary_item_id(&image.binary_id) as u32),
+                    alpha: None,
+                }),
+            }),
+            ..Default::default()
+        },
+    };
+    Some(brush)
+}
+
+/// 글자 모양 변환 (`CHARSHAPE`)
+fn convert_char_shape(shape: &CharShape, id: u32) -> HwpmlCharShape {
+    let mut font_id = LangValues::all(0);
+    let mut ratio = LangValues::all(shape.char_scale.0 as i32);
+    let mut spacing = LangValues::all(shape.char_spacing.0 as i32);
+    let mut relative_size = LangValues::all(100);
+    let mut offset = LangValues::all(0);
+    for language in LangType::ALL {
+        if let Some(font_ref) = shape.fonts.get(convert_language(language)) {
+            font_id.set(language, font_ref.id.value() as i32);
+            ratio.set(language, font_ref.width_ratio.0 as i32);
+            spacing.set(language, font_ref.spacing.0 as i32);
+            relative_size.set(language, font_ref.relative_size.0 as i32);
+            offset.set(language, font_ref.offset.0 as i32);
+        }
+    }
+
+    let text_color = color_to_rgb(shape.color);
+    let marker = |flag: bool| flag.then_some(Marker);
+
+    HwpmlCharShape {
+        id,
+        height: Some(shape.font_size.value()),
+        text_color,
+        shade_color: Some(
+            shape
+                .shade_color
+                .or(shape.background_color)
+                .map_or(NO_COLOR, color_to_rgb),
+        ),
+        use_font_space: false,
+        use_kerning: shape.use_kerning,
+        sym_mark: match shape.emphasis.emphasis_type {
+            EmphasisType::None => 0,
+            EmphasisType::Circle => 1,
+            EmphasisType::CircleOpen => 2,
+            EmphasisType::Caron => 3,
+            EmphasisType::Tilde => 4,
+            EmphasisType::Dot | EmphasisType::Comma => 5,
+            EmphasisType::Colon => 6,
+        },
+        border_fill_id: shape.border_fill_id_ref.map(|id| id.value()),
+        font_id: Some(font_id),
+        ratio: Some(ratio),
+        char_spacing: Some(spacing),
+        relative_size: Some(relative_size),
+        char_offset: Some(offset),
+        italic: marker(shape.italic),
+        bold: marker(shape.bold),
+        underline: (shape.underline.line_type != UnderlineType::None).then(|| Underline {
+            underline_type: match shape.underline.position {
+                UnderlinePosition::Bottom => HwpmlUnderlineType::Bottom,
+                UnderlinePosition::Top => HwpmlUnderlineType::Top,
+            },
+            shape: convert_underline_type(shape.underline.line_type),
+            color: shape.underline.color.map_or(text_color, color_to_rgb),
+        }),
+        strikeout: (shape.strikethrough != StrikethroughType::None).then_some(Strikeout {
+            strikeout_type: StrikeoutType::Continuous,
+            shape: match shape.strikethrough {
+                StrikethroughType::Double => HwpmlLineType::DoubleSlim,
+                _ => HwpmlLineType::Solid,
+            },
+            color: text_color,
+        }),
+        outline: (shape.outline != OutlineType::None).then_some(Outline {
+            outline_type: OutlineLineType::Solid,
+        }),
+        shadow: (shape.shadow.shadow_type != ShadowType::None).then(|| Shadow {
+            shadow_type: match shape.shadow.shadow_type {
+                ShadowType::TopLeftContinuous
+                | ShadowType::TopRightContinuous
+                | ShadowType::BottomLeftContinuous
+                | ShadowType::BottomRightContinuous => HwpmlShadowType::Cont,
+                _ => HwpmlShadowType::Drop,
+            },
+            color: shape.shadow.color.map_or(0x00B2_B2B2, color_to_rgb),
+            offset_x: Some(shape.shadow.offset_x.value()),
+            offset_y: Some(shape.shadow.offset_y.value()),
+        }),
+        emboss: marker(shape.emboss),
+        engrave: marker(shape.engrave),
+        superscript: marker(shape.superscript),
+        subscript: marker(shape.subscript),
+    }
+}
+
+/// 문단 모양 변환 (`PARASHAPE`)
+fn convert_para_shape(shape: &ParaShape, id: u32) -> HwpmlParaShape {
+    let line_spacing = match shape.line_spacing.value {
+        LineSpacingValue::Percent(percent) => percent.0 as i32,
+        LineSpacingValue::Fixed(unit) => unit.value(),
+    };
+
+    HwpmlParaShape {
+        id,
+        align: match shape.alignment {
+            Alignment::Justify => HwpmlAlignment::Justify,
+            Alignment::Left => HwpmlAlignment::Left,
+            Alignment::Right => HwpmlAlignment::Right,
+            Alignment::Center => HwpmlAlignment::Center,
+            Alignment::Distribute => HwpmlAlignment::Distribute,
+            Alignment::Divide => HwpmlAlignment::DistributeSpace,
+        },
+        tab_def: shape.tab_def_id.map(|id| id.value()),
+        widow_orphan: shape.widow_orphan_control,
+        keep_with_next: shape.keep_with_next,
+        keep_lines: shape.keep_lines,
+        page_break_before: shape.page_break_before,
+        snap_to_grid: shape.snap_to_grid,
+        auto_space_east_asian_english: shape.auto_spacing_east_asian_english,
+        auto_space_east_asian_number: shape.auto_spacing_east_asian_number,
+        margin: Some(ParaMargin {
+            indent: shape.first_line_indent.value(),
+            left: shape.margin_left.value(),
+            right: shape.margin_right.value(),
+            prev: shape.space_before.value(),
+            next: shape.space_after.value(),
+            line_spacing_type: match shape.line_spacing.spacing_type {
+                LineSpacingType::Percent => HwpmlLineSpacingType::Percent,
+                LineSpacingType::Fixed => HwpmlLineSpacingType::Fixed,
+                LineSpacingType::FontBased => HwpmlLineSpacingType::BetweenLines,
+                LineSpacingType::AtLeast => HwpmlLineSpacingType::AtLeast,
+            },
+            line_spacing: Some(line_spacing),
+        }),
+        border: shape.border_fill_id.map(|id| ParaBorder {
+            border_fill: Some(id.value()),
+        }),
+    }
+}
+
+/// 스타일 변환 (`STYLE`)
+fn convert_style(style: &Style, id: u32) -> HwpmlStyle {
+    HwpmlStyle {
+        id,
+        style_type: match style.style_type {
+            StyleType::Paragraph => HwpmlStyleType::Para,
+            StyleType::Character => HwpmlStyleType::Char,
+        },
+        name: style.name.clone(),
+        english_name: style.english_name.clone(),
+        para_shape: style.para_shape_id.map(|id| id.value()),
+        char_shape: style.char_shape_id.map(|id| id.value()),
+        next_style: style.next_style_id.map(|id| id.value()),
+        language_id: None,
+    }
+}
+
+/// 구역 빌드 (`SECTION`)
+///
+/// 구역 정의와 단 정의는 첫 문단 앞쪽에 별도 `TEXT`로 넣습니다.
+fn build_section(
+    section: &IrSection,
+    id: u32,
+    lost_contents: &mut usize,
+    ctx: &mut FromIrContext,
+) -> HwpmlSection {
+    if !section.headers.is_empty() || !section.footers.is_empty() {
+        ctx.warnings
+            .data_loss("머리말/꼬리말은 HWPML로 변환되지 않습니다");
+    }
+
+    let mut paragraphs: Vec<HwpmlParagraph> = section
+        .paragraphs
+        .iter()
+        .map(|paragraph| build_paragraph(paragraph, lost_contents, ctx))
+        .collect();
+    if paragraphs.is_empty() {
+        paragraphs.push(empty_paragraph());
+    }
+
+    let first = &mut paragraphs[0];
+    let char_shape = first.texts.first().and_then(|text| text.char_shape);
+    first.texts.insert(
+        0,
+        HwpmlText {
+            char_shape: char_shape.or(Some(0)),
+            items: vec![
+                TextItem::SectionDef(Box::new(build_section_def(section))),
+                TextItem::ColumnDef(build_column_def(section)),
+            ],
+        },
+    );
+
+    HwpmlSection { id, paragraphs }
+}
+
+/// 구역 정의 빌드 (`SECDEF`)
+fn build_section_def(section: &IrSection) -> SectionDef {
+    let page = &section.page;
+    let margins = &page.margins;
+    let start = &section.start_number;
+
+    SectionDef {
+        space_columns: Some(section.columns.gap.value()),
+        tab_stop: None,
+        start_number: Some(StartNumber {
+            page_starts_on: match start.page_starts_on {
+                PageStartsOn::Both => HwpmlPageStartsOn::Both,
+                PageStartsOn::Even => HwpmlPageStartsOn::Even,
+                PageStartsOn::Odd => HwpmlPageStartsOn::Odd,
+            },
+            page: start.page,
+            figure: start.picture,
+            table: start.table,
+            equation: start.equation,
+        }),
+        page_def: Some(PageDef {
+            landscape: u8::from(page.orientation == PageOrientation::Landscape),
+            width: page.width.value(),
+            height: page.height.value(),
+            gutter_type: match page.gutter_position {
+                GutterPosition::Left => GutterType::LeftOnly,
+                GutterPosition::Right => GutterType::LeftRight,
+                GutterPosition::Top | GutterPosition::Bottom => GutterType::TopBottom,
+            },
+            margin: Some(PageMargin {
+                left: margins.left.value(),
+                right: margins.right.value(),
+                top: margins.top.value(),
+                bottom: margins.bottom.value(),
+                header: margins.header.value(),
+                footer: margins.footer.value(),
+                gutter: margins.gutter.value(),
+            }),
+        }),
+    }
+}
+
+/// 단 정의 빌드 (`COLDEF`)
+fn build_column_def(section: &IrSection) -> ColumnDef {
+    let columns = &section.columns;
+    ColumnDef {
+        column_type: ColumnType::Newspaper,
+        count: columns.count.max(1),
+        layout: match columns.direction {
+            ColumnDirection::LeftToRight => ColumnLayout::Left,
+            ColumnDirection::RightToLeft => ColumnLayout::Right,
+            ColumnDirection::FacingPages => ColumnLayout::Mirror,
+        },
+        same_size: columns.widths.is_empty(),
+        same_gap: columns.gap.value(),
+    }
+}
+
+/// 빈 문단 (구역과 셀은 문단이 하나 이상 있어야 함)
+fn empty_paragraph() -> HwpmlParagraph {
+    HwpmlParagraph {
+        para_shape: Some(0),
+        style: Some(0),
+        ..Default::default()
+    }
+}
+
+/// 문단 빌드 (`P`)
+///
+/// 런 하나를 `TEXT` 하나로 옮기고, 런의 글자 내용은 컨트롤 사이마다 `CHAR` 하나로 묶습니다.
+fn build_paragraph(
+    paragraph: &IrParagraph,
+    lost_contents: &mut usize,
+    ctx: &FromIrContext,
+) -> HwpmlParagraph {
+    let texts = paragraph
+        .runs
+        .iter()
+        .map(|run| {
+            let mut items = Vec::new();
+            let mut chars = Vec::new();
+            for content in &run.contents {
+                let item = match content {
+                    IrRunContent::Text(text) if text.text.is_empty() => continue,
+                    IrRunContent::Text(text) => CharItem::Text(text.text.clone()),
+                    IrRunContent::Tab(_) => CharItem::Tab,
+                    IrRunContent::LineBreak => CharItem::LineBreak,
+                    IrRunContent::Hyphen => CharItem::Hyphen,
+                    IrRunContent::NonBreakingSpace => CharItem::NonBreakingSpace,
+                    IrRunContent::FixedWidthSpace => CharItem::FixedWidthSpace,
+                    IrRunContent::Control(control) => {
+                        match build_control(control, lost_contents, ctx) {
+                            Some(control) => {
+                                push_chars(&mut items, &mut chars);
+                                items.push(control);
+                            }
+                            None => *lost_contents += 1,
+                        }
+                        continue;
+                    }
+                    // 필드/책갈피 끝은 시작과 짝을 이루므로 한 번만 셉니다.
+                    IrRunContent::FieldEnd(_) | IrRunContent::BookmarkEnd(_) => continue,
+                    IrRunContent::FieldStart(_)
+                    | IrRunContent::BookmarkStart(_)
+                    | IrRunContent::Compose(_)
+                    | IrRunContent::Dutmal(_) => {
+                        *lost_contents += 1;
+                        continue;
+                    }
+                };
+                chars.push(item);
+            }
+            push_chars(&mut items, &mut chars);
+
+            HwpmlText {
+                char_shape: Some(run.char_shape_id.map_or(0, |id| id.value())),
+                items,
+            }
+        })
+        .collect();
+
+    HwpmlParagraph {
+        para_shape: Some(paragraph.para_shape_id.map_or(0, |id| id.value())),
+        style: Some(paragraph.style_id.map_or(0, |id| id.value())),
+        instance_id: paragraph.instance_id,
+        page_break: paragraph.break_type == BreakType::Page,
+        column_break: paragraph.break_type == BreakType::Column,
+        texts,
+    }
+}
+
+/// 모아 둔 글자 내용을 `CHAR` 하나로 추가
+fn push_chars(items: &mut Vec<TextItem>, chars: &mut Vec<CharItem>) {
+    if !chars.is_empty() {
+        items.push(TextItem::Char(Char {
+            style: None,
+            items: std::mem::take(chars),
+        }));
+    }
+}
+
+/// 컨트롤 빌드 (표, 그림, 한글 수식 스크립트만, 나머지는 `None`)
+fn build_control(
+    control: &Control,
+    lost_contents: &mut usize,
+    ctx: &FromIrContext,
+) -> Option<TextItem> {
+    Some(match control {
+        Control::Table(table) => TextItem::Table(Box::new(build_table(table, lost_contents, ctx))),
+        Control::Picture(picture) => {
+            TextItem::Picture(Box::new(build_picture(picture, lost_contents, ctx)))
+        }
+        Control::Equation(equation) if equation.format == EquationFormat::HwpScript => {
+            TextItem::Equation(Box::new(build_equation(equation, lost_contents)))
+        }
+        _ => return None,
+    })
+}
+
+/// 개체 속성 빌드 (`SHAPEOBJECT`)
+///
+/// 캡션은 HWPML 모델에 없으므로 버린 내용으로 셉니다.
+fn build_shape_object(common: &ObjectCommon, lost_contents: &mut usize) -> ShapeObject {
+    if common.caption.is_some() {
+        *lost_contents += 1;
+    }
+
+    let wrap = &common.text_wrap;
+    let treat_as_char = wrap.treat_as_char || wrap.wrap_type == TextWrapType::Inline;
+    ShapeObject {
+        instance_id: common.id,
+        z_order: Some(common.z_order),
+        numbering_type: common.numbering_type.map(|numbering| match numbering {
+            ObjectNumberingType::None => NumberingType::None,
+            ObjectNumberingType::Picture => NumberingType::Figure,
+            ObjectNumberingType::Table => NumberingType::Table,
+            ObjectNumberingType::Equation => NumberingType::Equation,
+        }),
+        text_wrap: Some(match wrap.wrap_type {
+            TextWrapType::Inline => HwpmlTextWrap::TopAndBottom,
+            TextWrapType::Square => HwpmlTextWrap::Square,
+            TextWrapType::Tight => HwpmlTextWrap::Tight,
+            TextWrapType::Behind => HwpmlTextWrap::BehindText,
+            TextWrapType::InFront => HwpmlTextWrap::InFrontOfText,
+        }),
+        text_flow: Some(match wrap.wrap_side {
+            TextWrapSide::Both => TextFlow::BothSides,
+            TextWrapSide::Left => TextFlow::LeftOnly,
+            TextWrapSide::Right => TextFlow::RightOnly,
+            TextWrapSide::Largest => TextFlow::LargestOnly,
+        }),
+        lock: None,
+        size: Some(ObjectSize {
+            width: common.size.width.value(),
+            width_rel_to: Some(match common.width_relative_to {
+                WidthRelativeTo::Paper => WidthRelTo::Paper,
+                WidthRelativeTo::Page => WidthRelTo::Page,
+                WidthRelativeTo::Column => WidthRelTo::Column,
+                WidthRelativeTo::Paragraph => WidthRelTo::Para,
+                WidthRelativeTo::Absolute => WidthRelTo::Absolute,
+            }),
+            height: common.size.height.value(),
+            height_rel_to: Some(match common.height_relative_to {
+                HeightRelativeTo::Paper => HeightRelTo::Paper,
+                HeightRelativeTo::Page => HeightRelTo::Page,
+                HeightRelativeTo::Absolute => HeightRelTo::Absolute,
+            }),
+            protect: None,
+        }),
+        position: Some(ObjectPosition {
+            treat_as_char,
+            affect_line_spacing: None,
+            vert_rel_to: match wrap.vertical_rel {
+                VerticalRelativeTo::Paper => VertRelTo::Paper,
+                VerticalRelativeTo::Page => VertRelTo::Page,
+                VerticalRelativeTo::Paragraph => VertRelTo::Para,
+            },
+            vert_align: match wrap.vertical_offset_type {
+                VerticalOffsetType::Top => VertAlign::Top,
+                VerticalOffsetType::Center => VertAlign::Center,
+                VerticalOffsetType::Bottom => VertAlign::Bottom,
+                VerticalOffsetType::Inside => VertAlign::Inside,
+                VerticalOffsetType::Outside => VertAlign::Outside,
+            },
+            horz_rel_to: match wrap.horizontal_rel {
+                HorizontalRelativeTo::Paper => HorzRelTo::Paper,
+                HorizontalRelativeTo::Page => HorzRelTo::Page,
+                HorizontalRelativeTo::Column => HorzRelTo::Column,
+                HorizontalRelativeTo::Paragraph => HorzRelTo::Para,
+            },
+            horz_align: match wrap.horizontal_offset_type {
+                HorizontalOffsetType::Left => HorzAlign::Left,
+                HorizontalOffsetType::Center => HorzAlign::Center,
+                HorizontalOffsetType::Right => HorzAlign::Right,
+                HorizontalOffsetType::Inside => HorzAlign::Inside,
+                HorizontalOffsetType::Outside => HorzAlign::Outside,
+            },
+            vert_offset: common.position.y.value(),
+            horz_offset: common.position.x.value(),
+            flow_with_text: wrap.flow_with_text,
+            allow_overlap: wrap.allow_overlap,
+            hold_anchor_and_shape: None,
+        }),
+        outside_margin: Some(Margin {
+            left: common.margin.left.value(),
+            right: common.margin.right.value(),
+            top: common.margin.top.value(),
+            bottom: common.margin.bottom.value(),
+        }),
+        caption: None,
+        comment: common.shape_comment.clone(),
+    }
+}
+
+fn build_margin(insets: &Insets) -> Margin {
+    Margin {
+        left: insets.left.value(),
+        right: insets.right.value(),
+        top: insets.top.value(),
+        bottom: insets.bottom.value(),
+    }
+}
+
+/// 표 빌드 (`TABLE`)
+///
+/// 다른 셀에 병합되어 보이지 않는 셀은 기록하지 않습니다.
+fn build_table(table: &IrTable, lost_contents: &mut usize, ctx: &FromIrContext) -> HwpmlTable {
+    HwpmlTable {
+        page_break: Some(match table.page_break {
+            TablePageBreak::None => HwpmlTablePageBreak::None,
+            TablePageBreak::Cell => HwpmlTablePageBreak::Cell,
+            TablePageBreak::Table => HwpmlTablePageBreak::Table,
+        }),
+        repeat_header: Some(table.repeat_header),
+        row_count: table.row_count,
+        col_count: table.column_count,
+        cell_spacing: Some(table.cell_spacing.value()),
+        border_fill: table.border_fill_id.map(|id| id.value()),
+        shape_object: Some(build_shape_object(&table.common, lost_contents)),
+        inside_margin: table.inside_margin.as_ref().map(build_margin),
+        cell_zone_list: None,
+        rows: table
+            .rows
+            .iter()
+            .map(|row| Row {
+                cells: row
+                    .cells
+                    .iter()
+                    .filter(|cell| !cell.is_merged)
+                    .map(|cell| build_cell(cell, lost_contents, ctx))
+                    .collect(),
+            })
+            .collect(),
+    }
+}
+
+/// 셀 빌드 (`CELL`)
+fn build_cell(cell: &TableCell, lost_contents: &mut usize, ctx: &FromIrContext) -> Cell {
+    let mut paragraphs: Vec<HwpmlParagraph> = cell
+        .paragraphs
+        .iter()
+        .map(|paragraph| build_paragraph(paragraph, lost_contents, ctx))
+        .collect();
+    if paragraphs.is_empty() {
+        paragraphs.push(empty_paragraph());
+    }
+
+    Cell {
+        name: cell.name.clone(),
+        col_addr: cell.column,
+        row_addr: cell.row,
+        col_span: Some(cell.column_span),
+        row_span: Some(cell.row_span),
+        width: Some(cell.width.value()),
+        height: Some(cell.height.value()),
+        header: Some(cell.is_header),
+        has_margin: Some(cell.padding != Insets::ZERO),
+        protect: Some(cell.protect),
+        editable: Some(cell.editable),
+        border_fill: cell.border_fill_id.map(|id| id.value()),
+        margin: Some(build_margin(&cell.padding)),
+        para_list: ParaList {
+            text_direction: None,
+            line_wrap: None,
+            vert_align: Some(match cell.vertical_alignment {
+                VerticalAlignment::Top | VerticalAlignment::Baseline => ParaListVertAlign::Top,
+                VerticalAlignment::Middle => ParaListVertAlign::Center,
+                VerticalAlignment::Bottom => ParaListVertAlign::Bottom,
+            }),
+            paragraphs,
+        },
+    }
+}
+
+/// 그림 빌드 (`PICTURE`)
+fn build_picture(
+    picture: &IrPicture,
+    lost_contents: &mut usize,
+    ctx: &FromIrContext,
+) -> HwpmlPicture {
+    HwpmlPicture {
+        reverse: None,
+        shape_object: Some(build_shape_object(&picture.common, lost_contents)),
+        shape_component: None,
+        line_shape: None,
+        image_rect: None,
+        image_clip: None,
+        inside_margin: Some(build_margin(&picture.inside_margin)),
+        image: Image {
+            bright: Some(picture.brightness as i32),
+            contrast: Some(picture.contrast as i32),
+            effect: Some(image_effect_name(picture.effect).to_string()),
+            bin_item: Some(ctx.binary_item_id(&picture.binary_id) as u32),
+            // IR은 불투명도, `Alpha`는 투명도 (0 = 불투명)
+            alpha: Some(((1.0 - picture.alpha.clamp(0.0, 1.0)) * 255.0).round() as u8),
+        },
+        effects: None,
+    }
+}
+
+/// 수식 빌드 (`EQUATION`)
+///
+/// HWP 수식 속성의 첫 비트를 줄 단위 배치(`LineMode`)로 기록합니다.
+fn build_equation(equation: &IrEquation, lost_contents: &mut usize) -> HwpmlEquation {
+    HwpmlEquation {
+        line_mode: equation.properties.map(|properties| properties & 1 != 0),
+        base_unit: Some(equation.font_size.value()),
+        text_color: equation.color.map(color_to_rgb),
+        base_line: Some(equation.baseline_offset.value()),
+        version: equation.version.clone(),
+        shape_object: Some(build_shape_object(&equation.common, lost_contents)),
+        script: equation.script.clone(),
+    }
+}
+
+/// 부가 정보 빌드 (`TAIL`)
+fn build_tail(ir: &IrDocument, bin_data: Vec<BinData>) -> Option<Tail> {
+    let script_code = ir
+        .extensions
+        .hwp
+        .as_ref()
+        .and_then(|hwp| hwp.scripts.as_ref())
+        .map(|scripts| {
+            let text = |value: &String| (!value.is_empty()).then(|| value.clone());
+            ScriptCode {
+                script_type: Some("JScript".to_string()),
+                version: Some(format!("{}.{}", scripts.version_high, scripts.version_low)),
+                header: text(&scripts.header),
+                source: text(&scripts.source),
+                pre_script: text(&scripts.pre_source),
+                post_script: text(&scripts.post_source),
+            }
+        });
+
+    if bin_data.is_empty() && script_code.is_none() {
+        return None;
+    }
+
+    Some(Tail {
+        bin_data_storage: (!bin_data.is_empty()).then_some(BinDataStorage { items: bin_data }),
+        script_code,
+        xml_template: None,
+    })
+}
+
+// 열거형 변환 헬퍼 함수들
+
+const fn convert_language(language: LangType) -> LanguageType {
+    match language {
+        LangType::Hangul => LanguageType::Korean,
+        LangType::Latin => LanguageType::English,
+        LangType::Hanja => LanguageType::Hanja,
+        LangType::Japanese => LanguageType::Japanese,
+        LangType::Other => LanguageType::Other,
+        LangType::Symbol => LanguageType::Symbol,
+        LangType::User => LanguageType::User,
+    }
+}
+
+const fn convert_font_type(font_type: FontType) -> HwpmlFontType {
+    match font_type {
+        FontType::Representative => HwpmlFontType::Representative,
+        FontType::TrueType => HwpmlFontType::TrueType,
+        FontType::HangeulOnly => HwpmlFontType::HangulOnly,
+    }
+}
+
+const fn convert_line_type(line_type: LineType) -> HwpmlLineType {
+    match line_type {
+        LineType::None => HwpmlLineType::None,
+        LineType::Solid | LineType::Wave => HwpmlLineType::Solid,
+        LineType::Dash => HwpmlLineType::Dash,
+        LineType::Dot => HwpmlLineType::Dot,
+        LineType::DashDot => HwpmlLineType::DashDot,
+        LineType::DashDotDot => HwpmlLineType::DashDotDot,
+        LineType::LongDash => HwpmlLineType::LongDash,
+        LineType::Circle => HwpmlLineType::Circle,
+        LineType::Double | LineType::DoubleWave => HwpmlLineType::DoubleSlim,
+        LineType::ThinThickLarge => HwpmlLineType::SlimThick,
+        LineType::ThickThinLarge => HwpmlLineType::ThickSlim,
+        LineType::Triple => HwpmlLineType::SlimThickSlim,
+    }
+}
+
+const fn convert_underline_type(line_type: UnderlineType) -> HwpmlLineType {
+    match line_type {
+        UnderlineType::None => HwpmlLineType::None,
+        UnderlineType::Single | UnderlineType::Wave => HwpmlLineType::Solid,
+        UnderlineType::Double => HwpmlLineType::DoubleSlim,
+        UnderlineType::Thick => HwpmlLineType::SlimThick,
+        UnderlineType::Dotted => HwpmlLineType::Dot,
+        UnderlineType::Dash => HwpmlLineType::Dash,
+        UnderlineType::DashDot => HwpmlLineType::DashDot,
+        UnderlineType::DashDotDot => HwpmlLineType::DashDotDot,
+    }
+}
+
+const fn convert_pattern_type(pattern_type: PatternType) -> Option<HatchStyle> {
+    Some(match pattern_type {
+        PatternType::None => return None,
+        PatternType::Horizontal => HatchStyle::Horizontal,
+        PatternType::Vertical => HatchStyle::Vertical,
+        PatternType::BackSlash => HatchStyle::BackSlash,
+        PatternType::Slash => HatchStyle::Slash,
+        PatternType::Cross => HatchStyle::Cross,
+        PatternType::CrossDiagonal => HatchStyle::CrossDiagonal,
+    })
+}
+
+/// 그림 효과 이름 (`IMAGE`의 `Effect`)
+const fn image_effect_name(effect: ImageEffect) -> &'static str {
+    match effect {
+        ImageEffect::Grayscale => "GrayScale",
+        ImageEffect::BlackWhite => "BlackWhite",
+        _ => "RealPic",
+    }
+}
+
+fn image_fill_mode_name(mode: ImageFillMode) -> &'static str {
+    IMAGE_FILL_MODES
+        .iter()
+        .find(|(_, value)| *value == mode)
+        .map_or("Tile", |(name, _)| name)
+}
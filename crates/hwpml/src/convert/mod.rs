@@ -0,0 +1,86 @@
+//! HWPML ↔ IR 변환 모듈
+//!
+//! HWPML 문서와 IR 간의 양방향 변환을 제공합니다.
+//! 스타일 정보(글꼴, 글자 모양, 문단 모양, 테두리/배경, 스타일), 구역 정의,
+//! 문단의 텍스트와 바이너리 데이터를 변환합니다.
+
+mod from_ir;
+mod to_ir;
+
+pub use from_ir::IrToHwpml;
+pub use to_ir::HwpmlToIr;
+
+use std::collections::HashMap;
+
+use ir::border_fill::ImageFillMode;
+use ir::{BinaryDataId, WarningCollector};
+
+/// HWPML → IR 변환 컨텍스트
+pub struct ToIrContext {
+    /// 경고 수집기
+    pub warnings: WarningCollector,
+}
+
+impl ToIrContext {
+    /// 새 컨텍스트 생성
+    pub const fn new() -> Self {
+        Self {
+            warnings: WarningCollector::new(),
+        }
+    }
+}
+
+impl Default for ToIrContext {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// IR → HWPML 변환 컨텍스트
+pub struct FromIrContext {
+    /// 경고 수집기
+    pub warnings: WarningCollector,
+    /// IR 바이너리 데이터 아이디 → `BINITEM` 번호 (1부터)
+    binary_ids: HashMap<BinaryDataId, u16>,
+}
+
+impl FromIrContext {
+    /// 새 컨텍스트 생성
+    pub fn new() -> Self {
+        Self {
+            warnings: WarningCollector::new(),
+            binary_ids: HashMap::new(),
+        }
+    }
+
+    /// IR 바이너리 데이터 아이디에 해당하는 `BINITEM` 번호 (없으면 0)
+    fn binary_item_id(&self, id: &BinaryDataId) -> u16 {
+        self.binary_ids.get(id).copied().unwrap_or(0)
+    }
+}
+
+impl Default for FromIrContext {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// 그림 채우기 방식 (`IMAGEBRUSH`의 `Mode`) 값과 IR 채우기 방식의 대응
+const IMAGE_FILL_MODES: [(&str, ImageFillMode); 16] = [
+    ("Tile", ImageFillMode::Tile),
+    ("TileHorzTop", ImageFillMode::TileHorizontalTop),
+    ("TileHorzBottom", ImageFillMode::TileHorizontalBottom),
+    ("TileVertLeft", ImageFillMode::TileVerticalLeft),
+    ("TileVertRight", ImageFillMode::TileVerticalRight),
+    ("Total", ImageFillMode::Stretch),
+    ("Center", ImageFillMode::Center),
+    ("CenterTop", ImageFillMode::CenterTop),
+    ("CenterBottom", ImageFillMode::CenterBottom),
+    ("LeftCenter", ImageFillMode::CenterLeft),
+    ("LeftTop", ImageFillMode::TopLeft),
+    ("LeftBottom", ImageFillMode::BottomLeft),
+    ("RightCenter", ImageFillMode::CenterRight),
+    ("RightTop", ImageFillMode::TopRight),
+    ("RightBottom", ImageFillMode::BottomRight),
+    ("Zoom", ImageFillMode::Original),
+];
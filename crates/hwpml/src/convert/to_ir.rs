@@ -0,0 +1,1126 @@
+//! HWPML → IR 변환
+//!
+//! HWPML 문서를 IR로 변환합니다.
+//! 매핑 테이블의 아이디는 HWP와 같이 그대로 IR 아이디로 사용하고,
+//! 글꼴은 언어별 목록을 하나로 이어 붙인 뒤 각 글꼴에 언어를 기록합니다.
+//! 표, 그림, 수식은 IR 컨트롤로 옮기고, 도형이나 필드 같은 나머지 컨트롤은 모델에 없으므로
+//! 개수만 세어 경고로 남깁니다.
+
+use super::{IMAGE_FILL_MODES, ToIrContext};
+use crate::Document as HwpmlDocument;
+use crate::body::{
+    CharItem, ColumnDef, ColumnLayout, GutterType, PageDef, PageStartsOn as HwpmlPageStartsOn,
+    Paragraph as HwpmlParagraph, Section as HwpmlSection, SectionDef, StartNumber, TextItem,
+};
+use crate::control::{
+    Cell, Equation as HwpmlEquation, HeightRelTo, HorzAlign, HorzRelTo, NumberingType,
+    ParaListVertAlign, Picture as HwpmlPicture, ShapeObject, Table as HwpmlTable,
+    TablePageBreak as HwpmlTablePageBreak, TextFlow, TextWrap as HwpmlTextWrap, VertAlign,
+    VertRelTo, WidthRelTo,
+};
+use crate::head::Head;
+use crate::mapping_table::{
+    BinItemType, BorderFill as HwpmlBorderFill, BorderLine, CharShape as HwpmlCharShape, FillBrush,
+    Font as HwpmlFont, GradationType, LangValues, LineSpacingType as HwpmlLineSpacingType,
+    MappingTable, ParaShape as HwpmlParaShape, ShadowType as HwpmlShadowType, StrikeoutType,
+    Style as HwpmlStyle, StyleType as HwpmlStyleType, UnderlineType as HwpmlUnderlineType,
+};
+use crate::types::{
+    Alignment as HwpmlAlignment, FontType as HwpmlFontType, HatchStyle, LangType,
+    LineType as HwpmlLineType, NO_COLOR, color_from_rgb, parse_line_width,
+};
+use ir::{
+    BinaryData, BinaryDataId, BinaryDataStore, BinaryFormat, CaretPosition, ConversionError,
+    ConversionResult, Document as IrDocument, DocumentSettings, DocumentVersion, Extensions,
+    HwpExtensions, HwpScripts, Metadata, Paragraph as IrParagraph, Run as IrRun,
+    RunContent as IrRunContent, Section as IrSection,
+    border_fill::{
+        Border, BorderFill, Fill, GradientFill, GradientStop, GradientType, ImageFill,
+        ImageFillMode, PatternFill, PatternType, SolidFill,
+    },
+    char_shape::{
+        CharShape, EmphasisStyle, Font, FontFamily, FontRef, FontSet, FontType, ShadowStyle,
+        SubstituteFont, UnderlineStyle,
+    },
+    control::{Control, Equation as IrEquation, ObjectCommon},
+    para_shape::{LineSpacing, LineSpacingType, LineSpacingValue, ParaShape},
+    paragraph::{TabChar, Text as IrText},
+    picture::Picture as IrPicture,
+    section::ColumnDirection,
+    style::{Style, StyleStore},
+    table::{Table as IrTable, TableCell, TablePageBreak, TableRow},
+};
+use primitive::{
+    Alignment, BorderFillId, BreakType, CharShapeId, EmphasisType, EquationFormat, FontId,
+    GutterPosition, HeightRelativeTo, HorizontalOffsetType, HorizontalRelativeTo, HwpUnit,
+    ImageEffect, Insets, LanguageType, LineType, ObjectMargin, ObjectNumberingType, OutlineType,
+    PageOrientation, PageStartsOn, ParaShapeId, Percent, Point, ShadowType, Size,
+    StrikethroughType, StyleId, StyleType, TabDefId, TextWrapSide, TextWrapType, UnderlinePosition,
+    UnderlineType, VerticalAlignment, VerticalOffsetType, VerticalRelativeTo, WidthRelativeTo,
+};
+
+/// HWPML → IR 변환 트레이트
+pub trait HwpmlToIr {
+    /// IR 문서로 변환
+    fn to_ir(&self) -> Result<ConversionResult<IrDocument>, ConversionError>;
+}
+
+impl HwpmlToIr for HwpmlDocument {
+    fn to_ir(&self) -> Result<ConversionResult<IrDocument>, ConversionError> {
+        let mut ctx = ToIrContext::new();
+        let doc = convert_document(self, &mut ctx)?;
+        Ok(ctx.warnings.into_result(doc))
+    }
+}
+
+/// 문서 변환
+fn convert_document(
+    hml: &HwpmlDocument,
+    ctx: &mut ToIrContext,
+) -> Result<IrDocument, ConversionError> {
+    let mut doc = IrDocument::new();
+
+    // 메타데이터 변환
+    doc.metadata = convert_metadata(hml);
+
+    // 문서 설정 변환
+    convert_settings(&hml.head, &mut doc.settings);
+
+    // 스타일 저장소 변환
+    if let Some(table) = &hml.head.mapping_table {
+        doc.styles = convert_styles(table);
+    }
+
+    // 구역 변환
+    let mut unknown_controls = 0;
+    for section in &hml.body.sections {
+        doc.sections
+            .push(convert_section(section, &mut unknown_controls));
+    }
+    if unknown_controls > 0 {
+        ctx.warnings.data_loss(format!(
+            "지원하지 않는 HWPML 컨트롤 {}개는 변환되지 않습니다",
+            unknown_controls
+        ));
+    }
+
+    // 바이너리 데이터 변환
+    doc.binary_data = convert_binary_data(hml, ctx);
+
+    // 확장 데이터 변환
+    doc.extensions = convert_extensions(hml);
+
+    Ok(doc)
+}
+
+/// 메타데이터 변환 (`DOCSUMMARY`)
+fn convert_metadata(hml: &HwpmlDocument) -> Metadata {
+    let mut metadata = Metadata::new();
+
+    if let Some(summary) = &hml.head.summary {
+        metadata.title = summary.title.clone();
+        metadata.subject = summary.subject.clone();
+        metadata.author = summary.author.clone();
+        metadata.created = summary.date.clone();
+        metadata.description = summary.comments.clone();
+        if let Some(keywords) = &summary.keywords {
+            metadata.keywords = keywords
+                .split(',')
+                .map(|s| s.trim().to_owned())
+                .filter(|s| !s.is_empty())
+                .collect();
+        }
+    }
+
+    // 하위 버전 (`SubVersion`, 예: 8.0.0.0)은 문서를 저장한 한글 버전
+    metadata.version = hml.sub_version.as_deref().and_then(parse_version);
+
+    metadata
+}
+
+/// `major.minor.patch.build` 형식의 버전 문자열 파싱
+fn parse_version(version: &str) -> Option<DocumentVersion> {
+    let mut parts = version.split('.').map(|part| part.trim().parse::<u32>());
+    let mut next = || parts.next().unwrap_or(Ok(0)).ok();
+    Some(DocumentVersion::new(next()?, next()?, next()?, next()?))
+}
+
+/// 문서 설정 변환 (`DOCSETTING`)
+fn convert_settings(head: &Head, settings: &mut DocumentSettings) {
+    let Some(setting) = &head.setting else {
+        return;
+    };
+
+    if let Some(begin) = &setting.begin_number {
+        settings.starting_page_number = begin.page.unwrap_or(1);
+        settings.starting_footnote_number = begin.footnote.unwrap_or(1);
+        settings.starting_endnote_number = begin.endnote.unwrap_or(1);
+    }
+
+    if let Some(caret) = &setting.caret_position {
+        settings.caret_position = Some(CaretPosition {
+            section: caret.list.unwrap_or(0),
+            paragraph: caret.paragraph.unwrap_or(0),
+            position: caret.position.unwrap_or(0),
+        });
+    }
+}
+
+/// 스타일 저장소 변환 (`MAPPINGTABLE`)
+fn convert_styles(table: &MappingTable) -> StyleStore {
+    let mut store = StyleStore::new();
+
+    // 글꼴 변환 (언어 순서대로 이어 붙임)
+    if let Some(list) = &table.face_name_list {
+        for language in LangType::ALL {
+            let faces = list
+                .font_faces
+                .iter()
+                .filter(|face| face.language == language);
+            for font in faces.flat_map(|face| &face.fonts) {
+                let mut ir_font = convert_font(font);
+                ir_font.language = Some(convert_language(language));
+                store.fonts.push(ir_font);
+            }
+        }
+    }
+
+    // 테두리/배경 변환
+    if let Some(list) = &table.border_fill_list {
+        for border_fill in &list.border_fills {
+            store.border_fills.push(convert_border_fill(border_fill));
+        }
+    }
+
+    // 글자 모양 변환
+    if let Some(list) = &table.char_shape_list {
+        for shape in &list.char_shapes {
+            store.char_shapes.push(convert_char_shape(shape));
+        }
+    }
+
+    // 문단 모양 변환
+    if let Some(list) = &table.para_shape_list {
+        for shape in &list.para_shapes {
+            store.para_shapes.push(convert_para_shape(shape));
+        }
+    }
+
+    // 스타일 변환
+    if let Some(list) = &table.style_list {
+        for style in &list.styles {
+            store.styles.push(convert_style(style));
+        }
+    }
+
+    store
+}
+
+/// 글꼴 변환 (`FONT`)
+fn convert_font(font: &HwpmlFont) -> Font {
+    let mut ir_font = Font::new(&font.name);
+    ir_font.font_type = convert_font_type(font.font_type);
+
+    if let Some(substitute) = &font.substitute {
+        ir_font.alternate_name = Some(substitute.name.clone());
+        ir_font.substitute_font = Some(SubstituteFont {
+            face: substitute.name.clone(),
+            font_type: convert_font_type(substitute.font_type),
+            is_embedded: false,
+            binary_item_id_ref: None,
+        });
+    }
+
+    if let Some(info) = &font.type_info {
+        ir_font.family = match info.family_type {
+            1 => FontFamily::Serif,
+            2 => FontFamily::SansSerif,
+            3 => FontFamily::Monospace,
+            4 => FontFamily::Decorative,
+            5 => FontFamily::Script,
+            _ => FontFamily::Unknown,
+        };
+        ir_font.panose = Some(primitive::Panose::from_bytes([
+            info.family_type,
+            info.serif_style,
+            info.weight,
+            info.proportion,
+            info.contrast,
+            info.stroke_variation,
+            info.arm_style,
+            info.letterform,
+            info.midline,
+            info.x_height,
+        ]));
+    }
+
+    ir_font
+}
+
+/// 테두리/배경 변환 (`BORDERFILL`)
+fn convert_border_fill(border_fill: &HwpmlBorderFill) -> BorderFill {
+    let diagonal = border_fill.diagonal.as_ref().map(convert_border_line);
+
+    BorderFill {
+        left: border_fill
+            .left
+            .as_ref()
+            .map_or_else(Border::none, convert_border_line),
+        right: border_fill
+            .right
+            .as_ref()
+            .map_or_else(Border::none, convert_border_line),
+        top: border_fill
+            .top
+            .as_ref()
+            .map_or_else(Border::none, convert_border_line),
+        bottom: border_fill
+            .bottom
+            .as_ref()
+            .map_or_else(Border::none, convert_border_line),
+        diagonal_down: diagonal
+            .clone()
+            .filter(|_| border_fill.back_slash.unwrap_or(0) != 0),
+        diagonal_up: diagonal.filter(|_| border_fill.slash.unwrap_or(0) != 0),
+        fill: border_fill
+            .fill_brush
+            .as_ref()
+            .map_or(Fill::None, convert_fill_brush),
+        is_3d: border_fill.three_d.unwrap_or(false),
+        has_shadow: border_fill.shadow.unwrap_or(false),
+    }
+}
+
+/// 테두리 선 변환
+fn convert_border_line(line: &BorderLine) -> Border {
+    Border {
+        line_type: convert_line_type(line.line_type),
+        width: line
+            .width
+            .as_deref()
+            .and_then(parse_line_width)
+            .unwrap_or_else(|| HwpUnit::from_mm(0.12)),
+        color: color_from_rgb(line.color),
+    }
+}
+
+/// 채우기 변환 (`FILLBRUSH`)
+///
+/// 면 채우기, 그러데이션, 그림 중 하나만 표현할 수 있어 그림, 그러데이션, 면 순서로 택합니다.
+fn convert_fill_brush(brush: &FillBrush) -> Fill {
+    let image = brush
+        .image_brush
+        .as_ref()
+        .and_then(|b| Some((b.mode.as_deref(), b.image.as_ref()?)));
+    if let Some((mode, image)) = image
+        && let Some(bin_item) = image.bin_item
+    {
+        return Fill::Image(ImageFill {
+            binary_id: BinaryDataId::from_numeric(bin_item as u16),
+            mode: mode.map_or(ImageFillMode::Tile, convert_image_fill_mode),
+            brightness: image.bright.unwrap_or(0).clamp(-100, 100) as i8,
+            contrast: image.contrast.unwrap_or(0).clamp(-100, 100) as i8,
+            effect: convert_image_effect(image.effect.as_deref()),
+            offset_x: HwpUnit::ZERO,
+            offset_y: HwpUnit::ZERO,
+            size: None,
+        });
+    }
+
+    if let Some(gradation) = &brush.gradation {
+        let count = gradation.colors.len();
+        let stops = gradation
+            .colors
+            .iter()
+            .enumerate()
+            .map(|(index, color)| GradientStop {
+                position: if count > 1 {
+                    (index * 100 / (count - 1)) as u8
+                } else {
+                    0
+                },
+                color: color_from_rgb(color.value),
+            })
+            .collect();
+        return Fill::Gradient(GradientFill {
+            gradient_type: match gradation.gradation_type {
+                GradationType::Linear => GradientType::Linear,
+                GradationType::Radial => GradientType::Radial,
+                GradationType::Conical => GradientType::Conical,
+                GradationType::Square => GradientType::Square,
+            },
+            angle: gradation.angle.unwrap_or(0).rem_euclid(360) as u16,
+            center_x: gradation.center_x.unwrap_or(0).clamp(0, 100) as u8,
+            center_y: gradation.center_y.unwrap_or(0).clamp(0, 100) as u8,
+            stops,
+            blur: gradation.step.unwrap_or(50).min(255) as u8,
+            step_center: gradation.step_center.unwrap_or(50).min(100) as u8,
+        });
+    }
+
+    if let Some(window) = &brush.window_brush {
+        if let Some(style) = window.hatch_style {
+            return Fill::Pattern(PatternFill {
+                pattern_type: convert_hatch_style(style),
+                foreground: color_from_rgb(window.hatch_color),
+                background: color_from_rgb(window.face_color),
+            });
+        }
+        if window.face_color != NO_COLOR {
+            return Fill::Solid(SolidFill {
+                color: color_from_rgb(window.face_color),
+                alpha: 255 - window.alpha.unwrap_or(0),
+            });
+        }
+    }
+
+    Fill::None
+}
+
+/// 글자 모양 변환 (`CHARSHAPE`)
+fn convert_char_shape(shape: &HwpmlCharShape) -> CharShape {
+    let mut ir_shape = CharShape::new();
+
+    // 글자 크기
+    ir_shape.font_size = HwpUnit::new(shape.height.unwrap_or(1000));
+
+    // 글자 색
+    ir_shape.color = color_from_rgb(shape.text_color);
+
+    // 언어별 글꼴
+    let font_id = shape.font_id.unwrap_or_default();
+    let ratio = shape.ratio.unwrap_or(LangValues::all(100));
+    let spacing = shape.char_spacing.unwrap_or_default();
+    let relative_size = shape.relative_size.unwrap_or(LangValues::all(100));
+    let offset = shape.char_offset.unwrap_or_default();
+    let mut fonts = FontSet::default();
+    for language in LangType::ALL {
+        fonts.set(
+            convert_language(language),
+            FontRef {
+                id: FontId::new(font_id.get(language).max(0) as u32),
+                width_ratio: Percent::new(ratio.get(language) as f64),
+                spacing: Percent::new(spacing.get(language) as f64),
+                offset: Percent::new(offset.get(language) as f64),
+                relative_size: Percent::new(relative_size.get(language) as f64),
+            },
+        );
+    }
+    ir_shape.fonts = fonts;
+
+    // 장평/자간 (한글 값 사용)
+    ir_shape.char_scale = Percent::new(ratio.hangul as f64);
+    ir_shape.char_spacing = Percent::new(spacing.hangul as f64);
+
+    // 글자 속성
+    ir_shape.bold = shape.bold.is_some();
+    ir_shape.italic = shape.italic.is_some();
+    ir_shape.superscript = shape.superscript.is_some();
+    ir_shape.subscript = shape.subscript.is_some();
+    ir_shape.emboss = shape.emboss.is_some();
+    ir_shape.engrave = shape.engrave.is_some();
+
+    // 밑줄
+    if let Some(underline) = &shape.underline {
+        ir_shape.underline = UnderlineStyle {
+            line_type: convert_underline_type(underline.shape),
+            position: match underline.underline_type {
+                HwpmlUnderlineType::Top => UnderlinePosition::Top,
+                HwpmlUnderlineType::Bottom | HwpmlUnderlineType::Center => {
+                    UnderlinePosition::Bottom
+                }
+            },
+            color: Some(color_from_rgb(underline.color)),
+        };
+    }
+
+    // 취소선
+    if let Some(strikeout) = &shape.strikeout
+        && strikeout.strikeout_type == StrikeoutType::Continuous
+    {
+        ir_shape.strikethrough = match strikeout.shape {
+            HwpmlLineType::None => StrikethroughType::None,
+            HwpmlLineType::DoubleSlim => StrikethroughType::Double,
+            _ => StrikethroughType::Single,
+        };
+    }
+
+    // 외곽선
+    if shape.outline.is_some() {
+        ir_shape.outline = OutlineType::Outline;
+    }
+
+    // 그림자
+    if let Some(shadow) = &shape.shadow {
+        ir_shape.shadow = ShadowStyle {
+            shadow_type: match shadow.shadow_type {
+                HwpmlShadowType::Drop => ShadowType::BottomRightDiscrete,
+                HwpmlShadowType::Cont => ShadowType::BottomRightContinuous,
+            },
+            color: Some(color_from_rgb(shadow.color)),
+            offset_x: HwpUnit::new(shadow.offset_x.unwrap_or(10)),
+            offset_y: HwpUnit::new(shadow.offset_y.unwrap_or(10)),
+        };
+    }
+
+    // 강조점
+    ir_shape.emphasis = EmphasisStyle {
+        emphasis_type: match shape.sym_mark {
+            1 => EmphasisType::Circle,
+            2 => EmphasisType::CircleOpen,
+            3 => EmphasisType::Caron,
+            4 => EmphasisType::Tilde,
+            5 => EmphasisType::Dot,
+            6 => EmphasisType::Colon,
+            _ => EmphasisType::None,
+        },
+        color: None,
+    };
+
+    // 음영 색 (`ShadeColor`가 없음이 아닌 경우)
+    if let Some(shade) = shape.shade_color.filter(|&color| color != NO_COLOR) {
+        ir_shape.shade_color = Some(color_from_rgb(shade));
+        if shade != 0x00FF_FFFF {
+            ir_shape.background_color = Some(color_from_rgb(shade));
+        }
+    }
+
+    ir_shape.use_kerning = shape.use_kerning;
+    ir_shape.border_fill_id_ref = shape.border_fill_id.map(BorderFillId::new);
+
+    ir_shape
+}
+
+/// 문단 모양 변환 (`PARASHAPE`)
+fn convert_para_shape(shape: &HwpmlParaShape) -> ParaShape {
+    let mut ir_shape = ParaShape::new();
+
+    ir_shape.alignment = match shape.align {
+        HwpmlAlignment::Justify => Alignment::Justify,
+        HwpmlAlignment::Left => Alignment::Left,
+        HwpmlAlignment::Right => Alignment::Right,
+        HwpmlAlignment::Center => Alignment::Center,
+        HwpmlAlignment::Distribute => Alignment::Distribute,
+        HwpmlAlignment::DistributeSpace => Alignment::Divide,
+    };
+
+    // 여백과 줄 간격
+    let margin = shape.margin.unwrap_or_default();
+    ir_shape.first_line_indent = HwpUnit::new(margin.indent);
+    ir_shape.margin_left = HwpUnit::new(margin.left);
+    ir_shape.margin_right = HwpUnit::new(margin.right);
+    ir_shape.space_before = HwpUnit::new(margin.prev);
+    ir_shape.space_after = HwpUnit::new(margin.next);
+    ir_shape.line_spacing = match margin.line_spacing_type {
+        HwpmlLineSpacingType::Percent => LineSpacing {
+            spacing_type: LineSpacingType::Percent,
+            value: LineSpacingValue::Percent(Percent::new(
+                margin.line_spacing.unwrap_or(160) as f64
+            )),
+        },
+        HwpmlLineSpacingType::Fixed => LineSpacing {
+            spacing_type: LineSpacingType::Fixed,
+            value: LineSpacingValue::Fixed(HwpUnit::new(margin.line_spacing.unwrap_or(0))),
+        },
+        HwpmlLineSpacingType::BetweenLines => LineSpacing {
+            spacing_type: LineSpacingType::FontBased,
+            value: LineSpacingValue::Fixed(HwpUnit::new(margin.line_spacing.unwrap_or(0))),
+        },
+        HwpmlLineSpacingType::AtLeast => LineSpacing {
+            spacing_type: LineSpacingType::AtLeast,
+            value: LineSpacingValue::Fixed(HwpUnit::new(margin.line_spacing.unwrap_or(0))),
+        },
+    };
+
+    ir_shape.tab_def_id = shape.tab_def.map(TabDefId::new);
+    ir_shape.border_fill_id = shape
+        .border
+        .and_then(|border| border.border_fill)
+        .map(BorderFillId::new);
+
+    ir_shape.widow_orphan_control = shape.widow_orphan;
+    ir_shape.keep_with_next = shape.keep_with_next;
+    ir_shape.keep_lines = shape.keep_lines;
+    ir_shape.page_break_before = shape.page_break_before;
+    ir_shape.snap_to_grid = shape.snap_to_grid;
+    ir_shape.auto_spacing_east_asian_english = shape.auto_space_east_asian_english;
+    ir_shape.auto_spacing_east_asian_number = shape.auto_space_east_asian_number;
+
+    ir_shape
+}
+
+/// 스타일 변환 (`STYLE`)
+fn convert_style(style: &HwpmlStyle) -> Style {
+    Style {
+        name: style.name.clone(),
+        english_name: style.english_name.clone(),
+        style_type: match style.style_type {
+            HwpmlStyleType::Para => StyleType::Paragraph,
+            HwpmlStyleType::Char => StyleType::Character,
+        },
+        para_shape_id: style.para_shape.map(ParaShapeId::new),
+        char_shape_id: style.char_shape.map(CharShapeId::new),
+        next_style_id: style.next_style.map(StyleId::new),
+    }
+}
+
+/// 구역 변환 (`SECTION`)
+///
+/// 구역 정의(`SECDEF`)와 단 정의(`COLDEF`)는 구역 속성으로 옮기고 문단에서는 뺍니다.
+fn convert_section(section: &HwpmlSection, unknown_controls: &mut usize) -> IrSection {
+    let mut ir_section = IrSection::new();
+
+    let items = section
+        .paragraphs
+        .iter()
+        .flat_map(|paragraph| &paragraph.texts)
+        .flat_map(|text| &text.items);
+    let mut section_def = None;
+    let mut column_def = None;
+    for item in items {
+        match item {
+            TextItem::SectionDef(def) if section_def.is_none() => section_def = Some(def),
+            TextItem::ColumnDef(def) if column_def.is_none() => column_def = Some(def),
+            _ => {}
+        }
+    }
+
+    if let Some(def) = section_def {
+        convert_section_def(def, &mut ir_section);
+    }
+    if let Some(def) = column_def {
+        convert_column_def(def, &mut ir_section);
+    }
+
+    for paragraph in &section.paragraphs {
+        ir_section
+            .paragraphs
+            .push(convert_paragraph(paragraph, unknown_controls));
+    }
+
+    ir_section
+}
+
+/// 구역 정의 변환 (`SECDEF`)
+fn convert_section_def(def: &SectionDef, section: &mut IrSection) {
+    if let Some(page_def) = &def.page_def {
+        convert_page_def(page_def, section);
+    }
+    if let Some(start) = &def.start_number {
+        convert_start_number(start, section);
+    }
+}
+
+/// 용지 설정 변환 (`PAGEDEF`)
+fn convert_page_def(page_def: &PageDef, section: &mut IrSection) {
+    let page = &mut section.page;
+    page.width = HwpUnit::new(page_def.width);
+    page.height = HwpUnit::new(page_def.height);
+    page.orientation = if page_def.landscape == 1 {
+        PageOrientation::Landscape
+    } else {
+        PageOrientation::Portrait
+    };
+    page.gutter_position = match page_def.gutter_type {
+        GutterType::LeftOnly | GutterType::LeftRight => GutterPosition::Left,
+        GutterType::TopBottom => GutterPosition::Top,
+    };
+    if let Some(margin) = &page_def.margin {
+        page.margins.left = HwpUnit::new(margin.left);
+        page.margins.right = HwpUnit::new(margin.right);
+        page.margins.top = HwpUnit::new(margin.top);
+        page.margins.bottom = HwpUnit::new(margin.bottom);
+        page.margins.header = HwpUnit::new(margin.header);
+        page.margins.footer = HwpUnit::new(margin.footer);
+        page.margins.gutter = HwpUnit::new(margin.gutter);
+    }
+}
+
+/// 시작 번호 변환 (`STARTNUMBER`)
+fn convert_start_number(start: &StartNumber, section: &mut IrSection) {
+    let number = &mut section.start_number;
+    number.page_starts_on = match start.page_starts_on {
+        HwpmlPageStartsOn::Both => PageStartsOn::Both,
+        HwpmlPageStartsOn::Even => PageStartsOn::Even,
+        HwpmlPageStartsOn::Odd => PageStartsOn::Odd,
+    };
+    number.page = start.page;
+    number.picture = start.figure;
+    number.table = start.table;
+    number.equation = start.equation;
+}
+
+/// 단 정의 변환 (`COLDEF`)
+fn convert_column_def(def: &ColumnDef, section: &mut IrSection) {
+    let columns = &mut section.columns;
+    columns.count = def.count.max(1);
+    columns.gap = HwpUnit::new(def.same_gap);
+    columns.direction = match def.layout {
+        ColumnLayout::Left => ColumnDirection::LeftToRight,
+        ColumnLayout::Right => ColumnDirection::RightToLeft,
+        ColumnLayout::Mirror => ColumnDirection::FacingPages,
+    };
+}
+
+/// 문단 변환 (`P`)
+fn convert_paragraph(paragraph: &HwpmlParagraph, unknown_controls: &mut usize) -> IrParagraph {
+    let mut ir_paragraph = IrParagraph::new();
+    ir_paragraph.para_shape_id = paragraph.para_shape.map(ParaShapeId::new);
+    ir_paragraph.style_id = paragraph.style.map(StyleId::new);
+    ir_paragraph.instance_id = paragraph.instance_id;
+    ir_paragraph.break_type = if paragraph.page_break {
+        BreakType::Page
+    } else if paragraph.column_break {
+        BreakType::Column
+    } else {
+        BreakType::None
+    };
+
+    for text in &paragraph.texts {
+        let mut run = IrRun::new();
+        run.char_shape_id = text.char_shape.map(CharShapeId::new);
+
+        for item in &text.items {
+            match item {
+                TextItem::Char(ch) => {
+                    run.contents
+                        .extend(ch.items.iter().filter_map(convert_char_item));
+                }
+                TextItem::Table(table) => {
+                    let table = convert_table(table, unknown_controls);
+                    run.contents.push(control(Control::Table(Box::new(table))));
+                }
+                TextItem::Picture(picture) => {
+                    let picture = convert_picture(picture);
+                    run.contents
+                        .push(control(Control::Picture(Box::new(picture))));
+                }
+                TextItem::Equation(equation) => {
+                    let equation = convert_equation(equation);
+                    run.contents
+                        .push(control(Control::Equation(Box::new(equation))));
+                }
+                TextItem::Unknown(_) => *unknown_controls += 1,
+                TextItem::SectionDef(_) | TextItem::ColumnDef(_) => {}
+            }
+        }
+
+        if !run.contents.is_empty() {
+            ir_paragraph.runs.push(run);
+        }
+    }
+
+    ir_paragraph
+}
+
+/// 글자 내용 변환 (`CHAR`의 자식)
+///
+/// 제목 차례 표시와 형광펜은 IR에 대응하는 내용이 없어 건너뜁니다.
+fn convert_char_item(item: &CharItem) -> Option<IrRunContent> {
+    Some(match item {
+        CharItem::Text(text) => IrRunContent::Text(IrText::new(text)),
+        CharItem::Tab => IrRunContent::Tab(TabChar::default()),
+        CharItem::LineBreak => IrRunContent::LineBreak,
+        CharItem::Hyphen => IrRunContent::Hyphen,
+        CharItem::NonBreakingSpace => IrRunContent::NonBreakingSpace,
+        CharItem::FixedWidthSpace => IrRunContent::FixedWidthSpace,
+        CharItem::TitleMark(_) | CharItem::MarkPenBegin(_) | CharItem::MarkPenEnd => {
+            return None;
+        }
+    })
+}
+
+fn control(control: Control) -> IrRunContent {
+    IrRunContent::Control(Box::new(control))
+}
+
+/// 개체 속성 변환 (`SHAPEOBJECT`)
+fn convert_shape_object(shape: Option<&ShapeObject>) -> ObjectCommon {
+    let mut common = ObjectCommon::default();
+    let Some(shape) = shape else {
+        return common;
+    };
+
+    common.id = shape.instance_id;
+    common.z_order = shape.z_order.unwrap_or(0);
+    common.numbering_type = shape.numbering_type.map(|numbering| match numbering {
+        NumberingType::None => ObjectNumberingType::None,
+        NumberingType::Figure => ObjectNumberingType::Picture,
+        NumberingType::Table => ObjectNumberingType::Table,
+        NumberingType::Equation => ObjectNumberingType::Equation,
+    });
+    common.shape_comment = shape.comment.clone();
+
+    if let Some(size) = &shape.size {
+        common.size = Size::new(HwpUnit::new(size.width), HwpUnit::new(size.height));
+        common.width_relative_to = match size.width_rel_to.unwrap_or_default() {
+            WidthRelTo::Paper => WidthRelativeTo::Paper,
+            WidthRelTo::Page => WidthRelativeTo::Page,
+            WidthRelTo::Column => WidthRelativeTo::Column,
+            WidthRelTo::Para => WidthRelativeTo::Paragraph,
+            WidthRelTo::Absolute => WidthRelativeTo::Absolute,
+        };
+        common.height_relative_to = match size.height_rel_to.unwrap_or_default() {
+            HeightRelTo::Paper => HeightRelativeTo::Paper,
+            HeightRelTo::Page => HeightRelativeTo::Page,
+            HeightRelTo::Absolute => HeightRelativeTo::Absolute,
+        };
+    }
+
+    if let Some(margin) = &shape.outside_margin {
+        common.margin = ObjectMargin {
+            left: HwpUnit::new(margin.left),
+            right: HwpUnit::new(margin.right),
+            top: HwpUnit::new(margin.top),
+            bottom: HwpUnit::new(margin.bottom),
+        };
+    }
+
+    let wrap = &mut common.text_wrap;
+    wrap.wrap_type = match shape.text_wrap.unwrap_or_default() {
+        HwpmlTextWrap::Square | HwpmlTextWrap::TopAndBottom => TextWrapType::Square,
+        HwpmlTextWrap::Tight | HwpmlTextWrap::Through => TextWrapType::Tight,
+        HwpmlTextWrap::BehindText => TextWrapType::Behind,
+        HwpmlTextWrap::InFrontOfText => TextWrapType::InFront,
+    };
+    wrap.wrap_side = match shape.text_flow.unwrap_or_default() {
+        TextFlow::BothSides => TextWrapSide::Both,
+        TextFlow::LeftOnly => TextWrapSide::Left,
+        TextFlow::RightOnly => TextWrapSide::Right,
+        TextFlow::LargestOnly => TextWrapSide::Largest,
+    };
+    if let Some(position) = &shape.position {
+        common.position = Point::new(
+            HwpUnit::new(position.horz_offset),
+            HwpUnit::new(position.vert_offset),
+        );
+        // 글자처럼 취급하면 배치 방식과 상관없이 글자 사이에 놓임
+        if position.treat_as_char {
+            wrap.wrap_type = TextWrapType::Inline;
+        }
+        wrap.treat_as_char = position.treat_as_char;
+        wrap.vertical_rel = match position.vert_rel_to {
+            VertRelTo::Paper => VerticalRelativeTo::Paper,
+            VertRelTo::Page => VerticalRelativeTo::Page,
+            VertRelTo::Para => VerticalRelativeTo::Paragraph,
+        };
+        wrap.horizontal_rel = match position.horz_rel_to {
+            HorzRelTo::Paper => HorizontalRelativeTo::Paper,
+            HorzRelTo::Page => HorizontalRelativeTo::Page,
+            HorzRelTo::Column => HorizontalRelativeTo::Column,
+            HorzRelTo::Para => HorizontalRelativeTo::Paragraph,
+        };
+        wrap.vertical_offset_type = match position.vert_align {
+            VertAlign::Top => VerticalOffsetType::Top,
+            VertAlign::Center => VerticalOffsetType::Center,
+            VertAlign::Bottom => VerticalOffsetType::Bottom,
+            VertAlign::Inside => VerticalOffsetType::Inside,
+            VertAlign::Outside => VerticalOffsetType::Outside,
+        };
+        wrap.horizontal_offset_type = match position.horz_align {
+            HorzAlign::Left => HorizontalOffsetType::Left,
+            HorzAlign::Center => HorizontalOffsetType::Center,
+            HorzAlign::Right => HorizontalOffsetType::Right,
+            HorzAlign::Inside => HorizontalOffsetType::Inside,
+            HorzAlign::Outside => HorizontalOffsetType::Outside,
+        };
+        wrap.flow_with_text = position.flow_with_text;
+        wrap.allow_overlap = position.allow_overlap;
+    }
+
+    common
+}
+
+/// 표 변환 (`TABLE`)
+fn convert_table(table: &HwpmlTable, unknown_controls: &mut usize) -> IrTable {
+    let mut ir_table = IrTable::new(table.row_count, table.col_count);
+    ir_table.common = convert_shape_object(table.shape_object.as_ref());
+    ir_table.cell_spacing = HwpUnit::new(table.cell_spacing.unwrap_or(0));
+    ir_table.border_fill_id = table.border_fill.map(BorderFillId::new);
+    ir_table.page_break = match table.page_break.unwrap_or_default() {
+        HwpmlTablePageBreak::None => TablePageBreak::None,
+        HwpmlTablePageBreak::Cell => TablePageBreak::Cell,
+        HwpmlTablePageBreak::Table => TablePageBreak::Table,
+    };
+    ir_table.repeat_header = table.repeat_header.unwrap_or(false);
+    ir_table.inside_margin = table.inside_margin.map(|margin| Insets {
+        left: HwpUnit::new(margin.left),
+        right: HwpUnit::new(margin.right),
+        top: HwpUnit::new(margin.top),
+        bottom: HwpUnit::new(margin.bottom),
+    });
+
+    for row in &table.rows {
+        let mut ir_row = TableRow::new();
+        for cell in &row.cells {
+            let ir_cell = convert_cell(cell, unknown_controls);
+            // 행 높이는 행을 병합하지 않은 셀 중 가장 높은 셀
+            if ir_cell.row_span == 1 && ir_cell.height.value() > ir_row.height.value() {
+                ir_row.height = ir_cell.height;
+            }
+            ir_row.cells.push(ir_cell);
+        }
+        ir_table.rows.push(ir_row);
+    }
+    ir_table.header_row_count = table
+        .rows
+        .iter()
+        .take_while(|row| row.cells.iter().any(|cell| cell.header == Some(true)))
+        .count() as u16;
+
+    ir_table
+}
+
+/// 셀 변환 (`CELL`)
+fn convert_cell(cell: &Cell, unknown_controls: &mut usize) -> TableCell {
+    let mut ir_cell = TableCell::new(cell.row_addr, cell.col_addr);
+    ir_cell.column_span = cell.col_span.unwrap_or(1).max(1);
+    ir_cell.row_span = cell.row_span.unwrap_or(1).max(1);
+    ir_cell.width = HwpUnit::new(cell.width.unwrap_or(0));
+    ir_cell.height = HwpUnit::new(cell.height.unwrap_or(0));
+    if let Some(margin) = &cell.margin {
+        ir_cell.padding = Insets {
+            left: HwpUnit::new(margin.left),
+            right: HwpUnit::new(margin.right),
+            top: HwpUnit::new(margin.top),
+            bottom: HwpUnit::new(margin.bottom),
+        };
+    }
+    ir_cell.border_fill_id = cell.border_fill.map(BorderFillId::new);
+    ir_cell.is_header = cell.header.unwrap_or(false);
+    ir_cell.protect = cell.protect.unwrap_or(false);
+    ir_cell.editable = cell.editable.unwrap_or(true);
+    ir_cell.name = cell.name.clone();
+
+    let para_list = &cell.para_list;
+    ir_cell.vertical_alignment = match para_list.vert_align.unwrap_or_default() {
+        ParaListVertAlign::Top => VerticalAlignment::Top,
+        ParaListVertAlign::Center => VerticalAlignment::Middle,
+        ParaListVertAlign::Bottom => VerticalAlignment::Bottom,
+    };
+    ir_cell.paragraphs = para_list
+        .paragraphs
+        .iter()
+        .map(|paragraph| convert_paragraph(paragraph, unknown_controls))
+        .collect();
+
+    ir_cell
+}
+
+/// 그림 변환 (`PICTURE`)
+///
+/// `IMAGE`의 `BinItem`은 [`convert_binary_data`]가 매긴 바이너리 데이터 번호와 같습니다.
+fn convert_picture(picture: &HwpmlPicture) -> IrPicture {
+    let image = &picture.image;
+    let mut ir_picture = IrPicture::new(BinaryDataId::from_numeric(
+        image.bin_item.unwrap_or(0) as u16
+    ));
+    ir_picture.common = convert_shape_object(picture.shape_object.as_ref());
+    ir_picture.effect = convert_image_effect(image.effect.as_deref());
+    ir_picture.brightness = image.bright.unwrap_or(0).clamp(-100, 100) as i8;
+    ir_picture.contrast = image.contrast.unwrap_or(0).clamp(-100, 100) as i8;
+    // `Alpha`는 투명도 (0 = 불투명, 255 = 투명)
+    ir_picture.alpha = 1.0 - f64::from(image.alpha.unwrap_or(0)) / 255.0;
+    if let Some(margin) = &picture.inside_margin {
+        ir_picture.inside_margin = Insets {
+            left: HwpUnit::new(margin.left),
+            right: HwpUnit::new(margin.right),
+            top: HwpUnit::new(margin.top),
+            bottom: HwpUnit::new(margin.bottom),
+        };
+    }
+    ir_picture
+}
+
+/// 수식 변환 (`EQUATION`)
+///
+/// 줄 단위 배치(`LineMode`)는 HWP 수식 속성처럼 첫 비트로 기록합니다.
+fn convert_equation(equation: &HwpmlEquation) -> IrEquation {
+    IrEquation {
+        common: convert_shape_object(equation.shape_object.as_ref()),
+        script: equation.script.clone(),
+        format: EquationFormat::HwpScript,
+        baseline_offset: HwpUnit::new(equation.base_line.unwrap_or(0)),
+        font_size: HwpUnit::new(equation.base_unit.unwrap_or(1000)),
+        color: equation.text_color.map(color_from_rgb),
+        line_mode: None,
+        version: equation.version.clone(),
+        font_name: None,
+        properties: equation.line_mode.map(u32::from),
+    }
+}
+
+/// 그림 효과 변환 (`IMAGE`의 `Effect`)
+fn convert_image_effect(effect: Option<&str>) -> ImageEffect {
+    match effect {
+        Some("GrayScale") => ImageEffect::Grayscale,
+        Some("BlackWhite") => ImageEffect::BlackWhite,
+        _ => ImageEffect::Original,
+    }
+}
+
+/// 바이너리 데이터 변환 (`BINDATALIST`, `BINDATASTORAGE`)
+///
+/// `BINITEM`은 목록 순서대로 1부터 번호를 매겨 `BIN{16진수}` 아이디로 등록합니다.
+fn convert_binary_data(hml: &HwpmlDocument, ctx: &mut ToIrContext) -> BinaryDataStore {
+    let mut store = BinaryDataStore::new();
+
+    for (item, id) in hml.bin_items().iter().zip(1u16..) {
+        if item.item_type == BinItemType::Link {
+            ctx.warnings.data_loss(format!(
+                "연결된 외부 파일은 포함되지 않습니다: {}",
+                item.absolute_path.as_deref().unwrap_or_default()
+            ));
+            continue;
+        }
+
+        let Some(bin_data) = item
+            .bin_data
+            .as_deref()
+            .and_then(|data_id| hml.bin_data_by_id(data_id))
+        else {
+            ctx.warnings
+                .data_loss(format!("{}번 바이너리 데이터 내용이 없습니다", id));
+            continue;
+        };
+
+        let data = match bin_data.decode() {
+            Ok(data) => data,
+            Err(error) => {
+                ctx.warnings.data_loss(error.to_string());
+                continue;
+            }
+        };
+
+        let format = match item.format.as_deref() {
+            Some(format) => match BinaryData::format_from_extension(format) {
+                BinaryFormat::Unknown => detect_binary_format(&data),
+                format => format,
+            },
+            None => detect_binary_format(&data),
+        };
+        let mut ir_data = BinaryData::new(format, data);
+        ir_data.compressed = bin_data.is_compressed();
+        store.add(BinaryDataId::from_numeric(id), ir_data);
+    }
+
+    store
+}
+
+/// 바이너리 데이터 형식 감지
+fn detect_binary_format(data: &[u8]) -> BinaryFormat {
+    if data.len() < 8 {
+        return BinaryFormat::Unknown;
+    }
+
+    // 매직 바이트로 형식 감지
+    match &data[0..4] {
+        [0x89, b'P', b'N', b'G'] => BinaryFormat::Png,
+        [0xFF, 0xD8, 0xFF, _] => BinaryFormat::Jpg,
+        [b'G', b'I', b'F', b'8'] => BinaryFormat::Gif,
+        [b'B', b'M', _, _] => BinaryFormat::Bmp,
+        [0xD0, 0xCF, 0x11, 0xE0] => BinaryFormat::Ole,
+        [0x49, 0x49, 0x2A, 0x00] | [0x4D, 0x4D, 0x00, 0x2A] => BinaryFormat::Tiff,
+        _ => BinaryFormat::Unknown,
+    }
+}
+
+/// 확장 데이터 변환 (`SCRIPTCODE`)
+fn convert_extensions(hml: &HwpmlDocument) -> Extensions {
+    let mut ext = Extensions::new();
+
+    let script = hml.tail.as_ref().and_then(|tail| tail.script_code.as_ref());
+    if let Some(script) = script {
+        let (version_high, version_low) = script
+            .version
+            .as_deref()
+            .and_then(|version| version.split_once('.'))
+            .and_then(|(high, low)| Some((high.trim().parse().ok()?, low.trim().parse().ok()?)))
+            .unwrap_or((0, 0));
+        ext.hwp = Some(HwpExtensions {
+            scripts: Some(HwpScripts {
+                version_high,
+                version_low,
+                header: script.header.clone().unwrap_or_default(),
+                source: script.source.clone().unwrap_or_default(),
+                pre_source: script.pre_script.clone().unwrap_or_default(),
+                post_source: script.post_script.clone().unwrap_or_default(),
+            }),
+            ..Default::default()
+        });
+    }
+
+    ext
+}
+
+// 열거형 변환 헬퍼 함수들
+
+const fn convert_language(language: LangType) -> LanguageType {
+    match language {
+        LangType::Hangul => LanguageType::Korean,
+        LangType::Latin => LanguageType::English,
+        LangType::Hanja => LanguageType::Hanja,
+        LangType::Japanese => LanguageType::Japanese,
+        LangType::Other => LanguageType::Other,
+        LangType::Symbol => LanguageType::Symbol,
+        LangType::User => LanguageType::User,
+    }
+}
+
+const fn convert_font_type(font_type: HwpmlFontType) -> FontType {
+    match font_type {
+        HwpmlFontType::Representative => FontType::Representative,
+        HwpmlFontType::TrueType => FontType::TrueType,
+        HwpmlFontType::HangulOnly => FontType::HangeulOnly,
+    }
+}
+
+const fn convert_line_type(line_type: HwpmlLineType) -> LineType {
+    match line_type {
+        HwpmlLineType::Solid => LineType::Solid,
+        HwpmlLineType::Dash => LineType::Dash,
+        HwpmlLineType::Dot => LineType::Dot,
+        HwpmlLineType::DashDot => LineType::DashDot,
+        HwpmlLineType::DashDotDot => LineType::DashDotDot,
+        HwpmlLineType::LongDash => LineType::LongDash,
+        HwpmlLineType::Circle => LineType::Circle,
+        HwpmlLineType::DoubleSlim => LineType::Double,
+        HwpmlLineType::SlimThick => LineType::ThinThickLarge,
+        HwpmlLineType::ThickSlim => LineType::ThickThinLarge,
+        HwpmlLineType::SlimThickSlim => LineType::Triple,
+        HwpmlLineType::None => LineType::None,
+    }
+}
+
+const fn convert_underline_type(line_type: HwpmlLineType) -> UnderlineType {
+    match line_type {
+        HwpmlLineType::Solid => UnderlineType::Single,
+        HwpmlLineType::Dash | HwpmlLineType::LongDash => UnderlineType::Dash,
+        HwpmlLineType::Dot | HwpmlLineType::Circle => UnderlineType::Dotted,
+        HwpmlLineType::DashDot => UnderlineType::DashDot,
+        HwpmlLineType::DashDotDot => UnderlineType::DashDotDot,
+        HwpmlLineType::DoubleSlim => UnderlineType::Double,
+        HwpmlLineType::SlimThick | HwpmlLineType::ThickSlim | HwpmlLineType::SlimThickSlim => {
+            UnderlineType::Thick
+        }
+        HwpmlLineType::None => UnderlineType::None,
+    }
+}
+
+const fn convert_hatch_style(style: HatchStyle) -> PatternType {
+    match style {
+        HatchStyle::Horizontal => PatternType::Horizontal,
+        HatchStyle::Vertical => PatternType::Vertical,
+        HatchStyle::BackSlash => PatternType::BackSlash,
+        HatchStyle::Slash => PatternType::Slash,
+        HatchStyle::Cross => PatternType::Cross,
+        HatchStyle::CrossDiagonal => PatternType::CrossDiagonal,
+    }
+}
+
+fn convert_image_fill_mode(mode: &str) -> ImageFillMode {
+    IMAGE_FILL_MODES
+        .iter()
+        .find(|(name, _)| *name == mode)
+        .map_or(ImageFillMode::Tile, |(_, mode)| *mode)
+}
@@ -0,0 +1,227 @@
+//! HWPML 문서 타입
+//!
+//! `.hml` 파일은 루트 엘리먼트 `HWPML` 하나로 된 XML 문서입니다.
+//! [`Document::from_bytes`]/[`Document::to_bytes`]로 파일 내용을 직접 읽고 씁니다.
+
+use std::io::{Read, Write};
+
+use serde::{Deserialize, Serialize};
+
+use crate::body::Body;
+use crate::error::{Error, Result};
+use crate::head::Head;
+use crate::mapping_table::BinItem;
+use crate::tail::{BinData, Tail};
+
+/// 쓰기에 사용하는 HWPML 버전 (`Version`)
+pub const HWPML_VERSION: &str = "2.8";
+
+/// 쓰기에 사용하는 HWPML 하위 버전 (`SubVersion`)
+pub const HWPML_SUB_VERSION: &str = "8.0.0.0";
+
+/// 파일 앞에 붙이는 XML 선언
+const XML_DECLARATION: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="no" ?>"#;
+
+/// HWPML 문서 (`HWPML`)
+///
+/// - `HEAD` → `head`: 문서 요약, 설정, 글꼴/글자 모양/문단 모양 등의 매핑 테이블
+/// - `BODY` → `body`: 구역과 문단
+/// - `TAIL` → `tail`: Base64로 포함된 바이너리 데이터, 스크립트 (선택적)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename = "HWPML")]
+pub struct Document {
+    /// HWPML 버전 (`Version`)
+    #[serde(rename = "@Version", default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+
+    /// 하위 버전 (`SubVersion`)
+    #[serde(
+        rename = "@SubVersion",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub sub_version: Option<String>,
+
+    /// 스타일 (`Style`, `embed | export`)
+    #[serde(rename = "@Style", default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<String>,
+
+    /// 헤더 (`HEAD`)
+    #[serde(rename = "HEAD", default)]
+    pub head: Head,
+
+    /// 본문 (`BODY`)
+    #[serde(rename = "BODY", default)]
+    pub body: Body,
+
+    /// 부가 정보 (`TAIL`)
+    #[serde(rename = "TAIL", default, skip_serializing_if = "Option::is_none")]
+    pub tail: Option<Tail>,
+}
+
+impl Default for Document {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Document {
+    /// 빈 문서 생성
+    pub fn new() -> Self {
+        Self {
+            version: Some(HWPML_VERSION.to_string()),
+            sub_version: Some(HWPML_SUB_VERSION.to_string()),
+            style: Some("embed".to_string()),
+            head: Head::default(),
+            body: Body::default(),
+            tail: None,
+        }
+    }
+
+    /// `.hml` 파일 내용에서 문서를 읽습니다.
+    ///
+    /// 바이트 순서 표시(BOM)로 UTF-8과 UTF-16(LE/BE)을 구분하고, 없으면 UTF-8로 읽습니다.
+    pub fn from_bytes(data: &[u8]) -> Result<Self> {
+        Self::from_str(&decode_text(data)?)
+    }
+
+    /// 리더에서 문서를 읽습니다.
+    pub fn from_reader<R: Read>(mut reader: R) -> Result<Self> {
+        let mut data = Vec::new();
+        reader.read_to_end(&mut data)?;
+        Self::from_bytes(&data)
+    }
+
+    /// XML 문자열에서 문서를 읽습니다.
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(xml: &str) -> Result<Self> {
+        Ok(crate::from_str(xml)?)
+    }
+
+    /// XML 선언을 포함한 문자열로 씁니다.
+    pub fn to_xml_string(&self) -> Result<String> {
+        let body = crate::to_string(self)?;
+        Ok(format!("{}{}", XML_DECLARATION, body))
+    }
+
+    /// UTF-8 `.hml` 파일 내용으로 씁니다.
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        Ok(self.to_xml_string()?.into_bytes())
+    }
+
+    /// 라이터에 UTF-8 `.hml` 파일 내용을 씁니다.
+    pub fn write_to<W: Write>(&self, mut writer: W) -> Result<()> {
+        writer.write_all(&self.to_bytes()?)?;
+        Ok(())
+    }
+
+    /// `BINDATALIST`의 바이너리 데이터 항목 목록
+    pub fn bin_items(&self) -> &[BinItem] {
+        self.head
+            .mapping_table
+            .as_ref()
+            .and_then(|table| table.bin_data_list.as_ref())
+            .map_or(&[], |list| list.items.as_slice())
+    }
+
+    /// `BINDATASTORAGE`의 바이너리 데이터 목록
+    pub fn bin_data(&self) -> &[BinData] {
+        self.tail
+            .as_ref()
+            .and_then(|tail| tail.bin_data_storage.as_ref())
+            .map_or(&[], |storage| storage.items.as_slice())
+    }
+
+    /// 아이디로 `BINDATA`를 찾습니다.
+    pub fn bin_data_by_id(&self, id: &str) -> Option<&BinData> {
+        self.bin_data().iter().find(|data| data.id == id)
+    }
+
+    /// 문서의 모든 텍스트를 문단 단위로 줄을 바꿔 추출합니다.
+    pub fn extract_text(&self) -> String {
+        use crate::body::{CharItem, TextItem};
+
+        let mut lines = Vec::new();
+        for section in &self.body.sections {
+            for paragraph in &section.paragraphs {
+                let mut line = String::new();
+                let chars = paragraph
+                    .texts
+                    .iter()
+                    .flat_map(|text| &text.items)
+                    .filter_map(|item| match item {
+                        TextItem::Char(ch) => Some(ch),
+                        _ => None,
+                    });
+                for item in chars.flat_map(|ch| &ch.items) {
+                    match item {
+                        CharItem::Text(text) => line.push_str(text),
+                        CharItem::Tab => line.push('\t'),
+                        CharItem::LineBreak => line.push('\n'),
+                        CharItem::Hyphen => line.push('-'),
+                        CharItem::NonBreakingSpace | CharItem::FixedWidthSpace => line.push(' '),
+                        _ => {}
+                    }
+                }
+                lines.push(line);
+            }
+        }
+        lines.join("\n")
+    }
+}
+
+/// BOM으로 인코딩을 구분해 텍스트로 변환
+fn decode_text(data: &[u8]) -> Result<String> {
+    let utf16 = |bytes: &[u8], from: fn([u8; 2]) -> u16| {
+        let units: Vec<u16> = bytes
+            .chunks_exact(2)
+            .map(|pair| from([pair[0], pair[1]]))
+            .collect();
+        String::from_utf16(&units).map_err(|_| Error::InvalidText)
+    };
+
+    match data {
+        [0xEF, 0xBB, 0xBF, rest @ ..] => {
+            String::from_utf8(rest.to_vec()).map_err(|_| Error::InvalidText)
+        }
+        [0xFF, 0xFE, rest @ ..] => utf16(rest, u16::from_le_bytes),
+        [0xFE, 0xFF, rest @ ..] => utf16(rest, u16::from_be_bytes),
+        _ => String::from_utf8(data.to_vec()).map_err(|_| Error::InvalidText),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_text_with_bom() {
+        let xml = "<HWPML/>";
+        let mut utf16le = vec![0xFF, 0xFE];
+        utf16le.extend(xml.encode_utf16().flat_map(u16::to_le_bytes));
+        let mut utf16be = vec![0xFE, 0xFF];
+        utf16be.extend(xml.encode_utf16().flat_map(u16::to_be_bytes));
+        let mut utf8 = vec![0xEF, 0xBB, 0xBF];
+        utf8.extend_from_slice(xml.as_bytes());
+
+        for data in [utf16le, utf16be, utf8, xml.as_bytes().to_vec()] {
+            assert_eq!(decode_text(&data).unwrap(), xml);
+        }
+        assert!(matches!(
+            decode_text(&[0xC3, 0x28]),
+            Err(Error::InvalidText)
+        ));
+    }
+
+    #[test]
+    fn test_new_document_round_trip() {
+        let document = Document::new();
+        let bytes = document.to_bytes().unwrap();
+        let xml = std::str::from_utf8(&bytes).unwrap();
+        assert!(xml.starts_with("<?xml"));
+        assert!(xml.contains(r#"<HWPML Version="2.8" SubVersion="8.0.0.0" Style="embed">"#));
+
+        let parsed = Document::from_bytes(&bytes).unwrap();
+        assert_eq!(parsed, document);
+    }
+}
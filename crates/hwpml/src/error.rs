@@ -0,0 +1,93 @@
+//! HWPML 문서 처리 오류
+//!
+//! `.hml` 파일을 읽거나 쓸 때 발생하는 오류를 정의합니다.
+
+use std::fmt;
+
+use primitive::{LimitExceeded, LimitKind};
+
+/// HWPML 문서 처리 결과 타입
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// HWPML 문서 처리 오류
+#[derive(Debug)]
+pub enum Error {
+    /// 입출력 오류
+    Io(std::io::Error),
+
+    /// 파일 내용이 UTF-8/UTF-16 텍스트가 아님
+    InvalidText,
+
+    /// XML 역직렬화 실패
+    Deserialize(quick_xml::DeError),
+
+    /// XML 직렬화 실패
+    Serialize(quick_xml::SeError),
+
+    /// `BINDATA` 내용을 풀 수 없음 (Base64 디코딩 또는 압축 해제 실패)
+    InvalidBinaryData {
+        /// `BINDATA` 아이디
+        id: String,
+    },
+
+    /// 자원 제한을 넘음
+    LimitExceeded {
+        /// 넘어선 제한 종류
+        kind: LimitKind,
+        /// 제한값
+        limit: u64,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(err) => write!(f, "I/O error: {}", err),
+            Error::InvalidText => write!(f, "Document is not valid UTF-8 or UTF-16 text"),
+            Error::Deserialize(err) => write!(f, "Failed to parse HWPML: {}", err),
+            Error::Serialize(err) => write!(f, "Failed to serialize HWPML: {}", err),
+            Error::InvalidBinaryData { id } => write!(f, "Invalid BINDATA content: {}", id),
+            Error::LimitExceeded { kind, limit } => {
+                write!(f, "Limit exceeded: {} over {}", kind, limit)
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(err) => Some(err),
+            Error::Deserialize(err) => Some(err),
+            Error::Serialize(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(err: std::io::Error) -> Self {
+        Error::Io(err)
+    }
+}
+
+impl From<quick_xml::DeError> for Error {
+    fn from(err: quick_xml::DeError) -> Self {
+        Error::Deserialize(err)
+    }
+}
+
+impl From<quick_xml::SeError> for Error {
+    fn from(err: quick_xml::SeError) -> Self {
+        Error::Serialize(err)
+    }
+}
+
+impl From<LimitExceeded> for Error {
+    fn from(err: LimitExceeded) -> Self {
+        Error::LimitExceeded {
+            kind: err.kind,
+            limit: err.limit,
+        }
+    }
+}
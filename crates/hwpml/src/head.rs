@@ -0,0 +1,166 @@
+//! 헤더 엘리먼트 (`HEAD`)
+//!
+//! 문서 요약 정보, 문서 설정, 글꼴/글자 모양/문단 모양 등의 매핑 테이블을 담습니다.
+//! HWPML 명세 4장.
+
+use serde::{Deserialize, Serialize};
+
+use crate::mapping_table::MappingTable;
+
+/// 헤더 (`HEAD`)
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct Head {
+    /// 구역의 개수 (`SecCnt`)
+    #[serde(rename = "@SecCnt", default, skip_serializing_if = "Option::is_none")]
+    pub section_count: Option<u32>,
+
+    /// 문서 요약 정보 (`DOCSUMMARY`)
+    #[serde(
+        rename = "DOCSUMMARY",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub summary: Option<DocSummary>,
+
+    /// 문서 설정 정보 (`DOCSETTING`)
+    #[serde(
+        rename = "DOCSETTING",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub setting: Option<DocSetting>,
+
+    /// 글꼴/스타일 정보 (`MAPPINGTABLE`)
+    #[serde(
+        rename = "MAPPINGTABLE",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub mapping_table: Option<MappingTable>,
+}
+
+/// 문서 요약 정보 (`DOCSUMMARY`)
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct DocSummary {
+    /// 문서 제목 (`TITLE`)
+    #[serde(rename = "TITLE", default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+
+    /// 문서 주제 (`SUBJECT`)
+    #[serde(rename = "SUBJECT", default, skip_serializing_if = "Option::is_none")]
+    pub subject: Option<String>,
+
+    /// 문서 저자 (`AUTHOR`)
+    #[serde(rename = "AUTHOR", default, skip_serializing_if = "Option::is_none")]
+    pub author: Option<String>,
+
+    /// 작성 날짜 (`DATE`)
+    #[serde(rename = "DATE", default, skip_serializing_if = "Option::is_none")]
+    pub date: Option<String>,
+
+    /// 키워드 (`KEYWORDS`)
+    #[serde(rename = "KEYWORDS", default, skip_serializing_if = "Option::is_none")]
+    pub keywords: Option<String>,
+
+    /// 기타 설명 (`COMMENTS`)
+    #[serde(rename = "COMMENTS", default, skip_serializing_if = "Option::is_none")]
+    pub comments: Option<String>,
+
+    /// 금칙 문자 (`FORBIDDENSTRING`)
+    #[serde(
+        rename = "FORBIDDENSTRING",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub forbidden_string: Option<ForbiddenString>,
+}
+
+/// 금칙 문자 (`FORBIDDENSTRING`)
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct ForbiddenString {
+    /// 금지 문자열 목록 (`FORBIDDEN`)
+    #[serde(rename = "FORBIDDEN", default)]
+    pub items: Vec<Forbidden>,
+}
+
+/// 금지 문자열 (`FORBIDDEN`)
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct Forbidden {
+    /// 한정자 (`id`)
+    #[serde(rename = "@id", default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+
+    /// 금지 문자열
+    #[serde(rename = "$text", default)]
+    pub value: String,
+}
+
+/// 문서 설정 정보 (`DOCSETTING`)
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct DocSetting {
+    /// 시작 번호 (`BEGINNUMBER`)
+    #[serde(
+        rename = "BEGINNUMBER",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub begin_number: Option<BeginNumber>,
+
+    /// 캐럿 위치 (`CARETPOS`)
+    #[serde(rename = "CARETPOS", default, skip_serializing_if = "Option::is_none")]
+    pub caret_position: Option<CaretPosition>,
+}
+
+/// 문서 내 각종 시작 번호 (`BEGINNUMBER`)
+///
+/// 속성이 없으면 1부터 시작합니다.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct BeginNumber {
+    /// 페이지 시작 번호 (`Page`)
+    #[serde(rename = "@Page", default, skip_serializing_if = "Option::is_none")]
+    pub page: Option<u32>,
+
+    /// 각주 시작 번호 (`Footnote`)
+    #[serde(rename = "@Footnote", default, skip_serializing_if = "Option::is_none")]
+    pub footnote: Option<u32>,
+
+    /// 미주 시작 번호 (`Endnote`)
+    #[serde(rename = "@Endnote", default, skip_serializing_if = "Option::is_none")]
+    pub endnote: Option<u32>,
+
+    /// 그림 시작 번호 (`Picture`)
+    #[serde(rename = "@Picture", default, skip_serializing_if = "Option::is_none")]
+    pub picture: Option<u32>,
+
+    /// 표 시작 번호 (`Table`)
+    #[serde(rename = "@Table", default, skip_serializing_if = "Option::is_none")]
+    pub table: Option<u32>,
+
+    /// 수식 시작 번호 (`Equation`)
+    #[serde(rename = "@Equation", default, skip_serializing_if = "Option::is_none")]
+    pub equation: Option<u32>,
+
+    /// 전체 페이지 수 (`TotalPage`)
+    #[serde(
+        rename = "@TotalPage",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub total_page: Option<u32>,
+}
+
+/// 문서 내 캐럿 위치 (`CARETPOS`)
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct CaretPosition {
+    /// 리스트 아이디 (`List`)
+    #[serde(rename = "@List", default, skip_serializing_if = "Option::is_none")]
+    pub list: Option<u32>,
+
+    /// 문단 아이디 (`Para`)
+    #[serde(rename = "@Para", default, skip_serializing_if = "Option::is_none")]
+    pub paragraph: Option<u32>,
+
+    /// 문단 내 글자 단위 위치 (`Pos`)
+    #[serde(rename = "@Pos", default, skip_serializing_if = "Option::is_none")]
+    pub position: Option<u32>,
+}
@@ -0,0 +1,72 @@
+//! # HWPML 파서 라이브러리
+//!
+//! HWPML 3.0 (`.hml`) 문서를 파싱하고 생성하기 위한 Rust 라이브러리.
+//!
+//! ## 개요
+//!
+//! HWPML은 한글 문서를 하나의 XML 파일로 표현하는 형식입니다.
+//! 루트 엘리먼트 `HWPML` 아래에 헤더(`HEAD`), 본문(`BODY`), 부가 정보(`TAIL`)가 오고,
+//! 그림 등의 바이너리 데이터는 `TAIL`에 Base64로 포함됩니다.
+//! `serde`와 `quick-xml`을 사용하여 XML 직렬화/역직렬화를 제공합니다.
+//!
+//! ## 사용 예시
+//!
+//! ```ignore
+//! // .hml 파일 읽기/쓰기
+//! let bytes = std::fs::read("document.hml")?;
+//! let document = hwpml::Document::from_bytes(&bytes)?;
+//! println!("{}", document.extract_text());
+//! std::fs::write("copy.hml", document.to_bytes()?)?;
+//! ```
+//!
+//! IR과 양방향으로 변환할 수 있습니다.
+//!
+//! ```ignore
+//! use hwpml::convert::{HwpmlToIr, IrToHwpml};
+//!
+//! let ir = document.to_ir()?.value;
+//! let hml = ir.to_hwpml()?.value;
+//! ```
+//!
+//! ## 주요 모듈
+//!
+//! - [`document`]: 문서 루트와 파일 읽기/쓰기
+//! - [`head`]: 문서 요약, 문서 설정
+//! - [`mapping_table`]: 글꼴, 테두리/배경, 글자 모양, 문단 모양, 스타일
+//! - [`body`]: 구역, 문단, 글자
+//! - [`control`]: 표, 그림, 수식
+//! - [`tail`]: 바이너리 데이터, 스크립트
+//! - [`types`]: 공통 속성 값 형식
+//! - [`convert`]: IR 변환
+
+#![deny(clippy::all)]
+#![deny(missing_docs)]
+
+pub mod body;
+pub mod control;
+pub mod convert;
+pub mod document;
+pub mod error;
+pub mod head;
+pub mod mapping_table;
+pub mod tail;
+pub mod types;
+
+pub use document::Document;
+pub use error::{Error, Result};
+
+#[inline]
+/// 문자열 HWPML/XML을 지정 타입으로 역직렬화합니다. `quick_xml::de`를 래핑합니다.
+pub fn from_str<T: serde::de::DeserializeOwned>(
+    s: &str,
+) -> std::result::Result<T, quick_xml::DeError> {
+    quick_xml::de::from_str(s)
+}
+
+#[inline]
+/// 지정 값을 HWPML/XML 문자열로 직렬화합니다. `quick_xml::se`를 래핑합니다.
+pub fn to_string<T: serde::ser::Serialize>(
+    value: &T,
+) -> std::result::Result<String, quick_xml::SeError> {
+    quick_xml::se::to_string(value)
+}
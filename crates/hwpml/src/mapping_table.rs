@@ -0,0 +1,1026 @@
+//! 문서 글꼴/스타일 정보 (`MAPPINGTABLE`)
+//!
+//! 본문에서 아이디로 참조하는 그림/OLE, 글꼴, 테두리/배경, 글자 모양, 문단 모양, 스타일 목록입니다.
+//! HWPML 명세 4.4절. 명세에 세부 구조가 없는 `TABDEFLIST`, `NUMBERINGLIST`, `BULLETLIST`,
+//! `MEMOSHAPELIST`는 [`RawElement`]로 보관했다가 그대로 다시 씁니다.
+
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::body::RawElement;
+use crate::types::{
+    Alignment, FontType, HatchStyle, LangType, LineType, OutlineLineType, RgbColor,
+};
+
+/// 글꼴/스타일 정보 (`MAPPINGTABLE`)
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct MappingTable {
+    /// 그림/OLE 바이너리 데이터 목록 (`BINDATALIST`)
+    #[serde(
+        rename = "BINDATALIST",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub bin_data_list: Option<BinDataList>,
+
+    /// 글꼴 목록 (`FACENAMELIST`)
+    #[serde(
+        rename = "FACENAMELIST",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub face_name_list: Option<FaceNameList>,
+
+    /// 테두리/배경 목록 (`BORDERFILLLIST`)
+    #[serde(
+        rename = "BORDERFILLLIST",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub border_fill_list: Option<BorderFillList>,
+
+    /// 글자 모양 목록 (`CHARSHAPELIST`)
+    #[serde(
+        rename = "CHARSHAPELIST",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub char_shape_list: Option<CharShapeList>,
+
+    /// 탭 정의 목록 (`TABDEFLIST`)
+    #[serde(
+        rename = "TABDEFLIST",
+        default,
+        deserialize_with = "tab_def_list",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub tab_def_list: Option<RawElement>,
+
+    /// 문단 번호 목록 (`NUMBERINGLIST`)
+    #[serde(
+        rename = "NUMBERINGLIST",
+        default,
+        deserialize_with = "numbering_list",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub numbering_list: Option<RawElement>,
+
+    /// 글머리표 목록 (`BULLETLIST`)
+    #[serde(
+        rename = "BULLETLIST",
+        default,
+        deserialize_with = "bullet_list",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub bullet_list: Option<RawElement>,
+
+    /// 문단 모양 목록 (`PARASHAPELIST`)
+    #[serde(
+        rename = "PARASHAPELIST",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub para_shape_list: Option<ParaShapeList>,
+
+    /// 스타일 목록 (`STYLELIST`)
+    #[serde(rename = "STYLELIST", default, skip_serializing_if = "Option::is_none")]
+    pub style_list: Option<StyleList>,
+
+    /// 메모 모양 목록 (`MEMOSHAPELIST`)
+    #[serde(
+        rename = "MEMOSHAPELIST",
+        default,
+        deserialize_with = "memo_shape_list",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub memo_shape_list: Option<RawElement>,
+}
+
+// 구조를 모르는 목록은 필드 이름을 엘리먼트 이름으로 기록합니다.
+
+fn tab_def_list<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<RawElement>, D::Error> {
+    RawElement::deserialize_named(deserializer, "TABDEFLIST").map(Some)
+}
+
+fn numbering_list<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<RawElement>, D::Error> {
+    RawElement::deserialize_named(deserializer, "NUMBERINGLIST").map(Some)
+}
+
+fn bullet_list<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<RawElement>, D::Error> {
+    RawElement::deserialize_named(deserializer, "BULLETLIST").map(Some)
+}
+
+fn memo_shape_list<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<RawElement>, D::Error> {
+    RawElement::deserialize_named(deserializer, "MEMOSHAPELIST").map(Some)
+}
+
+// ---------------------------------------------------------------------------
+// 그림/OLE 정보
+// ---------------------------------------------------------------------------
+
+/// 바이너리 데이터 목록 (`BINDATALIST`)
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct BinDataList {
+    /// 항목 개수 (`Count`)
+    #[serde(rename = "@Count", default)]
+    pub count: u32,
+
+    /// 바이너리 데이터 항목 (`BINITEM`)
+    #[serde(rename = "BINITEM", default)]
+    pub items: Vec<BinItem>,
+}
+
+/// 바이너리 데이터 저장 방식 (`BINITEM`의 `Type`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum BinItemType {
+    /// 외부 파일 연결 (그림)
+    Link,
+    /// 문서에 포함 (그림)
+    #[default]
+    Embedding,
+    /// 스토리지로 포함 (OLE)
+    Storage,
+}
+
+/// 바이너리 데이터 항목 (`BINITEM`)
+///
+/// 본문과 `BORDERFILL`의 그림은 목록에서의 순번(1부터)으로 이 항목을 참조합니다.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct BinItem {
+    /// 저장 방식 (`Type`)
+    #[serde(rename = "@Type")]
+    pub item_type: BinItemType,
+
+    /// 연결 파일의 절대 경로 (`APath`, `Link`일 때)
+    #[serde(rename = "@APath", default, skip_serializing_if = "Option::is_none")]
+    pub absolute_path: Option<String>,
+
+    /// 연결 파일의 상대 경로 (`RPath`, `Link`일 때)
+    #[serde(rename = "@RPath", default, skip_serializing_if = "Option::is_none")]
+    pub relative_path: Option<String>,
+
+    /// `BINDATASTORAGE`에 저장된 `BINDATA`의 아이디 (`BinData`)
+    #[serde(rename = "@BinData", default, skip_serializing_if = "Option::is_none")]
+    pub bin_data: Option<String>,
+
+    /// 데이터 형식 (`Format`, 예: `jpg`, `bmp`, `gif`, `ole`)
+    #[serde(rename = "@Format", default, skip_serializing_if = "Option::is_none")]
+    pub format: Option<String>,
+}
+
+// ---------------------------------------------------------------------------
+// 글꼴 정보
+// ---------------------------------------------------------------------------
+
+/// 글꼴 목록 (`FACENAMELIST`)
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct FaceNameList {
+    /// 언어별 글꼴 그룹 (`FONTFACE`)
+    #[serde(rename = "FONTFACE", default)]
+    pub font_faces: Vec<FontFace>,
+}
+
+/// 언어별 글꼴 그룹 (`FONTFACE`)
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct FontFace {
+    /// 언어 종류 (`Lang`)
+    #[serde(rename = "@Lang")]
+    pub language: LangType,
+
+    /// 글꼴 개수 (`Count`)
+    #[serde(rename = "@Count", default)]
+    pub count: u32,
+
+    /// 글꼴 (`FONT`)
+    #[serde(rename = "FONT", default)]
+    pub fonts: Vec<Font>,
+}
+
+/// 글꼴 (`FONT`)
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct Font {
+    /// 언어 그룹 안에서의 글꼴 아이디 (`Id`)
+    #[serde(rename = "@Id")]
+    pub id: u32,
+
+    /// 글꼴 유형 (`Type`)
+    #[serde(rename = "@Type", default)]
+    pub font_type: FontType,
+
+    /// 글꼴 이름 (`Name`)
+    #[serde(rename = "@Name")]
+    pub name: String,
+
+    /// 대체 글꼴 (`SUBSTFONT`)
+    #[serde(rename = "SUBSTFONT", default, skip_serializing_if = "Option::is_none")]
+    pub substitute: Option<SubstituteFont>,
+
+    /// PANOSE 글꼴 분류 (`TYPEINFO`)
+    #[serde(rename = "TYPEINFO", default, skip_serializing_if = "Option::is_none")]
+    pub type_info: Option<TypeInfo>,
+}
+
+/// 대체 글꼴 (`SUBSTFONT`)
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct SubstituteFont {
+    /// 대체 글꼴의 유형 (`Type`)
+    #[serde(rename = "@Type", default)]
+    pub font_type: FontType,
+
+    /// 글꼴 이름 (`Name`)
+    #[serde(rename = "@Name")]
+    pub name: String,
+}
+
+/// PANOSE 글꼴 분류 (`TYPEINFO`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct TypeInfo {
+    /// 글꼴 계열 (`FamilyType`)
+    #[serde(rename = "@FamilyType", default)]
+    pub family_type: u8,
+    /// 세리프 유형 (`SerifStyle`)
+    #[serde(rename = "@SerifStyle", default)]
+    pub serif_style: u8,
+    /// 굵기 (`Weight`)
+    #[serde(rename = "@Weight", default)]
+    pub weight: u8,
+    /// 비례 (`Proportion`)
+    #[serde(rename = "@Proportion", default)]
+    pub proportion: u8,
+    /// 대조 (`Contrast`)
+    #[serde(rename = "@Contrast", default)]
+    pub contrast: u8,
+    /// 스트로크 편차 (`StrokeVariation`)
+    #[serde(rename = "@StrokeVariation", default)]
+    pub stroke_variation: u8,
+    /// 자획 유형 (`ArmStyle`)
+    #[serde(rename = "@ArmStyle", default)]
+    pub arm_style: u8,
+    /// 글자형 (`Letterform`)
+    #[serde(rename = "@Letterform", default)]
+    pub letterform: u8,
+    /// 중간선 (`Midline`)
+    #[serde(rename = "@Midline", default)]
+    pub midline: u8,
+    /// X-높이 (`XHeight`)
+    #[serde(rename = "@XHeight", default)]
+    pub x_height: u8,
+}
+
+// ---------------------------------------------------------------------------
+// 테두리/배경/채우기 정보
+// ---------------------------------------------------------------------------
+
+/// 테두리/배경 목록 (`BORDERFILLLIST`)
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct BorderFillList {
+    /// 항목 개수 (`Count`)
+    #[serde(rename = "@Count", default)]
+    pub count: u32,
+
+    /// 테두리/배경 (`BORDERFILL`)
+    #[serde(rename = "BORDERFILL", default)]
+    pub border_fills: Vec<BorderFill>,
+}
+
+/// 테두리/배경/채우기 (`BORDERFILL`)
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct BorderFill {
+    /// 아이디 (`Id`, 1부터)
+    #[serde(rename = "@Id")]
+    pub id: u32,
+
+    /// 3D 효과 (`ThreeD`)
+    #[serde(rename = "@ThreeD", default, skip_serializing_if = "Option::is_none")]
+    pub three_d: Option<bool>,
+
+    /// 그림자 효과 (`Shadow`)
+    #[serde(rename = "@Shadow", default, skip_serializing_if = "Option::is_none")]
+    pub shadow: Option<bool>,
+
+    /// Slash 대각선 모양 (`Slash`)
+    #[serde(rename = "@Slash", default, skip_serializing_if = "Option::is_none")]
+    pub slash: Option<u8>,
+
+    /// BackSlash 대각선 모양 (`BackSlash`)
+    #[serde(
+        rename = "@BackSlash",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub back_slash: Option<u8>,
+
+    /// 왼쪽 테두리 (`LEFTBORDER`)
+    #[serde(
+        rename = "LEFTBORDER",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub left: Option<BorderLine>,
+
+    /// 오른쪽 테두리 (`RIGHTBORDER`)
+    #[serde(
+        rename = "RIGHTBORDER",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub right: Option<BorderLine>,
+
+    /// 위 테두리 (`TOPBORDER`)
+    #[serde(rename = "TOPBORDER", default, skip_serializing_if = "Option::is_none")]
+    pub top: Option<BorderLine>,
+
+    /// 아래 테두리 (`BOTTOMBORDER`)
+    #[serde(
+        rename = "BOTTOMBORDER",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub bottom: Option<BorderLine>,
+
+    /// 대각선 (`DIAGONAL`)
+    #[serde(rename = "DIAGONAL", default, skip_serializing_if = "Option::is_none")]
+    pub diagonal: Option<BorderLine>,
+
+    /// 채우기 (`FILLBRUSH`)
+    #[serde(rename = "FILLBRUSH", default, skip_serializing_if = "Option::is_none")]
+    pub fill_brush: Option<FillBrush>,
+}
+
+/// 테두리선 (`LEFTBORDER`, `RIGHTBORDER`, `TOPBORDER`, `BOTTOMBORDER`, `DIAGONAL`)
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct BorderLine {
+    /// 선 종류 (`Type`, 기본값 `Solid`)
+    #[serde(rename = "@Type", default)]
+    pub line_type: LineType,
+
+    /// 선 굵기 (`Width`, 예: `0.12mm`)
+    #[serde(rename = "@Width", default, skip_serializing_if = "Option::is_none")]
+    pub width: Option<String>,
+
+    /// 선 색상 (`Color`)
+    #[serde(rename = "@Color", default)]
+    pub color: RgbColor,
+}
+
+/// 채우기 (`FILLBRUSH`)
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct FillBrush {
+    /// 면 채우기 (`WINDOWBRUSH`)
+    #[serde(
+        rename = "WINDOWBRUSH",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub window_brush: Option<WindowBrush>,
+
+    /// 그러데이션 (`GRADATION`)
+    #[serde(rename = "GRADATION", default, skip_serializing_if = "Option::is_none")]
+    pub gradation: Option<Gradation>,
+
+    /// 그림 채우기 (`IMAGEBRUSH`)
+    #[serde(
+        rename = "IMAGEBRUSH",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub image_brush: Option<ImageBrush>,
+}
+
+/// 면 채우기 (`WINDOWBRUSH`)
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct WindowBrush {
+    /// 면색 (`FaceColor`)
+    #[serde(rename = "@FaceColor", default)]
+    pub face_color: RgbColor,
+
+    /// 무늬색 (`HatchColor`)
+    #[serde(rename = "@HatchColor", default)]
+    pub hatch_color: RgbColor,
+
+    /// 무늬 종류 (`HatchStyle`, 없으면 무늬 없음)
+    #[serde(
+        rename = "@HatchStyle",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub hatch_style: Option<HatchStyle>,
+
+    /// 투명도 (`Alpha`)
+    #[serde(rename = "@Alpha", default, skip_serializing_if = "Option::is_none")]
+    pub alpha: Option<u8>,
+}
+
+/// 그러데이션 유형 (`GRADATION`의 `Type`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum GradationType {
+    /// 줄무늬형
+    #[default]
+    Linear,
+    /// 원형
+    Radial,
+    /// 원뿔형
+    Conical,
+    /// 사각형
+    Square,
+}
+
+/// 그러데이션 (`GRADATION`)
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct Gradation {
+    /// 그러데이션 유형 (`Type`)
+    #[serde(rename = "@Type", default)]
+    pub gradation_type: GradationType,
+
+    /// 기울임 (`Angle`, 기본값 90)
+    #[serde(rename = "@Angle", default, skip_serializing_if = "Option::is_none")]
+    pub angle: Option<i32>,
+
+    /// 가로 중심 (`CenterX`)
+    #[serde(rename = "@CenterX", default, skip_serializing_if = "Option::is_none")]
+    pub center_x: Option<i32>,
+
+    /// 세로 중심 (`CenterY`)
+    #[serde(rename = "@CenterY", default, skip_serializing_if = "Option::is_none")]
+    pub center_y: Option<i32>,
+
+    /// 번짐 정도 (`Step`, 0~100)
+    #[serde(rename = "@Step", default, skip_serializing_if = "Option::is_none")]
+    pub step: Option<u32>,
+
+    /// 색 수 (`ColorNum`)
+    #[serde(rename = "@ColorNum", default, skip_serializing_if = "Option::is_none")]
+    pub color_count: Option<u32>,
+
+    /// 번짐 정도의 중심 (`StepCenter`, 0~100)
+    #[serde(
+        rename = "@StepCenter",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub step_center: Option<u32>,
+
+    /// 투명도 (`Alpha`)
+    #[serde(rename = "@Alpha", default, skip_serializing_if = "Option::is_none")]
+    pub alpha: Option<u8>,
+
+    /// 그러데이션 색 (`COLOR`)
+    #[serde(rename = "COLOR", default)]
+    pub colors: Vec<GradationColor>,
+}
+
+/// 그러데이션 색 (`COLOR`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct GradationColor {
+    /// 색 (`Value`)
+    #[serde(rename = "@Value")]
+    pub value: RgbColor,
+}
+
+/// 그림 채우기 (`IMAGEBRUSH`)
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct ImageBrush {
+    /// 채우기 유형 (`Mode`, `InfillMode`, 기본값 `Tile`)
+    #[serde(rename = "@Mode", default, skip_serializing_if = "Option::is_none")]
+    pub mode: Option<String>,
+
+    /// 그림 (`IMAGE`)
+    #[serde(rename = "IMAGE", default, skip_serializing_if = "Option::is_none")]
+    pub image: Option<Image>,
+}
+
+/// 그림 (`IMAGE`)
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct Image {
+    /// 밝기 (`Bright`)
+    #[serde(rename = "@Bright", default, skip_serializing_if = "Option::is_none")]
+    pub bright: Option<i32>,
+
+    /// 명암 (`Contrast`)
+    #[serde(rename = "@Contrast", default, skip_serializing_if = "Option::is_none")]
+    pub contrast: Option<i32>,
+
+    /// 그림 효과 (`Effect`, `RealPic | GrayScale | BlackWhite`)
+    #[serde(rename = "@Effect", default, skip_serializing_if = "Option::is_none")]
+    pub effect: Option<String>,
+
+    /// `BINITEM` 참조 (`BinItem`)
+    #[serde(rename = "@BinItem", default, skip_serializing_if = "Option::is_none")]
+    pub bin_item: Option<u32>,
+
+    /// 투명도 (`Alpha`)
+    #[serde(rename = "@Alpha", default, skip_serializing_if = "Option::is_none")]
+    pub alpha: Option<u8>,
+}
+
+// ---------------------------------------------------------------------------
+// 글자 모양 정보
+// ---------------------------------------------------------------------------
+
+/// 글자 모양 목록 (`CHARSHAPELIST`)
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct CharShapeList {
+    /// 항목 개수 (`Count`)
+    #[serde(rename = "@Count", default)]
+    pub count: u32,
+
+    /// 글자 모양 (`CHARSHAPE`)
+    #[serde(rename = "CHARSHAPE", default)]
+    pub char_shapes: Vec<CharShape>,
+}
+
+/// 글자 모양 (`CHARSHAPE`)
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct CharShape {
+    /// 아이디 (`Id`)
+    #[serde(rename = "@Id")]
+    pub id: u32,
+
+    /// 글자 크기 (`Height`, hwpunit, 기본값 1000)
+    #[serde(rename = "@Height", default, skip_serializing_if = "Option::is_none")]
+    pub height: Option<i32>,
+
+    /// 글자색 (`TextColor`)
+    #[serde(rename = "@TextColor", default)]
+    pub text_color: RgbColor,
+
+    /// 음영색 (`ShadeColor`, 기본값 4294967295 = 없음)
+    #[serde(
+        rename = "@ShadeColor",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub shade_color: Option<RgbColor>,
+
+    /// 글꼴에 어울리는 빈칸 (`UseFontSpace`)
+    #[serde(rename = "@UseFontSpace", default)]
+    pub use_font_space: bool,
+
+    /// 커닝 (`UseKerning`)
+    #[serde(rename = "@UseKerning", default)]
+    pub use_kerning: bool,
+
+    /// 강조점 종류 (`SymMark`)
+    #[serde(rename = "@SymMark", default)]
+    pub sym_mark: u8,
+
+    /// 글자 테두리 (`BorderFillId`)
+    #[serde(
+        rename = "@BorderFillId",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub border_fill_id: Option<u32>,
+
+    /// 언어별 글꼴 아이디 (`FONTID`)
+    #[serde(rename = "FONTID", default, skip_serializing_if = "Option::is_none")]
+    pub font_id: Option<LangValues>,
+
+    /// 언어별 장평 (`RATIO`, %)
+    #[serde(rename = "RATIO", default, skip_serializing_if = "Option::is_none")]
+    pub ratio: Option<LangValues>,
+
+    /// 언어별 자간 (`CHARSPACING`, %)
+    #[serde(
+        rename = "CHARSPACING",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub char_spacing: Option<LangValues>,
+
+    /// 언어별 상대 크기 (`RELSIZE`, %)
+    #[serde(rename = "RELSIZE", default, skip_serializing_if = "Option::is_none")]
+    pub relative_size: Option<LangValues>,
+
+    /// 언어별 글자 위치 (`CHAROFFSET`, %)
+    #[serde(
+        rename = "CHAROFFSET",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub char_offset: Option<LangValues>,
+
+    /// 기울임 (`ITALIC`)
+    #[serde(rename = "ITALIC", default, skip_serializing_if = "Option::is_none")]
+    pub italic: Option<Marker>,
+
+    /// 진하게 (`BOLD`)
+    #[serde(rename = "BOLD", default, skip_serializing_if = "Option::is_none")]
+    pub bold: Option<Marker>,
+
+    /// 밑줄 (`UNDERLINE`)
+    #[serde(rename = "UNDERLINE", default, skip_serializing_if = "Option::is_none")]
+    pub underline: Option<Underline>,
+
+    /// 취소선 (`STRIKEOUT`)
+    #[serde(rename = "STRIKEOUT", default, skip_serializing_if = "Option::is_none")]
+    pub strikeout: Option<Strikeout>,
+
+    /// 외곽선 (`OUTLINE`)
+    #[serde(rename = "OUTLINE", default, skip_serializing_if = "Option::is_none")]
+    pub outline: Option<Outline>,
+
+    /// 그림자 (`SHADOW`)
+    #[serde(rename = "SHADOW", default, skip_serializing_if = "Option::is_none")]
+    pub shadow: Option<Shadow>,
+
+    /// 양각 (`EMBOSS`)
+    #[serde(rename = "EMBOSS", default, skip_serializing_if = "Option::is_none")]
+    pub emboss: Option<Marker>,
+
+    /// 음각 (`ENGRAVE`)
+    #[serde(rename = "ENGRAVE", default, skip_serializing_if = "Option::is_none")]
+    pub engrave: Option<Marker>,
+
+    /// 위 첨자 (`SUPERSCRIPT`)
+    #[serde(
+        rename = "SUPERSCRIPT",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub superscript: Option<Marker>,
+
+    /// 아래 첨자 (`SUBSCRIPT`)
+    #[serde(rename = "SUBSCRIPT", default, skip_serializing_if = "Option::is_none")]
+    pub subscript: Option<Marker>,
+}
+
+/// 내용 없이 있는지 여부만 뜻을 갖는 엘리먼트 (예: `<BOLD/>`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct Marker;
+
+/// 언어별 값 (`FONTID`, `RATIO`, `CHARSPACING`, `RELSIZE`, `CHAROFFSET`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct LangValues {
+    /// 한글 (`Hangul`)
+    #[serde(rename = "@Hangul", default)]
+    pub hangul: i32,
+    /// 영문 (`Latin`)
+    #[serde(rename = "@Latin", default)]
+    pub latin: i32,
+    /// 한자 (`Hanja`)
+    #[serde(rename = "@Hanja", default)]
+    pub hanja: i32,
+    /// 일본어 (`Japanese`)
+    #[serde(rename = "@Japanese", default)]
+    pub japanese: i32,
+    /// 외국어 (`Other`)
+    #[serde(rename = "@Other", default)]
+    pub other: i32,
+    /// 기호 (`Symbol`)
+    #[serde(rename = "@Symbol", default)]
+    pub symbol: i32,
+    /// 사용자 (`User`)
+    #[serde(rename = "@User", default)]
+    pub user: i32,
+}
+
+impl LangValues {
+    /// 모든 언어에 같은 값
+    pub const fn all(value: i32) -> Self {
+        Self {
+            hangul: value,
+            latin: value,
+            hanja: value,
+            japanese: value,
+            other: value,
+            symbol: value,
+            user: value,
+        }
+    }
+
+    /// 언어별 값
+    pub const fn get(&self, language: LangType) -> i32 {
+        match language {
+            LangType::Hangul => self.hangul,
+            LangType::Latin => self.latin,
+            LangType::Hanja => self.hanja,
+            LangType::Japanese => self.japanese,
+            LangType::Other => self.other,
+            LangType::Symbol => self.symbol,
+            LangType::User => self.user,
+        }
+    }
+
+    /// 언어별 값 설정
+    pub const fn set(&mut self, language: LangType, value: i32) {
+        match language {
+            LangType::Hangul => self.hangul = value,
+            LangType::Latin => self.latin = value,
+            LangType::Hanja => self.hanja = value,
+            LangType::Japanese => self.japanese = value,
+            LangType::Other => self.other = value,
+            LangType::Symbol => self.symbol = value,
+            LangType::User => self.user = value,
+        }
+    }
+}
+
+/// 밑줄 위치 (`UNDERLINE`의 `Type`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum UnderlineType {
+    /// 글자 아래
+    #[default]
+    Bottom,
+    /// 글자 가운데
+    Center,
+    /// 글자 위
+    Top,
+}
+
+/// 밑줄 (`UNDERLINE`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct Underline {
+    /// 밑줄 종류 (`Type`)
+    #[serde(rename = "@Type", default)]
+    pub underline_type: UnderlineType,
+
+    /// 밑줄 모양 (`Shape`)
+    #[serde(rename = "@Shape", default)]
+    pub shape: LineType,
+
+    /// 밑줄 색 (`Color`)
+    #[serde(rename = "@Color", default)]
+    pub color: RgbColor,
+}
+
+/// 취소선 종류 (`STRIKEOUT`의 `Type`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum StrikeoutType {
+    /// 없음
+    None,
+    /// 연속
+    #[default]
+    Continuous,
+}
+
+/// 취소선 (`STRIKEOUT`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct Strikeout {
+    /// 취소선 종류 (`Type`)
+    #[serde(rename = "@Type", default)]
+    pub strikeout_type: StrikeoutType,
+
+    /// 취소선 모양 (`Shape`)
+    #[serde(rename = "@Shape", default)]
+    pub shape: LineType,
+
+    /// 취소선 색 (`Color`)
+    #[serde(rename = "@Color", default)]
+    pub color: RgbColor,
+}
+
+/// 외곽선 (`OUTLINE`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct Outline {
+    /// 외곽선 종류 (`Type`)
+    #[serde(rename = "@Type", default)]
+    pub outline_type: OutlineLineType,
+}
+
+/// 그림자 종류 (`SHADOW`의 `Type`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ShadowType {
+    /// 비연속
+    #[default]
+    Drop,
+    /// 연속
+    Cont,
+}
+
+/// 그림자 (`SHADOW`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct Shadow {
+    /// 그림자 종류 (`Type`)
+    #[serde(rename = "@Type", default)]
+    pub shadow_type: ShadowType,
+
+    /// 그림자 색 (`Color`)
+    #[serde(rename = "@Color", default)]
+    pub color: RgbColor,
+
+    /// 그림자 간격 X (`OffsetX`, %, 기본값 10)
+    #[serde(rename = "@OffsetX", default, skip_serializing_if = "Option::is_none")]
+    pub offset_x: Option<i32>,
+
+    /// 그림자 간격 Y (`OffsetY`, %, 기본값 10)
+    #[serde(rename = "@OffsetY", default, skip_serializing_if = "Option::is_none")]
+    pub offset_y: Option<i32>,
+}
+
+// ---------------------------------------------------------------------------
+// 문단 모양 정보
+// ---------------------------------------------------------------------------
+
+/// 문단 모양 목록 (`PARASHAPELIST`)
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct ParaShapeList {
+    /// 항목 개수 (`Count`)
+    #[serde(rename = "@Count", default)]
+    pub count: u32,
+
+    /// 문단 모양 (`PARASHAPE`)
+    #[serde(rename = "PARASHAPE", default)]
+    pub para_shapes: Vec<ParaShape>,
+}
+
+/// 문단 모양 (`PARASHAPE`)
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct ParaShape {
+    /// 아이디 (`Id`)
+    #[serde(rename = "@Id")]
+    pub id: u32,
+
+    /// 정렬 방식 (`Align`)
+    #[serde(rename = "@Align", default)]
+    pub align: Alignment,
+
+    /// 탭 정의 아이디 (`TabDef`)
+    #[serde(rename = "@TabDef", default, skip_serializing_if = "Option::is_none")]
+    pub tab_def: Option<u32>,
+
+    /// 외톨이줄 보호 (`WidowOrphan`)
+    #[serde(rename = "@WidowOrphan", default)]
+    pub widow_orphan: bool,
+
+    /// 다음 문단과 함께 (`KeepWithNext`)
+    #[serde(rename = "@KeepWithNext", default)]
+    pub keep_with_next: bool,
+
+    /// 문단 보호 (`KeepLines`)
+    #[serde(rename = "@KeepLines", default)]
+    pub keep_lines: bool,
+
+    /// 문단 앞에서 항상 쪽 나눔 (`PageBreakBefore`)
+    #[serde(rename = "@PageBreakBefore", default)]
+    pub page_break_before: bool,
+
+    /// 편집 용지의 줄 격자 사용 (`SnapToGrid`)
+    #[serde(rename = "@SnapToGrid", default)]
+    pub snap_to_grid: bool,
+
+    /// 한글과 영어 간격 자동 조절 (`AutoSpaceEAsianEng`)
+    #[serde(rename = "@AutoSpaceEAsianEng", default)]
+    pub auto_space_east_asian_english: bool,
+
+    /// 한글과 숫자 간격 자동 조절 (`AutoSpaceEAsianNum`)
+    #[serde(rename = "@AutoSpaceEAsianNum", default)]
+    pub auto_space_east_asian_number: bool,
+
+    /// 여백과 줄 간격 (`PARAMARGIN`)
+    #[serde(
+        rename = "PARAMARGIN",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub margin: Option<ParaMargin>,
+
+    /// 문단 테두리 (`PARABORDER`)
+    #[serde(
+        rename = "PARABORDER",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub border: Option<ParaBorder>,
+}
+
+/// 줄 간격 종류 (`PARAMARGIN`의 `LineSpacingType`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum LineSpacingType {
+    /// 글자에 따라 (%)
+    #[default]
+    Percent,
+    /// 고정 값
+    Fixed,
+    /// 여백만 지정
+    BetweenLines,
+    /// 최소
+    AtLeast,
+}
+
+/// 문단 여백과 줄 간격 (`PARAMARGIN`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct ParaMargin {
+    /// 들여쓰기/내어쓰기 (`Indent`)
+    #[serde(rename = "@Indent", default)]
+    pub indent: i32,
+
+    /// 왼쪽 여백 (`Left`)
+    #[serde(rename = "@Left", default)]
+    pub left: i32,
+
+    /// 오른쪽 여백 (`Right`)
+    #[serde(rename = "@Right", default)]
+    pub right: i32,
+
+    /// 문단 위 간격 (`Prev`)
+    #[serde(rename = "@Prev", default)]
+    pub prev: i32,
+
+    /// 문단 아래 간격 (`Next`)
+    #[serde(rename = "@Next", default)]
+    pub next: i32,
+
+    /// 줄 간격 종류 (`LineSpacingType`)
+    #[serde(rename = "@LineSpacingType", default)]
+    pub line_spacing_type: LineSpacingType,
+
+    /// 줄 간격 (`LineSpacing`, 종류가 `Percent`이면 %, 아니면 hwpunit)
+    #[serde(
+        rename = "@LineSpacing",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub line_spacing: Option<i32>,
+}
+
+/// 문단 테두리 (`PARABORDER`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct ParaBorder {
+    /// 테두리/배경 아이디 (`BorderFill`)
+    #[serde(
+        rename = "@BorderFill",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub border_fill: Option<u32>,
+}
+
+// ---------------------------------------------------------------------------
+// 스타일 정보
+// ---------------------------------------------------------------------------
+
+/// 스타일 목록 (`STYLELIST`)
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct StyleList {
+    /// 항목 개수 (`Count`)
+    #[serde(rename = "@Count", default)]
+    pub count: u32,
+
+    /// 스타일 (`STYLE`)
+    #[serde(rename = "STYLE", default)]
+    pub styles: Vec<Style>,
+}
+
+/// 스타일 종류 (`STYLE`의 `Type`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum StyleType {
+    /// 문단 스타일
+    #[default]
+    Para,
+    /// 글자 스타일
+    Char,
+}
+
+/// 스타일 (`STYLE`)
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct Style {
+    /// 아이디 (`Id`)
+    #[serde(rename = "@Id")]
+    pub id: u32,
+
+    /// 스타일 종류 (`Type`)
+    #[serde(rename = "@Type", default)]
+    pub style_type: StyleType,
+
+    /// 이름 (`Name`)
+    #[serde(rename = "@Name", default)]
+    pub name: String,
+
+    /// 영문 이름 (`EngName`)
+    #[serde(rename = "@EngName", default, skip_serializing_if = "Option::is_none")]
+    pub english_name: Option<String>,
+
+    /// 문단 모양 아이디 (`ParaShape`)
+    #[serde(
+        rename = "@ParaShape",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub para_shape: Option<u32>,
+
+    /// 글자 모양 아이디 (`CharShape`)
+    #[serde(
+        rename = "@CharShape",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub char_shape: Option<u32>,
+
+    /// 다음 스타일 아이디 (`NextStyle`)
+    #[serde(
+        rename = "@NextStyle",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub next_style: Option<u32>,
+
+    /// 언어 아이디 (`LangId`)
+    #[serde(rename = "@LangId", default, skip_serializing_if = "Option::is_none")]
+    pub language_id: Option<u16>,
+}
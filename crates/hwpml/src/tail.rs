@@ -0,0 +1,253 @@
+//! 부가 정보 엘리먼트 (`TAIL`)
+//!
+//! 문서에 포함된 바이너리 데이터(`BINDATASTORAGE`), 스크립트(`SCRIPTCODE`),
+//! XML 템플릿(`XMLTEMPLATE`)을 담습니다. HWPML 명세 6장.
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use miniz_oxide::inflate::{TINFLStatus, decompress_to_vec_with_limit};
+use primitive::{LimitKind, Limits};
+use serde::{Deserialize, Serialize};
+
+use crate::error::{Error, Result};
+
+/// 부가 정보 (`TAIL`)
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct Tail {
+    /// 바이너리 데이터 저장소 (`BINDATASTORAGE`)
+    #[serde(
+        rename = "BINDATASTORAGE",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub bin_data_storage: Option<BinDataStorage>,
+
+    /// 스크립트 코드 (`SCRIPTCODE`)
+    #[serde(
+        rename = "SCRIPTCODE",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub script_code: Option<ScriptCode>,
+
+    /// XML 템플릿 (`XMLTEMPLATE`)
+    #[serde(
+        rename = "XMLTEMPLATE",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub xml_template: Option<XmlTemplate>,
+}
+
+/// 바이너리 데이터 저장소 (`BINDATASTORAGE`)
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct BinDataStorage {
+    /// 바이너리 데이터 (`BINDATA`)
+    #[serde(rename = "BINDATA", default)]
+    pub items: Vec<BinData>,
+}
+
+/// 바이너리 데이터 (`BINDATA`)
+///
+/// 내용은 Base64로 인코딩되어 있고, `Compress`가 `true`(기본값)이면
+/// 인코딩 전에 HWP 스트림과 같은 raw deflate로 압축되어 있습니다.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct BinData {
+    /// 아이디 (`Id`, `BINITEM`의 `BinData`가 참조)
+    #[serde(rename = "@Id")]
+    pub id: String,
+
+    /// 원본 데이터 크기 (`Size`)
+    #[serde(rename = "@Size", default, skip_serializing_if = "Option::is_none")]
+    pub size: Option<u64>,
+
+    /// 인코딩 방식 (`Encoding`, `Base64`로 고정)
+    #[serde(rename = "@Encoding", default, skip_serializing_if = "Option::is_none")]
+    pub encoding: Option<String>,
+
+    /// 압축 여부 (`Compress`, 기본값 `true`)
+    #[serde(rename = "@Compress", default, skip_serializing_if = "Option::is_none")]
+    pub compress: Option<bool>,
+
+    /// Base64 인코딩된 내용
+    #[serde(rename = "$text", default)]
+    pub content: String,
+}
+
+impl BinData {
+    /// 데이터를 압축(선택)하고 Base64로 인코딩해 만듭니다.
+    pub fn encode(id: impl Into<String>, data: &[u8], compress: bool) -> Self {
+        let payload = if compress {
+            miniz_oxide::deflate::compress_to_vec(data, 6)
+        } else {
+            data.to_vec()
+        };
+        Self {
+            id: id.into(),
+            size: Some(data.len() as u64),
+            encoding: Some("Base64".to_string()),
+            compress: Some(compress),
+            content: STANDARD.encode(payload),
+        }
+    }
+
+    /// 압축 여부 (`Compress` 속성이 없으면 `true`)
+    pub fn is_compressed(&self) -> bool {
+        self.compress.unwrap_or(true)
+    }
+
+    /// Base64를 풀고, 압축되어 있으면 압축을 해제한 원본 데이터를 반환합니다.
+    ///
+    /// 기본 [`Limits`]를 적용합니다.
+    pub fn decode(&self) -> Result<Vec<u8>> {
+        self.decode_with_limits(&Limits::default())
+    }
+
+    /// [`decode`](Self::decode)와 같지만 `limits`를 적용합니다.
+    ///
+    /// 풀린 데이터가 [`Limits::max_binary_data_size`]를 넘으면 [`Error::LimitExceeded`]를,
+    /// Base64 디코딩이나 압축 해제에 실패하면 [`Error::InvalidBinaryData`]를 반환합니다.
+    pub fn decode_with_limits(&self, limits: &Limits) -> Result<Vec<u8>> {
+        let invalid = || Error::InvalidBinaryData {
+            id: self.id.clone(),
+        };
+        let compact: String = self.content.split_whitespace().collect();
+        let payload = STANDARD.decode(compact).map_err(|_| invalid())?;
+        if !self.is_compressed() {
+            limits.check(LimitKind::BinaryDataSize, payload.len() as u64)?;
+            return Ok(payload);
+        }
+
+        let limit = limits.max_binary_data_size;
+        let max_size = usize::try_from(limit).unwrap_or(usize::MAX);
+        decompress_to_vec_with_limit(&payload, max_size).map_err(|err| {
+            if err.status == TINFLStatus::HasMoreOutput {
+                Error::LimitExceeded {
+                    kind: LimitKind::BinaryDataSize,
+                    limit,
+                }
+            } else {
+                invalid()
+            }
+        })
+    }
+}
+
+/// 스크립트 코드 (`SCRIPTCODE`)
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct ScriptCode {
+    /// 스크립트 코드 종류 (`Type`, 기본값 `JScript`)
+    #[serde(rename = "@Type", default, skip_serializing_if = "Option::is_none")]
+    pub script_type: Option<String>,
+
+    /// 스크립트 코드 버전 (`Version`)
+    #[serde(rename = "@Version", default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+
+    /// 스크립트 헤더 (`SCRIPTHEADER`)
+    #[serde(
+        rename = "SCRIPTHEADER",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub header: Option<String>,
+
+    /// 스크립트 소스 (`SCRIPTSOURCE`)
+    #[serde(
+        rename = "SCRIPTSOURCE",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub source: Option<String>,
+
+    /// 문서를 열 때 실행하는 스크립트 (`PRESCRIPT`)
+    #[serde(rename = "PRESCRIPT", default, skip_serializing_if = "Option::is_none")]
+    pub pre_script: Option<String>,
+
+    /// 문서를 닫을 때 실행하는 스크립트 (`POSTSCRIPT`)
+    #[serde(
+        rename = "POSTSCRIPT",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub post_script: Option<String>,
+}
+
+/// XML 템플릿 (`XMLTEMPLATE`)
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct XmlTemplate {
+    /// XML 스키마 (`SCHEMA`)
+    #[serde(rename = "SCHEMA", default, skip_serializing_if = "Option::is_none")]
+    pub schema: Option<String>,
+
+    /// XML 인스턴스 (`INSTANCE`)
+    #[serde(rename = "INSTANCE", default, skip_serializing_if = "Option::is_none")]
+    pub instance: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bin_data_round_trip() {
+        let data = b"\x89PNG\r\n\x1a\nHWPML binary data".repeat(8);
+
+        for compress in [true, false] {
+            let bin_data = BinData::encode("1", &data, compress);
+            assert_eq!(bin_data.size, Some(data.len() as u64));
+            assert_eq!(bin_data.decode().unwrap(), data);
+        }
+    }
+
+    #[test]
+    fn test_bin_data_decode_wrapped_base64() {
+        let bin_data = BinData {
+            id: "1".to_string(),
+            compress: Some(false),
+            content: "SFdQ\r\nTUw=\n".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(bin_data.decode().unwrap(), b"HWPML");
+
+        let invalid = BinData {
+            content: "@@@".to_string(),
+            ..bin_data
+        };
+        assert!(matches!(
+            invalid.decode(),
+            Err(Error::InvalidBinaryData { id }) if id == "1"
+        ));
+    }
+
+    #[test]
+    fn test_bin_data_decode_errors() {
+        let data = vec![0u8; 4096];
+        let bin_data = BinData::encode("2", &data, true);
+        let limits = Limits::new().with_max_binary_data_size(1024);
+        assert!(matches!(
+            bin_data.decode_with_limits(&limits),
+            Err(Error::LimitExceeded {
+                kind: LimitKind::BinaryDataSize,
+                limit: 1024
+            })
+        ));
+        let bin_data = BinData::encode("2", &data, false);
+        assert!(matches!(
+            bin_data.decode_with_limits(&limits),
+            Err(Error::LimitExceeded { .. })
+        ));
+
+        // Compressed data that does not inflate is an error, not raw data
+        let bin_data = BinData {
+            id: "3".to_string(),
+            compress: Some(true),
+            content: STANDARD.encode([0xFF; 16]),
+            ..Default::default()
+        };
+        assert!(matches!(
+            bin_data.decode(),
+            Err(Error::InvalidBinaryData { id }) if id == "3"
+        ));
+    }
+}
@@ -0,0 +1,213 @@
+//! 기본 속성 값 형식
+//!
+//! HWPML 명세 2.2절 "기본 속성 값"에 정의된 열거형 속성 값입니다.
+//! 색은 `0x00bbggrr` 값을 십진수로 쓴 [`RgbColor`]로, 크기와 위치는 hwpunit(10 pt = 1000)으로 나타냅니다.
+
+use primitive::{Color, HwpUnit};
+use serde::{Deserialize, Serialize};
+
+/// 색 (`RGB-Color`, `0x00bbggrr`을 십진수로 표현)
+pub type RgbColor = u32;
+
+/// 색이 없음을 나타내는 값 (예: 음영색 `4294967295`)
+pub const NO_COLOR: RgbColor = u32::MAX;
+
+/// `0x00bbggrr` 색을 IR 색으로 변환
+pub const fn color_from_rgb(value: RgbColor) -> Color {
+    Color::rgb(
+        (value & 0xFF) as u8,
+        ((value >> 8) & 0xFF) as u8,
+        ((value >> 16) & 0xFF) as u8,
+    )
+}
+
+/// IR 색을 `0x00bbggrr` 색으로 변환
+pub const fn color_to_rgb(color: Color) -> RgbColor {
+    (color.red as u32) | ((color.green as u32) << 8) | ((color.blue as u32) << 16)
+}
+
+/// 언어 종류 (`LangType`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum LangType {
+    /// 한글
+    #[default]
+    Hangul,
+    /// 영어
+    Latin,
+    /// 한자
+    Hanja,
+    /// 일본어
+    Japanese,
+    /// 기타
+    Other,
+    /// 심볼
+    Symbol,
+    /// 사용자
+    User,
+}
+
+impl LangType {
+    /// 명세 순서대로 나열한 언어 종류
+    pub const ALL: [LangType; 7] = [
+        LangType::Hangul,
+        LangType::Latin,
+        LangType::Hanja,
+        LangType::Japanese,
+        LangType::Other,
+        LangType::Symbol,
+        LangType::User,
+    ];
+}
+
+/// 글꼴 유형 (`FontType`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum FontType {
+    /// 대표 글꼴
+    #[default]
+    #[serde(rename = "rep")]
+    Representative,
+    /// 트루타입 글꼴
+    #[serde(rename = "ttf")]
+    TrueType,
+    /// 한글 전용 글꼴
+    #[serde(rename = "hft")]
+    HangulOnly,
+}
+
+/// 선 종류 (`LineType1`, `LineType2`는 `None`을 쓰지 않음)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum LineType {
+    /// 실선
+    #[default]
+    Solid,
+    /// 긴 점선
+    Dash,
+    /// 점선
+    Dot,
+    /// -.-.-.-.-
+    DashDot,
+    /// -..-..-..-
+    DashDotDot,
+    /// Dash보다 긴 선분의 반복
+    LongDash,
+    /// Dot보다 큰 동그라미의 반복
+    Circle,
+    /// 2중선
+    DoubleSlim,
+    /// 가는 선 + 굵은 선 2중선
+    SlimThick,
+    /// 굵은 선 + 가는 선 2중선
+    ThickSlim,
+    /// 가는 선 + 굵은 선 + 가는 선 3중선
+    SlimThickSlim,
+    /// 선 없음
+    None,
+}
+
+/// 외곽선 종류 (`LineType3`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum OutlineLineType {
+    /// 실선
+    #[default]
+    Solid,
+    /// 점선
+    Dot,
+    /// 두꺼운 선
+    Thick,
+    /// 긴 점선
+    Dash,
+    /// -.-.-.-.-
+    DashDot,
+    /// -..-..-..-
+    DashDotDot,
+}
+
+/// 정렬 방식 (`AlignmentType1`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Alignment {
+    /// 양쪽 정렬
+    #[default]
+    Justify,
+    /// 왼쪽 정렬
+    Left,
+    /// 오른쪽 정렬
+    Right,
+    /// 가운데 정렬
+    Center,
+    /// 배분 정렬
+    Distribute,
+    /// 나눔 정렬 (공백에만 배분)
+    DistributeSpace,
+}
+
+/// 무늬 종류 (`HatchStyle`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum HatchStyle {
+    /// `- - - -`
+    Horizontal,
+    /// `|||||`
+    Vertical,
+    /// `\\\\\`
+    BackSlash,
+    /// `/////`
+    Slash,
+    /// `+++++`
+    Cross,
+    /// `xxxxx`
+    CrossDiagonal,
+}
+
+/// 선 두께 (`LineWidth`, 예: `0.12mm`)를 hwpunit으로 변환
+pub fn parse_line_width(width: &str) -> Option<HwpUnit> {
+    let mm: f64 = width.trim().trim_end_matches("mm").trim().parse().ok()?;
+    Some(HwpUnit::from_mm(mm))
+}
+
+/// hwpunit을 가장 가까운 선 두께 (`LineWidth`)로 변환
+pub fn format_line_width(width: HwpUnit) -> &'static str {
+    const WIDTHS: [(&str, f64); 16] = [
+        ("0.1mm", 0.1),
+        ("0.12mm", 0.12),
+        ("0.15mm", 0.15),
+        ("0.2mm", 0.2),
+        ("0.25mm", 0.25),
+        ("0.3mm", 0.3),
+        ("0.4mm", 0.4),
+        ("0.5mm", 0.5),
+        ("0.6mm", 0.6),
+        ("0.7mm", 0.7),
+        ("1.0mm", 1.0),
+        ("1.5mm", 1.5),
+        ("2.0mm", 2.0),
+        ("3.0mm", 3.0),
+        ("4.0mm", 4.0),
+        ("5.0mm", 5.0),
+    ];
+    let mm = width.to_mm();
+    WIDTHS
+        .iter()
+        .min_by(|a, b| (a.1 - mm).abs().total_cmp(&(b.1 - mm).abs()))
+        .map(|(name, _)| *name)
+        .unwrap_or("0.12mm")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_color_is_bgr() {
+        let color = color_from_rgb(0x00FF8000);
+        assert_eq!((color.red, color.green, color.blue), (0x00, 0x80, 0xFF));
+        assert_eq!(color_to_rgb(color), 0x00FF8000);
+    }
+
+    #[test]
+    fn test_line_width() {
+        let width = parse_line_width("0.12mm").unwrap();
+        assert_eq!(width, HwpUnit::from_mm(0.12));
+        assert_eq!(format_line_width(width), "0.12mm");
+        assert_eq!(format_line_width(HwpUnit::from_mm(4.8)), "5.0mm");
+        assert_eq!(parse_line_width("thin"), None);
+    }
+}
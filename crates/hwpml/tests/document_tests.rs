@@ -0,0 +1,622 @@
+//! HWPML 문서 읽기/쓰기와 IR 변환 테스트
+//!
+//! - `.hml` 파싱 테스트
+//! - HWPML → IR 변환 테스트
+//! - IR → HWPML → IR 왕복 테스트
+
+use hwpml::Document;
+use hwpml::body::{CharItem, RawNode, TextItem};
+use hwpml::convert::{HwpmlToIr, IrToHwpml};
+use hwpml::tail::BinData;
+use ir::control::Control;
+use ir::table::TablePageBreak;
+use ir::{BinaryData, BinaryDataId, BinaryFormat, RunContent};
+use primitive::{
+    Color, EquationFormat, HwpUnit, ImageEffect, LanguageType, PageOrientation, StrikethroughType,
+    TextWrapType, VerticalAlignment,
+};
+
+const PNG: &[u8] = b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR";
+
+fn sample() -> String {
+    let bin_data = BinData::encode("1", PNG, true);
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8" standalone="no" ?>
+<HWPML Version="2.8" SubVersion="8.0.0.0" Style="embed">
+  <HEAD SecCnt="1">
+    <DOCSUMMARY>
+      <TITLE>제목</TITLE>
+      <AUTHOR>작성자</AUTHOR>
+      <KEYWORDS>한글, HWPML</KEYWORDS>
+    </DOCSUMMARY>
+    <DOCSETTING>
+      <BEGINNUMBER Page="3" Footnote="1" Endnote="1" Picture="1" Table="1" Equation="1"/>
+      <CARETPOS List="0" Para="1" Pos="2"/>
+    </DOCSETTING>
+    <MAPPINGTABLE>
+      <BINDATALIST Count="1">
+        <BINITEM Type="Embedding" BinData="1" Format="png"/>
+      </BINDATALIST>
+      <FACENAMELIST>
+        <FONTFACE Lang="Hangul" Count="2">
+          <FONT Id="0" Type="ttf" Name="함초롬바탕">
+            <TYPEINFO FamilyType="2" SerifStyle="0" Weight="6" Proportion="4" Contrast="0" StrokeVariation="1" ArmStyle="1" Letterform="1" Midline="1" XHeight="1"/>
+          </FONT>
+          <FONT Id="1" Type="ttf" Name="함초롬돋움"/>
+        </FONTFACE>
+        <FONTFACE Lang="Latin" Count="1">
+          <FONT Id="0" Type="ttf" Name="Arial"/>
+        </FONTFACE>
+      </FACENAMELIST>
+      <BORDERFILLLIST Count="1">
+        <BORDERFILL Id="1" ThreeD="false" Shadow="false" Slash="0" BackSlash="0">
+          <LEFTBORDER Type="Solid" Width="0.4mm" Color="0"/>
+          <RIGHTBORDER Type="None" Width="0.1mm" Color="0"/>
+          <TOPBORDER Type="None" Width="0.1mm" Color="0"/>
+          <BOTTOMBORDER Type="DoubleSlim" Width="0.5mm" Color="255"/>
+          <FILLBRUSH>
+            <WINDOWBRUSH FaceColor="16711680" HatchColor="0" Alpha="0"/>
+          </FILLBRUSH>
+        </BORDERFILL>
+      </BORDERFILLLIST>
+      <CHARSHAPELIST Count="1">
+        <CHARSHAPE Id="0" Height="1200" TextColor="255" ShadeColor="4294967295" UseFontSpace="false" UseKerning="false" SymMark="1">
+          <FONTID Hangul="1" Latin="0" Hanja="0" Japanese="0" Other="0" Symbol="0" User="0"/>
+          <RATIO Hangul="90" Latin="100" Hanja="100" Japanese="100" Other="100" Symbol="100" User="100"/>
+          <CHARSPACING Hangul="-5" Latin="0" Hanja="0" Japanese="0" Other="0" Symbol="0" User="0"/>
+          <RELSIZE Hangul="100" Latin="100" Hanja="100" Japanese="100" Other="100" Symbol="100" User="100"/>
+          <CHAROFFSET Hangul="0" Latin="0" Hanja="0" Japanese="0" Other="0" Symbol="0" User="0"/>
+          <BOLD/>
+          <STRIKEOUT Type="Continuous" Shape="DoubleSlim" Color="0"/>
+        </CHARSHAPE>
+      </CHARSHAPELIST>
+      <TABDEFLIST Count="1">
+        <TABDEF Id="0" AutoTabLeft="false" AutoTabRight="true">
+          <TABITEM Pos="4000" Type="Left" Leader="Dot"/>
+        </TABDEF>
+      </TABDEFLIST>
+      <PARASHAPELIST Count="1">
+        <PARASHAPE Id="0" Align="Center" KeepWithNext="true">
+          <PARAMARGIN Indent="-2000" Left="1000" Right="0" Prev="0" Next="600" LineSpacingType="Percent" LineSpacing="180"/>
+          <PARABORDER BorderFill="1"/>
+        </PARASHAPE>
+      </PARASHAPELIST>
+      <STYLELIST Count="1">
+        <STYLE Id="0" Type="Para" Name="바탕글" EngName="Normal" ParaShape="0" CharShape="0" NextStyle="0"/>
+      </STYLELIST>
+      <MEMOSHAPELIST Count="1">
+        <MEMOSHAPE Id="0" Width="15591" LineType="Solid" LineColor="11842740"/>
+      </MEMOSHAPELIST>
+    </MAPPINGTABLE>
+  </HEAD>
+  <BODY>
+    <SECTION Id="0">
+      <P ParaShape="0" Style="0">
+        <TEXT CharShape="0">
+          <SECDEF SpaceColumns="1134" TabStop="8000">
+            <STARTNUMBER PageStartsOn="Both" Page="0" Figure="0" Table="0" Equation="0"/>
+            <PAGEDEF Landscape="1" Width="84188" Height="59528" GutterType="LeftOnly">
+              <PAGEMARGIN Left="8504" Right="8504" Top="5668" Bottom="4252" Header="4252" Footer="4252" Gutter="0"/>
+            </PAGEDEF>
+          </SECDEF>
+          <COLDEF Type="Newspaper" Count="2" Layout="Left" SameSize="true" SameGap="1134"/>
+        </TEXT>
+        <TEXT CharShape="0"><CHAR>첫 문단<TAB/>탭 뒤</CHAR></TEXT>
+      </P>
+      <P ParaShape="0" Style="0" PageBreak="true">
+        <TEXT CharShape="0">
+          <LINE X1="0" X2="100"/>
+          <CHAR>둘째 문단<LINEBREAK/>줄 바꿈</CHAR>
+        </TEXT>
+      </P>
+    </SECTION>
+  </BODY>
+  <TAIL>
+    <BINDATASTORAGE>
+      <BINDATA Id="1" Size="{size}" Encoding="Base64" Compress="true">{content}</BINDATA>
+    </BINDATASTORAGE>
+    <SCRIPTCODE Type="JScript" Version="1.0">
+      <SCRIPTSOURCE>function OnDocument_New() {{}}</SCRIPTSOURCE>
+    </SCRIPTCODE>
+  </TAIL>
+</HWPML>"#,
+        size = PNG.len(),
+        content = bin_data.content,
+    )
+}
+
+/// 표, 그림, 수식이 들어 있는 문서
+fn controls() -> String {
+    let bin_data = BinData::encode("1", PNG, false);
+    format!(
+        r#"<HWPML Version="2.8">
+<HEAD SecCnt="1"><MAPPINGTABLE>
+  <BINDATALIST Count="1"><BINITEM Type="Embedding" BinData="1" Format="png"/></BINDATALIST>
+</MAPPINGTABLE></HEAD>
+<BODY><SECTION Id="0">
+  <P ParaShape="0" Style="0"><TEXT CharShape="0">
+    <CHAR>표 앞</CHAR>
+    <TABLE PageBreak="Cell" RepeatHeader="true" RowCount="2" ColCount="2" CellSpacing="0" BorderFill="1">
+      <SHAPEOBJECT InstId="10" ZOrder="1" NumberingType="Table" TextWrap="TopAndBottom">
+        <SIZE Width="8000" WidthRelTo="Absolute" Height="2000" HeightRelTo="Absolute"/>
+        <POSITION TreatAsChar="true" VertRelTo="Para" VertAlign="Top" HorzRelTo="Para" HorzAlign="Left" VertOffset="0" HorzOffset="0"/>
+        <OUTSIDEMARGIN Left="283" Right="283" Top="283" Bottom="283"/>
+      </SHAPEOBJECT>
+      <INSIDEMARGIN Left="510" Right="510" Top="141" Bottom="141"/>
+      <ROW>
+        <CELL ColAddr="0" RowAddr="0" ColSpan="2" RowSpan="1" Width="8000" Height="1000" Header="true" BorderFill="1">
+          <CELLMARGIN Left="510" Right="510" Top="141" Bottom="141"/>
+          <PARALIST VertAlign="Center"><P ParaShape="0" Style="0"><TEXT CharShape="0"><CHAR>머리</CHAR></TEXT></P></PARALIST>
+        </CELL>
+      </ROW>
+      <ROW>
+        <CELL ColAddr="0" RowAddr="1" Width="4000" Height="1000"><PARALIST><P ParaShape="0" Style="0"><TEXT CharShape="0"><CHAR>가</CHAR></TEXT></P></PARALIST></CELL>
+        <CELL ColAddr="1" RowAddr="1" Width="4000" Height="1000"><PARALIST><P ParaShape="0" Style="0"><TEXT CharShape="0"><CHAR>나</CHAR></TEXT></P></PARALIST></CELL>
+      </ROW>
+    </TABLE>
+    <PICTURE Reverse="false">
+      <SHAPEOBJECT InstId="11" TextWrap="Square">
+        <SIZE Width="2000" Height="3000"/>
+        <POSITION TreatAsChar="false" VertRelTo="Para" VertAlign="Top" HorzRelTo="Column" HorzAlign="Left" VertOffset="100" HorzOffset="200"/>
+      </SHAPEOBJECT>
+      <IMAGE Bright="10" Contrast="-5" Effect="GrayScale" BinItem="1" Alpha="51"/>
+    </PICTURE>
+    <EQUATION LineMode="true" BaseUnit="1200" TextColor="255" BaseLine="85" Version="Equation Version 60">
+      <SHAPEOBJECT InstId="12"><SIZE Width="1500" Height="1000"/></SHAPEOBJECT>
+      <SCRIPT>a over b</SCRIPT>
+    </EQUATION>
+  </TEXT></P>
+</SECTION></BODY>
+<TAIL><BINDATASTORAGE><BINDATA Id="1" Size="{size}" Encoding="Base64" Compress="false">{content}</BINDATA></BINDATASTORAGE></TAIL>
+</HWPML>"#,
+        size = PNG.len(),
+        content = bin_data.content,
+    )
+}
+
+mod parse_tests {
+    use super::*;
+
+    #[test]
+    fn parse_head() {
+        let document = Document::from_str(&sample()).unwrap();
+        assert_eq!(document.version.as_deref(), Some("2.8"));
+
+        let summary = document.head.summary.as_ref().unwrap();
+        assert_eq!(summary.title.as_deref(), Some("제목"));
+        assert_eq!(summary.keywords.as_deref(), Some("한글, HWPML"));
+
+        let table = document.head.mapping_table.as_ref().unwrap();
+        let faces = &table.face_name_list.as_ref().unwrap().font_faces;
+        assert_eq!(faces.len(), 2);
+        assert_eq!(faces[0].fonts[1].name, "함초롬돋움");
+
+        let char_shape = &table.char_shape_list.as_ref().unwrap().char_shapes[0];
+        assert_eq!(char_shape.height, Some(1200));
+        assert!(char_shape.bold.is_some());
+        assert!(char_shape.italic.is_none());
+        assert_eq!(char_shape.font_id.unwrap().hangul, 1);
+
+        // 구조를 모르는 목록은 그대로 보관
+        let tab_defs = table.tab_def_list.as_ref().unwrap();
+        assert_eq!(tab_defs.name, "TABDEFLIST");
+        let RawNode::Element(tab_def) = &tab_defs.children[0] else {
+            panic!("TABDEF가 아닙니다");
+        };
+        assert_eq!(tab_def.name, "TABDEF");
+        assert_eq!(tab_def.children.len(), 1);
+        assert!(table.numbering_list.is_none());
+        assert_eq!(
+            table.memo_shape_list.as_ref().unwrap().name,
+            "MEMOSHAPELIST"
+        );
+    }
+
+    #[test]
+    fn parse_body() {
+        let document = Document::from_str(&sample()).unwrap();
+        let section = &document.body.sections[0];
+        assert_eq!(section.paragraphs.len(), 2);
+
+        let first = &section.paragraphs[0].texts[0].items;
+        assert!(matches!(&first[0], TextItem::SectionDef(def)
+            if def.page_def.unwrap().width == 84188));
+        assert!(matches!(&first[1], TextItem::ColumnDef(def) if def.count == 2));
+
+        let TextItem::Char(ch) = &section.paragraphs[0].texts[1].items[0] else {
+            panic!("CHAR가 아닙니다");
+        };
+        assert_eq!(
+            ch.items,
+            vec![
+                CharItem::Text("첫 문단".to_string()),
+                CharItem::Tab,
+                CharItem::Text("탭 뒤".to_string()),
+            ]
+        );
+
+        // 모델에 없는 컨트롤은 Unknown으로 남김
+        let TextItem::Unknown(line) = &section.paragraphs[1].texts[0].items[0] else {
+            panic!("Unknown이 아닙니다");
+        };
+        assert_eq!(line.name, "LINE");
+        assert_eq!(
+            line.attributes,
+            vec![
+                ("X1".to_string(), "0".to_string()),
+                ("X2".to_string(), "100".to_string()),
+            ]
+        );
+        assert_eq!(
+            document.extract_text(),
+            "첫 문단\t탭 뒤\n둘째 문단\n줄 바꿈"
+        );
+    }
+
+    #[test]
+    fn parse_controls() {
+        let document = Document::from_str(&controls()).unwrap();
+        let items = &document.body.sections[0].paragraphs[0].texts[0].items;
+        assert_eq!(items.len(), 4);
+
+        let TextItem::Table(table) = &items[1] else {
+            panic!("TABLE이 아닙니다");
+        };
+        assert_eq!((table.row_count, table.col_count), (2, 2));
+        assert_eq!(table.rows.len(), 2);
+        assert_eq!(table.rows[0].cells[0].col_span, Some(2));
+        assert_eq!(table.rows[1].cells[1].para_list.paragraphs.len(), 1);
+        let shape_object = table.shape_object.as_ref().unwrap();
+        assert!(shape_object.position.as_ref().unwrap().treat_as_char);
+        assert_eq!(shape_object.size.as_ref().unwrap().width, 8000);
+
+        let TextItem::Picture(picture) = &items[2] else {
+            panic!("PICTURE가 아닙니다");
+        };
+        assert_eq!(picture.image.bin_item, Some(1));
+        assert_eq!(picture.image.alpha, Some(51));
+
+        let TextItem::Equation(equation) = &items[3] else {
+            panic!("EQUATION이 아닙니다");
+        };
+        assert_eq!(equation.script, "a over b");
+        assert_eq!(equation.line_mode, Some(true));
+    }
+
+    #[test]
+    fn parse_tail() {
+        let document = Document::from_str(&sample()).unwrap();
+        assert_eq!(document.bin_items().len(), 1);
+        let bin_data = document.bin_data_by_id("1").unwrap();
+        assert_eq!(bin_data.decode().unwrap(), PNG);
+
+        let script = document
+            .tail
+            .as_ref()
+            .unwrap()
+            .script_code
+            .as_ref()
+            .unwrap();
+        assert_eq!(
+            script.source.as_deref(),
+            Some("function OnDocument_New() {}")
+        );
+    }
+
+    #[test]
+    fn write_and_read_again() {
+        let document = Document::from_str(&sample()).unwrap();
+        let bytes = document.to_bytes().unwrap();
+        let xml = std::str::from_utf8(&bytes).unwrap();
+        let tab_def_list = xml.find("<TABDEFLIST").unwrap();
+        assert!(xml.find("</CHARSHAPELIST>").unwrap() < tab_def_list);
+        assert!(tab_def_list < xml.find("<PARASHAPELIST").unwrap());
+        let parsed = Document::from_bytes(&bytes).unwrap();
+        assert_eq!(parsed, document);
+    }
+
+    #[test]
+    fn write_table_and_picture() {
+        let xml = r#"<HWPML Version="2.8"><HEAD SecCnt="1"/><BODY><SECTION Id="0">
+  <P><TEXT CharShape="0">
+    <TABLE RowCount="1" ColCount="2" BorderFill="1">
+      <SHAPEOBJECT InstId="10"><SIZE Width="4000" Height="1000"/></SHAPEOBJECT>
+      <ROW>
+        <CELL ColAddr="0" RowAddr="0"><PARALIST><P><TEXT CharShape="0"><CHAR>가 &amp; 나</CHAR></TEXT></P></PARALIST></CELL>
+        <CELL ColAddr="1" RowAddr="0"><PARALIST><P><TEXT CharShape="0"><CHAR>다</CHAR></TEXT></P></PARALIST></CELL>
+      </ROW>
+    </TABLE>
+    <CHAR>표 뒤</CHAR>
+    <PICTURE Reverse="false">
+      <SHAPEOBJECT InstId="11"><SIZE Width="2000" Height="2000"/></SHAPEOBJECT>
+      <IMAGE BinItem="1" Bright="0" Contrast="0" Effect="RealPic"/>
+    </PICTURE>
+  </TEXT></P>
+</SECTION></BODY></HWPML>"#;
+        let document = Document::from_str(xml).unwrap();
+        let items = &document.body.sections[0].paragraphs[0].texts[0].items;
+        assert!(matches!(
+            items.as_slice(),
+            [TextItem::Table(_), TextItem::Char(_), TextItem::Picture(_)]
+        ));
+
+        let bytes = document.to_bytes().unwrap();
+        let xml = std::str::from_utf8(&bytes).unwrap();
+        assert!(xml.contains(r#"<TABLE RowCount="1" ColCount="2" BorderFill="1">"#));
+        assert!(xml.contains("<CHAR>가 &amp; 나</CHAR>"));
+        assert!(xml.contains(r#"<IMAGE Bright="0" Contrast="0" Effect="RealPic" BinItem="1"/>"#));
+
+        let parsed = Document::from_bytes(&bytes).unwrap();
+        assert_eq!(parsed, document);
+    }
+}
+
+mod to_ir_tests {
+    use super::*;
+
+    #[test]
+    fn convert_metadata_and_settings() {
+        let document = Document::from_str(&sample()).unwrap();
+        let result = document.to_ir().unwrap();
+        let ir = result.value;
+
+        assert_eq!(ir.metadata.title.as_deref(), Some("제목"));
+        assert_eq!(ir.metadata.keywords, vec!["한글", "HWPML"]);
+        assert_eq!(ir.settings.starting_page_number, 3);
+        let caret = ir.settings.caret_position.unwrap();
+        assert_eq!((caret.paragraph, caret.position), (1, 2));
+        // LINE 컨트롤 손실 경고
+        assert_eq!(result.warnings.len(), 1);
+    }
+
+    #[test]
+    fn convert_styles() {
+        let ir = Document::from_str(&sample())
+            .unwrap()
+            .to_ir()
+            .unwrap()
+            .value;
+        let styles = &ir.styles;
+
+        assert_eq!(styles.fonts.len(), 3);
+        assert_eq!(styles.fonts[2].name, "Arial");
+        assert_eq!(styles.fonts[2].language, Some(LanguageType::English));
+        assert!(styles.fonts[0].panose.is_some());
+
+        let char_shape = &styles.char_shapes[0];
+        assert_eq!(char_shape.font_size, HwpUnit::new(1200));
+        assert_eq!(char_shape.color, Color::rgb(255, 0, 0));
+        assert!(char_shape.bold);
+        assert_eq!(char_shape.strikethrough, StrikethroughType::Double);
+        assert_eq!(char_shape.shade_color, None);
+        assert_eq!(char_shape.fonts.korean.as_ref().unwrap().id.value(), 1);
+        assert_eq!(char_shape.char_scale.0, 90.0);
+
+        let para_shape = &styles.para_shapes[0];
+        assert_eq!(para_shape.alignment, primitive::Alignment::Center);
+        assert_eq!(para_shape.first_line_indent, HwpUnit::new(-2000));
+        assert!(para_shape.keep_with_next);
+        assert_eq!(para_shape.border_fill_id.unwrap().value(), 1);
+
+        let border_fill = &styles.border_fills[0];
+        assert_eq!(border_fill.left.width, HwpUnit::from_mm(0.4));
+        assert_eq!(border_fill.bottom.line_type, primitive::LineType::Double);
+        assert!(
+            matches!(&border_fill.fill, ir::border_fill::Fill::Solid(solid)
+            if solid.color == Color::rgb(0, 0, 255))
+        );
+
+        assert_eq!(styles.styles[0].english_name.as_deref(), Some("Normal"));
+    }
+
+    #[test]
+    fn convert_sections() {
+        let ir = Document::from_str(&sample())
+            .unwrap()
+            .to_ir()
+            .unwrap()
+            .value;
+        let section = &ir.sections[0];
+
+        assert_eq!(section.page.width, HwpUnit::new(84188));
+        assert_eq!(section.page.orientation, PageOrientation::Landscape);
+        assert_eq!(section.page.margins.top, HwpUnit::new(5668));
+        assert_eq!(section.columns.count, 2);
+
+        assert_eq!(section.paragraphs.len(), 2);
+        let first = &section.paragraphs[0];
+        assert_eq!(first.runs.len(), 1);
+        assert!(matches!(first.runs[0].contents[1], RunContent::Tab(_)));
+        assert_eq!(first.to_plain_text(), "첫 문단탭 뒤");
+        assert_eq!(section.paragraphs[1].break_type, primitive::BreakType::Page);
+    }
+
+    #[test]
+    fn convert_controls() {
+        let result = Document::from_str(&controls()).unwrap().to_ir().unwrap();
+        assert!(result.warnings.is_empty(), "{:?}", result.warnings);
+        let paragraph = &result.value.sections[0].paragraphs[0];
+        let controls: Vec<_> = paragraph.runs[0]
+            .contents
+            .iter()
+            .filter_map(|content| match content {
+                RunContent::Control(control) => Some(control.as_ref()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(controls.len(), 3);
+
+        let Control::Table(table) = controls[0] else {
+            panic!("표가 아닙니다");
+        };
+        assert_eq!((table.row_count, table.column_count), (2, 2));
+        assert_eq!(table.page_break, TablePageBreak::Cell);
+        assert!(table.repeat_header);
+        assert_eq!(table.header_row_count, 1);
+        assert_eq!(table.common.text_wrap.wrap_type, TextWrapType::Inline);
+        assert_eq!(table.common.size.width, HwpUnit::new(8000));
+        let head = &table.rows[0].cells[0];
+        assert_eq!(head.column_span, 2);
+        assert_eq!(head.vertical_alignment, VerticalAlignment::Middle);
+        assert_eq!(head.paragraphs[0].to_plain_text(), "머리");
+        assert_eq!(table.rows[1].cells[1].paragraphs[0].to_plain_text(), "나");
+
+        let Control::Picture(picture) = controls[1] else {
+            panic!("그림이 아닙니다");
+        };
+        assert_eq!(picture.binary_id, BinaryDataId::from_numeric(1));
+        assert_eq!(picture.effect, ImageEffect::Grayscale);
+        assert_eq!((picture.brightness, picture.contrast), (10, -5));
+        assert!((picture.alpha - 0.8).abs() < 1e-9);
+        assert_eq!(picture.common.text_wrap.wrap_type, TextWrapType::Square);
+        assert_eq!(picture.common.position.x, HwpUnit::new(200));
+
+        let Control::Equation(equation) = controls[2] else {
+            panic!("수식이 아닙니다");
+        };
+        assert_eq!(equation.script, "a over b");
+        assert_eq!(equation.font_size, HwpUnit::new(1200));
+        assert_eq!(equation.color, Some(Color::rgb(255, 0, 0)));
+        assert_eq!(equation.properties, Some(1));
+    }
+
+    #[test]
+    fn convert_binary_data_and_scripts() {
+        let ir = Document::from_str(&sample())
+            .unwrap()
+            .to_ir()
+            .unwrap()
+            .value;
+
+        let binary = ir.binary_data.get(&BinaryDataId::from_numeric(1)).unwrap();
+        assert_eq!(binary.format, BinaryFormat::Png);
+        assert_eq!(binary.data, PNG);
+        assert!(binary.compressed);
+
+        let scripts = ir.extensions.hwp.unwrap().scripts.unwrap();
+        assert_eq!((scripts.version_high, scripts.version_low), (1, 0));
+        assert_eq!(scripts.source, "function OnDocument_New() {}");
+    }
+}
+
+mod round_trip_tests {
+    use super::*;
+
+    #[test]
+    fn hwpml_ir_round_trip() {
+        let ir = Document::from_str(&sample())
+            .unwrap()
+            .to_ir()
+            .unwrap()
+            .value;
+        let hml = ir.to_hwpml().unwrap().value;
+        let xml = hml.to_xml_string().unwrap();
+        let again = Document::from_str(&xml).unwrap().to_ir().unwrap().value;
+
+        assert_eq!(again.metadata.title, ir.metadata.title);
+        assert_eq!(again.metadata.keywords, ir.metadata.keywords);
+        assert_eq!(again.styles.fonts.len(), 7 + 1);
+        assert_eq!(again.styles.char_shapes.len(), 1);
+        assert_eq!(again.styles.char_shapes[0].color, Color::rgb(255, 0, 0));
+        assert_eq!(
+            again.styles.char_shapes[0].strikethrough,
+            StrikethroughType::Double
+        );
+        assert_eq!(again.sections[0].page.width, HwpUnit::new(84188));
+        assert_eq!(again.sections[0].columns.count, 2);
+        assert_eq!(again.sections[0].paragraphs.len(), 2);
+        assert_eq!(again.to_plain_text(), ir.to_plain_text());
+        assert_eq!(
+            again
+                .binary_data
+                .get(&BinaryDataId::from_numeric(1))
+                .unwrap()
+                .data,
+            PNG
+        );
+    }
+
+    #[test]
+    fn controls_round_trip() {
+        let ir = Document::from_str(&controls())
+            .unwrap()
+            .to_ir()
+            .unwrap()
+            .value;
+        let result = ir.to_hwpml().unwrap();
+        assert!(result.warnings.is_empty());
+        let xml = result.value.to_xml_string().unwrap();
+        let again = Document::from_str(&xml).unwrap().to_ir().unwrap().value;
+
+        // IR 컨트롤은 PartialEq가 없으므로 Debug 출력으로 비교
+        let controls = |document: &ir::Document| -> Vec<String> {
+            document.sections[0].paragraphs[0].runs[0]
+                .contents
+                .iter()
+                .filter_map(|content| match content {
+                    RunContent::Control(control) => Some(format!("{control:?}")),
+                    _ => None,
+                })
+                .collect()
+        };
+        assert_eq!(controls(&again), controls(&ir));
+        assert_eq!(again.to_plain_text(), ir.to_plain_text());
+    }
+
+    #[test]
+    fn unsupported_controls_are_reported() {
+        // 한글 수식 스크립트가 아닌 수식은 EQUATION으로 쓸 수 없음
+        let equation = ir::control::Equation {
+            common: Default::default(),
+            script: r"\frac{a}{b}".to_string(),
+            format: EquationFormat::LaTeX,
+            baseline_offset: HwpUnit::ZERO,
+            font_size: HwpUnit::new(1000),
+            color: None,
+            line_mode: None,
+            version: None,
+            font_name: None,
+            properties: None,
+        };
+        let mut paragraph = ir::Paragraph::with_text("수식");
+        paragraph
+            .runs
+            .push(ir::Run::control(Control::Equation(Box::new(equation))));
+        let mut section = ir::Section::new();
+        section.add_paragraph(paragraph);
+        let mut ir = ir::Document::new();
+        ir.add_section(section);
+
+        let result = ir.to_hwpml().unwrap();
+        assert_eq!(result.warnings.len(), 1);
+        assert_eq!(result.value.extract_text(), "수식");
+    }
+
+    #[test]
+    fn ir_document_to_hwpml() {
+        let mut ir = ir::Document::new();
+        let mut section = ir::Section::new();
+        section.add_paragraph(ir::Paragraph::with_text("안녕하세요"));
+        ir.add_section(section);
+        ir.binary_data.add(
+            BinaryDataId::from_numeric(1),
+            BinaryData::new(BinaryFormat::Png, PNG.to_vec()),
+        );
+
+        let result = ir.to_hwpml().unwrap();
+        let document = result.value;
+        assert_eq!(document.head.section_count, Some(1));
+        assert_eq!(document.bin_items()[0].format.as_deref(), Some("png"));
+        assert_eq!(document.bin_data()[0].decode().unwrap(), PNG);
+        // 구역 정의는 첫 문단의 첫 TEXT에 들어감
+        assert!(matches!(
+            document.body.sections[0].paragraphs[0].texts[0].items[0],
+            TextItem::SectionDef(_)
+        ));
+        assert_eq!(document.extract_text(), "안녕하세요");
+
+        let bytes = document.to_bytes().unwrap();
+        let again = Document::from_bytes(&bytes).unwrap().to_ir().unwrap().value;
+        assert_eq!(again.to_plain_text(), "안녕하세요");
+    }
+}
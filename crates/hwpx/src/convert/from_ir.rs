@@ -4,6 +4,11 @@
 //! 스타일 정보(폰트, 글자 모양, 문단 모양, 스타일)를 모두 HWPX로 변환합니다.
 
 use crate::Document as HwpxDocument;
+use crate::package::{
+    self, BIN_DATA_DIR, META_CREATED_DATE, META_CREATOR, META_DESCRIPTION, META_KEYWORD,
+    META_LAST_SAVED_BY, META_MODIFIED_DATE, META_SUBJECT, ManifestItem,
+    Metadata as PackageMetadata,
+};
 use crate::core::enums::{LineStyleType1, LineStyleType2};
 use crate::core::types::BeginIdRef;
 use crate::core::types::RgbColor;
@@ -58,6 +63,8 @@ use crate::paragraph::{
     RunContent as HwpxRunContent, Section as HwpxSection, TextElement, TextMarkup,
 };
 use crate::paragraph::{TextFlowMode, TextWrapMode};
+use crate::preserve::{UnknownContent, UnknownNode};
+use crate::settings::{ApplicationSetting, CaretPosition};
 use crate::version::{HcfVersion, TargetApplication};
 use ir::{
     ConversionError, ConversionResult, Document as IrDocument, RunContent as IrRunContent,
@@ -97,6 +104,11 @@ use primitive::{
 
 use super::FromIrContext;
 
+/// 글꼴이 없을 때 넣는 기본 글꼴
+///
+/// header.xsd상 `font`는 하나 이상 필수이며, HWP 변환이 빈 언어 그룹에 넣는 글꼴과 같습니다.
+const DEFAULT_FONT_NAME: &str = "함초롬돋움";
+
 /// IR → HWPX 변환 트레이트
 pub trait IrToHwpx {
     /// HWPX 문서로 변환
@@ -155,10 +167,34 @@ fn convert_document(
         doc.sections.push(hwpx_section);
     }
 
-    // 바이너리 데이터 변환
-    for (id, binary) in ir.binary_data.iter() {
-        doc.binary_data
-            .insert(id.value().to_string(), binary.data.clone());
+    // 설정 변환 (커서 위치가 있을 때만 settings.xml 생성)
+    doc.settings = ir.settings.caret_position.as_ref().map(|caret| {
+        // 문단 인덱스를 변환한 문단의 아이디로 되돌림
+        let paragraph_id = doc
+            .sections
+            .get(caret.section as usize)
+            .and_then(|section| section.paragraphs.get(caret.paragraph as usize))
+            .map_or(caret.paragraph, |para| para.id);
+        ApplicationSetting::with_caret_position(CaretPosition {
+            list_id_reference: caret.section,
+            paragraph_id_reference: paragraph_id,
+            position: caret.position,
+        })
+    });
+
+    // 미리보기 텍스트 변환 (한글처럼 본문 앞부분으로 생성)
+    doc.preview_text = Some(ir.to_preview_text());
+
+    // 패키지 메타데이터 변환
+    doc.content.metadata = convert_package_metadata(&ir.metadata);
+
+    // 바이너리 데이터 변환 (content.hpf 매니페스트 항목 함께 등록)
+    let mut binaries: Vec<_> = ir.binary_data.iter().collect();
+    binaries.sort_by(|(a, _), (b, _)| a.value().cmp(b.value()));
+    for (id, binary) in binaries {
+        let item = binary_manifest_item(id.value(), binary);
+        doc.binary_data.insert(item.href.clone(), binary.data.clone());
+        doc.content.manifest.items.push(item);
     }
 
     // 마스터 페이지 변환
@@ -168,6 +204,23 @@ fn convert_document(
                 doc.master_pages.push(master_page);
             }
         }
+
+        // 보존한 XML 노드 (저장할 때 같은 위치에 다시 기록)
+        doc.unknown_nodes = hwpx_ext
+            .unknown_nodes
+            .iter()
+            .map(convert_unknown_node)
+            .collect();
+    }
+    let orphans = doc
+        .orphaned_unknown_nodes()
+        .map_err(|err| ConversionError::invalid_data(err.to_string()))?
+        .len();
+    if orphans > 0 {
+        ctx.warnings.data_loss(format!(
+            "보존한 XML 노드 {}개는 속한 요소가 없어 HWPX로 저장할 때 빠집니다",
+            orphans
+        ));
     }
 
     // HWP 확장 데이터 경고
@@ -179,6 +232,79 @@ fn convert_document(
     Ok(doc)
 }
 
+/// 보존한 XML 노드 변환
+fn convert_unknown_node(node: &ir::extensions::HwpxUnknownNode) -> UnknownNode {
+    let content = match &node.content {
+        ir::extensions::HwpxUnknownContent::Element { index, xml } => UnknownContent::Element {
+            index: *index,
+            xml: xml.clone(),
+        },
+        ir::extensions::HwpxUnknownContent::Attribute { name, value } => {
+            UnknownContent::Attribute {
+                name: name.clone(),
+                value: value.clone(),
+            }
+        }
+    };
+    UnknownNode {
+        part: node.part.clone(),
+        path: node.path.clone(),
+        content,
+    }
+}
+
+/// IR 메타데이터를 content.hpf 메타데이터로 변환
+fn convert_package_metadata(metadata: &ir::Metadata) -> PackageMetadata {
+    let mut content = PackageMetadata {
+        title: metadata.title.clone(),
+        language: Some("ko".to_string()),
+        metas: Vec::new(),
+    };
+
+    let metas = [
+        (META_CREATOR, &metadata.author),
+        (META_SUBJECT, &metadata.subject),
+        (META_DESCRIPTION, &metadata.description),
+        (META_LAST_SAVED_BY, &metadata.last_saved_by),
+        (META_CREATED_DATE, &metadata.created),
+        (META_MODIFIED_DATE, &metadata.modified),
+    ];
+    for (name, value) in metas {
+        if let Some(value) = value {
+            content.set_meta(name, value.clone());
+        }
+    }
+    if !metadata.keywords.is_empty() {
+        content.set_meta(META_KEYWORD, metadata.keywords.join(", "));
+    }
+
+    content
+}
+
+/// IR 바이너리 데이터의 패키지 경로와 매니페스트 항목 생성
+///
+/// 매니페스트 아이디는 본문의 `binaryItemIDRef`와 같은 IR 아이디를 그대로 쓰고,
+/// 경로는 `BinData/{아이디}.{확장자}`로 정합니다. 아이디가 이미 `BinData/` 경로라면
+/// 그 경로를 유지하고 파일 이름을 아이디로 씁니다.
+fn binary_manifest_item(id: &str, binary: &ir::BinaryData) -> ManifestItem {
+    let (item_id, href) = if id.starts_with(BIN_DATA_DIR) {
+        (package::file_stem(id).to_string(), id.to_string())
+    } else {
+        let extension = binary
+            .filename
+            .as_deref()
+            .and_then(|name| name.rsplit_once('.'))
+            .map_or(binary.format.extension(), |(_, extension)| extension);
+        (id.to_string(), format!("{}{}.{}", BIN_DATA_DIR, id, extension))
+    };
+
+    let item = ManifestItem::new(item_id, href).with_embedded(true);
+    match binary.format {
+        ir::BinaryFormat::Unknown | ir::BinaryFormat::Video => item,
+        format => item.with_media_type(format.mime_type()),
+    }
+}
+
 /// 섹션 변환
 fn convert_section(section: &ir::Section, section_id: u32) -> Result<HwpxSection, ConversionError> {
     let mut hwpx_section = HwpxSection {
@@ -735,9 +861,12 @@ fn has_column_settings(col_def: &ir::section::ColumnDefinition) -> bool {
 }
 
 /// 문단 변환
-fn convert_paragraph(para: &ir::Paragraph, id: u32) -> Result<HwpxParagraph, ConversionError> {
+///
+/// 원본 문단 아이디(`instance_id`)가 있으면 그대로 쓰고, 없으면 목록 안 순번(`index`)을 씀.
+/// 보존한 노드는 문단 아이디로 자리를 찾으므로 아이디를 바꾸면 안 됨.
+fn convert_paragraph(para: &ir::Paragraph, index: u32) -> Result<HwpxParagraph, ConversionError> {
     let mut hwpx_para = HwpxParagraph {
-        id,
+        id: para.instance_id.unwrap_or(index),
         runs: Vec::new(),
         line_segments: None,
         paragraph_property_id_reference: para
@@ -1157,33 +1286,25 @@ fn convert_head(
     use crate::header::forbidden_word::ForbiddenWordList;
     use crate::header::track_change::TrackChangeConfig;
 
-    // 폰트 변환
-    let fontfaces = if !styles.fonts.is_empty() {
-        Some(convert_fonts(&styles.fonts))
-    } else {
-        None
-    };
+    // 폰트 변환 (스키마상 필수)
+    let fontfaces = Some(convert_fonts(&styles.fonts));
 
-    // 글자 모양 변환
+    // 글자 모양 변환 (스키마상 하나 이상 필수)
     let character_shapes = if !styles.char_shapes.is_empty() {
         Some(convert_char_shapes(&styles.char_shapes))
     } else {
-        None
+        Some(convert_char_shapes(&[IrCharShape::default()]))
     };
 
-    // 문단 모양 변환
+    // 문단 모양 변환 (스키마상 하나 이상 필수)
     let paragraph_shapes = if !styles.para_shapes.is_empty() {
         Some(convert_para_shapes(&styles.para_shapes))
     } else {
-        None
+        Some(convert_para_shapes(&[IrParaShape::default()]))
     };
 
-    // 스타일 변환
-    let style_list = if !styles.styles.is_empty() {
-        Some(convert_styles_list(&styles.styles))
-    } else {
-        None
-    };
+    // 스타일 변환 (빈 목록도 요소는 필수)
+    let style_list = Some(convert_styles_list(&styles.styles));
 
     // 테두리/채우기 변환
     let border_fills = if !styles.border_fills.is_empty() {
@@ -1215,14 +1336,11 @@ fn convert_head(
             }
         });
 
-    // 변경 추적 설정 변환
-    let track_change_config = hwpx_ext
-        .and_then(|ext| ext.track_change_config.as_ref())
-        .filter(|config| config.enabled)
-        .map(|_config| TrackChangeConfig {
-            authors: None,
-            changes: None,
-        });
+    // 변경 추적 설정 변환 (스키마상 필수, 사용자와 변경 목록은 IR에 없음)
+    let track_change_config = Some(TrackChangeConfig {
+        authors: None,
+        changes: None,
+    });
 
     Ok(Head {
         begin_number: BeginNumber::default(),
@@ -1278,11 +1396,15 @@ fn convert_document_option(
 fn convert_fonts(fonts: &[IrFont]) -> FontfaceList {
     // HWPX는 언어별로 폰트를 그룹화
     // IR은 단순 폰트 목록이므로, 한글 언어 그룹으로 통합
-    let hwpx_fonts: Vec<HwpxFont> = fonts
+    let mut hwpx_fonts: Vec<HwpxFont> = fonts
         .iter()
         .enumerate()
         .map(|(i, font)| convert_font_to_hwpx(font, i as u32))
         .collect();
+    // 언어 그룹에는 글꼴이 하나 이상 있어야 함
+    if hwpx_fonts.is_empty() {
+        hwpx_fonts.push(convert_font_to_hwpx(&IrFont::new(DEFAULT_FONT_NAME), 0));
+    }
 
     let fontface = Fontface {
         fonts: hwpx_fonts.clone(),
@@ -1292,7 +1414,7 @@ fn convert_fonts(fonts: &[IrFont]) -> FontfaceList {
 
     FontfaceList {
         fontfaces: vec![fontface],
-        item_count: fonts.len() as u32,
+        item_count: 1,
     }
 }
 
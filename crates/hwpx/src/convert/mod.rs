@@ -25,6 +25,12 @@ impl ToIrContext {
     }
 }
 
+impl Default for ToIrContext {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// IR → HWPX 변환 컨텍스트
 pub struct FromIrContext {
     /// 경고 수집기
@@ -39,3 +45,9 @@ impl FromIrContext {
         }
     }
 }
+
+impl Default for FromIrContext {
+    fn default() -> Self {
+        Self::new()
+    }
+}
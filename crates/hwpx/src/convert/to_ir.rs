@@ -19,6 +19,11 @@ use crate::header::{
     },
     style::Style as HwpxStyle,
 };
+use crate::package::{
+    self, META_CREATED_DATE, META_CREATOR, META_DESCRIPTION, META_KEYWORD, META_LAST_SAVED_BY,
+    META_MODIFIED_DATE, META_SUBJECT,
+};
+use crate::preserve::{UnknownContent, UnknownNode};
 use ir::{
     BinaryData, BinaryDataId, BinaryDataStore, BinaryFormat, ConversionError, ConversionResult,
     Document as IrDocument, Extensions, HwpxExtensions, Metadata, Paragraph as IrParagraph,
@@ -94,6 +99,9 @@ fn convert_document(
     // 메타데이터 변환
     doc.metadata = convert_metadata(hwpx);
 
+    // 설정 변환 (settings.xml 커서 위치)
+    doc.settings.caret_position = convert_caret_position(hwpx);
+
     // 스타일 변환 (완전한 변환)
     doc.styles = convert_styles(hwpx, ctx)?;
 
@@ -125,9 +133,46 @@ fn convert_metadata(hwpx: &HwpxDocument) -> Metadata {
         version.build_number,
     ));
 
+    // content.hpf 메타데이터
+    let content = &hwpx.content.metadata;
+    let text = |value: Option<&str>| value.filter(|v| !v.is_empty()).map(str::to_string);
+    metadata.title = text(content.title.as_deref());
+    metadata.author = text(content.meta(META_CREATOR));
+    metadata.subject = text(content.meta(META_SUBJECT));
+    metadata.description = text(content.meta(META_DESCRIPTION));
+    metadata.last_saved_by = text(content.meta(META_LAST_SAVED_BY));
+    metadata.created = text(content.meta(META_CREATED_DATE));
+    metadata.modified = text(content.meta(META_MODIFIED_DATE));
+    if let Some(keywords) = content.meta(META_KEYWORD) {
+        metadata.keywords = keywords
+            .split(',')
+            .map(str::trim)
+            .filter(|keyword| !keyword.is_empty())
+            .map(str::to_string)
+            .collect();
+    }
+
     metadata
 }
 
+/// 커서 위치 변환
+///
+/// 리스트 아이디는 구역 인덱스로, 문단 아이디는 그 구역에서 아이디가 같은 첫 문단의 인덱스로
+/// 대응시킵니다. 가리키는 문단이 없으면 커서 위치를 버립니다.
+fn convert_caret_position(hwpx: &HwpxDocument) -> Option<ir::CaretPosition> {
+    let caret = hwpx.settings.as_ref()?.caret_position?;
+    let section = hwpx.sections.get(caret.list_id_reference as usize)?;
+    let paragraph = section
+        .paragraphs
+        .iter()
+        .position(|para| para.id == caret.paragraph_id_reference)?;
+    Some(ir::CaretPosition {
+        section: caret.list_id_reference,
+        paragraph: paragraph as u32,
+        position: caret.position,
+    })
+}
+
 /// 스타일 저장소 변환
 fn convert_styles(
     hwpx: &HwpxDocument,
@@ -2011,13 +2056,20 @@ fn convert_hwpx_control(
 }
 
 /// 바이너리 데이터 변환
+///
+/// 본문의 `binaryItemIDRef`와 맞도록 매니페스트 항목 아이디를 IR 아이디로 씁니다.
+/// 매니페스트에 없는 항목은 파일 이름(확장자 제외)을 아이디로 씁니다.
 fn convert_binary_data(hwpx: &HwpxDocument) -> Result<BinaryDataStore, ConversionError> {
     let mut store = BinaryDataStore::new();
 
     for (path, data) in &hwpx.binary_data {
         let format = detect_binary_format(data, path);
-        let ir_data = BinaryData::new(format, data.clone());
-        let ir_id = BinaryDataId::new(path.clone());
+        let filename = path.rsplit('/').next().unwrap_or(path);
+        let ir_data = BinaryData::new(format, data.clone()).with_filename(filename);
+        let ir_id = match hwpx.content.item_by_href(path) {
+            Some(item) => BinaryDataId::new(item.id.clone()),
+            None => BinaryDataId::new(package::file_stem(path)),
+        };
         store.add(ir_id, ir_data);
     }
 
@@ -2148,11 +2200,39 @@ fn convert_extensions(hwpx: &HwpxDocument, _ctx: &mut ToIrContext) -> Extensions
         });
     }
 
+    // 모델에 없는 XML 노드 (보존 모드로 읽은 경우)
+    hwpx_ext.unknown_nodes = hwpx
+        .unknown_nodes
+        .iter()
+        .map(convert_unknown_node)
+        .collect();
+
     ext.hwpx = Some(hwpx_ext);
 
     ext
 }
 
+/// 보존한 XML 노드 변환
+fn convert_unknown_node(node: &UnknownNode) -> ir::extensions::HwpxUnknownNode {
+    let content = match &node.content {
+        UnknownContent::Element { index, xml } => ir::extensions::HwpxUnknownContent::Element {
+            index: *index,
+            xml: xml.clone(),
+        },
+        UnknownContent::Attribute { name, value } => {
+            ir::extensions::HwpxUnknownContent::Attribute {
+                name: name.clone(),
+                value: value.clone(),
+            }
+        }
+    };
+    ir::extensions::HwpxUnknownNode {
+        part: node.part.clone(),
+        path: node.path.clone(),
+        content,
+    }
+}
+
 // 열거형 변환 헬퍼 함수들
 
 const fn convert_hwpx_underline_type(
@@ -3556,6 +3636,10 @@ fn convert_hwpx_chart(chart: &crate::paragraph::Chart) -> Result<IrChart, Conver
         common,
         chart_id,
         chart_type: IrChartType::Bar, // 기본값
+        title: None,
+        axes: Vec::new(),
+        categories: Vec::new(),
+        series: Vec::new(),
     })
 }
 
@@ -1,10 +1,28 @@
 //! HWPX 통합 문서 타입
 //!
 //! HWPX 패키지의 여러 XML 파일을 하나의 문서 타입으로 통합합니다.
+//! [`Document::from_bytes`]/[`Document::to_bytes`]로 `.hwpx` ZIP 패키지를 직접 읽고 씁니다.
 
+use std::collections::HashMap;
+use std::io::{Cursor, Read, Seek, Write};
+
+use primitive::Limits;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+use crate::error::{Error, Result};
 use crate::header::Head;
 use crate::master_page::MasterPage;
+use crate::package::{
+    self, BIN_DATA_DIR, CONTAINER_NAMESPACE, CONTAINER_PATH, CONTENT_HPF_PATH, Container,
+    ContentHpf, EncryptionOptions, HEADER_PATH, MASTER_PAGE_DIR, MIMETYPE, MIMETYPE_PATH,
+    ManifestItem, OPF_NAMESPACE, PACKAGE_MEDIA_TYPE, PREVIEW_IMAGE_PATH, PREVIEW_TEXT_PATH,
+    PackageReader, PackageWriter, RootFile, SETTINGS_PATH, VERSION_PATH,
+};
 use crate::paragraph::Section;
+use crate::preserve::{self, UnknownNode};
+use crate::settings::{ApplicationSetting, CONFIG_NAMESPACE, SETTINGS_NAMESPACE};
+use crate::signature::{self, SigningOptions};
 use crate::version::HcfVersion;
 
 /// HWPX 문서
@@ -14,6 +32,12 @@ use crate::version::HcfVersion;
 /// - `Contents/header.xml` → `header`
 /// - `Contents/section*.xml` → `sections`
 /// - `MasterPage/master*.xml` → `master_pages` (선택적)
+/// - `Contents/content.hpf` → `content`
+/// - `settings.xml` → `settings` (선택적)
+/// - `Preview/PrvText.txt`, `Preview/PrvImage.*` → `preview_text`, `preview_image` (선택적)
+///
+/// [`ReadOptions::with_preserve_unknown`]으로 읽으면 헤더/구역/바탕쪽에서 모델에 없는
+/// 요소와 속성을 `unknown_nodes`에 모아 두었다가 저장할 때 같은 위치에 다시 기록합니다.
 #[derive(Debug, Clone)]
 pub struct Document {
     /// 버전 정보 (version.xml)
@@ -30,7 +54,66 @@ pub struct Document {
 
     /// 바이너리 데이터 (BinData/*)
     /// 키: 파일 경로 (예: "BinData/BIN0001.png")
-    pub binary_data: std::collections::HashMap<String, Vec<u8>>,
+    pub binary_data: HashMap<String, Vec<u8>>,
+
+    /// 패키지 정보 (Contents/content.hpf)
+    ///
+    /// 저장할 때 헤더/구역/바탕쪽/바이너리 항목은 현재 파트 구성으로 다시 만들고,
+    /// 메타데이터와 바이너리 항목의 아이디/미디어 타입은 그대로 유지합니다.
+    pub content: ContentHpf,
+
+    /// 응용 프로그램 설정 (settings.xml)
+    pub settings: Option<ApplicationSetting>,
+
+    /// 미리보기 텍스트 (Preview/PrvText.txt)
+    pub preview_text: Option<String>,
+
+    /// 미리보기 이미지 (Preview/PrvImage.*)
+    pub preview_image: Option<Vec<u8>>,
+
+    /// 미리보기 이미지 파트 경로 (없으면 `Preview/PrvImage.png`)
+    ///
+    /// 읽을 때 원래 파트 이름을 기록해 두어 PNG가 아닌 이미지도 같은 이름으로 다시 씁니다.
+    pub preview_image_path: Option<String>,
+
+    /// 모델에 없는 XML 노드 (보존 모드로 읽었을 때만 채워짐)
+    pub unknown_nodes: Vec<UnknownNode>,
+}
+
+/// 문서 읽기 설정
+#[derive(Debug, Clone, Default)]
+pub struct ReadOptions {
+    /// 암호 (암호가 설정된 패키지를 읽을 때)
+    pub password: Option<String>,
+    /// 모델에 없는 요소와 속성을 [`Document::unknown_nodes`]에 보존할지 여부
+    pub preserve_unknown: bool,
+    /// 파트 크기, 전체 크기, 중첩 깊이 제한
+    pub limits: Limits,
+}
+
+impl ReadOptions {
+    /// 기본 설정(암호 없음, 보존 안 함, 기본 [`Limits`])으로 읽기 설정 생성
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 암호 지정
+    pub fn with_password(mut self, password: impl Into<String>) -> Self {
+        self.password = Some(password.into());
+        self
+    }
+
+    /// 알 수 없는 요소와 속성 보존 여부 지정
+    pub const fn with_preserve_unknown(mut self, preserve_unknown: bool) -> Self {
+        self.preserve_unknown = preserve_unknown;
+        self
+    }
+
+    /// 자원 제한 지정
+    pub const fn with_limits(mut self, limits: Limits) -> Self {
+        self.limits = limits;
+        self
+    }
 }
 
 impl Document {
@@ -41,8 +124,350 @@ impl Document {
             header,
             sections: Vec::new(),
             master_pages: Vec::new(),
-            binary_data: std::collections::HashMap::new(),
+            binary_data: HashMap::new(),
+            content: ContentHpf::default(),
+            settings: None,
+            preview_text: None,
+            preview_image: None,
+            preview_image_path: None,
+            unknown_nodes: Vec::new(),
+        }
+    }
+
+    /// `.hwpx` 파일 바이트에서 문서 읽기
+    pub fn from_bytes(data: &[u8]) -> Result<Self> {
+        Self::from_reader(Cursor::new(data))
+    }
+
+    /// 암호가 설정된 `.hwpx` 파일 바이트에서 문서 읽기
+    pub fn from_bytes_with_password(data: &[u8], password: &str) -> Result<Self> {
+        Self::from_reader_with_password(Cursor::new(data), password)
+    }
+
+    /// 읽기 설정을 지정해 `.hwpx` 파일 바이트에서 문서 읽기
+    pub fn from_bytes_with_options(data: &[u8], options: &ReadOptions) -> Result<Self> {
+        Self::from_reader_with_options(Cursor::new(data), options)
+    }
+
+    /// `.hwpx` ZIP 패키지에서 문서 읽기
+    ///
+    /// `META-INF/container.xml`이 가리키는 `content.hpf`의 스파인 순서대로 구역을 읽습니다.
+    /// 패키지 정보가 없으면 `Contents/section{N}.xml`을 번호 순으로 읽습니다.
+    /// 암호화된 패키지는 [`Error::PasswordRequired`]를 반환합니다.
+    pub fn from_reader<R: Read + Seek>(reader: R) -> Result<Self> {
+        Self::from_reader_with_options(reader, &ReadOptions::new())
+    }
+
+    /// 암호가 설정된 `.hwpx` ZIP 패키지에서 문서 읽기
+    ///
+    /// `META-INF/manifest.xml`에 선언된 파트를 복호화하며,
+    /// 암호가 틀리면 [`Error::InvalidPassword`]를 반환합니다.
+    /// 암호화되지 않은 패키지도 그대로 읽습니다.
+    pub fn from_reader_with_password<R: Read + Seek>(reader: R, password: &str) -> Result<Self> {
+        Self::from_reader_with_options(reader, &ReadOptions::new().with_password(password))
+    }
+
+    /// 읽기 설정을 지정해 `.hwpx` ZIP 패키지에서 문서 읽기
+    pub fn from_reader_with_options<R: Read + Seek>(
+        reader: R,
+        options: &ReadOptions,
+    ) -> Result<Self> {
+        let mut package = PackageReader::new(reader)?.with_limits(options.limits);
+
+        if package.contains(MIMETYPE_PATH) {
+            let mimetype = package.read_string(MIMETYPE_PATH)?;
+            if mimetype.trim() != MIMETYPE {
+                return Err(Error::InvalidMimeType(mimetype));
+            }
+        }
+
+        let version: HcfVersion = package.read_xml(VERSION_PATH)?;
+        package.load_manifest(options.password.as_deref())?;
+
+        let content_path = if package.contains(CONTAINER_PATH) {
+            let container: Container = package.read_xml(CONTAINER_PATH)?;
+            container
+                .package_path()
+                .unwrap_or(CONTENT_HPF_PATH)
+                .to_string()
+        } else {
+            CONTENT_HPF_PATH.to_string()
+        };
+        let content = if package.contains(&content_path) {
+            let content: ContentHpf = package.read_xml(&content_path)?;
+            Some(content)
+        } else {
+            None
+        };
+        let parts = PartPaths::resolve(&package, content.as_ref(), &content_path);
+
+        let mut unknown_nodes = Vec::new();
+        let mut reader = ModelReader {
+            package: &mut package,
+            unknown_nodes: options.preserve_unknown.then_some(&mut unknown_nodes),
+        };
+        let header: Head = reader.read(&parts.header, HEADER_PATH)?;
+        let mut sections = Vec::new();
+        for (index, path) in parts.sections.iter().enumerate() {
+            sections.push(reader.read(path, &package::section_path(index))?);
+        }
+        let mut master_pages = Vec::new();
+        for (index, path) in parts.master_pages.iter().enumerate() {
+            master_pages.push(reader.read(path, &package::master_page_path(index))?);
+        }
+
+        let mut document = Self::new(version, header);
+        document.sections = sections;
+        document.master_pages = master_pages;
+        document.unknown_nodes = unknown_nodes;
+        for path in &parts.binaries {
+            let data = package.read(path)?;
+            document.binary_data.insert(path.clone(), data);
+        }
+        if let Some(path) = &parts.settings {
+            document.settings = Some(package.read_xml(path)?);
+        }
+        if let Some(path) = &parts.preview_text {
+            document.preview_text = Some(decode_preview_text(&package.read(path)?));
+        }
+        if let Some(path) = &parts.preview_image {
+            document.preview_image = Some(package.read(path)?);
+            document.preview_image_path = Some(path.clone());
+        }
+        if let Some(content) = content {
+            document.content = content;
+        }
+
+        Ok(document)
+    }
+
+    /// `.hwpx` 파일 바이트로 저장
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        let mut cursor = Cursor::new(Vec::new());
+        self.write_to(&mut cursor)?;
+        Ok(cursor.into_inner())
+    }
+
+    /// 암호를 설정해 `.hwpx` 파일 바이트로 저장 (AES-256-CBC)
+    pub fn to_bytes_with_password(&self, password: &str) -> Result<Vec<u8>> {
+        self.to_bytes_encrypted(&EncryptionOptions::new(password))
+    }
+
+    /// 암호화 설정을 지정해 `.hwpx` 파일 바이트로 저장
+    pub fn to_bytes_encrypted(&self, options: &EncryptionOptions) -> Result<Vec<u8>> {
+        let mut cursor = Cursor::new(Vec::new());
+        self.write_to_encrypted(&mut cursor, options)?;
+        Ok(cursor.into_inner())
+    }
+
+    /// 전자서명한 `.hwpx` 파일 바이트로 저장 (`META-INF/signatures.xml`)
+    pub fn to_bytes_signed(&self, options: &SigningOptions) -> Result<Vec<u8>> {
+        signature::sign(&self.to_bytes()?, options)
+    }
+
+    /// `.hwpx` ZIP 패키지로 저장
+    ///
+    /// 한글이 요구하는 대로 `mimetype`을 무압축 첫 항목으로 기록하고,
+    /// 구역/바탕쪽/바이너리 데이터를 매니페스트와 스파인에 등록합니다.
+    pub fn write_to<W: Write + Seek>(&self, writer: W) -> Result<()> {
+        self.write_package(PackageWriter::new(writer))
+    }
+
+    /// 암호화된 `.hwpx` ZIP 패키지로 저장
+    ///
+    /// 헤더, 구역, 바탕쪽, 설정, 바이너리 데이터를 암호화하고
+    /// 복호화 정보를 `META-INF/manifest.xml`에 기록합니다.
+    pub fn write_to_encrypted<W: Write + Seek>(
+        &self,
+        writer: W,
+        options: &EncryptionOptions,
+    ) -> Result<()> {
+        self.write_package(PackageWriter::with_encryption(writer, options.clone()))
+    }
+
+    fn write_package<W: Write + Seek>(&self, mut package: PackageWriter<W>) -> Result<()> {
+        package.write_stored(MIMETYPE_PATH, MIMETYPE.as_bytes())?;
+        let version = package::to_xml_part(VERSION_PATH, &self.version, &[])?;
+        package.write_stored(VERSION_PATH, version.as_bytes())?;
+
+        let container = Container::new(vec![RootFile {
+            full_path: CONTENT_HPF_PATH.to_string(),
+            media_type: PACKAGE_MEDIA_TYPE.to_string(),
+        }]);
+        package.write_xml(CONTAINER_PATH, &container, &[("ocf", CONTAINER_NAMESPACE)])?;
+
+        let mut binary_paths: Vec<(String, &Vec<u8>)> = self
+            .binary_data
+            .iter()
+            .map(|(key, data)| (binary_data_path(key), data))
+            .collect();
+        binary_paths.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        let content = self.build_content_hpf(binary_paths.iter().map(|(path, _)| path.as_str()));
+        package.write_xml(CONTENT_HPF_PATH, &content, &[("opf", OPF_NAMESPACE)])?;
+
+        let header = self.model_xml(HEADER_PATH, &self.header)?;
+        package.write_deflated(HEADER_PATH, header.as_bytes())?;
+        for (index, section) in self.sections.iter().enumerate() {
+            let path = package::section_path(index);
+            let xml = self.model_xml(&path, section)?;
+            package.write_deflated(&path, xml.as_bytes())?;
+        }
+        for (index, master_page) in self.master_pages.iter().enumerate() {
+            let path = package::master_page_path(index);
+            let xml = self.model_xml(&path, master_page)?;
+            package.write_deflated(&path, xml.as_bytes())?;
+        }
+        if let Some(settings) = &self.settings {
+            package.write_xml(
+                SETTINGS_PATH,
+                settings,
+                &[("ha", SETTINGS_NAMESPACE), ("config", CONFIG_NAMESPACE)],
+            )?;
+        }
+        if let Some(text) = &self.preview_text {
+            package.write_deflated(PREVIEW_TEXT_PATH, text.as_bytes())?;
+        }
+        if let Some(image) = &self.preview_image {
+            package.write_deflated(self.preview_image_path(), image)?;
+        }
+        for (path, data) in binary_paths {
+            package.write_deflated(&path, data)?;
+        }
+
+        package.finish()?;
+        Ok(())
+    }
+
+    /// 헤더/구역/바탕쪽 모델을 저장할 XML로 직렬화 (보존한 노드 포함)
+    pub(crate) fn model_xml<T: Serialize>(&self, part: &str, value: &T) -> Result<String> {
+        self.restore_part(part, value).map(|(xml, _)| xml)
+    }
+
+    /// 저장할 때 기록할 곳이 없어 빠지는 보존 노드
+    ///
+    /// 노드가 속한 요소(같은 아이디의 문단 등)를 지웠거나 파트가 없어진 경우입니다.
+    /// 보존 노드가 없으면 직렬화하지 않고 빈 목록을 반환합니다.
+    pub fn orphaned_unknown_nodes(&self) -> Result<Vec<&UnknownNode>> {
+        if self.unknown_nodes.is_empty() {
+            return Ok(Vec::new());
+        }
+        let mut parts = vec![HEADER_PATH.to_string()];
+        let mut orphans = self.restore_part(HEADER_PATH, &self.header)?.1;
+        for (index, section) in self.sections.iter().enumerate() {
+            let path = package::section_path(index);
+            orphans.extend(self.restore_part(&path, section)?.1);
+            parts.push(path);
+        }
+        for (index, master_page) in self.master_pages.iter().enumerate() {
+            let path = package::master_page_path(index);
+            orphans.extend(self.restore_part(&path, master_page)?.1);
+            parts.push(path);
+        }
+        orphans.extend(
+            self.unknown_nodes
+                .iter()
+                .filter(|node| !parts.contains(&node.part)),
+        );
+        Ok(orphans)
+    }
+
+    /// 모델을 직렬화하고 보존한 노드를 되돌림 (기록하지 못한 노드 함께 반환)
+    fn restore_part<T: Serialize>(
+        &self,
+        part: &str,
+        value: &T,
+    ) -> Result<(String, Vec<&UnknownNode>)> {
+        let xml = package::to_xml_part(part, value, &[])?;
+        let mut nodes = self
+            .unknown_nodes
+            .iter()
+            .filter(|node| node.part == part)
+            .peekable();
+        if nodes.peek().is_none() {
+            return Ok((xml, Vec::new()));
+        }
+        preserve::restore(&xml, nodes).map_err(|err| Error::Serialize {
+            part: part.to_string(),
+            source: quick_xml::SeError::Custom(err.to_string()),
+        })
+    }
+
+    /// 현재 파트 구성으로 `content.hpf` 생성
+    ///
+    /// 바이너리 항목은 기존 매니페스트에 같은 경로의 항목이 있으면 그 아이디와 미디어 타입을 쓰고,
+    /// 없으면 파일 이름(확장자 제외)을 아이디로 새로 등록합니다.
+    fn build_content_hpf<'a>(&self, binary_paths: impl Iterator<Item = &'a str>) -> ContentHpf {
+        let mut content = self.content.clone();
+        content.manifest.items.clear();
+        content.spine.item_refs.clear();
+
+        content.push_item(ManifestItem::new("header", HEADER_PATH), true);
+        for index in 0..self.master_pages.len() {
+            content.push_item(
+                ManifestItem::new(
+                    format!("masterpage{}", index),
+                    package::master_page_path(index),
+                ),
+                false,
+            );
+        }
+        for path in binary_paths {
+            let item = match self.content.item_by_href(path) {
+                Some(item) => item.clone(),
+                None => ManifestItem::new(package::file_stem(path), path).with_embedded(true),
+            };
+            content.push_item(item, false);
+        }
+        for index in 0..self.sections.len() {
+            content.push_item(
+                ManifestItem::new(format!("section{}", index), package::section_path(index)),
+                true,
+            );
+        }
+        if self.settings.is_some() {
+            content.push_item(ManifestItem::new("settings", SETTINGS_PATH), false);
+        }
+
+        content
+    }
+
+    /// 매니페스트 아이디(본문의 `binaryItemIDRef`)로 바이너리 데이터 찾기
+    pub fn binary_data_by_id(&self, id: &str) -> Option<&[u8]> {
+        let path = self.binary_data_path(id)?;
+        self.binary_data.get(path).map(Vec::as_slice)
+    }
+
+    /// 매니페스트 아이디에 해당하는 바이너리 데이터 경로
+    ///
+    /// 매니페스트에 없으면 `BinData/{id}.*` 형태의 경로를 찾습니다.
+    pub fn binary_data_path(&self, id: &str) -> Option<&str> {
+        if let Some(item) = self.content.item(id)
+            && let Some((path, _)) = self.binary_data.get_key_value(&item.href)
+        {
+            return Some(path.as_str());
         }
+        self.binary_data
+            .keys()
+            .find(|path| path.starts_with(BIN_DATA_DIR) && package::file_stem(path) == id)
+            .map(String::as_str)
+    }
+
+    /// 미리보기 텍스트 (`Preview/PrvText.txt`)
+    pub fn preview_text(&self) -> Option<&str> {
+        self.preview_text.as_deref()
+    }
+
+    /// 미리보기 이미지 (보통 `Preview/PrvImage.png`의 PNG)
+    pub fn preview_image(&self) -> Option<&[u8]> {
+        self.preview_image.as_deref()
+    }
+
+    /// 미리보기 이미지를 기록할 파트 경로
+    pub fn preview_image_path(&self) -> &str {
+        self.preview_image_path
+            .as_deref()
+            .unwrap_or(PREVIEW_IMAGE_PATH)
     }
 
     /// 섹션 추가
@@ -60,3 +485,218 @@ impl Document {
         self.binary_data.insert(path, data);
     }
 }
+
+/// 헤더/구역/바탕쪽 모델 읽기 (보존 모드면 모델에 없는 노드도 수집)
+struct ModelReader<'a, R> {
+    package: &'a mut PackageReader<R>,
+    unknown_nodes: Option<&'a mut Vec<UnknownNode>>,
+}
+
+impl<R: Read + Seek> ModelReader<'_, R> {
+    /// `path`의 파트를 읽고, 보존한 노드는 저장할 때의 경로 `part`로 기록
+    fn read<T: DeserializeOwned + Serialize>(&mut self, path: &str, part: &str) -> Result<T> {
+        let Some(unknown_nodes) = self.unknown_nodes.as_deref_mut() else {
+            return self.package.read_xml(path);
+        };
+        let xml = self.package.read_xml_string(path)?;
+        let value: T = crate::from_str(&xml).map_err(|source| Error::Deserialize {
+            part: path.to_string(),
+            source,
+        })?;
+        let regenerated = package::to_xml_part(part, &value, &[])?;
+        let nodes =
+            preserve::capture(part, &xml, &regenerated).map_err(|err| Error::Deserialize {
+                part: path.to_string(),
+                source: quick_xml::DeError::InvalidXml(err),
+            })?;
+        unknown_nodes.extend(nodes);
+        Ok(value)
+    }
+}
+
+/// 패키지에서 읽을 XML 파트 경로
+struct PartPaths {
+    header: String,
+    sections: Vec<String>,
+    master_pages: Vec<String>,
+    binaries: Vec<String>,
+    settings: Option<String>,
+    preview_text: Option<String>,
+    preview_image: Option<String>,
+}
+
+impl PartPaths {
+    /// 패키지 정보(있는 경우)와 실제 파트 목록으로 경로 결정
+    fn resolve<R: Read + Seek>(
+        package: &PackageReader<R>,
+        content: Option<&ContentHpf>,
+        content_path: &str,
+    ) -> Self {
+        let names = package.names();
+        let base_dir = content_path.rsplit_once('/').map_or("", |(dir, _)| dir);
+        // href는 보통 패키지 루트 기준이지만, content.hpf 기준 상대 경로도 허용
+        let locate = |href: &str| -> Option<String> {
+            if package.contains(href) {
+                return Some(href.to_string());
+            }
+            let joined = format!("{}/{}", base_dir, href);
+            package.contains(&joined).then_some(joined)
+        };
+
+        let mut header = None;
+        let mut sections = Vec::new();
+        let mut master_pages = Vec::new();
+        let mut binaries = Vec::new();
+        let mut settings = None;
+
+        if let Some(content) = content {
+            header = content
+                .item("header")
+                .or_else(|| {
+                    content
+                        .manifest
+                        .items
+                        .iter()
+                        .find(|item| item.href.ends_with("header.xml"))
+                })
+                .and_then(|item| locate(&item.href));
+
+            sections = content
+                .spine_items()
+                .filter(|item| is_section_part(&item.href))
+                .filter_map(|item| locate(&item.href))
+                .collect();
+
+            master_pages = content
+                .manifest
+                .items
+                .iter()
+                .filter(|item| is_master_page_part(&item.href))
+                .filter_map(|item| locate(&item.href))
+                .collect();
+
+            settings = content
+                .item("settings")
+                .or_else(|| {
+                    content
+                        .manifest
+                        .items
+                        .iter()
+                        .find(|item| item.href.ends_with(SETTINGS_PATH))
+                })
+                .and_then(|item| locate(&item.href));
+
+            // 매니페스트에 등록된 바이너리 항목 (컨테이너 밖 리소스는 제외)
+            binaries = content
+                .manifest
+                .items
+                .iter()
+                .filter(|item| item.is_embedded != Some(false))
+                .filter(|item| is_binary_part(&item.href))
+                .filter_map(|item| locate(&item.href))
+                .collect();
+        }
+
+        if sections.is_empty() {
+            sections = numbered_parts(&names, is_section_part);
+        }
+        if master_pages.is_empty() {
+            master_pages = numbered_parts(&names, is_master_page_part);
+        }
+
+        // 한글은 PNG로 기록하지만 다른 형식의 미리보기 이미지도 읽음
+        let preview_image = names
+            .iter()
+            .find(|name| name.starts_with("Preview/PrvImage."))
+            .cloned();
+
+        // 매니페스트에 빠진 BinData 항목도 버리지 않음
+        for name in names {
+            if name.starts_with(BIN_DATA_DIR) && !binaries.contains(&name) {
+                binaries.push(name);
+            }
+        }
+
+        Self {
+            header: header.unwrap_or_else(|| HEADER_PATH.to_string()),
+            sections,
+            master_pages,
+            binaries,
+            settings: settings.or_else(|| {
+                package
+                    .contains(SETTINGS_PATH)
+                    .then(|| SETTINGS_PATH.to_string())
+            }),
+            preview_text: package
+                .contains(PREVIEW_TEXT_PATH)
+                .then(|| PREVIEW_TEXT_PATH.to_string()),
+            preview_image,
+        }
+    }
+}
+
+/// 미리보기 텍스트 파트 디코딩
+///
+/// 한글은 UTF-8로 기록하지만, BOM으로 UTF-16LE임이 드러나면 그에 맞게 읽습니다.
+fn decode_preview_text(data: &[u8]) -> String {
+    if let Some(data) = data.strip_prefix(&[0xFF, 0xFE]) {
+        let units: Vec<u16> = data
+            .chunks_exact(2)
+            .map(|chunk| u16::from_le_bytes([chunk[0], chunk[1]]))
+            .collect();
+        return String::from_utf16_lossy(&units);
+    }
+    let data = data.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(data);
+    String::from_utf8_lossy(data).into_owned()
+}
+
+/// 구역 파트 여부 (`section{N}.xml`)
+fn is_section_part(path: &str) -> bool {
+    part_number(path, "section").is_some()
+}
+
+/// 바탕쪽 파트 여부 (`Contents/masterpage{N}.xml` 또는 `MasterPage/*.xml`)
+fn is_master_page_part(path: &str) -> bool {
+    part_number(path, "masterpage").is_some()
+        || (path.starts_with(MASTER_PAGE_DIR) && path.ends_with(".xml"))
+}
+
+/// 바이너리 데이터 파트 여부 (`BinData/` 아래 항목)
+fn is_binary_part(path: &str) -> bool {
+    path.starts_with(BIN_DATA_DIR) || path.contains(&format!("/{}", BIN_DATA_DIR))
+}
+
+/// 파일 이름이 `{prefix}{N}.xml`이면 N 반환
+fn part_number(path: &str, prefix: &str) -> Option<usize> {
+    let name = path.rsplit('/').next()?;
+    name.strip_prefix(prefix)?
+        .strip_suffix(".xml")?
+        .parse()
+        .ok()
+}
+
+/// 조건에 맞는 파트를 파일 번호 순으로 정렬
+fn numbered_parts(names: &[String], predicate: fn(&str) -> bool) -> Vec<String> {
+    let mut parts: Vec<&String> = names.iter().filter(|name| predicate(name)).collect();
+    parts.sort_by_key(|name| {
+        let name = name.as_str();
+        (
+            part_number(name, "section")
+                .or_else(|| part_number(name, "masterpage"))
+                .unwrap_or(usize::MAX),
+            name.to_string(),
+        )
+    });
+    parts.into_iter().cloned().collect()
+}
+
+/// 바이너리 데이터 키를 패키지 경로로 변환
+///
+/// 이미 `BinData/`로 시작하는 키는 그대로 쓰고, 그 외에는 `BinData/` 아래에 둡니다.
+fn binary_data_path(key: &str) -> String {
+    if key.starts_with(BIN_DATA_DIR) {
+        key.to_string()
+    } else {
+        format!("{}{}", BIN_DATA_DIR, key)
+    }
+}
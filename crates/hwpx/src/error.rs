@@ -0,0 +1,159 @@
+//! HWPX 패키지 처리 오류
+//!
+//! ZIP 컨테이너를 열거나 저장할 때 발생하는 오류를 정의합니다.
+//! 개별 XML 파트의 (역)직렬화 오류는 어느 파트에서 발생했는지 함께 보고합니다.
+
+use std::fmt;
+
+use primitive::{LimitExceeded, LimitKind};
+
+/// HWPX 패키지 처리 결과 타입
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// HWPX 패키지 처리 오류
+#[derive(Debug)]
+pub enum Error {
+    /// 입출력 오류
+    Io(std::io::Error),
+
+    /// ZIP 컨테이너 오류
+    Zip(zip::result::ZipError),
+
+    /// XML 파트 역직렬화 실패
+    Deserialize {
+        /// 패키지 내 파트 경로
+        part: String,
+        /// 원본 오류
+        source: quick_xml::DeError,
+    },
+
+    /// XML 파트 직렬화 실패
+    Serialize {
+        /// 패키지 내 파트 경로
+        part: String,
+        /// 원본 오류
+        source: quick_xml::SeError,
+    },
+
+    /// 필수 파트가 패키지에 없음
+    MissingPart {
+        /// 누락된 파트 경로
+        name: String,
+    },
+
+    /// 파트 내용이 UTF-8 텍스트가 아님
+    InvalidText {
+        /// 패키지 내 파트 경로
+        part: String,
+    },
+
+    /// `mimetype` 파트가 HWPX 형식이 아님
+    InvalidMimeType(String),
+
+    /// 암호화된 문서인데 암호가 주어지지 않음
+    PasswordRequired,
+
+    /// 암호가 틀림 (체크섬 불일치)
+    InvalidPassword {
+        /// 체크섬이 맞지 않은 파트 경로
+        part: String,
+    },
+
+    /// 지원하지 않는 암호화 알고리즘
+    UnsupportedEncryption(String),
+
+    /// 암호화 정보(초기화 벡터, 솔트, 키 길이 등)나 암호문이 잘못됨
+    InvalidEncryptionData {
+        /// 패키지 내 파트 경로
+        part: String,
+    },
+
+    /// 복호화한 파트가 매니페스트와 맞지 않음 (압축 해제 실패, 크기 불일치)
+    Decrypt {
+        /// 패키지 내 파트 경로
+        part: String,
+    },
+
+    /// 서명 키나 인증서가 잘못됨
+    InvalidKey(String),
+
+    /// 서명 생성 실패
+    Signing(String),
+
+    /// 읽기 자원 제한([`primitive::Limits`])을 넘음
+    LimitExceeded {
+        /// 넘어선 제한 종류
+        kind: LimitKind,
+        /// 제한값
+        limit: u64,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(err) => write!(f, "I/O error: {}", err),
+            Error::Zip(err) => write!(f, "ZIP container error: {}", err),
+            Error::Deserialize { part, source } => {
+                write!(f, "Failed to parse {}: {}", part, source)
+            }
+            Error::Serialize { part, source } => {
+                write!(f, "Failed to serialize {}: {}", part, source)
+            }
+            Error::MissingPart { name } => write!(f, "Missing package part: {}", name),
+            Error::InvalidText { part } => write!(f, "Part is not valid UTF-8: {}", part),
+            Error::InvalidMimeType(mime) => write!(f, "Invalid HWPX mimetype: {}", mime),
+            Error::PasswordRequired => write!(f, "Document is encrypted; a password is required"),
+            Error::InvalidPassword { part } => {
+                write!(f, "Invalid password (checksum mismatch in {})", part)
+            }
+            Error::UnsupportedEncryption(name) => {
+                write!(f, "Unsupported encryption algorithm: {}", name)
+            }
+            Error::InvalidEncryptionData { part } => {
+                write!(f, "Invalid encryption data for {}", part)
+            }
+            Error::Decrypt { part } => {
+                write!(f, "Decrypted part is corrupted or size mismatch: {}", part)
+            }
+            Error::InvalidKey(reason) => write!(f, "Invalid signing key: {}", reason),
+            Error::Signing(reason) => write!(f, "Failed to sign package: {}", reason),
+            Error::LimitExceeded { kind, limit } => {
+                write!(f, "Limit exceeded: {} over {}", kind, limit)
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(err) => Some(err),
+            Error::Zip(err) => Some(err),
+            Error::Deserialize { source, .. } => Some(source),
+            Error::Serialize { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(err: std::io::Error) -> Self {
+        Error::Io(err)
+    }
+}
+
+impl From<zip::result::ZipError> for Error {
+    fn from(err: zip::result::ZipError) -> Self {
+        Error::Zip(err)
+    }
+}
+
+impl From<LimitExceeded> for Error {
+    fn from(err: LimitExceeded) -> Self {
+        Error::LimitExceeded {
+            kind: err.kind,
+            limit: err.limit,
+        }
+    }
+}
@@ -445,13 +445,13 @@ fn default_character_height() -> i32 {
 
 /// [AI 생성] 글자 모양 목록
 ///
-/// 원본: `charShapes` 요소의 익명 타입
-/// 원본: `charShapes` 요소의 익명 타입. 전역 글자 스타일 풀입니다.
+/// 원본: `charProperties` 요소의 익명 타입
+/// 원본: `charProperties` 요소의 익명 타입. 전역 글자 스타일 풀입니다.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename = "charShapes")]
+#[serde(rename = "charProperties")]
 pub struct CharacterShapeList {
-    /// [AI 생성] 글자 모양 목록 (`charShape` 요소)
-    #[serde(rename = "charShape")]
+    /// [AI 생성] 글자 모양 목록 (`charPr` 요소)
+    #[serde(rename = "charPr", alias = "charShape")]
     pub character_shapes: Vec<CharacterShape>,
 
     /// [AI 생성] 항목 개수 (`itemCnt` 속성). 목록 길이 검증용.
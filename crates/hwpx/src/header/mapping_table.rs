@@ -71,12 +71,20 @@ pub struct MappingTable {
     #[serde(rename = "borderFills", skip_serializing_if = "Option::is_none")]
     pub border_fills: Option<BorderFillList>,
 
-    /// [AI 생성] 글자 모양 목록 (`charShapes` 요소)
-    #[serde(rename = "charShapes", skip_serializing_if = "Option::is_none")]
+    /// [AI 생성] 글자 모양 목록 (`charProperties` 요소)
+    #[serde(
+        rename = "charProperties",
+        alias = "charShapes",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub character_shapes: Option<CharacterShapeList>,
 
-    /// [AI 생성] 탭 정의 목록 (`tabDefs` 요소)
-    #[serde(rename = "tabDefs", skip_serializing_if = "Option::is_none")]
+    /// [AI 생성] 탭 정의 목록 (`tabProperties` 요소)
+    #[serde(
+        rename = "tabProperties",
+        alias = "tabDefs",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub tab_definitions: Option<TabDefinitionList>,
 
     /// [AI 생성] 번호 문단 모양 목록 (`numberings` 요소)
@@ -87,8 +95,12 @@ pub struct MappingTable {
     #[serde(rename = "bullets", skip_serializing_if = "Option::is_none")]
     pub bullets: Option<BulletList>,
 
-    /// [AI 생성] 문단 모양 목록 (`paraShapes` 요소)
-    #[serde(rename = "paraShapes", skip_serializing_if = "Option::is_none")]
+    /// [AI 생성] 문단 모양 목록 (`paraProperties` 요소)
+    #[serde(
+        rename = "paraProperties",
+        alias = "paraShapes",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub paragraph_shapes: Option<ParagraphShapeList>,
 
     /// [AI 생성] 스타일 목록 (`styles` 요소)
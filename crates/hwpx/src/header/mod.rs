@@ -53,10 +53,6 @@ pub struct Head {
     #[serde(rename = "compatibleDocument", skip_serializing_if = "Option::is_none")]
     pub compatible_document: Option<CompatibleDocument>,
 
-    /// [AI 생성] 문서 옵션 (`docOption` 요소)
-    #[serde(rename = "docOption", skip_serializing_if = "Option::is_none")]
-    pub document_option: Option<DocumentOption>,
-
     /// [AI 생성] 변경 추적 설정 (`trackchangeConfig` 요소, 일부 문서에서는 `trackchageConfig` 오타로 사용됨)
     #[serde(
         rename = "trackchangeConfig",
@@ -65,6 +61,10 @@ pub struct Head {
     )]
     pub track_change_config: Option<TrackChangeConfig>,
 
+    /// [AI 생성] 문서 옵션 (`docOption` 요소)
+    #[serde(rename = "docOption", skip_serializing_if = "Option::is_none")]
+    pub document_option: Option<DocumentOption>,
+
     /// [AI 생성] 버전 (`version` 속성)
     #[serde(rename = "@version")]
     pub version: String,
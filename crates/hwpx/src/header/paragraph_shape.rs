@@ -442,12 +442,12 @@ pub struct VersionSwitch {
 
 /// [AI 생성] 문단 모양 목록
 ///
-/// 원본: `paraShapes` 요소의 익명 타입
+/// 원본: `paraProperties` 요소의 익명 타입
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename = "paraShapes")]
+#[serde(rename = "paraProperties")]
 pub struct ParagraphShapeList {
-    /// [AI 생성] 문단 모양 목록 (`paraShape` 요소)
-    #[serde(rename = "paraShape")]
+    /// [AI 생성] 문단 모양 목록 (`paraPr` 요소)
+    #[serde(rename = "paraPr", alias = "paraShape")]
     pub paragraph_shapes: Vec<ParagraphShape>,
 
     /// [AI 생성] 항목 개수 (`itemCnt` 속성)
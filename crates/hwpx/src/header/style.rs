@@ -71,7 +71,7 @@ pub struct Style {
 #[serde(rename = "styles")]
 pub struct StyleList {
     /// [AI 생성] 스타일 목록 (`style` 요소)
-    #[serde(rename = "style")]
+    #[serde(rename = "style", default)]
     pub styles: Vec<Style>,
 
     /// [AI 생성] 항목 개수 (`itemCnt` 속성)
@@ -70,12 +70,12 @@ pub struct TabDefinition {
 
 /// [AI 생성] 탭 정의 목록
 ///
-/// 원본: `tabDefs` 요소의 익명 타입. 문서 전체 탭 스타일 컬렉션입니다.
+/// 원본: `tabProperties` 요소의 익명 타입. 문서 전체 탭 스타일 컬렉션입니다.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename = "tabDefs")]
+#[serde(rename = "tabProperties")]
 pub struct TabDefinitionList {
-    /// [AI 생성] 탭 정의 목록 (`tabDef` 요소)
-    #[serde(rename = "tabDef")]
+    /// [AI 생성] 탭 정의 목록 (`tabPr` 요소)
+    #[serde(rename = "tabPr", alias = "tabDef")]
     pub tab_definitions: Vec<TabDefinition>,
 
     /// [AI 생성] 항목 개수 (`itemCnt` 속성). 목록 길이 검증용.
@@ -16,6 +16,15 @@
 //! ## 사용 예시
 //!
 //! ```ignore
+//! // .hwpx 패키지 읽기/쓰기
+//! let bytes = std::fs::read("document.hwpx")?;
+//! let document = hwpx::Document::from_bytes(&bytes)?;
+//! std::fs::write("copy.hwpx", document.to_bytes()?)?;
+//! ```
+//!
+//! 개별 XML 파트를 직접 다룰 수도 있습니다.
+//!
+//! ```ignore
 //! use hwpx::version::HcfVersion;
 //! use hwpx::header::Head;
 //! use hwpx::paragraph::Section;
@@ -41,6 +50,11 @@
 //! - `master_page`: 바탕쪽
 //! - [`history`]: 변경 이력
 //! - [`version`]: 버전 정보
+//! - [`settings`]: 응용 프로그램 설정 (커서 위치, 설정 항목)
+//! - [`package`]: OCF 컨테이너 (ZIP 패키지) 경로와 메타데이터
+//! - [`signature`]: 전자서명 검증과 생성
+//! - [`schema`]: KS X 6101 XSD로 XML 파트 검증
+//! - [`preserve`]: 모델에 없는 요소와 속성 보존
 
 #![deny(clippy::all)]
 #![deny(missing_docs)]
@@ -48,13 +62,22 @@
 pub mod convert;
 pub mod core;
 pub mod document;
+pub mod error;
 pub mod header;
 pub mod history;
 pub mod master_page;
+pub mod package;
 pub mod paragraph;
+pub mod preserve;
+pub mod schema;
+pub mod settings;
+pub mod signature;
 pub mod version;
 
-pub use document::Document;
+pub use document::{Document, ReadOptions};
+pub use error::Error;
+pub use primitive::{LimitKind, Limits};
+pub use schema::validate;
 
 #[inline]
 /// 문자열 HWPX/XML을 지정 타입으로 역직렬화합니다. `quick_xml::de`를 래핑합니다.
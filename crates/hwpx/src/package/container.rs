@@ -0,0 +1,68 @@
+//! 컨테이너 메타데이터 (`META-INF/container.xml`)
+//!
+//! OCF 컨테이너의 루트 파일 목록입니다. 첫 번째 OWPML 패키지 루트 파일이
+//! `Contents/content.hpf` 위치를 알려 줍니다.
+
+use serde::{Deserialize, Serialize};
+
+/// OCF 컨테이너 네임스페이스
+pub const CONTAINER_NAMESPACE: &str = "urn:oasis:names:tc:opendocument:xmlns:container";
+
+/// OWPML 패키지 루트 파일의 미디어 타입
+pub const PACKAGE_MEDIA_TYPE: &str = "application/hwpml-package+xml";
+
+/// 컨테이너 루트 요소
+///
+/// 원본: `ocf:container` 요소
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename = "ocf:container")]
+pub struct Container {
+    /// 루트 파일 목록 (`rootfiles` 요소)
+    #[serde(
+        rename(serialize = "ocf:rootfiles", deserialize = "rootfiles"),
+        default
+    )]
+    pub root_files: RootFiles,
+}
+
+/// 루트 파일 목록
+///
+/// 원본: `ocf:rootfiles` 요소
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct RootFiles {
+    /// 루트 파일 (`rootfile` 요소)
+    #[serde(rename(serialize = "ocf:rootfile", deserialize = "rootfile"), default)]
+    pub items: Vec<RootFile>,
+}
+
+/// 루트 파일
+///
+/// 원본: `ocf:rootfile` 요소
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RootFile {
+    /// 패키지 내 전체 경로 (`full-path` 속성)
+    #[serde(rename = "@full-path")]
+    pub full_path: String,
+
+    /// 미디어 타입 (`media-type` 속성)
+    #[serde(rename = "@media-type")]
+    pub media_type: String,
+}
+
+impl Container {
+    /// 지정한 루트 파일들로 컨테이너 생성
+    pub fn new(items: Vec<RootFile>) -> Self {
+        Self {
+            root_files: RootFiles { items },
+        }
+    }
+
+    /// OWPML 패키지 루트 파일 (`content.hpf`) 경로
+    pub fn package_path(&self) -> Option<&str> {
+        self.root_files
+            .items
+            .iter()
+            .find(|item| item.media_type == PACKAGE_MEDIA_TYPE)
+            .map(|item| item.full_path.as_str())
+    }
+}
@@ -0,0 +1,239 @@
+//! 패키지 정보 (`Contents/content.hpf`)
+//!
+//! OPF 패키지 문서로, 문서 메타데이터와 패키지에 포함된 모든 리소스의 목록(매니페스트),
+//! 그리고 구역을 읽는 순서(스파인)를 담습니다. KS X 6101:2024 8.4절 OPF OWPML 프로파일.
+//!
+//! 한글은 `<opf:meta name="creator" content="text">`처럼 대부분의 메타데이터를
+//! `meta` 요소로 기록하므로 [`Metadata::meta`]로 이름별 값을 조회합니다.
+
+use serde::{Deserialize, Serialize};
+
+/// OPF 네임스페이스
+pub const OPF_NAMESPACE: &str = "http://www.idpf.org/2007/opf/";
+
+/// 메타데이터 이름: 지은이
+pub const META_CREATOR: &str = "creator";
+
+/// 메타데이터 이름: 주제
+pub const META_SUBJECT: &str = "subject";
+
+/// 메타데이터 이름: 기타 설명
+pub const META_DESCRIPTION: &str = "description";
+
+/// 메타데이터 이름: 마지막 저장자
+pub const META_LAST_SAVED_BY: &str = "lastsaveby";
+
+/// 메타데이터 이름: 작성된 시각
+pub const META_CREATED_DATE: &str = "CreatedDate";
+
+/// 메타데이터 이름: 수정된 시각
+pub const META_MODIFIED_DATE: &str = "ModifiedDate";
+
+/// 메타데이터 이름: 키워드
+pub const META_KEYWORD: &str = "keyword";
+
+/// OPF 패키지 (`package` 요소)
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename = "opf:package")]
+pub struct ContentHpf {
+    /// 패키지 버전 (`version`)
+    #[serde(rename = "@version", skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+
+    /// 고유 식별자로 쓰는 메타데이터 아이디 (`unique-identifier`)
+    #[serde(rename = "@unique-identifier", skip_serializing_if = "Option::is_none")]
+    pub unique_identifier: Option<String>,
+
+    /// 패키지 아이디 (`id`)
+    #[serde(rename = "@id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+
+    /// 메타데이터
+    #[serde(rename(serialize = "opf:metadata", deserialize = "metadata"), default)]
+    pub metadata: Metadata,
+
+    /// 매니페스트
+    #[serde(rename(serialize = "opf:manifest", deserialize = "manifest"), default)]
+    pub manifest: Manifest,
+
+    /// 스파인
+    #[serde(rename(serialize = "opf:spine", deserialize = "spine"), default)]
+    pub spine: Spine,
+}
+
+/// 문서 메타데이터 (`metadata` 요소)
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct Metadata {
+    /// 제목 (`title`)
+    #[serde(
+        rename(serialize = "opf:title", deserialize = "title"),
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub title: Option<String>,
+
+    /// 언어 (`language`)
+    #[serde(
+        rename(serialize = "opf:language", deserialize = "language"),
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub language: Option<String>,
+
+    /// 이름-값 메타데이터 목록 (`meta`)
+    #[serde(
+        rename(serialize = "opf:meta", deserialize = "meta"),
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub metas: Vec<Meta>,
+}
+
+/// 이름-값 메타데이터 (`meta` 요소)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Meta {
+    /// 메타데이터 이름 (`name`)
+    #[serde(rename = "@name")]
+    pub name: String,
+
+    /// 값의 형식 (`content`, 한글은 항상 `text`)
+    #[serde(rename = "@content", skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+
+    /// 값
+    #[serde(rename = "$text", default)]
+    pub value: String,
+}
+
+/// 리소스 목록 (`manifest` 요소)
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct Manifest {
+    /// 항목 목록
+    #[serde(rename(serialize = "opf:item", deserialize = "item"), default)]
+    pub items: Vec<ManifestItem>,
+}
+
+/// 리소스 항목 (`item` 요소)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ManifestItem {
+    /// 항목 아이디 (`id`, 본문의 `binaryItemIDRef`가 참조)
+    #[serde(rename = "@id")]
+    pub id: String,
+
+    /// 리소스 경로 (`href`)
+    #[serde(rename = "@href")]
+    pub href: String,
+
+    /// 미디어 타입 (`media-type`)
+    #[serde(rename = "@media-type", default)]
+    pub media_type: String,
+
+    /// 컨테이너 내 포함 여부 (`isEmbeded`)
+    ///
+    /// 표준 문서는 `isEmbedded`로 적고 있으나 한글이 실제로 쓰는 철자를 따릅니다.
+    #[serde(rename = "@isEmbeded", skip_serializing_if = "Option::is_none")]
+    pub is_embedded: Option<bool>,
+
+    /// 컨테이너 밖 리소스 경로 (`sub-path`)
+    #[serde(rename = "@sub-path", skip_serializing_if = "Option::is_none")]
+    pub sub_path: Option<String>,
+}
+
+/// 읽기 순서 (`spine` 요소)
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct Spine {
+    /// 항목 참조 목록
+    #[serde(rename(serialize = "opf:itemref", deserialize = "itemref"), default)]
+    pub item_refs: Vec<SpineItemRef>,
+}
+
+/// 스파인 항목 참조 (`itemref` 요소)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SpineItemRef {
+    /// 매니페스트 항목 아이디 참조 (`idref`)
+    #[serde(rename = "@idref")]
+    pub id_ref: String,
+
+    /// 선형 읽기 대상 여부 (`linear`, `yes`/`no`)
+    #[serde(rename = "@linear", skip_serializing_if = "Option::is_none")]
+    pub linear: Option<String>,
+}
+
+impl ContentHpf {
+    /// 아이디로 매니페스트 항목 찾기
+    pub fn item(&self, id: &str) -> Option<&ManifestItem> {
+        self.manifest.items.iter().find(|item| item.id == id)
+    }
+
+    /// 경로로 매니페스트 항목 찾기
+    pub fn item_by_href(&self, href: &str) -> Option<&ManifestItem> {
+        self.manifest.items.iter().find(|item| item.href == href)
+    }
+
+    /// 스파인 순서대로 매니페스트 항목 반환
+    pub fn spine_items(&self) -> impl Iterator<Item = &ManifestItem> {
+        self.spine
+            .item_refs
+            .iter()
+            .filter_map(|item_ref| self.item(&item_ref.id_ref))
+    }
+
+    /// 매니페스트에 항목 추가 (스파인 등록 여부 선택)
+    pub fn push_item(&mut self, item: ManifestItem, in_spine: bool) {
+        if in_spine {
+            self.spine.item_refs.push(SpineItemRef {
+                id_ref: item.id.clone(),
+                linear: Some("yes".to_string()),
+            });
+        }
+        self.manifest.items.push(item);
+    }
+}
+
+impl ManifestItem {
+    /// 경로의 확장자로 미디어 타입을 정해 항목 생성
+    pub fn new(id: impl Into<String>, href: impl Into<String>) -> Self {
+        let href = href.into();
+        Self {
+            id: id.into(),
+            media_type: super::media_type_from_path(&href).to_string(),
+            href,
+            is_embedded: None,
+            sub_path: None,
+        }
+    }
+
+    /// 미디어 타입 지정
+    pub fn with_media_type(mut self, media_type: impl Into<String>) -> Self {
+        self.media_type = media_type.into();
+        self
+    }
+
+    /// 컨테이너 내 포함 여부 지정
+    pub const fn with_embedded(mut self, embedded: bool) -> Self {
+        self.is_embedded = Some(embedded);
+        self
+    }
+}
+
+impl Metadata {
+    /// 이름으로 `meta` 값 조회 (빈 값은 `None`)
+    pub fn meta(&self, name: &str) -> Option<&str> {
+        self.metas
+            .iter()
+            .find(|meta| meta.name == name)
+            .map(|meta| meta.value.as_str())
+            .filter(|value| !value.is_empty())
+    }
+
+    /// 이름으로 `meta` 값 설정 (이미 있으면 교체)
+    pub fn set_meta(&mut self, name: &str, value: impl Into<String>) {
+        let value = value.into();
+        match self.metas.iter_mut().find(|meta| meta.name == name) {
+            Some(meta) => meta.value = value,
+            None => self.metas.push(Meta {
+                name: name.to_string(),
+                content: Some("text".to_string()),
+                value,
+            }),
+        }
+    }
+}
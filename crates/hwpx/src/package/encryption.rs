@@ -0,0 +1,386 @@
+//! 파트 암호화/복호화
+//!
+//! ODF 1.2 Part 3 3.4절의 암호화 방식을 따릅니다.
+//!
+//! 1. 시작 키: 암호(UTF-8)의 SHA-256 또는 SHA-1 해시
+//! 2. 암호화 키: 시작 키로 PBKDF2(HMAC-SHA1, 솔트, 반복 횟수) 유도
+//! 3. 파트 데이터: Deflate 압축 후 AES-CBC 암호화 (W3C XML Encryption 패딩)
+//! 4. 체크섬: 압축된 평문 앞 1024바이트의 해시로 암호가 맞는지 확인
+//!
+//! 암호화된 파트는 ZIP 안에 무압축으로 저장되고, 복호화 정보는 `META-INF/manifest.xml`에 기록됩니다.
+
+use aes::cipher::block_padding::{NoPadding, Pkcs7};
+use aes::cipher::{BlockCipher, BlockDecryptMut, BlockEncryptMut, KeyIvInit};
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use sha1::Sha1;
+use sha2::{Digest, Sha256};
+
+use super::manifest::{Algorithm, EncryptionData, KeyDerivation, StartKeyGeneration};
+use crate::error::{Error, Result};
+use miniz_oxide::inflate::TINFLStatus;
+use primitive::{LimitExceeded, LimitKind};
+
+/// AES-128-CBC 알고리즘 이름
+pub const AES128_CBC: &str = "http://www.w3.org/2001/04/xmlenc#aes128-cbc";
+
+/// AES-192-CBC 알고리즘 이름
+pub const AES192_CBC: &str = "http://www.w3.org/2001/04/xmlenc#aes192-cbc";
+
+/// AES-256-CBC 알고리즘 이름
+pub const AES256_CBC: &str = "http://www.w3.org/2001/04/xmlenc#aes256-cbc";
+
+/// PBKDF2 키 유도 이름
+pub const PBKDF2: &str = "urn:oasis:names:tc:opendocument:xmlns:manifest:1.0#pbkdf2";
+
+/// SHA-1 시작 키 생성 이름
+pub const START_KEY_SHA1: &str = "http://www.w3.org/2000/09/xmldsig#sha1";
+
+/// SHA-256 시작 키 생성 이름
+pub const START_KEY_SHA256: &str = "http://www.w3.org/2000/09/xmldsig#sha256";
+
+/// SHA-1 체크섬(앞 1024바이트) 이름
+pub const CHECKSUM_SHA1_1K: &str = "urn:oasis:names:tc:opendocument:xmlns:manifest:1.0#sha1-1k";
+
+/// SHA-256 체크섬(앞 1024바이트) 이름
+pub const CHECKSUM_SHA256_1K: &str = "urn:oasis:names:tc:opendocument:xmlns:manifest:1.0#sha256-1k";
+
+/// 읽을 때 허용하는 최대 PBKDF2 반복 횟수
+///
+/// 반복 횟수는 매니페스트 값을 그대로 쓰므로, 파트마다 키를 유도하는 데 드는 시간을 제한합니다.
+pub const MAX_ITERATION_COUNT: u32 = 1_000_000;
+
+/// 체크섬 대상 길이
+const CHECKSUM_LENGTH: usize = 1024;
+
+/// AES 블록 크기 (초기화 벡터 길이)
+const BLOCK_SIZE: usize = 16;
+
+/// 솔트 길이
+const SALT_LENGTH: usize = 16;
+
+/// 데이터 암호화 알고리즘
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EncryptionAlgorithm {
+    /// AES-128-CBC
+    Aes128Cbc,
+    /// AES-192-CBC
+    Aes192Cbc,
+    /// AES-256-CBC
+    #[default]
+    Aes256Cbc,
+}
+
+/// 시작 키 해시 알고리즘
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StartKeyHash {
+    /// SHA-1
+    Sha1,
+    /// SHA-256
+    #[default]
+    Sha256,
+}
+
+/// 저장 시 암호화 설정
+///
+/// 기본값은 한글과 같은 AES-256-CBC, PBKDF2 1024회, SHA-256 시작 키입니다.
+#[derive(Debug, Clone)]
+pub struct EncryptionOptions {
+    /// 문서 암호
+    pub password: String,
+    /// 데이터 암호화 알고리즘
+    pub algorithm: EncryptionAlgorithm,
+    /// 시작 키 해시 알고리즘
+    pub start_key_hash: StartKeyHash,
+    /// PBKDF2 반복 횟수
+    pub iteration_count: u32,
+}
+
+impl EncryptionOptions {
+    /// 기본 설정으로 암호화 옵션 생성
+    pub fn new(password: impl Into<String>) -> Self {
+        Self {
+            password: password.into(),
+            algorithm: EncryptionAlgorithm::default(),
+            start_key_hash: StartKeyHash::default(),
+            iteration_count: 1024,
+        }
+    }
+
+    /// 데이터 암호화 알고리즘 지정
+    pub const fn with_algorithm(mut self, algorithm: EncryptionAlgorithm) -> Self {
+        self.algorithm = algorithm;
+        self
+    }
+
+    /// 시작 키 해시 알고리즘 지정
+    pub const fn with_start_key_hash(mut self, start_key_hash: StartKeyHash) -> Self {
+        self.start_key_hash = start_key_hash;
+        self
+    }
+
+    /// PBKDF2 반복 횟수 지정
+    ///
+    /// [`MAX_ITERATION_COUNT`]를 넘으면 이 크레이트로 다시 읽을 수 없습니다.
+    pub const fn with_iteration_count(mut self, iteration_count: u32) -> Self {
+        self.iteration_count = iteration_count;
+        self
+    }
+}
+
+impl EncryptionAlgorithm {
+    /// 매니페스트에 기록되는 알고리즘 이름
+    pub const fn name(&self) -> &'static str {
+        match self {
+            EncryptionAlgorithm::Aes128Cbc => AES128_CBC,
+            EncryptionAlgorithm::Aes192Cbc => AES192_CBC,
+            EncryptionAlgorithm::Aes256Cbc => AES256_CBC,
+        }
+    }
+
+    /// 키 길이 (바이트)
+    pub const fn key_size(&self) -> usize {
+        match self {
+            EncryptionAlgorithm::Aes128Cbc => 16,
+            EncryptionAlgorithm::Aes192Cbc => 24,
+            EncryptionAlgorithm::Aes256Cbc => 32,
+        }
+    }
+
+    /// 알고리즘 이름에서 변환
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            AES128_CBC => Some(EncryptionAlgorithm::Aes128Cbc),
+            AES192_CBC => Some(EncryptionAlgorithm::Aes192Cbc),
+            AES256_CBC => Some(EncryptionAlgorithm::Aes256Cbc),
+            _ => None,
+        }
+    }
+}
+
+impl StartKeyHash {
+    /// 매니페스트에 기록되는 알고리즘 이름
+    pub const fn name(&self) -> &'static str {
+        match self {
+            StartKeyHash::Sha1 => START_KEY_SHA1,
+            StartKeyHash::Sha256 => START_KEY_SHA256,
+        }
+    }
+
+    /// 알고리즘 이름에서 변환 (ODF 1.1의 `SHA1` 표기 포함)
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            START_KEY_SHA1 | "SHA1" => Some(StartKeyHash::Sha1),
+            START_KEY_SHA256 | "urn:oasis:names:tc:opendocument:xmlns:manifest:1.0#sha256" => {
+                Some(StartKeyHash::Sha256)
+            }
+            _ => None,
+        }
+    }
+
+    fn hash(&self, password: &str) -> Vec<u8> {
+        match self {
+            StartKeyHash::Sha1 => Sha1::digest(password.as_bytes()).to_vec(),
+            StartKeyHash::Sha256 => Sha256::digest(password.as_bytes()).to_vec(),
+        }
+    }
+}
+
+/// 암호화된 파트 복호화
+///
+/// 반복 횟수가 [`MAX_ITERATION_COUNT`]를 넘으면 키를 유도하기 전에 [`Error::InvalidEncryptionData`]를 반환합니다.
+/// 체크섬이 맞지 않으면 [`Error::InvalidPassword`]를 반환합니다.
+/// 압축 해제에 실패하거나 결과 길이가 `size`(암호화 전 크기)와 다르면 [`Error::Decrypt`]를 반환합니다.
+/// 압축 해제 결과가 `limit`을 넘으면 `kind` 제한 초과 오류를 반환합니다.
+pub(crate) fn decrypt_part(
+    part: &str,
+    data: &[u8],
+    encryption: &EncryptionData,
+    size: Option<u64>,
+    password: &str,
+    kind: LimitKind,
+    limit: u64,
+) -> Result<Vec<u8>> {
+    let exceeded = || Error::from(LimitExceeded { kind, limit });
+    if size.is_some_and(|size| size > limit) {
+        return Err(exceeded());
+    }
+
+    let algorithm = EncryptionAlgorithm::from_name(&encryption.algorithm.name)
+        .ok_or_else(|| Error::UnsupportedEncryption(encryption.algorithm.name.clone()))?;
+    let start_key_hash = match &encryption.start_key_generation {
+        Some(generation) => StartKeyHash::from_name(&generation.name)
+            .ok_or_else(|| Error::UnsupportedEncryption(generation.name.clone()))?,
+        None => StartKeyHash::Sha1,
+    };
+    let derivation = &encryption.key_derivation;
+    if !is_pbkdf2(&derivation.name) {
+        return Err(Error::UnsupportedEncryption(derivation.name.clone()));
+    }
+
+    let invalid = || Error::InvalidEncryptionData {
+        part: part.to_string(),
+    };
+    let iv = BASE64
+        .decode(&encryption.algorithm.initialisation_vector)
+        .map_err(|_| invalid())?;
+    let salt = BASE64.decode(&derivation.salt).map_err(|_| invalid())?;
+    let expected_checksum = BASE64.decode(&encryption.checksum).map_err(|_| invalid())?;
+
+    let key_size = derivation.key_size.unwrap_or(algorithm.key_size());
+    if key_size != algorithm.key_size() || derivation.iteration_count > MAX_ITERATION_COUNT {
+        return Err(invalid());
+    }
+    let key = derive_key(
+        &start_key_hash.hash(password),
+        &salt,
+        derivation.iteration_count,
+        key_size,
+    );
+
+    let mut plain = cbc_decrypt(algorithm, &key, &iv, data).ok_or_else(invalid)?;
+    strip_padding(&mut plain);
+
+    let checksum = checksum(&encryption.checksum_type, &plain)
+        .ok_or_else(|| Error::UnsupportedEncryption(encryption.checksum_type.clone()))?;
+    if checksum != expected_checksum {
+        return Err(Error::InvalidPassword {
+            part: part.to_string(),
+        });
+    }
+
+    // ODF는 암호화 전에 Deflate 압축하므로, 압축 해제 결과가 매니페스트 크기와 맞아야 함
+    let corrupted = || Error::Decrypt {
+        part: part.to_string(),
+    };
+    let max_size = usize::try_from(limit).unwrap_or(usize::MAX);
+    match miniz_oxide::inflate::decompress_to_vec_with_limit(&plain, max_size) {
+        Ok(inflated) if size.is_none_or(|size| size == inflated.len() as u64) => Ok(inflated),
+        Ok(_) => Err(corrupted()),
+        Err(err) if err.status == TINFLStatus::HasMoreOutput => Err(exceeded()),
+        // 압축하지 않은 파트는 매니페스트 크기가 평문 길이와 같을 때만 허용
+        Err(_) if size == Some(plain.len() as u64) => Ok(plain),
+        Err(_) => Err(corrupted()),
+    }
+}
+
+/// 파트를 압축 후 암호화하고 매니페스트에 기록할 암호화 정보 반환
+pub(crate) fn encrypt_part(
+    data: &[u8],
+    options: &EncryptionOptions,
+) -> Result<(Vec<u8>, EncryptionData)> {
+    let algorithm = options.algorithm;
+    let mut iv = [0u8; BLOCK_SIZE];
+    let mut salt = [0u8; SALT_LENGTH];
+    getrandom::fill(&mut iv).map_err(std::io::Error::other)?;
+    getrandom::fill(&mut salt).map_err(std::io::Error::other)?;
+
+    let key = derive_key(
+        &options.start_key_hash.hash(&options.password),
+        &salt,
+        options.iteration_count,
+        algorithm.key_size(),
+    );
+
+    let compressed = miniz_oxide::deflate::compress_to_vec(data, 6);
+    let checksum = checksum(CHECKSUM_SHA256_1K, &compressed).unwrap_or_default();
+    let encrypted = cbc_encrypt(algorithm, &key, &iv, &compressed);
+
+    let encryption = EncryptionData {
+        checksum_type: CHECKSUM_SHA256_1K.to_string(),
+        checksum: BASE64.encode(checksum),
+        algorithm: Algorithm {
+            name: algorithm.name().to_string(),
+            initialisation_vector: BASE64.encode(iv),
+        },
+        key_derivation: KeyDerivation {
+            name: PBKDF2.to_string(),
+            key_size: Some(algorithm.key_size()),
+            iteration_count: options.iteration_count,
+            salt: BASE64.encode(salt),
+        },
+        start_key_generation: Some(StartKeyGeneration {
+            name: options.start_key_hash.name().to_string(),
+            key_size: Some(match options.start_key_hash {
+                StartKeyHash::Sha1 => 20,
+                StartKeyHash::Sha256 => 32,
+            }),
+        }),
+    };
+    Ok((encrypted, encryption))
+}
+
+/// PBKDF2 이름 여부 (ODF 1.1의 `PBKDF2` 표기 포함)
+fn is_pbkdf2(name: &str) -> bool {
+    name == PBKDF2 || name == "PBKDF2"
+}
+
+/// PBKDF2(HMAC-SHA1)로 암호화 키 유도
+fn derive_key(start_key: &[u8], salt: &[u8], iteration_count: u32, key_size: usize) -> Vec<u8> {
+    let mut key = vec![0u8; key_size];
+    pbkdf2::pbkdf2_hmac::<Sha1>(start_key, salt, iteration_count, &mut key);
+    key
+}
+
+/// 앞 1024바이트의 체크섬 계산 (지원하지 않는 방식이면 `None`)
+fn checksum(checksum_type: &str, data: &[u8]) -> Option<Vec<u8>> {
+    let head = &data[..data.len().min(CHECKSUM_LENGTH)];
+    match checksum_type {
+        CHECKSUM_SHA256_1K => Some(Sha256::digest(head).to_vec()),
+        CHECKSUM_SHA1_1K | "SHA1/1K" => Some(Sha1::digest(head).to_vec()),
+        _ => None,
+    }
+}
+
+/// W3C XML Encryption 패딩 제거 (마지막 바이트가 패딩 길이)
+fn strip_padding(data: &mut Vec<u8>) {
+    if let Some(&padding) = data.last() {
+        let padding = padding as usize;
+        if (1..=BLOCK_SIZE).contains(&padding) && padding <= data.len() {
+            data.truncate(data.len() - padding);
+        }
+    }
+}
+
+fn cbc_decrypt(
+    algorithm: EncryptionAlgorithm,
+    key: &[u8],
+    iv: &[u8],
+    data: &[u8],
+) -> Option<Vec<u8>> {
+    match algorithm {
+        EncryptionAlgorithm::Aes128Cbc => decrypt_with::<aes::Aes128>(key, iv, data),
+        EncryptionAlgorithm::Aes192Cbc => decrypt_with::<aes::Aes192>(key, iv, data),
+        EncryptionAlgorithm::Aes256Cbc => decrypt_with::<aes::Aes256>(key, iv, data),
+    }
+}
+
+fn cbc_encrypt(algorithm: EncryptionAlgorithm, key: &[u8], iv: &[u8], data: &[u8]) -> Vec<u8> {
+    match algorithm {
+        EncryptionAlgorithm::Aes128Cbc => encrypt_with::<aes::Aes128>(key, iv, data),
+        EncryptionAlgorithm::Aes192Cbc => encrypt_with::<aes::Aes192>(key, iv, data),
+        EncryptionAlgorithm::Aes256Cbc => encrypt_with::<aes::Aes256>(key, iv, data),
+    }
+}
+
+fn decrypt_with<C>(key: &[u8], iv: &[u8], data: &[u8]) -> Option<Vec<u8>>
+where
+    C: BlockCipher + BlockDecryptMut,
+    cbc::Decryptor<C>: KeyIvInit,
+{
+    cbc::Decryptor::<C>::new_from_slices(key, iv)
+        .ok()?
+        .decrypt_padded_vec_mut::<NoPadding>(data)
+        .ok()
+}
+
+fn encrypt_with<C>(key: &[u8], iv: &[u8], data: &[u8]) -> Vec<u8>
+where
+    C: BlockCipher + BlockEncryptMut,
+    cbc::Encryptor<C>: KeyIvInit,
+{
+    // 키/IV 길이는 알고리즘에 맞춰 생성하므로 실패하지 않음
+    cbc::Encryptor::<C>::new_from_slices(key, iv)
+        .expect("key and IV sizes match the algorithm")
+        .encrypt_padded_vec_mut::<Pkcs7>(data)
+}
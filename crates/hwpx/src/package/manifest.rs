@@ -0,0 +1,155 @@
+//! 파일 목록 메타데이터 (`META-INF/manifest.xml`)
+//!
+//! ODF 매니페스트로, 암호화된 파트마다 복호화에 필요한 정보(알고리즘, 키 유도 방식,
+//! 암호 확인용 체크섬)를 담습니다. KS X 6101:2024 15장 암호화.
+
+use serde::{Deserialize, Serialize};
+
+/// ODF 매니페스트 네임스페이스 (접두어 `odf`)
+pub const MANIFEST_NAMESPACE: &str = "urn:oasis:names:tc:opendocument:xmlns:manifest:1.0";
+
+/// 파일 목록 (`manifest` 요소)
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename = "odf:manifest")]
+pub struct FileManifest {
+    /// 파일 항목 목록 (`file-entry`)
+    #[serde(
+        rename(serialize = "odf:file-entry", deserialize = "file-entry"),
+        default
+    )]
+    pub file_entries: Vec<FileEntry>,
+}
+
+/// 파일 항목 (`file-entry` 요소)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FileEntry {
+    /// 패키지 내 파일 경로 (`full-path`)
+    #[serde(rename(serialize = "@odf:full-path", deserialize = "@full-path"))]
+    pub full_path: String,
+
+    /// 미디어 타입 (`media-type`)
+    #[serde(
+        rename(serialize = "@odf:media-type", deserialize = "@media-type"),
+        default
+    )]
+    pub media_type: String,
+
+    /// 암호화 전 파일 크기 (`size`)
+    #[serde(
+        rename(serialize = "@odf:size", deserialize = "@size"),
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub size: Option<u64>,
+
+    /// 암호화 정보 (`encryption-data`)
+    #[serde(
+        rename(serialize = "odf:encryption-data", deserialize = "encryption-data"),
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub encryption_data: Option<EncryptionData>,
+}
+
+/// 암호화 정보 (`encryption-data` 요소)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EncryptionData {
+    /// 암호 확인 체크섬 알고리즘 (`checksum-type`)
+    #[serde(rename(serialize = "@odf:checksum-type", deserialize = "@checksum-type"))]
+    pub checksum_type: String,
+
+    /// 암호 확인 체크섬 (`checksum`, base64)
+    #[serde(rename(serialize = "@odf:checksum", deserialize = "@checksum"))]
+    pub checksum: String,
+
+    /// 데이터 암호화 알고리즘 (`algorithm`)
+    #[serde(rename(serialize = "odf:algorithm", deserialize = "algorithm"))]
+    pub algorithm: Algorithm,
+
+    /// 키 유도 방식 (`key-derivation`)
+    #[serde(rename(serialize = "odf:key-derivation", deserialize = "key-derivation"))]
+    pub key_derivation: KeyDerivation,
+
+    /// 시작 키 생성 방식 (`start-key-generation`, 없으면 SHA1)
+    #[serde(
+        rename(
+            serialize = "odf:start-key-generation",
+            deserialize = "start-key-generation"
+        ),
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub start_key_generation: Option<StartKeyGeneration>,
+}
+
+/// 데이터 암호화 알고리즘 (`algorithm` 요소)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Algorithm {
+    /// 알고리즘 이름 (`algorithm-name`, 예: `http://www.w3.org/2001/04/xmlenc#aes256-cbc`)
+    #[serde(rename(serialize = "@odf:algorithm-name", deserialize = "@algorithm-name"))]
+    pub name: String,
+
+    /// 초기화 벡터 (`initialisation-vector`, base64)
+    #[serde(rename(
+        serialize = "@odf:initialisation-vector",
+        deserialize = "@initialisation-vector"
+    ))]
+    pub initialisation_vector: String,
+}
+
+/// 키 유도 방식 (`key-derivation` 요소)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KeyDerivation {
+    /// 키 유도 알고리즘 이름 (`key-derivation-name`, 예: `...#pbkdf2`)
+    #[serde(rename(
+        serialize = "@odf:key-derivation-name",
+        deserialize = "@key-derivation-name"
+    ))]
+    pub name: String,
+
+    /// 키 길이 (바이트, `key-size`)
+    #[serde(
+        rename(serialize = "@odf:key-size", deserialize = "@key-size"),
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub key_size: Option<usize>,
+
+    /// 반복 횟수 (`iteration-count`)
+    #[serde(rename(serialize = "@odf:iteration-count", deserialize = "@iteration-count"))]
+    pub iteration_count: u32,
+
+    /// 솔트 (`salt`, base64)
+    #[serde(rename(serialize = "@odf:salt", deserialize = "@salt"))]
+    pub salt: String,
+}
+
+/// 시작 키 생성 방식 (`start-key-generation` 요소)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StartKeyGeneration {
+    /// 해시 알고리즘 이름 (`start-key-generation-name`, 예: `...xmldsig#sha256`)
+    #[serde(rename(
+        serialize = "@odf:start-key-generation-name",
+        deserialize = "@start-key-generation-name"
+    ))]
+    pub name: String,
+
+    /// 시작 키 길이 (바이트, `key-size`)
+    #[serde(
+        rename(serialize = "@odf:key-size", deserialize = "@key-size"),
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub key_size: Option<usize>,
+}
+
+impl FileManifest {
+    /// 경로로 파일 항목 찾기
+    pub fn entry(&self, full_path: &str) -> Option<&FileEntry> {
+        self.file_entries
+            .iter()
+            .find(|entry| entry.full_path == full_path)
+    }
+
+    /// 암호화된 파트가 하나라도 있는지 여부
+    pub fn is_encrypted(&self) -> bool {
+        self.file_entries
+            .iter()
+            .any(|entry| entry.encryption_data.is_some())
+    }
+}
@@ -0,0 +1,107 @@
+//! HWPX 패키지 (OCF 컨테이너)
+//!
+//! HWPX 파일은 여러 XML 파트와 바이너리 파일을 ZIP으로 묶은 OCF 컨테이너입니다.
+//! 이 모듈은 컨테이너 안의 파트 경로 규칙과 ZIP 입출력을 담당합니다.
+//! KS X 6101:2024 8장(컨테이너 및 패키징)을 근거로 합니다.
+
+mod container;
+mod content;
+mod encryption;
+mod manifest;
+mod reader;
+mod writer;
+
+pub use container::{CONTAINER_NAMESPACE, Container, PACKAGE_MEDIA_TYPE, RootFile, RootFiles};
+pub use content::{
+    ContentHpf, META_CREATED_DATE, META_CREATOR, META_DESCRIPTION, META_KEYWORD,
+    META_LAST_SAVED_BY, META_MODIFIED_DATE, META_SUBJECT, Manifest, ManifestItem, Meta, Metadata,
+    OPF_NAMESPACE, Spine, SpineItemRef,
+};
+pub use encryption::{
+    AES128_CBC, AES192_CBC, AES256_CBC, CHECKSUM_SHA1_1K, CHECKSUM_SHA256_1K, EncryptionAlgorithm,
+    EncryptionOptions, MAX_ITERATION_COUNT, PBKDF2, START_KEY_SHA1, START_KEY_SHA256, StartKeyHash,
+};
+pub use manifest::{
+    Algorithm, EncryptionData, FileEntry, FileManifest, KeyDerivation, MANIFEST_NAMESPACE,
+    StartKeyGeneration,
+};
+
+pub(crate) use reader::PackageReader;
+pub(crate) use writer::{PackageWriter, XML_DECLARATION, to_xml_part};
+
+/// HWPX `mimetype` 파트 내용
+pub const MIMETYPE: &str = "application/hwp+zip";
+
+/// `mimetype` 파트 경로 (패키지의 첫 번째 항목, 무압축)
+pub const MIMETYPE_PATH: &str = "mimetype";
+
+/// 버전 정보 파트 경로
+pub const VERSION_PATH: &str = "version.xml";
+
+/// 컨테이너 메타데이터 파트 경로
+pub const CONTAINER_PATH: &str = "META-INF/container.xml";
+
+/// 파일 목록 메타데이터 파트 경로
+pub const MANIFEST_PATH: &str = "META-INF/manifest.xml";
+
+/// 전자서명 파트 경로
+pub const SIGNATURES_PATH: &str = "META-INF/signatures.xml";
+
+/// 패키지 정보 파트 기본 경로
+pub const CONTENT_HPF_PATH: &str = "Contents/content.hpf";
+
+/// 헤더 파트 기본 경로
+pub const HEADER_PATH: &str = "Contents/header.xml";
+
+/// 응용 프로그램 설정 파트 기본 경로
+pub const SETTINGS_PATH: &str = "settings.xml";
+
+/// 미리보기 텍스트 파트 경로
+pub const PREVIEW_TEXT_PATH: &str = "Preview/PrvText.txt";
+
+/// 미리보기 이미지 파트 경로
+pub const PREVIEW_IMAGE_PATH: &str = "Preview/PrvImage.png";
+
+/// 바이너리 데이터 디렉터리
+pub const BIN_DATA_DIR: &str = "BinData/";
+
+/// 바탕쪽 디렉터리
+pub const MASTER_PAGE_DIR: &str = "MasterPage/";
+
+/// 구역 파트 기본 경로 (`Contents/section{index}.xml`)
+pub fn section_path(index: usize) -> String {
+    format!("Contents/section{}.xml", index)
+}
+
+/// 바탕쪽 파트 기본 경로 (`Contents/masterpage{index}.xml`)
+pub fn master_page_path(index: usize) -> String {
+    format!("Contents/masterpage{}.xml", index)
+}
+
+/// 확장자로 미디어 타입 추측
+pub fn media_type_from_path(path: &str) -> &'static str {
+    let extension = path.rsplit('.').next().unwrap_or("").to_lowercase();
+    match extension.as_str() {
+        "xml" | "hpf" => "application/xml",
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "bmp" => "image/bmp",
+        "tif" | "tiff" => "image/tiff",
+        "wmf" => "image/x-wmf",
+        "emf" => "image/x-emf",
+        "svg" => "image/svg+xml",
+        "txt" => "text/plain",
+        "js" => "application/x-javascript",
+        "ole" => "application/x-ole-object",
+        "hwp" => "application/x-hwp",
+        "hwpx" => MIMETYPE,
+        _ => "application/octet-stream",
+    }
+}
+
+/// 경로의 파일 이름에서 확장자를 제외한 부분
+pub fn file_stem(path: &str) -> &str {
+    let name = path.rsplit('/').next().unwrap_or(path);
+    name.split_once('.').map_or(name, |(stem, _)| stem)
+}
@@ -0,0 +1,218 @@
+//! ZIP 컨테이너 읽기
+
+use std::io::{Read, Seek};
+
+use primitive::{LimitExceeded, LimitKind, Limits};
+use quick_xml::Reader;
+use quick_xml::events::Event;
+use serde::de::DeserializeOwned;
+use zip::ZipArchive;
+use zip::result::ZipError;
+
+use super::MANIFEST_PATH;
+use super::encryption::decrypt_part;
+use super::manifest::FileManifest;
+use crate::error::{Error, Result};
+
+/// 패키지 파트 읽기 도우미
+///
+/// ZIP 아카이브를 열어 두고 파트 단위로 바이트, 텍스트, XML 모델을 꺼냅니다.
+/// [`PackageReader::load_manifest`] 이후에는 암호화된 파트를 읽을 때 자동으로 복호화합니다.
+/// 파트 크기와 중첩 깊이는 [`Limits`]로 제한합니다.
+pub(crate) struct PackageReader<R> {
+    archive: ZipArchive<R>,
+    manifest: FileManifest,
+    password: Option<String>,
+    limits: Limits,
+    /// 지금까지 읽은 파트의 압축 해제 크기 합
+    total_size: u64,
+}
+
+impl<R: Read + Seek> PackageReader<R> {
+    /// ZIP 아카이브 열기
+    pub fn new(reader: R) -> Result<Self> {
+        Ok(Self {
+            archive: ZipArchive::new(reader)?,
+            manifest: FileManifest::default(),
+            password: None,
+            limits: Limits::default(),
+            total_size: 0,
+        })
+    }
+
+    /// 자원 제한 지정
+    pub fn with_limits(mut self, limits: Limits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// `META-INF/manifest.xml`의 암호화 정보 읽기
+    ///
+    /// 암호화된 파트가 있는데 암호가 없으면 [`Error::PasswordRequired`]를 반환합니다.
+    pub fn load_manifest(&mut self, password: Option<&str>) -> Result<()> {
+        if self.contains(MANIFEST_PATH) {
+            self.manifest = self.read_xml(MANIFEST_PATH)?;
+        }
+        if self.manifest.is_encrypted() && password.is_none() {
+            return Err(Error::PasswordRequired);
+        }
+        self.password = password.map(str::to_string);
+        Ok(())
+    }
+
+    /// 파트 존재 여부
+    pub fn contains(&self, name: &str) -> bool {
+        self.archive.index_for_name(name).is_some()
+    }
+
+    /// 디렉터리를 제외한 모든 파트 경로 (아카이브 순서)
+    pub fn names(&self) -> Vec<String> {
+        self.archive
+            .file_names()
+            .filter(|name| !name.ends_with('/'))
+            .map(str::to_string)
+            .collect()
+    }
+
+    /// 파트를 바이트로 읽기
+    ///
+    /// `BinData/` 아래 파트는 [`Limits::max_binary_data_size`], 나머지는
+    /// [`Limits::max_stream_size`]를 넘으면 [`Error::LimitExceeded`]를 반환합니다.
+    /// ZIP 항목에 기록된 크기를 믿지 않고 실제로 풀린 바이트 수로 검사합니다.
+    pub fn read(&mut self, name: &str) -> Result<Vec<u8>> {
+        let kind = if name.starts_with("BinData/") {
+            LimitKind::BinaryDataSize
+        } else {
+            LimitKind::StreamSize
+        };
+        let limit = self.limits.get(kind);
+
+        let file = self.archive.by_name(name).map_err(|err| match err {
+            ZipError::FileNotFound => Error::MissingPart {
+                name: name.to_string(),
+            },
+            err => Error::Zip(err),
+        })?;
+        self.limits.check(kind, file.size())?;
+        // ZIP 항목이 밝힌 크기로 미리 할당하지 않고 실제로 읽은 만큼만 늘린다
+        let mut data = Vec::new();
+        file.take(limit.saturating_add(1)).read_to_end(&mut data)?;
+        self.limits.check(kind, data.len() as u64)?;
+
+        let data = match self.manifest.entry(name) {
+            Some(entry) if let Some(encryption) = &entry.encryption_data => {
+                let password = self.password.as_deref().ok_or(Error::PasswordRequired)?;
+                decrypt_part(name, &data, encryption, entry.size, password, kind, limit)?
+            }
+            _ => data,
+        };
+
+        self.total_size = self.total_size.saturating_add(data.len() as u64);
+        self.limits.check(LimitKind::TotalSize, self.total_size)?;
+        Ok(data)
+    }
+
+    /// 파트를 UTF-8 텍스트로 읽기 (BOM 제거)
+    pub fn read_string(&mut self, name: &str) -> Result<String> {
+        let data = self.read(name)?;
+        let text = String::from_utf8(data).map_err(|_| Error::InvalidText {
+            part: name.to_string(),
+        })?;
+        Ok(match text.strip_prefix('\u{FEFF}') {
+            Some(stripped) => stripped.to_string(),
+            None => text,
+        })
+    }
+
+    /// 파트를 XML 텍스트로 읽기
+    ///
+    /// 문단 목록(`subList`)과 묶음 개체(`container`)의 중첩이
+    /// [`Limits::max_nesting_depth`]를 넘거나, 요소 깊이가 그 중첩 깊이로 허용되는
+    /// 범위를 넘으면 [`Error::LimitExceeded`]를 반환합니다.
+    pub fn read_xml_string(&mut self, name: &str) -> Result<String> {
+        let xml = self.read_string(name)?;
+        let depth = XmlDepth::measure(&xml);
+        self.limits.check(LimitKind::NestingDepth, depth.nesting)?;
+        if depth.elements > element_depth_limit(&self.limits) {
+            return Err(LimitExceeded {
+                kind: LimitKind::NestingDepth,
+                limit: self.limits.get(LimitKind::NestingDepth),
+            }
+            .into());
+        }
+        Ok(xml)
+    }
+
+    /// 파트를 XML 모델로 역직렬화
+    pub fn read_xml<T: DeserializeOwned>(&mut self, name: &str) -> Result<T> {
+        let xml = self.read_xml_string(name)?;
+        crate::from_str(&xml).map_err(|source| Error::Deserialize {
+            part: name.to_string(),
+            source,
+        })
+    }
+}
+
+/// 중첩 구조를 이루는 요소: 문단 목록(표 셀, 글상자, 각주 등)과 묶음 개체
+const NESTING_ELEMENTS: [&[u8]; 2] = [b"subList", b"container"];
+
+/// 중첩 구조 밖에서 허용하는 요소 깊이 (구역 → 문단 → 실행 → 개체 → 속성 …)
+const BASE_ELEMENT_DEPTH: u64 = 16;
+
+/// 중첩 한 단계(표 → 행 → 셀 → 문단 목록 → 문단 → 실행 …)에 허용하는 요소 깊이
+const ELEMENTS_PER_NESTING_LEVEL: u64 = 8;
+
+/// 중첩 깊이 제한에서 정한 요소 깊이 상한
+fn element_depth_limit(limits: &Limits) -> u64 {
+    limits
+        .get(LimitKind::NestingDepth)
+        .saturating_mul(ELEMENTS_PER_NESTING_LEVEL)
+        .saturating_add(BASE_ELEMENT_DEPTH)
+}
+
+/// XML 파트의 최대 깊이
+///
+/// 잘못된 XML이면 그 지점까지의 깊이를 잽니다. 오류는 역직렬화에서 보고합니다.
+#[derive(Debug, Default)]
+struct XmlDepth {
+    /// [`NESTING_ELEMENTS`]의 최대 중첩 깊이
+    nesting: u64,
+    /// 모든 요소의 최대 중첩 깊이
+    elements: u64,
+}
+
+impl XmlDepth {
+    fn measure(xml: &str) -> Self {
+        let mut reader = Reader::from_str(xml);
+        let mut stack = Vec::new();
+        let mut nesting = 0;
+        let mut max = Self::default();
+        loop {
+            match reader.read_event() {
+                Ok(Event::Start(start)) => {
+                    let is_nesting = NESTING_ELEMENTS.contains(&start.local_name().as_ref());
+                    if is_nesting {
+                        nesting += 1;
+                        max.nesting = max.nesting.max(nesting);
+                    }
+                    stack.push(is_nesting);
+                    max.elements = max.elements.max(stack.len() as u64);
+                }
+                Ok(Event::Empty(start)) => {
+                    if NESTING_ELEMENTS.contains(&start.local_name().as_ref()) {
+                        max.nesting = max.nesting.max(nesting + 1);
+                    }
+                    max.elements = max.elements.max(stack.len() as u64 + 1);
+                }
+                Ok(Event::End(_)) => {
+                    if stack.pop() == Some(true) {
+                        nesting -= 1;
+                    }
+                }
+                Ok(Event::Eof) | Err(_) => break,
+                Ok(_) => {}
+            }
+        }
+        max
+    }
+}
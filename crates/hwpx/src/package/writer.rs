@@ -0,0 +1,143 @@
+//! ZIP 컨테이너 쓰기
+
+use std::io::{Seek, Write};
+
+use serde::Serialize;
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipWriter};
+
+use super::encryption::{EncryptionOptions, encrypt_part};
+use super::manifest::{FileEntry, FileManifest, MANIFEST_NAMESPACE};
+use super::{CONTENT_HPF_PATH, MANIFEST_PATH, MIMETYPE_PATH, VERSION_PATH, media_type_from_path};
+use crate::error::{Error, Result};
+
+/// 모든 XML 파트 앞에 붙는 선언
+pub(crate) const XML_DECLARATION: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes" ?>"#;
+
+/// 패키지 파트 쓰기 도우미
+///
+/// 파트를 기록한 순서가 곧 아카이브 안의 순서입니다.
+/// 한글은 `mimetype`이 첫 항목이면서 무압축이어야 파일을 인식하므로
+/// 호출 측에서 [`PackageWriter::write_stored`]로 가장 먼저 기록해야 합니다.
+///
+/// 암호화 옵션이 있으면 압축 파트 중 컨테이너 정보(`version.xml`, `META-INF/*`,
+/// `content.hpf`)를 제외한 파트를 암호화하고, [`PackageWriter::finish`]에서
+/// 복호화 정보를 담은 `META-INF/manifest.xml`을 기록합니다.
+pub(crate) struct PackageWriter<W: Write + Seek> {
+    zip: ZipWriter<W>,
+    encryption: Option<EncryptionOptions>,
+    manifest: FileManifest,
+}
+
+impl<W: Write + Seek> PackageWriter<W> {
+    /// 새 ZIP 아카이브 시작
+    pub fn new(writer: W) -> Self {
+        Self {
+            zip: ZipWriter::new(writer),
+            encryption: None,
+            manifest: FileManifest::default(),
+        }
+    }
+
+    /// 파트를 암호화하는 ZIP 아카이브 시작
+    pub fn with_encryption(writer: W, options: EncryptionOptions) -> Self {
+        Self {
+            encryption: Some(options),
+            ..Self::new(writer)
+        }
+    }
+
+    /// 무압축(Stored) 파트 기록
+    pub fn write_stored(&mut self, name: &str, data: &[u8]) -> Result<()> {
+        self.write_entry(name, data, CompressionMethod::Stored)
+    }
+
+    /// 압축(Deflate) 파트 기록 (암호화 대상이면 암호화)
+    pub fn write_deflated(&mut self, name: &str, data: &[u8]) -> Result<()> {
+        let Some(options) = self.encryption.as_ref().filter(|_| is_encryptable(name)) else {
+            return self.write_entry(name, data, CompressionMethod::Deflated);
+        };
+
+        // 암호문은 이미 압축되어 있으므로 무압축으로 저장
+        let (encrypted, encryption_data) = encrypt_part(data, options)?;
+        self.write_entry(name, &encrypted, CompressionMethod::Stored)?;
+        self.manifest.file_entries.push(FileEntry {
+            full_path: name.to_string(),
+            media_type: media_type_from_path(name).to_string(),
+            size: Some(data.len() as u64),
+            encryption_data: Some(encryption_data),
+        });
+        Ok(())
+    }
+
+    /// XML 모델을 직렬화해 압축 파트로 기록
+    ///
+    /// `namespaces`의 (접두어, URI) 쌍은 루트 요소에 `xmlns:접두어` 선언으로 추가됩니다.
+    pub fn write_xml<T: Serialize>(
+        &mut self,
+        name: &str,
+        value: &T,
+        namespaces: &[(&str, &str)],
+    ) -> Result<()> {
+        let xml = to_xml_part(name, value, namespaces)?;
+        self.write_deflated(name, xml.as_bytes())
+    }
+
+    /// 파일 목록 메타데이터를 기록하고 아카이브 마무리 후 내부 writer 반환
+    pub fn finish(mut self) -> Result<W> {
+        let manifest = to_xml_part(
+            MANIFEST_PATH,
+            &self.manifest,
+            &[("odf", MANIFEST_NAMESPACE)],
+        )?;
+        self.write_entry(
+            MANIFEST_PATH,
+            manifest.as_bytes(),
+            CompressionMethod::Deflated,
+        )?;
+        Ok(self.zip.finish()?)
+    }
+
+    fn write_entry(&mut self, name: &str, data: &[u8], method: CompressionMethod) -> Result<()> {
+        let options = SimpleFileOptions::default().compression_method(method);
+        self.zip.start_file(name, options)?;
+        self.zip.write_all(data)?;
+        Ok(())
+    }
+}
+
+/// 암호화 대상 파트 여부
+///
+/// 파일 형식과 패키지 구조를 알려 주는 파트는 암호 없이도 읽을 수 있어야 하므로 제외합니다.
+fn is_encryptable(name: &str) -> bool {
+    !(name == MIMETYPE_PATH
+        || name == VERSION_PATH
+        || name == CONTENT_HPF_PATH
+        || name.starts_with("META-INF/"))
+}
+
+/// XML 모델을 선언과 네임스페이스가 포함된 파트 문자열로 직렬화
+pub(crate) fn to_xml_part<T: Serialize>(
+    name: &str,
+    value: &T,
+    namespaces: &[(&str, &str)],
+) -> Result<String> {
+    let body = crate::to_string(value).map_err(|source| Error::Serialize {
+        part: name.to_string(),
+        source,
+    })?;
+
+    let declarations: String = namespaces
+        .iter()
+        .map(|(prefix, uri)| format!(r#" xmlns:{}="{}""#, prefix, uri))
+        .collect();
+    // 루트 요소 이름 바로 뒤에 네임스페이스 선언 삽입
+    let insert_at = body.find([' ', '>', '/']).unwrap_or(body.len());
+
+    let mut xml = String::with_capacity(XML_DECLARATION.len() + body.len() + declarations.len());
+    xml.push_str(XML_DECLARATION);
+    xml.push_str(&body[..insert_at]);
+    xml.push_str(&declarations);
+    xml.push_str(&body[insert_at..]);
+    Ok(xml)
+}
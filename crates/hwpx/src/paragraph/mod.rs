@@ -17,6 +17,7 @@ pub mod run;
 pub mod section_definition;
 pub mod shadow;
 pub mod shape_common;
+pub mod stream;
 pub mod table;
 pub mod text;
 pub mod text_art;
@@ -37,6 +38,7 @@ pub use run::*;
 pub use section_definition::*;
 pub use shadow::*;
 pub use shape_common::*;
+pub use stream::*;
 pub use table::*;
 pub use text::*;
 pub use text_art::*;
@@ -2,7 +2,7 @@
 //!
 //! 단일 스타일 컨텍스트 안에서 텍스트·표·도형·컨트롤 등 다양한 개체를 순서대로 담는 블록입니다. `$value` 순서가 레이아웃에 직결되므로 변경 시 주의가 필요합니다. KS X 6101:2024 `paralist.xsd`.
 
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Serialize, Serializer};
 
 use super::control::Control;
 use super::drawing::{Arc, ConnectLine, Curve, Ellipse, Line, Polygon, Rectangle, UnknownObject};
@@ -105,6 +105,11 @@ pub enum RunContent {
     /// 알 수 없는 개체
     #[serde(rename = "unknownObject")]
     UnknownObject(Box<UnknownObject>),
+    /// 모델에 없는 요소 (읽을 때 건너뛰고 저장할 때는 기록하지 않음)
+    ///
+    /// 보존 모드로 읽으면 원본 요소는 [`Document::unknown_nodes`](crate::Document::unknown_nodes)에 남습니다.
+    #[serde(other, skip_serializing)]
+    Unknown,
 }
 
 /// [AI 생성] 런
@@ -114,7 +119,12 @@ pub enum RunContent {
 #[serde(rename = "run")]
 pub struct Run {
     /// [AI 생성] 런 내용
-    #[serde(rename = "$value", default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(
+        rename = "$value",
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        serialize_with = "serialize_contents"
+    )]
     pub contents: Vec<RunContent>,
 
     /// [AI 생성] 글자 모양 아이디 참조 (`charPrIDRef` 속성)
@@ -125,3 +135,15 @@ pub struct Run {
     #[serde(rename = "@charTcId", skip_serializing_if = "Option::is_none")]
     pub character_track_change_id: Option<u32>,
 }
+
+/// [`RunContent::Unknown`]을 뺀 런 내용 직렬화
+fn serialize_contents<S: Serializer>(
+    contents: &[RunContent],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_seq(
+        contents
+            .iter()
+            .filter(|content| !matches!(content, RunContent::Unknown)),
+    )
+}
@@ -0,0 +1,210 @@
+//! 구역 XML 스트리밍 읽기/쓰기
+//!
+//! [`crate::from_str`]로 [`Section`](super::Section)을 읽으면 구역 XML 전체를 문자열로 올리고
+//! 모든 문단을 한 번에 만듭니다. 수백 MB짜리 구역 파트는 [`ParagraphReader`]로
+//! 루트(`sec`) 바로 아래 문단(`p`)을 하나씩 꺼내고, [`ParagraphWriter`]로 하나씩 기록하면
+//! 문단 하나 크기의 메모리만 씁니다.
+//!
+//! ```ignore
+//! use std::fs::File;
+//! use std::io::BufReader;
+//! use hwpx::paragraph::{ParagraphReader, ParagraphWriter};
+//!
+//! let reader = ParagraphReader::new(BufReader::new(File::open("section0.xml")?));
+//! let mut writer = ParagraphWriter::new(File::create("copy.xml")?)?;
+//! for paragraph in reader {
+//!     writer.write(&paragraph?)?;
+//! }
+//! writer.finish()?;
+//! ```
+
+use std::io::{BufRead, Write};
+
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::{DeError, Reader, Writer};
+
+use super::Paragraph;
+use crate::error::{Error, Result};
+use crate::package::XML_DECLARATION;
+
+/// 오류에 기록하는 기본 파트 이름
+const DEFAULT_PART: &str = "sec";
+
+/// 구역 XML에서 문단을 하나씩 읽는 리더
+///
+/// 루트 요소의 자식 중 로컬 이름이 `p`인 요소만 [`Paragraph`]로 읽고,
+/// 다른 자식 요소는 건너뜁니다. 접두사(`hp:p`)가 있어도 됩니다.
+pub struct ParagraphReader<R: BufRead> {
+    reader: Reader<R>,
+    part: String,
+    buf: Vec<u8>,
+    /// 현재 요소 깊이 (루트 안이면 1)
+    depth: usize,
+    finished: bool,
+}
+
+impl<R: BufRead> ParagraphReader<R> {
+    /// 새 리더 생성
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader: Reader::from_reader(reader),
+            part: DEFAULT_PART.to_string(),
+            buf: Vec::new(),
+            depth: 0,
+            finished: false,
+        }
+    }
+
+    /// 오류에 기록할 파트 이름 지정 (예: `Contents/section0.xml`)
+    pub fn with_part(mut self, part: impl Into<String>) -> Self {
+        self.part = part.into();
+        self
+    }
+
+    /// 다음 문단 읽기 (구역 끝이면 `None`)
+    pub fn read_paragraph(&mut self) -> Result<Option<Paragraph>> {
+        if self.finished {
+            return Ok(None);
+        }
+        let result = self.next_paragraph();
+        if !matches!(result, Ok(Some(_))) {
+            self.finished = true;
+        }
+        result
+    }
+
+    /// 내부 리더 반환
+    pub fn into_inner(self) -> R {
+        self.reader.into_inner()
+    }
+
+    fn next_paragraph(&mut self) -> Result<Option<Paragraph>> {
+        loop {
+            self.buf.clear();
+            let event = self
+                .reader
+                .read_event_into(&mut self.buf)
+                .map_err(|err| deserialize_error(&self.part, DeError::InvalidXml(err)))?;
+            match event {
+                Event::Start(start) if self.depth == 1 && is_paragraph(&start) => {
+                    let start = start.into_owned();
+                    let xml = self.read_subtree(start)?;
+                    return self.deserialize(&xml).map(Some);
+                }
+                Event::Empty(start) if self.depth == 1 && is_paragraph(&start) => {
+                    let mut writer = Writer::new(Vec::new());
+                    writer.write_event(Event::Empty(start))?;
+                    return self.deserialize(&writer.into_inner()).map(Some);
+                }
+                Event::Start(start) if self.depth == 1 => {
+                    let end = start.to_end().into_owned();
+                    let mut skipped = Vec::new();
+                    self.reader
+                        .read_to_end_into(end.name(), &mut skipped)
+                        .map_err(|err| deserialize_error(&self.part, DeError::InvalidXml(err)))?;
+                }
+                Event::Start(_) => self.depth += 1,
+                Event::End(_) => {
+                    self.depth -= 1;
+                    if self.depth == 0 {
+                        return Ok(None);
+                    }
+                }
+                Event::Eof if self.depth == 0 => return Ok(None),
+                Event::Eof => return Err(deserialize_error(&self.part, DeError::UnexpectedEof)),
+                _ => {}
+            }
+        }
+    }
+
+    /// 시작 태그부터 짝이 맞는 끝 태그까지의 XML
+    fn read_subtree(&mut self, start: BytesStart<'static>) -> Result<Vec<u8>> {
+        let mut writer = Writer::new(Vec::new());
+        let mut depth = 0usize;
+        let mut event = Event::Start(start);
+        loop {
+            match &event {
+                Event::Start(_) => depth += 1,
+                Event::End(_) => depth -= 1,
+                Event::Eof => return Err(deserialize_error(&self.part, DeError::UnexpectedEof)),
+                _ => {}
+            }
+            writer.write_event(event)?;
+            if depth == 0 {
+                return Ok(writer.into_inner());
+            }
+            self.buf.clear();
+            event = self
+                .reader
+                .read_event_into(&mut self.buf)
+                .map_err(|err| deserialize_error(&self.part, DeError::InvalidXml(err)))?;
+        }
+    }
+
+    fn deserialize(&self, xml: &[u8]) -> Result<Paragraph> {
+        quick_xml::de::from_reader(xml).map_err(|source| deserialize_error(&self.part, source))
+    }
+}
+
+impl<R: BufRead> Iterator for ParagraphReader<R> {
+    type Item = Result<Paragraph>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read_paragraph().transpose()
+    }
+}
+
+fn deserialize_error(part: &str, source: DeError) -> Error {
+    Error::Deserialize {
+        part: part.to_string(),
+        source,
+    }
+}
+
+fn is_paragraph(start: &BytesStart<'_>) -> bool {
+    start.local_name().as_ref() == b"p"
+}
+
+/// 문단을 하나씩 기록해 구역 XML을 만드는 라이터
+///
+/// [`crate::Document::to_bytes`]가 기록하는 구역 파트와 같은 형식(XML 선언, 접두사 없는 `sec`)으로
+/// 씁니다. 마지막에 [`ParagraphWriter::finish`]로 루트 요소를 닫아야 합니다.
+pub struct ParagraphWriter<W: Write> {
+    writer: W,
+    part: String,
+}
+
+impl<W: Write> ParagraphWriter<W> {
+    /// XML 선언과 루트 시작 태그를 기록하고 라이터 생성
+    pub fn new(mut writer: W) -> Result<Self> {
+        writer.write_all(XML_DECLARATION.as_bytes())?;
+        writer.write_all(b"<sec>")?;
+        Ok(Self {
+            writer,
+            part: DEFAULT_PART.to_string(),
+        })
+    }
+
+    /// 오류에 기록할 파트 이름 지정 (예: `Contents/section0.xml`)
+    pub fn with_part(mut self, part: impl Into<String>) -> Self {
+        self.part = part.into();
+        self
+    }
+
+    /// 문단 하나 기록
+    pub fn write(&mut self, paragraph: &Paragraph) -> Result<()> {
+        let xml = crate::to_string(paragraph).map_err(|source| Error::Serialize {
+            part: self.part.clone(),
+            source,
+        })?;
+        self.writer.write_all(xml.as_bytes())?;
+        Ok(())
+    }
+
+    /// 루트 끝 태그를 기록하고 내부 라이터 반환
+    pub fn finish(mut self) -> Result<W> {
+        self.writer.write_all(b"</sec>")?;
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+}
@@ -2,6 +2,7 @@
 //!
 //! 문단/런 안에서 글자 수준의 마크업(형광펜, 제목 표시, 탭, 추적 기록)을 담는 요소입니다. 변경 추적과 혼용되므로 `$value` 시퀀스 순서를 유지해야 합니다. KS X 6101:2024 `paralist.xsd`.
 
+use serde::ser::{SerializeStruct, Serializer};
 use serde::{Deserialize, Serialize};
 
 use super::control::TrackChangeTag;
@@ -143,7 +144,7 @@ pub enum TextMarkup {
 /// [AI 생성] 텍스트 블록 (`t` 요소)
 ///
 /// 원본: `t` 요소의 익명 타입
-#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Deserialize)]
 #[serde(rename = "t")]
 pub struct TextElement {
     /// [AI 생성] 텍스트 내용 및 마크업 항목들 (`$value` 시퀀스)
@@ -157,6 +158,25 @@ pub struct TextElement {
     pub character_style_id_reference: Option<StyleIdRef>,
 }
 
+/// `$value` 시퀀스의 첫 항목이 `$text`이면 quick-xml 시퀀스 직렬화가 실패하므로,
+/// 텍스트는 `$text` 필드로, 마크업은 `$value` 필드로 하나씩 기록합니다.
+impl Serialize for TextElement {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let attributes = usize::from(self.character_style_id_reference.is_some());
+        let mut state = serializer.serialize_struct("t", attributes + self.contents.len())?;
+        if let Some(style) = &self.character_style_id_reference {
+            state.serialize_field("@charStyleIDRef", style)?;
+        }
+        for item in &self.contents {
+            match item {
+                TextMarkup::Text(text) => state.serialize_field("$text", text)?,
+                markup => state.serialize_field("$value", markup)?,
+            }
+        }
+        state.end()
+    }
+}
+
 impl TextElement {
     /// 텍스트 내용만 추출
     pub fn text(&self) -> String {
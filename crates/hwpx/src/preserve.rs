@@ -0,0 +1,517 @@
+//! 알 수 없는 XML 노드 보존
+//!
+//! serde 모델은 모르는 요소와 속성을 버리므로, 보존 모드로 읽으면 원본 파트와
+//! 모델을 다시 직렬화한 결과를 비교해 모델에 없는 노드를 [`UnknownNode`]로 모아 둡니다.
+//! 저장할 때는 같은 경로의 요소에 속성을 되돌리고, 자식 요소는 원래 순번 위치에 끼워 넣습니다.
+//!
+//! 요소 경로는 모델이 기록하는 XML 기준으로 `/sec/p[@id='3']/run[2]`처럼 나타냅니다.
+//! `id` 속성이 있는 요소는 아이디로, 없는 요소는 같은 이름 형제 중 순번(1부터)으로 찾으므로
+//! 앞 문단을 지우거나 끼워 넣어도 노드는 원래 문단에 남습니다. 같은 아이디 형제가 여럿이면
+//! 두 번째부터 `p[@id='3'][2]`처럼 순번을 붙입니다.
+//!
+//! 문서를 고쳐 경로가 사라진 노드는 기록되지 않으며, [`Document::orphaned_unknown_nodes`]로
+//! 저장 전에 확인할 수 있습니다.
+//!
+//! 런 내용처럼 자식 요소를 열거형으로 읽는 곳은 모르는 요소를 [`RunContent::Unknown`]으로 건너뛰므로
+//! 그 요소도 같은 방식으로 보존됩니다.
+//!
+//! [`Document::orphaned_unknown_nodes`]: crate::Document::orphaned_unknown_nodes
+//! [`RunContent::Unknown`]: crate::paragraph::RunContent::Unknown
+//!
+//! ```ignore
+//! use hwpx::{Document, ReadOptions};
+//!
+//! let options = ReadOptions::new().with_preserve_unknown(true);
+//! let document = Document::from_bytes_with_options(&bytes, &options)?;
+//! let saved = document.to_bytes()?; // 확장 요소와 속성이 그대로 남음
+//! ```
+
+use std::collections::{HashMap, VecDeque};
+use std::io::Write;
+
+use quick_xml::events::{BytesEnd, BytesStart, Event};
+use quick_xml::{Reader, Writer};
+
+/// 모델에 없는 XML 노드
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownNode {
+    /// 파트 경로 (저장할 때의 경로, 예: `Contents/section0.xml`)
+    pub part: String,
+    /// 노드가 속한 요소의 경로 (예: `/sec/p[@id='3']/run[2]`)
+    pub path: String,
+    /// 노드 내용
+    pub content: UnknownContent,
+}
+
+/// 모델에 없는 XML 노드 내용
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UnknownContent {
+    /// 자식 요소
+    Element {
+        /// 원본에서 부모의 자식 요소 중 위치 (0부터)
+        index: usize,
+        /// 요소 전체 XML (쓰인 접두어의 네임스페이스 선언 포함)
+        xml: String,
+    },
+    /// 속성
+    Attribute {
+        /// 속성 이름 (접두어 포함)
+        name: String,
+        /// 속성 값
+        value: String,
+    },
+}
+
+/// 비교용 XML 요소
+#[derive(Debug, Default)]
+struct Node {
+    /// 접두어를 포함한 이름
+    name: String,
+    /// (접두어를 포함한 이름, 값)
+    attributes: Vec<(String, String)>,
+    children: Vec<Node>,
+    /// 원본에서 요소가 차지하는 바이트 범위
+    start: usize,
+    end: usize,
+}
+
+impl Node {
+    fn local_name(&self) -> &str {
+        local_name(&self.name)
+    }
+
+    fn id(&self) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(name, _)| name == "id")
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// 이 요소에서 선언한 네임스페이스 (접두어, URI), 기본 네임스페이스는 빈 접두어
+    fn declarations(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.attributes.iter().filter_map(|(name, value)| {
+            if name == "xmlns" {
+                Some(("", value.as_str()))
+            } else {
+                name.strip_prefix("xmlns:")
+                    .map(|prefix| (prefix, value.as_str()))
+            }
+        })
+    }
+}
+
+fn local_name(name: &str) -> &str {
+    name.rsplit_once(':').map_or(name, |(_, local)| local)
+}
+
+fn prefix(name: &str) -> &str {
+    name.split_once(':').map_or("", |(prefix, _)| prefix)
+}
+
+/// 경로 한 단계: 아이디가 있으면 `p[@id='3']`(같은 아이디 형제 중 두 번째부터 `p[@id='3'][2]`),
+/// 없으면 `run[2]`
+fn path_segment(name: &str, id: Option<&str>, count: usize) -> String {
+    match id {
+        Some(id) if count > 1 => format!("{}[@id='{}'][{}]", name, id, count),
+        Some(id) => format!("{}[@id='{}']", name, id),
+        None => format!("{}[{}]", name, count),
+    }
+}
+
+fn is_namespace_declaration(name: &str) -> bool {
+    name == "xmlns" || name.starts_with("xmlns:")
+}
+
+/// 원본 파트에서 모델에 없는 노드 찾기
+///
+/// `regenerated`는 원본을 읽은 모델을 다시 직렬화한 XML입니다.
+pub(crate) fn capture(
+    part: &str,
+    original: &str,
+    regenerated: &str,
+) -> Result<Vec<UnknownNode>, quick_xml::Error> {
+    let (Some(original_root), Some(regenerated_root)) =
+        (parse_tree(original)?, parse_tree(regenerated)?)
+    else {
+        return Ok(Vec::new());
+    };
+    if original_root.local_name() != regenerated_root.local_name() {
+        return Ok(Vec::new());
+    }
+
+    let mut capture = Capture {
+        part,
+        source: original,
+        scope: Vec::new(),
+        nodes: Vec::new(),
+    };
+    let path = format!("/{}", regenerated_root.local_name());
+    capture.node(&original_root, &regenerated_root, &path);
+    Ok(capture.nodes)
+}
+
+struct Capture<'a> {
+    part: &'a str,
+    source: &'a str,
+    /// 바깥 요소부터 쌓인 네임스페이스 선언
+    scope: Vec<(&'a str, &'a str)>,
+    nodes: Vec<UnknownNode>,
+}
+
+impl<'a> Capture<'a> {
+    fn push(&mut self, path: &str, content: UnknownContent) {
+        self.nodes.push(UnknownNode {
+            part: self.part.to_string(),
+            path: path.to_string(),
+            content,
+        });
+    }
+
+    fn namespace(&self, prefix: &str) -> Option<&'a str> {
+        self.scope
+            .iter()
+            .rev()
+            .find(|(declared, _)| *declared == prefix)
+            .map(|(_, uri)| *uri)
+    }
+
+    fn node(&mut self, original: &'a Node, regenerated: &Node, path: &str) {
+        let scope_len = self.scope.len();
+        self.scope.extend(original.declarations());
+
+        let mut declared = Vec::new();
+        for (name, value) in &original.attributes {
+            if is_namespace_declaration(name) {
+                continue;
+            }
+            let known = regenerated
+                .attributes
+                .iter()
+                .any(|(known, _)| local_name(known) == local_name(name));
+            if known {
+                continue;
+            }
+            // 접두어가 붙은 속성은 그 네임스페이스 선언도 함께 보존
+            let attribute_prefix = prefix(name);
+            if !matches!(attribute_prefix, "" | "xml") && !declared.contains(&attribute_prefix) {
+                declared.push(attribute_prefix);
+                if let Some(uri) = self.namespace(attribute_prefix) {
+                    self.push(
+                        path,
+                        UnknownContent::Attribute {
+                            name: format!("xmlns:{}", attribute_prefix),
+                            value: uri.to_string(),
+                        },
+                    );
+                }
+            }
+            self.push(
+                path,
+                UnknownContent::Attribute {
+                    name: name.clone(),
+                    value: value.clone(),
+                },
+            );
+        }
+
+        // 같은 이름의 n번째 원본 자식은 다시 직렬화한 결과의 n번째 자식과 짝지음 (순서가 바뀌어도 됨)
+        let mut seen: HashMap<&str, usize> = HashMap::new();
+        for (index, child) in original.children.iter().enumerate() {
+            let count = seen.entry(child.local_name()).or_default();
+            *count += 1;
+            let matched = regenerated
+                .children
+                .iter()
+                .enumerate()
+                .filter(|(_, candidate)| candidate.local_name() == child.local_name())
+                .nth(*count - 1);
+            match matched {
+                Some((position, matched)) => {
+                    let id = matched.id();
+                    let same = regenerated.children[..=position]
+                        .iter()
+                        .filter(|sibling| {
+                            sibling.local_name() == child.local_name() && sibling.id() == id
+                        })
+                        .count();
+                    let segment = path_segment(child.local_name(), id, same);
+                    self.node(child, matched, &format!("{}/{}", path, segment));
+                }
+                None => {
+                    *count -= 1;
+                    let xml = self.subtree(child);
+                    self.push(path, UnknownContent::Element { index, xml });
+                }
+            }
+        }
+
+        self.scope.truncate(scope_len);
+    }
+
+    /// 원본 요소 XML에 바깥에서 선언된 네임스페이스를 붙여 반환
+    fn subtree(&self, node: &Node) -> String {
+        let mut prefixes = Vec::new();
+        collect_prefixes(node, &mut prefixes);
+        let declarations: String = prefixes
+            .into_iter()
+            .filter(|prefix| !node.declarations().any(|(declared, _)| declared == *prefix))
+            .filter_map(|prefix| {
+                let uri = self.namespace(prefix)?;
+                Some(match prefix {
+                    "" => format!(r#" xmlns="{}""#, escape_attribute(uri)),
+                    _ => format!(r#" xmlns:{}="{}""#, prefix, escape_attribute(uri)),
+                })
+            })
+            .collect();
+
+        let xml = &self.source[node.start..node.end];
+        // `<` 다음 요소 이름 바로 뒤에 선언 삽입
+        let insert_at = 1 + node.name.len();
+        format!("{}{}{}", &xml[..insert_at], declarations, &xml[insert_at..])
+    }
+}
+
+/// 요소와 속성 이름에 쓰인 접두어 (요소 이름의 빈 접두어는 기본 네임스페이스)
+fn collect_prefixes<'n>(node: &'n Node, prefixes: &mut Vec<&'n str>) {
+    let names = std::iter::once((node.name.as_str(), true)).chain(
+        node.attributes
+            .iter()
+            .filter(|(name, _)| !is_namespace_declaration(name))
+            .map(|(name, _)| (name.as_str(), false)),
+    );
+    for (name, is_element) in names {
+        let name_prefix = prefix(name);
+        let needs_declaration = match name_prefix {
+            "" => is_element,
+            "xml" => false,
+            _ => true,
+        };
+        if needs_declaration && !prefixes.contains(&name_prefix) {
+            prefixes.push(name_prefix);
+        }
+    }
+    for child in &node.children {
+        collect_prefixes(child, prefixes);
+    }
+}
+
+fn escape_attribute(value: &str) -> String {
+    quick_xml::escape::escape(value).into_owned()
+}
+
+fn parse_tree(xml: &str) -> Result<Option<Node>, quick_xml::Error> {
+    let mut reader = Reader::from_str(xml);
+    let mut stack: Vec<Node> = Vec::new();
+    let mut root = None;
+
+    loop {
+        let start = reader.buffer_position() as usize;
+        let event = reader.read_event()?;
+        let end = reader.buffer_position() as usize;
+        match event {
+            Event::Start(element) => stack.push(node(&element, start)?),
+            Event::Empty(element) => {
+                let mut node = node(&element, start)?;
+                node.end = end;
+                match stack.last_mut() {
+                    Some(parent) => parent.children.push(node),
+                    None => root = root.or(Some(node)),
+                }
+            }
+            Event::End(_) => {
+                let Some(mut node) = stack.pop() else {
+                    continue;
+                };
+                node.end = end;
+                match stack.last_mut() {
+                    Some(parent) => parent.children.push(node),
+                    None => root = root.or(Some(node)),
+                }
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+    Ok(root)
+}
+
+fn node(element: &BytesStart<'_>, start: usize) -> Result<Node, quick_xml::Error> {
+    let mut attributes = Vec::new();
+    for attribute in element.attributes() {
+        let attribute = attribute?;
+        let name = String::from_utf8_lossy(attribute.key.as_ref()).into_owned();
+        let value = attribute.unescape_value()?.into_owned();
+        attributes.push((name, value));
+    }
+    Ok(Node {
+        name: String::from_utf8_lossy(element.name().as_ref()).into_owned(),
+        attributes,
+        children: Vec::new(),
+        start,
+        end: start,
+    })
+}
+
+/// 모델이 직렬화한 XML에 보존한 노드를 되돌림
+///
+/// `nodes`는 같은 파트의 노드여야 합니다. 경로가 없어 기록하지 못한 노드는 함께 반환합니다.
+pub(crate) fn restore<'n>(
+    xml: &str,
+    nodes: impl IntoIterator<Item = &'n UnknownNode>,
+) -> Result<(String, Vec<&'n UnknownNode>), quick_xml::Error> {
+    let nodes: Vec<&UnknownNode> = nodes.into_iter().collect();
+    let mut attributes: HashMap<&str, Vec<&UnknownNode>> = HashMap::new();
+    let mut elements: HashMap<&str, Vec<&UnknownNode>> = HashMap::new();
+    for &node in &nodes {
+        match &node.content {
+            UnknownContent::Attribute { .. } => {
+                attributes.entry(&node.path).or_default().push(node)
+            }
+            UnknownContent::Element { .. } => elements.entry(&node.path).or_default().push(node),
+        }
+    }
+    let mut elements: HashMap<&str, VecDeque<&UnknownNode>> = elements
+        .into_iter()
+        .map(|(path, mut children)| {
+            children.sort_by_key(|node| element_index(node));
+            (path, children.into())
+        })
+        .collect();
+
+    let mut reader = Reader::from_str(xml);
+    let mut writer = Writer::new(Vec::with_capacity(xml.len()));
+    let mut stack: Vec<Frame> = Vec::new();
+
+    loop {
+        let event = reader.read_event()?;
+        let (element, empty) = match event {
+            Event::Start(element) => (element, false),
+            Event::Empty(element) => (element, true),
+            Event::End(end) => {
+                if let Some(mut frame) = stack.pop() {
+                    frame.flush(&mut elements, true, writer.get_mut())?;
+                }
+                writer.write_event(Event::End(end))?;
+                continue;
+            }
+            Event::Eof => break,
+            event => {
+                writer.write_event(event)?;
+                continue;
+            }
+        };
+
+        let name = String::from_utf8_lossy(element.local_name().as_ref()).into_owned();
+        let id = element
+            .try_get_attribute("id")?
+            .map(|attribute| attribute.unescape_value().map(|value| value.into_owned()))
+            .transpose()?;
+        let path = match stack.last_mut() {
+            Some(parent) => {
+                // 앞에 끼워 넣을 자식 요소부터 기록
+                parent.flush(&mut elements, false, writer.get_mut())?;
+                parent.written += 1;
+                let count = parent.counts.entry((name.clone(), id.clone())).or_default();
+                *count += 1;
+                format!(
+                    "{}/{}",
+                    parent.path,
+                    path_segment(&name, id.as_deref(), *count)
+                )
+            }
+            None => format!("/{}", name),
+        };
+
+        let mut element = element.into_owned();
+        for node in attributes.remove(path.as_str()).unwrap_or_default() {
+            let UnknownContent::Attribute { name, value } = &node.content else {
+                continue;
+            };
+            let exists = element
+                .attributes()
+                .flatten()
+                .any(|attribute| attribute.key.as_ref() == name.as_bytes());
+            if !exists {
+                element.push_attribute((name.as_str(), value.as_str()));
+            }
+        }
+
+        let mut frame = Frame {
+            path,
+            written: 0,
+            counts: HashMap::new(),
+        };
+        if !empty {
+            writer.write_event(Event::Start(element))?;
+            stack.push(frame);
+        } else if elements.contains_key(frame.path.as_str()) {
+            // 자식을 끼워 넣어야 하는 빈 요소는 시작/끝 태그로 나눔
+            let end = BytesEnd::new(String::from_utf8_lossy(element.name().as_ref()).into_owned());
+            writer.write_event(Event::Start(element))?;
+            frame.flush(&mut elements, true, writer.get_mut())?;
+            writer.write_event(Event::End(end))?;
+        } else {
+            writer.write_event(Event::Empty(element))?;
+        }
+    }
+
+    // 기록하지 못한 노드는 받은 순서대로 반환
+    let orphans = nodes
+        .into_iter()
+        .filter(|node| {
+            attributes
+                .get(node.path.as_str())
+                .is_some_and(|pending| pending.iter().any(|pending| std::ptr::eq(*pending, *node)))
+                || elements.get(node.path.as_str()).is_some_and(|pending| {
+                    pending.iter().any(|pending| std::ptr::eq(*pending, *node))
+                })
+        })
+        .collect();
+    Ok((
+        String::from_utf8_lossy(&writer.into_inner()).into_owned(),
+        orphans,
+    ))
+}
+
+fn element_index(node: &UnknownNode) -> usize {
+    match node.content {
+        UnknownContent::Element { index, .. } => index,
+        UnknownContent::Attribute { .. } => 0,
+    }
+}
+
+/// 기록 중인 요소
+struct Frame {
+    path: String,
+    /// 기록한 자식 요소 수 (끼워 넣은 요소 포함)
+    written: usize,
+    /// (이름, 아이디)별 자식 요소 수 (경로 순번용)
+    counts: HashMap<(String, Option<String>), usize>,
+}
+
+impl Frame {
+    /// 보존한 자식 요소 중 지금 위치까지 올 것(`all`이면 남은 것 전부) 기록
+    fn flush(
+        &mut self,
+        elements: &mut HashMap<&str, VecDeque<&UnknownNode>>,
+        all: bool,
+        out: &mut Vec<u8>,
+    ) -> std::io::Result<()> {
+        let Some(pending) = elements.get_mut(self.path.as_str()) else {
+            return Ok(());
+        };
+        while let Some(node) = pending.front() {
+            let UnknownContent::Element { index, xml } = &node.content else {
+                pending.pop_front();
+                continue;
+            };
+            if !all && *index > self.written {
+                break;
+            }
+            out.write_all(xml.as_bytes())?;
+            pending.pop_front();
+            self.written += 1;
+        }
+        Ok(())
+    }
+}
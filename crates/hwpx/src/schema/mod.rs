@@ -0,0 +1,196 @@
+//! XML 스키마 검증
+//!
+//! 크레이트에 포함한 KS X 6101 XSD(`schemas`)로 패키지 XML 파트를 검사합니다.
+//! 필수 속성, 열거형/숫자 값, 자식 요소의 순서와 개수를 확인하고
+//! 어긋난 곳마다 파트 이름과 요소 경로를 담은 [`SchemaViolation`]을 보고합니다.
+//!
+//! 요소와 속성은 로컬 이름으로만 비교합니다. 표준 XSD는 `owpml.org/owpml/2024`
+//! 네임스페이스를 쓰지만 한글이 저장한 문서는 `hancom.co.kr/hwpml/2011` 네임스페이스를 쓰기
+//! 때문입니다. `pattern` 제약은 검사하지 않습니다.
+//!
+//! 표준 XSD가 실제 문서와 다른 부분은 다음과 같이 보정합니다.
+//!
+//! - `version.xml`: 루트 `HCFVersion`을 허용하고, `micro`를 음이 아닌 정수로 보며,
+//!   `os`/`xmlVersion` 속성을 허용
+//! - 문단(`p`): 한글이 런 뒤에 기록하는 줄 배치 정보 `linesegarray`를 허용
+//!
+//! ```ignore
+//! let document = hwpx::Document::from_bytes(&std::fs::read("document.hwpx")?)?;
+//! for violation in hwpx::validate(&document)? {
+//!     eprintln!("{}", violation);
+//! }
+//! ```
+
+mod validator;
+mod xsd;
+
+use std::fmt;
+
+use crate::document::Document;
+use crate::error::Result;
+use crate::package::{self, HEADER_PATH, MASTER_PAGE_DIR, VERSION_PATH};
+
+/// 스키마 위반
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaViolation {
+    /// 파트 경로 (예: `Contents/section0.xml`)
+    pub part: String,
+    /// 요소 경로 (예: `/sec/p[1]/run[2]`, 같은 이름 형제 중 1부터 센 순번)
+    pub path: String,
+    /// 위반 종류
+    pub kind: ViolationKind,
+}
+
+/// 스키마 위반 종류
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ViolationKind {
+    /// XML로 읽을 수 없음 (사유)
+    Malformed(String),
+    /// 루트 요소가 파트에 맞지 않음
+    UnexpectedRoot {
+        /// 스키마의 루트 요소 이름
+        expected: String,
+        /// 문서의 루트 요소 이름
+        found: String,
+    },
+    /// 필수 속성이 없음 (속성 이름)
+    MissingAttribute(String),
+    /// 선언되지 않은 속성 (속성 이름)
+    UnexpectedAttribute(String),
+    /// 속성 값이 형식에 맞지 않음
+    InvalidAttributeValue {
+        /// 속성 이름
+        name: String,
+        /// 속성 값
+        value: String,
+        /// 기대한 형식 (예: `xs:nonNegativeInteger`, `one of LEFT, RIGHT`)
+        expected: String,
+    },
+    /// 이 위치에 올 수 없는 요소 (순서나 개수가 어긋난 경우 포함)
+    UnexpectedElement {
+        /// 요소 이름
+        name: String,
+        /// 이 위치에 올 수 있었던 요소 이름
+        expected: Vec<String>,
+    },
+    /// 필수 자식 요소가 없음 (요소 이름, 알 수 없으면 빈 문자열)
+    MissingElement(String),
+    /// 요소만 담을 수 있는 곳의 문자 데이터
+    UnexpectedText(String),
+    /// 문자 데이터가 형식에 맞지 않음
+    InvalidText {
+        /// 문자 데이터
+        value: String,
+        /// 기대한 형식
+        expected: String,
+    },
+}
+
+impl fmt::Display for SchemaViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}: {}", self.part, self.path, self.kind)
+    }
+}
+
+impl fmt::Display for ViolationKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ViolationKind::Malformed(reason) => write!(f, "malformed XML: {}", reason),
+            ViolationKind::UnexpectedRoot { expected, found } => {
+                write!(f, "root element <{}>, expected <{}>", found, expected)
+            }
+            ViolationKind::MissingAttribute(name) => {
+                write!(f, "missing required attribute \"{}\"", name)
+            }
+            ViolationKind::UnexpectedAttribute(name) => {
+                write!(f, "undeclared attribute \"{}\"", name)
+            }
+            ViolationKind::InvalidAttributeValue {
+                name,
+                value,
+                expected,
+            } => write!(
+                f,
+                "attribute \"{}\" has value \"{}\", expected {}",
+                name, value, expected
+            ),
+            ViolationKind::UnexpectedElement { name, expected } if expected.is_empty() => {
+                write!(f, "unexpected element <{}>", name)
+            }
+            ViolationKind::UnexpectedElement { name, expected } => write!(
+                f,
+                "unexpected element <{}>, expected one of <{}>",
+                name,
+                expected.join(">, <")
+            ),
+            ViolationKind::MissingElement(name) if name.is_empty() => {
+                write!(f, "incomplete content")
+            }
+            ViolationKind::MissingElement(name) => {
+                write!(f, "missing required element <{}>", name)
+            }
+            ViolationKind::UnexpectedText(text) => write!(f, "unexpected text \"{}\"", text),
+            ViolationKind::InvalidText { value, expected } => {
+                write!(f, "text \"{}\", expected {}", value, expected)
+            }
+        }
+    }
+}
+
+/// 문서의 XML 파트를 스키마로 검증
+///
+/// [`Document::to_bytes`]가 기록하는 것과 같은 XML로 `version.xml`, 헤더, 구역, 바탕쪽을 직렬화해
+/// 검사합니다. 위반이 없으면 빈 목록을 반환합니다.
+pub fn validate(document: &Document) -> Result<Vec<SchemaViolation>> {
+    let mut violations = Vec::new();
+    let mut check = |part: String, xml: String| violations.extend(validate_part(&part, &xml));
+
+    check(
+        VERSION_PATH.to_string(),
+        package::to_xml_part(VERSION_PATH, &document.version, &[])?,
+    );
+    check(
+        HEADER_PATH.to_string(),
+        document.model_xml(HEADER_PATH, &document.header)?,
+    );
+    for (index, section) in document.sections.iter().enumerate() {
+        let part = package::section_path(index);
+        let xml = document.model_xml(&part, section)?;
+        check(part, xml);
+    }
+    for (index, master_page) in document.master_pages.iter().enumerate() {
+        let part = package::master_page_path(index);
+        let xml = document.model_xml(&part, master_page)?;
+        check(part, xml);
+    }
+    Ok(violations)
+}
+
+/// XML 파트 하나를 스키마로 검증
+///
+/// 파트 경로로 루트 요소를 정합니다.
+///
+/// - `version.xml` → `version` (`HCFVersion`도 허용)
+/// - `Contents/header.xml` → `head`
+/// - `Contents/section*.xml` → `sec`
+/// - `Contents/masterpage*.xml`, `MasterPage/*` → `masterPage`
+/// - 이름에 `history`가 들어간 파트 → `history`
+///
+/// 스키마가 없는 파트(`content.hpf`, `settings.xml` 등)는 검사하지 않고 빈 목록을 반환합니다.
+pub fn validate_part(part: &str, xml: &str) -> Vec<SchemaViolation> {
+    let file_name = part.rsplit('/').next().unwrap_or(part).to_lowercase();
+    let (root, aliases): (&str, &[&str]) = if part == VERSION_PATH {
+        ("version", &["HCFVersion"])
+    } else if part == HEADER_PATH {
+        ("head", &[])
+    } else if part.starts_with(MASTER_PAGE_DIR) || file_name.starts_with("masterpage") {
+        ("masterPage", &[])
+    } else if file_name.contains("history") {
+        ("history", &[])
+    } else if part.starts_with("Contents/section") && file_name.ends_with(".xml") {
+        ("sec", &[])
+    } else {
+        return Vec::new();
+    };
+    validator::validate_xml(part, xml, root, aliases)
+}
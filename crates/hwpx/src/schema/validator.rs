@@ -0,0 +1,608 @@
+//! 인스턴스 문서 검증
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use quick_xml::Reader;
+use quick_xml::events::{BytesStart, Event};
+
+use super::xsd::{
+    AttributeDecl, Builtin, ComplexType, ElementDecl, MaxOccurs, Particle, SCHEMA, Schema,
+    SimpleType, TypeRef,
+};
+use super::{SchemaViolation, ViolationKind};
+
+/// 검증할 XML 요소
+#[derive(Debug, Default)]
+struct Element {
+    /// 로컬 이름
+    name: String,
+    /// (접두어를 포함한 이름, 값)
+    attributes: Vec<(String, String)>,
+    children: Vec<Element>,
+    text: String,
+}
+
+/// 요소 형식을 펼친 결과 (확장 기반 형식 포함)
+#[derive(Default)]
+struct Content<'a> {
+    attributes: Vec<&'a AttributeDecl>,
+    particles: Vec<&'a Particle>,
+    mixed: bool,
+    /// 단순 내용이면 문자 데이터의 형식
+    simple: Option<&'a TypeRef>,
+    /// 형식을 알 수 없어 검사하지 않음
+    any: bool,
+}
+
+/// 파트 하나 검증
+///
+/// `root`는 파트에 맞는 전역 요소 이름이고, `aliases`는 루트로 함께 허용하는 이름입니다.
+pub(super) fn validate_xml(
+    part: &str,
+    xml: &str,
+    root: &str,
+    aliases: &[&str],
+) -> Vec<SchemaViolation> {
+    let violation = |path: &str, kind| SchemaViolation {
+        part: part.to_string(),
+        path: path.to_string(),
+        kind,
+    };
+    let element = match parse_tree(xml) {
+        Ok(element) => element,
+        Err(reason) => return vec![violation("/", ViolationKind::Malformed(reason))],
+    };
+    let path = format!("/{}", element.name);
+    if element.name != root && !aliases.contains(&element.name.as_str()) {
+        return vec![violation(
+            &path,
+            ViolationKind::UnexpectedRoot {
+                expected: root.to_string(),
+                found: element.name.clone(),
+            },
+        )];
+    }
+
+    let schema = &*SCHEMA;
+    let mut validator = Validator {
+        schema,
+        part,
+        violations: Vec::new(),
+    };
+    if let Some(decl) = schema.elements.get(root) {
+        validator.element(&element, &decl.type_ref, &path);
+    }
+    validator.violations
+}
+
+struct Validator<'a> {
+    schema: &'a Schema,
+    part: &'a str,
+    violations: Vec<SchemaViolation>,
+}
+
+impl<'a> Validator<'a> {
+    fn report(&mut self, path: &str, kind: ViolationKind) {
+        self.violations.push(SchemaViolation {
+            part: self.part.to_string(),
+            path: path.to_string(),
+            kind,
+        });
+    }
+
+    fn element(&mut self, element: &Element, type_ref: &'a TypeRef, path: &str) {
+        let content = self.content(type_ref);
+        if content.any {
+            return;
+        }
+
+        self.attributes(element, &content, path);
+
+        if let Some(simple) = content.simple {
+            if !element.children.is_empty() {
+                let name = element.children[0].name.clone();
+                let child_path = format!("{}/{}[1]", path, name);
+                let expected = Vec::new();
+                self.report(
+                    &child_path,
+                    ViolationKind::UnexpectedElement { name, expected },
+                );
+            }
+            if let Err(expected) = self.check_value(simple, &element.text) {
+                self.report(
+                    path,
+                    ViolationKind::InvalidText {
+                        value: element.text.clone(),
+                        expected,
+                    },
+                );
+            }
+            return;
+        }
+        if !content.mixed && !element.text.trim().is_empty() {
+            self.report(path, ViolationKind::UnexpectedText(element.text.clone()));
+        }
+
+        self.children(element, &content, path);
+    }
+
+    /// 형식을 확장 기반 형식까지 펼쳐 속성과 내용 모델 수집
+    fn content(&self, type_ref: &'a TypeRef) -> Content<'a> {
+        let mut content = Content::default();
+        match type_ref {
+            TypeRef::Any => content.any = true,
+            TypeRef::Builtin(_) | TypeRef::Simple(_) => content.simple = Some(type_ref),
+            TypeRef::Named(key) => match self.schema.complex_types.get(key) {
+                Some(complex_type) => self.extend(&mut content, complex_type),
+                None if self.schema.simple_types.contains_key(key) => {
+                    content.simple = Some(type_ref)
+                }
+                None => content.any = true,
+            },
+            TypeRef::Complex(complex_type) => self.extend(&mut content, complex_type),
+        }
+        content
+    }
+
+    fn extend(&self, content: &mut Content<'a>, complex_type: &'a ComplexType) {
+        if let Some(base) = &complex_type.base {
+            match base {
+                TypeRef::Named(key) if self.schema.complex_types.contains_key(key) => {
+                    self.extend(content, &self.schema.complex_types[key]);
+                }
+                TypeRef::Any => content.any = true,
+                _ => content.simple = Some(base),
+            }
+        }
+        content.mixed |= complex_type.mixed;
+        content.attributes.extend(&complex_type.attributes);
+        for group in &complex_type.attribute_groups {
+            if let Some(attributes) = self.schema.attribute_groups.get(group) {
+                content.attributes.extend(attributes);
+            }
+        }
+        content.particles.extend(&complex_type.particle);
+    }
+
+    fn attributes(&mut self, element: &Element, content: &Content<'a>, path: &str) {
+        for (name, value) in &element.attributes {
+            if name == "xmlns" || name.starts_with("xmlns:") {
+                continue;
+            }
+            // `xml:` 속성은 접두어까지, 나머지는 로컬 이름으로 비교
+            let local = match name.split_once(':') {
+                Some(("xml", _)) => name.as_str(),
+                Some((_, local)) => local,
+                None => name.as_str(),
+            };
+            let Some(decl) = content.attributes.iter().find(|decl| decl.name == local) else {
+                if !local.starts_with("xml:") {
+                    self.report(path, ViolationKind::UnexpectedAttribute(local.to_string()));
+                }
+                continue;
+            };
+            let checked = match &decl.fixed {
+                Some(fixed) if fixed != value => Err(format!("fixed value \"{}\"", fixed)),
+                _ => self.check_value(&decl.type_ref, value),
+            };
+            if let Err(expected) = checked {
+                self.report(
+                    path,
+                    ViolationKind::InvalidAttributeValue {
+                        name: local.to_string(),
+                        value: value.clone(),
+                        expected,
+                    },
+                );
+            }
+        }
+
+        for decl in content.attributes.iter().filter(|decl| decl.required) {
+            let present = element.attributes.iter().any(|(name, _)| {
+                name == &decl.name || name.rsplit_once(':').is_some_and(|(_, l)| l == decl.name)
+            });
+            if !present {
+                self.report(path, ViolationKind::MissingAttribute(decl.name.clone()));
+            }
+        }
+    }
+
+    fn children(&mut self, element: &Element, content: &Content<'a>, path: &str) {
+        let has_any = content
+            .particles
+            .iter()
+            .any(|particle| contains_any(particle));
+        let mut declarations = Vec::with_capacity(element.children.len());
+        let mut unknown = false;
+        for (child, child_path) in element.children.iter().zip(child_paths(element, path)) {
+            let decl = content
+                .particles
+                .iter()
+                .find_map(|particle| find_element(particle, &child.name));
+            if decl.is_none() && !has_any {
+                self.report(
+                    &child_path,
+                    ViolationKind::UnexpectedElement {
+                        name: child.name.clone(),
+                        expected: Vec::new(),
+                    },
+                );
+                unknown = true;
+            }
+            declarations.push((child, child_path, decl));
+        }
+
+        // 모르는 자식 요소가 없을 때만 순서와 개수 확인 (같은 원인을 두 번 보고하지 않도록)
+        if !unknown {
+            self.order(element, content, path);
+        }
+
+        for (child, child_path, decl) in declarations {
+            if let Some(decl) = decl {
+                self.element(child, &decl.type_ref, &child_path);
+            }
+        }
+    }
+
+    fn order(&mut self, element: &Element, content: &Content<'a>, path: &str) {
+        let names: Vec<&str> = element
+            .children
+            .iter()
+            .map(|child| child.name.as_str())
+            .collect();
+        let mut matcher = Matcher {
+            names: &names,
+            furthest: 0,
+            expected: BTreeMap::new(),
+        };
+        let mut positions = BTreeSet::from([0]);
+        for particle in &content.particles {
+            positions = matcher.particle(particle, &positions);
+        }
+        if positions.contains(&names.len()) {
+            return;
+        }
+
+        let furthest = positions
+            .iter()
+            .copied()
+            .max()
+            .unwrap_or(0)
+            .max(matcher.furthest);
+        if furthest < names.len() {
+            let child_path = child_paths(element, path).nth(furthest).unwrap_or_default();
+            let expected = matcher
+                .expected
+                .remove(&furthest)
+                .unwrap_or_default()
+                .into_iter()
+                .filter(|name| *name != names[furthest])
+                .map(str::to_string)
+                .collect();
+            self.report(
+                &child_path,
+                ViolationKind::UnexpectedElement {
+                    name: names[furthest].to_string(),
+                    expected,
+                },
+            );
+        } else {
+            let missing = content
+                .particles
+                .iter()
+                .find_map(|particle| missing_element(particle, &names))
+                .unwrap_or_default();
+            self.report(path, ViolationKind::MissingElement(missing));
+        }
+    }
+
+    /// 단순 값 검사 (실패하면 기대한 형식 설명)
+    fn check_value(&self, type_ref: &TypeRef, value: &str) -> Result<(), String> {
+        match type_ref {
+            TypeRef::Any | TypeRef::Complex(_) => Ok(()),
+            TypeRef::Builtin(builtin) => check_builtin(*builtin, value),
+            TypeRef::Simple(simple_type) => self.check_simple(simple_type, value),
+            TypeRef::Named(key) => match self.schema.simple_types.get(key) {
+                Some(simple_type) => self.check_simple(simple_type, value),
+                None => Ok(()),
+            },
+        }
+    }
+
+    fn check_simple(&self, simple_type: &SimpleType, value: &str) -> Result<(), String> {
+        self.check_value(&simple_type.base, value)?;
+        if !simple_type.enumerations.is_empty()
+            && !simple_type.enumerations.iter().any(|item| item == value)
+        {
+            return Err(format!("one of {}", simple_type.enumerations.join(", ")));
+        }
+        if simple_type.min_inclusive.is_some() || simple_type.max_inclusive.is_some() {
+            let number: f64 = value.trim().parse().map_err(|_| "a number".to_string())?;
+            if let Some(min) = simple_type.min_inclusive
+                && number < min
+            {
+                return Err(format!("a value >= {}", min));
+            }
+            if let Some(max) = simple_type.max_inclusive
+                && number > max
+            {
+                return Err(format!("a value <= {}", max));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// 자식 요소 경로 (`부모/이름[같은 이름 중 순번]`)
+fn child_paths<'e>(element: &'e Element, path: &'e str) -> impl Iterator<Item = String> + 'e {
+    element
+        .children
+        .iter()
+        .enumerate()
+        .map(move |(index, child)| {
+            let position = element.children[..=index]
+                .iter()
+                .filter(|sibling| sibling.name == child.name)
+                .count();
+            format!("{}/{}[{}]", path, child.name, position)
+        })
+}
+
+fn contains_any(particle: &Particle) -> bool {
+    match particle {
+        Particle::Any { .. } => true,
+        Particle::Element(_) => false,
+        Particle::Sequence(group) | Particle::Choice(group) => {
+            group.particles.iter().any(contains_any)
+        }
+    }
+}
+
+fn find_element<'p>(particle: &'p Particle, name: &str) -> Option<&'p ElementDecl> {
+    match particle {
+        Particle::Element(decl) => (decl.name == name).then_some(decl),
+        Particle::Sequence(group) | Particle::Choice(group) => group
+            .particles
+            .iter()
+            .find_map(|particle| find_element(particle, name)),
+        Particle::Any { .. } => None,
+    }
+}
+
+/// 필수인데 자식 요소에 없는 첫 요소 이름
+fn missing_element(particle: &Particle, names: &[&str]) -> Option<String> {
+    match particle {
+        Particle::Element(decl) => {
+            let count = names.iter().filter(|name| **name == decl.name).count();
+            (count < decl.min).then(|| decl.name.clone())
+        }
+        Particle::Sequence(group) if group.min > 0 => group
+            .particles
+            .iter()
+            .find_map(|particle| missing_element(particle, names)),
+        _ => None,
+    }
+}
+
+/// 내용 모델 일치 검사
+///
+/// 자식 요소 목록에서 도달할 수 있는 위치의 집합을 파티클마다 넓혀 가는 방식으로,
+/// 되돌아가기 없이 `sequence`/`choice`와 반복 횟수를 모두 확인합니다.
+struct Matcher<'n, 's> {
+    names: &'n [&'n str],
+    /// 지금까지 도달한 가장 먼 위치 (어긋난 자식 요소 보고용)
+    furthest: usize,
+    /// 위치마다 시도한 요소 이름
+    expected: BTreeMap<usize, BTreeSet<&'s str>>,
+}
+
+impl<'s> Matcher<'_, 's> {
+    fn particle(&mut self, particle: &'s Particle, from: &BTreeSet<usize>) -> BTreeSet<usize> {
+        let result = match particle {
+            Particle::Element(decl) => self.repeat(from, decl.min, decl.max, |matcher, from| {
+                for position in from {
+                    matcher
+                        .expected
+                        .entry(*position)
+                        .or_default()
+                        .insert(&decl.name);
+                }
+                matcher.step(from, |name| name == decl.name)
+            }),
+            Particle::Any { min, max } => self.repeat(from, *min, *max, |matcher, from| {
+                matcher.step(from, |_| true)
+            }),
+            Particle::Sequence(group) => {
+                self.repeat(from, group.min, group.max, |matcher, from| {
+                    let mut positions = from.clone();
+                    for particle in &group.particles {
+                        positions = matcher.particle(particle, &positions);
+                    }
+                    positions
+                })
+            }
+            Particle::Choice(group) => self.repeat(from, group.min, group.max, |matcher, from| {
+                let mut positions = BTreeSet::new();
+                for particle in &group.particles {
+                    positions.extend(matcher.particle(particle, from));
+                }
+                positions
+            }),
+        };
+        if let Some(last) = result.last() {
+            self.furthest = self.furthest.max(*last);
+        }
+        result
+    }
+
+    /// 요소 하나 소비
+    fn step(&self, from: &BTreeSet<usize>, matches: impl Fn(&str) -> bool) -> BTreeSet<usize> {
+        from.iter()
+            .filter(|position| self.names.get(**position).is_some_and(|name| matches(name)))
+            .map(|position| position + 1)
+            .collect()
+    }
+
+    /// `once`를 `min`번 이상 `max`번 이하 반복
+    fn repeat(
+        &mut self,
+        from: &BTreeSet<usize>,
+        min: usize,
+        max: MaxOccurs,
+        mut once: impl FnMut(&mut Self, &BTreeSet<usize>) -> BTreeSet<usize>,
+    ) -> BTreeSet<usize> {
+        let mut current = from.clone();
+        for _ in 0..min {
+            current = once(self, &current);
+            if current.is_empty() {
+                return current;
+            }
+        }
+
+        let mut result = current.clone();
+        let mut count = min;
+        // 위치는 자식 요소 수를 넘지 않으므로 새 위치가 없으면 멈춤
+        while max.is_none_or(|max| count < max) {
+            let next: BTreeSet<usize> = once(self, &current)
+                .into_iter()
+                .filter(|position| !result.contains(position))
+                .collect();
+            if next.is_empty() {
+                break;
+            }
+            result.extend(&next);
+            current = next;
+            count += 1;
+        }
+        result
+    }
+}
+
+fn check_builtin(builtin: Builtin, value: &str) -> Result<(), String> {
+    let value = value.trim();
+    let valid = match builtin {
+        Builtin::String => true,
+        Builtin::Boolean => matches!(value, "true" | "false" | "1" | "0"),
+        Builtin::Integer => value.parse::<i128>().is_ok(),
+        Builtin::Int => value.parse::<i32>().is_ok(),
+        Builtin::Byte => value.parse::<i8>().is_ok(),
+        Builtin::UnsignedInt => value.parse::<u32>().is_ok(),
+        Builtin::UnsignedShort => value.parse::<u16>().is_ok(),
+        Builtin::NonNegativeInteger => value.parse::<i128>().is_ok_and(|number| number >= 0),
+        Builtin::PositiveInteger => value.parse::<i128>().is_ok_and(|number| number > 0),
+        Builtin::NonPositiveInteger => value.parse::<i128>().is_ok_and(|number| number <= 0),
+        Builtin::Float => matches!(value, "INF" | "-INF" | "NaN") || value.parse::<f64>().is_ok(),
+        Builtin::DateTime => is_date_time(value),
+    };
+    if valid {
+        Ok(())
+    } else {
+        Err(builtin_name(builtin).to_string())
+    }
+}
+
+/// `YYYY-MM-DDThh:mm:ss` 형태인지 (소수 초와 시간대는 따지지 않음)
+fn is_date_time(value: &str) -> bool {
+    let bytes = value.trim_start_matches('-').as_bytes();
+    bytes.len() >= 19
+        && bytes[4] == b'-'
+        && bytes[7] == b'-'
+        && bytes[10] == b'T'
+        && bytes[13] == b':'
+        && bytes[16] == b':'
+        && [0..4, 5..7, 8..10, 11..13, 14..16, 17..19]
+            .into_iter()
+            .all(|range| bytes[range].iter().all(u8::is_ascii_digit))
+}
+
+fn builtin_name(builtin: Builtin) -> &'static str {
+    match builtin {
+        Builtin::String => "xs:string",
+        Builtin::Boolean => "xs:boolean",
+        Builtin::Integer => "xs:integer",
+        Builtin::Int => "xs:int",
+        Builtin::Byte => "xs:byte",
+        Builtin::UnsignedInt => "xs:unsignedInt",
+        Builtin::UnsignedShort => "xs:unsignedShort",
+        Builtin::NonNegativeInteger => "xs:nonNegativeInteger",
+        Builtin::PositiveInteger => "xs:positiveInteger",
+        Builtin::NonPositiveInteger => "xs:nonPositiveInteger",
+        Builtin::Float => "xs:float",
+        Builtin::DateTime => "xs:dateTime",
+    }
+}
+
+fn parse_tree(xml: &str) -> Result<Element, String> {
+    let mut reader = Reader::from_str(xml.strip_prefix('\u{FEFF}').unwrap_or(xml));
+    let mut stack = vec![Element::default()];
+
+    loop {
+        let event = reader
+            .read_event()
+            .map_err(|err| format!("{} at byte {}", err, reader.error_position()))?;
+        match event {
+            Event::Start(start) => stack.push(element(&start)?),
+            Event::Empty(start) => {
+                let element = element(&start)?;
+                stack.last_mut().unwrap().children.push(element);
+            }
+            Event::End(_) => {
+                let element = stack.pop().unwrap();
+                stack
+                    .last_mut()
+                    .ok_or("unbalanced end tag")?
+                    .children
+                    .push(element);
+            }
+            Event::Text(text) => {
+                let text = text.decode().map_err(|err| err.to_string())?;
+                let text = quick_xml::escape::unescape(&text).map_err(|err| err.to_string())?;
+                stack.last_mut().unwrap().text.push_str(&text);
+            }
+            Event::CData(data) => {
+                let data = data.decode().map_err(|err| err.to_string())?;
+                stack.last_mut().unwrap().text.push_str(&data);
+            }
+            Event::GeneralRef(reference) => {
+                let text = match reference
+                    .resolve_char_ref()
+                    .map_err(|err| err.to_string())?
+                {
+                    Some(ch) => ch.to_string(),
+                    None => {
+                        let name = reference.decode().map_err(|err| err.to_string())?;
+                        quick_xml::escape::resolve_predefined_entity(&name)
+                            .ok_or_else(|| format!("unknown entity &{};", name))?
+                            .to_string()
+                    }
+                };
+                stack.last_mut().unwrap().text.push_str(&text);
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+    if stack.len() != 1 {
+        return Err("unclosed element".to_string());
+    }
+    stack
+        .pop()
+        .and_then(|document| document.children.into_iter().next())
+        .ok_or_else(|| "missing root element".to_string())
+}
+
+fn element(start: &BytesStart<'_>) -> Result<Element, String> {
+    let mut attributes = Vec::new();
+    for attribute in start.attributes() {
+        let attribute = attribute.map_err(|err| err.to_string())?;
+        let key = String::from_utf8_lossy(attribute.key.as_ref()).into_owned();
+        let value = attribute.unescape_value().map_err(|err| err.to_string())?;
+        attributes.push((key, value.into_owned()));
+    }
+    Ok(Element {
+        name: String::from_utf8_lossy(start.local_name().as_ref()).into_owned(),
+        attributes,
+        children: Vec::new(),
+        text: String::new(),
+    })
+}
@@ -0,0 +1,540 @@
+//! XSD 읽기
+//!
+//! 크레이트의 `schemas`(`docs/hwpx/schemas`를 가리키는 링크)에 있는 KS X 6101 스키마를
+//! 검증에 필요한 만큼만 해석합니다.
+//! 요소/속성 선언, 시퀀스/선택/`any` 파티클, 복합 형식 확장, 속성 그룹,
+//! 열거형과 최소/최대값 제약을 읽고, 주석과 `pattern`/`whiteSpace` 제약은 무시합니다.
+
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+use quick_xml::Reader;
+use quick_xml::events::{BytesStart, Event};
+
+/// 포함된 스키마 원본 (파일 이름, 내용)
+const SOURCES: &[(&str, &str)] = &[
+    ("core.xsd", include_str!("../../schemas/core.xsd")),
+    ("header.xsd", include_str!("../../schemas/header.xsd")),
+    ("paralist.xsd", include_str!("../../schemas/paralist.xsd")),
+    ("body.xsd", include_str!("../../schemas/body.xsd")),
+    (
+        "masterpage.xsd",
+        include_str!("../../schemas/masterpage.xsd"),
+    ),
+    ("history.xsd", include_str!("../../schemas/history.xsd")),
+    ("version.xsd", include_str!("../../schemas/version.xsd")),
+];
+
+/// XML 스키마 네임스페이스
+const XS_NAMESPACE: &str = "http://www.w3.org/2001/XMLSchema";
+
+/// paralist.xsd 네임스페이스
+const PARAGRAPH_NAMESPACE: &str = "http://www.owpml.org/owpml/2024/paragraph";
+
+/// 포함된 스키마 전체
+pub(crate) static SCHEMA: LazyLock<Schema> = LazyLock::new(|| {
+    let mut schema = Schema::default();
+    for (name, source) in SOURCES {
+        let root = parse_tree(source).unwrap_or_else(|err| panic!("{}: {}", name, err));
+        schema.load(&root);
+    }
+    schema.apply_errata();
+    schema
+});
+
+/// 최대 출현 횟수 (`None`은 `unbounded`)
+pub(crate) type MaxOccurs = Option<usize>;
+
+/// 해석된 스키마 집합
+#[derive(Debug, Default)]
+pub(crate) struct Schema {
+    /// 전역 요소 (로컬 이름 → 선언)
+    pub elements: HashMap<String, ElementDecl>,
+    /// 이름 있는 복합 형식 (`{네임스페이스}이름` → 정의)
+    pub complex_types: HashMap<String, ComplexType>,
+    /// 이름 있는 단순 형식 (`{네임스페이스}이름` → 정의)
+    pub simple_types: HashMap<String, SimpleType>,
+    /// 속성 그룹 (`{네임스페이스}이름` → 속성 목록)
+    pub attribute_groups: HashMap<String, Vec<AttributeDecl>>,
+}
+
+/// 형식 참조
+#[derive(Debug, Clone)]
+pub(crate) enum TypeRef {
+    /// 형식 지정 없음 (`anyType`, 무엇이든 허용)
+    Any,
+    /// XML 스키마 기본 형식
+    Builtin(Builtin),
+    /// 이름 있는 형식 (`{네임스페이스}이름`)
+    Named(String),
+    /// 요소 안에 정의된 복합 형식
+    Complex(Box<ComplexType>),
+    /// 요소/속성 안에 정의된 단순 형식
+    Simple(Box<SimpleType>),
+}
+
+/// XML 스키마 기본 형식
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Builtin {
+    String,
+    Boolean,
+    Integer,
+    Int,
+    Byte,
+    UnsignedInt,
+    UnsignedShort,
+    NonNegativeInteger,
+    PositiveInteger,
+    NonPositiveInteger,
+    Float,
+    DateTime,
+}
+
+/// 요소 선언
+#[derive(Debug, Clone)]
+pub(crate) struct ElementDecl {
+    pub name: String,
+    pub type_ref: TypeRef,
+    pub min: usize,
+    pub max: MaxOccurs,
+}
+
+/// 내용 모델 파티클
+#[derive(Debug, Clone)]
+pub(crate) enum Particle {
+    /// 요소
+    Element(ElementDecl),
+    /// 순서대로 나오는 파티클 (`sequence`)
+    Sequence(Group),
+    /// 하나를 고르는 파티클 (`choice`)
+    Choice(Group),
+    /// 아무 요소 (`any`)
+    Any { min: usize, max: MaxOccurs },
+}
+
+/// 파티클 묶음
+#[derive(Debug, Clone)]
+pub(crate) struct Group {
+    pub particles: Vec<Particle>,
+    pub min: usize,
+    pub max: MaxOccurs,
+}
+
+/// 복합 형식
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ComplexType {
+    /// 확장 기반 형식 (`complexContent`/`simpleContent`의 `extension`)
+    pub base: Option<TypeRef>,
+    /// 내용 모델
+    pub particle: Option<Particle>,
+    /// 속성 선언
+    pub attributes: Vec<AttributeDecl>,
+    /// 참조한 속성 그룹
+    pub attribute_groups: Vec<String>,
+    /// 문자 데이터와 요소를 섞어 쓸 수 있는지 여부
+    pub mixed: bool,
+}
+
+/// 속성 선언
+#[derive(Debug, Clone)]
+pub(crate) struct AttributeDecl {
+    pub name: String,
+    pub type_ref: TypeRef,
+    pub required: bool,
+    pub fixed: Option<String>,
+}
+
+/// 단순 형식 (`restriction`)
+#[derive(Debug, Clone)]
+pub(crate) struct SimpleType {
+    pub base: TypeRef,
+    pub enumerations: Vec<String>,
+    pub min_inclusive: Option<f64>,
+    pub max_inclusive: Option<f64>,
+}
+
+/// 스키마 파일의 XML 트리
+#[derive(Debug, Default)]
+struct Node {
+    /// 로컬 이름
+    name: String,
+    attributes: HashMap<String, String>,
+    children: Vec<Node>,
+}
+
+impl Node {
+    fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes.get(name).map(String::as_str)
+    }
+
+    fn children_named<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a Node> {
+        self.children.iter().filter(move |child| child.name == name)
+    }
+
+    fn child(&self, name: &str) -> Option<&Node> {
+        self.children.iter().find(|child| child.name == name)
+    }
+}
+
+fn parse_tree(source: &str) -> Result<Node, String> {
+    let mut reader = Reader::from_str(source);
+    reader.config_mut().trim_text(true);
+    let mut stack = vec![Node::default()];
+
+    loop {
+        match reader.read_event().map_err(|err| err.to_string())? {
+            Event::Start(start) => stack.push(node(&start)?),
+            Event::Empty(start) => {
+                let node = node(&start)?;
+                stack.last_mut().unwrap().children.push(node);
+            }
+            Event::End(_) => {
+                let node = stack.pop().unwrap();
+                stack
+                    .last_mut()
+                    .ok_or("unbalanced end tag")?
+                    .children
+                    .push(node);
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+    let document = stack.pop().ok_or("empty schema")?;
+    document
+        .children
+        .into_iter()
+        .next()
+        .ok_or_else(|| "missing schema root".to_string())
+}
+
+fn node(start: &BytesStart<'_>) -> Result<Node, String> {
+    let name = String::from_utf8_lossy(start.local_name().as_ref()).into_owned();
+    let mut attributes = HashMap::new();
+    for attribute in start.attributes() {
+        let attribute = attribute.map_err(|err| err.to_string())?;
+        let key = String::from_utf8_lossy(attribute.key.as_ref()).into_owned();
+        let value = attribute.unescape_value().map_err(|err| err.to_string())?;
+        attributes.insert(key, value.into_owned());
+    }
+    Ok(Node {
+        name,
+        attributes,
+        children: Vec::new(),
+    })
+}
+
+/// 스키마 파일 하나의 이름 해석 문맥
+struct Context<'a> {
+    target_namespace: &'a str,
+    /// 접두어 → 네임스페이스 (기본 네임스페이스는 빈 접두어)
+    namespaces: HashMap<&'a str, &'a str>,
+}
+
+impl<'a> Context<'a> {
+    fn new(root: &'a Node) -> Self {
+        let namespaces = root
+            .attributes
+            .iter()
+            .filter_map(|(key, value)| {
+                if key == "xmlns" {
+                    Some(("", value.as_str()))
+                } else {
+                    key.strip_prefix("xmlns:")
+                        .map(|prefix| (prefix, value.as_str()))
+                }
+            })
+            .collect();
+        Self {
+            target_namespace: root.attribute("targetNamespace").unwrap_or_default(),
+            namespaces,
+        }
+    }
+
+    /// 정의하는 이름의 키
+    fn key(&self, name: &str) -> String {
+        format!("{{{}}}{}", self.target_namespace, name)
+    }
+
+    /// `prefix:name` 참조를 `{네임스페이스}이름` 키로 변환
+    fn resolve(&self, qualified: &str) -> (String, String) {
+        let (prefix, local) = qualified.split_once(':').unwrap_or(("", qualified));
+        let namespace = self.namespaces.get(prefix).copied().unwrap_or_default();
+        (namespace.to_string(), local.to_string())
+    }
+
+    fn type_ref(&self, qualified: &str) -> TypeRef {
+        let (namespace, local) = self.resolve(qualified);
+        if namespace == XS_NAMESPACE {
+            return match builtin(&local) {
+                Some(builtin) => TypeRef::Builtin(builtin),
+                None => TypeRef::Any,
+            };
+        }
+        TypeRef::Named(format!("{{{}}}{}", namespace, local))
+    }
+}
+
+fn builtin(name: &str) -> Option<Builtin> {
+    Some(match name {
+        "string" | "normalizedString" | "token" | "anyURI" | "ID" | "IDREF" => Builtin::String,
+        "boolean" => Builtin::Boolean,
+        "integer" | "long" => Builtin::Integer,
+        "int" => Builtin::Int,
+        "byte" => Builtin::Byte,
+        "unsignedInt" => Builtin::UnsignedInt,
+        "unsignedShort" => Builtin::UnsignedShort,
+        "nonNegativeInteger" => Builtin::NonNegativeInteger,
+        "positiveInteger" => Builtin::PositiveInteger,
+        "nonPositiveInteger" => Builtin::NonPositiveInteger,
+        "float" | "double" | "decimal" => Builtin::Float,
+        "dateTime" => Builtin::DateTime,
+        _ => return None,
+    })
+}
+
+fn occurs(node: &Node) -> (usize, MaxOccurs) {
+    let min = node
+        .attribute("minOccurs")
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(1);
+    let max = match node.attribute("maxOccurs") {
+        Some("unbounded") => None,
+        Some(value) => Some(value.parse().unwrap_or(1)),
+        None => Some(1),
+    };
+    (min, max)
+}
+
+impl Schema {
+    fn load(&mut self, root: &Node) {
+        let context = Context::new(root);
+        for child in &root.children {
+            let Some(name) = child.attribute("name") else {
+                continue;
+            };
+            match child.name.as_str() {
+                "element" => {
+                    let element = element_decl(&context, child);
+                    self.elements.insert(name.to_string(), element);
+                }
+                "complexType" => {
+                    let complex_type = complex_type(&context, child);
+                    self.complex_types.insert(context.key(name), complex_type);
+                }
+                "simpleType" => {
+                    let simple_type = simple_type(&context, child);
+                    self.simple_types.insert(context.key(name), simple_type);
+                }
+                "attributeGroup" => {
+                    let attributes = child
+                        .children_named("attribute")
+                        .map(|attribute| attribute_decl(&context, attribute))
+                        .collect();
+                    self.attribute_groups.insert(context.key(name), attributes);
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+impl Schema {
+    /// 표준 XSD가 실제 문서와 다른 부분 보정
+    fn apply_errata(&mut self) {
+        // version.xsd: `micro`가 nonPositiveInteger로 잘못 선언되어 있고,
+        // 한글이 기록하는 `os`/`xmlVersion` 속성이 빠져 있음
+        if let Some(ElementDecl {
+            type_ref: TypeRef::Complex(version),
+            ..
+        }) = self.elements.get_mut("version")
+        {
+            for attribute in &mut version.attributes {
+                if attribute.name == "micro" {
+                    attribute.type_ref = TypeRef::Builtin(Builtin::NonNegativeInteger);
+                }
+            }
+            version
+                .attributes
+                .extend(["os", "xmlVersion"].map(|name| optional_attribute(name, Builtin::String)));
+        }
+
+        // paralist.xsd: 한글이 문단마다 기록하는 줄 배치 정보(`linesegarray`)가 빠져 있음
+        let key = format!("{{{}}}PType", PARAGRAPH_NAMESPACE);
+        if let Some(Particle::Sequence(group)) = self
+            .complex_types
+            .get_mut(&key)
+            .and_then(|paragraph| paragraph.particle.as_mut())
+        {
+            let line_segment = ComplexType {
+                attributes: [
+                    "textpos",
+                    "vertpos",
+                    "vertsize",
+                    "textheight",
+                    "baseline",
+                    "spacing",
+                    "horzpos",
+                    "horzsize",
+                    "flags",
+                ]
+                .map(|name| optional_attribute(name, Builtin::Integer))
+                .into(),
+                ..ComplexType::default()
+            };
+            let line_segments = ComplexType {
+                particle: Some(Particle::Element(ElementDecl {
+                    name: "lineseg".to_string(),
+                    type_ref: TypeRef::Complex(Box::new(line_segment)),
+                    min: 0,
+                    max: None,
+                })),
+                ..ComplexType::default()
+            };
+            group.particles.push(Particle::Element(ElementDecl {
+                name: "linesegarray".to_string(),
+                type_ref: TypeRef::Complex(Box::new(line_segments)),
+                min: 0,
+                max: Some(1),
+            }));
+        }
+    }
+}
+
+fn optional_attribute(name: &str, builtin: Builtin) -> AttributeDecl {
+    AttributeDecl {
+        name: name.to_string(),
+        type_ref: TypeRef::Builtin(builtin),
+        required: false,
+        fixed: None,
+    }
+}
+
+fn element_decl(context: &Context<'_>, node: &Node) -> ElementDecl {
+    let type_ref = if let Some(type_name) = node.attribute("type") {
+        context.type_ref(type_name)
+    } else if let Some(complex) = node.child("complexType") {
+        TypeRef::Complex(Box::new(complex_type(context, complex)))
+    } else if let Some(simple) = node.child("simpleType") {
+        TypeRef::Simple(Box::new(simple_type(context, simple)))
+    } else {
+        TypeRef::Any
+    };
+    let (min, max) = occurs(node);
+    ElementDecl {
+        name: node.attribute("name").unwrap_or_default().to_string(),
+        type_ref,
+        min,
+        max,
+    }
+}
+
+fn complex_type(context: &Context<'_>, node: &Node) -> ComplexType {
+    let mut complex_type = ComplexType {
+        mixed: node.attribute("mixed") == Some("true"),
+        ..ComplexType::default()
+    };
+
+    // complexContent/simpleContent 확장이면 extension 요소 안에 내용이 있음
+    let content = node
+        .child("complexContent")
+        .or_else(|| node.child("simpleContent"));
+    let body = match content.and_then(|content| content.child("extension")) {
+        Some(extension) => {
+            complex_type.base = extension
+                .attribute("base")
+                .map(|base| context.type_ref(base));
+            if content.and_then(|content| content.attribute("mixed")) == Some("true") {
+                complex_type.mixed = true;
+            }
+            extension
+        }
+        None => node,
+    };
+
+    for child in &body.children {
+        match child.name.as_str() {
+            "sequence" | "choice" | "any" => complex_type.particle = particle(context, child),
+            "attribute" => complex_type.attributes.push(attribute_decl(context, child)),
+            "attributeGroup" => {
+                if let Some(reference) = child.attribute("ref") {
+                    let (namespace, local) = context.resolve(reference);
+                    complex_type
+                        .attribute_groups
+                        .push(format!("{{{}}}{}", namespace, local));
+                }
+            }
+            _ => {}
+        }
+    }
+    complex_type
+}
+
+fn particle(context: &Context<'_>, node: &Node) -> Option<Particle> {
+    let (min, max) = occurs(node);
+    let group = || Group {
+        particles: node
+            .children
+            .iter()
+            .filter_map(|child| particle(context, child))
+            .collect(),
+        min,
+        max,
+    };
+    match node.name.as_str() {
+        "element" => Some(Particle::Element(element_decl(context, node))),
+        "sequence" => Some(Particle::Sequence(group())),
+        "choice" => Some(Particle::Choice(group())),
+        "any" => Some(Particle::Any { min, max }),
+        _ => None,
+    }
+}
+
+fn attribute_decl(context: &Context<'_>, node: &Node) -> AttributeDecl {
+    let (name, type_ref) = match node.attribute("ref") {
+        // 외부 스키마(xml.xsd)를 읽지 않으므로 `xml:space` 같은 참조는 문자열로 취급
+        Some(reference) => (reference.to_string(), TypeRef::Builtin(Builtin::String)),
+        None => {
+            let type_ref = match (node.attribute("type"), node.child("simpleType")) {
+                (Some(type_name), _) => context.type_ref(type_name),
+                (None, Some(simple)) => TypeRef::Simple(Box::new(simple_type(context, simple))),
+                (None, None) => TypeRef::Builtin(Builtin::String),
+            };
+            (
+                node.attribute("name").unwrap_or_default().to_string(),
+                type_ref,
+            )
+        }
+    };
+    AttributeDecl {
+        name,
+        type_ref,
+        required: node.attribute("use") == Some("required"),
+        fixed: node.attribute("fixed").map(str::to_string),
+    }
+}
+
+fn simple_type(context: &Context<'_>, node: &Node) -> SimpleType {
+    let mut simple_type = SimpleType {
+        base: TypeRef::Builtin(Builtin::String),
+        enumerations: Vec::new(),
+        min_inclusive: None,
+        max_inclusive: None,
+    };
+    let Some(restriction) = node.child("restriction") else {
+        return simple_type;
+    };
+    if let Some(base) = restriction.attribute("base") {
+        simple_type.base = context.type_ref(base);
+    }
+    for facet in &restriction.children {
+        let value = facet.attribute("value").unwrap_or_default();
+        match facet.name.as_str() {
+            "enumeration" => simple_type.enumerations.push(value.to_string()),
+            "minInclusive" => simple_type.min_inclusive = value.parse().ok(),
+            "maxInclusive" => simple_type.max_inclusive = value.parse().ok(),
+            _ => {}
+        }
+    }
+    simple_type
+}
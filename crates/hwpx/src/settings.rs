@@ -0,0 +1,111 @@
+//! 응용 프로그램 설정 (`settings.xml`)
+//!
+//! 문서를 다시 열 때 복원할 커서 위치와 인쇄/보기 설정 같은 응용 프로그램 설정을 담습니다.
+//! 설정 항목은 ODF `config-item-set`/`config-item` 구조를 그대로 씁니다.
+//! KS X 6101:2024 14장 settings XML 스키마를 근거로 합니다.
+
+use serde::{Deserialize, Serialize};
+
+/// settings XML 네임스페이스 (접두어 `ha`)
+pub const SETTINGS_NAMESPACE: &str = "http://www.owpml.org/owpml/2024/app";
+
+/// ODF 설정 네임스페이스 (접두어 `config`)
+pub const CONFIG_NAMESPACE: &str = "urn:oasis:names:tc:opendocument:xmlns:config:1.0";
+
+/// 응용 프로그램 설정 (`HWPApplicationSetting` 요소)
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename = "ha:HWPApplicationSetting")]
+pub struct ApplicationSetting {
+    /// 커서 위치 (`CaretPosition`)
+    #[serde(
+        rename(serialize = "ha:CaretPosition", deserialize = "CaretPosition"),
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub caret_position: Option<CaretPosition>,
+
+    /// 설정 묶음 목록 (`config-item-set`)
+    #[serde(
+        rename(serialize = "config:config-item-set", deserialize = "config-item-set"),
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub config_item_sets: Vec<ConfigItemSet>,
+
+    /// 묶음에 속하지 않은 설정 목록 (`config-item`)
+    #[serde(
+        rename(serialize = "config:config-item", deserialize = "config-item"),
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub config_items: Vec<ConfigItem>,
+}
+
+/// 커서 위치 (`CaretPosition` 요소)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct CaretPosition {
+    /// 리스트 아이디 (`listIDRef`)
+    #[serde(rename = "@listIDRef", default)]
+    pub list_id_reference: u32,
+
+    /// 문단 아이디 (`paraIDRef`)
+    #[serde(rename = "@paraIDRef", default)]
+    pub paragraph_id_reference: u32,
+
+    /// 문단 내의 글자 위치 (`pos`)
+    #[serde(rename = "@pos", default)]
+    pub position: u32,
+}
+
+/// 설정 묶음 (`config-item-set` 요소)
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct ConfigItemSet {
+    /// 설정 묶음 이름 (`name`)
+    #[serde(rename(serialize = "@config:name", deserialize = "@name"))]
+    pub name: String,
+
+    /// 설정 목록 (`config-item`)
+    #[serde(
+        rename(serialize = "config:config-item", deserialize = "config-item"),
+        default
+    )]
+    pub items: Vec<ConfigItem>,
+}
+
+/// 설정 (`config-item` 요소)
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct ConfigItem {
+    /// 설정 이름 (`name`)
+    #[serde(rename(serialize = "@config:name", deserialize = "@name"))]
+    pub name: String,
+
+    /// 설정 데이터 타입 (`type`, 예: `short`, `boolean`, `string`, `base64Binary`)
+    #[serde(rename(serialize = "@config:type", deserialize = "@type"))]
+    pub item_type: String,
+
+    /// 설정 값
+    #[serde(rename = "$text", default)]
+    pub value: String,
+}
+
+impl ApplicationSetting {
+    /// 커서 위치만 가진 설정 생성
+    pub const fn with_caret_position(caret_position: CaretPosition) -> Self {
+        Self {
+            caret_position: Some(caret_position),
+            config_item_sets: Vec::new(),
+            config_items: Vec::new(),
+        }
+    }
+
+    /// 이름으로 설정 묶음 찾기
+    pub fn config_item_set(&self, name: &str) -> Option<&ConfigItemSet> {
+        self.config_item_sets.iter().find(|set| set.name == name)
+    }
+}
+
+impl ConfigItemSet {
+    /// 이름으로 설정 찾기
+    pub fn item(&self, name: &str) -> Option<&ConfigItem> {
+        self.items.iter().find(|item| item.name == name)
+    }
+}
@@ -0,0 +1,480 @@
+//! XML 정규화 (Canonical XML 1.0, Exclusive XML Canonicalization 1.0)
+//!
+//! 서명 대상 XML을 바이트 단위로 비교할 수 있도록 같은 의미의 문서를 항상 같은 바이트열로 바꿉니다.
+//!
+//! - XML 선언과 DOCTYPE 제거, 빈 요소는 시작/끝 태그 쌍으로 확장
+//! - 줄바꿈은 `\n`으로, 문자/엔티티 참조와 CDATA는 풀어서 다시 이스케이프
+//! - 네임스페이스 선언은 접두어 순, 속성은 (네임스페이스 URI, 로컬 이름) 순으로 정렬
+//! - 조상에서 이미 출력된 네임스페이스 선언은 생략
+//!
+//! DTD 기반 기본 속성이나 사용자 정의 엔티티는 지원하지 않습니다.
+
+use std::collections::BTreeMap;
+
+use quick_xml::Reader;
+use quick_xml::escape::unescape;
+use quick_xml::events::{BytesStart, Event};
+
+use super::DSIG_NAMESPACE;
+
+/// `xml` 접두어 네임스페이스
+const XML_NAMESPACE: &str = "http://www.w3.org/XML/1998/namespace";
+
+/// 정규화 방식
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(crate) struct C14nMode {
+    /// Exclusive XML Canonicalization 여부
+    pub exclusive: bool,
+    /// 주석 유지 여부
+    pub with_comments: bool,
+}
+
+/// 정규화할 노드 범위
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Subset<'a> {
+    /// 문서 전체
+    Document,
+    /// `Id` 속성이 일치하는 요소와 그 하위
+    Id(&'a str),
+    /// 문서 순서로 `index`번째 `ds:{local}` 요소와 그 하위
+    Signature { local: &'a str, index: usize },
+}
+
+/// 정규화 요청
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct C14n<'a> {
+    /// 정규화 방식
+    pub mode: C14nMode,
+    /// 정규화할 범위
+    pub subset: Subset<'a>,
+    /// 결과에서 뺄 `ds:Signature` 요소의 문서 순서 (enveloped-signature 변환)
+    pub exclude_signature: Option<usize>,
+}
+
+impl<'a> C14n<'a> {
+    /// 문서 전체 정규화
+    pub const fn document(mode: C14nMode) -> Self {
+        Self {
+            mode,
+            subset: Subset::Document,
+            exclude_signature: None,
+        }
+    }
+
+    /// 문서 일부 정규화
+    pub const fn subset(mode: C14nMode, subset: Subset<'a>) -> Self {
+        Self {
+            mode,
+            subset,
+            exclude_signature: None,
+        }
+    }
+}
+
+/// 요소 하나의 네임스페이스/`xml:*` 속성 문맥
+#[derive(Debug, Clone, Default)]
+struct Scope {
+    /// 요소 이름
+    name: String,
+    /// 범위 안의 네임스페이스 (접두어 → URI, 기본 네임스페이스는 빈 접두어)
+    namespaces: BTreeMap<String, String>,
+    /// 상속되는 `xml:*` 속성
+    xml_attributes: BTreeMap<String, String>,
+}
+
+impl Scope {
+    fn namespace(&self, prefix: &str) -> Option<&str> {
+        match prefix {
+            "xml" => Some(XML_NAMESPACE),
+            _ => self.namespaces.get(prefix).map(String::as_str),
+        }
+    }
+}
+
+/// 출력 중인 요소 정보
+struct Rendered {
+    /// 출력된 조상까지 포함해 선언이 끝난 네임스페이스
+    namespaces: BTreeMap<String, String>,
+}
+
+/// XML 문서를 정규화
+pub(crate) fn canonicalize(xml: &str, request: C14n<'_>) -> Result<Vec<u8>, String> {
+    Canonicalizer::new(request).run(xml)
+}
+
+struct Canonicalizer<'a> {
+    request: C14n<'a>,
+    output: String,
+    scopes: Vec<Scope>,
+    rendered: Vec<Rendered>,
+    /// 선택된 범위의 최상위 요소 깊이
+    apex_depth: Option<usize>,
+    /// 범위 출력을 마쳤는지 여부
+    finished: bool,
+    /// 제외 중인 서명 요소 깊이
+    skip_depth: Option<usize>,
+    signature_count: usize,
+    element_count: usize,
+    /// 문서 요소 이후인지 여부
+    after_root: bool,
+}
+
+impl<'a> Canonicalizer<'a> {
+    fn new(request: C14n<'a>) -> Self {
+        Self {
+            request,
+            output: String::new(),
+            scopes: vec![Scope::default()],
+            rendered: Vec::new(),
+            apex_depth: match request.subset {
+                Subset::Document => Some(0),
+                _ => None,
+            },
+            finished: false,
+            skip_depth: None,
+            signature_count: 0,
+            element_count: 0,
+            after_root: false,
+        }
+    }
+
+    fn depth(&self) -> usize {
+        self.scopes.len() - 1
+    }
+
+    fn is_document(&self) -> bool {
+        self.request.subset == Subset::Document
+    }
+
+    /// 현재 위치가 출력 대상인지 여부
+    fn in_output(&self) -> bool {
+        !self.finished && self.apex_depth.is_some() && self.skip_depth.is_none()
+    }
+
+    fn run(mut self, xml: &str) -> Result<Vec<u8>, String> {
+        let mut reader = Reader::from_str(xml);
+        reader.config_mut().expand_empty_elements = true;
+
+        loop {
+            let event = reader.read_event().map_err(|err| err.to_string())?;
+            match event {
+                Event::Start(start) => self.start(&start)?,
+                Event::End(_) => self.end(),
+                Event::Text(text) => {
+                    let text = text.decode().map_err(|err| err.to_string())?;
+                    self.text(&normalize_newlines(&text));
+                }
+                Event::CData(cdata) => {
+                    let text = cdata.decode().map_err(|err| err.to_string())?;
+                    self.text(&normalize_newlines(&text));
+                }
+                Event::GeneralRef(reference) => {
+                    let character = match reference.resolve_char_ref() {
+                        Ok(Some(character)) => character,
+                        Ok(None) => {
+                            let name = reference.decode().map_err(|err| err.to_string())?;
+                            predefined_entity(&name)
+                                .ok_or_else(|| format!("unknown entity: &{};", name))?
+                        }
+                        Err(err) => return Err(err.to_string()),
+                    };
+                    self.text(character.encode_utf8(&mut [0; 4]));
+                }
+                Event::Comment(comment) => {
+                    if self.request.mode.with_comments {
+                        let text = comment.decode().map_err(|err| err.to_string())?;
+                        self.outside_node(&format!("<!--{}-->", normalize_newlines(&text)));
+                    }
+                }
+                Event::PI(pi) => {
+                    let content = String::from_utf8_lossy(&pi).into_owned();
+                    self.outside_node(&format!("<?{}?>", normalize_newlines(&content)));
+                }
+                Event::Decl(_) | Event::DocType(_) | Event::Empty(_) => {}
+                Event::Eof => break,
+            }
+        }
+
+        if self.apex_depth.is_none() {
+            return Err(match self.request.subset {
+                Subset::Id(id) => format!("no element with Id \"{}\"", id),
+                _ => "referenced element not found".to_string(),
+            });
+        }
+        Ok(self.output.into_bytes())
+    }
+
+    fn start(&mut self, start: &BytesStart<'_>) -> Result<(), String> {
+        let name = String::from_utf8_lossy(start.name().as_ref()).into_owned();
+        let mut declarations = Vec::new();
+        let mut attributes = Vec::new();
+        for attribute in start.attributes() {
+            let attribute = attribute.map_err(|err| err.to_string())?;
+            let key = String::from_utf8_lossy(attribute.key.as_ref()).into_owned();
+            let value = attribute_value(&attribute.value)?;
+            if key == "xmlns" {
+                declarations.push((String::new(), value));
+            } else if let Some(prefix) = key.strip_prefix("xmlns:") {
+                declarations.push((prefix.to_string(), value));
+            } else {
+                attributes.push((key, value));
+            }
+        }
+
+        let parent = self.scopes.last().cloned().unwrap_or_default();
+        let mut scope = parent.clone();
+        scope.name = name.clone();
+        for (prefix, uri) in declarations {
+            scope.namespaces.insert(prefix, uri);
+        }
+        for (key, value) in &attributes {
+            if key.starts_with("xml:") {
+                scope.xml_attributes.insert(key.clone(), value.clone());
+            }
+        }
+        self.scopes.push(scope);
+        let depth = self.depth();
+        let scope = &self.scopes[depth];
+
+        let (prefix, local) = split_name(&name);
+        let is_signature_element = scope.namespace(prefix) == Some(DSIG_NAMESPACE);
+        if is_signature_element && local == "Signature" {
+            if self.skip_depth.is_none()
+                && self.request.exclude_signature == Some(self.signature_count)
+            {
+                self.skip_depth = Some(depth);
+            }
+            self.signature_count += 1;
+        }
+
+        let mut is_apex = false;
+        if self.apex_depth.is_none() && !self.finished {
+            is_apex = match self.request.subset {
+                Subset::Document => false,
+                Subset::Id(id) => attributes
+                    .iter()
+                    .any(|(key, value)| matches!(key.as_str(), "Id" | "ID" | "id") && value == id),
+                Subset::Signature {
+                    local: target,
+                    index,
+                } => {
+                    let matched = is_signature_element && local == target;
+                    if matched {
+                        self.element_count += 1;
+                    }
+                    matched && self.element_count == index + 1
+                }
+            };
+            if is_apex {
+                self.apex_depth = Some(depth);
+            }
+        }
+
+        if !self.in_output() {
+            return Ok(());
+        }
+
+        // 범위 최상위 요소는 출력된 조상이 없으므로 상속 문맥을 모두 직접 선언
+        let parent_rendered = if is_apex {
+            BTreeMap::new()
+        } else {
+            self.rendered
+                .last()
+                .map(|rendered| rendered.namespaces.clone())
+                .unwrap_or_default()
+        };
+        if is_apex && !self.request.mode.exclusive {
+            for (key, value) in &parent.xml_attributes {
+                if !attributes.iter().any(|(name, _)| name == key) {
+                    attributes.push((key.clone(), value.clone()));
+                }
+            }
+        }
+
+        let scope = &self.scopes[depth];
+        let mut rendered = parent_rendered.clone();
+        let mut namespace_output = Vec::new();
+        let candidates: Vec<(String, String)> = if self.request.mode.exclusive {
+            let mut used = vec![prefix.to_string()];
+            for (key, _) in &attributes {
+                let (attribute_prefix, _) = split_name(key);
+                if !attribute_prefix.is_empty() && attribute_prefix != "xml" {
+                    used.push(attribute_prefix.to_string());
+                }
+            }
+            used.sort();
+            used.dedup();
+            used.into_iter()
+                .map(|prefix| {
+                    let uri = scope.namespace(&prefix).unwrap_or_default().to_string();
+                    (prefix, uri)
+                })
+                .collect()
+        } else {
+            scope
+                .namespaces
+                .iter()
+                .map(|(prefix, uri)| (prefix.clone(), uri.clone()))
+                .collect()
+        };
+        for (ns_prefix, uri) in candidates {
+            let inherited = parent_rendered.get(&ns_prefix).map(String::as_str);
+            let needed = if ns_prefix.is_empty() && uri.is_empty() {
+                inherited.is_some_and(|inherited| !inherited.is_empty())
+            } else {
+                inherited != Some(uri.as_str())
+            };
+            if needed {
+                rendered.insert(ns_prefix.clone(), uri.clone());
+                namespace_output.push((ns_prefix, uri));
+            }
+        }
+
+        let mut sorted_attributes: Vec<(String, String, String)> = attributes
+            .into_iter()
+            .map(|(key, value)| {
+                let (attribute_prefix, attribute_local) = split_name(&key);
+                let uri = if attribute_prefix.is_empty() {
+                    String::new()
+                } else {
+                    scope
+                        .namespace(attribute_prefix)
+                        .unwrap_or_default()
+                        .to_string()
+                };
+                let rendered_attribute = format!(" {}=\"{}\"", key, escape_attribute(&value));
+                (uri, attribute_local.to_string(), rendered_attribute)
+            })
+            .collect();
+        sorted_attributes.sort_by(|a, b| (&a.0, &a.1).cmp(&(&b.0, &b.1)));
+
+        self.output.push('<');
+        self.output.push_str(&name);
+        for (ns_prefix, uri) in namespace_output {
+            if ns_prefix.is_empty() {
+                self.output.push_str(" xmlns");
+            } else {
+                self.output.push_str(" xmlns:");
+                self.output.push_str(&ns_prefix);
+            }
+            self.output.push_str("=\"");
+            self.output.push_str(&escape_attribute(&uri));
+            self.output.push('"');
+        }
+        for (_, _, rendered_attribute) in sorted_attributes {
+            self.output.push_str(&rendered_attribute);
+        }
+        self.output.push('>');
+        self.rendered.push(Rendered {
+            namespaces: rendered,
+        });
+        Ok(())
+    }
+
+    fn end(&mut self) {
+        let depth = self.depth();
+        if self.in_output() {
+            self.output.push_str("</");
+            self.output.push_str(&self.scopes[depth].name);
+            self.output.push('>');
+            self.rendered.pop();
+        }
+        if self.skip_depth == Some(depth) {
+            self.skip_depth = None;
+        }
+        if !self.is_document() && self.apex_depth == Some(depth) {
+            self.finished = true;
+        }
+        if depth == 1 {
+            self.after_root = true;
+        }
+        self.scopes.pop();
+    }
+
+    fn text(&mut self, text: &str) {
+        if self.in_output() && self.depth() > 0 {
+            self.output.push_str(&escape_text(text));
+        }
+    }
+
+    /// 주석/처리 명령 출력
+    ///
+    /// 문서 전체를 정규화할 때 문서 요소 바깥의 노드는 앞뒤에 줄바꿈을 붙입니다.
+    fn outside_node(&mut self, node: &str) {
+        if !self.in_output() {
+            return;
+        }
+        if self.depth() > 0 {
+            self.output.push_str(node);
+        } else if self.is_document() {
+            if self.after_root {
+                self.output.push('\n');
+                self.output.push_str(node);
+            } else {
+                self.output.push_str(node);
+                self.output.push('\n');
+            }
+        }
+    }
+}
+
+/// `prefix:local` 이름 나누기
+fn split_name(name: &str) -> (&str, &str) {
+    name.split_once(':').unwrap_or(("", name))
+}
+
+/// 미리 정의된 XML 엔티티
+fn predefined_entity(name: &str) -> Option<char> {
+    match name {
+        "amp" => Some('&'),
+        "lt" => Some('<'),
+        "gt" => Some('>'),
+        "quot" => Some('"'),
+        "apos" => Some('\''),
+        _ => None,
+    }
+}
+
+/// 줄바꿈 정규화 (`\r\n`, `\r` → `\n`)
+fn normalize_newlines(text: &str) -> String {
+    text.replace("\r\n", "\n").replace('\r', "\n")
+}
+
+/// 속성 값 정규화 (공백 문자 → 공백, 참조 해석)
+fn attribute_value(raw: &[u8]) -> Result<String, String> {
+    let raw = std::str::from_utf8(raw).map_err(|err| err.to_string())?;
+    let normalized = normalize_newlines(raw).replace(['\t', '\n'], " ");
+    unescape(&normalized)
+        .map(|value| value.into_owned())
+        .map_err(|err| err.to_string())
+}
+
+fn escape_text(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for character in text.chars() {
+        match character {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '\r' => escaped.push_str("&#xD;"),
+            _ => escaped.push(character),
+        }
+    }
+    escaped
+}
+
+fn escape_attribute(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for character in value.chars() {
+        match character {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\t' => escaped.push_str("&#x9;"),
+            '\n' => escaped.push_str("&#xA;"),
+            '\r' => escaped.push_str("&#xD;"),
+            _ => escaped.push(character),
+        }
+    }
+    escaped
+}
@@ -0,0 +1,102 @@
+//! 서명 키와 인증서 처리
+//!
+//! X.509 인증서 전체를 해석하지 않고, DER 구조에서 공개키(`SubjectPublicKeyInfo`)만 꺼냅니다.
+//! 인증서 체인 검증이나 신뢰 판단은 이 모듈의 범위가 아닙니다.
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use rsa::pkcs8::DecodePublicKey;
+use rsa::traits::PublicKeyParts;
+use rsa::{BigUint, RsaPublicKey};
+
+use super::model::{RsaKeyValue, decode_base64};
+
+/// DER SEQUENCE 태그
+const TAG_SEQUENCE: u8 = 0x30;
+
+/// DER `[0]` 명시적 태그 (인증서 버전)
+const TAG_VERSION: u8 = 0xA0;
+
+/// DER 요소 하나
+struct DerElement<'a> {
+    tag: u8,
+    /// 태그와 길이를 뺀 내용
+    content: &'a [u8],
+    /// 태그와 길이를 포함한 요소 전체
+    whole: &'a [u8],
+    /// 요소 뒤에 남은 바이트
+    rest: &'a [u8],
+}
+
+fn read_der(input: &[u8]) -> Option<DerElement<'_>> {
+    let tag = *input.first()?;
+    let first = *input.get(1)?;
+    let (length, header) = if first < 0x80 {
+        (first as usize, 2)
+    } else {
+        let count = (first & 0x7F) as usize;
+        if count == 0 || count > 4 {
+            return None;
+        }
+        let bytes = input.get(2..2 + count)?;
+        let length = bytes
+            .iter()
+            .fold(0usize, |length, &byte| (length << 8) | byte as usize);
+        (length, 2 + count)
+    };
+    let end = header.checked_add(length)?;
+    Some(DerElement {
+        tag,
+        content: input.get(header..end)?,
+        whole: input.get(..end)?,
+        rest: input.get(end..)?,
+    })
+}
+
+/// DER X.509 인증서에서 RSA 공개키 꺼내기
+///
+/// `Certificate ::= SEQUENCE { tbsCertificate, ... }`의 `tbsCertificate`에서
+/// 버전, 일련번호, 서명 알고리즘, 발급자, 유효기간, 주체 다음의 `subjectPublicKeyInfo`를 읽습니다.
+pub(crate) fn public_key_from_certificate(der: &[u8]) -> Option<RsaPublicKey> {
+    let certificate = read_der(der).filter(|element| element.tag == TAG_SEQUENCE)?;
+    let tbs = read_der(certificate.content).filter(|element| element.tag == TAG_SEQUENCE)?;
+
+    let mut fields = tbs.content;
+    if fields.first() == Some(&TAG_VERSION) {
+        fields = read_der(fields)?.rest;
+    }
+    // serialNumber, signature, issuer, validity, subject
+    for _ in 0..5 {
+        fields = read_der(fields)?.rest;
+    }
+    let spki = read_der(fields).filter(|element| element.tag == TAG_SEQUENCE)?;
+    RsaPublicKey::from_public_key_der(spki.whole).ok()
+}
+
+/// `RSAKeyValue` 요소에서 RSA 공개키 만들기
+pub(crate) fn public_key_from_key_value(key_value: &RsaKeyValue) -> Option<RsaPublicKey> {
+    let modulus = decode_base64(&key_value.modulus)?;
+    let exponent = decode_base64(&key_value.exponent)?;
+    RsaPublicKey::new(
+        BigUint::from_bytes_be(&modulus),
+        BigUint::from_bytes_be(&exponent),
+    )
+    .ok()
+}
+
+/// RSA 공개키를 `RSAKeyValue` 요소로 만들기
+pub(crate) fn key_value(public_key: &RsaPublicKey) -> RsaKeyValue {
+    RsaKeyValue {
+        modulus: BASE64.encode(public_key.n().to_bytes_be()),
+        exponent: BASE64.encode(public_key.e().to_bytes_be()),
+    }
+}
+
+/// PEM 문서에서 `label` 블록의 DER 바이트 꺼내기
+pub(crate) fn pem_to_der(pem: &str, label: &str) -> Option<Vec<u8>> {
+    let begin = format!("-----BEGIN {}-----", label);
+    let end = format!("-----END {}-----", label);
+    let start = pem.find(&begin)? + begin.len();
+    let stop = start + pem[start..].find(&end)?;
+    decode_base64(&pem[start..stop])
+}
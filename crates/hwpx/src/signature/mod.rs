@@ -0,0 +1,238 @@
+//! 전자서명 (`META-INF/signatures.xml`)
+//!
+//! W3C XML Signature로 패키지 파트에 서명하고 검증합니다. KS X 6101:2024 16장 전자서명.
+//!
+//! 검증은 서명 파트의 `ds:Reference`마다 대상 파트의 해시를 다시 계산해
+//! 유효/변조/누락/미지원으로 보고하고, `ds:SignedInfo`의 서명 값을 공개키로 확인합니다.
+//! 공개키는 호출 측이 넘긴 키, `ds:KeyValue`, `ds:X509Certificate` 순으로 찾습니다.
+//! 인증서를 신뢰할지는 [`Signature::certificates`]로 꺼내 호출 측에서 판단해야 합니다.
+//!
+//! 서명은 한글과 같은 구성으로 만듭니다.
+//!
+//! - 정규화: Canonical XML 1.0 (`c14n-20010315`)
+//! - 서명 알고리즘: RSA PKCS#1 v1.5 (기본 SHA-256)
+//! - `ds:Object/ds:Manifest`에 파트별 참조, `ds:SignedInfo`는 그 목록 전체를 참조
+//!
+//! ```ignore
+//! use hwpx::signature::{self, SigningOptions};
+//!
+//! let options = SigningOptions::from_pkcs8_pem(&key_pem)?.with_certificate_pem(&cert_pem)?;
+//! let signed = signature::sign(&std::fs::read("document.hwpx")?, &options)?;
+//!
+//! for report in signature::verify(&signed)? {
+//!     assert!(report.is_valid());
+//! }
+//! ```
+
+pub(crate) mod c14n;
+mod key;
+mod model;
+mod sign;
+mod verify;
+
+use rsa::Pkcs1v15Sign;
+use sha1::Sha1;
+use sha2::{Digest, Sha256, Sha384, Sha512};
+
+pub use model::{
+    AlgorithmIdentifier, DocumentSignatures, KeyInfo, KeyValue, Object, Reference, RsaKeyValue,
+    Signature, SignatureManifest, SignatureValue, SignedInfo, Transforms, X509Data,
+    X509IssuerSerial,
+};
+pub use rsa::{RsaPrivateKey, RsaPublicKey};
+pub use sign::{SigningOptions, sign};
+pub use verify::{
+    ReferenceReport, ReferenceStatus, SignatureReport, SignatureStatus, verify, verify_reader,
+    verify_with_key,
+};
+
+use c14n::C14nMode;
+
+/// XML 전자서명 네임스페이스 (접두어 `ds`)
+pub const DSIG_NAMESPACE: &str = "http://www.w3.org/2000/09/xmldsig#";
+
+/// ODF 서명 파일 네임스페이스 (접두어 `dsig`)
+pub const DOCUMENT_SIGNATURES_NAMESPACE: &str =
+    "urn:oasis:names:tc:opendocument:xmlns:digitalsignature:1.0";
+
+/// Canonical XML 1.0
+pub const C14N: &str = "http://www.w3.org/TR/2001/REC-xml-c14n-20010315";
+
+/// Canonical XML 1.0 (주석 유지)
+pub const C14N_WITH_COMMENTS: &str = "http://www.w3.org/TR/2001/REC-xml-c14n-20010315#WithComments";
+
+/// Exclusive XML Canonicalization 1.0
+pub const EXC_C14N: &str = "http://www.w3.org/2001/10/xml-exc-c14n#";
+
+/// Exclusive XML Canonicalization 1.0 (주석 유지)
+pub const EXC_C14N_WITH_COMMENTS: &str = "http://www.w3.org/2001/10/xml-exc-c14n#WithComments";
+
+/// 서명 요소 제외 변환
+pub const ENVELOPED_SIGNATURE: &str = "http://www.w3.org/2000/09/xmldsig#enveloped-signature";
+
+/// `Reference`가 `ds:Manifest`를 가리킬 때의 `Type`
+pub const MANIFEST_TYPE: &str = "http://www.w3.org/2000/09/xmldsig#Manifest";
+
+/// SHA-1 해시
+pub const DIGEST_SHA1: &str = "http://www.w3.org/2000/09/xmldsig#sha1";
+
+/// SHA-256 해시
+pub const DIGEST_SHA256: &str = "http://www.w3.org/2001/04/xmlenc#sha256";
+
+/// SHA-384 해시
+pub const DIGEST_SHA384: &str = "http://www.w3.org/2001/04/xmldsig-more#sha384";
+
+/// SHA-512 해시
+pub const DIGEST_SHA512: &str = "http://www.w3.org/2001/04/xmlenc#sha512";
+
+/// RSA-SHA1 서명
+pub const RSA_SHA1: &str = "http://www.w3.org/2000/09/xmldsig#rsa-sha1";
+
+/// RSA-SHA256 서명
+pub const RSA_SHA256: &str = "http://www.w3.org/2001/04/xmldsig-more#rsa-sha256";
+
+/// RSA-SHA384 서명
+pub const RSA_SHA384: &str = "http://www.w3.org/2001/04/xmldsig-more#rsa-sha384";
+
+/// RSA-SHA512 서명
+pub const RSA_SHA512: &str = "http://www.w3.org/2001/04/xmldsig-more#rsa-sha512";
+
+/// 해시 알고리즘
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DigestAlgorithm {
+    /// SHA-1
+    Sha1,
+    /// SHA-256
+    #[default]
+    Sha256,
+    /// SHA-384
+    Sha384,
+    /// SHA-512
+    Sha512,
+}
+
+impl DigestAlgorithm {
+    /// `DigestMethod` URI로 찾기
+    pub fn from_digest_uri(uri: &str) -> Option<Self> {
+        match uri {
+            DIGEST_SHA1 => Some(DigestAlgorithm::Sha1),
+            DIGEST_SHA256 => Some(DigestAlgorithm::Sha256),
+            DIGEST_SHA384 => Some(DigestAlgorithm::Sha384),
+            DIGEST_SHA512 => Some(DigestAlgorithm::Sha512),
+            _ => None,
+        }
+    }
+
+    /// RSA `SignatureMethod` URI로 찾기
+    pub fn from_rsa_signature_uri(uri: &str) -> Option<Self> {
+        match uri {
+            RSA_SHA1 => Some(DigestAlgorithm::Sha1),
+            RSA_SHA256 => Some(DigestAlgorithm::Sha256),
+            RSA_SHA384 => Some(DigestAlgorithm::Sha384),
+            RSA_SHA512 => Some(DigestAlgorithm::Sha512),
+            _ => None,
+        }
+    }
+
+    /// `DigestMethod` URI
+    pub const fn digest_uri(&self) -> &'static str {
+        match self {
+            DigestAlgorithm::Sha1 => DIGEST_SHA1,
+            DigestAlgorithm::Sha256 => DIGEST_SHA256,
+            DigestAlgorithm::Sha384 => DIGEST_SHA384,
+            DigestAlgorithm::Sha512 => DIGEST_SHA512,
+        }
+    }
+
+    /// RSA `SignatureMethod` URI
+    pub const fn rsa_signature_uri(&self) -> &'static str {
+        match self {
+            DigestAlgorithm::Sha1 => RSA_SHA1,
+            DigestAlgorithm::Sha256 => RSA_SHA256,
+            DigestAlgorithm::Sha384 => RSA_SHA384,
+            DigestAlgorithm::Sha512 => RSA_SHA512,
+        }
+    }
+
+    /// 해시 계산
+    pub fn digest(&self, data: &[u8]) -> Vec<u8> {
+        match self {
+            DigestAlgorithm::Sha1 => Sha1::digest(data).to_vec(),
+            DigestAlgorithm::Sha256 => Sha256::digest(data).to_vec(),
+            DigestAlgorithm::Sha384 => Sha384::digest(data).to_vec(),
+            DigestAlgorithm::Sha512 => Sha512::digest(data).to_vec(),
+        }
+    }
+
+    /// RSA PKCS#1 v1.5 서명 방식
+    fn pkcs1v15(&self) -> Pkcs1v15Sign {
+        match self {
+            DigestAlgorithm::Sha1 => Pkcs1v15Sign::new::<Sha1>(),
+            DigestAlgorithm::Sha256 => Pkcs1v15Sign::new::<Sha256>(),
+            DigestAlgorithm::Sha384 => Pkcs1v15Sign::new::<Sha384>(),
+            DigestAlgorithm::Sha512 => Pkcs1v15Sign::new::<Sha512>(),
+        }
+    }
+}
+
+/// 정규화 알고리즘 URI를 정규화 방식으로 변환
+fn c14n_mode(uri: &str) -> Option<C14nMode> {
+    let (exclusive, with_comments) = match uri {
+        C14N => (false, false),
+        C14N_WITH_COMMENTS => (false, true),
+        EXC_C14N => (true, false),
+        EXC_C14N_WITH_COMMENTS => (true, true),
+        _ => return None,
+    };
+    Some(C14nMode {
+        exclusive,
+        with_comments,
+    })
+}
+
+/// 서명 파트 여부 (`META-INF/` 안에서 이름에 `signature`가 들어간 XML)
+fn is_signature_part(name: &str) -> bool {
+    name.strip_prefix("META-INF/").is_some_and(|file_name| {
+        let file_name = file_name.to_lowercase();
+        file_name.contains("signature") && file_name.ends_with(".xml")
+    })
+}
+
+/// `Reference`의 `URI`를 패키지 파트 경로로 변환 (퍼센트 인코딩 해제)
+fn uri_to_part(uri: &str) -> String {
+    let uri = uri.trim_start_matches("./").trim_start_matches('/');
+    let bytes = uri.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    while index < bytes.len() {
+        let escaped = (bytes[index] == b'%')
+            .then(|| bytes.get(index + 1..index + 3))
+            .flatten()
+            .and_then(|hex| std::str::from_utf8(hex).ok())
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match escaped {
+            Some(byte) => {
+                decoded.push(byte);
+                index += 3;
+            }
+            None => {
+                decoded.push(bytes[index]);
+                index += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+/// 패키지 파트 경로를 `Reference`의 `URI`로 변환 (퍼센트 인코딩)
+fn part_to_uri(part: &str) -> String {
+    let mut uri = String::with_capacity(part.len());
+    for byte in part.bytes() {
+        if byte.is_ascii_alphanumeric() || b"-._~/".contains(&byte) {
+            uri.push(byte as char);
+        } else {
+            uri.push_str(&format!("%{:02X}", byte));
+        }
+    }
+    uri
+}
@@ -0,0 +1,271 @@
+//! XML 전자서명 모델 (`META-INF/signatures.xml`)
+//!
+//! W3C XML Signature의 `ds:Signature` 요소 중 HWPX 서명에 쓰이는 부분을 다룹니다.
+//! KS X 6101:2024 16장 전자서명.
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use serde::{Deserialize, Serialize};
+
+/// 서명 파트 루트 (`document-signatures` 요소)
+///
+/// ODF 방식 서명 파일은 여러 `Signature`를 이 요소로 감쌉니다.
+/// 한글은 `ds:Signature` 하나를 루트로 쓰는데, 읽을 때는 두 형태를 모두 받아들입니다.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename = "dsig:document-signatures")]
+pub struct DocumentSignatures {
+    /// 서명 목록 (`Signature`)
+    #[serde(rename(serialize = "ds:Signature", deserialize = "Signature"), default)]
+    pub signatures: Vec<Signature>,
+}
+
+/// 전자서명 (`Signature` 요소)
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename = "ds:Signature")]
+pub struct Signature {
+    /// 서명 아이디 (`Id`)
+    #[serde(rename = "@Id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+
+    /// 서명 대상 정보 (`SignedInfo`)
+    #[serde(rename(serialize = "ds:SignedInfo", deserialize = "SignedInfo"))]
+    pub signed_info: SignedInfo,
+
+    /// 서명 값 (`SignatureValue`, base64)
+    #[serde(rename(serialize = "ds:SignatureValue", deserialize = "SignatureValue"))]
+    pub signature_value: SignatureValue,
+
+    /// 키 정보 (`KeyInfo`)
+    #[serde(
+        rename(serialize = "ds:KeyInfo", deserialize = "KeyInfo"),
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub key_info: Option<KeyInfo>,
+
+    /// 서명 대상 객체 (`Object`)
+    #[serde(
+        rename(serialize = "ds:Object", deserialize = "Object"),
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub objects: Vec<Object>,
+}
+
+/// 서명 대상 정보 (`SignedInfo` 요소)
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct SignedInfo {
+    /// `SignedInfo`에 적용할 정규화 알고리즘 (`CanonicalizationMethod`)
+    #[serde(rename(
+        serialize = "ds:CanonicalizationMethod",
+        deserialize = "CanonicalizationMethod"
+    ))]
+    pub canonicalization_method: AlgorithmIdentifier,
+
+    /// 서명 알고리즘 (`SignatureMethod`)
+    #[serde(rename(serialize = "ds:SignatureMethod", deserialize = "SignatureMethod"))]
+    pub signature_method: AlgorithmIdentifier,
+
+    /// 서명 대상 참조 목록 (`Reference`)
+    #[serde(rename(serialize = "ds:Reference", deserialize = "Reference"), default)]
+    pub references: Vec<Reference>,
+}
+
+/// 알고리즘 지정 요소 (`CanonicalizationMethod`, `SignatureMethod`, `DigestMethod`, `Transform`)
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct AlgorithmIdentifier {
+    /// 알고리즘 URI (`Algorithm`)
+    #[serde(rename = "@Algorithm")]
+    pub algorithm: String,
+}
+
+/// 서명 대상 참조 (`Reference` 요소)
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct Reference {
+    /// 참조 아이디 (`Id`)
+    #[serde(rename = "@Id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+
+    /// 대상 위치 (`URI`, 패키지 파트 경로 또는 `#아이디`)
+    #[serde(rename = "@URI", skip_serializing_if = "Option::is_none")]
+    pub uri: Option<String>,
+
+    /// 대상 종류 (`Type`)
+    #[serde(rename = "@Type", skip_serializing_if = "Option::is_none")]
+    pub reference_type: Option<String>,
+
+    /// 변환 목록 (`Transforms`)
+    #[serde(
+        rename(serialize = "ds:Transforms", deserialize = "Transforms"),
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub transforms: Option<Transforms>,
+
+    /// 해시 알고리즘 (`DigestMethod`)
+    #[serde(rename(serialize = "ds:DigestMethod", deserialize = "DigestMethod"))]
+    pub digest_method: AlgorithmIdentifier,
+
+    /// 해시 값 (`DigestValue`, base64)
+    #[serde(rename(serialize = "ds:DigestValue", deserialize = "DigestValue"))]
+    pub digest_value: String,
+}
+
+/// 변환 목록 (`Transforms` 요소)
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct Transforms {
+    /// 변환 (`Transform`)
+    #[serde(rename(serialize = "ds:Transform", deserialize = "Transform"), default)]
+    pub transforms: Vec<AlgorithmIdentifier>,
+}
+
+/// 서명 값 (`SignatureValue` 요소)
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct SignatureValue {
+    /// 서명 값 아이디 (`Id`)
+    #[serde(rename = "@Id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+
+    /// base64 서명 값
+    #[serde(rename = "$text", default)]
+    pub value: String,
+}
+
+/// 키 정보 (`KeyInfo` 요소)
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct KeyInfo {
+    /// 공개키 값 (`KeyValue`)
+    #[serde(
+        rename(serialize = "ds:KeyValue", deserialize = "KeyValue"),
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub key_value: Option<KeyValue>,
+
+    /// 인증서 정보 (`X509Data`)
+    #[serde(
+        rename(serialize = "ds:X509Data", deserialize = "X509Data"),
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub x509_data: Vec<X509Data>,
+}
+
+/// 공개키 값 (`KeyValue` 요소)
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct KeyValue {
+    /// RSA 공개키 (`RSAKeyValue`)
+    #[serde(
+        rename(serialize = "ds:RSAKeyValue", deserialize = "RSAKeyValue"),
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub rsa: Option<RsaKeyValue>,
+}
+
+/// RSA 공개키 (`RSAKeyValue` 요소)
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct RsaKeyValue {
+    /// 모듈러스 (`Modulus`, base64 빅엔디언)
+    #[serde(rename(serialize = "ds:Modulus", deserialize = "Modulus"))]
+    pub modulus: String,
+
+    /// 공개 지수 (`Exponent`, base64 빅엔디언)
+    #[serde(rename(serialize = "ds:Exponent", deserialize = "Exponent"))]
+    pub exponent: String,
+}
+
+/// 인증서 정보 (`X509Data` 요소)
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct X509Data {
+    /// 발급자와 일련번호 (`X509IssuerSerial`)
+    #[serde(
+        rename(serialize = "ds:X509IssuerSerial", deserialize = "X509IssuerSerial"),
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub issuer_serial: Option<X509IssuerSerial>,
+
+    /// 주체 이름 (`X509SubjectName`)
+    #[serde(
+        rename(serialize = "ds:X509SubjectName", deserialize = "X509SubjectName"),
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub subject_name: Option<String>,
+
+    /// DER 인증서 (`X509Certificate`, base64)
+    #[serde(
+        rename(serialize = "ds:X509Certificate", deserialize = "X509Certificate"),
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub certificates: Vec<String>,
+}
+
+/// 인증서 발급자와 일련번호 (`X509IssuerSerial` 요소)
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct X509IssuerSerial {
+    /// 발급자 이름 (`X509IssuerName`)
+    #[serde(rename(serialize = "ds:X509IssuerName", deserialize = "X509IssuerName"))]
+    pub issuer_name: String,
+
+    /// 일련번호 (`X509SerialNumber`, 10진수)
+    #[serde(rename(serialize = "ds:X509SerialNumber", deserialize = "X509SerialNumber"))]
+    pub serial_number: String,
+}
+
+/// 서명 대상 객체 (`Object` 요소)
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct Object {
+    /// 객체 아이디 (`Id`)
+    #[serde(rename = "@Id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+
+    /// 참조 목록 (`Manifest`)
+    #[serde(
+        rename(serialize = "ds:Manifest", deserialize = "Manifest"),
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub manifests: Vec<SignatureManifest>,
+}
+
+/// 참조 목록 (`Manifest` 요소)
+///
+/// 패키지 파트마다의 해시를 담고, `SignedInfo`는 이 목록 전체를 참조합니다.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct SignatureManifest {
+    /// 목록 아이디 (`Id`)
+    #[serde(rename = "@Id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+
+    /// 참조 (`Reference`)
+    #[serde(rename(serialize = "ds:Reference", deserialize = "Reference"), default)]
+    pub references: Vec<Reference>,
+}
+
+impl Signature {
+    /// `KeyInfo`에 담긴 DER 인증서 목록
+    ///
+    /// base64가 잘못된 항목은 건너뜁니다. 인증서를 신뢰할지는 호출 측에서 판단해야 합니다.
+    pub fn certificates(&self) -> Vec<Vec<u8>> {
+        self.key_info
+            .iter()
+            .flat_map(|key_info| &key_info.x509_data)
+            .flat_map(|data| &data.certificates)
+            .filter_map(|certificate| decode_base64(certificate))
+            .collect()
+    }
+
+    /// `Object/Manifest` 안의 참조 목록
+    ///
+    /// `SignedInfo`가 참조하지 않는 `Manifest`도 포함하므로 신뢰 여부는
+    /// [`ReferenceReport::signed`](super::ReferenceReport::signed)로 확인해야 합니다.
+    pub fn manifest_references(&self) -> impl Iterator<Item = &Reference> {
+        self.objects
+            .iter()
+            .flat_map(|object| &object.manifests)
+            .flat_map(|manifest| &manifest.references)
+    }
+}
+
+/// 공백(줄바꿈 포함)을 무시하고 base64 디코딩
+pub(crate) fn decode_base64(value: &str) -> Option<Vec<u8>> {
+    let compact: String = value.split_whitespace().collect();
+    BASE64.decode(compact).ok()
+}
@@ -0,0 +1,232 @@
+//! 전자서명 생성
+
+use std::io::{Cursor, Write};
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use rsa::RsaPrivateKey;
+use rsa::pkcs1::DecodeRsaPrivateKey;
+use rsa::pkcs8::DecodePrivateKey;
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipArchive, ZipWriter};
+
+use super::c14n::{C14n, C14nMode, Subset, canonicalize};
+use super::key::{key_value, pem_to_der, public_key_from_certificate};
+use super::model::{
+    AlgorithmIdentifier, KeyInfo, KeyValue, Object, Reference, Signature, SignatureManifest,
+    SignedInfo, Transforms, X509Data,
+};
+use super::{C14N, DSIG_NAMESPACE, DigestAlgorithm, MANIFEST_TYPE, is_signature_part, part_to_uri};
+use crate::error::{Error, Result};
+use crate::package::{PackageReader, SIGNATURES_PATH, media_type_from_path, to_xml_part};
+
+/// 서명 아이디
+const SIGNATURE_ID: &str = "HwpxSignature";
+
+/// 파트 참조 목록(`ds:Manifest`) 아이디
+const MANIFEST_ID: &str = "HwpxSignatureManifest";
+
+/// 서명 설정
+///
+/// 인증서를 주면 `ds:X509Data`에, 없으면 공개키 값을 `ds:KeyValue`에 기록합니다.
+#[derive(Debug, Clone)]
+pub struct SigningOptions {
+    /// 서명에 쓸 RSA 개인키
+    pub private_key: RsaPrivateKey,
+    /// 개인키에 대응하는 DER X.509 인증서
+    pub certificate: Option<Vec<u8>>,
+    /// 해시 알고리즘 (서명 알고리즘은 RSA와 이 해시의 조합)
+    pub digest_algorithm: DigestAlgorithm,
+}
+
+impl SigningOptions {
+    /// 기본 설정(RSA-SHA256, 인증서 없음)으로 서명 옵션 생성
+    pub fn new(private_key: RsaPrivateKey) -> Self {
+        Self {
+            private_key,
+            certificate: None,
+            digest_algorithm: DigestAlgorithm::default(),
+        }
+    }
+
+    /// PEM 개인키(`PRIVATE KEY` 또는 `RSA PRIVATE KEY`)로 서명 옵션 생성
+    pub fn from_pkcs8_pem(pem: &str) -> Result<Self> {
+        RsaPrivateKey::from_pkcs8_pem(pem)
+            .or_else(|_| RsaPrivateKey::from_pkcs1_pem(pem))
+            .map(Self::new)
+            .map_err(|err| Error::InvalidKey(err.to_string()))
+    }
+
+    /// DER PKCS#8 개인키로 서명 옵션 생성
+    pub fn from_pkcs8_der(der: &[u8]) -> Result<Self> {
+        RsaPrivateKey::from_pkcs8_der(der)
+            .map(Self::new)
+            .map_err(|err| Error::InvalidKey(err.to_string()))
+    }
+
+    /// DER X.509 인증서 지정
+    pub fn with_certificate(mut self, certificate: Vec<u8>) -> Self {
+        self.certificate = Some(certificate);
+        self
+    }
+
+    /// PEM X.509 인증서 지정
+    pub fn with_certificate_pem(self, pem: &str) -> Result<Self> {
+        let certificate = pem_to_der(pem, "CERTIFICATE")
+            .ok_or_else(|| Error::InvalidKey("invalid PEM certificate".to_string()))?;
+        Ok(self.with_certificate(certificate))
+    }
+
+    /// 해시 알고리즘 지정
+    pub const fn with_digest_algorithm(mut self, digest_algorithm: DigestAlgorithm) -> Self {
+        self.digest_algorithm = digest_algorithm;
+        self
+    }
+}
+
+/// .hwpx 바이트에 서명해 새 패키지 반환
+///
+/// 서명 파트를 뺀 모든 파트를 `ds:Manifest`에 참조로 담고(XML 파트는 정규화 후 해시),
+/// `META-INF/signatures.xml`을 기록합니다. 기존 서명 파트는 교체됩니다.
+/// 파트는 다시 압축하지 않고 그대로 복사하므로 암호화된 패키지에도 서명할 수 있습니다.
+pub fn sign(data: &[u8], options: &SigningOptions) -> Result<Vec<u8>> {
+    if let Some(certificate) = &options.certificate {
+        let matches_key = public_key_from_certificate(certificate)
+            .is_some_and(|public_key| public_key == options.private_key.to_public_key());
+        if !matches_key {
+            return Err(Error::InvalidKey(
+                "certificate does not match the private key".to_string(),
+            ));
+        }
+    }
+
+    let mut package = PackageReader::new(Cursor::new(data))?;
+    let digest = options.digest_algorithm;
+    let mut references = Vec::new();
+    for name in package.names() {
+        if is_signature_part(&name) {
+            continue;
+        }
+        let part = package.read(&name)?;
+        references.push(part_reference(&name, &part, digest));
+    }
+
+    let mut signature = Signature {
+        id: Some(SIGNATURE_ID.to_string()),
+        signed_info: SignedInfo {
+            canonicalization_method: algorithm(C14N),
+            signature_method: algorithm(digest.rsa_signature_uri()),
+            references: vec![Reference {
+                uri: Some(format!("#{}", MANIFEST_ID)),
+                reference_type: Some(MANIFEST_TYPE.to_string()),
+                digest_method: algorithm(digest.digest_uri()),
+                ..Reference::default()
+            }],
+        },
+        key_info: Some(key_info(options)),
+        objects: vec![Object {
+            id: None,
+            manifests: vec![SignatureManifest {
+                id: Some(MANIFEST_ID.to_string()),
+                references,
+            }],
+        }],
+        ..Signature::default()
+    };
+
+    // 1. 파트 참조 목록의 해시, 2. SignedInfo 서명 순으로 채움
+    let xml = signature_xml(&signature)?;
+    let manifest = canonicalize_own(&xml, Subset::Id(MANIFEST_ID))?;
+    signature.signed_info.references[0].digest_value = BASE64.encode(digest.digest(&manifest));
+
+    let xml = signature_xml(&signature)?;
+    let signed_info = canonicalize_own(
+        &xml,
+        Subset::Signature {
+            local: "SignedInfo",
+            index: 0,
+        },
+    )?;
+    let value = options
+        .private_key
+        .sign(digest.pkcs1v15(), &digest.digest(&signed_info))
+        .map_err(|err| Error::Signing(err.to_string()))?;
+    signature.signature_value.value = BASE64.encode(value);
+    let xml = signature_xml(&signature)?;
+
+    let mut archive = ZipArchive::new(Cursor::new(data))?;
+    let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+    for index in 0..archive.len() {
+        let file = archive.by_index_raw(index)?;
+        if is_signature_part(file.name()) {
+            continue;
+        }
+        zip.raw_copy_file(file)?;
+    }
+    let deflated = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+    zip.start_file(SIGNATURES_PATH, deflated)?;
+    zip.write_all(xml.as_bytes())?;
+    Ok(zip.finish()?.into_inner())
+}
+
+fn algorithm(uri: &str) -> AlgorithmIdentifier {
+    AlgorithmIdentifier {
+        algorithm: uri.to_string(),
+    }
+}
+
+/// 파트 하나의 참조
+///
+/// XML 파트는 Canonical XML로 정규화해 해시하고, 정규화할 수 없는 파트는 그대로 해시합니다.
+fn part_reference(name: &str, data: &[u8], digest: DigestAlgorithm) -> Reference {
+    let canonical = (media_type_from_path(name) == "application/xml")
+        .then(|| std::str::from_utf8(data).ok())
+        .flatten()
+        .and_then(|text| {
+            let text = text.strip_prefix('\u{FEFF}').unwrap_or(text);
+            canonicalize(text, C14n::document(C14nMode::default())).ok()
+        });
+
+    let (transforms, digest_value) = match canonical {
+        Some(canonical) => (
+            Some(Transforms {
+                transforms: vec![algorithm(C14N)],
+            }),
+            digest.digest(&canonical),
+        ),
+        None => (None, digest.digest(data)),
+    };
+    Reference {
+        uri: Some(part_to_uri(name)),
+        transforms,
+        digest_method: algorithm(digest.digest_uri()),
+        digest_value: BASE64.encode(digest_value),
+        ..Reference::default()
+    }
+}
+
+fn key_info(options: &SigningOptions) -> KeyInfo {
+    match &options.certificate {
+        Some(certificate) => KeyInfo {
+            key_value: None,
+            x509_data: vec![X509Data {
+                certificates: vec![BASE64.encode(certificate)],
+                ..X509Data::default()
+            }],
+        },
+        None => KeyInfo {
+            key_value: Some(KeyValue {
+                rsa: Some(key_value(&options.private_key.to_public_key())),
+            }),
+            x509_data: Vec::new(),
+        },
+    }
+}
+
+fn signature_xml(signature: &Signature) -> Result<String> {
+    to_xml_part(SIGNATURES_PATH, signature, &[("ds", DSIG_NAMESPACE)])
+}
+
+fn canonicalize_own(xml: &str, subset: Subset<'_>) -> Result<Vec<u8>> {
+    canonicalize(xml, C14n::subset(C14nMode::default(), subset)).map_err(Error::Signing)
+}
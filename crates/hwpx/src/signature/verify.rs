@@ -0,0 +1,405 @@
+//! 전자서명 검증
+
+use std::io::{Cursor, Read, Seek};
+
+use quick_xml::Reader;
+use quick_xml::events::Event;
+use rsa::RsaPublicKey;
+
+use super::c14n::{C14n, C14nMode, Subset, canonicalize};
+use super::key::{public_key_from_certificate, public_key_from_key_value};
+use super::model::{DocumentSignatures, Reference, Signature, decode_base64};
+use super::{DigestAlgorithm, ENVELOPED_SIGNATURE, c14n_mode, is_signature_part, uri_to_part};
+use crate::error::{Error, Result};
+use crate::package::PackageReader;
+
+/// 참조 하나의 검증 결과
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReferenceStatus {
+    /// 해시가 일치함
+    Valid,
+    /// 해시가 다름 (서명 후 내용이 바뀜)
+    Tampered,
+    /// 참조한 파트가 패키지에 없음
+    Missing,
+    /// 알고리즘이나 변환을 지원하지 않아 확인할 수 없음 (사유)
+    Unsupported(String),
+}
+
+/// 서명 값의 검증 결과
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SignatureStatus {
+    /// 공개키로 `SignedInfo` 서명이 확인됨
+    Valid,
+    /// 서명 값이 `SignedInfo`와 맞지 않음
+    Invalid,
+    /// 알고리즘을 지원하지 않거나 공개키를 찾지 못해 확인할 수 없음 (사유)
+    Unsupported(String),
+}
+
+/// 참조 검증 결과
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReferenceReport {
+    /// 참조 대상 (`URI`, 없으면 빈 문자열)
+    pub uri: String,
+    /// `ds:Object/ds:Manifest` 안의 참조인지 여부 (아니면 `ds:SignedInfo`의 참조)
+    pub in_manifest: bool,
+    /// 서명 값이 이 참조를 보증하는지 여부
+    ///
+    /// `SignedInfo` 참조는 항상 참입니다. `Manifest` 참조는 그 `Manifest`(또는 감싼 `Object`)를
+    /// 가리키는 `SignedInfo` 참조가 유효할 때만 참이고, 아니면 누구나 끼워 넣을 수 있는 목록입니다.
+    pub signed: bool,
+    /// 검증 결과
+    pub status: ReferenceStatus,
+}
+
+/// 서명 하나의 검증 결과
+#[derive(Debug, Clone, PartialEq)]
+pub struct SignatureReport {
+    /// 서명 파트 경로
+    pub part: String,
+    /// 서명 모델
+    pub signature: Signature,
+    /// 서명 값 검증 결과
+    pub signature_value: SignatureStatus,
+    /// 참조별 검증 결과 (`SignedInfo` 참조 다음에 `Manifest` 참조)
+    pub references: Vec<ReferenceReport>,
+    /// 어떤 참조에도 포함되지 않은 패키지 파트 (서명 파트 제외)
+    pub unsigned_parts: Vec<String>,
+}
+
+impl SignatureReport {
+    /// 서명 값과 모든 참조가 유효하고 모든 참조가 서명으로 보증되는지 여부
+    ///
+    /// 서명되지 않은 파트가 있는지는 따지지 않으므로 필요하면
+    /// [`SignatureReport::unsigned_parts`]를 따로 확인해야 합니다.
+    pub fn is_valid(&self) -> bool {
+        self.signature_value == SignatureStatus::Valid
+            && !self.references.is_empty()
+            && self.references.iter().all(ReferenceReport::is_valid)
+    }
+
+    /// 유효하지 않거나 서명으로 보증되지 않는 참조 목록
+    pub fn invalid_references(&self) -> impl Iterator<Item = &ReferenceReport> {
+        self.references
+            .iter()
+            .filter(|reference| !reference.is_valid())
+    }
+}
+
+impl ReferenceReport {
+    /// 해시가 일치하고 서명으로 보증되는 참조인지 여부
+    pub fn is_valid(&self) -> bool {
+        self.signed && self.status == ReferenceStatus::Valid
+    }
+}
+
+/// .hwpx 바이트의 모든 서명 검증
+///
+/// 서명 파트가 없으면 빈 목록을 반환합니다.
+pub fn verify(data: &[u8]) -> Result<Vec<SignatureReport>> {
+    verify_reader(Cursor::new(data))
+}
+
+/// .hwpx 스트림의 모든 서명 검증
+pub fn verify_reader<R: Read + Seek>(reader: R) -> Result<Vec<SignatureReport>> {
+    Verifier::new(reader, None)?.run()
+}
+
+/// 주어진 공개키로 .hwpx 바이트의 모든 서명 검증
+///
+/// 서명 파트에 담긴 키 대신 호출 측이 신뢰하는 공개키로 서명 값을 확인합니다.
+pub fn verify_with_key(data: &[u8], public_key: &RsaPublicKey) -> Result<Vec<SignatureReport>> {
+    Verifier::new(Cursor::new(data), Some(public_key))?.run()
+}
+
+struct Verifier<'a, R> {
+    package: PackageReader<R>,
+    public_key: Option<&'a RsaPublicKey>,
+}
+
+impl<'a, R: Read + Seek> Verifier<'a, R> {
+    fn new(reader: R, public_key: Option<&'a RsaPublicKey>) -> Result<Self> {
+        // 매니페스트를 읽지 않으므로 암호화된 파트도 저장된 그대로(암호문) 해시합니다.
+        Ok(Self {
+            package: PackageReader::new(reader)?,
+            public_key,
+        })
+    }
+
+    fn run(mut self) -> Result<Vec<SignatureReport>> {
+        let names = self.package.names();
+        let mut reports = Vec::new();
+
+        for part in names.iter().filter(|name| is_signature_part(name)) {
+            let xml = self.package.read_string(part)?;
+            for (index, signature) in parse_signatures(part, &xml)?.into_iter().enumerate() {
+                let mut references = Vec::new();
+                for reference in &signature.signed_info.references {
+                    references.push(self.check_reference(&xml, index, reference, false, true)?);
+                }
+
+                // 유효한 SignedInfo 참조가 가리키는 Manifest만 신뢰
+                let verified_ids: Vec<String> = references
+                    .iter()
+                    .filter(|reference| reference.status == ReferenceStatus::Valid)
+                    .filter_map(|reference| reference.uri.strip_prefix('#'))
+                    .map(str::to_string)
+                    .collect();
+                for object in &signature.objects {
+                    let object_signed = is_verified(&verified_ids, object.id.as_deref());
+                    for manifest in &object.manifests {
+                        let signed =
+                            object_signed || is_verified(&verified_ids, manifest.id.as_deref());
+                        for reference in &manifest.references {
+                            references
+                                .push(self.check_reference(&xml, index, reference, true, signed)?);
+                        }
+                    }
+                }
+
+                let unsigned_parts = names
+                    .iter()
+                    .filter(|name| !is_signature_part(name))
+                    .filter(|name| {
+                        !references.iter().any(|reference| {
+                            reference.signed && uri_to_part(&reference.uri) == **name
+                        })
+                    })
+                    .cloned()
+                    .collect();
+
+                reports.push(SignatureReport {
+                    part: part.clone(),
+                    signature_value: self.check_signature_value(&xml, index, &signature),
+                    signature,
+                    references,
+                    unsigned_parts,
+                });
+            }
+        }
+        Ok(reports)
+    }
+
+    fn check_reference(
+        &mut self,
+        xml: &str,
+        index: usize,
+        reference: &Reference,
+        in_manifest: bool,
+        signed: bool,
+    ) -> Result<ReferenceReport> {
+        let uri = reference.uri.clone().unwrap_or_default();
+        let status = self.reference_status(xml, index, &uri, reference)?;
+        Ok(ReferenceReport {
+            uri,
+            in_manifest,
+            signed,
+            status,
+        })
+    }
+
+    fn reference_status(
+        &mut self,
+        xml: &str,
+        index: usize,
+        uri: &str,
+        reference: &Reference,
+    ) -> Result<ReferenceStatus> {
+        let digest_method = &reference.digest_method.algorithm;
+        let Some(digest) = DigestAlgorithm::from_digest_uri(digest_method) else {
+            return Ok(ReferenceStatus::Unsupported(format!(
+                "digest method {}",
+                digest_method
+            )));
+        };
+
+        let mut mode = None;
+        let mut enveloped = false;
+        for transform in reference.transforms.iter().flat_map(|t| &t.transforms) {
+            if transform.algorithm == ENVELOPED_SIGNATURE {
+                enveloped = true;
+            } else if let Some(transform_mode) = c14n_mode(&transform.algorithm) {
+                mode = Some(transform_mode);
+            } else {
+                return Ok(ReferenceStatus::Unsupported(format!(
+                    "transform {}",
+                    transform.algorithm
+                )));
+            }
+        }
+
+        let octets = if uri.is_empty() || uri.starts_with('#') {
+            // 정규화는 아이디가 같은 첫 요소만 해시하므로, 같은 아이디를 가진 요소를 끼워 넣어
+            // 서명된 요소인 척하지 못하도록 아이디가 겹치면 확인하지 않음
+            if let Some(id) = uri.strip_prefix('#')
+                && id_count(xml, id) > 1
+            {
+                return Ok(ReferenceStatus::Unsupported(format!(
+                    "duplicate Id \"{}\"",
+                    id
+                )));
+            }
+            // 같은 문서 참조는 주석을 빼고 정규화 (XML Signature 4.4.3.3)
+            let subset = match uri.strip_prefix('#') {
+                Some(id) => Subset::Id(id),
+                None => Subset::Document,
+            };
+            let mut request = C14n::subset(
+                C14nMode {
+                    with_comments: false,
+                    ..mode.unwrap_or_default()
+                },
+                subset,
+            );
+            if enveloped {
+                request.exclude_signature = Some(index);
+            }
+            match canonicalize(xml, request) {
+                Ok(octets) => octets,
+                Err(reason) => return Ok(ReferenceStatus::Unsupported(reason)),
+            }
+        } else {
+            if enveloped {
+                return Ok(ReferenceStatus::Unsupported(
+                    "enveloped-signature transform on a package part".to_string(),
+                ));
+            }
+            let part = uri_to_part(uri);
+            if !self.package.contains(&part) {
+                return Ok(ReferenceStatus::Missing);
+            }
+            let data = self.package.read(&part)?;
+            match mode {
+                Some(mode) => {
+                    let Ok(text) = std::str::from_utf8(&data) else {
+                        return Ok(ReferenceStatus::Unsupported(format!(
+                            "{} is not UTF-8 XML",
+                            part
+                        )));
+                    };
+                    let text = text.strip_prefix('\u{FEFF}').unwrap_or(text);
+                    match canonicalize(text, C14n::document(mode)) {
+                        Ok(octets) => octets,
+                        Err(reason) => return Ok(ReferenceStatus::Unsupported(reason)),
+                    }
+                }
+                None => data,
+            }
+        };
+
+        Ok(match decode_base64(&reference.digest_value) {
+            Some(expected) if expected == digest.digest(&octets) => ReferenceStatus::Valid,
+            _ => ReferenceStatus::Tampered,
+        })
+    }
+
+    fn check_signature_value(
+        &self,
+        xml: &str,
+        index: usize,
+        signature: &Signature,
+    ) -> SignatureStatus {
+        let signed_info = &signature.signed_info;
+        let method = &signed_info.signature_method.algorithm;
+        let Some(digest) = DigestAlgorithm::from_rsa_signature_uri(method) else {
+            return SignatureStatus::Unsupported(format!("signature method {}", method));
+        };
+        let canonicalization = &signed_info.canonicalization_method.algorithm;
+        let Some(mode) = c14n_mode(canonicalization) else {
+            return SignatureStatus::Unsupported(format!(
+                "canonicalization method {}",
+                canonicalization
+            ));
+        };
+
+        let embedded_key = || {
+            let key_info = signature.key_info.as_ref()?;
+            key_info
+                .key_value
+                .as_ref()
+                .and_then(|key_value| key_value.rsa.as_ref())
+                .and_then(public_key_from_key_value)
+                .or_else(|| {
+                    signature
+                        .certificates()
+                        .iter()
+                        .find_map(|certificate| public_key_from_certificate(certificate))
+                })
+        };
+        let Some(public_key) = self.public_key.cloned().or_else(embedded_key) else {
+            return SignatureStatus::Unsupported("no RSA public key".to_string());
+        };
+
+        let subset = Subset::Signature {
+            local: "SignedInfo",
+            index,
+        };
+        let canonical = match canonicalize(xml, C14n::subset(mode, subset)) {
+            Ok(canonical) => canonical,
+            Err(reason) => return SignatureStatus::Unsupported(reason),
+        };
+        let Some(value) = decode_base64(&signature.signature_value.value) else {
+            return SignatureStatus::Invalid;
+        };
+
+        match public_key.verify(digest.pkcs1v15(), &digest.digest(&canonical), &value) {
+            Ok(()) => SignatureStatus::Valid,
+            Err(_) => SignatureStatus::Invalid,
+        }
+    }
+}
+
+/// 아이디가 유효한 `SignedInfo` 참조의 대상인지 여부
+fn is_verified(verified_ids: &[String], id: Option<&str>) -> bool {
+    id.is_some_and(|id| verified_ids.iter().any(|verified| verified == id))
+}
+
+/// `Id` 속성(정규화와 같이 `Id`, `ID`, `id`)이 `id`인 요소 수
+fn id_count(xml: &str, id: &str) -> usize {
+    let mut reader = Reader::from_str(xml);
+    let mut count = 0;
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(start) | Event::Empty(start)) => {
+                let matched = start.attributes().flatten().any(|attribute| {
+                    matches!(attribute.key.as_ref(), b"Id" | b"ID" | b"id")
+                        && attribute.unescape_value().is_ok_and(|value| value == id)
+                });
+                count += usize::from(matched);
+            }
+            Ok(Event::Eof) | Err(_) => return count,
+            _ => {}
+        }
+    }
+}
+
+/// 서명 파트에서 서명 목록 읽기
+///
+/// 루트가 `Signature`이면 서명 하나, 아니면 `document-signatures`로 읽습니다.
+pub(crate) fn parse_signatures(part: &str, xml: &str) -> Result<Vec<Signature>> {
+    let map_error = |source| Error::Deserialize {
+        part: part.to_string(),
+        source,
+    };
+    if root_local_name(xml).as_deref() == Some("Signature") {
+        let signature: Signature = crate::from_str(xml).map_err(map_error)?;
+        Ok(vec![signature])
+    } else {
+        let signatures: DocumentSignatures = crate::from_str(xml).map_err(map_error)?;
+        Ok(signatures.signatures)
+    }
+}
+
+fn root_local_name(xml: &str) -> Option<String> {
+    let mut reader = Reader::from_str(xml);
+    loop {
+        match reader.read_event().ok()? {
+            Event::Start(start) | Event::Empty(start) => {
+                return Some(String::from_utf8_lossy(start.local_name().as_ref()).into_owned());
+            }
+            Event::Eof => return None,
+            _ => {}
+        }
+    }
+}
@@ -174,9 +174,11 @@ mod layout_compatibility_tests {
 
     #[test]
     fn serialize_with_flags() {
-        let mut layout = LayoutCompatibility::default();
-        layout.apply_font_weight_to_bold = Some(());
-        layout.use_inner_underline = Some(());
+        let layout = LayoutCompatibility {
+            apply_font_weight_to_bold: Some(()),
+            use_inner_underline: Some(()),
+            ..Default::default()
+        };
 
         let xml = quick_xml::se::to_string(&layout).unwrap();
         insta::assert_snapshot!("layout_compatibility_with_flags_serialized", xml);
@@ -192,10 +194,12 @@ mod layout_compatibility_tests {
 
     #[test]
     fn roundtrip_with_flags() {
-        let mut original = LayoutCompatibility::default();
-        original.apply_font_weight_to_bold = Some(());
-        original.use_inner_underline = Some(());
-        original.do_not_apply_strikeout_with_underline = Some(());
+        let original = LayoutCompatibility {
+            apply_font_weight_to_bold: Some(()),
+            use_inner_underline: Some(()),
+            do_not_apply_strikeout_with_underline: Some(()),
+            ..Default::default()
+        };
 
         let xml = quick_xml::se::to_string(&original).unwrap();
         let parsed: LayoutCompatibility = quick_xml::de::from_str(&xml).unwrap();
@@ -250,8 +254,10 @@ mod compatible_document_tests {
 
     #[test]
     fn serialize_with_flags() {
-        let mut layout = LayoutCompatibility::default();
-        layout.apply_font_weight_to_bold = Some(());
+        let layout = LayoutCompatibility {
+            apply_font_weight_to_bold: Some(()),
+            ..Default::default()
+        };
 
         let doc = CompatibleDocument {
             target_program: TargetProgram::MsWord,
@@ -263,9 +269,11 @@ mod compatible_document_tests {
 
     #[test]
     fn roundtrip() {
-        let mut layout = LayoutCompatibility::default();
-        layout.apply_font_weight_to_bold = Some(());
-        layout.base_char_unit_on_east_asian = Some(());
+        let layout = LayoutCompatibility {
+            apply_font_weight_to_bold: Some(()),
+            base_char_unit_on_east_asian: Some(()),
+            ..Default::default()
+        };
 
         let original = CompatibleDocument {
             target_program: TargetProgram::Hwp201X,
@@ -307,11 +315,11 @@ mod fixture_tests {
         let start_with_ns = "<hh:compatibleDocument";
         let end_with_ns = "</hh:compatibleDocument>";
 
-        if let Some(start_idx) = header_content.find(start_with_ns) {
-            if let Some(end_idx) = header_content.find(end_with_ns) {
-                let end_pos = end_idx + end_with_ns.len();
-                return Some(header_content[start_idx..end_pos].to_string());
-            }
+        if let Some(start_idx) = header_content.find(start_with_ns)
+            && let Some(end_idx) = header_content.find(end_with_ns)
+        {
+            let end_pos = end_idx + end_with_ns.len();
+            return Some(header_content[start_idx..end_pos].to_string());
         }
         None
     }
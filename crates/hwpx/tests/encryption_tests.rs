@@ -0,0 +1,247 @@
+//! 암호화된 HWPX 패키지 읽기/쓰기 테스트
+
+use std::io::{Cursor, Read, Write};
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use hwpx::convert::{HwpxToIr, IrToHwpx};
+use hwpx::package::{
+    EncryptionAlgorithm, EncryptionOptions, FileManifest, MAX_ITERATION_COUNT, StartKeyHash,
+};
+use hwpx::{Document, Error};
+use zip::CompressionMethod;
+use zip::write::SimpleFileOptions;
+
+const VERSION_XML: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes" ?><hv:HCFVersion xmlns:hv="http://www.hancom.co.kr/hwpml/2011/version" tagetApplication="WORDPROCESSOR" major="5" minor="1" micro="1" buildNumber="0" os="1" xmlVersion="1.5" application="Hancom Office Hangul" appVersion="12, 0, 0, 3650 WIN32LEWindows_10"/>"#;
+
+fn sample_document(text: &str) -> Document {
+    let mut ir_doc = ir::Document::new();
+    let mut section = ir::Section::new();
+    section.add_paragraph(ir::Paragraph::with_text(text));
+    ir_doc.add_section(section);
+    let mut document = ir_doc.to_hwpx().unwrap().value;
+    document.add_binary_data(
+        "BinData/image1.png".to_string(),
+        vec![0x89, b'P', b'N', b'G'],
+    );
+    document
+}
+
+fn first_text(document: &Document) -> String {
+    document.to_ir().unwrap().value.sections[0].paragraphs[0].to_plain_text()
+}
+
+fn read_entry(bytes: &[u8], name: &str) -> (CompressionMethod, Vec<u8>) {
+    let mut archive = zip::ZipArchive::new(Cursor::new(bytes)).unwrap();
+    let mut file = archive.by_name(name).unwrap();
+    let mut data = Vec::new();
+    file.read_to_end(&mut data).unwrap();
+    (file.compression(), data)
+}
+
+fn read_manifest(bytes: &[u8]) -> FileManifest {
+    let (_, data) = read_entry(bytes, "META-INF/manifest.xml");
+    hwpx::from_str(&String::from_utf8(data).unwrap()).unwrap()
+}
+
+mod roundtrip_tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip_with_password() {
+        let original = sample_document("기밀 문서");
+        let bytes = original.to_bytes_with_password("암호123").unwrap();
+
+        let parsed = Document::from_bytes_with_password(&bytes, "암호123").unwrap();
+        assert_eq!(parsed.header, original.header);
+        assert_eq!(parsed.sections, original.sections);
+        assert_eq!(first_text(&parsed), "기밀 문서");
+        assert_eq!(
+            parsed.binary_data.get("BinData/image1.png"),
+            Some(&vec![0x89, b'P', b'N', b'G'])
+        );
+    }
+
+    #[test]
+    fn roundtrip_with_aes128_and_sha1() {
+        let options = EncryptionOptions::new("password")
+            .with_algorithm(EncryptionAlgorithm::Aes128Cbc)
+            .with_start_key_hash(StartKeyHash::Sha1)
+            .with_iteration_count(100);
+        let bytes = sample_document("AES-128")
+            .to_bytes_encrypted(&options)
+            .unwrap();
+
+        let manifest = read_manifest(&bytes);
+        let encryption = manifest
+            .entry("Contents/section0.xml")
+            .and_then(|entry| entry.encryption_data.as_ref())
+            .unwrap();
+        assert_eq!(encryption.key_derivation.key_size, Some(16));
+        assert_eq!(encryption.key_derivation.iteration_count, 100);
+
+        let parsed = Document::from_bytes_with_password(&bytes, "password").unwrap();
+        assert_eq!(first_text(&parsed), "AES-128");
+    }
+
+    #[test]
+    fn password_on_plain_document_is_ignored() {
+        let bytes = sample_document("평문").to_bytes().unwrap();
+        let parsed = Document::from_bytes_with_password(&bytes, "unused").unwrap();
+        assert_eq!(first_text(&parsed), "평문");
+    }
+}
+
+mod write_tests {
+    use super::*;
+
+    #[test]
+    fn manifest_declares_encrypted_parts() {
+        let bytes = sample_document("본문")
+            .to_bytes_with_password("pw")
+            .unwrap();
+        let manifest = read_manifest(&bytes);
+
+        for part in [
+            "Contents/header.xml",
+            "Contents/section0.xml",
+            "BinData/image1.png",
+        ] {
+            let entry = manifest.entry(part).unwrap();
+            assert!(entry.encryption_data.is_some(), "{} 암호화 정보 누락", part);
+            assert!(entry.size.is_some());
+        }
+        for part in [
+            "version.xml",
+            "Contents/content.hpf",
+            "META-INF/container.xml",
+        ] {
+            assert!(
+                manifest.entry(part).is_none(),
+                "{}는 암호화하지 않아야 함",
+                part
+            );
+        }
+    }
+
+    #[test]
+    fn encrypted_parts_are_stored_and_unreadable() {
+        let bytes = sample_document("본문")
+            .to_bytes_with_password("pw")
+            .unwrap();
+
+        let (method, data) = read_entry(&bytes, "Contents/section0.xml");
+        assert_eq!(method, CompressionMethod::Stored);
+        assert_eq!(data.len() % 16, 0);
+        assert!(!data.starts_with(b"<?xml"));
+
+        let (method, data) = read_entry(&bytes, "mimetype");
+        assert_eq!(method, CompressionMethod::Stored);
+        assert_eq!(data, b"application/hwp+zip");
+    }
+}
+
+mod read_tests {
+    use super::*;
+
+    /// 독립 구현(Python `cryptography`)으로 만든 AES-256-CBC 구역 파트
+    ///
+    /// 암호 "비밀번호", SHA-256 시작 키, PBKDF2 1024회, raw Deflate 후 W3C 패딩
+    const SECTION_CIPHERTEXT: &str = "1oK8XDQitRDKTT4Fx7R9Gi2M4Cj72lg8AUlz/aq65ubM3R00OyYJkRoTzph/SHRMHb2QZ8Wi4Ai+POXnN0iVPDKQfVDsVS5ltHoSwk3Jflmnpehvv0GMoKUSPo7TYHOQH+KyCNaR8EWwOu65MUBmCuoFebMaWK9p4kjtFLoLi55R/jQFVyOQzNA0S71kh8+JKsg/Fk6TBO9Nls+/UMzQuA==";
+
+    const MANIFEST_XML: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes" ?><odf:manifest xmlns:odf="urn:oasis:names:tc:opendocument:xmlns:manifest:1.0"><odf:file-entry odf:full-path="Contents/section0.xml" odf:media-type="application/xml" odf:size="237"><odf:encryption-data odf:checksum-type="urn:oasis:names:tc:opendocument:xmlns:manifest:1.0#sha256-1k" odf:checksum="gzVrx9ABbMVSffejyG4KRJc05nqOU2HDu3Y90CLbAdM="><odf:algorithm odf:algorithm-name="http://www.w3.org/2001/04/xmlenc#aes256-cbc" odf:initialisation-vector="EBESExQVFhcYGRobHB0eHw=="/><odf:key-derivation odf:key-derivation-name="urn:oasis:names:tc:opendocument:xmlns:manifest:1.0#pbkdf2" odf:key-size="32" odf:iteration-count="1024" odf:salt="AAECAwQFBgcICQoLDA0ODw=="/><odf:start-key-generation odf:start-key-generation-name="http://www.w3.org/2000/09/xmldsig#sha256" odf:key-size="32"/></odf:encryption-data></odf:file-entry></odf:manifest>"#;
+
+    fn encrypted_package() -> Vec<u8> {
+        let header = hwpx::to_string(&sample_document("").header).unwrap();
+        let section = BASE64.decode(SECTION_CIPHERTEXT).unwrap();
+
+        let mut zip = zip::ZipWriter::new(Cursor::new(Vec::new()));
+        let stored = SimpleFileOptions::default().compression_method(CompressionMethod::Stored);
+        for (name, data) in [
+            ("mimetype", "application/hwp+zip".as_bytes()),
+            ("version.xml", VERSION_XML.as_bytes()),
+            ("META-INF/manifest.xml", MANIFEST_XML.as_bytes()),
+            ("Contents/header.xml", header.as_bytes()),
+            ("Contents/section0.xml", &section),
+        ] {
+            zip.start_file(name, stored).unwrap();
+            zip.write_all(data).unwrap();
+        }
+        zip.finish().unwrap().into_inner()
+    }
+
+    #[test]
+    fn decrypt_externally_encrypted_part() {
+        let document =
+            Document::from_bytes_with_password(&encrypted_package(), "비밀번호").unwrap();
+        assert_eq!(first_text(&document), "암호 문서");
+    }
+
+    #[test]
+    fn missing_password_is_reported() {
+        assert!(matches!(
+            Document::from_bytes(&encrypted_package()),
+            Err(Error::PasswordRequired)
+        ));
+    }
+
+    #[test]
+    fn wrong_password_is_reported() {
+        match Document::from_bytes_with_password(&encrypted_package(), "틀린 암호") {
+            Err(Error::InvalidPassword { part }) => assert_eq!(part, "Contents/section0.xml"),
+            other => panic!("InvalidPassword 오류 기대: {:?}", other.map(|_| ())),
+        }
+    }
+
+    /// 매니페스트만 바꾼 패키지
+    fn with_manifest(manifest: &str) -> Vec<u8> {
+        let package = encrypted_package();
+        let mut archive = zip::ZipArchive::new(Cursor::new(package)).unwrap();
+
+        let mut zip = zip::ZipWriter::new(Cursor::new(Vec::new()));
+        for index in 0..archive.len() {
+            let mut file = archive.by_index(index).unwrap();
+            let name = file.name().to_string();
+            let mut data = Vec::new();
+            file.read_to_end(&mut data).unwrap();
+            if name == "META-INF/manifest.xml" {
+                data = manifest.as_bytes().to_vec();
+            }
+            zip.start_file(name, SimpleFileOptions::default()).unwrap();
+            zip.write_all(&data).unwrap();
+        }
+        zip.finish().unwrap().into_inner()
+    }
+
+    #[test]
+    fn mismatched_size_is_reported() {
+        let bytes = with_manifest(&MANIFEST_XML.replace(r#"odf:size="237""#, r#"odf:size="236""#));
+        match Document::from_bytes_with_password(&bytes, "비밀번호") {
+            Err(Error::Decrypt { part }) => assert_eq!(part, "Contents/section0.xml"),
+            other => panic!("Decrypt 오류 기대: {:?}", other.map(|_| ())),
+        }
+    }
+
+    #[test]
+    fn excessive_iteration_count_is_rejected() {
+        let bytes = with_manifest(&MANIFEST_XML.replace(
+            r#"odf:iteration-count="1024""#,
+            &format!(r#"odf:iteration-count="{}""#, MAX_ITERATION_COUNT + 1),
+        ));
+        match Document::from_bytes_with_password(&bytes, "비밀번호") {
+            Err(Error::InvalidEncryptionData { part }) => {
+                assert_eq!(part, "Contents/section0.xml")
+            }
+            other => panic!("InvalidEncryptionData 오류 기대: {:?}", other.map(|_| ())),
+        }
+    }
+
+    #[test]
+    fn unsupported_algorithm_is_reported() {
+        let bytes = with_manifest(&MANIFEST_XML.replace("aes256-cbc", "blowfish"));
+        assert!(matches!(
+            Document::from_bytes_with_password(&bytes, "비밀번호"),
+            Err(Error::UnsupportedEncryption(name)) if name.ends_with("blowfish")
+        ));
+    }
+}
@@ -0,0 +1,194 @@
+//! 자원 제한 테스트
+
+use std::io::{Cursor, Read, Write};
+
+use hwpx::convert::IrToHwpx;
+use hwpx::package::PREVIEW_TEXT_PATH;
+use hwpx::{Document, Error, LimitKind, Limits, ReadOptions};
+use zip::write::SimpleFileOptions;
+
+const PNG: &[u8] = &[0x89, 0x50, 0x4E, 0x47, 0x0D, 0x0A, 0x1A, 0x0A, 0x00];
+
+fn converted(paragraphs: Vec<ir::Paragraph>) -> Document {
+    let mut ir_doc = ir::Document::new();
+    let mut section = ir::Section::new();
+    for paragraph in paragraphs {
+        section.add_paragraph(paragraph);
+    }
+    ir_doc.add_section(section);
+    ir_doc.to_hwpx().unwrap().value
+}
+
+fn table_paragraph() -> ir::Paragraph {
+    let mut table = ir::table::Table::new(1, 1);
+    let mut row = ir::table::TableRow::new();
+    row.cells.push(ir::table::TableCell::with_text(0, 0, "셀"));
+    table.rows.push(row);
+
+    let mut paragraph = ir::Paragraph::new();
+    paragraph.add_run(ir::paragraph::Run::control(ir::control::Control::Table(
+        Box::new(table),
+    )));
+    paragraph
+}
+
+fn part_sizes(bytes: &[u8]) -> Vec<(String, u64)> {
+    let mut archive = zip::ZipArchive::new(Cursor::new(bytes)).unwrap();
+    (0..archive.len())
+        .map(|index| {
+            let file = archive.by_index(index).unwrap();
+            (file.name().to_string(), file.size())
+        })
+        .collect()
+}
+
+fn replace_part(bytes: &[u8], name: &str, data: &[u8]) -> Vec<u8> {
+    let mut archive = zip::ZipArchive::new(Cursor::new(bytes)).unwrap();
+    let mut zip = zip::ZipWriter::new(Cursor::new(Vec::new()));
+    for index in 0..archive.len() {
+        let mut file = archive.by_index(index).unwrap();
+        let file_name = file.name().to_string();
+        let mut content = Vec::new();
+        file.read_to_end(&mut content).unwrap();
+        if file_name == name {
+            content = data.to_vec();
+        }
+        zip.start_file(file_name, SimpleFileOptions::default())
+            .unwrap();
+        zip.write_all(&content).unwrap();
+    }
+    zip.finish().unwrap().into_inner()
+}
+
+fn read_part(bytes: &[u8], name: &str) -> String {
+    let mut archive = zip::ZipArchive::new(Cursor::new(bytes)).unwrap();
+    let mut content = String::new();
+    archive
+        .by_name(name)
+        .unwrap()
+        .read_to_string(&mut content)
+        .unwrap();
+    content
+}
+
+fn read(bytes: &[u8], limits: Limits) -> Result<Document, Error> {
+    Document::from_bytes_with_options(bytes, &ReadOptions::new().with_limits(limits))
+}
+
+fn exceeded(result: Result<Document, Error>) -> Option<LimitKind> {
+    match result {
+        Err(Error::LimitExceeded { kind, .. }) => Some(kind),
+        _ => None,
+    }
+}
+
+#[test]
+fn deflate_bomb_is_rejected() {
+    let bytes = converted(vec![ir::Paragraph::with_text("본문")])
+        .to_bytes()
+        .unwrap();
+    let bomb = vec![b' '; 4 * 1024 * 1024];
+    let bytes = replace_part(&bytes, PREVIEW_TEXT_PATH, &bomb);
+    assert!(bytes.len() < bomb.len() / 100);
+
+    let limits = Limits::new().with_max_stream_size(1024 * 1024);
+    assert_eq!(exceeded(read(&bytes, limits)), Some(LimitKind::StreamSize));
+    assert!(Document::from_bytes(&bytes).is_ok());
+}
+
+#[test]
+fn part_and_total_size_limits() {
+    let mut document = converted(vec![ir::Paragraph::with_text("본문")]);
+    document.add_binary_data("BinData/image1.png".to_string(), PNG.to_vec());
+    let bytes = document.to_bytes().unwrap();
+    let sizes = part_sizes(&bytes);
+    let largest = sizes
+        .iter()
+        .filter(|(name, _)| !name.starts_with("BinData/"))
+        .map(|(_, size)| *size)
+        .max()
+        .unwrap();
+    let total: u64 = sizes.iter().map(|(_, size)| size).sum();
+
+    assert!(read(&bytes, Limits::new().with_max_stream_size(largest)).is_ok());
+    assert_eq!(
+        exceeded(read(
+            &bytes,
+            Limits::new().with_max_stream_size(largest - 1)
+        )),
+        Some(LimitKind::StreamSize)
+    );
+    assert_eq!(
+        exceeded(read(
+            &bytes,
+            Limits::new().with_max_binary_data_size(PNG.len() as u64 - 1)
+        )),
+        Some(LimitKind::BinaryDataSize)
+    );
+    assert_eq!(
+        exceeded(read(&bytes, Limits::new().with_max_total_size(total / 2))),
+        Some(LimitKind::TotalSize)
+    );
+}
+
+#[test]
+fn encrypted_part_limits() {
+    let document = converted(vec![ir::Paragraph::with_text("비밀")]);
+    let bytes = document.to_bytes_with_password("암호").unwrap();
+
+    let options = ReadOptions::new()
+        .with_password("암호")
+        .with_limits(Limits::new().with_max_stream_size(64));
+    assert!(matches!(
+        Document::from_bytes_with_options(&bytes, &options),
+        Err(Error::LimitExceeded {
+            kind: LimitKind::StreamSize,
+            limit: 64
+        })
+    ));
+}
+
+#[test]
+fn nesting_depth_limit() {
+    let bytes = converted(vec![table_paragraph()]).to_bytes().unwrap();
+
+    assert!(read(&bytes, Limits::new().with_max_nesting_depth(1)).is_ok());
+    assert_eq!(
+        exceeded(read(&bytes, Limits::new().with_max_nesting_depth(0))),
+        Some(LimitKind::NestingDepth)
+    );
+
+    let options = ReadOptions::new()
+        .with_preserve_unknown(true)
+        .with_limits(Limits::new().with_max_nesting_depth(0));
+    assert!(Document::from_bytes_with_options(&bytes, &options).is_err());
+}
+
+#[test]
+fn element_depth_limit() {
+    let bytes = converted(vec![ir::Paragraph::with_text("본문")])
+        .to_bytes()
+        .unwrap();
+    let section = read_part(&bytes, "Contents/section0.xml");
+    let nested = |name: &str, depth: usize| {
+        let end = section.find("</p>").unwrap();
+        let open = format!("<{name}>").repeat(depth);
+        let close = format!("</{name}>").repeat(depth);
+        let xml = format!("{}{open}{close}{}", &section[..end], &section[end..]);
+        replace_part(&bytes, "Contents/section0.xml", xml.as_bytes())
+    };
+
+    // 묶음 개체는 문단 목록 없이도 중첩 단계로 셈
+    let limits = Limits::new().with_max_nesting_depth(4);
+    assert!(read(&nested("container", 4), limits).is_ok());
+    assert_eq!(
+        exceeded(read(&nested("container", 5), limits)),
+        Some(LimitKind::NestingDepth)
+    );
+
+    // 중첩 구조가 아닌 요소도 깊이 제한을 받음
+    assert_eq!(
+        exceeded(read(&nested("unknown", 10_000), Limits::new())),
+        Some(LimitKind::NestingDepth)
+    );
+}
@@ -0,0 +1,291 @@
+//! HWPX 패키지(ZIP) 읽기/쓰기 테스트
+
+use std::io::{Cursor, Write};
+
+use hwpx::Document;
+use hwpx::convert::{HwpxToIr, IrToHwpx};
+use hwpx::package::{ContentHpf, META_CREATOR};
+use zip::write::SimpleFileOptions;
+
+const VERSION_XML: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes" ?><hv:HCFVersion xmlns:hv="http://www.hancom.co.kr/hwpml/2011/version" tagetApplication="WORDPROCESSOR" major="5" minor="1" micro="1" buildNumber="0" os="1" xmlVersion="1.5" application="Hancom Office Hangul" appVersion="12, 0, 0, 3650 WIN32LEWindows_10"/>"#;
+
+/// IR에서 텍스트 문단을 가진 HWPX 문서 생성
+fn sample_document(texts: &[&str]) -> Document {
+    let mut ir_doc = ir::Document::new();
+    for text in texts {
+        let mut section = ir::Section::new();
+        section.add_paragraph(ir::Paragraph::with_text(*text));
+        ir_doc.add_section(section);
+    }
+    ir_doc.to_hwpx().unwrap().value
+}
+
+fn section_text(document: &Document, index: usize) -> String {
+    let ir_doc = document.to_ir().unwrap().value;
+    ir_doc.sections[index]
+        .paragraphs
+        .iter()
+        .map(|p| p.to_plain_text())
+        .collect()
+}
+
+/// 테스트용 ZIP 패키지 생성
+fn build_package(entries: &[(&str, &str)]) -> Vec<u8> {
+    let mut zip = zip::ZipWriter::new(Cursor::new(Vec::new()));
+    for (name, content) in entries {
+        zip.start_file(*name, SimpleFileOptions::default()).unwrap();
+        zip.write_all(content.as_bytes()).unwrap();
+    }
+    zip.finish().unwrap().into_inner()
+}
+
+mod write_tests {
+    use super::*;
+
+    #[test]
+    fn mimetype_is_first_and_stored() {
+        let bytes = sample_document(&["본문"]).to_bytes().unwrap();
+
+        // 첫 로컬 파일 헤더: 시그니처, 압축 방식(0 = Stored), 파일 이름
+        assert_eq!(&bytes[0..4], b"PK\x03\x04");
+        assert_eq!(u16::from_le_bytes([bytes[8], bytes[9]]), 0);
+        let name_len = u16::from_le_bytes([bytes[26], bytes[27]]) as usize;
+        assert_eq!(&bytes[30..30 + name_len], b"mimetype");
+        assert_eq!(
+            &bytes[30 + name_len..30 + name_len + 19],
+            b"application/hwp+zip"
+        );
+    }
+
+    #[test]
+    fn writes_required_parts() {
+        let bytes = sample_document(&["첫째", "둘째"]).to_bytes().unwrap();
+        let archive = zip::ZipArchive::new(Cursor::new(bytes)).unwrap();
+        let names: Vec<&str> = archive.file_names().collect();
+
+        for part in [
+            "mimetype",
+            "version.xml",
+            "META-INF/container.xml",
+            "META-INF/manifest.xml",
+            "Contents/content.hpf",
+            "Contents/header.xml",
+            "Contents/section0.xml",
+            "Contents/section1.xml",
+        ] {
+            assert!(names.contains(&part), "{} 누락: {:?}", part, names);
+        }
+    }
+}
+
+mod roundtrip_tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip_sections_in_order() {
+        let original = sample_document(&["첫째 구역", "둘째 구역", "셋째 구역"]);
+        let bytes = original.to_bytes().unwrap();
+        let parsed = Document::from_bytes(&bytes).unwrap();
+
+        assert_eq!(parsed.version, original.version);
+        assert_eq!(parsed.header, original.header);
+        assert_eq!(parsed.sections, original.sections);
+        assert_eq!(section_text(&parsed, 2), "셋째 구역");
+    }
+
+    #[test]
+    fn roundtrip_binary_data() {
+        let mut original = sample_document(&["그림"]);
+        original.add_binary_data(
+            "BinData/image1.png".to_string(),
+            vec![0x89, b'P', b'N', b'G'],
+        );
+
+        let parsed = Document::from_bytes(&original.to_bytes().unwrap()).unwrap();
+        assert_eq!(
+            parsed.binary_data.get("BinData/image1.png"),
+            Some(&vec![0x89, b'P', b'N', b'G'])
+        );
+    }
+}
+
+/// 패키지에서 `Contents/content.hpf` 읽기
+fn read_content_hpf(bytes: &[u8]) -> ContentHpf {
+    let mut archive = zip::ZipArchive::new(Cursor::new(bytes)).unwrap();
+    let mut xml = String::new();
+    std::io::Read::read_to_string(
+        &mut archive.by_name("Contents/content.hpf").unwrap(),
+        &mut xml,
+    )
+    .unwrap();
+    hwpx::from_str(&xml).unwrap()
+}
+
+mod content_hpf_tests {
+    use super::*;
+
+    const CONTENT_HPF: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes" ?><opf:package xmlns:opf="http://www.idpf.org/2007/opf/" version="" unique-identifier="" id=""><opf:metadata><opf:title>보고서</opf:title><opf:language>ko</opf:language><opf:meta name="creator" content="text">홍길동</opf:meta><opf:meta name="subject" content="text"/></opf:metadata><opf:manifest><opf:item id="header" href="Contents/header.xml" media-type="application/xml"/><opf:item id="image1" href="BinData/image1.png" media-type="image/png" isEmbeded="1"/><opf:item id="section0" href="Contents/section0.xml" media-type="application/xml"/></opf:manifest><opf:spine><opf:itemref idref="header" linear="yes"/><opf:itemref idref="section0" linear="yes"/></opf:spine></opf:package>"#;
+
+    #[test]
+    fn parse_content_hpf() {
+        let content: ContentHpf = hwpx::from_str(CONTENT_HPF).unwrap();
+
+        assert_eq!(content.metadata.title.as_deref(), Some("보고서"));
+        assert_eq!(content.metadata.language.as_deref(), Some("ko"));
+        assert_eq!(content.metadata.meta(META_CREATOR), Some("홍길동"));
+        assert_eq!(content.metadata.meta("subject"), None);
+
+        let image = content.item("image1").unwrap();
+        assert_eq!(image.href, "BinData/image1.png");
+        assert_eq!(image.media_type, "image/png");
+        assert_eq!(image.is_embedded, Some(true));
+
+        let spine: Vec<&str> = content.spine_items().map(|item| item.id.as_str()).collect();
+        assert_eq!(spine, ["header", "section0"]);
+    }
+
+    #[test]
+    fn binary_data_resolved_through_manifest() {
+        let header = hwpx::to_string(&sample_document(&[""]).header).unwrap();
+        let bytes = build_package(&[
+            ("version.xml", VERSION_XML),
+            ("Contents/content.hpf", CONTENT_HPF),
+            ("Contents/header.xml", &header),
+            ("Contents/section0.xml", read_tests::SECTION_A),
+            ("BinData/image1.png", "PNG"),
+        ]);
+
+        let document = Document::from_bytes(&bytes).unwrap();
+        assert_eq!(document.content.metadata.title.as_deref(), Some("보고서"));
+        assert_eq!(document.binary_data_by_id("image1"), Some(&b"PNG"[..]));
+
+        let ir_doc = document.to_ir().unwrap().value;
+        assert_eq!(ir_doc.metadata.author.as_deref(), Some("홍길동"));
+        assert!(
+            ir_doc
+                .binary_data
+                .get(&ir::BinaryDataId::new("image1"))
+                .is_some()
+        );
+    }
+
+    #[test]
+    fn manifest_keeps_binary_item_ids() {
+        let mut ir_doc = ir::Document::new();
+        ir_doc.metadata.title = Some("제목".to_string());
+        ir_doc.add_section(ir::Section::new());
+        ir_doc.binary_data.add(
+            ir::BinaryDataId::new("image7"),
+            ir::BinaryData::new(ir::BinaryFormat::Jpg, vec![0xFF, 0xD8, 0xFF]),
+        );
+
+        let document = ir_doc.to_hwpx().unwrap().value;
+        assert_eq!(
+            document.binary_data_path("image7"),
+            Some("BinData/image7.jpg")
+        );
+
+        let bytes = document.to_bytes().unwrap();
+        let content = read_content_hpf(&bytes);
+        let item = content.item("image7").unwrap();
+        assert_eq!(item.href, "BinData/image7.jpg");
+        assert_eq!(item.media_type, "image/jpeg");
+        assert_eq!(item.is_embedded, Some(true));
+        assert_eq!(content.metadata.title.as_deref(), Some("제목"));
+
+        let parsed = Document::from_bytes(&bytes).unwrap();
+        let ir_doc = parsed.to_ir().unwrap().value;
+        assert_eq!(ir_doc.metadata.title.as_deref(), Some("제목"));
+        let binary = ir_doc
+            .binary_data
+            .get(&ir::BinaryDataId::new("image7"))
+            .unwrap();
+        assert_eq!(binary.data, vec![0xFF, 0xD8, 0xFF]);
+        assert_eq!(binary.format, ir::BinaryFormat::Jpg);
+    }
+
+    #[test]
+    fn spine_lists_header_and_sections() {
+        let bytes = sample_document(&["가", "나"]).to_bytes().unwrap();
+        let content = read_content_hpf(&bytes);
+
+        let spine: Vec<&str> = content
+            .spine_items()
+            .map(|item| item.href.as_str())
+            .collect();
+        assert_eq!(
+            spine,
+            [
+                "Contents/header.xml",
+                "Contents/section0.xml",
+                "Contents/section1.xml"
+            ]
+        );
+    }
+}
+
+mod read_tests {
+    use super::*;
+
+    pub(super) const SECTION_A: &str = r#"<hs:sec xmlns:hs="http://www.hancom.co.kr/hwpml/2011/section" xmlns:hp="http://www.hancom.co.kr/hwpml/2011/paragraph"><hp:p id="0" paraPrIDRef="0" styleIDRef="0"><hp:run charPrIDRef="0"><hp:t>A</hp:t></hp:run></hp:p></hs:sec>"#;
+    const SECTION_B: &str = r#"<hs:sec xmlns:hs="http://www.hancom.co.kr/hwpml/2011/section" xmlns:hp="http://www.hancom.co.kr/hwpml/2011/paragraph"><hp:p id="0" paraPrIDRef="0" styleIDRef="0"><hp:run charPrIDRef="0"><hp:t>B</hp:t></hp:run></hp:p></hs:sec>"#;
+
+    fn header_xml() -> String {
+        hwpx::to_string(&sample_document(&[""]).header).unwrap()
+    }
+
+    #[test]
+    fn sections_follow_spine_order() {
+        let content_hpf = r#"<opf:package xmlns:opf="http://www.idpf.org/2007/opf/"><opf:manifest><opf:item id="header" href="Contents/header.xml" media-type="application/xml"/><opf:item id="section0" href="Contents/section0.xml" media-type="application/xml"/><opf:item id="section1" href="Contents/section1.xml" media-type="application/xml"/></opf:manifest><opf:spine><opf:itemref idref="header"/><opf:itemref idref="section1"/><opf:itemref idref="section0"/></opf:spine></opf:package>"#;
+        let header = header_xml();
+        let bytes = build_package(&[
+            ("mimetype", "application/hwp+zip"),
+            ("version.xml", VERSION_XML),
+            ("Contents/content.hpf", content_hpf),
+            ("Contents/header.xml", &header),
+            ("Contents/section0.xml", SECTION_A),
+            ("Contents/section1.xml", SECTION_B),
+        ]);
+
+        let document = Document::from_bytes(&bytes).unwrap();
+        assert_eq!(document.sections.len(), 2);
+        assert_eq!(section_text(&document, 0), "B");
+        assert_eq!(section_text(&document, 1), "A");
+    }
+
+    #[test]
+    fn sections_fall_back_to_file_numbers() {
+        let header = header_xml();
+        let bytes = build_package(&[
+            ("version.xml", VERSION_XML),
+            ("Contents/header.xml", &header),
+            ("Contents/section10.xml", SECTION_B),
+            ("Contents/section2.xml", SECTION_A),
+        ]);
+
+        let document = Document::from_bytes(&bytes).unwrap();
+        assert_eq!(section_text(&document, 0), "A");
+        assert_eq!(section_text(&document, 1), "B");
+    }
+
+    #[test]
+    fn invalid_mimetype_is_rejected() {
+        let bytes = build_package(&[
+            ("mimetype", "application/zip"),
+            ("version.xml", VERSION_XML),
+        ]);
+        assert!(matches!(
+            Document::from_bytes(&bytes),
+            Err(hwpx::Error::InvalidMimeType(_))
+        ));
+    }
+
+    #[test]
+    fn missing_header_is_reported() {
+        let bytes = build_package(&[("version.xml", VERSION_XML)]);
+        match Document::from_bytes(&bytes) {
+            Err(hwpx::Error::MissingPart { name }) => assert_eq!(name, "Contents/header.xml"),
+            other => panic!("MissingPart 오류 기대: {:?}", other.map(|_| ())),
+        }
+    }
+}
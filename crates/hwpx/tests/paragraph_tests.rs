@@ -151,6 +151,18 @@ mod text_tests {
             panic!("TextMarkup::Text 매칭 실패");
         }
     }
+
+    #[test]
+    fn serialize_text_with_markup_roundtrip() {
+        let xml = r#"<hp:t xmlns:hp="http://www.hancom.co.kr/hwpml/2011/paragraph">앞<hp:markpenEnd/>뒤</hp:t>"#;
+        let text: TextElement = from_str(xml).unwrap();
+
+        let serialized = hwpx::to_string(&text).unwrap();
+        let reparsed: TextElement = from_str(&serialized).unwrap();
+
+        assert_eq!(reparsed, text);
+        assert_eq!(reparsed.text(), "앞뒤");
+    }
 }
 
 mod line_segment_tests {
@@ -0,0 +1,359 @@
+//! 알 수 없는 요소/속성 보존 테스트
+
+use std::io::{Cursor, Read, Write};
+
+use hwpx::convert::{HwpxToIr, IrToHwpx};
+use hwpx::paragraph::RunContent;
+use hwpx::preserve::UnknownContent;
+use hwpx::{Document, ReadOptions};
+use zip::write::SimpleFileOptions;
+
+const SECTION_PATH: &str = "Contents/section0.xml";
+
+const EXTENSION_NAMESPACE: &str = "urn:example:extension";
+
+/// 구역에 확장 요소와 속성을 넣은 패키지
+fn package_with_extensions() -> Vec<u8> {
+    let mut ir_doc = ir::Document::new();
+    let mut section = ir::Section::new();
+    section.add_paragraph(ir::Paragraph::with_text("first"));
+    section.add_paragraph(ir::Paragraph::with_text("second"));
+    ir_doc.add_section(section);
+    let bytes = ir_doc.to_hwpx().unwrap().value.to_bytes().unwrap();
+
+    let xml = read_part(&bytes, SECTION_PATH)
+        .replacen(
+            "<sec>",
+            r#"<sec xmlns:ext="urn:example:extension"><ext:marker level="2"><ext:item name="a"/></ext:marker>"#,
+            1,
+        )
+        .replacen(r#"<p id="1" "#, r#"<p id="1" ext:tag="keep" futureAttr="1" "#, 1)
+        .replacen("<t>second</t></run>", "<t>second</t></run><ext:inline/>", 1);
+    replace_part(&bytes, SECTION_PATH, &xml)
+}
+
+fn read_part(bytes: &[u8], name: &str) -> String {
+    let mut archive = zip::ZipArchive::new(Cursor::new(bytes)).unwrap();
+    let mut xml = String::new();
+    archive
+        .by_name(name)
+        .unwrap()
+        .read_to_string(&mut xml)
+        .unwrap();
+    xml
+}
+
+fn replace_part(bytes: &[u8], name: &str, xml: &str) -> Vec<u8> {
+    let mut archive = zip::ZipArchive::new(Cursor::new(bytes)).unwrap();
+    let mut zip = zip::ZipWriter::new(Cursor::new(Vec::new()));
+    for index in 0..archive.len() {
+        let mut file = archive.by_index(index).unwrap();
+        let file_name = file.name().to_string();
+        let mut data = Vec::new();
+        file.read_to_end(&mut data).unwrap();
+        if file_name == name {
+            data = xml.as_bytes().to_vec();
+        }
+        zip.start_file(file_name, SimpleFileOptions::default())
+            .unwrap();
+        zip.write_all(&data).unwrap();
+    }
+    zip.finish().unwrap().into_inner()
+}
+
+fn preserving() -> ReadOptions {
+    ReadOptions::new().with_preserve_unknown(true)
+}
+
+/// 보존한 확장 노드가 원래 자리에 있는지 확인
+fn assert_extensions_restored(xml: &str) {
+    assert!(
+        xml.contains(&format!(
+            r#"<sec><ext:marker xmlns:ext="{}" level="2"><ext:item name="a"/></ext:marker><p id="0""#,
+            EXTENSION_NAMESPACE
+        )),
+        "구역 첫 자식 요소로 복원되어야 함: {}",
+        xml
+    );
+    assert!(
+        xml.contains(&format!(
+            r#"xmlns:ext="{}" ext:tag="keep" futureAttr="1">"#,
+            EXTENSION_NAMESPACE
+        )),
+        "문단 속성이 복원되어야 함: {}",
+        xml
+    );
+    assert!(
+        xml.contains(&format!(
+            r#"<t>second</t></run><ext:inline xmlns:ext="{}"/></p>"#,
+            EXTENSION_NAMESPACE
+        )),
+        "문단 안의 요소가 같은 위치에 복원되어야 함: {}",
+        xml
+    );
+}
+
+mod capture_tests {
+    use super::*;
+
+    #[test]
+    fn unknown_nodes_dropped_by_default() {
+        let document = Document::from_bytes(&package_with_extensions()).unwrap();
+        assert!(document.unknown_nodes.is_empty());
+
+        let saved = document.to_bytes().unwrap();
+        assert!(!read_part(&saved, SECTION_PATH).contains("ext:"));
+    }
+
+    #[test]
+    fn captures_unknown_elements_and_attributes() {
+        let document =
+            Document::from_bytes_with_options(&package_with_extensions(), &preserving()).unwrap();
+
+        let nodes: Vec<_> = document
+            .unknown_nodes
+            .iter()
+            .map(|node| (node.part.as_str(), node.path.as_str(), &node.content))
+            .collect();
+        assert_eq!(
+            nodes,
+            [
+                (
+                    SECTION_PATH,
+                    "/sec",
+                    &UnknownContent::Element {
+                        index: 0,
+                        xml: format!(
+                            r#"<ext:marker xmlns:ext="{}" level="2"><ext:item name="a"/></ext:marker>"#,
+                            EXTENSION_NAMESPACE
+                        ),
+                    }
+                ),
+                (
+                    SECTION_PATH,
+                    "/sec/p[@id='1']",
+                    &UnknownContent::Attribute {
+                        name: "xmlns:ext".to_string(),
+                        value: EXTENSION_NAMESPACE.to_string(),
+                    }
+                ),
+                (
+                    SECTION_PATH,
+                    "/sec/p[@id='1']",
+                    &UnknownContent::Attribute {
+                        name: "ext:tag".to_string(),
+                        value: "keep".to_string(),
+                    }
+                ),
+                (
+                    SECTION_PATH,
+                    "/sec/p[@id='1']",
+                    &UnknownContent::Attribute {
+                        name: "futureAttr".to_string(),
+                        value: "1".to_string(),
+                    }
+                ),
+                (
+                    SECTION_PATH,
+                    "/sec/p[@id='1']",
+                    &UnknownContent::Element {
+                        index: 1,
+                        xml: format!(r#"<ext:inline xmlns:ext="{}"/>"#, EXTENSION_NAMESPACE),
+                    }
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn known_parts_have_no_unknown_nodes() {
+        let mut ir_doc = ir::Document::new();
+        ir_doc.add_section(ir::Section::new());
+        let bytes = ir_doc.to_hwpx().unwrap().value.to_bytes().unwrap();
+
+        let document = Document::from_bytes_with_options(&bytes, &preserving()).unwrap();
+        assert!(
+            document.unknown_nodes.is_empty(),
+            "모델이 기록한 파트에는 보존할 노드가 없어야 함: {:?}",
+            document.unknown_nodes
+        );
+    }
+}
+
+mod restore_tests {
+    use super::*;
+
+    #[test]
+    fn save_restores_nodes_in_place() {
+        let document =
+            Document::from_bytes_with_options(&package_with_extensions(), &preserving()).unwrap();
+        let saved = document.to_bytes().unwrap();
+
+        assert_extensions_restored(&read_part(&saved, SECTION_PATH));
+    }
+
+    #[test]
+    fn repeated_round_trips_are_stable() {
+        let first =
+            Document::from_bytes_with_options(&package_with_extensions(), &preserving()).unwrap();
+        let saved = first.to_bytes().unwrap();
+        let second = Document::from_bytes_with_options(&saved, &preserving()).unwrap();
+
+        assert_eq!(first.unknown_nodes, second.unknown_nodes);
+        assert_eq!(
+            read_part(&saved, SECTION_PATH),
+            read_part(&second.to_bytes().unwrap(), SECTION_PATH)
+        );
+    }
+
+    #[test]
+    fn nodes_survive_ir_conversion() {
+        let document =
+            Document::from_bytes_with_options(&package_with_extensions(), &preserving()).unwrap();
+        let ir_doc = document.to_ir().unwrap().value;
+        assert_eq!(
+            ir_doc.extensions.hwpx.as_ref().unwrap().unknown_nodes.len(),
+            document.unknown_nodes.len()
+        );
+
+        let converted = ir_doc.to_hwpx().unwrap().value;
+        assert_eq!(converted.unknown_nodes, document.unknown_nodes);
+        assert_extensions_restored(&read_part(&converted.to_bytes().unwrap(), SECTION_PATH));
+    }
+
+    #[test]
+    fn nodes_survive_ir_conversion_with_duplicate_ids() {
+        // 실제 파일처럼 문단 아이디가 순번이 아니고 겹쳐도 IR을 거친 뒤 같은 문단에 복원됨
+        let bytes = package_with_extensions();
+        let xml = read_part(&bytes, SECTION_PATH)
+            .replacen(r#"<p id="0" "#, r#"<p id="7" "#, 1)
+            .replacen(r#"<p id="1" "#, r#"<p id="7" "#, 1);
+        let bytes = replace_part(&bytes, SECTION_PATH, &xml);
+
+        let document = Document::from_bytes_with_options(&bytes, &preserving()).unwrap();
+        assert!(
+            document
+                .unknown_nodes
+                .iter()
+                .any(|node| node.path == "/sec/p[@id='7'][2]")
+        );
+
+        let converted = document.to_ir().unwrap().value.to_hwpx().unwrap();
+        assert!(converted.warnings.is_empty(), "{:?}", converted.warnings);
+        assert_eq!(converted.value.unknown_nodes, document.unknown_nodes);
+        assert_eq!(
+            read_part(&converted.value.to_bytes().unwrap(), SECTION_PATH),
+            read_part(&document.to_bytes().unwrap(), SECTION_PATH)
+        );
+    }
+
+    #[test]
+    fn nodes_with_missing_parent_are_skipped() {
+        let mut document =
+            Document::from_bytes_with_options(&package_with_extensions(), &preserving()).unwrap();
+        // 두 번째 문단을 지우면 그 문단의 속성과 자식 요소는 기록할 곳이 없음
+        document.sections[0].paragraphs.truncate(1);
+        let xml = read_part(&document.to_bytes().unwrap(), SECTION_PATH);
+
+        assert!(
+            xml.contains("<ext:marker"),
+            "구역의 요소는 남아야 함: {}",
+            xml
+        );
+        assert!(
+            !xml.contains("ext:tag"),
+            "지운 문단의 속성은 없어야 함: {}",
+            xml
+        );
+        assert!(
+            !xml.contains("<ext:inline"),
+            "지운 문단의 요소는 없어야 함: {}",
+            xml
+        );
+
+        let orphans = document.orphaned_unknown_nodes().unwrap();
+        assert_eq!(orphans.len(), 4);
+        assert!(orphans.iter().all(|node| node.path == "/sec/p[@id='1']"));
+    }
+
+    #[test]
+    fn nodes_follow_paragraph_id() {
+        let mut document =
+            Document::from_bytes_with_options(&package_with_extensions(), &preserving()).unwrap();
+        // 첫 문단을 지워도 아이디가 1인 문단의 노드는 그 문단에 남음
+        document.sections[0].paragraphs.remove(0);
+        assert!(document.orphaned_unknown_nodes().unwrap().is_empty());
+        let xml = read_part(&document.to_bytes().unwrap(), SECTION_PATH);
+
+        assert!(
+            xml.contains(&format!(
+                r#"<sec><ext:marker xmlns:ext="{}" level="2"><ext:item name="a"/></ext:marker><p id="1""#,
+                EXTENSION_NAMESPACE
+            )),
+            "구역의 요소는 남은 문단 앞에 있어야 함: {}",
+            xml
+        );
+        assert!(
+            xml.contains(r#"<p id="1" "#) && xml.contains(r#"ext:tag="keep" futureAttr="1">"#),
+            "문단 속성이 같은 아이디 문단에 복원되어야 함: {}",
+            xml
+        );
+        assert!(
+            xml.contains(&format!(
+                r#"<t>second</t></run><ext:inline xmlns:ext="{}"/></p>"#,
+                EXTENSION_NAMESPACE
+            )),
+            "문단 안의 요소가 같은 문단에 복원되어야 함: {}",
+            xml
+        );
+    }
+
+    #[test]
+    fn orphaned_nodes_are_reported_on_conversion() {
+        let mut document =
+            Document::from_bytes_with_options(&package_with_extensions(), &preserving()).unwrap();
+        document.sections[0].paragraphs.truncate(1);
+        let ir_doc = document.to_ir().unwrap().value;
+
+        let converted = ir_doc.to_hwpx().unwrap();
+        assert!(
+            converted
+                .warnings
+                .iter()
+                .any(|warning| warning.message.contains("보존한 XML 노드")),
+            "기록할 곳이 없는 노드는 경고해야 함: {:?}",
+            converted.warnings
+        );
+    }
+
+    #[test]
+    fn unknown_run_children_are_preserved() {
+        let bytes = package_with_extensions();
+        let xml = read_part(&bytes, SECTION_PATH).replacen(
+            "<t>first</t>",
+            r#"<ext:future a="1"><ext:part/></ext:future><t>first</t>"#,
+            1,
+        );
+        let bytes = replace_part(&bytes, SECTION_PATH, &xml);
+
+        // 보존하지 않아도 모르는 런 자식 때문에 읽기가 실패하지 않음
+        let document = Document::from_bytes(&bytes).unwrap();
+        let contents = &document.sections[0].paragraphs[0].runs[0].contents;
+        assert!(contents.contains(&RunContent::Unknown));
+
+        let document = Document::from_bytes_with_options(&bytes, &preserving()).unwrap();
+        assert!(document.unknown_nodes.iter().any(|node| {
+            node.path == "/sec/p[@id='0']/run[1]"
+                && matches!(node.content, UnknownContent::Element { .. })
+        }));
+        let saved = read_part(&document.to_bytes().unwrap(), SECTION_PATH);
+        assert!(
+            saved.contains(&format!(
+                r#"<ext:future xmlns:ext="{}" a="1"><ext:part/></ext:future><t>first</t>"#,
+                EXTENSION_NAMESPACE
+            )),
+            "런 안의 요소가 같은 위치에 복원되어야 함: {}",
+            saved
+        );
+    }
+}
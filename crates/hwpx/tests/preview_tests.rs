@@ -0,0 +1,163 @@
+//! 미리보기 파트 테스트
+
+use std::io::{Cursor, Read, Write};
+
+use hwpx::Document;
+use hwpx::convert::IrToHwpx;
+use hwpx::package::{PREVIEW_IMAGE_PATH, PREVIEW_TEXT_PATH};
+use zip::write::SimpleFileOptions;
+
+const PNG: &[u8] = &[0x89, 0x50, 0x4E, 0x47, 0x0D, 0x0A, 0x1A, 0x0A, 0x00];
+
+fn converted(texts: &[&str]) -> Document {
+    let mut ir_doc = ir::Document::new();
+    let mut section = ir::Section::new();
+    for text in texts {
+        section.add_paragraph(ir::Paragraph::with_text(*text));
+    }
+    ir_doc.add_section(section);
+    ir_doc.to_hwpx().unwrap().value
+}
+
+fn read_part(bytes: &[u8], name: &str) -> Option<Vec<u8>> {
+    let mut archive = zip::ZipArchive::new(Cursor::new(bytes)).unwrap();
+    let mut file = archive.by_name(name).ok()?;
+    let mut data = Vec::new();
+    file.read_to_end(&mut data).unwrap();
+    Some(data)
+}
+
+fn replace_part(bytes: &[u8], name: &str, data: &[u8]) -> Vec<u8> {
+    let mut archive = zip::ZipArchive::new(Cursor::new(bytes)).unwrap();
+    let mut zip = zip::ZipWriter::new(Cursor::new(Vec::new()));
+    for index in 0..archive.len() {
+        let mut file = archive.by_index(index).unwrap();
+        let file_name = file.name().to_string();
+        let mut content = Vec::new();
+        file.read_to_end(&mut content).unwrap();
+        if file_name == name {
+            content = data.to_vec();
+        }
+        zip.start_file(file_name, SimpleFileOptions::default())
+            .unwrap();
+        zip.write_all(&content).unwrap();
+    }
+    zip.finish().unwrap().into_inner()
+}
+
+fn rename_part(bytes: &[u8], from: &str, to: &str) -> Vec<u8> {
+    let mut archive = zip::ZipArchive::new(Cursor::new(bytes)).unwrap();
+    let mut zip = zip::ZipWriter::new(Cursor::new(Vec::new()));
+    for index in 0..archive.len() {
+        let mut file = archive.by_index(index).unwrap();
+        let mut file_name = file.name().to_string();
+        let mut content = Vec::new();
+        file.read_to_end(&mut content).unwrap();
+        if file_name == from {
+            file_name = to.to_string();
+        }
+        zip.start_file(file_name, SimpleFileOptions::default())
+            .unwrap();
+        zip.write_all(&content).unwrap();
+    }
+    zip.finish().unwrap().into_inner()
+}
+
+mod generate_tests {
+    use super::*;
+
+    #[test]
+    fn converter_generates_preview_text() {
+        let document = converted(&["첫 문단", "둘째 문단"]);
+        assert_eq!(document.preview_text(), Some("첫 문단\r\n둘째 문단\r\n"));
+        assert_eq!(document.preview_image(), None);
+
+        let bytes = document.to_bytes().unwrap();
+        assert_eq!(
+            read_part(&bytes, PREVIEW_TEXT_PATH).unwrap(),
+            "첫 문단\r\n둘째 문단\r\n".as_bytes()
+        );
+        assert!(read_part(&bytes, PREVIEW_IMAGE_PATH).is_none());
+    }
+
+    #[test]
+    fn preview_text_is_truncated() {
+        let long = "가".repeat(ir::PREVIEW_TEXT_MAX_LEN * 2);
+        let document = converted(&[&long]);
+
+        let preview = document.preview_text().unwrap();
+        assert_eq!(preview.chars().count(), ir::PREVIEW_TEXT_MAX_LEN);
+    }
+
+    #[test]
+    fn document_without_preview_writes_none() {
+        let mut document = converted(&["본문"]);
+        document.preview_text = None;
+
+        let bytes = document.to_bytes().unwrap();
+        assert!(read_part(&bytes, PREVIEW_TEXT_PATH).is_none());
+    }
+}
+
+mod read_tests {
+    use super::*;
+
+    #[test]
+    fn preview_round_trip() {
+        let mut document = converted(&["본문"]);
+        document.preview_image = Some(PNG.to_vec());
+
+        let read = Document::from_bytes(&document.to_bytes().unwrap()).unwrap();
+        assert_eq!(read.preview_text(), Some("본문\r\n"));
+        assert_eq!(read.preview_image(), Some(PNG));
+    }
+
+    #[test]
+    fn encrypted_preview_round_trip() {
+        let mut document = converted(&["비밀"]);
+        document.preview_image = Some(PNG.to_vec());
+
+        let bytes = document.to_bytes_with_password("암호").unwrap();
+        let read = Document::from_bytes_with_password(&bytes, "암호").unwrap();
+        assert_eq!(read.preview_text(), Some("비밀\r\n"));
+        assert_eq!(read.preview_image(), Some(PNG));
+    }
+
+    #[test]
+    fn preview_image_keeps_part_name() {
+        const BMP: &[u8] = b"BM\0\0\0\0";
+        let mut document = converted(&["본문"]);
+        document.preview_image = Some(BMP.to_vec());
+        let bytes = document.to_bytes().unwrap();
+        let bytes = rename_part(&bytes, PREVIEW_IMAGE_PATH, "Preview/PrvImage.bmp");
+
+        let read = Document::from_bytes(&bytes).unwrap();
+        assert_eq!(read.preview_image(), Some(BMP));
+        assert_eq!(read.preview_image_path(), "Preview/PrvImage.bmp");
+
+        let bytes = read.to_bytes().unwrap();
+        assert_eq!(read_part(&bytes, "Preview/PrvImage.bmp").unwrap(), BMP);
+        assert!(read_part(&bytes, PREVIEW_IMAGE_PATH).is_none());
+    }
+
+    #[test]
+    fn utf16_preview_text() {
+        let bytes = converted(&["본문"]).to_bytes().unwrap();
+        let mut utf16 = vec![0xFF, 0xFE];
+        utf16.extend("미리보기".encode_utf16().flat_map(u16::to_le_bytes));
+        let bytes = replace_part(&bytes, PREVIEW_TEXT_PATH, &utf16);
+
+        let read = Document::from_bytes(&bytes).unwrap();
+        assert_eq!(read.preview_text(), Some("미리보기"));
+    }
+
+    #[test]
+    fn missing_preview_parts() {
+        let mut document = converted(&["본문"]);
+        document.preview_text = None;
+
+        let read = Document::from_bytes(&document.to_bytes().unwrap()).unwrap();
+        assert_eq!(read.preview_text(), None);
+        assert_eq!(read.preview_image(), None);
+    }
+}
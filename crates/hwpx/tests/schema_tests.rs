@@ -0,0 +1,230 @@
+//! XML 스키마 검증 테스트
+
+use hwpx::convert::IrToHwpx;
+use hwpx::package::{HEADER_PATH, VERSION_PATH};
+use hwpx::schema::{SchemaViolation, ViolationKind, validate_part};
+
+/// 한글이 저장한 version.xml
+const VERSION_XML: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes" ?><hv:HCFVersion xmlns:hv="http://www.hancom.co.kr/hwpml/2011/version" tagetApplication="WORDPROCESSOR" major="5" minor="1" micro="1" buildNumber="0" os="1" xmlVersion="1.5" application="Hancom Office Hangul" appVersion="12, 0, 0, 3650 WIN32LEWindows_10"/>"#;
+
+const SECTION_PATH: &str = "Contents/section0.xml";
+
+/// 주어진 문단 요소로 만든 구역 XML
+fn section(paragraphs: &str) -> String {
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?><hs:sec xmlns:hs="http://www.hancom.co.kr/hwpml/2011/section" xmlns:hp="http://www.hancom.co.kr/hwpml/2011/paragraph">{}</hs:sec>"#,
+        paragraphs
+    )
+}
+
+fn kinds(violations: &[SchemaViolation]) -> Vec<&ViolationKind> {
+    violations.iter().map(|violation| &violation.kind).collect()
+}
+
+mod attribute_tests {
+    use super::*;
+
+    #[test]
+    fn hangul_version_is_valid() {
+        let violations = validate_part(VERSION_PATH, VERSION_XML);
+        assert!(violations.is_empty(), "위반이 없어야 함: {:?}", violations);
+    }
+
+    #[test]
+    fn missing_required_attribute() {
+        let xml = VERSION_XML.replace(r#" major="5""#, "");
+        let violations = validate_part(VERSION_PATH, &xml);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].part, VERSION_PATH);
+        assert_eq!(violations[0].path, "/HCFVersion");
+        assert_eq!(
+            violations[0].kind,
+            ViolationKind::MissingAttribute("major".to_string())
+        );
+    }
+
+    #[test]
+    fn invalid_enumeration_value() {
+        let xml = VERSION_XML.replace("WORDPROCESSOR", "DRAWING");
+        let violations = validate_part(VERSION_PATH, &xml);
+
+        assert!(
+            matches!(
+                kinds(&violations)[..],
+                [ViolationKind::InvalidAttributeValue { name, value, .. }]
+                    if name == "tagetApplication" && value == "DRAWING"
+            ),
+            "열거형 위반이 보고되어야 함: {:?}",
+            violations
+        );
+    }
+
+    #[test]
+    fn invalid_builtin_value() {
+        let xml = VERSION_XML.replace(r#"minor="1""#, r#"minor="-1""#);
+        let violations = validate_part(VERSION_PATH, &xml);
+
+        assert_eq!(
+            kinds(&violations),
+            [&ViolationKind::InvalidAttributeValue {
+                name: "minor".to_string(),
+                value: "-1".to_string(),
+                expected: "xs:nonNegativeInteger".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn undeclared_attribute() {
+        let xml = VERSION_XML.replace(r#" os="1""#, r#" os="1" color="red""#);
+        let violations = validate_part(VERSION_PATH, &xml);
+
+        assert_eq!(
+            kinds(&violations),
+            [&ViolationKind::UnexpectedAttribute("color".to_string())]
+        );
+    }
+}
+
+mod content_tests {
+    use super::*;
+
+    #[test]
+    fn valid_section() {
+        let xml = section(
+            r#"<hp:p id="0" paraPrIDRef="0" styleIDRef="0"><hp:run charPrIDRef="0"><hp:t>가나다</hp:t></hp:run></hp:p><hp:p id="1"><hp:run><hp:t>라마바</hp:t></hp:run></hp:p>"#,
+        );
+        let violations = validate_part(SECTION_PATH, &xml);
+        assert!(violations.is_empty(), "위반이 없어야 함: {:?}", violations);
+    }
+
+    #[test]
+    fn missing_required_element() {
+        let violations = validate_part(SECTION_PATH, &section(""));
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].path, "/sec");
+        assert_eq!(
+            violations[0].kind,
+            ViolationKind::MissingElement("p".to_string())
+        );
+    }
+
+    #[test]
+    fn unknown_element_reports_path() {
+        let xml = section(
+            r#"<hp:p id="0"><hp:run><hp:t>a</hp:t></hp:run></hp:p><hp:p id="1"><hp:run><hp:t>b</hp:t></hp:run><hp:run><hp:bogus/></hp:run></hp:p>"#,
+        );
+        let violations = validate_part(SECTION_PATH, &xml);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].path, "/sec/p[2]/run[2]/bogus[1]");
+        assert!(
+            matches!(&violations[0].kind, ViolationKind::UnexpectedElement { name, .. } if name == "bogus"),
+            "모르는 요소가 보고되어야 함: {:?}",
+            violations
+        );
+    }
+
+    #[test]
+    fn wrong_element_order() {
+        // beginNum은 refList보다 앞에 와야 함
+        let xml = r#"<hh:head xmlns:hh="http://www.hancom.co.kr/hwpml/2011/head" version="1.4" secCnt="1"><hh:refList/><hh:beginNum page="1" footnote="1" endnote="1" pic="1" tbl="1" equation="1"/></hh:head>"#;
+        let violations = validate_part(HEADER_PATH, xml);
+
+        let unexpected = violations
+            .iter()
+            .find(|violation| matches!(violation.kind, ViolationKind::UnexpectedElement { .. }))
+            .expect("순서 위반이 보고되어야 함");
+        assert_eq!(unexpected.path, "/head/refList[1]");
+        assert_eq!(
+            unexpected.kind,
+            ViolationKind::UnexpectedElement {
+                name: "refList".to_string(),
+                expected: vec!["beginNum".to_string()],
+            }
+        );
+    }
+
+    #[test]
+    fn too_many_occurrences() {
+        let xml = section(
+            r#"<hp:p id="0"><hp:run><hp:t>a</hp:t></hp:run><hp:linesegarray/><hp:linesegarray/></hp:p>"#,
+        );
+        let violations = validate_part(SECTION_PATH, &xml);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].path, "/sec/p[1]/linesegarray[2]");
+    }
+
+    #[test]
+    fn text_in_element_only_content() {
+        let xml = section(r#"<hp:p id="0">stray<hp:run/></hp:p>"#);
+        let violations = validate_part(SECTION_PATH, &xml);
+
+        assert_eq!(
+            kinds(&violations),
+            [&ViolationKind::UnexpectedText("stray".to_string())]
+        );
+    }
+}
+
+mod part_tests {
+    use super::*;
+
+    #[test]
+    fn malformed_xml() {
+        let violations = validate_part(SECTION_PATH, "<hs:sec><hp:p></hs:sec>");
+        assert!(
+            matches!(kinds(&violations)[..], [ViolationKind::Malformed(_)]),
+            "읽을 수 없는 XML이 보고되어야 함: {:?}",
+            violations
+        );
+    }
+
+    #[test]
+    fn unexpected_root() {
+        let violations = validate_part(HEADER_PATH, &section(""));
+        assert_eq!(
+            kinds(&violations),
+            [&ViolationKind::UnexpectedRoot {
+                expected: "head".to_string(),
+                found: "sec".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn parts_without_schema_are_skipped() {
+        assert!(validate_part("settings.xml", "<not-a-schema/>").is_empty());
+        assert!(validate_part("Contents/content.hpf", "<package/>").is_empty());
+    }
+
+    #[test]
+    fn display_includes_part_and_path() {
+        let xml = VERSION_XML.replace(r#" major="5""#, "");
+        let violations = validate_part(VERSION_PATH, &xml);
+        assert_eq!(
+            violations[0].to_string(),
+            r#"version.xml:/HCFVersion: missing required attribute "major""#
+        );
+    }
+
+    #[test]
+    fn converted_body_is_valid() {
+        let mut ir_doc = ir::Document::new();
+        let mut section = ir::Section::new();
+        section.add_paragraph(ir::Paragraph::with_text("첫 문단"));
+        section.add_paragraph(ir::Paragraph::with_text("둘째 문단"));
+        ir_doc.add_section(section);
+        let document = ir_doc.to_hwpx().unwrap().value;
+
+        let violations = hwpx::validate(&document).unwrap();
+        assert!(
+            violations.is_empty(),
+            "변환한 패키지는 스키마를 만족해야 함: {:#?}",
+            violations
+        );
+    }
+}
@@ -0,0 +1,145 @@
+//! settings.xml 파싱/생성 테스트
+
+use hwpx::Document;
+use hwpx::convert::{HwpxToIr, IrToHwpx};
+use hwpx::settings::{ApplicationSetting, CaretPosition};
+
+const SETTINGS_XML: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes" ?><ha:HWPApplicationSetting xmlns:ha="http://www.owpml.org/owpml/2024/app" xmlns:config="urn:oasis:names:tc:opendocument:xmlns:config:1.0"><ha:CaretPosition listIDRef="0" paraIDRef="12" pos="6"/><config:config-item-set config:name="PrintInfo"><config:config-item config:name="PrintAutoFootNote" config:type="boolean">false</config:config-item><config:config-item config:name="PrintMethod" config:type="short">0</config:config-item></config:config-item-set></ha:HWPApplicationSetting>"#;
+
+mod parse_tests {
+    use super::*;
+
+    #[test]
+    fn parse_caret_position() {
+        let settings: ApplicationSetting = hwpx::from_str(SETTINGS_XML).unwrap();
+
+        assert_eq!(
+            settings.caret_position,
+            Some(CaretPosition {
+                list_id_reference: 0,
+                paragraph_id_reference: 12,
+                position: 6,
+            })
+        );
+    }
+
+    #[test]
+    fn parse_config_items() {
+        let settings: ApplicationSetting = hwpx::from_str(SETTINGS_XML).unwrap();
+
+        let print_info = settings.config_item_set("PrintInfo").unwrap();
+        assert_eq!(print_info.items.len(), 2);
+
+        let method = print_info.item("PrintMethod").unwrap();
+        assert_eq!(method.item_type, "short");
+        assert_eq!(method.value, "0");
+    }
+
+    #[test]
+    fn parse_empty_settings() {
+        let xml = r#"<ha:HWPApplicationSetting xmlns:ha="http://www.owpml.org/owpml/2024/app"/>"#;
+        let settings: ApplicationSetting = hwpx::from_str(xml).unwrap();
+
+        assert_eq!(settings, ApplicationSetting::default());
+    }
+}
+
+mod serialize_tests {
+    use super::*;
+
+    #[test]
+    fn serialize_roundtrip() {
+        let settings: ApplicationSetting = hwpx::from_str(SETTINGS_XML).unwrap();
+
+        let xml = hwpx::to_string(&settings).unwrap();
+        assert!(xml.starts_with("<ha:HWPApplicationSetting>"));
+        assert!(xml.contains(r#"config:name="PrintInfo""#));
+
+        let reparsed: ApplicationSetting = hwpx::from_str(&xml).unwrap();
+        assert_eq!(reparsed, settings);
+    }
+}
+
+mod conversion_tests {
+    use super::*;
+
+    fn document_with_caret(section: u32, paragraph: u32, position: u32) -> ir::Document {
+        let mut ir_doc = ir::Document::new();
+        let mut ir_section = ir::Section::new();
+        for text in ["a", "b", "c", "d"] {
+            ir_section.add_paragraph(ir::Paragraph::with_text(text));
+        }
+        ir_doc.add_section(ir_section);
+        ir_doc.settings.caret_position = Some(ir::CaretPosition {
+            section,
+            paragraph,
+            position,
+        });
+        ir_doc
+    }
+
+    #[test]
+    fn caret_position_roundtrip_through_package() {
+        let hwpx_doc = document_with_caret(0, 3, 17).to_hwpx().unwrap().value;
+        let bytes = hwpx_doc.to_bytes().unwrap();
+
+        let parsed = Document::from_bytes(&bytes).unwrap();
+        assert!(parsed.content.item("settings").is_some());
+
+        let caret = parsed
+            .to_ir()
+            .unwrap()
+            .value
+            .settings
+            .caret_position
+            .unwrap();
+        assert_eq!((caret.section, caret.paragraph, caret.position), (0, 3, 17));
+    }
+
+    #[test]
+    fn caret_paragraph_id_maps_to_index() {
+        // 문단 아이디가 인덱스와 다르면 IR에는 인덱스로, HWPX에는 다시 아이디로 기록
+        let mut ir_doc = document_with_caret(0, 1, 2);
+        for (para, id) in ir_doc.sections[0]
+            .paragraphs
+            .iter_mut()
+            .zip([7, 12, 30, 31])
+        {
+            para.instance_id = Some(id);
+        }
+        let hwpx_doc = ir_doc.to_hwpx().unwrap().value;
+        let caret = hwpx_doc.settings.as_ref().unwrap().caret_position.unwrap();
+        assert_eq!(
+            (caret.list_id_reference, caret.paragraph_id_reference),
+            (0, 12)
+        );
+
+        let parsed = Document::from_bytes(&hwpx_doc.to_bytes().unwrap()).unwrap();
+        let caret = parsed
+            .to_ir()
+            .unwrap()
+            .value
+            .settings
+            .caret_position
+            .unwrap();
+        assert_eq!((caret.section, caret.paragraph, caret.position), (0, 1, 2));
+    }
+
+    #[test]
+    fn no_settings_without_caret_position() {
+        let mut ir_doc = ir::Document::new();
+        ir_doc.add_section(ir::Section::new());
+
+        let hwpx_doc = ir_doc.to_hwpx().unwrap().value;
+        assert!(hwpx_doc.settings.is_none());
+        assert!(
+            hwpx_doc
+                .to_ir()
+                .unwrap()
+                .value
+                .settings
+                .caret_position
+                .is_none()
+        );
+    }
+}
@@ -0,0 +1,115 @@
+//! 구역 XML 스트리밍 읽기/쓰기 테스트
+
+use std::io::{BufReader, Cursor, Read};
+
+use hwpx::Error;
+use hwpx::convert::IrToHwpx;
+use hwpx::paragraph::{Paragraph, ParagraphReader, ParagraphWriter, Section};
+
+/// 변환기가 기록한 구역 XML
+fn converted_section_xml() -> String {
+    let mut ir_doc = ir::Document::new();
+    let mut section = ir::Section::new();
+    section.add_paragraph(ir::Paragraph::with_text("첫 문단"));
+    section.add_paragraph(ir::Paragraph::with_text("a < b & c"));
+    section.add_paragraph(ir::Paragraph::with_text("셋째 문단"));
+    ir_doc.add_section(section);
+    let bytes = ir_doc.to_hwpx().unwrap().value.to_bytes().unwrap();
+
+    let mut archive = zip::ZipArchive::new(Cursor::new(bytes)).unwrap();
+    let mut xml = String::new();
+    archive
+        .by_name("Contents/section0.xml")
+        .unwrap()
+        .read_to_string(&mut xml)
+        .unwrap();
+    xml
+}
+
+fn read_all(xml: &str) -> Vec<Paragraph> {
+    ParagraphReader::new(xml.as_bytes())
+        .collect::<Result<_, _>>()
+        .unwrap()
+}
+
+mod reader_tests {
+    use super::*;
+
+    #[test]
+    fn matches_full_section_parse() {
+        let xml = converted_section_xml();
+        let section: Section = hwpx::from_str(&xml).unwrap();
+
+        assert_eq!(read_all(&xml), section.paragraphs);
+    }
+
+    #[test]
+    fn small_buffer_reads_same_paragraphs() {
+        let xml = converted_section_xml();
+        let reader = ParagraphReader::new(BufReader::with_capacity(7, xml.as_bytes()));
+        let paragraphs: Vec<_> = reader.collect::<Result<_, _>>().unwrap();
+
+        assert_eq!(paragraphs, read_all(&xml));
+    }
+
+    #[test]
+    fn prefixed_section() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?><hs:sec xmlns:hs="http://www.hancom.co.kr/hwpml/2011/section" xmlns:hp="http://www.hancom.co.kr/hwpml/2011/paragraph"><hp:p id="0" paraPrIDRef="0"><hp:run charPrIDRef="0"><hp:t>가나다</hp:t></hp:run></hp:p><hp:p id="1"/></hs:sec>"#;
+        let paragraphs = read_all(xml);
+
+        assert_eq!(paragraphs.len(), 2);
+        assert_eq!(paragraphs[0].id, 0);
+        assert_eq!(paragraphs[0].runs.len(), 1);
+        assert_eq!(paragraphs[1].id, 1);
+        assert!(paragraphs[1].runs.is_empty());
+    }
+
+    #[test]
+    fn skips_other_children() {
+        // 다른 요소 안의 문단은 구역의 문단이 아님
+        let xml = r#"<sec><p id="0"/><ext><p id="9"/></ext><p id="1"/></sec>"#;
+        let ids: Vec<_> = read_all(xml).iter().map(|paragraph| paragraph.id).collect();
+
+        assert_eq!(ids, [0, 1]);
+    }
+
+    #[test]
+    fn truncated_section_reports_error_once() {
+        let xml = converted_section_xml();
+        let truncated = &xml[..xml.rfind("<p ").unwrap() + 20];
+        let mut reader = ParagraphReader::new(truncated.as_bytes()).with_part("section0.xml");
+
+        assert!(reader.next().unwrap().is_ok());
+        assert!(reader.next().unwrap().is_ok());
+        match reader.next() {
+            Some(Err(Error::Deserialize { part, .. })) => assert_eq!(part, "section0.xml"),
+            other => panic!("잘린 문단은 역직렬화 오류여야 함: {:?}", other),
+        }
+        assert!(reader.next().is_none(), "오류 뒤에는 끝나야 함");
+    }
+}
+
+mod writer_tests {
+    use super::*;
+
+    #[test]
+    fn matches_package_section_part() {
+        let xml = converted_section_xml();
+        let mut writer = ParagraphWriter::new(Vec::new()).unwrap();
+        for paragraph in ParagraphReader::new(xml.as_bytes()) {
+            writer.write(&paragraph.unwrap()).unwrap();
+        }
+        let written = String::from_utf8(writer.finish().unwrap()).unwrap();
+
+        assert_eq!(written, xml);
+    }
+
+    #[test]
+    fn empty_section_round_trip() {
+        let written = ParagraphWriter::new(Vec::new()).unwrap().finish().unwrap();
+        let xml = String::from_utf8(written).unwrap();
+
+        assert!(xml.ends_with("<sec></sec>"));
+        assert!(read_all(&xml).is_empty());
+    }
+}
@@ -247,6 +247,8 @@ pub struct Font {
     pub is_embedded: bool,
     /// 바이너리 항목 참조 (임베디드 폰트 데이터)
     pub binary_item_id_ref: Option<primitive::BinaryDataId>,
+    /// 폰트가 속한 언어 그룹 (`FontRef` 아이디는 언어 그룹 안의 순서)
+    pub language: Option<LanguageType>,
 }
 
 impl Font {
@@ -262,6 +264,7 @@ impl Font {
             default_font_name: None,
             is_embedded: false,
             binary_item_id_ref: None,
+            language: None,
         }
     }
 }
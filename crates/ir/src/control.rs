@@ -2,6 +2,7 @@
 //!
 //! 문단 내에 삽입되는 표, 그림, 도형 등의 객체를 정의합니다.
 
+use crate::equation;
 use crate::paragraph::Paragraph;
 use crate::picture::Picture;
 use crate::shape::Shape;
@@ -184,6 +185,41 @@ pub struct Equation {
     pub properties: Option<u32>,
 }
 
+impl Equation {
+    /// 한글 수식 스크립트를 구문 트리로 파싱합니다.
+    ///
+    /// 스크립트 형식이 [`EquationFormat::HwpScript`]가 아니면 `None`을 반환합니다.
+    pub fn parse_script(&self) -> Option<Result<equation::Formula, equation::ParseError>> {
+        (self.format == EquationFormat::HwpScript).then(|| equation::parse(&self.script))
+    }
+
+    /// 수식을 MathML로 변환합니다.
+    ///
+    /// MathML 형식이면 스크립트를 그대로, 한글 수식 스크립트이면 파싱해 변환한
+    /// 결과를 반환합니다. 그 밖의 형식은 `None`입니다.
+    pub fn to_mathml(&self) -> Option<Result<String, equation::ParseError>> {
+        match self.format {
+            EquationFormat::MathML => Some(Ok(self.script.clone())),
+            _ => self
+                .parse_script()
+                .map(|formula| formula.map(|formula| formula.to_mathml())),
+        }
+    }
+
+    /// 수식을 LaTeX 수식 모드 내용으로 변환합니다.
+    ///
+    /// LaTeX 형식이면 스크립트를 그대로, 한글 수식 스크립트이면 파싱해 변환한
+    /// 결과를 반환합니다. 그 밖의 형식은 `None`입니다.
+    pub fn to_latex(&self) -> Option<Result<String, equation::ParseError>> {
+        match self.format {
+            EquationFormat::LaTeX => Some(Ok(self.script.clone())),
+            _ => self
+                .parse_script()
+                .map(|formula| formula.map(|formula| formula.to_latex())),
+        }
+    }
+}
+
 /// OLE 객체
 #[derive(Debug, Clone)]
 pub struct OleObject {
@@ -334,6 +370,46 @@ pub struct Chart {
     pub chart_id: String,
     /// 차트 종류
     pub chart_type: ChartType,
+    /// 차트 제목
+    pub title: Option<String>,
+    /// 축
+    pub axes: Vec<ChartAxis>,
+    /// 항목 이름 (가로 축 레이블)
+    pub categories: Vec<String>,
+    /// 계열
+    pub series: Vec<ChartSeries>,
+}
+
+/// 차트 축
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ChartAxis {
+    /// 축 종류
+    pub axis_type: ChartAxisType,
+    /// 축 제목
+    pub title: Option<String>,
+}
+
+/// 차트 축 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ChartAxisType {
+    /// 항목 축
+    #[default]
+    Category,
+    /// 값 축
+    Value,
+    /// 날짜 축
+    Date,
+    /// 계열 축 (3차원 차트의 깊이 축)
+    Series,
+}
+
+/// 차트 계열
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ChartSeries {
+    /// 계열 이름
+    pub name: Option<String>,
+    /// 값 (값이 없는 항목은 NaN)
+    pub values: Vec<f64>,
 }
 
 /// 비디오
@@ -3,10 +3,18 @@
 //! IR 문서의 루트 타입을 정의합니다.
 
 use crate::binary::BinaryDataStore;
+use crate::control::Control;
 use crate::extensions::Extensions;
 use crate::metadata::Metadata;
+use crate::paragraph::{Paragraph, RunContent};
 use crate::section::Section;
 use crate::style::StyleStore;
+use crate::table::Table;
+
+/// 미리보기 텍스트 최대 길이 (UTF-16 코드 단위)
+///
+/// 한글은 문서 앞부분을 이 길이까지만 `PrvText`에 기록합니다.
+pub const PREVIEW_TEXT_MAX_LEN: usize = 1024;
 
 /// IR 문서
 ///
@@ -56,6 +64,90 @@ impl Document {
         }
         text
     }
+
+    /// 미리보기 텍스트 생성 (HWP `PrvText` 스트림, HWPX `Preview/PrvText.txt`)
+    ///
+    /// 한글이 기록하는 형식을 따라 문단마다 `\r\n`으로 줄을 끝내고, 표는 행마다 한 줄에
+    /// 셀 텍스트를 `<...>`로 감싸 기록합니다. [`PREVIEW_TEXT_MAX_LEN`]을 넘는 부분은 버립니다.
+    pub fn to_preview_text(&self) -> String {
+        let mut preview = PreviewText::default();
+        for para in self.sections.iter().flat_map(|section| &section.paragraphs) {
+            if preview.is_full() {
+                break;
+            }
+            preview.push_paragraph(para);
+        }
+        preview.text
+    }
+}
+
+/// 길이 제한이 있는 미리보기 텍스트
+#[derive(Default)]
+struct PreviewText {
+    text: String,
+    /// UTF-16 코드 단위 길이
+    len: usize,
+}
+
+impl PreviewText {
+    fn is_full(&self) -> bool {
+        self.len >= PREVIEW_TEXT_MAX_LEN
+    }
+
+    fn push_paragraph(&mut self, para: &Paragraph) {
+        let mut line = String::new();
+        let mut tables = Vec::new();
+        for content in para.runs.iter().flat_map(|run| &run.contents) {
+            match content {
+                RunContent::Text(text) => line.push_str(&text.text),
+                RunContent::Tab(_) => line.push('\t'),
+                RunContent::LineBreak
+                | RunContent::NonBreakingSpace
+                | RunContent::FixedWidthSpace => line.push(' '),
+                RunContent::Hyphen => line.push('-'),
+                RunContent::Control(control) => {
+                    if let Control::Table(table) = control.as_ref() {
+                        tables.push(table.as_ref());
+                    }
+                }
+                _ => {}
+            }
+        }
+        self.push_line(&line);
+        for table in tables {
+            self.push_table(table);
+        }
+    }
+
+    fn push_table(&mut self, table: &Table) {
+        for row in &table.rows {
+            let line: String = row
+                .cells
+                .iter()
+                .map(|cell| {
+                    let text: Vec<_> = cell
+                        .paragraphs
+                        .iter()
+                        .map(Paragraph::to_plain_text)
+                        .collect();
+                    format!("<{}>", text.join(" "))
+                })
+                .collect();
+            self.push_line(&line);
+        }
+    }
+
+    fn push_line(&mut self, line: &str) {
+        for ch in line.chars().chain("\r\n".chars()) {
+            let len = self.len + ch.len_utf16();
+            if len > PREVIEW_TEXT_MAX_LEN {
+                self.len = PREVIEW_TEXT_MAX_LEN;
+                return;
+            }
+            self.text.push(ch);
+            self.len = len;
+        }
+    }
 }
 
 /// 문서 설정
@@ -0,0 +1,466 @@
+//! 수식 구문 트리
+//!
+//! [`parse`](super::parse)가 만드는 노드와 노드 종류를 정의합니다.
+//! 모든 노드는 원본 스크립트에서의 위치([`Span`])를 가집니다.
+
+/// 스크립트 내 위치 (바이트 오프셋, `start..end`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Span {
+    /// 시작 오프셋
+    pub start: usize,
+    /// 끝 오프셋 (포함하지 않음)
+    pub end: usize,
+}
+
+impl Span {
+    /// 위치 생성
+    pub const fn new(start: usize, end: usize) -> Self {
+        Self { start, end }
+    }
+
+    /// 두 위치를 모두 덮는 위치
+    pub const fn to(self, other: Span) -> Span {
+        Span::new(self.start, other.end)
+    }
+
+    /// 길이 (바이트)
+    pub const fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    /// 비어 있는지 여부
+    pub const fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+}
+
+/// 수식 전체
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Formula {
+    /// 최상위 항목들
+    pub nodes: Vec<Node>,
+    /// 스크립트 전체 위치
+    pub span: Span,
+}
+
+/// 구문 트리 노드
+#[derive(Debug, Clone, PartialEq)]
+pub struct Node {
+    /// 노드 종류
+    pub kind: NodeKind,
+    /// 원본 위치
+    pub span: Span,
+}
+
+impl Node {
+    /// 노드 생성
+    pub const fn new(kind: NodeKind, span: Span) -> Self {
+        Self { kind, span }
+    }
+}
+
+/// 표 형태 구성의 칸 (항목 목록)
+pub type Cell = Vec<Node>;
+
+/// 노드 종류
+#[derive(Debug, Clone, PartialEq)]
+pub enum NodeKind {
+    /// 변수 등 일반 낱말 (기본 이탤릭체)
+    Identifier(String),
+    /// 숫자
+    Number(String),
+    /// 따옴표로 묶은 낱말
+    Text(String),
+    /// 그리스 문자, 기호 명령어, 기호 연산자 (`->`, `<=` 등)
+    Symbol(Symbol),
+    /// 명령어가 아닌 문자 하나 (`+`, `(`, `=` 등)
+    Operator(char),
+    /// 로만체로 표시되는 기본 함수와 예약어 (`sin`, `lim`, `det` 등)
+    Function(&'static str),
+    /// 큰 연산자 (적분, 총합, 집합 등)
+    BigOperator(BigOperator),
+    /// 빈칸 (`~`, `` ` ``)
+    Space(SpaceKind),
+    /// 줄 바꾸기 (`#`)
+    LineBreak,
+    /// 세로 칸 맞춤 (`&`)
+    Align,
+    /// 글꼴 전환 (`rm`, `it`, `bold`); 묶음 끝까지 적용
+    Font(FontStyle),
+    /// 글자 크기 비율 (`scale`); 묶음 끝까지 적용
+    Scale(u32),
+    /// 묶음 (`{ }`) 또는 여러 항목으로 된 피연산자
+    Group(Vec<Node>),
+    /// 첨자 (`_`, `^`, `sub`, `sup`, `lsub`, `lsup`)
+    Scripts {
+        /// 첨자가 붙는 항목
+        base: Box<Node>,
+        /// 아래 첨자
+        sub: Option<Box<Node>>,
+        /// 위 첨자
+        sup: Option<Box<Node>>,
+        /// 왼쪽 아래 첨자
+        left_sub: Option<Box<Node>>,
+        /// 왼쪽 위 첨자
+        left_sup: Option<Box<Node>>,
+    },
+    /// 큰 연산자의 구간 (`from`, `to`)
+    Limits {
+        /// 구간이 붙는 항목
+        base: Box<Node>,
+        /// 시작 (아래)
+        from: Option<Box<Node>>,
+        /// 끝 (위)
+        to: Option<Box<Node>>,
+    },
+    /// 분수 (`over`, `atop`, `choose`, `binom`)
+    Fraction {
+        /// 분자 (위)
+        numerator: Box<Node>,
+        /// 분모 (아래)
+        denominator: Box<Node>,
+        /// 분수 종류
+        kind: FractionKind,
+    },
+    /// 제곱근과 거듭제곱근 (`sqrt`, `^n sqrt`, `root n of`)
+    Root {
+        /// 거듭제곱 지수
+        index: Option<Box<Node>>,
+        /// 근호 안의 항목
+        radicand: Box<Node>,
+    },
+    /// 글자 장식 (`hat`, `vec`, `bar` 등)
+    Decoration {
+        /// 장식 종류
+        kind: DecorationKind,
+        /// 장식되는 항목
+        body: Box<Node>,
+    },
+    /// 사선 긋기 (`not`)
+    Not(Box<Node>),
+    /// 기호 확대 (`bigg`)
+    Bigg(Box<Node>),
+    /// 관계 화살표 (`rel`, `buildrel`)
+    Relation {
+        /// 화살표 등 가운데 기호
+        arrow: Box<Node>,
+        /// 위쪽 내용
+        over: Box<Node>,
+        /// 아래쪽 내용 (`buildrel`은 없음)
+        under: Option<Box<Node>>,
+    },
+    /// 색상 (`color {r,g,b}`)
+    Color {
+        /// 색상 (R, G, B)
+        rgb: [u8; 3],
+        /// 색상이 적용되는 항목
+        body: Box<Node>,
+    },
+    /// 괄호 (`left ... right`)
+    Fenced {
+        /// 여는 괄호 (`None`은 `.`)
+        open: Option<char>,
+        /// 닫는 괄호 (`None`은 `.`)
+        close: Option<char>,
+        /// 괄호 안의 항목들
+        body: Vec<Node>,
+    },
+    /// 행렬 (`matrix`, `pmatrix`, `bmatrix`, `dmatrix`)
+    Matrix {
+        /// 행렬 괄호 종류
+        kind: MatrixKind,
+        /// 칸 단위(`col`, `lcol`, `rcol`)로 입력한 경우 칸별 맞춤
+        columns: Option<Vec<ColumnAlign>>,
+        /// 줄(행) 목록
+        rows: Vec<Vec<Cell>>,
+    },
+    /// 줄과 칸으로 나뉘는 구성 (`cases`, `pile`, `eqalign`, `ladder` 등)
+    Grid {
+        /// 구성 종류
+        kind: GridKind,
+        /// 줄(`#`)마다 칸(`&`) 목록
+        rows: Vec<Vec<Cell>>,
+    },
+    /// 나눗셈 (`longdiv`)
+    LongDiv {
+        /// 나누는 수
+        divisor: Box<Node>,
+        /// 몫
+        quotient: Box<Node>,
+        /// 계산 과정 줄들
+        rows: Vec<Cell>,
+    },
+}
+
+/// 기호
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Symbol {
+    /// 정규 이름 (예: `alpha`, `LARROW`, `->`)
+    pub name: &'static str,
+    /// 유니코드 문자
+    pub value: char,
+}
+
+/// 빈칸 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpaceKind {
+    /// 정상 빈칸 (`~`)
+    Normal,
+    /// 1/4 빈칸 (`` ` ``)
+    Quarter,
+}
+
+/// 글꼴
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FontStyle {
+    /// 로만체 (`rm`)
+    Roman,
+    /// 이탤릭체 (`it`)
+    Italic,
+    /// 볼드체 (`bold`)
+    Bold,
+}
+
+/// 분수 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FractionKind {
+    /// 가로선이 있는 분수 (`over`)
+    Over,
+    /// 가로선이 없는 위아래 배치 (`atop`)
+    Atop,
+    /// 조합 (`choose`, `binom`)
+    Choose,
+}
+
+/// 큰 연산자
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BigOperator {
+    /// 연산자 종류
+    pub kind: BigOperatorKind,
+    /// 첨자 없는 작은 기호 (`small` 접두사)
+    pub small: bool,
+}
+
+/// 큰 연산자 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BigOperatorKind {
+    /// 적분 (`int`)
+    Integral,
+    /// 이중 적분 (`dint`)
+    DoubleIntegral,
+    /// 삼중 적분 (`tint`)
+    TripleIntegral,
+    /// 선적분 (`oint`)
+    ContourIntegral,
+    /// 이중 선적분 (`odint`)
+    DoubleContourIntegral,
+    /// 삼중 선적분 (`otint`)
+    TripleContourIntegral,
+    /// 총합 (`sum`)
+    Sum,
+    /// 곱 (`prod`)
+    Product,
+    /// 쌍대곱 (`coprod`)
+    Coproduct,
+    /// 합집합 (`union`)
+    Union,
+    /// 교집합 (`inter`)
+    Intersection,
+}
+
+impl BigOperatorKind {
+    /// 명령어 이름
+    pub const fn keyword(self) -> &'static str {
+        match self {
+            Self::Integral => "int",
+            Self::DoubleIntegral => "dint",
+            Self::TripleIntegral => "tint",
+            Self::ContourIntegral => "oint",
+            Self::DoubleContourIntegral => "odint",
+            Self::TripleContourIntegral => "otint",
+            Self::Sum => "sum",
+            Self::Product => "prod",
+            Self::Coproduct => "coprod",
+            Self::Union => "union",
+            Self::Intersection => "inter",
+        }
+    }
+
+    /// 유니코드 기호
+    pub const fn symbol(self) -> char {
+        match self {
+            Self::Integral => '∫',
+            Self::DoubleIntegral => '∬',
+            Self::TripleIntegral => '∭',
+            Self::ContourIntegral => '∮',
+            Self::DoubleContourIntegral => '∯',
+            Self::TripleContourIntegral => '∰',
+            Self::Sum => '∑',
+            Self::Product => '∏',
+            Self::Coproduct => '∐',
+            Self::Union => '⋃',
+            Self::Intersection => '⋂',
+        }
+    }
+
+    /// 첨자 없는 작은 기호 (`small` 접두사)
+    pub const fn small_symbol(self) -> char {
+        match self {
+            Self::Sum => 'Σ',
+            Self::Product => 'Π',
+            Self::Union => '∪',
+            Self::Intersection => '∩',
+            _ => self.symbol(),
+        }
+    }
+
+    pub(crate) fn from_keyword(keyword: &str) -> Option<Self> {
+        Some(match keyword {
+            "int" => Self::Integral,
+            "dint" => Self::DoubleIntegral,
+            "tint" => Self::TripleIntegral,
+            "oint" => Self::ContourIntegral,
+            "odint" => Self::DoubleContourIntegral,
+            "otint" => Self::TripleContourIntegral,
+            "sum" => Self::Sum,
+            "prod" => Self::Product,
+            "coprod" => Self::Coproduct,
+            "union" => Self::Union,
+            "inter" => Self::Intersection,
+            _ => return None,
+        })
+    }
+}
+
+/// 글자 장식 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecorationKind {
+    /// 양음 부호 (`acute`)
+    Acute,
+    /// 억음 부호 (`grave`)
+    Grave,
+    /// 점 (`dot`)
+    Dot,
+    /// 두 점 (`ddot`)
+    DoubleDot,
+    /// 모자 (`hat`)
+    Hat,
+    /// 역모자 (`check`)
+    Check,
+    /// 윗줄 (`bar`)
+    Bar,
+    /// 벡터 화살표 (`vec`)
+    Vec,
+    /// 양방향 화살표 (`dyad`)
+    Dyad,
+    /// 밑줄 (`under`)
+    Under,
+    /// 호 (`arch`)
+    Arch,
+    /// 물결 (`tilde`)
+    Tilde,
+}
+
+impl DecorationKind {
+    /// 명령어 이름
+    pub const fn keyword(self) -> &'static str {
+        match self {
+            Self::Acute => "acute",
+            Self::Grave => "grave",
+            Self::Dot => "dot",
+            Self::DoubleDot => "ddot",
+            Self::Hat => "hat",
+            Self::Check => "check",
+            Self::Bar => "bar",
+            Self::Vec => "vec",
+            Self::Dyad => "dyad",
+            Self::Under => "under",
+            Self::Arch => "arch",
+            Self::Tilde => "tilde",
+        }
+    }
+
+    pub(crate) fn from_keyword(keyword: &str) -> Option<Self> {
+        Some(match keyword {
+            "acute" => Self::Acute,
+            "grave" => Self::Grave,
+            "dot" => Self::Dot,
+            "ddot" => Self::DoubleDot,
+            "hat" => Self::Hat,
+            "check" => Self::Check,
+            "bar" => Self::Bar,
+            "vec" => Self::Vec,
+            "dyad" => Self::Dyad,
+            "under" => Self::Under,
+            "arch" => Self::Arch,
+            "tilde" => Self::Tilde,
+            _ => return None,
+        })
+    }
+}
+
+/// 행렬 괄호 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MatrixKind {
+    /// 괄호 없음 (`matrix`)
+    Plain,
+    /// 소괄호 (`pmatrix`)
+    Paren,
+    /// 대괄호 (`bmatrix`)
+    Bracket,
+    /// 세로줄 (`dmatrix`)
+    Vertical,
+}
+
+impl MatrixKind {
+    /// 명령어 이름
+    pub const fn keyword(self) -> &'static str {
+        match self {
+            Self::Plain => "matrix",
+            Self::Paren => "pmatrix",
+            Self::Bracket => "bmatrix",
+            Self::Vertical => "dmatrix",
+        }
+    }
+}
+
+/// 칸 맞춤
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColumnAlign {
+    /// 가운데 (`col`, `pile`)
+    Center,
+    /// 왼쪽 (`lcol`, `lpile`)
+    Left,
+    /// 오른쪽 (`rcol`, `rpile`)
+    Right,
+}
+
+/// 줄과 칸으로 나뉘는 구성의 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GridKind {
+    /// 경우 묶음 (`cases`)
+    Cases,
+    /// 세로 쌓기 (`pile`, `lpile`, `rpile`)
+    Pile(ColumnAlign),
+    /// `&` 기준 세로 맞춤 (`eqalign`)
+    EqAlign,
+    /// 최소공배수/최대공약수 계산 (`ladder`)
+    Ladder,
+    /// 진법 변환 계산 (`sladder`)
+    SLadder,
+}
+
+impl GridKind {
+    /// 명령어 이름
+    pub const fn keyword(self) -> &'static str {
+        match self {
+            Self::Cases => "cases",
+            Self::Pile(ColumnAlign::Center) => "pile",
+            Self::Pile(ColumnAlign::Left) => "lpile",
+            Self::Pile(ColumnAlign::Right) => "rpile",
+            Self::EqAlign => "eqalign",
+            Self::Ladder => "ladder",
+            Self::SLadder => "sladder",
+        }
+    }
+}
@@ -0,0 +1,641 @@
+//! 구문 트리를 LaTeX으로 변환
+//!
+//! 수식 모드 안의 내용만 만들며 `$`나 `\[`로 감싸지 않습니다. `amsmath`의
+//! 명령(`\binom`, `\genfrac`, `\operatorname`, `aligned`, `cases` 등)과
+//! `xcolor`의 `\textcolor`를 씁니다.
+
+use super::ast::{
+    ColumnAlign, DecorationKind, Formula, FractionKind, GridKind, MatrixKind, Node, NodeKind,
+    SpaceKind,
+};
+use super::render::{self, TextStyle};
+
+impl Formula {
+    /// 수식을 LaTeX 수식 모드 내용으로 변환합니다.
+    pub fn to_latex(&self) -> String {
+        lines(&self.nodes, &mut TextStyle::default())
+    }
+}
+
+/// 줄 바꾸기가 있으면 `aligned`(칸 맞춤이 있을 때) 또는 `gathered`로 씁니다.
+fn lines(nodes: &[Node], style: &mut TextStyle) -> String {
+    let lines = render::lines(nodes);
+    if lines.len() == 1 {
+        return row(nodes, style, false);
+    }
+    let align = nodes
+        .iter()
+        .any(|node| matches!(node.kind, NodeKind::Align));
+    let environment = if align { "aligned" } else { "gathered" };
+    let body = lines
+        .into_iter()
+        .map(|line| row(line, style, align))
+        .collect::<Vec<_>>()
+        .join(" \\\\ ");
+    format!("\\begin{{{0}}} {1} \\end{{{0}}}", environment, body)
+}
+
+/// 묶음 안의 항목들. 글꼴 전환은 묶음이 끝나면 원래대로 돌아갑니다.
+fn group(nodes: &[Node], mut style: TextStyle) -> String {
+    lines(nodes, &mut style)
+}
+
+/// 인자: 묶음이면 괄호를 벗기고 내용만 씁니다.
+fn argument(node: &Node, style: TextStyle) -> String {
+    match &node.kind {
+        NodeKind::Group(nodes) => group(nodes, style),
+        _ => self::node(node, style),
+    }
+}
+
+fn row(nodes: &[Node], style: &mut TextStyle, align: bool) -> String {
+    let mut parts = Vec::new();
+    for node in nodes {
+        match node.kind {
+            NodeKind::Font(font) => style.apply(font),
+            // LaTeX에는 비율로 글자 크기를 바꾸는 명령이 없으므로 무시한다
+            NodeKind::Scale(_) | NodeKind::LineBreak => {}
+            NodeKind::Align => {
+                if align {
+                    parts.push("&".to_string());
+                }
+            }
+            _ => parts.push(self::node(node, *style)),
+        }
+    }
+    parts.join(" ")
+}
+
+fn node(node: &Node, style: TextStyle) -> String {
+    match &node.kind {
+        NodeKind::Identifier(name) if !name.is_ascii() => text(name, style),
+        NodeKind::Identifier(name) => styled(name, style, false),
+        NodeKind::Number(number) => styled(number, style, true),
+        NodeKind::Text(content) => text(content, style),
+        NodeKind::Symbol(symbol) => symbol_latex(symbol.value),
+        NodeKind::Operator(c) => symbol_latex(*c),
+        NodeKind::Function(name) => function(name),
+        NodeKind::BigOperator(op) if op.small => symbol_latex(op.kind.small_symbol()),
+        NodeKind::BigOperator(op) => symbol_latex(op.kind.symbol()),
+        NodeKind::Space(SpaceKind::Normal) => "\\ ".to_string(),
+        NodeKind::Space(SpaceKind::Quarter) => "\\,".to_string(),
+        NodeKind::LineBreak | NodeKind::Align | NodeKind::Font(_) | NodeKind::Scale(_) => {
+            String::new()
+        }
+        NodeKind::Group(nodes) => format!("{{{}}}", group(nodes, style)),
+        NodeKind::Scripts {
+            base,
+            sub,
+            sup,
+            left_sub,
+            left_sup,
+        } => {
+            let mut out = String::new();
+            if left_sub.is_some() || left_sup.is_some() {
+                out.push_str("{}");
+                push_scripts(&mut out, left_sub, left_sup, style);
+            }
+            out.push_str(&script_base(base, style));
+            push_scripts(&mut out, sub, sup, style);
+            out
+        }
+        NodeKind::Limits { base, from, to } => {
+            let mut out = match &base.kind {
+                NodeKind::BigOperator(_) => format!("{}\\limits", self::node(base, style)),
+                NodeKind::Function(_) => self::node(base, style),
+                _ => format!("\\mathop{{{}}}\\limits", argument(base, style)),
+            };
+            push_scripts(&mut out, from, to, style);
+            out
+        }
+        NodeKind::Fraction {
+            numerator,
+            denominator,
+            kind,
+        } => {
+            let (numerator, denominator) =
+                (argument(numerator, style), argument(denominator, style));
+            match kind {
+                FractionKind::Over => format!("\\frac{{{}}}{{{}}}", numerator, denominator),
+                FractionKind::Atop => {
+                    format!(
+                        "\\genfrac{{}}{{}}{{0pt}}{{}}{{{}}}{{{}}}",
+                        numerator, denominator
+                    )
+                }
+                FractionKind::Choose => format!("\\binom{{{}}}{{{}}}", numerator, denominator),
+            }
+        }
+        NodeKind::Root { index, radicand } => match index {
+            Some(index) => format!(
+                "\\sqrt[{}]{{{}}}",
+                argument(index, style),
+                argument(radicand, style)
+            ),
+            None => format!("\\sqrt{{{}}}", argument(radicand, style)),
+        },
+        NodeKind::Decoration { kind, body } => {
+            let single = render::is_single_char(body);
+            let body = argument(body, style);
+            match kind {
+                DecorationKind::Arch => format!("\\overset{{\\frown}}{{{}}}", body),
+                kind => format!("{}{{{}}}", decoration(*kind, single), body),
+            }
+        }
+        NodeKind::Not(body) => match render::negated(body) {
+            Some(c) => symbol_latex(c),
+            None => format!("\\not{{{}}}", argument(body, style)),
+        },
+        NodeKind::Bigg(body) => match &body.kind {
+            NodeKind::Operator(c) if delimiter(Some(*c)).is_some() => {
+                format!("\\bigg{}", delimiter(Some(*c)).unwrap_or_default())
+            }
+            NodeKind::Symbol(symbol) if delimiter(Some(symbol.value)).is_some() => {
+                format!(
+                    "\\bigg{}",
+                    delimiter(Some(symbol.value)).unwrap_or_default()
+                )
+            }
+            // 괄호가 아닌 항목은 \bigg를 쓸 수 없으므로 그대로 쓴다
+            _ => argument(body, style),
+        },
+        NodeKind::Relation { arrow, over, under } => {
+            let arrow = format!(
+                "\\overset{{{}}}{{{}}}",
+                argument(over, style),
+                argument(arrow, style)
+            );
+            match under {
+                Some(under) => format!(
+                    "\\mathrel{{\\underset{{{}}}{{{}}}}}",
+                    argument(under, style),
+                    arrow
+                ),
+                None => format!("\\mathrel{{{}}}", arrow),
+            }
+        }
+        NodeKind::Color { rgb, body } => format!(
+            "\\textcolor[RGB]{{{},{},{}}}{{{}}}",
+            rgb[0],
+            rgb[1],
+            rgb[2],
+            argument(body, style)
+        ),
+        NodeKind::Fenced { open, close, body } => format!(
+            "\\left{} {} \\right{}",
+            delimiter(*open).unwrap_or("."),
+            group(body, style),
+            delimiter(*close).unwrap_or(".")
+        ),
+        NodeKind::Matrix {
+            kind,
+            columns: None,
+            rows,
+        } => {
+            let environment = match kind {
+                MatrixKind::Plain => "matrix",
+                MatrixKind::Paren => "pmatrix",
+                MatrixKind::Bracket => "bmatrix",
+                MatrixKind::Vertical => "vmatrix",
+            };
+            environment_block(environment, "", &grid(rows, style))
+        }
+        NodeKind::Matrix {
+            kind,
+            columns: Some(columns),
+            rows,
+        } => {
+            let (open, close) = match kind {
+                MatrixKind::Plain => (".", "."),
+                MatrixKind::Paren => ("(", ")"),
+                MatrixKind::Bracket => ("[", "]"),
+                MatrixKind::Vertical => ("|", "|"),
+            };
+            let spec: String = columns.iter().map(|align| align_char(*align)).collect();
+            format!(
+                "\\left{} {} \\right{}",
+                open,
+                environment_block("array", &format!("{{{}}}", spec), &grid(rows, style)),
+                close
+            )
+        }
+        NodeKind::Grid { kind, rows } => match kind {
+            GridKind::Cases => environment_block("cases", "", &grid(rows, style)),
+            GridKind::EqAlign => environment_block("aligned", "", &grid(rows, style)),
+            GridKind::Pile(align) => {
+                let body = rows
+                    .iter()
+                    .map(|cells| row(&cells.concat(), &mut { style }, false))
+                    .collect::<Vec<_>>()
+                    .join(" \\\\ ");
+                environment_block("array", &format!("{{{}}}", align_char(*align)), &body)
+            }
+            // 사다리 계산식은 첫 칸 뒤의 세로선과 줄 사이 가로선을 가진 array로 나타낸다
+            GridKind::Ladder | GridKind::SLadder => {
+                let width = rows.iter().map(Vec::len).max().unwrap_or(1).max(1);
+                let spec = format!("{{r|{}}}", "r".repeat(width - 1));
+                let body = rows
+                    .iter()
+                    .map(|cells| grid_row(cells, style))
+                    .collect::<Vec<_>>()
+                    .join(" \\\\ \\hline ");
+                environment_block("array", &spec, &body)
+            }
+        },
+        NodeKind::LongDiv {
+            divisor,
+            quotient,
+            rows,
+        } => {
+            let mut lines = vec![argument(quotient, style)];
+            let mut rows = rows.iter();
+            let dividend = rows
+                .next()
+                .map(|cells| row(cells, &mut { style }, false))
+                .unwrap_or_default();
+            lines.push(format!(
+                "{} \\overline{{\\smash{{)}}\\, {}}}",
+                argument(divisor, style),
+                dividend
+            ));
+            lines.extend(rows.map(|cells| row(cells, &mut { style }, false)));
+            environment_block("array", "{r}", &lines.join(" \\\\ "))
+        }
+    }
+}
+
+fn environment_block(environment: &str, spec: &str, body: &str) -> String {
+    format!(
+        "\\begin{{{0}}}{1} {2} \\end{{{0}}}",
+        environment, spec, body
+    )
+}
+
+fn grid(rows: &[Vec<Vec<Node>>], style: TextStyle) -> String {
+    rows.iter()
+        .map(|cells| grid_row(cells, style))
+        .collect::<Vec<_>>()
+        .join(" \\\\ ")
+}
+
+fn grid_row(cells: &[Vec<Node>], style: TextStyle) -> String {
+    cells
+        .iter()
+        .map(|cell| row(cell, &mut { style }, false))
+        .collect::<Vec<_>>()
+        .join(" & ")
+}
+
+fn push_scripts(
+    out: &mut String,
+    sub: &Option<Box<Node>>,
+    sup: &Option<Box<Node>>,
+    style: TextStyle,
+) {
+    if let Some(sub) = sub {
+        out.push_str(&format!("_{{{}}}", argument(sub, style)));
+    }
+    if let Some(sup) = sup {
+        out.push_str(&format!("^{{{}}}", argument(sup, style)));
+    }
+}
+
+/// 첨자가 붙는 항목. 첨자가 겹치지 않도록 구성 노드는 묶는다.
+fn script_base(base: &Node, style: TextStyle) -> String {
+    match &base.kind {
+        NodeKind::Identifier(_)
+        | NodeKind::Number(_)
+        | NodeKind::Symbol(_)
+        | NodeKind::Operator(_)
+        | NodeKind::Function(_)
+        | NodeKind::BigOperator(_)
+        | NodeKind::Group(_) => node(base, style),
+        _ => format!("{{{}}}", node(base, style)),
+    }
+}
+
+fn styled(content: &str, style: TextStyle, number: bool) -> String {
+    // 숫자는 원래 똑바로 세운 글꼴이므로 로만체를 따로 표시하지 않는다
+    match (style.roman || number, style.bold) {
+        (true, true) => format!("\\mathbf{{{}}}", content),
+        (false, true) => format!("\\boldsymbol{{{}}}", content),
+        (true, false) if !number => format!("\\mathrm{{{}}}", content),
+        _ => content.to_string(),
+    }
+}
+
+fn text(content: &str, style: TextStyle) -> String {
+    let mut escaped = String::new();
+    for c in content.chars() {
+        match c {
+            '\\' => escaped.push_str("\\textbackslash{}"),
+            '{' | '}' | '$' | '&' | '#' | '%' | '_' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            '^' => escaped.push_str("\\^{}"),
+            '~' => escaped.push_str("\\~{}"),
+            c => escaped.push(c),
+        }
+    }
+    if style.bold {
+        format!("\\textbf{{{}}}", escaped)
+    } else {
+        format!("\\text{{{}}}", escaped)
+    }
+}
+
+/// 기본 함수: LaTeX에 있는 연산자 이름은 그대로, 나머지는 `\operatorname`
+fn function(name: &str) -> String {
+    match name {
+        "sin" | "cos" | "tan" | "cot" | "sec" | "csc" | "arcsin" | "arccos" | "arctan" | "sinh"
+        | "cosh" | "tanh" | "coth" | "log" | "ln" | "lg" | "exp" | "det" | "gcd" | "lim"
+        | "max" | "min" | "ker" | "deg" | "arg" | "dim" | "hom" | "Pr" => {
+            format!("\\{}", name)
+        }
+        "Lim" => "\\operatorname*{Lim}".to_string(),
+        "mod" => "\\bmod".to_string(),
+        name => format!("\\operatorname{{{}}}", name),
+    }
+}
+
+fn decoration(kind: DecorationKind, single: bool) -> &'static str {
+    match (kind, single) {
+        (DecorationKind::Acute, _) => "\\acute",
+        (DecorationKind::Grave, _) => "\\grave",
+        (DecorationKind::Dot, _) => "\\dot",
+        (DecorationKind::DoubleDot, _) => "\\ddot",
+        (DecorationKind::Check, _) => "\\check",
+        (DecorationKind::Hat, true) => "\\hat",
+        (DecorationKind::Hat, false) => "\\widehat",
+        (DecorationKind::Tilde, true) => "\\tilde",
+        (DecorationKind::Tilde, false) => "\\widetilde",
+        (DecorationKind::Bar, true) => "\\bar",
+        (DecorationKind::Bar, false) => "\\overline",
+        (DecorationKind::Vec, true) => "\\vec",
+        (DecorationKind::Vec, false) => "\\overrightarrow",
+        (DecorationKind::Dyad, _) => "\\overleftrightarrow",
+        (DecorationKind::Under, _) => "\\underline",
+        (DecorationKind::Arch, _) => "\\overset{\\frown}",
+    }
+}
+
+fn align_char(align: ColumnAlign) -> char {
+    match align {
+        ColumnAlign::Center => 'c',
+        ColumnAlign::Left => 'l',
+        ColumnAlign::Right => 'r',
+    }
+}
+
+/// `\left`, `\right`, `\bigg` 뒤에 쓸 수 있는 괄호
+fn delimiter(c: Option<char>) -> Option<&'static str> {
+    Some(match c? {
+        '(' => "(",
+        ')' => ")",
+        '[' => "[",
+        ']' => "]",
+        '{' => "\\{",
+        '}' => "\\}",
+        '|' => "|",
+        '‖' => "\\|",
+        '/' => "/",
+        '\\' | '⧹' => "\\backslash",
+        '<' | '⟨' => "\\langle",
+        '>' | '⟩' => "\\rangle",
+        '⌈' => "\\lceil",
+        '⌉' => "\\rceil",
+        '⌊' => "\\lfloor",
+        '⌋' => "\\rfloor",
+        '↑' => "\\uparrow",
+        '↓' => "\\downarrow",
+        '↕' => "\\updownarrow",
+        '⇑' => "\\Uparrow",
+        '⇓' => "\\Downarrow",
+        '⇕' => "\\Updownarrow",
+        _ => return None,
+    })
+}
+
+/// 문자 하나의 LaTeX 표현. 해당 명령이 없는 문자는 유니코드 그대로 쓴다.
+fn symbol_latex(c: char) -> String {
+    let command = match c {
+        // 그리스 문자
+        'α' => "\\alpha",
+        'β' => "\\beta",
+        'γ' => "\\gamma",
+        'δ' => "\\delta",
+        'ϵ' => "\\epsilon",
+        'ε' => "\\varepsilon",
+        'ζ' => "\\zeta",
+        'η' => "\\eta",
+        'θ' => "\\theta",
+        'ϑ' => "\\vartheta",
+        'ι' => "\\iota",
+        'κ' => "\\kappa",
+        'λ' => "\\lambda",
+        'μ' => "\\mu",
+        'ν' => "\\nu",
+        'ξ' => "\\xi",
+        'ο' => "o",
+        'π' => "\\pi",
+        'ϖ' => "\\varpi",
+        'ρ' => "\\rho",
+        'σ' => "\\sigma",
+        'ς' => "\\varsigma",
+        'τ' => "\\tau",
+        'υ' => "\\upsilon",
+        'ϕ' => "\\phi",
+        'φ' => "\\varphi",
+        'χ' => "\\chi",
+        'ψ' => "\\psi",
+        'ω' => "\\omega",
+        'Α' => "\\mathrm{A}",
+        'Β' => "\\mathrm{B}",
+        'Γ' => "\\Gamma",
+        'Δ' => "\\Delta",
+        'Ε' => "\\mathrm{E}",
+        'Ζ' => "\\mathrm{Z}",
+        'Η' => "\\mathrm{H}",
+        'Θ' => "\\Theta",
+        'Ι' => "\\mathrm{I}",
+        'Κ' => "\\mathrm{K}",
+        'Λ' => "\\Lambda",
+        'Μ' => "\\mathrm{M}",
+        'Ν' => "\\mathrm{N}",
+        'Ξ' => "\\Xi",
+        'Ο' => "\\mathrm{O}",
+        'Π' => "\\Pi",
+        'Ρ' => "\\mathrm{P}",
+        'Σ' => "\\Sigma",
+        'Τ' => "\\mathrm{T}",
+        'Υ' | 'ϒ' => "\\Upsilon",
+        'Φ' => "\\Phi",
+        'Χ' => "\\mathrm{X}",
+        'Ψ' => "\\Psi",
+        'Ω' => "\\Omega",
+        'ℵ' => "\\aleph",
+        'ℏ' => "\\hbar",
+        'ı' => "\\imath",
+        'ȷ' => "\\jmath",
+        'ℓ' => "\\ell",
+        '℘' => "\\wp",
+        'ℑ' => "\\Im",
+        'ℜ' => "\\Re",
+        'Å' => "\\mathring{\\mathrm{A}}",
+        // 합/집합 기호
+        '∩' => "\\cap",
+        '∪' => "\\cup",
+        '⊓' => "\\sqcap",
+        '⊔' => "\\sqcup",
+        '⊕' => "\\oplus",
+        '⊖' => "\\ominus",
+        '⊗' => "\\otimes",
+        '⊙' => "\\odot",
+        '⊘' => "\\oslash",
+        '∨' => "\\vee",
+        '∧' => "\\wedge",
+        '⊂' => "\\subset",
+        '⊃' => "\\supset",
+        '⊆' => "\\subseteq",
+        '⊇' => "\\supseteq",
+        '∈' => "\\in",
+        '∋' => "\\ni",
+        '∉' => "\\notin",
+        '≤' => "\\leq",
+        '≥' => "\\geq",
+        '⊏' => "\\sqsubset",
+        '⊐' => "\\sqsupset",
+        '⊑' => "\\sqsubseteq",
+        '⊒' => "\\sqsupseteq",
+        '≪' => "\\ll",
+        '≫' => "\\gg",
+        '⋘' => "\\lll",
+        '⋙' => "\\ggg",
+        '≺' => "\\prec",
+        '≻' => "\\succ",
+        '⊎' => "\\uplus",
+        // 큰 연산자
+        '∫' => "\\int",
+        '∬' => "\\iint",
+        '∭' => "\\iiint",
+        '∮' => "\\oint",
+        '∑' => "\\sum",
+        '∏' => "\\prod",
+        '∐' => "\\coprod",
+        '⋃' => "\\bigcup",
+        '⋂' => "\\bigcap",
+        // 연산/논리 기호
+        '±' => "\\pm",
+        '∓' => "\\mp",
+        '×' => "\\times",
+        '÷' => "\\div",
+        '∘' => "\\circ",
+        '•' => "\\bullet",
+        '°' => "^{\\circ}",
+        '∗' => "\\ast",
+        '⋆' => "\\star",
+        '◯' => "\\bigcirc",
+        '∅' => "\\emptyset",
+        '∴' => "\\therefore",
+        '∵' => "\\because",
+        '≡' => "\\equiv",
+        '∃' => "\\exists",
+        '≠' => "\\neq",
+        '≐' => "\\doteq",
+        '∼' => "\\sim",
+        '≈' => "\\approx",
+        '≃' => "\\simeq",
+        '≅' => "\\cong",
+        '≍' => "\\asymp",
+        '◇' => "\\diamond",
+        '∀' => "\\forall",
+        '′' => "\\prime",
+        '∂' => "\\partial",
+        '∞' => "\\infty",
+        '¬' => "\\neg",
+        '∝' => "\\propto",
+        '⊻' => "\\veebar",
+        '△' => "\\triangle",
+        '▽' => "\\triangledown",
+        '†' => "\\dagger",
+        '‡' => "\\ddagger",
+        // 부정 기호
+        '≮' => "\\nless",
+        '≯' => "\\ngtr",
+        '≰' => "\\nleq",
+        '≱' => "\\ngeq",
+        '∌' => "\\not\\ni",
+        '⊄' => "\\not\\subset",
+        '⊅' => "\\not\\supset",
+        '⊈' => "\\nsubseteq",
+        '⊉' => "\\nsupseteq",
+        '≢' => "\\not\\equiv",
+        '≁' => "\\nsim",
+        '≉' => "\\not\\approx",
+        '≇' => "\\ncong",
+        '∄' => "\\nexists",
+        '⊀' => "\\nprec",
+        '⊁' => "\\nsucc",
+        '∤' => "\\nmid",
+        '∦' => "\\nparallel",
+        // 화살표
+        '←' => "\\leftarrow",
+        '→' => "\\rightarrow",
+        '↑' => "\\uparrow",
+        '↓' => "\\downarrow",
+        '⇐' => "\\Leftarrow",
+        '⇒' => "\\Rightarrow",
+        '⇑' => "\\Uparrow",
+        '⇓' => "\\Downarrow",
+        '↕' => "\\updownarrow",
+        '↔' => "\\leftrightarrow",
+        '⇕' => "\\Updownarrow",
+        '⇔' => "\\Leftrightarrow",
+        '↖' => "\\nwarrow",
+        '↘' => "\\searrow",
+        '↗' => "\\nearrow",
+        '↙' => "\\swarrow",
+        '↩' => "\\hookleftarrow",
+        '↪' => "\\hookrightarrow",
+        '↦' => "\\mapsto",
+        '|' => "\\vert",
+        '‖' => "\\Vert",
+        // 기타 기호
+        '⋯' => "\\cdots",
+        '…' => "\\ldots",
+        '⋮' => "\\vdots",
+        '⋱' => "\\ddots",
+        '∠' => "\\angle",
+        '∡' => "\\measuredangle",
+        '∢' => "\\sphericalangle",
+        '⊢' => "\\vdash",
+        '⊣' => "\\dashv",
+        '⊥' => "\\bot",
+        '⊤' => "\\top",
+        '⊨' => "\\models",
+        '∇' => "\\nabla",
+        '℃' => "{}^{\\circ}\\mathrm{C}",
+        '℉' => "{}^{\\circ}\\mathrm{F}",
+        '⧸' => "/",
+        '⧹' => "\\backslash",
+        '⟨' => "\\langle",
+        '⟩' => "\\rangle",
+        '⌈' => "\\lceil",
+        '⌉' => "\\rceil",
+        '⌊' => "\\lfloor",
+        '⌋' => "\\rfloor",
+        '{' => "\\{",
+        '}' => "\\}",
+        '\\' => "\\backslash",
+        '%' => "\\%",
+        '$' => "\\$",
+        '#' => "\\#",
+        '&' => "\\&",
+        '_' => "\\_",
+        '^' => "\\hat{}",
+        '~' => "\\sim",
+        c => return c.to_string(),
+    };
+    command.to_string()
+}
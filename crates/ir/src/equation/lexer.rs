@@ -0,0 +1,146 @@
+//! 수식 스크립트 토큰화
+//!
+//! 빈칸, 탭, 줄 바꿈은 토큰을 나누기만 하고 토큰으로 남지 않습니다.
+//! 대신 각 토큰은 앞에 빈칸이 있었는지([`Token::spaced`])를 기억합니다.
+//! 수식 편집기는 빈칸으로 항을 구분하므로 파서가 이 정보를 사용합니다.
+
+use super::ast::Span;
+use super::parser::{ParseError, ParseErrorKind};
+
+/// 여러 글자로 된 기호 연산자 (긴 것부터)
+const OPERATOR_SEQUENCES: &[&str] = &[
+    "<->", "<<<", ">>>", "->", "<-", "<=", ">=", "!=", "==", "<<", ">>", "+-", "-+",
+];
+
+/// 토큰 종류
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TokenKind {
+    /// 영문자 또는 한글 등 문자로 된 낱말 (명령어 포함)
+    Word(String),
+    /// 숫자 (소수점 포함)
+    Number(String),
+    /// 따옴표로 묶은 낱말 (따옴표 제외)
+    Quoted(String),
+    /// 여러 글자로 된 기호 연산자 (`->`, `<=` 등)
+    Sequence(&'static str),
+    /// 그 밖의 문자 하나
+    Char(char),
+    /// `{`
+    OpenBrace,
+    /// `}`
+    CloseBrace,
+    /// `^`
+    Caret,
+    /// `_`
+    Underscore,
+    /// `~`
+    Tilde,
+    /// `` ` ``
+    Backtick,
+    /// `#`
+    Hash,
+    /// `&`
+    Ampersand,
+}
+
+/// 토큰
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Token {
+    /// 토큰 종류
+    pub kind: TokenKind,
+    /// 원본 위치
+    pub span: Span,
+    /// 앞에 빈칸(공백, 탭, 줄 바꿈)이 있었는지 여부
+    pub spaced: bool,
+}
+
+/// 수식 스크립트를 토큰으로 나눕니다.
+pub fn tokenize(script: &str) -> Result<Vec<Token>, ParseError> {
+    let mut tokens = Vec::new();
+    let mut chars = script.char_indices().peekable();
+    let mut spaced = false;
+
+    while let Some(&(start, c)) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+            spaced = true;
+            continue;
+        }
+
+        let kind = if c.is_ascii_digit() {
+            let mut end = start;
+            let mut seen_dot = false;
+            while let Some(&(i, c)) = chars.peek() {
+                let is_fraction_dot = c == '.'
+                    && !seen_dot
+                    && script[i + 1..].starts_with(|c: char| c.is_ascii_digit());
+                if !c.is_ascii_digit() && !is_fraction_dot {
+                    break;
+                }
+                seen_dot |= c == '.';
+                end = i + c.len_utf8();
+                chars.next();
+            }
+            TokenKind::Number(script[start..end].to_string())
+        } else if c.is_alphabetic() {
+            // 영문 낱말과 한글 등 그 밖의 문자 낱말은 서로 나눈다
+            let ascii = c.is_ascii();
+            let mut end = start;
+            while let Some(&(i, c)) = chars.peek() {
+                if !c.is_alphabetic() || c.is_ascii() != ascii {
+                    break;
+                }
+                end = i + c.len_utf8();
+                chars.next();
+            }
+            TokenKind::Word(script[start..end].to_string())
+        } else if c == '"' {
+            chars.next();
+            let mut text = String::new();
+            loop {
+                match chars.next() {
+                    Some((_, '"')) => break,
+                    Some((_, c)) => text.push(c),
+                    None => {
+                        return Err(ParseError::new(
+                            ParseErrorKind::UnterminatedQuote,
+                            Span::new(start, script.len()),
+                        ));
+                    }
+                }
+            }
+            TokenKind::Quoted(text)
+        } else if let Some(sequence) = OPERATOR_SEQUENCES
+            .iter()
+            .find(|sequence| script[start..].starts_with(**sequence))
+        {
+            for _ in 0..sequence.len() {
+                chars.next();
+            }
+            TokenKind::Sequence(sequence)
+        } else {
+            chars.next();
+            match c {
+                '{' => TokenKind::OpenBrace,
+                '}' => TokenKind::CloseBrace,
+                '^' => TokenKind::Caret,
+                '_' => TokenKind::Underscore,
+                '~' => TokenKind::Tilde,
+                '`' => TokenKind::Backtick,
+                '#' => TokenKind::Hash,
+                '&' => TokenKind::Ampersand,
+                c => TokenKind::Char(c),
+            }
+        };
+
+        let end = chars.peek().map_or(script.len(), |&(i, _)| i);
+        tokens.push(Token {
+            kind,
+            span: Span::new(start, end),
+            spaced,
+        });
+        spaced = false;
+    }
+
+    Ok(tokens)
+}
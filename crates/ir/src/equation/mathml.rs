@@ -0,0 +1,480 @@
+//! 구문 트리를 MathML(표현 형식)로 변환
+//!
+//! 결과는 `<math>` 요소 하나이며 MathML Core와 MathML 3의 표현 요소만
+//! 씁니다. 항목 사이 간격은 연산자 사전에 맡깁니다.
+
+use std::fmt::Write;
+
+use super::ast::{
+    BigOperatorKind, ColumnAlign, DecorationKind, Formula, FractionKind, GridKind, MatrixKind,
+    Node, NodeKind, SpaceKind,
+};
+use super::render::{self, TextStyle};
+
+const NAMESPACE: &str = "http://www.w3.org/1998/Math/MathML";
+
+impl Formula {
+    /// 수식을 MathML `<math>` 요소로 변환합니다.
+    pub fn to_mathml(&self) -> String {
+        format!(
+            "<math xmlns=\"{}\">{}</math>",
+            NAMESPACE,
+            lines(&self.nodes, &mut TextStyle::default())
+        )
+    }
+}
+
+/// 줄 바꾸기가 있으면 한 줄을 한 행으로 하는 `mtable`로 씁니다.
+/// 칸 맞춤(`&`)이 있으면 칸마다 오른쪽/왼쪽 정렬을 번갈아 줍니다.
+fn lines(nodes: &[Node], style: &mut TextStyle) -> String {
+    let lines = render::lines(nodes);
+    if lines.len() == 1 {
+        return row(nodes, style);
+    }
+    let align = nodes
+        .iter()
+        .any(|node| matches!(node.kind, NodeKind::Align));
+    let mut out = String::from(if align {
+        "<mtable columnalign=\"right left\" columnspacing=\"0\">"
+    } else {
+        "<mtable>"
+    });
+    for line in lines {
+        out.push_str("<mtr>");
+        let cells: Vec<&[Node]> = if align {
+            line.split(|node| matches!(node.kind, NodeKind::Align))
+                .collect()
+        } else {
+            vec![line]
+        };
+        for cell in cells {
+            let _ = write!(out, "<mtd>{}</mtd>", row(cell, style));
+        }
+        out.push_str("</mtr>");
+    }
+    out.push_str("</mtable>");
+    out
+}
+
+/// 묶음 안의 항목들. 글꼴 전환은 묶음이 끝나면 원래대로 돌아갑니다.
+fn group(nodes: &[Node], mut style: TextStyle) -> String {
+    format!("<mrow>{}</mrow>", lines(nodes, &mut style))
+}
+
+/// 인자: 요소 하나가 되도록 묶음은 `mrow`로 씁니다.
+fn argument(node: &Node, style: TextStyle) -> String {
+    match &node.kind {
+        NodeKind::Group(nodes) => group(nodes, style),
+        _ => self::node(node, style),
+    }
+}
+
+/// `scale`은 줄이 끝날 때까지 `mstyle mathsize`로 감쌉니다.
+fn row(nodes: &[Node], style: &mut TextStyle) -> String {
+    let mut out = String::new();
+    let mut scales = 0;
+    for node in nodes {
+        match node.kind {
+            NodeKind::Font(font) => style.apply(font),
+            NodeKind::Scale(percent) => {
+                let _ = write!(out, "<mstyle mathsize=\"{}%\">", percent);
+                scales += 1;
+            }
+            NodeKind::LineBreak | NodeKind::Align => {}
+            _ => out.push_str(&self::node(node, *style)),
+        }
+    }
+    for _ in 0..scales {
+        out.push_str("</mstyle>");
+    }
+    out
+}
+
+fn node(node: &Node, style: TextStyle) -> String {
+    match &node.kind {
+        NodeKind::Identifier(name) if !name.is_ascii() => text(name, style),
+        NodeKind::Identifier(name) => identifier(name, style),
+        NodeKind::Number(number) if style.bold => {
+            format!("<mn mathvariant=\"bold\">{}</mn>", escape(number))
+        }
+        NodeKind::Number(number) => format!("<mn>{}</mn>", escape(number)),
+        NodeKind::Text(content) => text(content, style),
+        NodeKind::Symbol(symbol) => symbol_element(symbol.value),
+        NodeKind::Operator(c) => operator(*c),
+        NodeKind::Function(name) => format!("<mi>{}</mi>", name),
+        NodeKind::BigOperator(op) if op.small => {
+            format!("<mo largeop=\"false\">{}</mo>", op.kind.small_symbol())
+        }
+        NodeKind::BigOperator(op) => format!("<mo largeop=\"true\">{}</mo>", op.kind.symbol()),
+        NodeKind::Space(SpaceKind::Normal) => "<mspace width=\"0.333em\"/>".to_string(),
+        NodeKind::Space(SpaceKind::Quarter) => "<mspace width=\"0.167em\"/>".to_string(),
+        NodeKind::LineBreak | NodeKind::Align | NodeKind::Font(_) | NodeKind::Scale(_) => {
+            String::new()
+        }
+        NodeKind::Group(nodes) => group(nodes, style),
+        NodeKind::Scripts {
+            base,
+            sub,
+            sup,
+            left_sub,
+            left_sup,
+        } => {
+            let base_element = argument(base, style);
+            if left_sub.is_some() || left_sup.is_some() {
+                return format!(
+                    "<mmultiscripts>{}{}{}<mprescripts/>{}{}</mmultiscripts>",
+                    base_element,
+                    script(sub, style),
+                    script(sup, style),
+                    script(left_sub, style),
+                    script(left_sup, style)
+                );
+            }
+            if has_limits(base) {
+                under_over(base_element, sub, sup, style)
+            } else {
+                match (sub, sup) {
+                    (Some(sub), Some(sup)) => format!(
+                        "<msubsup>{}{}{}</msubsup>",
+                        base_element,
+                        argument(sub, style),
+                        argument(sup, style)
+                    ),
+                    (Some(sub), None) => {
+                        format!("<msub>{}{}</msub>", base_element, argument(sub, style))
+                    }
+                    (None, Some(sup)) => {
+                        format!("<msup>{}{}</msup>", base_element, argument(sup, style))
+                    }
+                    (None, None) => base_element,
+                }
+            }
+        }
+        NodeKind::Limits { base, from, to } => under_over(argument(base, style), from, to, style),
+        NodeKind::Fraction {
+            numerator,
+            denominator,
+            kind,
+        } => {
+            let (numerator, denominator) =
+                (argument(numerator, style), argument(denominator, style));
+            match kind {
+                FractionKind::Over => format!("<mfrac>{}{}</mfrac>", numerator, denominator),
+                FractionKind::Atop => format!(
+                    "<mfrac linethickness=\"0\">{}{}</mfrac>",
+                    numerator, denominator
+                ),
+                FractionKind::Choose => format!(
+                    "<mrow><mo>(</mo><mfrac linethickness=\"0\">{}{}</mfrac><mo>)</mo></mrow>",
+                    numerator, denominator
+                ),
+            }
+        }
+        NodeKind::Root { index, radicand } => match index {
+            Some(index) => format!(
+                "<mroot>{}{}</mroot>",
+                argument(radicand, style),
+                argument(index, style)
+            ),
+            None => format!("<msqrt>{}</msqrt>", argument(radicand, style)),
+        },
+        NodeKind::Decoration { kind, body } => {
+            let stretchy = if render::is_single_char(body) {
+                "false"
+            } else {
+                "true"
+            };
+            let body = argument(body, style);
+            match kind {
+                DecorationKind::Under => format!(
+                    "<munder accentunder=\"true\">{}<mo stretchy=\"true\">_</mo></munder>",
+                    body
+                ),
+                kind => format!(
+                    "<mover accent=\"true\">{}<mo stretchy=\"{}\">{}</mo></mover>",
+                    body,
+                    stretchy,
+                    accent(*kind)
+                ),
+            }
+        }
+        NodeKind::Not(body) => match render::negated(body) {
+            Some(c) => operator(c),
+            None => format!(
+                "<menclose notation=\"updiagonalstrike\">{}</menclose>",
+                argument(body, style)
+            ),
+        },
+        NodeKind::Bigg(body) => match body.kind {
+            NodeKind::Operator(c) => big_delimiter(c),
+            NodeKind::Symbol(symbol) => big_delimiter(symbol.value),
+            _ => argument(body, style),
+        },
+        NodeKind::Relation { arrow, over, under } => match under {
+            Some(under) => format!(
+                "<munderover>{}{}{}</munderover>",
+                argument(arrow, style),
+                argument(under, style),
+                argument(over, style)
+            ),
+            None => format!(
+                "<mover>{}{}</mover>",
+                argument(arrow, style),
+                argument(over, style)
+            ),
+        },
+        NodeKind::Color { rgb, body } => format!(
+            "<mstyle mathcolor=\"#{:02X}{:02X}{:02X}\">{}</mstyle>",
+            rgb[0],
+            rgb[1],
+            rgb[2],
+            argument(body, style)
+        ),
+        NodeKind::Fenced { open, close, body } => {
+            let mut out = String::from("<mrow>");
+            if let Some(open) = open {
+                out.push_str(&fence(*open));
+            }
+            out.push_str(&group(body, style));
+            if let Some(close) = close {
+                out.push_str(&fence(*close));
+            }
+            out.push_str("</mrow>");
+            out
+        }
+        NodeKind::Matrix {
+            kind,
+            columns,
+            rows,
+        } => {
+            let align = columns.as_ref().map(|columns| {
+                columns
+                    .iter()
+                    .map(|align| align_name(*align))
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            });
+            let table = table(rows, align.as_deref(), None, style);
+            match kind {
+                MatrixKind::Plain => table,
+                MatrixKind::Paren => fenced('(', ')', &table),
+                MatrixKind::Bracket => fenced('[', ']', &table),
+                MatrixKind::Vertical => fenced('|', '|', &table),
+            }
+        }
+        NodeKind::Grid { kind, rows } => match kind {
+            GridKind::Cases => format!(
+                "<mrow>{}{}</mrow>",
+                fence('{'),
+                table(rows, Some("left"), None, style)
+            ),
+            GridKind::EqAlign => table(rows, Some("right left"), None, style),
+            GridKind::Pile(align) => {
+                let rows: Vec<Vec<Vec<Node>>> =
+                    rows.iter().map(|cells| vec![cells.concat()]).collect();
+                table(&rows, Some(align_name(*align)), None, style)
+            }
+            // 사다리 계산식은 첫 칸 뒤의 세로선과 줄 사이 가로선을 가진 표로 나타낸다
+            GridKind::Ladder | GridKind::SLadder => table(
+                rows,
+                Some("right"),
+                Some("columnlines=\"solid none\" rowlines=\"solid\""),
+                style,
+            ),
+        },
+        NodeKind::LongDiv {
+            divisor,
+            quotient,
+            rows,
+        } => {
+            let mut out = String::from("<mtable columnalign=\"right\">");
+            let _ = write!(out, "<mtr><mtd>{}</mtd></mtr>", argument(quotient, style));
+            let mut rows = rows.iter();
+            let dividend = rows
+                .next()
+                .map(|cells| row(cells, &mut { style }))
+                .unwrap_or_default();
+            let _ = write!(
+                out,
+                "<mtr><mtd><mrow>{}<menclose notation=\"longdiv\">{}</menclose></mrow></mtd></mtr>",
+                argument(divisor, style),
+                dividend
+            );
+            for cells in rows {
+                let _ = write!(out, "<mtr><mtd>{}</mtd></mtr>", row(cells, &mut { style }));
+            }
+            out.push_str("</mtable>");
+            out
+        }
+    }
+}
+
+fn table(
+    rows: &[Vec<Vec<Node>>],
+    align: Option<&str>,
+    attributes: Option<&str>,
+    style: TextStyle,
+) -> String {
+    let mut out = String::from("<mtable");
+    if let Some(align) = align {
+        let _ = write!(out, " columnalign=\"{}\"", align);
+    }
+    if let Some(attributes) = attributes {
+        let _ = write!(out, " {}", attributes);
+    }
+    out.push('>');
+    for cells in rows {
+        out.push_str("<mtr>");
+        for cell in cells {
+            let _ = write!(out, "<mtd>{}</mtd>", row(cell, &mut { style }));
+        }
+        out.push_str("</mtr>");
+    }
+    out.push_str("</mtable>");
+    out
+}
+
+fn fenced(open: char, close: char, body: &str) -> String {
+    format!("<mrow>{}{}{}</mrow>", fence(open), body, fence(close))
+}
+
+fn fence(c: char) -> String {
+    format!(
+        "<mo fence=\"true\" stretchy=\"true\">{}</mo>",
+        escape(&c.to_string())
+    )
+}
+
+fn big_delimiter(c: char) -> String {
+    format!(
+        "<mo minsize=\"2.4em\" maxsize=\"2.4em\">{}</mo>",
+        escape(&c.to_string())
+    )
+}
+
+/// 첨자 자리. 비어 있으면 `<none/>`을 씁니다.
+fn script(node: &Option<Box<Node>>, style: TextStyle) -> String {
+    match node {
+        Some(node) => argument(node, style),
+        None => "<none/>".to_string(),
+    }
+}
+
+fn under_over(
+    base: String,
+    under: &Option<Box<Node>>,
+    over: &Option<Box<Node>>,
+    style: TextStyle,
+) -> String {
+    match (under, over) {
+        (Some(under), Some(over)) => format!(
+            "<munderover>{}{}{}</munderover>",
+            base,
+            argument(under, style),
+            argument(over, style)
+        ),
+        (Some(under), None) => format!("<munder>{}{}</munder>", base, argument(under, style)),
+        (None, Some(over)) => format!("<mover>{}{}</mover>", base, argument(over, style)),
+        (None, None) => base,
+    }
+}
+
+/// 첨자를 위아래에 두는 항목: `lim`과 적분이 아닌 큰 연산자
+fn has_limits(base: &Node) -> bool {
+    match &base.kind {
+        NodeKind::Function(name) => matches!(*name, "lim" | "Lim"),
+        NodeKind::BigOperator(op) => {
+            !op.small
+                && !matches!(
+                    op.kind,
+                    BigOperatorKind::Integral
+                        | BigOperatorKind::DoubleIntegral
+                        | BigOperatorKind::TripleIntegral
+                        | BigOperatorKind::ContourIntegral
+                        | BigOperatorKind::DoubleContourIntegral
+                        | BigOperatorKind::TripleContourIntegral
+                )
+        }
+        _ => false,
+    }
+}
+
+/// 한글 수식은 모든 변수를 기울여 쓰므로 여러 글자 변수도 `italic`을 줍니다.
+fn identifier(name: &str, style: TextStyle) -> String {
+    let variant = match (style.roman, style.bold) {
+        (false, false) => "italic",
+        (true, false) => "normal",
+        (true, true) => "bold",
+        (false, true) => "bold-italic",
+    };
+    let default = if name.chars().count() == 1 {
+        "italic"
+    } else {
+        "normal"
+    };
+    if variant == default {
+        format!("<mi>{}</mi>", escape(name))
+    } else {
+        format!("<mi mathvariant=\"{}\">{}</mi>", variant, escape(name))
+    }
+}
+
+fn text(content: &str, style: TextStyle) -> String {
+    if style.bold {
+        format!("<mtext mathvariant=\"bold\">{}</mtext>", escape(content))
+    } else {
+        format!("<mtext>{}</mtext>", escape(content))
+    }
+}
+
+fn operator(c: char) -> String {
+    format!("<mo>{}</mo>", escape(&c.to_string()))
+}
+
+/// 글자처럼 쓰이는 기호는 `mi`, 나머지는 `mo`로 씁니다.
+fn symbol_element(c: char) -> String {
+    if c.is_alphabetic() || matches!(c, '∞' | '∂' | '∅' | '∇' | '℃' | '℉') {
+        format!("<mi mathvariant=\"normal\">{}</mi>", c)
+    } else {
+        operator(c)
+    }
+}
+
+fn accent(kind: DecorationKind) -> char {
+    match kind {
+        DecorationKind::Acute => '´',
+        DecorationKind::Grave => '`',
+        DecorationKind::Dot => '˙',
+        DecorationKind::DoubleDot => '¨',
+        DecorationKind::Hat => '^',
+        DecorationKind::Check => 'ˇ',
+        DecorationKind::Bar => '¯',
+        DecorationKind::Vec => '→',
+        DecorationKind::Dyad => '↔',
+        DecorationKind::Under => '_',
+        DecorationKind::Arch => '⌢',
+        DecorationKind::Tilde => '˜',
+    }
+}
+
+fn align_name(align: ColumnAlign) -> &'static str {
+    match align {
+        ColumnAlign::Center => "center",
+        ColumnAlign::Left => "left",
+        ColumnAlign::Right => "right",
+    }
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
@@ -0,0 +1,50 @@
+//! 한글 수식 스크립트
+//!
+//! 수식 컨트롤의 스크립트(예: `{a over b} + sqrt {x^2}`)를 토큰으로 나누고
+//! 구문 트리로 파싱하며, 구문 트리를 다시 정규 스크립트로 출력하거나
+//! MathML([`Formula::to_mathml`])과 LaTeX([`Formula::to_latex`])으로 변환합니다.
+//! 문법은 「한글 문서 파일 형식 - 수식」 문서를 따릅니다.
+//!
+//! HWP와 HWPX 모두 같은 스크립트를 쓰므로 [`Equation`](crate::control::Equation)의
+//! `script`나 각 형식의 수식 스크립트를 그대로 넘기면 됩니다.
+//!
+//! ```
+//! use ir::equation::{self, NodeKind};
+//!
+//! let formula = equation::parse("{a over b} + sqrt {x^2}").unwrap();
+//! assert!(matches!(formula.nodes[2].kind, NodeKind::Root { .. }));
+//! assert_eq!(formula.to_string(), "{a over b} + sqrt {x^2}");
+//! ```
+//!
+//! # 변환
+//!
+//! 대응하는 구성이 없는 항목은 다음처럼 가까운 표현으로 바꿉니다.
+//!
+//! | 항목 | MathML | LaTeX |
+//! |------|--------|-------|
+//! | `scale` | `mstyle mathsize` | 무시 |
+//! | `bigg` 뒤의 괄호가 아닌 항목 | 크기 조절 없이 그대로 | 크기 조절 없이 그대로 |
+//! | `ladder`, `sladder` | 선을 그은 `mtable` | 선을 그은 `array` |
+//! | `longdiv` | `menclose notation="longdiv"` | `\overline`과 `)`로 그린 `array` |
+//! | `arch` | `⌢`를 얹은 `mover` | `\overset{\frown}` |
+//! | LaTeX 명령이 없는 기호 (`∯` 등) | 해당 없음 | 유니코드 문자 그대로 |
+//! | `not` 뒤의 부정형이 없는 항목 | `menclose` 사선 | `\not` |
+//!
+//! 글꼴 전환(`rm`, `it`, `bold`)은 묶음이 끝날 때까지 이어지며, 한글 수식처럼
+//! 여러 글자 변수도 기울여 씁니다.
+
+mod ast;
+mod latex;
+mod lexer;
+mod mathml;
+mod parser;
+mod printer;
+mod render;
+mod symbols;
+
+pub use ast::{
+    BigOperator, BigOperatorKind, Cell, ColumnAlign, DecorationKind, FontStyle, Formula,
+    FractionKind, GridKind, MatrixKind, Node, NodeKind, SpaceKind, Span, Symbol,
+};
+pub use lexer::{Token, TokenKind, tokenize};
+pub use parser::{MAX_DEPTH, ParseError, ParseErrorKind, parse};
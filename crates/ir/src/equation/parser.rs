@@ -0,0 +1,1120 @@
+//! 수식 스크립트 파서
+//!
+//! 수식 편집기 규칙에 따라 빈칸으로 나뉜 토큰 묶음을 하나의 항으로 봅니다.
+//!
+//! - `over`, `atop`, `choose`는 바로 앞의 항과 바로 뒤의 항을 피연산자로
+//!   받습니다. `a+b over c`의 분자는 `a+b`이고, `a + b over c`의 분자는 `b`입니다.
+//! - `_`, `^`(`sub`, `sup`)와 `from`, `to`의 인자도 다음 빈칸 전까지의 항입니다.
+//!   `_`와 `^`는 인자를 끝내므로 `x_i^2`는 아래 첨자 `i`와 위 첨자 `2`를 가집니다.
+//! - `sqrt`, `hat`, `not` 같은 앞 명령의 인자는 다음 한 항목이고, 뒤따르는
+//!   첨자는 명령 전체에 붙습니다.
+//! - `rm`, `it`, `bold`, `scale`은 인자를 받지 않고 묶음 끝까지 적용됩니다.
+//!
+//! 여러 항목으로 된 피연산자는 [`NodeKind::Group`]으로 묶이므로 `{ }`로
+//! 직접 묶은 것과 같은 트리가 됩니다.
+
+use std::fmt;
+
+use super::ast::{
+    BigOperator, BigOperatorKind, Cell, ColumnAlign, DecorationKind, FontStyle, Formula,
+    FractionKind, GridKind, MatrixKind, Node, NodeKind, SpaceKind, Span, Symbol,
+};
+use super::lexer::{Token, TokenKind, tokenize};
+use super::symbols::{self, Entry};
+
+/// 구문 트리의 최대 중첩 깊이
+///
+/// 수식 스크립트는 신뢰할 수 없는 문서에서 오므로, 파서와 출력 단계의
+/// 재귀가 스택을 넘지 않도록 이보다 깊은 스크립트는 [`ParseErrorKind::TooDeep`]로
+/// 거부합니다.
+pub const MAX_DEPTH: usize = 256;
+
+/// 파싱 에러 종류
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// 닫는 따옴표가 없음
+    UnterminatedQuote,
+    /// 닫는 `}`가 없음
+    UnclosedBrace,
+    /// 여는 `{` 없이 `}`가 나옴
+    UnmatchedBrace,
+    /// `left` 뒤에 `right`가 없음
+    MissingRight,
+    /// `left` 없이 `right`가 나옴
+    UnmatchedRight,
+    /// 명령어의 인자가 없음
+    MissingArgument(String),
+    /// 명령어의 인자가 올바르지 않음
+    InvalidArgument(String),
+    /// 항목이 올 자리에 다른 토큰이 나옴
+    UnexpectedToken,
+    /// 중첩이 [`MAX_DEPTH`]보다 깊음
+    TooDeep,
+}
+
+/// 파싱 에러
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// 에러 종류
+    pub kind: ParseErrorKind,
+    /// 에러 위치
+    pub span: Span,
+}
+
+impl ParseError {
+    /// 에러 생성
+    pub const fn new(kind: ParseErrorKind, span: Span) -> Self {
+        Self { kind, span }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            ParseErrorKind::UnterminatedQuote => write!(f, "닫는 따옴표가 없습니다")?,
+            ParseErrorKind::UnclosedBrace => write!(f, "닫는 }}가 없습니다")?,
+            ParseErrorKind::UnmatchedBrace => write!(f, "여는 {{ 없이 }}가 있습니다")?,
+            ParseErrorKind::MissingRight => write!(f, "left에 짝이 되는 right가 없습니다")?,
+            ParseErrorKind::UnmatchedRight => write!(f, "left 없이 right가 있습니다")?,
+            ParseErrorKind::MissingArgument(command) => {
+                write!(f, "{} 명령의 인자가 없습니다", command)?
+            }
+            ParseErrorKind::InvalidArgument(command) => {
+                write!(f, "{} 명령의 인자가 올바르지 않습니다", command)?
+            }
+            ParseErrorKind::UnexpectedToken => write!(f, "항목이 올 자리가 아닙니다")?,
+            ParseErrorKind::TooDeep => write!(f, "중첩이 {}단계보다 깊습니다", MAX_DEPTH)?,
+        }
+        write!(f, " ({}..{})", self.span.start, self.span.end)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// 수식 스크립트를 구문 트리로 파싱합니다.
+pub fn parse(script: &str) -> Result<Formula, ParseError> {
+    let tokens = tokenize(script)?;
+    let mut parser = Parser {
+        tokens,
+        pos: 0,
+        len: script.len(),
+        depth: 0,
+    };
+    let nodes = parser.parse_row(RowEnd::Eof)?;
+    Ok(Formula {
+        nodes,
+        span: Span::new(0, script.len()),
+    })
+}
+
+/// 항목 나열이 끝나는 곳
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RowEnd {
+    /// 스크립트 끝
+    Eof,
+    /// `}`
+    Brace,
+    /// `right`
+    Right,
+}
+
+/// 파서 안에서 쓰는 명령어
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Command {
+    Fraction(FractionKind),
+    Binom,
+    Sqrt,
+    Root,
+    Sub,
+    Sup,
+    LeftSub,
+    LeftSup,
+    From,
+    To,
+    Matrix(MatrixKind),
+    Column(ColumnAlign),
+    Grid(GridKind),
+    LongDiv,
+    Left,
+    Right,
+    Bigg,
+    Not,
+    Rel,
+    BuildRel,
+    Color,
+    Scale,
+    Font(FontStyle),
+    Decoration(DecorationKind),
+    BigOperator(BigOperator),
+}
+
+impl Command {
+    /// 낱말이 명령어이면 명령어를 돌려줍니다 (대소문자 무시).
+    fn from_word(word: &str) -> Option<Self> {
+        let lower = word.to_ascii_lowercase();
+        let command = match lower.as_str() {
+            "over" => Self::Fraction(FractionKind::Over),
+            "atop" => Self::Fraction(FractionKind::Atop),
+            "choose" => Self::Fraction(FractionKind::Choose),
+            "binom" => Self::Binom,
+            "sqrt" => Self::Sqrt,
+            "root" => Self::Root,
+            "sub" => Self::Sub,
+            "sup" => Self::Sup,
+            "lsub" => Self::LeftSub,
+            "lsup" => Self::LeftSup,
+            "from" => Self::From,
+            "to" => Self::To,
+            "matrix" => Self::Matrix(MatrixKind::Plain),
+            "pmatrix" => Self::Matrix(MatrixKind::Paren),
+            "bmatrix" => Self::Matrix(MatrixKind::Bracket),
+            "dmatrix" => Self::Matrix(MatrixKind::Vertical),
+            "col" => Self::Column(ColumnAlign::Center),
+            "lcol" => Self::Column(ColumnAlign::Left),
+            "rcol" => Self::Column(ColumnAlign::Right),
+            "cases" => Self::Grid(GridKind::Cases),
+            "pile" => Self::Grid(GridKind::Pile(ColumnAlign::Center)),
+            "lpile" => Self::Grid(GridKind::Pile(ColumnAlign::Left)),
+            "rpile" => Self::Grid(GridKind::Pile(ColumnAlign::Right)),
+            "eqalign" => Self::Grid(GridKind::EqAlign),
+            "ladder" => Self::Grid(GridKind::Ladder),
+            "sladder" => Self::Grid(GridKind::SLadder),
+            "longdiv" => Self::LongDiv,
+            "left" => Self::Left,
+            "right" => Self::Right,
+            "bigg" => Self::Bigg,
+            "not" => Self::Not,
+            "rel" => Self::Rel,
+            "buildrel" => Self::BuildRel,
+            "color" => Self::Color,
+            "scale" => Self::Scale,
+            "rm" => Self::Font(FontStyle::Roman),
+            "it" => Self::Font(FontStyle::Italic),
+            "bold" => Self::Font(FontStyle::Bold),
+            other => {
+                if let Some(kind) = DecorationKind::from_keyword(other) {
+                    Self::Decoration(kind)
+                } else if let Some(kind) = BigOperatorKind::from_keyword(other) {
+                    Self::BigOperator(BigOperator { kind, small: false })
+                } else {
+                    let kind = BigOperatorKind::from_keyword(other.strip_prefix("small")?)?;
+                    Self::BigOperator(BigOperator { kind, small: true })
+                }
+            }
+        };
+        Some(command)
+    }
+
+    /// 에러 메시지에 쓰는 이름
+    fn name(self) -> &'static str {
+        match self {
+            Self::Fraction(FractionKind::Over) => "over",
+            Self::Fraction(FractionKind::Atop) => "atop",
+            Self::Fraction(FractionKind::Choose) => "choose",
+            Self::Binom => "binom",
+            Self::Sqrt => "sqrt",
+            Self::Root => "root",
+            Self::Sub => "sub",
+            Self::Sup => "sup",
+            Self::LeftSub => "lsub",
+            Self::LeftSup => "lsup",
+            Self::From => "from",
+            Self::To => "to",
+            Self::Matrix(kind) => kind.keyword(),
+            Self::Column(ColumnAlign::Center) => "col",
+            Self::Column(ColumnAlign::Left) => "lcol",
+            Self::Column(ColumnAlign::Right) => "rcol",
+            Self::Grid(kind) => kind.keyword(),
+            Self::LongDiv => "longdiv",
+            Self::Left => "left",
+            Self::Right => "right",
+            Self::Bigg => "bigg",
+            Self::Not => "not",
+            Self::Rel => "rel",
+            Self::BuildRel => "buildrel",
+            Self::Color => "color",
+            Self::Scale => "scale",
+            Self::Font(FontStyle::Roman) => "rm",
+            Self::Font(FontStyle::Italic) => "it",
+            Self::Font(FontStyle::Bold) => "bold",
+            Self::Decoration(kind) => kind.keyword(),
+            Self::BigOperator(op) => op.kind.keyword(),
+        }
+    }
+}
+
+/// 첨자 자리
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ScriptSlot {
+    Sub,
+    Sup,
+    LeftSub,
+    LeftSup,
+    From,
+    To,
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+    /// 스크립트 길이 (끝 위치 에러용)
+    len: usize,
+    /// 읽고 있는 항목의 중첩 깊이
+    depth: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned()?;
+        self.pos += 1;
+        Some(token)
+    }
+
+    fn peek_command(&self) -> Option<Command> {
+        match &self.peek()?.kind {
+            TokenKind::Word(word) => Command::from_word(word),
+            _ => None,
+        }
+    }
+
+    /// 다음 토큰 위치, 없으면 스크립트 끝
+    fn peek_span(&self) -> Span {
+        self.peek()
+            .map_or(Span::new(self.len, self.len), |token| token.span)
+    }
+
+    /// 마지막으로 읽은 토큰의 끝 위치
+    fn last_end(&self) -> usize {
+        self.pos
+            .checked_sub(1)
+            .and_then(|pos| self.tokens.get(pos))
+            .map_or(0, |token| token.span.end)
+    }
+
+    /// 반복으로 쌓인 노드(분수, 첨자)를 넣어도 최대 깊이를 넘지 않는지 확인합니다.
+    ///
+    /// 재귀 없이 깊어지는 노드라 [`Parser::parse_primary`]의 깊이 검사만으로는
+    /// 막을 수 없습니다.
+    fn check_depth(&self, node: Node) -> Result<Node, ParseError> {
+        if self.depth + height(&node) > MAX_DEPTH {
+            return Err(ParseError::new(ParseErrorKind::TooDeep, node.span));
+        }
+        Ok(node)
+    }
+
+    fn missing(&self, command: Command) -> ParseError {
+        ParseError::new(
+            ParseErrorKind::MissingArgument(command.name().to_string()),
+            self.peek_span(),
+        )
+    }
+
+    /// 다음 토큰이 빈칸 없이 이어지는 항목의 시작인지 여부
+    fn continues_term(&self) -> bool {
+        let Some(token) = self.peek() else {
+            return false;
+        };
+        if token.spaced {
+            return false;
+        }
+        match &token.kind {
+            TokenKind::CloseBrace
+            | TokenKind::Caret
+            | TokenKind::Underscore
+            | TokenKind::Tilde
+            | TokenKind::Backtick
+            | TokenKind::Hash
+            | TokenKind::Ampersand => false,
+            TokenKind::Word(word) => !matches!(
+                Command::from_word(word),
+                Some(
+                    Command::Fraction(_)
+                        | Command::Sub
+                        | Command::Sup
+                        | Command::LeftSub
+                        | Command::LeftSup
+                        | Command::From
+                        | Command::To
+                        | Command::Right
+                        | Command::Column(_)
+                )
+            ),
+            _ => true,
+        }
+    }
+
+    /// 줄 끝(`end`)까지 항목들을 읽습니다. 끝 토큰은 소비하지 않습니다.
+    fn parse_row(&mut self, end: RowEnd) -> Result<Vec<Node>, ParseError> {
+        let mut nodes: Vec<Node> = Vec::new();
+        // 현재 항이 시작된 nodes 위치
+        let mut term_start = 0;
+
+        while let Some(token) = self.peek() {
+            let span = token.span;
+            let spaced = token.spaced;
+            let separator = match token.kind {
+                TokenKind::CloseBrace => {
+                    return match end {
+                        RowEnd::Brace => Ok(nodes),
+                        RowEnd::Right => Err(ParseError::new(ParseErrorKind::MissingRight, span)),
+                        RowEnd::Eof => Err(ParseError::new(ParseErrorKind::UnmatchedBrace, span)),
+                    };
+                }
+                TokenKind::Tilde => Some(NodeKind::Space(SpaceKind::Normal)),
+                TokenKind::Backtick => Some(NodeKind::Space(SpaceKind::Quarter)),
+                TokenKind::Hash => Some(NodeKind::LineBreak),
+                TokenKind::Ampersand => Some(NodeKind::Align),
+                _ => None,
+            };
+            if let Some(kind) = separator {
+                self.pos += 1;
+                nodes.push(Node::new(kind, span));
+                term_start = nodes.len();
+                continue;
+            }
+
+            match self.peek_command() {
+                Some(Command::Right) => {
+                    return match end {
+                        RowEnd::Right => Ok(nodes),
+                        _ => Err(ParseError::new(ParseErrorKind::UnmatchedRight, span)),
+                    };
+                }
+                Some(command @ Command::Fraction(kind)) => {
+                    self.pos += 1;
+                    if term_start == nodes.len() {
+                        return Err(ParseError::new(
+                            ParseErrorKind::MissingArgument(command.name().to_string()),
+                            span,
+                        ));
+                    }
+                    let numerator = group(nodes.drain(term_start..).collect());
+                    let denominator = self.parse_term(command)?;
+                    let span = numerator.span.to(denominator.span);
+                    nodes.push(self.check_depth(Node::new(
+                        NodeKind::Fraction {
+                            numerator: Box::new(numerator),
+                            denominator: Box::new(denominator),
+                            kind,
+                        },
+                        span,
+                    ))?);
+                    term_start = nodes.len() - 1;
+                    continue;
+                }
+                _ => {}
+            }
+
+            if spaced {
+                term_start = nodes.len();
+            }
+            let atom = self.parse_atom()?;
+            nodes.push(atom);
+        }
+
+        match end {
+            RowEnd::Eof => Ok(nodes),
+            RowEnd::Brace => Err(ParseError::new(
+                ParseErrorKind::UnclosedBrace,
+                Span::new(self.len, self.len),
+            )),
+            RowEnd::Right => Err(ParseError::new(
+                ParseErrorKind::MissingRight,
+                Span::new(self.len, self.len),
+            )),
+        }
+    }
+
+    /// 분수의 오른쪽 피연산자처럼 빈칸 전까지의 항 하나를 읽습니다.
+    fn parse_term(&mut self, command: Command) -> Result<Node, ParseError> {
+        if !self.starts_item() {
+            return Err(self.missing(command));
+        }
+        let mut items = vec![self.parse_atom()?];
+        while self.continues_term() {
+            items.push(self.parse_atom()?);
+        }
+        Ok(group(items))
+    }
+
+    /// 첨자 인자처럼 빈칸 또는 다음 첨자 전까지의 항 하나를 읽습니다.
+    fn parse_script_argument(&mut self, command: Command) -> Result<Node, ParseError> {
+        if !self.starts_item() {
+            return Err(self.missing(command));
+        }
+        let mut items = vec![self.parse_primary()?];
+        while self.continues_term() {
+            items.push(self.parse_primary()?);
+        }
+        Ok(group(items))
+    }
+
+    /// 앞 명령의 인자 한 항목을 읽습니다.
+    fn parse_argument(&mut self, command: Command) -> Result<Node, ParseError> {
+        if !self.starts_item() {
+            return Err(self.missing(command));
+        }
+        self.parse_primary()
+    }
+
+    /// 다음 토큰이 항목을 시작할 수 있는지 여부
+    fn starts_item(&self) -> bool {
+        let Some(token) = self.peek() else {
+            return false;
+        };
+        match &token.kind {
+            TokenKind::CloseBrace
+            | TokenKind::Tilde
+            | TokenKind::Backtick
+            | TokenKind::Hash
+            | TokenKind::Ampersand => false,
+            TokenKind::Word(word) => !matches!(
+                Command::from_word(word),
+                Some(Command::Fraction(_) | Command::Right | Command::Column(_))
+            ),
+            _ => true,
+        }
+    }
+
+    /// 항목 하나와 뒤따르는 첨자, 구간을 읽습니다.
+    fn parse_atom(&mut self) -> Result<Node, ParseError> {
+        let mut node = self.parse_primary()?;
+
+        loop {
+            let slot = match self.peek().map(|token| &token.kind) {
+                Some(TokenKind::Underscore) => ScriptSlot::Sub,
+                Some(TokenKind::Caret) => ScriptSlot::Sup,
+                Some(TokenKind::Word(_)) => match self.peek_command() {
+                    Some(Command::Sub) => ScriptSlot::Sub,
+                    Some(Command::Sup) => ScriptSlot::Sup,
+                    Some(Command::LeftSub) => ScriptSlot::LeftSub,
+                    Some(Command::LeftSup) => ScriptSlot::LeftSup,
+                    Some(Command::From) => ScriptSlot::From,
+                    Some(Command::To) => ScriptSlot::To,
+                    _ => break,
+                },
+                _ => break,
+            };
+            self.pos += 1;
+            let command = match slot {
+                ScriptSlot::Sub => Command::Sub,
+                ScriptSlot::Sup => Command::Sup,
+                ScriptSlot::LeftSub => Command::LeftSub,
+                ScriptSlot::LeftSup => Command::LeftSup,
+                ScriptSlot::From => Command::From,
+                ScriptSlot::To => Command::To,
+            };
+            let argument = self.parse_script_argument(command)?;
+            node = self.check_depth(attach(node, slot, argument))?;
+        }
+
+        Ok(node)
+    }
+
+    /// 첨자가 붙기 전의 항목 하나를 읽습니다.
+    fn parse_primary(&mut self) -> Result<Node, ParseError> {
+        if self.depth >= MAX_DEPTH {
+            return Err(ParseError::new(ParseErrorKind::TooDeep, self.peek_span()));
+        }
+        self.depth += 1;
+        let node = self.read_primary();
+        self.depth -= 1;
+        node
+    }
+
+    /// 깊이를 검사한 뒤 항목 하나를 읽습니다.
+    fn read_primary(&mut self) -> Result<Node, ParseError> {
+        let Some(token) = self.next() else {
+            return Err(ParseError::new(
+                ParseErrorKind::UnexpectedToken,
+                Span::new(self.len, self.len),
+            ));
+        };
+        let span = token.span;
+
+        let kind = match token.kind {
+            TokenKind::Number(number) => NodeKind::Number(number),
+            TokenKind::Quoted(text) => NodeKind::Text(text),
+            TokenKind::Sequence(name) => NodeKind::Symbol(Symbol {
+                name,
+                value: symbols::sequence_symbol(name),
+            }),
+            TokenKind::Char(c) => NodeKind::Operator(c),
+            TokenKind::OpenBrace => {
+                let nodes = self.parse_row(RowEnd::Brace)?;
+                self.pos += 1;
+                NodeKind::Group(nodes)
+            }
+            TokenKind::Caret => return self.parse_leading_sup(span),
+            TokenKind::Underscore => {
+                let argument = self.parse_script_argument(Command::Sub)?;
+                return self.check_depth(attach(empty(span), ScriptSlot::Sub, argument));
+            }
+            TokenKind::Word(word) => match Command::from_word(&word) {
+                Some(command) => return self.parse_command(command, span),
+                None => word_kind(word),
+            },
+            TokenKind::CloseBrace
+            | TokenKind::Tilde
+            | TokenKind::Backtick
+            | TokenKind::Hash
+            | TokenKind::Ampersand => {
+                return Err(ParseError::new(ParseErrorKind::UnexpectedToken, span));
+            }
+        };
+
+        Ok(Node::new(kind, span))
+    }
+
+    /// 앞에 항목 없이 시작하는 `^`를 읽습니다. `^n sqrt x`는 거듭제곱근입니다.
+    fn parse_leading_sup(&mut self, span: Span) -> Result<Node, ParseError> {
+        if !self.starts_item() {
+            return Err(self.missing(Command::Sup));
+        }
+        let index = self.parse_primary()?;
+        if self.peek_command() == Some(Command::Sqrt) {
+            self.pos += 1;
+            let radicand = self.parse_argument(Command::Sqrt)?;
+            let span = span.to(radicand.span);
+            return Ok(Node::new(
+                NodeKind::Root {
+                    index: Some(Box::new(index)),
+                    radicand: Box::new(radicand),
+                },
+                span,
+            ));
+        }
+
+        let mut items = vec![index];
+        while self.continues_term() {
+            items.push(self.parse_primary()?);
+        }
+        self.check_depth(attach(empty(span), ScriptSlot::Sup, group(items)))
+    }
+
+    /// 명령어로 시작하는 항목을 읽습니다.
+    ///
+    /// 인자가 여럿인 명령은 따로 읽어 재귀마다 쌓이는 스택 프레임을 줄입니다.
+    fn parse_command(&mut self, command: Command, span: Span) -> Result<Node, ParseError> {
+        let kind = match command {
+            Command::BigOperator(op) => NodeKind::BigOperator(op),
+            Command::Font(style) => NodeKind::Font(style),
+            Command::Scale => self.parse_scale()?,
+            Command::Sqrt => NodeKind::Root {
+                index: None,
+                radicand: Box::new(self.parse_argument(command)?),
+            },
+            Command::Root => self.parse_root()?,
+            Command::Binom => self.parse_binom()?,
+            Command::Decoration(kind) => NodeKind::Decoration {
+                kind,
+                body: Box::new(self.parse_argument(command)?),
+            },
+            Command::Not => NodeKind::Not(Box::new(self.parse_argument(command)?)),
+            Command::Bigg => NodeKind::Bigg(Box::new(self.parse_argument(command)?)),
+            Command::Rel | Command::BuildRel => self.parse_relation(command)?,
+            Command::Color => self.parse_colored()?,
+            Command::Left => self.parse_fenced()?,
+            Command::Matrix(kind) => self.parse_matrix(kind)?,
+            Command::Grid(kind) => {
+                self.expect_open_brace(command)?;
+                NodeKind::Grid {
+                    kind,
+                    rows: self.parse_grid_body()?,
+                }
+            }
+            Command::LongDiv => self.parse_long_div()?,
+            Command::Fraction(_)
+            | Command::Sub
+            | Command::Sup
+            | Command::LeftSub
+            | Command::LeftSup
+            | Command::From
+            | Command::To
+            | Command::Right
+            | Command::Column(_) => {
+                return Err(ParseError::new(ParseErrorKind::UnexpectedToken, span));
+            }
+        };
+
+        Ok(Node::new(kind, Span::new(span.start, self.last_end())))
+    }
+
+    /// `scale` 뒤의 비율을 읽습니다.
+    fn parse_scale(&mut self) -> Result<NodeKind, ParseError> {
+        let command = Command::Scale;
+        let percent = match self.next() {
+            Some(Token {
+                kind: TokenKind::Number(number),
+                span: number_span,
+                ..
+            }) => number.parse().map_err(|_| {
+                ParseError::new(
+                    ParseErrorKind::InvalidArgument(command.name().to_string()),
+                    number_span,
+                )
+            })?,
+            Some(token) => {
+                return Err(ParseError::new(
+                    ParseErrorKind::MissingArgument(command.name().to_string()),
+                    token.span,
+                ));
+            }
+            None => return Err(self.missing(command)),
+        };
+        Ok(NodeKind::Scale(percent))
+    }
+
+    /// `root n of x`를 읽습니다.
+    fn parse_root(&mut self) -> Result<NodeKind, ParseError> {
+        let command = Command::Root;
+        let index = self.parse_argument(command)?;
+        match self.peek().map(|token| &token.kind) {
+            Some(TokenKind::Word(word)) if word.eq_ignore_ascii_case("of") => {
+                self.pos += 1;
+            }
+            _ => return Err(self.missing(command)),
+        }
+        Ok(NodeKind::Root {
+            index: Some(Box::new(index)),
+            radicand: Box::new(self.parse_argument(command)?),
+        })
+    }
+
+    /// `binom a b`를 읽습니다.
+    fn parse_binom(&mut self) -> Result<NodeKind, ParseError> {
+        let numerator = self.parse_argument(Command::Binom)?;
+        let denominator = self.parse_argument(Command::Binom)?;
+        Ok(NodeKind::Fraction {
+            numerator: Box::new(numerator),
+            denominator: Box::new(denominator),
+            kind: FractionKind::Choose,
+        })
+    }
+
+    /// `rel`, `buildrel`의 인자를 읽습니다.
+    fn parse_relation(&mut self, command: Command) -> Result<NodeKind, ParseError> {
+        let arrow = self.parse_argument(command)?;
+        let over = self.parse_argument(command)?;
+        let under = match command {
+            Command::Rel => Some(Box::new(self.parse_argument(command)?)),
+            _ => None,
+        };
+        Ok(NodeKind::Relation {
+            arrow: Box::new(arrow),
+            over: Box::new(over),
+            under,
+        })
+    }
+
+    /// `color {r,g,b} x`를 읽습니다.
+    fn parse_colored(&mut self) -> Result<NodeKind, ParseError> {
+        let rgb = self.parse_color()?;
+        Ok(NodeKind::Color {
+            rgb,
+            body: Box::new(self.parse_argument(Command::Color)?),
+        })
+    }
+
+    /// `left ... right`를 읽습니다.
+    fn parse_fenced(&mut self) -> Result<NodeKind, ParseError> {
+        let open = self.parse_delimiter(Command::Left)?;
+        let body = self.parse_row(RowEnd::Right)?;
+        self.pos += 1;
+        let close = self.parse_delimiter(Command::Right)?;
+        Ok(NodeKind::Fenced { open, close, body })
+    }
+
+    /// 행렬의 `{ }` 부분을 읽습니다.
+    fn parse_matrix(&mut self, kind: MatrixKind) -> Result<NodeKind, ParseError> {
+        self.expect_open_brace(Command::Matrix(kind))?;
+        if let Some(Command::Column(_)) = self.peek_command() {
+            let (columns, rows) = self.parse_columns()?;
+            Ok(NodeKind::Matrix {
+                kind,
+                columns: Some(columns),
+                rows,
+            })
+        } else {
+            Ok(NodeKind::Matrix {
+                kind,
+                columns: None,
+                rows: self.parse_grid_body()?,
+            })
+        }
+    }
+
+    /// `longdiv`의 나누는 수, 몫, 계산 과정을 읽습니다.
+    fn parse_long_div(&mut self) -> Result<NodeKind, ParseError> {
+        let command = Command::LongDiv;
+        let divisor = self.parse_argument(command)?;
+        let quotient = self.parse_argument(command)?;
+        self.expect_open_brace(command)?;
+        let rows = self
+            .parse_grid_body()?
+            .into_iter()
+            .map(|cells| cells.into_iter().flatten().collect())
+            .collect();
+        Ok(NodeKind::LongDiv {
+            divisor: Box::new(divisor),
+            quotient: Box::new(quotient),
+            rows,
+        })
+    }
+
+    fn expect_open_brace(&mut self, command: Command) -> Result<(), ParseError> {
+        match self.peek() {
+            Some(Token {
+                kind: TokenKind::OpenBrace,
+                ..
+            }) => {
+                self.pos += 1;
+                Ok(())
+            }
+            _ => Err(self.missing(command)),
+        }
+    }
+
+    /// `{` 다음부터 `}`까지 읽어 `#`으로 줄을, `&`로 칸을 나눕니다.
+    fn parse_grid_body(&mut self) -> Result<Vec<Vec<Cell>>, ParseError> {
+        let nodes = self.parse_row(RowEnd::Brace)?;
+        self.pos += 1;
+
+        let mut rows = vec![vec![Vec::new()]];
+        for node in nodes {
+            match node.kind {
+                NodeKind::LineBreak => rows.push(vec![Vec::new()]),
+                NodeKind::Align => rows.last_mut().unwrap().push(Vec::new()),
+                _ => rows.last_mut().unwrap().last_mut().unwrap().push(node),
+            }
+        }
+        Ok(rows)
+    }
+
+    /// 칸 단위로 입력한 행렬 (`col {..} lcol {..}`)을 줄 단위로 바꿔 읽습니다.
+    fn parse_columns(&mut self) -> Result<(Vec<ColumnAlign>, Vec<Vec<Cell>>), ParseError> {
+        let mut aligns = Vec::new();
+        let mut columns: Vec<Vec<Cell>> = Vec::new();
+
+        loop {
+            match self.peek_command() {
+                Some(command @ Command::Column(align)) => {
+                    self.pos += 1;
+                    self.expect_open_brace(command)?;
+                    let cells = self
+                        .parse_grid_body()?
+                        .into_iter()
+                        .map(|cells| cells.into_iter().flatten().collect())
+                        .collect();
+                    aligns.push(align);
+                    columns.push(cells);
+                }
+                _ => match self.next() {
+                    Some(Token {
+                        kind: TokenKind::CloseBrace,
+                        ..
+                    }) => break,
+                    Some(token) => {
+                        return Err(ParseError::new(ParseErrorKind::UnexpectedToken, token.span));
+                    }
+                    None => {
+                        return Err(ParseError::new(
+                            ParseErrorKind::UnclosedBrace,
+                            Span::new(self.len, self.len),
+                        ));
+                    }
+                },
+            }
+        }
+
+        let height = columns.iter().map(Vec::len).max().unwrap_or(0);
+        let rows = (0..height)
+            .map(|row| {
+                columns
+                    .iter()
+                    .map(|column| column.get(row).cloned().unwrap_or_default())
+                    .collect()
+            })
+            .collect();
+        Ok((aligns, rows))
+    }
+
+    /// `left`, `right` 뒤의 괄호를 읽습니다. `.`은 괄호 없음입니다.
+    fn parse_delimiter(&mut self, command: Command) -> Result<Option<char>, ParseError> {
+        let Some(token) = self.next() else {
+            return Err(ParseError::new(
+                ParseErrorKind::MissingArgument(command.name().to_string()),
+                Span::new(self.len, self.len),
+            ));
+        };
+        let delimiter = match &token.kind {
+            TokenKind::Char('.') => None,
+            TokenKind::Char(c) => Some(*c),
+            TokenKind::OpenBrace => Some('{'),
+            TokenKind::CloseBrace => Some('}'),
+            TokenKind::Word(word) => match symbols::lookup(word) {
+                Some((_, Entry::Symbol(value))) => Some(value),
+                _ => {
+                    return Err(ParseError::new(
+                        ParseErrorKind::InvalidArgument(command.name().to_string()),
+                        token.span,
+                    ));
+                }
+            },
+            _ => {
+                return Err(ParseError::new(
+                    ParseErrorKind::InvalidArgument(command.name().to_string()),
+                    token.span,
+                ));
+            }
+        };
+        Ok(delimiter)
+    }
+
+    /// `color`의 `{R,G,B}` 인자를 읽습니다.
+    fn parse_color(&mut self) -> Result<[u8; 3], ParseError> {
+        let start = self.peek_span();
+        let invalid = |span: Span| {
+            ParseError::new(
+                ParseErrorKind::InvalidArgument(Command::Color.name().to_string()),
+                span,
+            )
+        };
+        self.expect_open_brace(Command::Color)?;
+
+        let mut rgb = [0u8; 3];
+        for (i, value) in rgb.iter_mut().enumerate() {
+            if i > 0 {
+                match self.next() {
+                    Some(Token {
+                        kind: TokenKind::Char(','),
+                        ..
+                    }) => {}
+                    _ => return Err(invalid(start.to(self.peek_span()))),
+                }
+            }
+            *value = match self.next() {
+                Some(Token {
+                    kind: TokenKind::Number(number),
+                    span,
+                    ..
+                }) => number.parse().map_err(|_| invalid(span))?,
+                _ => return Err(invalid(start.to(self.peek_span()))),
+            };
+        }
+
+        match self.next() {
+            Some(Token {
+                kind: TokenKind::CloseBrace,
+                ..
+            }) => Ok(rgb),
+            _ => Err(invalid(start.to(self.peek_span()))),
+        }
+    }
+}
+
+/// 명령어가 아닌 낱말의 노드 종류
+fn word_kind(word: String) -> NodeKind {
+    match symbols::lookup(&word) {
+        Some((name, Entry::Symbol(value))) => NodeKind::Symbol(Symbol { name, value }),
+        Some((name, Entry::Function)) => NodeKind::Function(name),
+        None => NodeKind::Identifier(word),
+    }
+}
+
+/// 항목이 하나면 그대로, 여럿이면 묶음으로 만듭니다.
+fn group(mut items: Vec<Node>) -> Node {
+    if items.len() == 1 {
+        return items.pop().unwrap();
+    }
+    let span = match (items.first(), items.last()) {
+        (Some(first), Some(last)) => first.span.to(last.span),
+        _ => Span::default(),
+    };
+    Node::new(NodeKind::Group(items), span)
+}
+
+/// 노드 트리의 높이 (잎 노드는 1)
+fn height(node: &Node) -> usize {
+    let max = |nodes: &mut dyn Iterator<Item = &Node>| nodes.map(height).max().unwrap_or(0);
+    let children = match &node.kind {
+        NodeKind::Group(nodes) | NodeKind::Fenced { body: nodes, .. } => max(&mut nodes.iter()),
+        NodeKind::Scripts {
+            base,
+            sub,
+            sup,
+            left_sub,
+            left_sup,
+        } => max(&mut [
+            Some(base),
+            sub.as_ref(),
+            sup.as_ref(),
+            left_sub.as_ref(),
+            left_sup.as_ref(),
+        ]
+        .into_iter()
+        .flatten()
+        .map(|node| &**node)),
+        NodeKind::Limits { base, from, to } => max(&mut [Some(base), from.as_ref(), to.as_ref()]
+            .into_iter()
+            .flatten()
+            .map(|node| &**node)),
+        NodeKind::Fraction {
+            numerator,
+            denominator,
+            ..
+        } => height(numerator).max(height(denominator)),
+        NodeKind::Root { index, radicand } => {
+            height(radicand).max(index.as_deref().map_or(0, height))
+        }
+        NodeKind::Decoration { body, .. }
+        | NodeKind::Not(body)
+        | NodeKind::Bigg(body)
+        | NodeKind::Color { body, .. } => height(body),
+        NodeKind::Relation { arrow, over, under } => height(arrow)
+            .max(height(over))
+            .max(under.as_deref().map_or(0, height)),
+        NodeKind::Matrix { rows, .. } | NodeKind::Grid { rows, .. } => {
+            max(&mut rows.iter().flatten().flatten())
+        }
+        NodeKind::LongDiv {
+            divisor,
+            quotient,
+            rows,
+        } => height(divisor)
+            .max(height(quotient))
+            .max(max(&mut rows.iter().flatten())),
+        NodeKind::Identifier(_)
+        | NodeKind::Number(_)
+        | NodeKind::Text(_)
+        | NodeKind::Symbol(_)
+        | NodeKind::Operator(_)
+        | NodeKind::Function(_)
+        | NodeKind::BigOperator(_)
+        | NodeKind::Space(_)
+        | NodeKind::LineBreak
+        | NodeKind::Align
+        | NodeKind::Font(_)
+        | NodeKind::Scale(_) => 0,
+    };
+    children + 1
+}
+
+/// 첨자가 붙을 앞 항목이 없을 때 쓰는 빈 묶음
+fn empty(span: Span) -> Node {
+    Node::new(
+        NodeKind::Group(Vec::new()),
+        Span::new(span.start, span.start),
+    )
+}
+
+/// 항목에 첨자 또는 구간을 붙입니다. 같은 자리가 이미 차 있으면 바깥에 새로 붙입니다.
+fn attach(node: Node, slot: ScriptSlot, argument: Node) -> Node {
+    let span = node.span.to(argument.span);
+    let argument = Some(Box::new(argument));
+
+    match (slot, node) {
+        (
+            ScriptSlot::From | ScriptSlot::To,
+            Node {
+                kind: NodeKind::Limits { base, from, to },
+                ..
+            },
+        ) if (slot == ScriptSlot::From && from.is_none())
+            || (slot == ScriptSlot::To && to.is_none()) =>
+        {
+            let (from, to) = match slot {
+                ScriptSlot::From => (argument, to),
+                _ => (from, argument),
+            };
+            Node::new(NodeKind::Limits { base, from, to }, span)
+        }
+        (ScriptSlot::From, node) => Node::new(
+            NodeKind::Limits {
+                base: Box::new(node),
+                from: argument,
+                to: None,
+            },
+            span,
+        ),
+        (ScriptSlot::To, node) => Node::new(
+            NodeKind::Limits {
+                base: Box::new(node),
+                from: None,
+                to: argument,
+            },
+            span,
+        ),
+        (
+            slot,
+            Node {
+                kind:
+                    NodeKind::Scripts {
+                        base,
+                        mut sub,
+                        mut sup,
+                        mut left_sub,
+                        mut left_sup,
+                    },
+                span: node_span,
+            },
+        ) => {
+            let target = match slot {
+                ScriptSlot::Sub => &mut sub,
+                ScriptSlot::Sup => &mut sup,
+                ScriptSlot::LeftSub => &mut left_sub,
+                _ => &mut left_sup,
+            };
+            if target.is_none() {
+                *target = argument;
+                return Node::new(
+                    NodeKind::Scripts {
+                        base,
+                        sub,
+                        sup,
+                        left_sub,
+                        left_sup,
+                    },
+                    span,
+                );
+            }
+            let node = Node::new(
+                NodeKind::Scripts {
+                    base,
+                    sub,
+                    sup,
+                    left_sub,
+                    left_sup,
+                },
+                node_span,
+            );
+            scripts(node, slot, argument, span)
+        }
+        (slot, node) => scripts(node, slot, argument, span),
+    }
+}
+
+/// 새 첨자 노드
+fn scripts(base: Node, slot: ScriptSlot, argument: Option<Box<Node>>, span: Span) -> Node {
+    let (mut sub, mut sup, mut left_sub, mut left_sup) = (None, None, None, None);
+    match slot {
+        ScriptSlot::Sub => sub = argument,
+        ScriptSlot::Sup => sup = argument,
+        ScriptSlot::LeftSub => left_sub = argument,
+        _ => left_sup = argument,
+    }
+    Node::new(
+        NodeKind::Scripts {
+            base: Box::new(base),
+            sub,
+            sup,
+            left_sub,
+            left_sup,
+        },
+        span,
+    )
+}
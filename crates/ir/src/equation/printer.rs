@@ -0,0 +1,292 @@
+//! 구문 트리를 정규 수식 스크립트로 출력
+//!
+//! 명령어는 소문자로, 기호와 함수는 이름표의 이름으로 쓰고 항목 사이는
+//! 빈칸 하나로 띄웁니다. 여러 항목으로 된 피연산자는 `{ }`로 묶으므로
+//! 출력을 다시 파싱하면 같은 트리가 됩니다 (위치 제외).
+
+use std::fmt::{self, Display, Write};
+
+use super::ast::{ColumnAlign, FontStyle, Formula, FractionKind, Node, NodeKind, SpaceKind};
+use super::symbols;
+
+impl Display for Formula {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_row(f, &self.nodes)
+    }
+}
+
+impl Display for Node {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            NodeKind::Identifier(name) | NodeKind::Number(name) => f.write_str(name),
+            NodeKind::Text(text) => write!(f, "\"{}\"", text),
+            NodeKind::Symbol(symbol) => f.write_str(symbol.name),
+            NodeKind::Operator(c) => f.write_char(*c),
+            NodeKind::Function(name) => f.write_str(name),
+            NodeKind::BigOperator(op) => {
+                if op.small {
+                    f.write_str("small")?;
+                }
+                f.write_str(op.kind.keyword())
+            }
+            NodeKind::Space(SpaceKind::Normal) => f.write_char('~'),
+            NodeKind::Space(SpaceKind::Quarter) => f.write_char('`'),
+            NodeKind::LineBreak => f.write_char('#'),
+            NodeKind::Align => f.write_char('&'),
+            NodeKind::Font(style) => f.write_str(match style {
+                FontStyle::Roman => "rm",
+                FontStyle::Italic => "it",
+                FontStyle::Bold => "bold",
+            }),
+            NodeKind::Scale(percent) => write!(f, "scale {}", percent),
+            NodeKind::Group(nodes) => {
+                f.write_char('{')?;
+                write_row(f, nodes)?;
+                f.write_char('}')
+            }
+            NodeKind::Scripts {
+                base,
+                sub,
+                sup,
+                left_sub,
+                left_sup,
+            } => {
+                write_base(f, base)?;
+                if let Some(sub) = sub {
+                    f.write_char('_')?;
+                    write_argument(f, sub)?;
+                }
+                if let Some(sup) = sup {
+                    f.write_char('^')?;
+                    write_argument(f, sup)?;
+                }
+                if let Some(left_sub) = left_sub {
+                    f.write_str(" lsub ")?;
+                    write_argument(f, left_sub)?;
+                }
+                if let Some(left_sup) = left_sup {
+                    f.write_str(" lsup ")?;
+                    write_argument(f, left_sup)?;
+                }
+                Ok(())
+            }
+            NodeKind::Limits { base, from, to } => {
+                write_base(f, base)?;
+                if let Some(from) = from {
+                    f.write_str(" from ")?;
+                    write_argument(f, from)?;
+                }
+                if let Some(to) = to {
+                    f.write_str(" to ")?;
+                    write_argument(f, to)?;
+                }
+                Ok(())
+            }
+            NodeKind::Fraction {
+                numerator,
+                denominator,
+                kind,
+            } => {
+                if is_marker(numerator) {
+                    write_braced(f, numerator)?;
+                } else {
+                    write!(f, "{}", numerator)?;
+                }
+                f.write_str(match kind {
+                    FractionKind::Over => " over ",
+                    FractionKind::Atop => " atop ",
+                    FractionKind::Choose => " choose ",
+                })?;
+                if is_marker(denominator) || matches!(denominator.kind, NodeKind::Fraction { .. }) {
+                    write_braced(f, denominator)
+                } else {
+                    write!(f, "{}", denominator)
+                }
+            }
+            NodeKind::Root { index, radicand } => {
+                if let Some(index) = index {
+                    f.write_str("root ")?;
+                    write_argument(f, index)?;
+                    f.write_str(" of ")?;
+                } else {
+                    f.write_str("sqrt ")?;
+                }
+                write_argument(f, radicand)
+            }
+            NodeKind::Decoration { kind, body } => {
+                write!(f, "{} ", kind.keyword())?;
+                write_argument(f, body)
+            }
+            NodeKind::Not(body) => {
+                f.write_str("not ")?;
+                write_argument(f, body)
+            }
+            NodeKind::Bigg(body) => {
+                f.write_str("bigg ")?;
+                write_argument(f, body)
+            }
+            NodeKind::Relation { arrow, over, under } => {
+                f.write_str(if under.is_some() { "rel " } else { "buildrel " })?;
+                write_argument(f, arrow)?;
+                f.write_char(' ')?;
+                write_argument(f, over)?;
+                if let Some(under) = under {
+                    f.write_char(' ')?;
+                    write_argument(f, under)?;
+                }
+                Ok(())
+            }
+            NodeKind::Color { rgb, body } => {
+                write!(f, "color {{{},{},{}}} ", rgb[0], rgb[1], rgb[2])?;
+                write_argument(f, body)
+            }
+            NodeKind::Fenced { open, close, body } => {
+                f.write_str("left ")?;
+                write_delimiter(f, *open)?;
+                if !body.is_empty() {
+                    f.write_char(' ')?;
+                    write_row(f, body)?;
+                }
+                f.write_str(" right ")?;
+                write_delimiter(f, *close)
+            }
+            NodeKind::Matrix {
+                kind,
+                columns: Some(columns),
+                rows,
+            } => {
+                write!(f, "{} {{", kind.keyword())?;
+                for (i, align) in columns.iter().enumerate() {
+                    if i > 0 {
+                        f.write_char(' ')?;
+                    }
+                    f.write_str(match align {
+                        ColumnAlign::Center => "col",
+                        ColumnAlign::Left => "lcol",
+                        ColumnAlign::Right => "rcol",
+                    })?;
+                    f.write_str(" {")?;
+                    for (j, row) in rows.iter().enumerate() {
+                        if j > 0 {
+                            f.write_str(" # ")?;
+                        }
+                        if let Some(cell) = row.get(i) {
+                            write_row(f, cell)?;
+                        }
+                    }
+                    f.write_char('}')?;
+                }
+                f.write_char('}')
+            }
+            NodeKind::Matrix {
+                kind,
+                columns: None,
+                rows,
+            } => {
+                write!(f, "{} ", kind.keyword())?;
+                write_grid(f, rows)
+            }
+            NodeKind::Grid { kind, rows } => {
+                write!(f, "{} ", kind.keyword())?;
+                write_grid(f, rows)
+            }
+            NodeKind::LongDiv {
+                divisor,
+                quotient,
+                rows,
+            } => {
+                f.write_str("longdiv ")?;
+                write_argument(f, divisor)?;
+                f.write_char(' ')?;
+                write_argument(f, quotient)?;
+                f.write_str(" {")?;
+                for (i, row) in rows.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(" # ")?;
+                    }
+                    write_row(f, row)?;
+                }
+                f.write_char('}')
+            }
+        }
+    }
+}
+
+/// 항목들을 빈칸 하나로 띄워 씁니다.
+fn write_row(f: &mut fmt::Formatter<'_>, nodes: &[Node]) -> fmt::Result {
+    for (i, node) in nodes.iter().enumerate() {
+        if i > 0 {
+            f.write_char(' ')?;
+        }
+        write!(f, "{}", node)?;
+    }
+    Ok(())
+}
+
+/// `{` 줄 `#` 줄 `}` 형태로 씁니다. 칸은 `&`로 나눕니다.
+fn write_grid(f: &mut fmt::Formatter<'_>, rows: &[Vec<Vec<Node>>]) -> fmt::Result {
+    f.write_char('{')?;
+    for (i, row) in rows.iter().enumerate() {
+        if i > 0 {
+            f.write_str(" # ")?;
+        }
+        for (j, cell) in row.iter().enumerate() {
+            if j > 0 {
+                f.write_str(" & ")?;
+            }
+            write_row(f, cell)?;
+        }
+    }
+    f.write_char('}')
+}
+
+fn write_braced(f: &mut fmt::Formatter<'_>, node: &Node) -> fmt::Result {
+    write!(f, "{{{}}}", node)
+}
+
+/// 명령 인자와 첨자 인자: 한 항목으로 읽히지 않는 노드는 묶습니다.
+fn write_argument(f: &mut fmt::Formatter<'_>, node: &Node) -> fmt::Result {
+    if is_marker(node)
+        || matches!(
+            node.kind,
+            NodeKind::Scripts { .. } | NodeKind::Limits { .. } | NodeKind::Fraction { .. }
+        )
+    {
+        write_braced(f, node)
+    } else {
+        write!(f, "{}", node)
+    }
+}
+
+/// 첨자와 구간이 붙는 항목
+fn write_base(f: &mut fmt::Formatter<'_>, node: &Node) -> fmt::Result {
+    if is_marker(node) || matches!(node.kind, NodeKind::Fraction { .. }) {
+        write_braced(f, node)
+    } else {
+        write!(f, "{}", node)
+    }
+}
+
+/// 빈칸, 줄 바꿈, 글꼴처럼 혼자서 항목이 되지 못하는 노드
+fn is_marker(node: &Node) -> bool {
+    matches!(
+        node.kind,
+        NodeKind::Space(_)
+            | NodeKind::LineBreak
+            | NodeKind::Align
+            | NodeKind::Font(_)
+            | NodeKind::Scale(_)
+    )
+}
+
+/// `left`, `right` 뒤의 괄호
+fn write_delimiter(f: &mut fmt::Formatter<'_>, delimiter: Option<char>) -> fmt::Result {
+    match delimiter {
+        None => f.write_char('.'),
+        Some(c) if c.is_ascii() => f.write_char(c),
+        Some(c) => match symbols::name_of(c) {
+            Some(name) => f.write_str(name),
+            None => f.write_char(c),
+        },
+    }
+}
@@ -0,0 +1,71 @@
+//! MathML과 LaTeX 변환이 함께 쓰는 도우미
+
+use super::ast::{FontStyle, Node, NodeKind};
+
+/// `rm`, `it`, `bold`로 바뀌는 글꼴 상태
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct TextStyle {
+    /// 로만체 (`rm`)
+    pub roman: bool,
+    /// 볼드체 (`bold`)
+    pub bold: bool,
+}
+
+impl TextStyle {
+    pub(crate) fn apply(&mut self, font: FontStyle) {
+        match font {
+            FontStyle::Roman => self.roman = true,
+            FontStyle::Italic => self.roman = false,
+            FontStyle::Bold => self.bold = true,
+        }
+    }
+}
+
+/// 항목들을 줄 바꾸기(`#`)로 나눕니다.
+pub(crate) fn lines(nodes: &[Node]) -> Vec<&[Node]> {
+    nodes
+        .split(|node| matches!(node.kind, NodeKind::LineBreak))
+        .collect()
+}
+
+/// `not` 뒤의 기호에 사선을 그은 문자
+pub(crate) fn negated(node: &Node) -> Option<char> {
+    let c = match node.kind {
+        NodeKind::Operator(c) => c,
+        NodeKind::Symbol(symbol) => symbol.value,
+        _ => return None,
+    };
+    Some(match c {
+        '=' => '≠',
+        '<' => '≮',
+        '>' => '≯',
+        '≤' => '≰',
+        '≥' => '≱',
+        '∈' => '∉',
+        '∋' => '∌',
+        '⊂' => '⊄',
+        '⊃' => '⊅',
+        '⊆' => '⊈',
+        '⊇' => '⊉',
+        '≡' => '≢',
+        '∼' => '≁',
+        '≈' => '≉',
+        '≅' => '≇',
+        '∃' => '∄',
+        '≺' => '⊀',
+        '≻' => '⊁',
+        '|' => '∤',
+        '∥' | '‖' => '∦',
+        _ => return None,
+    })
+}
+
+/// 글자 하나 크기의 항목인지 여부 (장식 기호를 늘일지 정할 때 씁니다)
+pub(crate) fn is_single_char(node: &Node) -> bool {
+    match &node.kind {
+        NodeKind::Identifier(text) | NodeKind::Number(text) => text.chars().count() == 1,
+        NodeKind::Symbol(_) | NodeKind::Operator(_) => true,
+        NodeKind::Group(nodes) => nodes.len() == 1 && is_single_char(&nodes[0]),
+        _ => false,
+    }
+}
@@ -0,0 +1,152 @@
+//! 기호와 기본 함수 이름표
+//!
+//! 이름은 수식 문서의 표기를 따릅니다. 대소문자만 다른 이름이 따로 있으면
+//! (`larrow`/`LARROW`, `lim`/`Lim`, `alpha`/`Alpha`) 정확히 일치해야 하고,
+//! 그렇지 않으면 대소문자를 구분하지 않습니다.
+
+/// 이름이 가리키는 대상
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Entry {
+    /// 기호
+    Symbol(char),
+    /// 기본 함수 또는 예약어 (로만체)
+    Function,
+}
+
+const fn symbol(name: &'static str, value: char) -> (&'static str, Entry) {
+    (name, Entry::Symbol(value))
+}
+
+const fn function(name: &'static str) -> (&'static str, Entry) {
+    (name, Entry::Function)
+}
+
+/// 이름표
+#[rustfmt::skip]
+const NAMES: &[(&str, Entry)] = &[
+    // 그리스 소문자
+    symbol("alpha", 'α'), symbol("beta", 'β'), symbol("gamma", 'γ'), symbol("delta", 'δ'),
+    symbol("epsilon", 'ϵ'), symbol("zeta", 'ζ'), symbol("eta", 'η'), symbol("theta", 'θ'),
+    symbol("iota", 'ι'), symbol("kappa", 'κ'), symbol("lambda", 'λ'), symbol("mu", 'μ'),
+    symbol("nu", 'ν'), symbol("xi", 'ξ'), symbol("omicron", 'ο'), symbol("pi", 'π'),
+    symbol("rho", 'ρ'), symbol("sigma", 'σ'), symbol("tau", 'τ'), symbol("upsilon", 'υ'),
+    symbol("phi", 'ϕ'), symbol("chi", 'χ'), symbol("psi", 'ψ'), symbol("omega", 'ω'),
+    // 그리스 대문자
+    symbol("Alpha", 'Α'), symbol("Beta", 'Β'), symbol("Gamma", 'Γ'), symbol("Delta", 'Δ'),
+    symbol("Epsilon", 'Ε'), symbol("Zeta", 'Ζ'), symbol("Eta", 'Η'), symbol("Theta", 'Θ'),
+    symbol("Iota", 'Ι'), symbol("Kappa", 'Κ'), symbol("Lambda", 'Λ'), symbol("Mu", 'Μ'),
+    symbol("Nu", 'Ν'), symbol("Xi", 'Ξ'), symbol("Omicron", 'Ο'), symbol("Pi", 'Π'),
+    symbol("Rho", 'Ρ'), symbol("Sigma", 'Σ'), symbol("Tau", 'Τ'), symbol("Upsilon", 'Υ'),
+    symbol("Phi", 'Φ'), symbol("Chi", 'Χ'), symbol("Psi", 'Ψ'), symbol("Omega", 'Ω'),
+    // 그리스 문자 변형과 특수 문자
+    symbol("vartheta", 'ϑ'), symbol("varpi", 'ϖ'), symbol("varsigma", 'ς'),
+    symbol("varupsilon", 'ϒ'), symbol("varphi", 'φ'), symbol("varepsilon", 'ε'),
+    symbol("aleph", 'ℵ'), symbol("hbar", 'ℏ'), symbol("imath", 'ı'), symbol("jmath", 'ȷ'),
+    symbol("ohm", 'Ω'), symbol("ell", 'ℓ'), symbol("liter", 'ℓ'), symbol("wp", '℘'),
+    symbol("imag", 'ℑ'), symbol("angstrom", 'Å'),
+    // 합/집합 기호
+    symbol("cap", '∩'), symbol("cup", '∪'), symbol("sqcap", '⊓'), symbol("sqcup", '⊔'),
+    symbol("oplus", '⊕'), symbol("ominus", '⊖'), symbol("otimes", '⊗'), symbol("odot", '⊙'),
+    symbol("oslash", '⊘'), symbol("vee", '∨'), symbol("wedge", '∧'), symbol("subset", '⊂'),
+    symbol("supset", '⊃'), symbol("subseteq", '⊆'), symbol("supseteq", '⊇'), symbol("in", '∈'),
+    symbol("owns", '∋'), symbol("notin", '∉'), symbol("leq", '≤'), symbol("geq", '≥'),
+    symbol("sqsubset", '⊏'), symbol("sqsupset", '⊐'), symbol("sqsubseteq", '⊑'),
+    symbol("sqsupseteq", '⊒'), symbol("lll", '⋘'), symbol("ggg", '⋙'), symbol("prec", '≺'),
+    symbol("succ", '≻'), symbol("uplus", '⊎'),
+    // 연산/논리 기호
+    symbol("plusminus", '±'), symbol("minusplus", '∓'), symbol("times", '×'),
+    symbol("div", '÷'), symbol("divide", '÷'), symbol("circ", '∘'), symbol("bullet", '•'),
+    symbol("DEG", '°'), symbol("ast", '∗'), symbol("star", '⋆'), symbol("bigcirc", '◯'),
+    symbol("emptyset", '∅'), symbol("therefore", '∴'), symbol("because", '∵'),
+    symbol("identical", '≡'), symbol("exist", '∃'), symbol("neq", '≠'), symbol("doteq", '≐'),
+    symbol("image", 'ℑ'), symbol("reimage", 'ℜ'), symbol("sim", '∼'), symbol("approx", '≈'),
+    symbol("simeq", '≃'), symbol("cong", '≅'), symbol("equiv", '≡'), symbol("asymp", '≍'),
+    symbol("iso", '≅'), symbol("diamond", '◇'), symbol("dsum", '⊕'), symbol("forall", '∀'),
+    symbol("prime", '′'), symbol("partial", '∂'), symbol("inf", '∞'), symbol("lnot", '¬'),
+    symbol("propto", '∝'), symbol("xor", '⊻'), symbol("triangle", '△'), symbol("dagger", '†'),
+    symbol("ddagger", '‡'),
+    // 화살표
+    symbol("larrow", '←'), symbol("rarrow", '→'), symbol("uparrow", '↑'),
+    symbol("downarrow", '↓'), symbol("LARROW", '⇐'), symbol("RARROW", '⇒'),
+    symbol("UPARROW", '⇑'), symbol("DOWNARROW", '⇓'), symbol("udarrow", '↕'),
+    symbol("lrarrow", '↔'), symbol("UDARROW", '⇕'), symbol("LRARROW", '⇔'),
+    symbol("nwarrow", '↖'), symbol("searrow", '↘'), symbol("nearrow", '↗'),
+    symbol("swarrow", '↙'), symbol("hookleft", '↩'), symbol("hookright", '↪'),
+    symbol("mapsto", '↦'), symbol("vert", '|'), symbol("VERT", '‖'),
+    // 기타 기호 (문서의 `H`(⊣)와 `LEFT`(⊥)는 변수와 괄호 명령어에 양보한다)
+    symbol("cdots", '⋯'), symbol("ldots", '…'), symbol("vdots", '⋮'), symbol("ddots", '⋱'),
+    symbol("triangled", '▽'), symbol("angle", '∠'), symbol("msangle", '∡'),
+    symbol("sangle", '∢'), symbol("rtangle", '⊾'), symbol("vdash", '⊢'), symbol("dashv", '⊣'),
+    symbol("bot", '⊥'), symbol("top", '⊤'), symbol("models", '⊨'), symbol("laplace", '∇'),
+    symbol("centigrade", '℃'), symbol("fahrenheit", '℉'), symbol("lslant", '⧸'),
+    symbol("rslant", '⧹'), symbol("at", '@'), symbol("thund", '⚡'), symbol("thou", '‱'),
+    symbol("well", '⌂'), symbol("base", '⚓'), symbol("benzene", '⌬'),
+    // 괄호 이름 (`left`, `right` 뒤에서 주로 쓴다)
+    symbol("lbrace", '{'), symbol("rbrace", '}'), symbol("langle", '⟨'), symbol("rangle", '⟩'),
+    symbol("lceil", '⌈'), symbol("rceil", '⌉'), symbol("lfloor", '⌊'), symbol("rfloor", '⌋'),
+    // 기본 함수
+    function("sin"), function("cos"), function("coth"), function("log"), function("tan"),
+    function("cot"), function("ln"), function("lg"), function("sec"), function("cosec"),
+    function("max"), function("min"), function("csc"), function("arcsin"), function("lim"),
+    function("Lim"), function("arccos"), function("arctan"), function("exp"), function("Exp"),
+    function("arcsinh"), function("det"), function("gcd"), function("cosh"), function("sinh"),
+    function("tanh"), function("mod"), function("asin"), function("acos"), function("atan"),
+    function("lcm"),
+    // 예약어
+    function("if"), function("for"), function("and"), function("hom"), function("ker"),
+    function("deg"), function("arg"), function("dim"), function("Pr"),
+];
+
+/// 여러 글자 기호 연산자의 유니코드 문자
+pub(crate) fn sequence_symbol(sequence: &str) -> char {
+    match sequence {
+        "<->" => '↔',
+        "<<<" => '⋘',
+        ">>>" => '⋙',
+        "->" => '→',
+        "<-" => '←',
+        "<=" => '≤',
+        ">=" => '≥',
+        "!=" => '≠',
+        "==" => '≡',
+        "<<" => '≪',
+        ">>" => '≫',
+        "+-" => '±',
+        "-+" => '∓',
+        _ => '\u{FFFD}',
+    }
+}
+
+/// 낱말에 해당하는 이름을 찾습니다.
+///
+/// 정확히 일치하는 이름이 없으면 대소문자를 무시하고 찾습니다. 이때 후보가
+/// 여럿이면 모두 대문자인 낱말(`ALPHA`, `LIM`)은 첫 글자만 대문자인 이름을 고릅니다.
+pub(crate) fn lookup(word: &str) -> Option<(&'static str, Entry)> {
+    if let Some(&found) = NAMES.iter().find(|(name, _)| *name == word) {
+        return Some(found);
+    }
+
+    let mut candidates = NAMES
+        .iter()
+        .filter(|(name, _)| name.eq_ignore_ascii_case(word));
+    let first = *candidates.next()?;
+    let Some(&second) = candidates.next() else {
+        return Some(first);
+    };
+    if !word.chars().all(|c| c.is_ascii_uppercase()) {
+        return None;
+    }
+    [first, second].into_iter().find(|(name, _)| {
+        let mut chars = name.chars();
+        chars.next().is_some_and(|c| c.is_ascii_uppercase())
+            && chars.all(|c| c.is_ascii_lowercase())
+    })
+}
+
+/// 유니코드 문자에 해당하는 첫 기호 이름을 찾습니다.
+pub(crate) fn name_of(value: char) -> Option<&'static str> {
+    NAMES
+        .iter()
+        .find(|(_, entry)| *entry == Entry::Symbol(value))
+        .map(|(name, _)| *name)
+}
@@ -41,10 +41,15 @@ pub struct HwpExtensions {
     /// 문서 배포/보안 관련 정보. HWPX에는 해당 개념이 없음.
     pub distribute_doc_data: Option<DistributeDocData>,
 
-    /// 스크립트 코드
+    /// 스크립트
     ///
     /// 문서에 포함된 JavaScript. HWPX에서는 지원하지 않음.
-    pub scripts: Option<String>,
+    pub scripts: Option<HwpScripts>,
+
+    /// 연결 문서 경로
+    ///
+    /// DocOptions 저장소의 _LinkDoc 스트림에 기록되는 경로 목록.
+    pub linked_documents: Vec<String>,
 
     /// 레이아웃 호환성 설정
     pub layout_compatibility: Option<LayoutCompatibility>,
@@ -53,6 +58,25 @@ pub struct HwpExtensions {
     pub document_data: Option<Vec<u8>>,
 }
 
+/// HWP 문서 스크립트
+///
+/// Scripts 저장소의 JScriptVersion, DefaultJScript 스트림 내용.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HwpScripts {
+    /// 스크립트 버전 (상위)
+    pub version_high: u32,
+    /// 스크립트 버전 (하위)
+    pub version_low: u32,
+    /// 스크립트 헤더
+    pub header: String,
+    /// 스크립트 소스
+    pub source: String,
+    /// 스크립트 이전에 실행되는 소스
+    pub pre_source: String,
+    /// 스크립트 이후에 실행되는 소스
+    pub post_source: String,
+}
+
 /// 배포용 문서 데이터
 #[derive(Debug, Clone, Default)]
 pub struct DistributeDocData {
@@ -110,6 +134,41 @@ pub struct HwpxExtensions {
     ///
     /// 변경 추적 표시 옵션.
     pub track_change_config: Option<TrackChangeConfig>,
+
+    /// 알 수 없는 XML 노드
+    ///
+    /// 보존 모드로 읽은 문서에서 모델에 없는 요소와 속성. HWPX로 다시 저장할 때 같은 위치에 기록됨.
+    pub unknown_nodes: Vec<HwpxUnknownNode>,
+}
+
+/// 알 수 없는 HWPX XML 노드
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HwpxUnknownNode {
+    /// 파트 경로 (예: `Contents/section0.xml`)
+    pub part: String,
+    /// 노드가 속한 요소의 경로 (예: `/sec/p[@id='3']/run[2]`)
+    pub path: String,
+    /// 노드 내용
+    pub content: HwpxUnknownContent,
+}
+
+/// 알 수 없는 HWPX XML 노드 내용
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HwpxUnknownContent {
+    /// 자식 요소
+    Element {
+        /// 부모의 자식 요소 중 위치 (0부터)
+        index: usize,
+        /// 요소 전체 XML (필요한 네임스페이스 선언 포함)
+        xml: String,
+    },
+    /// 속성
+    Attribute {
+        /// 속성 이름 (접두어 포함)
+        name: String,
+        /// 속성 값
+        value: String,
+    },
 }
 
 /// 변경 이력
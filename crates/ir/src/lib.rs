@@ -32,6 +32,7 @@ pub mod border_fill;
 pub mod char_shape;
 pub mod control;
 pub mod document;
+pub mod equation;
 pub mod error;
 pub mod extensions;
 pub mod metadata;
@@ -48,12 +49,12 @@ pub use binary::{BinaryData, BinaryDataStore, BinaryFormat};
 pub use char_shape::{CharShape, Font, FontRef, FontSet};
 // Re-exports from char_shape that come from primitive
 pub use char_shape::{EmphasisStyle, FontFamily, FontType, ShadowStyle, SubstituteFont, UnderlineStyle};
-pub use document::{CaretPosition, CompatibleDocument, Document, DocumentSettings};
+pub use document::{CaretPosition, CompatibleDocument, Document, DocumentSettings, PREVIEW_TEXT_MAX_LEN};
 pub use error::{
     ConversionError, ConversionErrorKind, ConversionResult, ConversionWarning,
     ConversionWarningKind, WarningCollector,
 };
-pub use extensions::{Extensions, HwpExtensions, HwpxExtensions};
+pub use extensions::{Extensions, HwpExtensions, HwpScripts, HwpxExtensions};
 pub use metadata::{DocumentVersion, Metadata};
 pub use paragraph::{Paragraph, Run, RunContent};
 pub use section::Section;
@@ -0,0 +1,624 @@
+//! 수식 스크립트 파서와 출력 테스트
+
+use ir::equation::{
+    self, BigOperatorKind, ColumnAlign, DecorationKind, FontStyle, FractionKind, GridKind,
+    MatrixKind, Node, NodeKind, ParseErrorKind, SpaceKind, Span,
+};
+
+fn parse(script: &str) -> Vec<Node> {
+    equation::parse(script)
+        .unwrap_or_else(|error| panic!("{script:?}: {error}"))
+        .nodes
+}
+
+/// 출력한 스크립트를 다시 파싱해도 같은 정규 스크립트가 나오는지 확인합니다.
+fn canonical(script: &str) -> String {
+    let printed = equation::parse(script).unwrap().to_string();
+    let reparsed = equation::parse(&printed).unwrap().to_string();
+    assert_eq!(printed, reparsed, "{script:?}");
+    printed
+}
+
+fn identifier(node: &Node) -> &str {
+    match &node.kind {
+        NodeKind::Identifier(name) => name,
+        other => panic!("not an identifier: {other:?}"),
+    }
+}
+
+#[test]
+fn test_fraction_takes_adjacent_terms() {
+    let nodes = parse("a+b over c");
+    assert_eq!(nodes.len(), 1);
+    let NodeKind::Fraction {
+        numerator,
+        denominator,
+        kind,
+    } = &nodes[0].kind
+    else {
+        panic!("{:?}", nodes[0]);
+    };
+    assert_eq!(*kind, FractionKind::Over);
+    assert!(matches!(&numerator.kind, NodeKind::Group(items) if items.len() == 3));
+    assert_eq!(identifier(denominator), "c");
+    assert_eq!(nodes[0].span, Span::new(0, 10));
+
+    // 빈칸으로 나뉘면 바로 앞 항만 분자가 된다
+    let nodes = parse("a + b atop c");
+    assert_eq!(nodes.len(), 3);
+    assert!(matches!(
+        nodes[2].kind,
+        NodeKind::Fraction {
+            kind: FractionKind::Atop,
+            ..
+        }
+    ));
+}
+
+#[test]
+fn test_scripts() {
+    let nodes = parse("E=mc^2");
+    assert_eq!(nodes.len(), 3);
+    let NodeKind::Scripts { base, sup, sub, .. } = &nodes[2].kind else {
+        panic!("{:?}", nodes[2]);
+    };
+    assert_eq!(identifier(base), "mc");
+    assert!(sub.is_none());
+    assert!(matches!(&sup.as_ref().unwrap().kind, NodeKind::Number(n) if n == "2"));
+
+    let nodes = parse("x_i^2 + a SUB {n+1} + x LSUB y LSUP z");
+    let NodeKind::Scripts { sub, sup, .. } = &nodes[0].kind else {
+        panic!("{:?}", nodes[0]);
+    };
+    assert_eq!(identifier(sub.as_ref().unwrap()), "i");
+    assert!(sup.is_some());
+    assert!(matches!(
+        nodes[2].kind,
+        NodeKind::Scripts { sub: Some(_), .. }
+    ));
+    assert!(matches!(
+        nodes[4].kind,
+        NodeKind::Scripts {
+            left_sub: Some(_),
+            left_sup: Some(_),
+            ..
+        }
+    ));
+}
+
+#[test]
+fn test_roots() {
+    let nodes = parse("sqrt 2");
+    assert!(matches!(nodes[0].kind, NodeKind::Root { index: None, .. }));
+
+    // 예제 2.3: 구간이 있는 적분과 세제곱근
+    let nodes = parse("int from 0 to 3 `^3sqrt{x^2 +1}dx");
+    let NodeKind::Limits { base, from, to } = &nodes[0].kind else {
+        panic!("{:?}", nodes[0]);
+    };
+    assert!(matches!(
+        base.kind,
+        NodeKind::BigOperator(op) if op.kind == BigOperatorKind::Integral
+    ));
+    assert!(from.is_some() && to.is_some());
+    assert!(matches!(nodes[1].kind, NodeKind::Space(SpaceKind::Quarter)));
+    let NodeKind::Root { index, radicand } = &nodes[2].kind else {
+        panic!("{:?}", nodes[2]);
+    };
+    assert!(matches!(&index.as_ref().unwrap().kind, NodeKind::Number(n) if n == "3"));
+    assert!(matches!(radicand.kind, NodeKind::Group(_)));
+    assert_eq!(identifier(&nodes[3]), "dx");
+
+    assert_eq!(canonical("^3sqrt x"), "root 3 of x");
+    assert_eq!(canonical("ROOT n OF {x+1}"), "root n of {x + 1}");
+}
+
+#[test]
+fn test_big_operators() {
+    let nodes = parse("sum_{x=0} ^{inf} {a+b} over {a-b}");
+    assert_eq!(nodes.len(), 2);
+    let NodeKind::Scripts { base, sub, sup, .. } = &nodes[0].kind else {
+        panic!("{:?}", nodes[0]);
+    };
+    assert!(matches!(
+        base.kind,
+        NodeKind::BigOperator(op) if op.kind == BigOperatorKind::Sum && !op.small
+    ));
+    assert!(sub.is_some() && sup.is_some());
+    assert!(matches!(nodes[1].kind, NodeKind::Fraction { .. }));
+
+    let nodes = parse("U=(A SMALLUNION B) SMALLINTER C");
+    assert!(matches!(
+        nodes[4].kind,
+        NodeKind::BigOperator(op) if op.kind == BigOperatorKind::Union && op.small
+    ));
+    assert!(matches!(
+        nodes[7].kind,
+        NodeKind::BigOperator(op) if op.kind == BigOperatorKind::Intersection && op.small
+    ));
+
+    for (script, kind) in [
+        ("oint", BigOperatorKind::ContourIntegral),
+        ("dint", BigOperatorKind::DoubleIntegral),
+        ("TINT", BigOperatorKind::TripleIntegral),
+        ("odint", BigOperatorKind::DoubleContourIntegral),
+        ("otint", BigOperatorKind::TripleContourIntegral),
+        ("prod", BigOperatorKind::Product),
+        ("coprod", BigOperatorKind::Coproduct),
+    ] {
+        assert!(matches!(parse(script)[0].kind, NodeKind::BigOperator(op) if op.kind == kind));
+    }
+}
+
+#[test]
+fn test_limit_and_brackets() {
+    // 예제 2.5
+    let nodes = parse("lim_N->inf 1 over N sum_n=1^N LEFT(SUM_k=1^n 1 over 2^k right)");
+    let NodeKind::Scripts { base, sub, .. } = &nodes[0].kind else {
+        panic!("{:?}", nodes[0]);
+    };
+    assert!(matches!(base.kind, NodeKind::Function("lim")));
+    assert!(matches!(&sub.as_ref().unwrap().kind, NodeKind::Group(items) if items.len() == 3));
+    assert!(matches!(nodes[1].kind, NodeKind::Fraction { .. }));
+    let NodeKind::Fenced { open, close, body } = &nodes[3].kind else {
+        panic!("{:?}", nodes[3]);
+    };
+    assert_eq!((*open, *close), (Some('('), Some(')')));
+    assert_eq!(body.len(), 2);
+
+    assert!(matches!(parse("Lim x")[0].kind, NodeKind::Function("Lim")));
+    assert_eq!(canonical("left lbrace a right ."), "left { a right .");
+    assert_eq!(
+        canonical("left langle a right VERT"),
+        "left langle a right VERT"
+    );
+}
+
+#[test]
+fn test_matrices() {
+    let nodes = parse("X = bmatrix { 42 & 52 & 48 & 58 #4 & 5 & 4 & 3 }");
+    let NodeKind::Matrix {
+        kind,
+        columns,
+        rows,
+    } = &nodes[2].kind
+    else {
+        panic!("{:?}", nodes[2]);
+    };
+    assert_eq!(*kind, MatrixKind::Bracket);
+    assert!(columns.is_none());
+    assert_eq!(rows.len(), 2);
+    assert!(rows.iter().all(|row| row.len() == 4));
+
+    let nodes = parse("pmatrix{col{a#b} lcol{c#d} rcol{e#f}}");
+    let NodeKind::Matrix {
+        kind,
+        columns,
+        rows,
+    } = &nodes[0].kind
+    else {
+        panic!("{:?}", nodes[0]);
+    };
+    assert_eq!(*kind, MatrixKind::Paren);
+    assert_eq!(
+        columns.as_deref(),
+        Some(&[ColumnAlign::Center, ColumnAlign::Left, ColumnAlign::Right][..])
+    );
+    assert_eq!(identifier(&rows[1][1][0]), "d");
+    assert_eq!(
+        canonical("pmatrix{col{a#b} lcol{c#d}}"),
+        "pmatrix {col {a # b} lcol {c # d}}"
+    );
+    assert_eq!(canonical("dmatrix{a&b#c&d}"), "dmatrix {a & b # c & d}");
+}
+
+#[test]
+fn test_grids() {
+    let nodes = parse("cases {2x+y=4 # 3x-4y=-1}");
+    assert!(matches!(
+        &nodes[0].kind,
+        NodeKind::Grid { kind: GridKind::Cases, rows } if rows.len() == 2
+    ));
+
+    for (script, kind) in [
+        ("pile {a # b}", GridKind::Pile(ColumnAlign::Center)),
+        ("lpile {a # b}", GridKind::Pile(ColumnAlign::Left)),
+        ("rpile {a # b}", GridKind::Pile(ColumnAlign::Right)),
+        ("eqalign {x &= 1 # y &= 2}", GridKind::EqAlign),
+        ("LADDER {2&12&28#2&6&14#3&7&}", GridKind::Ladder),
+        ("SLADDER {2&12&#2&6&0#2&3&0#1&1&}", GridKind::SLadder),
+    ] {
+        assert!(
+            matches!(&parse(script)[0].kind, NodeKind::Grid { kind: k, .. } if *k == kind),
+            "{script}"
+        );
+    }
+
+    let nodes = parse("LONGDIV{6}{422}{2532#24#~13#~12#~~12#~~12#~~~0}");
+    let NodeKind::LongDiv { rows, .. } = &nodes[0].kind else {
+        panic!("{:?}", nodes[0]);
+    };
+    assert_eq!(rows.len(), 7);
+    assert!(matches!(
+        rows[6][2].kind,
+        NodeKind::Space(SpaceKind::Normal)
+    ));
+    canonical("LONGDIV{6}{422}{2532#24#~13#~12#~~12#~~12#~~~0}");
+    assert_eq!(
+        canonical("LADDER {2&12&28#3&7&}"),
+        "ladder {2 & 12 & 28 # 3 & 7 & }"
+    );
+}
+
+#[test]
+fn test_commands() {
+    let nodes = parse("A REL <-> {+2} {-5} B");
+    assert!(matches!(
+        nodes[1].kind,
+        NodeKind::Relation { under: Some(_), .. }
+    ));
+    let nodes = parse("A BUILDREL <-> {+2} B");
+    assert!(matches!(
+        nodes[1].kind,
+        NodeKind::Relation { under: None, .. }
+    ));
+
+    let nodes = parse("{COLOR {255,0,255} {3}} over {4}");
+    let NodeKind::Fraction { numerator, .. } = &nodes[0].kind else {
+        panic!("{:?}", nodes[0]);
+    };
+    assert!(matches!(
+        &numerator.kind,
+        NodeKind::Group(items) if matches!(items[0].kind, NodeKind::Color { rgb: [255, 0, 255], .. })
+    ));
+
+    assert!(matches!(parse("not =")[0].kind, NodeKind::Not(_)));
+    assert!(matches!(parse("bigg /")[0].kind, NodeKind::Bigg(_)));
+    assert!(matches!(
+        parse("n choose r")[0].kind,
+        NodeKind::Fraction {
+            kind: FractionKind::Choose,
+            ..
+        }
+    ));
+    assert_eq!(canonical("BINOM n r"), "n choose r");
+
+    let nodes = parse("{ scale 70 m over n }");
+    let NodeKind::Group(items) = &nodes[0].kind else {
+        panic!("{:?}", nodes[0]);
+    };
+    assert!(matches!(items[0].kind, NodeKind::Scale(70)));
+    assert!(matches!(items[1].kind, NodeKind::Fraction { .. }));
+
+    let nodes = parse("rm Equation~ bold Editor it x");
+    assert!(matches!(nodes[0].kind, NodeKind::Font(FontStyle::Roman)));
+    assert!(matches!(nodes[2].kind, NodeKind::Space(SpaceKind::Normal)));
+    assert!(matches!(nodes[3].kind, NodeKind::Font(FontStyle::Bold)));
+    assert!(matches!(nodes[5].kind, NodeKind::Font(FontStyle::Italic)));
+}
+
+#[test]
+fn test_decorations() {
+    for (keyword, kind) in [
+        ("acute", DecorationKind::Acute),
+        ("grave", DecorationKind::Grave),
+        ("dot", DecorationKind::Dot),
+        ("ddot", DecorationKind::DoubleDot),
+        ("hat", DecorationKind::Hat),
+        ("check", DecorationKind::Check),
+        ("bar", DecorationKind::Bar),
+        ("vec", DecorationKind::Vec),
+        ("dyad", DecorationKind::Dyad),
+        ("under", DecorationKind::Under),
+        ("arch", DecorationKind::Arch),
+        ("tilde", DecorationKind::Tilde),
+    ] {
+        let nodes = parse(&format!("{} AAA", keyword.to_uppercase()));
+        let NodeKind::Decoration { kind: k, body } = &nodes[0].kind else {
+            panic!("{keyword}: {:?}", nodes[0]);
+        };
+        assert_eq!(*k, kind);
+        assert_eq!(identifier(body), "AAA");
+    }
+}
+
+#[test]
+fn test_symbols_and_words() {
+    let nodes =
+        parse("alpha Alpha ALPHA LEQ leq larrow LARROW -> != times \"abc def\" 유리수 sin x 3.14");
+    let symbols: Vec<char> = nodes
+        .iter()
+        .filter_map(|node| match node.kind {
+            NodeKind::Symbol(symbol) => Some(symbol.value),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(symbols, ['α', 'Α', 'Α', '≤', '≤', '←', '⇐', '→', '≠', '×']);
+    assert!(matches!(&nodes[10].kind, NodeKind::Text(text) if text == "abc def"));
+    assert_eq!(identifier(&nodes[11]), "유리수");
+    assert!(matches!(nodes[12].kind, NodeKind::Function("sin")));
+    assert!(matches!(&nodes[14].kind, NodeKind::Number(n) if n == "3.14"));
+
+    let nodes = parse("a # b & c");
+    assert!(matches!(nodes[1].kind, NodeKind::LineBreak));
+    assert!(matches!(nodes[3].kind, NodeKind::Align));
+    assert_eq!(nodes[4].span, Span::new(8, 9));
+}
+
+#[test]
+fn test_canonical_script() {
+    assert_eq!(
+        canonical("{a over b} + sqrt {x^2}"),
+        "{a over b} + sqrt {x^2}"
+    );
+    assert_eq!(canonical("a+b OVER c"), "{a + b} over c");
+    assert_eq!(canonical("x_n+1"), "x_{n + 1}");
+    assert_eq!(canonical("ALPHA LEQ Larrow"), "Alpha leq Larrow");
+    assert_eq!(canonical("int _1 ^2 {3x^2}dx"), "int_1^2 {3 x^2} dx");
+
+    for script in [
+        "10a^3 over b^2 times ~□ ~÷ b^3 over 2a =( 2a^2 over b )^3",
+        "(A UNION B)^C` =` A^C INTER B^C",
+        "int from 0 to 3 `^3sqrt{x^2 +1}dx",
+        "lim_N->inf 1 over N sum_n=1^N LEFT(SUM_k=1^n 1 over 2^k right)",
+        "matrix{a_{1} &b_{1}&c_{1} # a_{2}&b_{2}&c_{2} # a_{3}&b_{3}&c_{3}}",
+        "y= lim _{x -> 0} {{1} over {x}}",
+        "rm 2H_2 O = 2H_2 + O_2",
+        "bigg / {x+y} over {x-y}",
+        "sqrt{x}^2 + hat x_1 + a over b over c",
+    ] {
+        canonical(script);
+    }
+}
+
+#[test]
+fn test_parse_errors() {
+    let cases = [
+        ("{a", ParseErrorKind::UnclosedBrace),
+        ("a}", ParseErrorKind::UnmatchedBrace),
+        ("\"abc", ParseErrorKind::UnterminatedQuote),
+        ("left ( a", ParseErrorKind::MissingRight),
+        ("a right )", ParseErrorKind::UnmatchedRight),
+        (
+            "a over",
+            ParseErrorKind::MissingArgument("over".to_string()),
+        ),
+        (
+            "over b",
+            ParseErrorKind::MissingArgument("over".to_string()),
+        ),
+        ("sqrt", ParseErrorKind::MissingArgument("sqrt".to_string())),
+        (
+            "color {300,0,0} x",
+            ParseErrorKind::InvalidArgument("color".to_string()),
+        ),
+        (
+            "matrix a",
+            ParseErrorKind::MissingArgument("matrix".to_string()),
+        ),
+    ];
+    for (script, kind) in cases {
+        let error = equation::parse(script).unwrap_err();
+        assert_eq!(error.kind, kind, "{script}");
+    }
+
+    let error = equation::parse("a over").unwrap_err();
+    assert_eq!(error.span, Span::new(6, 6));
+    assert!(error.to_string().contains("over"));
+}
+
+#[test]
+fn test_nesting_depth_limit() {
+    let deep = |open: &str, close: &str, count: usize| {
+        format!("{}a{}", open.repeat(count), close.repeat(count))
+    };
+    let rejected = [
+        deep("{", "}", 10000),
+        deep("sqrt {", "}", 10000),
+        deep("left ( ", " right )", 10000),
+        format!("a{}", " over a".repeat(10000)),
+        format!("x{}", "^a".repeat(10000)),
+        format!("{}a", "^".repeat(10000)),
+    ];
+    let accepted = [
+        deep("{", "}", equation::MAX_DEPTH - 1),
+        deep("left ( ", " right )", equation::MAX_DEPTH - 1),
+        format!("a{}", " over a".repeat(equation::MAX_DEPTH - 1)),
+    ];
+
+    // 디버그 빌드는 스택 프레임이 커서 메인 스레드 기본 크기(8MiB)에서 확인
+    std::thread::Builder::new()
+        .stack_size(8 << 20)
+        .spawn(move || {
+            for script in &rejected {
+                let error = equation::parse(script).unwrap_err();
+                assert_eq!(error.kind, ParseErrorKind::TooDeep, "{}", &script[..20]);
+            }
+            // 한도 안의 중첩은 그대로 읽고 출력함
+            for script in &accepted {
+                let formula = equation::parse(script).unwrap();
+                assert!(formula.to_latex().contains('a'));
+                assert!(formula.to_mathml().contains("<mi>a</mi>"));
+                assert_eq!(equation::parse(&formula.to_string()).unwrap(), formula);
+            }
+        })
+        .unwrap()
+        .join()
+        .unwrap();
+}
+
+#[test]
+fn test_equation_parse_script() {
+    use ir::HwpUnit;
+    use ir::control::{Equation, EquationFormat, ObjectCommon};
+
+    let mut equation = Equation {
+        common: ObjectCommon::default(),
+        script: "1 over 2".to_string(),
+        format: EquationFormat::HwpScript,
+        baseline_offset: HwpUnit::ZERO,
+        font_size: HwpUnit::ZERO,
+        color: None,
+        line_mode: None,
+        version: None,
+        font_name: None,
+        properties: None,
+    };
+    let formula = equation.parse_script().unwrap().unwrap();
+    assert!(matches!(formula.nodes[0].kind, NodeKind::Fraction { .. }));
+
+    equation.format = EquationFormat::LaTeX;
+    assert!(equation.parse_script().is_none());
+}
+
+fn latex(script: &str) -> String {
+    equation::parse(script).unwrap().to_latex()
+}
+
+/// `<math>` 요소 안의 내용만 돌려줍니다.
+fn mathml(script: &str) -> String {
+    let output = equation::parse(script).unwrap().to_mathml();
+    let body = output
+        .strip_prefix("<math xmlns=\"http://www.w3.org/1998/Math/MathML\">")
+        .and_then(|body| body.strip_suffix("</math>"))
+        .unwrap_or_else(|| panic!("{script:?}: {output}"));
+    body.to_string()
+}
+
+#[test]
+fn test_to_latex() {
+    assert_eq!(
+        latex("{a over b} + sqrt {x^2}"),
+        "{\\frac{a}{b}} + \\sqrt{x^{2}}"
+    );
+    assert_eq!(latex("root 3 of x"), "\\sqrt[3]{x}");
+    assert_eq!(
+        latex("sum from {i=1} to n i"),
+        "\\sum\\limits_{i = 1}^{n} i"
+    );
+    assert_eq!(latex("lim from {x -> 0} f"), "\\lim_{x \\rightarrow 0} f");
+    assert_eq!(latex("x lsub 1 lsup 2"), "{}_{1}^{2}x");
+    assert_eq!(latex("a choose b"), "\\binom{a}{b}");
+    assert_eq!(latex("hat {ab} + vec a"), "\\widehat{ab} + \\vec{a}");
+    assert_eq!(latex("not = alpha"), "\\neq \\alpha");
+    assert_eq!(latex("left ( a right ."), "\\left( a \\right.");
+    assert_eq!(latex("color {255,0,0} x"), "\\textcolor[RGB]{255,0,0}{x}");
+    assert_eq!(latex("rm abc bold x"), "\\mathrm{abc} \\mathbf{x}");
+    assert_eq!(latex("\"a&b\""), "\\text{a\\&b}");
+    assert_eq!(
+        latex("pmatrix {a & b # c & d}"),
+        "\\begin{pmatrix} a & b \\\\ c & d \\end{pmatrix}"
+    );
+    assert_eq!(
+        latex("cases {1 & x>0 # 0 & x<0}"),
+        "\\begin{cases} 1 & x > 0 \\\\ 0 & x < 0 \\end{cases}"
+    );
+    assert_eq!(
+        latex("a & = b # c & = d"),
+        "\\begin{aligned} a & = b \\\\ c & = d \\end{aligned}"
+    );
+    assert_eq!(latex("a # b"), "\\begin{gathered} a \\\\ b \\end{gathered}");
+}
+
+#[test]
+fn test_latex_fallbacks() {
+    // 크기 비율은 무시
+    assert_eq!(latex("scale 150 x"), "x");
+    // LaTeX 명령이 없는 큰 연산자는 유니코드 그대로
+    assert_eq!(latex("oint x + odint y"), "\\oint x + ∯ y");
+    // 괄호가 아닌 항목은 크기 조절 없이
+    assert_eq!(latex("bigg x"), "x");
+    assert_eq!(latex("bigg ("), "\\bigg(");
+    assert_eq!(
+        latex("ladder {1 & 2 # 3 & 4}"),
+        "\\begin{array}{r|r} 1 & 2 \\\\ \\hline 3 & 4 \\end{array}"
+    );
+    assert_eq!(latex("arch AB"), "\\overset{\\frown}{AB}");
+}
+
+#[test]
+fn test_to_mathml() {
+    assert_eq!(
+        mathml("{a over b} + sqrt {x^2}"),
+        "<mrow><mfrac><mi>a</mi><mi>b</mi></mfrac></mrow><mo>+</mo>\
+         <msqrt><mrow><msup><mi>x</mi><mn>2</mn></msup></mrow></msqrt>"
+    );
+    assert_eq!(mathml("root 3 of x"), "<mroot><mi>x</mi><mn>3</mn></mroot>");
+    assert_eq!(
+        mathml("sum _i ^n"),
+        "<munderover><mo largeop=\"true\">∑</mo><mi>i</mi><mi>n</mi></munderover>"
+    );
+    assert_eq!(
+        mathml("int _0 ^1"),
+        "<msubsup><mo largeop=\"true\">∫</mo><mn>0</mn><mn>1</mn></msubsup>"
+    );
+    assert_eq!(
+        mathml("x lsub 1"),
+        "<mmultiscripts><mi>x</mi><none/><none/><mprescripts/><mn>1</mn><none/></mmultiscripts>"
+    );
+    assert_eq!(
+        mathml("a atop b"),
+        "<mfrac linethickness=\"0\"><mi>a</mi><mi>b</mi></mfrac>"
+    );
+    assert_eq!(mathml("not in"), "<mo>∉</mo>");
+    assert_eq!(
+        mathml("rm abc it xyz bold y"),
+        "<mi>abc</mi><mi mathvariant=\"italic\">xyz</mi><mi mathvariant=\"bold-italic\">y</mi>"
+    );
+    assert_eq!(mathml("x<y"), "<mi>x</mi><mo>&lt;</mo><mi>y</mi>");
+    assert_eq!(
+        mathml("color {255,0,0} x"),
+        "<mstyle mathcolor=\"#FF0000\"><mi>x</mi></mstyle>"
+    );
+    assert_eq!(
+        mathml("scale 150 x"),
+        "<mstyle mathsize=\"150%\"><mi>x</mi></mstyle>"
+    );
+    assert_eq!(
+        mathml("bmatrix {a & b}"),
+        "<mrow><mo fence=\"true\" stretchy=\"true\">[</mo><mtable><mtr><mtd><mi>a</mi></mtd>\
+         <mtd><mi>b</mi></mtd></mtr></mtable><mo fence=\"true\" stretchy=\"true\">]</mo></mrow>"
+    );
+    assert_eq!(
+        mathml("a & = b # c & = d"),
+        "<mtable columnalign=\"right left\" columnspacing=\"0\"><mtr><mtd><mi>a</mi></mtd>\
+         <mtd><mo>=</mo><mi>b</mi></mtd></mtr><mtr><mtd><mi>c</mi></mtd><mtd><mo>=</mo>\
+         <mi>d</mi></mtd></mtr></mtable>"
+    );
+    assert!(mathml("longdiv 3 {12} {36}").contains("<menclose notation=\"longdiv\"><mn>36</mn>"));
+}
+
+#[test]
+fn test_equation_convert() {
+    use ir::HwpUnit;
+    use ir::control::{Equation, EquationFormat, ObjectCommon};
+
+    let mut equation = Equation {
+        common: ObjectCommon::default(),
+        script: "1 over 2".to_string(),
+        format: EquationFormat::HwpScript,
+        baseline_offset: HwpUnit::ZERO,
+        font_size: HwpUnit::ZERO,
+        color: None,
+        line_mode: None,
+        version: None,
+        font_name: None,
+        properties: None,
+    };
+    assert_eq!(equation.to_latex().unwrap().unwrap(), "\\frac{1}{2}");
+    assert!(
+        equation
+            .to_mathml()
+            .unwrap()
+            .unwrap()
+            .contains("<mfrac><mn>1</mn><mn>2</mn></mfrac>")
+    );
+
+    equation.script = "a over".to_string();
+    assert!(equation.to_latex().unwrap().is_err());
+
+    equation.format = EquationFormat::LaTeX;
+    equation.script = "\\frac{1}{2}".to_string();
+    assert_eq!(equation.to_latex().unwrap().unwrap(), "\\frac{1}{2}");
+    assert!(equation.to_mathml().is_none());
+}
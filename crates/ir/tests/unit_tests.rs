@@ -98,6 +98,55 @@ fn test_section_with_paragraphs() {
     assert_eq!(section.paragraphs.len(), 2);
 }
 
+#[test]
+fn test_preview_text() {
+    let mut table = table::Table::new(1, 2);
+    let mut row = table::TableRow::new();
+    row.cells.push(table::TableCell::with_text(0, 0, "이름"));
+    row.cells.push(table::TableCell::with_text(0, 1, "나이"));
+    table.rows.push(row);
+
+    let mut para = paragraph::Paragraph::with_text("표 앞");
+    let mut run = paragraph::Run::new();
+    run.contents.push(paragraph::RunContent::Control(Box::new(
+        control::Control::Table(Box::new(table)),
+    )));
+    para.runs.push(run);
+
+    let mut section = Section::default();
+    section
+        .paragraphs
+        .push(paragraph::Paragraph::with_text("첫 문단"));
+    section.paragraphs.push(para);
+    let mut doc = Document::new();
+    doc.add_section(section);
+
+    assert_eq!(
+        doc.to_preview_text(),
+        "첫 문단\r\n표 앞\r\n<이름><나이>\r\n"
+    );
+}
+
+#[test]
+fn test_preview_text_length_limit() {
+    let mut section = Section::default();
+    for _ in 0..100 {
+        section
+            .paragraphs
+            .push(paragraph::Paragraph::with_text("가".repeat(30)));
+    }
+    // 서로게이트 쌍은 UTF-16 코드 단위 두 개로 셈
+    section
+        .paragraphs
+        .insert(0, paragraph::Paragraph::with_text("😀"));
+    let mut doc = Document::new();
+    doc.add_section(section);
+
+    let preview = doc.to_preview_text();
+    assert_eq!(preview.encode_utf16().count(), PREVIEW_TEXT_MAX_LEN);
+    assert!(preview.starts_with("😀\r\n가"));
+}
+
 #[test]
 fn test_metadata() {
     let meta = Metadata::new()
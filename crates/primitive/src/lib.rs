@@ -43,6 +43,7 @@
 //! - `note` - 각주/미주 관련
 //! - `object` - 개체 공통 관련
 //! - `misc` - 기타
+//! - `limits` - 신뢰할 수 없는 문서를 읽을 때의 자원 제한
 
 #![deny(clippy::all)]
 
@@ -62,6 +63,7 @@ pub mod geometry;
 pub mod heading;
 pub mod id;
 pub mod image;
+pub mod limits;
 pub mod line;
 pub mod line_break;
 pub mod line_style;
@@ -136,6 +138,7 @@ pub use id::{
     TabDefId,
 };
 pub use image::{ImageEffect, ImageFlip};
+pub use limits::{LimitExceeded, LimitKind, Limits};
 pub use line::{LineCap, LineOutlineStyle, LineType, LineWrap};
 pub use line_break::{LineBreakKorean, LineBreakLatin};
 pub use line_style::{BorderLineStyle, LineStyle, UnderlineShape};
@@ -0,0 +1,190 @@
+//! 신뢰할 수 없는 문서를 읽을 때의 자원 제한
+//!
+//! HWP와 HWPX 리더가 공유하는 상한값입니다. 압축 폭탄이나 비정상적으로 깊은
+//! 중첩 구조가 프로세스의 메모리를 모두 써 버리지 않도록 읽는 도중에 검사합니다.
+
+use std::fmt;
+
+/// 문서 읽기 자원 제한
+///
+/// 기본값은 일반적인 문서를 모두 읽을 수 있을 만큼 넉넉하게 잡혀 있습니다.
+/// 서버에서 사용자 업로드를 다룰 때는 용도에 맞게 줄여서 사용합니다.
+///
+/// # Example
+///
+/// ```
+/// use primitive::Limits;
+///
+/// let limits = Limits::new()
+///     .with_max_stream_size(16 * 1024 * 1024)
+///     .with_max_nesting_depth(16);
+/// assert_eq!(limits.max_stream_size, 16 * 1024 * 1024);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Limits {
+    /// 스트림(파트) 하나의 압축 해제 후 최대 바이트 수
+    pub max_stream_size: u64,
+    /// 문서 전체에서 압축 해제한 바이트 수의 합 상한
+    pub max_total_size: u64,
+    /// 레코드 하나의 최대 바이트 수
+    pub max_record_size: u64,
+    /// 표, 글상자 등 중첩 구조의 최대 깊이
+    pub max_nesting_depth: usize,
+    /// 바이너리 데이터(그림, OLE 등) 하나의 최대 바이트 수
+    pub max_binary_data_size: u64,
+}
+
+impl Limits {
+    /// 기본 제한값
+    pub const DEFAULT: Self = Self {
+        max_stream_size: 256 * 1024 * 1024,
+        max_total_size: 1024 * 1024 * 1024,
+        max_record_size: 64 * 1024 * 1024,
+        max_nesting_depth: 64,
+        max_binary_data_size: 256 * 1024 * 1024,
+    };
+
+    /// 제한 없음
+    pub const UNLIMITED: Self = Self {
+        max_stream_size: u64::MAX,
+        max_total_size: u64::MAX,
+        max_record_size: u64::MAX,
+        max_nesting_depth: usize::MAX,
+        max_binary_data_size: u64::MAX,
+    };
+
+    /// 기본 제한값으로 생성
+    pub const fn new() -> Self {
+        Self::DEFAULT
+    }
+
+    /// 스트림 하나의 최대 크기 지정
+    pub const fn with_max_stream_size(mut self, max_stream_size: u64) -> Self {
+        self.max_stream_size = max_stream_size;
+        self
+    }
+
+    /// 전체 압축 해제 크기 상한 지정
+    pub const fn with_max_total_size(mut self, max_total_size: u64) -> Self {
+        self.max_total_size = max_total_size;
+        self
+    }
+
+    /// 레코드 하나의 최대 크기 지정
+    pub const fn with_max_record_size(mut self, max_record_size: u64) -> Self {
+        self.max_record_size = max_record_size;
+        self
+    }
+
+    /// 최대 중첩 깊이 지정
+    pub const fn with_max_nesting_depth(mut self, max_nesting_depth: usize) -> Self {
+        self.max_nesting_depth = max_nesting_depth;
+        self
+    }
+
+    /// 바이너리 데이터 하나의 최대 크기 지정
+    pub const fn with_max_binary_data_size(mut self, max_binary_data_size: u64) -> Self {
+        self.max_binary_data_size = max_binary_data_size;
+        self
+    }
+
+    /// `kind` 제한값 반환
+    pub const fn get(&self, kind: LimitKind) -> u64 {
+        match kind {
+            LimitKind::StreamSize => self.max_stream_size,
+            LimitKind::TotalSize => self.max_total_size,
+            LimitKind::RecordSize => self.max_record_size,
+            LimitKind::NestingDepth => self.max_nesting_depth as u64,
+            LimitKind::BinaryDataSize => self.max_binary_data_size,
+        }
+    }
+
+    /// `value`가 `kind` 제한 이내인지 검사
+    ///
+    /// 넘으면 [`LimitExceeded`]를 반환합니다.
+    pub const fn check(&self, kind: LimitKind, value: u64) -> Result<(), LimitExceeded> {
+        let limit = self.get(kind);
+        if value > limit {
+            Err(LimitExceeded { kind, limit })
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// 자원 제한 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LimitKind {
+    /// [`Limits::max_stream_size`]
+    StreamSize,
+    /// [`Limits::max_total_size`]
+    TotalSize,
+    /// [`Limits::max_record_size`]
+    RecordSize,
+    /// [`Limits::max_nesting_depth`]
+    NestingDepth,
+    /// [`Limits::max_binary_data_size`]
+    BinaryDataSize,
+}
+
+impl fmt::Display for LimitKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            LimitKind::StreamSize => "stream size",
+            LimitKind::TotalSize => "total decompressed size",
+            LimitKind::RecordSize => "record size",
+            LimitKind::NestingDepth => "nesting depth",
+            LimitKind::BinaryDataSize => "binary data size",
+        };
+        f.write_str(name)
+    }
+}
+
+/// 넘어선 제한
+///
+/// 각 크레이트의 `Error::LimitExceeded`로 변환됩니다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LimitExceeded {
+    /// 제한 종류
+    pub kind: LimitKind,
+    /// 제한값
+    pub limit: u64,
+}
+
+impl fmt::Display for LimitExceeded {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} exceeds the limit of {}", self.kind, self.limit)
+    }
+}
+
+impl std::error::Error for LimitExceeded {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        let limits = Limits::new().with_max_record_size(10);
+
+        assert!(limits.check(LimitKind::RecordSize, 10).is_ok());
+        assert_eq!(
+            limits.check(LimitKind::RecordSize, 11),
+            Err(LimitExceeded {
+                kind: LimitKind::RecordSize,
+                limit: 10
+            })
+        );
+        assert!(
+            Limits::UNLIMITED
+                .check(LimitKind::TotalSize, u64::MAX)
+                .is_ok()
+        );
+    }
+}